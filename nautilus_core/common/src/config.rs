@@ -0,0 +1,566 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use crate::config_loader::{resolved_config_dump, ConfigLoader};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::Path;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ConfigError {
+    /// The config could not be parsed, `field` is the path to the offending value.
+    Parse { field: String, message: String },
+    /// The config parsed but a value failed validation.
+    Invalid { field: String, message: String },
+    /// The config file could not be read.
+    Io(String),
+}
+
+impl ConfigError {
+    pub fn invalid(field: &str, message: &str) -> Self {
+        ConfigError::Invalid {
+            field: field.to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    /// Returns the path to the offending field (empty if not applicable).
+    pub fn field(&self) -> &str {
+        match self {
+            ConfigError::Parse { field, .. } | ConfigError::Invalid { field, .. } => field,
+            ConfigError::Io(_) => "",
+        }
+    }
+
+    fn prefixed(self, prefix: &str) -> Self {
+        match self {
+            ConfigError::Invalid { field, message } => ConfigError::Invalid {
+                field: format!("{}.{}", prefix, field),
+                message,
+            },
+            other => other,
+        }
+    }
+}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigError::Parse { field, message } => {
+                write!(f, "Cannot parse config at '{}', {}", field, message)
+            }
+            ConfigError::Invalid { field, message } => {
+                write!(f, "Invalid config value for '{}', {}", field, message)
+            }
+            ConfigError::Io(message) => write!(f, "Cannot read config, {}", message),
+        }
+    }
+}
+
+impl Error for ConfigError {}
+
+/// Validates the config values beyond what is enforced by deserialization.
+pub trait ValidateConfig {
+    fn validate(&self) -> Result<(), ConfigError>;
+}
+
+fn check_positive(field: &str, value: u64) -> Result<(), ConfigError> {
+    if value == 0 {
+        return Err(ConfigError::invalid(field, "must be positive"));
+    }
+    Ok(())
+}
+
+fn check_one_of(field: &str, value: &str, allowed: &[&str]) -> Result<(), ConfigError> {
+    if !allowed.contains(&value) {
+        return Err(ConfigError::invalid(
+            field,
+            &format!("was '{}', expected one of {:?}", value, allowed),
+        ));
+    }
+    Ok(())
+}
+
+/// Parses an order rate limit in the form `{limit}/{HH:MM:SS}`, returning the
+/// limit and the interval in seconds.
+///
+/// Minutes and seconds must be at most 59, and an interval which overflows is
+/// rejected.
+pub fn parse_rate_limit(value: &str) -> Option<(u64, u64)> {
+    let (limit, interval) = value.split_once('/')?;
+    let limit = limit.trim().parse::<u64>().ok()?;
+    let parts: Vec<&str> = interval.trim().split(':').collect();
+    if parts.len() != 3 {
+        return None;
+    }
+    let mut secs = 0_u64;
+    for (i, (part, scale)) in parts.iter().zip([3600, 60, 1]).enumerate() {
+        let value = part.parse::<u64>().ok()?;
+        if i > 0 && value > 59 {
+            return None;
+        }
+        secs = secs.checked_add(value.checked_mul(scale)?)?;
+    }
+    Some((limit, secs))
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DataEngineConfig {
+    pub qsize: u64,
+    pub debug: bool,
+}
+
+impl Default for DataEngineConfig {
+    fn default() -> Self {
+        DataEngineConfig {
+            qsize: 10_000,
+            debug: false,
+        }
+    }
+}
+
+impl ValidateConfig for DataEngineConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        check_positive("qsize", self.qsize)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RiskEngineConfig {
+    pub bypass: bool,
+    pub max_order_rate: String,
+    pub max_notional_per_order: HashMap<String, String>,
+    pub qsize: u64,
+    pub debug: bool,
+}
+
+impl Default for RiskEngineConfig {
+    fn default() -> Self {
+        RiskEngineConfig {
+            bypass: false,
+            max_order_rate: "100/00:00:01".to_string(),
+            max_notional_per_order: HashMap::new(),
+            qsize: 10_000,
+            debug: false,
+        }
+    }
+}
+
+impl ValidateConfig for RiskEngineConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        match parse_rate_limit(&self.max_order_rate) {
+            Some((limit, secs)) if limit > 0 && secs > 0 => {}
+            _ => {
+                return Err(ConfigError::invalid(
+                    "max_order_rate",
+                    &format!(
+                        "was '{}', expected a positive rate as 'limit/HH:MM:SS'",
+                        self.max_order_rate
+                    ),
+                ))
+            }
+        }
+        let mut instrument_ids: Vec<&String> = self.max_notional_per_order.keys().collect();
+        instrument_ids.sort();
+        for instrument_id in instrument_ids {
+            let field = format!("max_notional_per_order.{}", instrument_id);
+            if !instrument_id.contains('.') {
+                return Err(ConfigError::invalid(
+                    &field,
+                    "key must be an instrument ID as 'SYMBOL.VENUE'",
+                ));
+            }
+            let notional = &self.max_notional_per_order[instrument_id];
+            match notional.parse::<f64>() {
+                Ok(value) if value.is_finite() && value > 0.0 => {}
+                _ => {
+                    return Err(ConfigError::invalid(
+                        &field,
+                        &format!("was '{}', expected a positive decimal", notional),
+                    ))
+                }
+            }
+        }
+        check_positive("qsize", self.qsize)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ExecEngineConfig {
+    pub load_cache: bool,
+    pub allow_cash_positions: bool,
+    pub reconciliation_auto: bool,
+    pub reconciliation_lookback_mins: Option<u64>,
+    pub qsize: u64,
+    pub debug: bool,
+}
+
+impl Default for ExecEngineConfig {
+    fn default() -> Self {
+        ExecEngineConfig {
+            load_cache: true,
+            allow_cash_positions: false,
+            reconciliation_auto: true,
+            reconciliation_lookback_mins: None,
+            qsize: 10_000,
+            debug: false,
+        }
+    }
+}
+
+impl ValidateConfig for ExecEngineConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if let Some(mins) = self.reconciliation_lookback_mins {
+            check_positive("reconciliation_lookback_mins", mins)?;
+        }
+        check_positive("qsize", self.qsize)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct MatchingEngineConfig {
+    pub book_type: String,
+    pub oms_type: String,
+    pub account_type: String,
+    pub bar_execution: bool,
+    pub reject_stop_orders: bool,
+}
+
+impl Default for MatchingEngineConfig {
+    fn default() -> Self {
+        MatchingEngineConfig {
+            book_type: "L1_TBBO".to_string(),
+            oms_type: "NETTING".to_string(),
+            account_type: "MARGIN".to_string(),
+            bar_execution: true,
+            reject_stop_orders: true,
+        }
+    }
+}
+
+impl ValidateConfig for MatchingEngineConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        check_one_of(
+            "book_type",
+            &self.book_type,
+            &["L1_TBBO", "L2_MBP", "L3_MBO"],
+        )?;
+        check_one_of("oms_type", &self.oms_type, &["HEDGING", "NETTING"])?;
+        check_one_of("account_type", &self.account_type, &["CASH", "MARGIN"])
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct NetworkConfig {
+    pub base_url: Option<String>,
+    pub http_timeout_secs: u64,
+    pub max_retries: u32,
+    pub retry_delay_ms: u64,
+    pub recv_window_ms: u64,
+    pub heartbeat_secs: u64,
+}
+
+impl Default for NetworkConfig {
+    fn default() -> Self {
+        NetworkConfig {
+            base_url: None,
+            http_timeout_secs: 10,
+            max_retries: 3,
+            retry_delay_ms: 1_000,
+            recv_window_ms: 5_000,
+            heartbeat_secs: 30,
+        }
+    }
+}
+
+impl ValidateConfig for NetworkConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        if let Some(url) = &self.base_url {
+            if !(url.starts_with("http://") || url.starts_with("https://")) {
+                return Err(ConfigError::invalid(
+                    "base_url",
+                    &format!("was '{}', expected an http(s) URL", url),
+                ));
+            }
+        }
+        check_positive("http_timeout_secs", self.http_timeout_secs)?;
+        check_positive("recv_window_ms", self.recv_window_ms)?;
+        if self.recv_window_ms > 60_000 {
+            return Err(ConfigError::invalid(
+                "recv_window_ms",
+                "must not exceed 60000",
+            ));
+        }
+        check_positive("heartbeat_secs", self.heartbeat_secs)
+    }
+}
+
+/// The top level config for a node, grouping the config of each component.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct NodeConfig {
+    pub data_engine: DataEngineConfig,
+    pub risk_engine: RiskEngineConfig,
+    pub exec_engine: ExecEngineConfig,
+    pub matching_engine: MatchingEngineConfig,
+    pub network: NetworkConfig,
+}
+
+impl ValidateConfig for NodeConfig {
+    fn validate(&self) -> Result<(), ConfigError> {
+        self.data_engine
+            .validate()
+            .map_err(|e| e.prefixed("data_engine"))?;
+        self.risk_engine
+            .validate()
+            .map_err(|e| e.prefixed("risk_engine"))?;
+        self.exec_engine
+            .validate()
+            .map_err(|e| e.prefixed("exec_engine"))?;
+        self.matching_engine
+            .validate()
+            .map_err(|e| e.prefixed("matching_engine"))?;
+        self.network.validate().map_err(|e| e.prefixed("network"))
+    }
+}
+
+/// Returns a validated config parsed from a JSON string.
+pub fn config_from_json<T: DeserializeOwned + ValidateConfig>(s: &str) -> Result<T, ConfigError> {
+    let de = &mut serde_json::Deserializer::from_str(s);
+    let config: T = serde_path_to_error::deserialize(de).map_err(|e| ConfigError::Parse {
+        field: e.path().to_string(),
+        message: e.inner().to_string(),
+    })?;
+    config.validate()?;
+    Ok(config)
+}
+
+/// Returns a validated config parsed from a TOML string.
+pub fn config_from_toml<T: DeserializeOwned + ValidateConfig>(s: &str) -> Result<T, ConfigError> {
+    let de = toml::Deserializer::new(s);
+    let config: T = serde_path_to_error::deserialize(de).map_err(|e| ConfigError::Parse {
+        field: e.path().to_string(),
+        message: e.inner().message().to_string(),
+    })?;
+    config.validate()?;
+    Ok(config)
+}
+
+/// Returns a validated config loaded from a `.json` or `.toml` file.
+pub fn config_from_file<T: DeserializeOwned + ValidateConfig>(
+    path: &Path,
+) -> Result<T, ConfigError> {
+    let s = fs::read_to_string(path)
+        .map_err(|e| ConfigError::Io(format!("{}, {}", path.display(), e)))?;
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("json") => config_from_json(&s),
+        Some("toml") => config_from_toml(&s),
+        _ => Err(ConfigError::Io(format!(
+            "{}, unsupported file extension (expected .json or .toml)",
+            path.display()
+        ))),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Python API
+////////////////////////////////////////////////////////////////////////////////
+
+/// Validates a node config given as a JSON string, raising `ValueError` which
+/// names the offending field if invalid.
+#[pyfunction]
+fn validate_node_config(json: &str) -> PyResult<()> {
+    config_from_json::<NodeConfig>(json)
+        .map(|_| ())
+        .map_err(|e| PyValueError::new_err(e.to_string()))
+}
+
+/// Returns the resolved node config as JSON after applying the file, environment
+/// and override layers, raising `ValueError` if invalid.
+#[pyfunction]
+fn resolve_node_config(
+    path: Option<&str>,
+    env_prefix: Option<&str>,
+    overrides: Option<Vec<&str>>,
+) -> PyResult<String> {
+    let mut loader = ConfigLoader::new();
+    if let Some(path) = path {
+        loader = loader.file(Path::new(path));
+    }
+    if let Some(prefix) = env_prefix {
+        loader = loader.env_prefix(prefix);
+    }
+    if let Some(overrides) = overrides {
+        loader = loader.overrides(&overrides);
+    }
+    loader
+        .load::<NodeConfig>()
+        .map(|config| resolved_config_dump(&config))
+        .map_err(|e| PyValueError::new_err(e.to_string()))
+}
+
+pub fn register_module(py: Python<'_>, m: &PyModule) -> PyResult<()> {
+    let config = PyModule::new(py, "config")?;
+    config.add_function(wrap_pyfunction!(validate_node_config, config)?)?;
+    config.add_function(wrap_pyfunction!(resolve_node_config, config)?)?;
+
+    m.add_submodule(config)?;
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rate_limit() {
+        assert_eq!(parse_rate_limit("100/00:00:01"), Some((100, 1)));
+        assert_eq!(parse_rate_limit("10/01:02:03"), Some((10, 3723)));
+        assert_eq!(parse_rate_limit("100"), None);
+        assert_eq!(parse_rate_limit("100/00:01"), None);
+        assert_eq!(parse_rate_limit("x/00:00:01"), None);
+        assert_eq!(parse_rate_limit("10/00:60:00"), None);
+        assert_eq!(parse_rate_limit("10/00:00:60"), None);
+        assert_eq!(parse_rate_limit("10/100:59:59"), Some((10, 363_599)));
+        assert_eq!(parse_rate_limit("10/18446744073709551615:00:00"), None);
+    }
+
+    #[test]
+    fn test_node_config_defaults_are_valid() {
+        let config: NodeConfig = config_from_json("{}").unwrap();
+
+        assert_eq!(config, NodeConfig::default());
+        assert_eq!(config.risk_engine.max_order_rate, "100/00:00:01");
+    }
+
+    #[test]
+    fn test_node_config_from_json() {
+        let json = r#"{
+            "risk_engine": {"bypass": true, "max_notional_per_order": {"BTC/USDT.BINANCE": "10000"}},
+            "exec_engine": {"reconciliation_lookback_mins": 60},
+            "network": {"base_url": "https://api.binance.com"}
+        }"#;
+
+        let config: NodeConfig = config_from_json(json).unwrap();
+
+        assert!(config.risk_engine.bypass);
+        assert_eq!(config.exec_engine.reconciliation_lookback_mins, Some(60));
+        assert_eq!(
+            config.network.base_url.as_deref(),
+            Some("https://api.binance.com")
+        );
+    }
+
+    #[test]
+    fn test_node_config_from_toml() {
+        let s = r#"
+            [data_engine]
+            qsize = 500
+
+            [matching_engine]
+            book_type = "L2_MBP"
+            oms_type = "HEDGING"
+        "#;
+
+        let config: NodeConfig = config_from_toml(s).unwrap();
+
+        assert_eq!(config.data_engine.qsize, 500);
+        assert_eq!(config.matching_engine.book_type, "L2_MBP");
+    }
+
+    #[test]
+    fn test_parse_error_pinpoints_field() {
+        let result = config_from_json::<NodeConfig>(r#"{"exec_engine": {"qsize": "big"}}"#);
+
+        let err = result.unwrap_err();
+        assert!(matches!(err, ConfigError::Parse { .. }));
+        assert_eq!(err.field(), "exec_engine.qsize");
+    }
+
+    #[test]
+    fn test_unknown_field_is_rejected() {
+        let result = config_from_toml::<NodeConfig>("[network]\nhttp_timeout = 5\n");
+
+        assert_eq!(result.unwrap_err().field(), "network.http_timeout");
+    }
+
+    #[test]
+    fn test_validation_error_pinpoints_field() {
+        let json = r#"{"risk_engine": {"max_notional_per_order": {"BTC/USDT.BINANCE": "-1"}}}"#;
+
+        let err = config_from_json::<NodeConfig>(json).unwrap_err();
+
+        assert_eq!(
+            err,
+            ConfigError::invalid(
+                "risk_engine.max_notional_per_order.BTC/USDT.BINANCE",
+                "was '-1', expected a positive decimal"
+            )
+        );
+    }
+
+    #[test]
+    fn test_validation_errors() {
+        let cases = [
+            (r#"{"data_engine": {"qsize": 0}}"#, "data_engine.qsize"),
+            (
+                r#"{"risk_engine": {"max_order_rate": "100"}}"#,
+                "risk_engine.max_order_rate",
+            ),
+            (
+                r#"{"matching_engine": {"book_type": "L4"}}"#,
+                "matching_engine.book_type",
+            ),
+            (
+                r#"{"network": {"base_url": "ftp://x"}}"#,
+                "network.base_url",
+            ),
+            (
+                r#"{"network": {"recv_window_ms": 90000}}"#,
+                "network.recv_window_ms",
+            ),
+        ];
+
+        for (json, field) in cases {
+            let err = config_from_json::<NodeConfig>(json).unwrap_err();
+            assert_eq!(err.field(), field);
+        }
+    }
+
+    #[test]
+    fn test_config_from_file_unsupported_extension() {
+        let path = std::env::temp_dir().join("nautilus-config-test.yaml");
+        fs::write(&path, "").unwrap();
+
+        let result = config_from_file::<NodeConfig>(&path);
+
+        assert!(matches!(result, Err(ConfigError::Io(_))));
+        fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,449 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use crate::config::{ConfigError, ValidateConfig};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Loads a config by layering overrides on top of the config defaults.
+///
+/// Layers are applied in order: defaults, file, environment variables, then
+/// command line overrides. Nested fields are addressed with `.` on the command
+/// line (`risk_engine.bypass=true`) and with `__` in environment variables
+/// (`NAUTILUS__RISK_ENGINE__BYPASS=true`). Environment variables only apply to
+/// fields known from the defaults or file, so unrelated variables sharing the
+/// prefix are ignored. Any `${VAR}` or `${VAR:-default}` within string values
+/// of the file is interpolated from the environment before merging, while
+/// environment and command line values are taken literally.
+#[derive(Clone, Debug, Default)]
+pub struct ConfigLoader {
+    file: Option<PathBuf>,
+    env_prefix: Option<String>,
+    overrides: Vec<String>,
+    env: Option<HashMap<String, String>>,
+}
+
+impl ConfigLoader {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a `.json` or `.toml` file layer.
+    pub fn file(mut self, path: &Path) -> Self {
+        self.file = Some(path.to_path_buf());
+        self
+    }
+
+    /// Adds an environment variable layer for variables starting with `prefix`
+    /// which address a known field.
+    pub fn env_prefix(mut self, prefix: &str) -> Self {
+        self.env_prefix = Some(prefix.to_string());
+        self
+    }
+
+    /// Adds command line overrides, each in the form `path.to.field=value`.
+    pub fn overrides(mut self, overrides: &[&str]) -> Self {
+        self.overrides
+            .extend(overrides.iter().map(|s| s.to_string()));
+        self
+    }
+
+    /// Uses the given variables in place of the process environment.
+    pub fn with_env(mut self, env: HashMap<String, String>) -> Self {
+        self.env = Some(env);
+        self
+    }
+
+    fn env_vars(&self) -> HashMap<String, String> {
+        match &self.env {
+            Some(env) => env.clone(),
+            None => env::vars().collect(),
+        }
+    }
+
+    /// Returns the merged and interpolated config as a JSON value.
+    pub fn resolve<T: Default + Serialize>(&self) -> Result<Value, ConfigError> {
+        let env = self.env_vars();
+        let mut value = serde_json::to_value(T::default())
+            .map_err(|e| ConfigError::Io(format!("cannot serialize defaults, {}", e)))?;
+
+        if let Some(path) = &self.file {
+            let mut layer = read_file(path)?;
+            interpolate(&mut layer, "", &env)?;
+            merge(&mut value, layer);
+        }
+
+        if let Some(prefix) = &self.env_prefix {
+            let mut keys: Vec<&String> = env.keys().filter(|k| k.starts_with(prefix)).collect();
+            keys.sort();
+            for key in keys {
+                let path: Vec<String> = key[prefix.len()..]
+                    .split("__")
+                    .map(|s| s.to_lowercase())
+                    .collect();
+                if has_path(&value, &path) {
+                    set_path(&mut value, &path, parse_scalar(&env[key]));
+                }
+            }
+        }
+
+        for item in &self.overrides {
+            let (path, raw) = item.split_once('=').ok_or_else(|| ConfigError::Parse {
+                field: item.clone(),
+                message: "override must be in the form 'path.to.field=value'".to_string(),
+            })?;
+            let path: Vec<String> = path.trim().split('.').map(|s| s.to_string()).collect();
+            set_path(&mut value, &path, parse_scalar(raw.trim()));
+        }
+
+        Ok(value)
+    }
+
+    /// Returns the validated config resolved from all layers.
+    pub fn load<T: Default + Serialize + DeserializeOwned + ValidateConfig>(
+        &self,
+    ) -> Result<T, ConfigError> {
+        let value = self.resolve::<T>()?;
+        let config: T =
+            serde_path_to_error::deserialize(value).map_err(|e| ConfigError::Parse {
+                field: e.path().to_string(),
+                message: e.inner().to_string(),
+            })?;
+        config.validate()?;
+        Ok(config)
+    }
+}
+
+/// Returns the fully resolved config as pretty JSON with sorted keys, suitable
+/// for recording alongside run results for reproducibility.
+pub fn resolved_config_dump<T: Serialize>(config: &T) -> String {
+    let value = serde_json::to_value(config).expect("config must serialize to JSON");
+    serde_json::to_string_pretty(&sort_keys(value)).expect("JSON value must serialize")
+}
+
+/// Returns the value with the keys of every object in sorted order, whether or
+/// not `serde_json` preserves insertion order.
+fn sort_keys(value: Value) -> Value {
+    match value {
+        Value::Object(map) => {
+            let mut entries: Vec<(String, Value)> = map.into_iter().collect();
+            entries.sort_by(|a, b| a.0.cmp(&b.0));
+            Value::Object(
+                entries
+                    .into_iter()
+                    .map(|(key, value)| (key, sort_keys(value)))
+                    .collect(),
+            )
+        }
+        Value::Array(items) => Value::Array(items.into_iter().map(sort_keys).collect()),
+        value => value,
+    }
+}
+
+fn read_file(path: &Path) -> Result<Value, ConfigError> {
+    let s = fs::read_to_string(path)
+        .map_err(|e| ConfigError::Io(format!("{}, {}", path.display(), e)))?;
+    let parse_err = |message: String| ConfigError::Parse {
+        field: path.display().to_string(),
+        message,
+    };
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("json") => serde_json::from_str(&s).map_err(|e| parse_err(e.to_string())),
+        Some("toml") => toml::from_str(&s).map_err(|e| parse_err(e.message().to_string())),
+        _ => Err(ConfigError::Io(format!(
+            "{}, unsupported file extension (expected .json or .toml)",
+            path.display()
+        ))),
+    }
+}
+
+fn parse_scalar(raw: &str) -> Value {
+    match serde_json::from_str::<Value>(raw) {
+        Ok(value) => value,
+        Err(_) => Value::String(raw.to_string()),
+    }
+}
+
+fn merge(base: &mut Value, layer: Value) {
+    match (base, layer) {
+        (Value::Object(base), Value::Object(layer)) => {
+            for (key, value) in layer {
+                match base.get_mut(&key) {
+                    Some(existing) => merge(existing, value),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (base, layer) => *base = layer,
+    }
+}
+
+fn has_path(root: &Value, path: &[String]) -> bool {
+    let mut node = root;
+    for key in path {
+        match node.get(key) {
+            Some(child) => node = child,
+            None => return false,
+        }
+    }
+    true
+}
+
+fn set_path(root: &mut Value, path: &[String], value: Value) {
+    let mut node = root;
+    for key in &path[..path.len() - 1] {
+        if !node.is_object() {
+            *node = Value::Object(Map::new());
+        }
+        node = node
+            .as_object_mut()
+            .unwrap()
+            .entry(key.clone())
+            .or_insert_with(|| Value::Object(Map::new()));
+    }
+    if !node.is_object() {
+        *node = Value::Object(Map::new());
+    }
+    node.as_object_mut()
+        .unwrap()
+        .insert(path[path.len() - 1].clone(), value);
+}
+
+fn interpolate(
+    value: &mut Value,
+    field: &str,
+    env: &HashMap<String, String>,
+) -> Result<(), ConfigError> {
+    let child = |key: &str| {
+        if field.is_empty() {
+            key.to_string()
+        } else {
+            format!("{}.{}", field, key)
+        }
+    };
+    match value {
+        Value::String(s) if s.contains("${") => *s = interpolate_str(s, field, env)?,
+        Value::Array(items) => {
+            for (i, item) in items.iter_mut().enumerate() {
+                interpolate(item, &child(&i.to_string()), env)?;
+            }
+        }
+        Value::Object(map) => {
+            for (key, item) in map.iter_mut() {
+                interpolate(item, &child(key), env)?;
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Returns the string with each `${VAR}` or `${VAR:-default}` replaced.
+pub fn interpolate_str(
+    s: &str,
+    field: &str,
+    env: &HashMap<String, String>,
+) -> Result<String, ConfigError> {
+    let mut result = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(start) = rest.find("${") {
+        result.push_str(&rest[..start]);
+        let end = rest[start..]
+            .find('}')
+            .ok_or_else(|| ConfigError::invalid(field, "unterminated '${' in value"))?;
+        let expr = &rest[start + 2..start + end];
+        let (name, default) = match expr.split_once(":-") {
+            Some((name, default)) => (name, Some(default)),
+            None => (expr, None),
+        };
+        match (env.get(name), default) {
+            (Some(value), _) => result.push_str(value),
+            (None, Some(default)) => result.push_str(default),
+            (None, None) => {
+                return Err(ConfigError::invalid(
+                    field,
+                    &format!("environment variable '{}' is not set", name),
+                ))
+            }
+        }
+        rest = &rest[start + end + 1..];
+    }
+    result.push_str(rest);
+    Ok(result)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::NodeConfig;
+
+    fn env(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_interpolate_str() {
+        let env = env(&[("HOST", "api.binance.com")]);
+
+        assert_eq!(
+            interpolate_str("https://${HOST}/api", "f", &env).unwrap(),
+            "https://api.binance.com/api"
+        );
+        assert_eq!(interpolate_str("${PORT:-443}", "f", &env).unwrap(), "443");
+        assert_eq!(
+            interpolate_str("${MISSING}", "network.base_url", &env).unwrap_err(),
+            ConfigError::invalid(
+                "network.base_url",
+                "environment variable 'MISSING' is not set"
+            )
+        );
+        assert!(interpolate_str("${HOST", "f", &env).is_err());
+    }
+
+    #[test]
+    fn test_load_defaults_only() {
+        let config: NodeConfig = ConfigLoader::new().with_env(env(&[])).load().unwrap();
+
+        assert_eq!(config, NodeConfig::default());
+    }
+
+    #[test]
+    fn test_layers_apply_in_order() {
+        let path =
+            std::env::temp_dir().join(format!("nautilus-layers-{}.toml", std::process::id()));
+        fs::write(
+            &path,
+            "[data_engine]\nqsize = 100\ndebug = true\n\n[network]\nbase_url = \"https://${HOST}\"\n",
+        )
+        .unwrap();
+        let env = env(&[
+            ("HOST", "ftx.com"),
+            ("NAUTILUS__DATA_ENGINE__QSIZE", "200"),
+            ("NAUTILUS__RISK_ENGINE__BYPASS", "true"),
+        ]);
+
+        let config: NodeConfig = ConfigLoader::new()
+            .file(&path)
+            .env_prefix("NAUTILUS__")
+            .overrides(&[
+                "risk_engine.max_order_rate=50/00:00:01",
+                "data_engine.qsize=300",
+            ])
+            .with_env(env)
+            .load()
+            .unwrap();
+
+        assert!(config.data_engine.debug); // File
+        assert_eq!(config.data_engine.qsize, 300); // CLI over env over file
+        assert!(config.risk_engine.bypass); // Env
+        assert_eq!(config.risk_engine.max_order_rate, "50/00:00:01"); // CLI
+        assert_eq!(config.network.base_url.as_deref(), Some("https://ftx.com"));
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_env_layer_ignores_unknown_keys() {
+        let env = env(&[
+            ("NAUTILUS__LOG_DIR", "/var/log/nautilus"),
+            ("NAUTILUS__DATA_ENGINE__UNKNOWN", "1"),
+            ("NAUTILUS__DATA_ENGINE__QSIZE", "200"),
+        ]);
+
+        let config: NodeConfig = ConfigLoader::new()
+            .env_prefix("NAUTILUS__")
+            .with_env(env)
+            .load()
+            .unwrap();
+
+        assert_eq!(config.data_engine.qsize, 200);
+    }
+
+    #[test]
+    fn test_env_and_override_values_are_not_interpolated() {
+        let env = env(&[
+            ("HOST", "ftx.com"),
+            ("NAUTILUS__NETWORK__BASE_URL", "https://${HOST}/env"),
+        ]);
+
+        let from_env: NodeConfig = ConfigLoader::new()
+            .env_prefix("NAUTILUS__")
+            .with_env(env.clone())
+            .load()
+            .unwrap();
+        let from_cli: NodeConfig = ConfigLoader::new()
+            .overrides(&["network.base_url=https://${MISSING}/cli"])
+            .with_env(env)
+            .load()
+            .unwrap();
+
+        assert_eq!(
+            from_env.network.base_url.as_deref(),
+            Some("https://${HOST}/env")
+        );
+        assert_eq!(
+            from_cli.network.base_url.as_deref(),
+            Some("https://${MISSING}/cli")
+        );
+    }
+
+    #[test]
+    fn test_invalid_override_is_rejected() {
+        let result = ConfigLoader::new()
+            .overrides(&["risk_engine.bypass"])
+            .with_env(env(&[]))
+            .load::<NodeConfig>();
+
+        assert!(matches!(result, Err(ConfigError::Parse { .. })));
+    }
+
+    #[test]
+    fn test_override_type_error_pinpoints_field() {
+        let result = ConfigLoader::new()
+            .overrides(&["exec_engine.qsize=lots"])
+            .with_env(env(&[]))
+            .load::<NodeConfig>();
+
+        assert_eq!(result.unwrap_err().field(), "exec_engine.qsize");
+    }
+
+    #[test]
+    fn test_resolved_config_dump_is_sorted_and_roundtrips() {
+        let config = NodeConfig::default();
+
+        let dump = resolved_config_dump(&config);
+
+        assert!(dump.find("\"data_engine\"").unwrap() < dump.find("\"risk_engine\"").unwrap());
+        assert_eq!(
+            sort_keys(serde_json::json!({"b": {"d": 1, "c": [{"f": 2, "e": 3}]}, "a": 0}))
+                .to_string(),
+            r#"{"a":0,"b":{"c":[{"e":3,"f":2}],"d":1}}"#
+        );
+        let roundtrip: NodeConfig = crate::config::config_from_json(&dump).unwrap();
+        assert_eq!(roundtrip, config);
+    }
+}
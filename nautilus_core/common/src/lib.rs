@@ -13,12 +13,18 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+#![allow(non_local_definitions)] // Expanded from older `pyo3` macros
+
 use pyo3::prelude::*;
 
+pub mod config;
+pub mod config_loader;
 pub mod logging;
+pub mod supervisor;
 
 #[pymodule]
 fn common(py: Python<'_>, m: &PyModule) -> PyResult<()> {
+    config::register_module(py, m)?;
     logging::register_module(py, m)?;
     Ok(())
 }
@@ -0,0 +1,585 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::path::PathBuf;
+use std::process::{Child, Command};
+use std::time::{Duration, Instant};
+
+/// The environment variable giving a child the directory for its state snapshots.
+pub const ENV_STATE_DIR: &str = "NAUTILUS_STATE_DIR";
+/// The environment variable giving a child the number of times it has been restarted.
+pub const ENV_RESTART_COUNT: &str = "NAUTILUS_RESTART_COUNT";
+/// The environment variable giving a child the external message bus endpoint.
+pub const ENV_BUS_ENDPOINT: &str = "NAUTILUS_BUS_ENDPOINT";
+
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum RestartPolicy {
+    /// Never restart the process.
+    Never,
+    /// Restart the process only if it exits unsuccessfully.
+    OnFailure,
+    /// Always restart the process when it exits.
+    Always,
+}
+
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum ProcessRole {
+    Data,
+    Execution,
+    Strategy,
+}
+
+/// The specification for a supervised engine process.
+#[derive(Clone, Debug)]
+pub struct ProcessSpec {
+    pub name: String,
+    pub role: ProcessRole,
+    pub program: PathBuf,
+    pub args: Vec<String>,
+    pub env: HashMap<String, String>,
+    pub restart: RestartPolicy,
+    pub max_restarts: u32,
+    pub backoff: Duration,
+}
+
+impl ProcessSpec {
+    pub fn new(name: &str, role: ProcessRole, program: &str, args: &[&str]) -> Self {
+        ProcessSpec {
+            name: name.to_string(),
+            role,
+            program: PathBuf::from(program),
+            args: args.iter().map(|s| s.to_string()).collect(),
+            env: HashMap::new(),
+            restart: RestartPolicy::OnFailure,
+            max_restarts: 5,
+            backoff: Duration::from_secs(1),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ProcessStatus {
+    Pending,
+    Running { pid: u32 },
+    Backoff { exit_code: Option<i32> },
+    Exited { exit_code: Option<i32> },
+    Failed { reason: String },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SupervisorEvent {
+    Started {
+        name: String,
+        pid: u32,
+        restarts: u32,
+    },
+    Exited {
+        name: String,
+        exit_code: Option<i32>,
+    },
+    GaveUp {
+        name: String,
+        restarts: u32,
+    },
+    SpawnFailed {
+        name: String,
+        reason: String,
+    },
+}
+
+impl Display for SupervisorEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SupervisorEvent::Started {
+                name,
+                pid,
+                restarts,
+            } => write!(f, "{} started (pid={}, restarts={})", name, pid, restarts),
+            SupervisorEvent::Exited { name, exit_code } => {
+                write!(f, "{} exited (code={:?})", name, exit_code)
+            }
+            SupervisorEvent::GaveUp { name, restarts } => {
+                write!(f, "{} not restarted after {} restarts", name, restarts)
+            }
+            SupervisorEvent::SpawnFailed { name, reason } => {
+                write!(f, "{} failed to spawn, {}", name, reason)
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SupervisorError {
+    /// A process with the name was already added.
+    DuplicateName(String),
+    /// The name is empty or could escape the supervisor state directory.
+    InvalidName(String),
+}
+
+impl Display for SupervisorError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SupervisorError::DuplicateName(name) => {
+                write!(f, "process name '{}' was not unique", name)
+            }
+            SupervisorError::InvalidName(name) => {
+                write!(f, "process name '{}' was not a valid directory name", name)
+            }
+        }
+    }
+}
+
+impl Error for SupervisorError {}
+
+struct SupervisedProcess {
+    spec: ProcessSpec,
+    child: Option<Child>,
+    status: ProcessStatus,
+    restarts: u32,
+    restart_at: Option<Instant>,
+}
+
+/// Launches and monitors a topology of engine processes.
+///
+/// Each child is given its own state directory (under the supervisor state
+/// directory) and its restart count, so a restarted child can recover from the
+/// snapshots it last wrote. Children communicate through the external message
+/// bus at the configured endpoint rather than with the supervisor.
+pub struct Supervisor {
+    state_dir: PathBuf,
+    bus_endpoint: Option<String>,
+    processes: Vec<SupervisedProcess>,
+}
+
+impl Supervisor {
+    pub fn new(state_dir: PathBuf, bus_endpoint: Option<&str>) -> Self {
+        Supervisor {
+            state_dir,
+            bus_endpoint: bus_endpoint.map(|s| s.to_string()),
+            processes: Vec::new(),
+        }
+    }
+
+    /// Adds the process, which is started on the next `start`.
+    ///
+    /// The name must be unique and usable as a single directory name, as it is
+    /// joined onto the supervisor state directory.
+    pub fn add(&mut self, spec: ProcessSpec) -> Result<(), SupervisorError> {
+        let name = &spec.name;
+        if name.is_empty() || name == "." || name.contains(['/', '\\']) || name.contains("..") {
+            return Err(SupervisorError::InvalidName(name.clone()));
+        }
+        if self.processes.iter().any(|p| &p.spec.name == name) {
+            return Err(SupervisorError::DuplicateName(name.clone()));
+        }
+        self.processes.push(SupervisedProcess {
+            spec,
+            child: None,
+            status: ProcessStatus::Pending,
+            restarts: 0,
+            restart_at: None,
+        });
+        Ok(())
+    }
+
+    /// Returns the state directory for the named process.
+    pub fn process_state_dir(&self, name: &str) -> PathBuf {
+        self.state_dir.join(name)
+    }
+
+    /// Returns the status of each process by name, in the order added.
+    pub fn statuses(&self) -> Vec<(String, ProcessStatus)> {
+        self.processes
+            .iter()
+            .map(|p| (p.spec.name.clone(), p.status.clone()))
+            .collect()
+    }
+
+    /// Returns whether any process is running or waiting to be restarted.
+    pub fn is_active(&self) -> bool {
+        self.processes.iter().any(|p| {
+            matches!(
+                p.status,
+                ProcessStatus::Running { .. } | ProcessStatus::Backoff { .. }
+            )
+        })
+    }
+
+    /// Starts all pending processes.
+    pub fn start(&mut self) -> Vec<SupervisorEvent> {
+        let mut events = Vec::new();
+        for i in 0..self.processes.len() {
+            if self.processes[i].status == ProcessStatus::Pending {
+                events.push(self.spawn(i));
+            }
+        }
+        events
+    }
+
+    /// Checks each process, restarting exited processes per their policy.
+    ///
+    /// Should be called periodically by the owning event loop.
+    pub fn poll(&mut self, now: Instant) -> Vec<SupervisorEvent> {
+        let mut events = Vec::new();
+        for i in 0..self.processes.len() {
+            let exit_code = match self.processes[i].child.as_mut().map(|c| c.try_wait()) {
+                Some(Ok(Some(status))) => Some(status.code()),
+                Some(Ok(None)) | None => None,
+                Some(Err(e)) => {
+                    let process = &mut self.processes[i];
+                    process.child = None;
+                    process.status = ProcessStatus::Failed {
+                        reason: e.to_string(),
+                    };
+                    continue;
+                }
+            };
+
+            if let Some(exit_code) = exit_code {
+                let process = &mut self.processes[i];
+                process.child = None;
+                events.push(SupervisorEvent::Exited {
+                    name: process.spec.name.clone(),
+                    exit_code,
+                });
+                let should_restart = match process.spec.restart {
+                    RestartPolicy::Never => false,
+                    RestartPolicy::OnFailure => exit_code != Some(0),
+                    RestartPolicy::Always => true,
+                };
+                if !should_restart {
+                    process.status = ProcessStatus::Exited { exit_code };
+                } else if process.restarts >= process.spec.max_restarts {
+                    process.status = ProcessStatus::Exited { exit_code };
+                    events.push(SupervisorEvent::GaveUp {
+                        name: process.spec.name.clone(),
+                        restarts: process.restarts,
+                    });
+                } else {
+                    process.status = ProcessStatus::Backoff { exit_code };
+                    process.restart_at = Some(now + process.spec.backoff);
+                }
+            }
+
+            let process = &self.processes[i];
+            if let (ProcessStatus::Backoff { .. }, Some(restart_at)) =
+                (&process.status, process.restart_at)
+            {
+                if now >= restart_at {
+                    self.processes[i].restarts += 1;
+                    events.push(self.spawn(i));
+                }
+            }
+        }
+        events
+    }
+
+    /// Kills all running processes, and cancels any pending restarts.
+    ///
+    /// Every process is killed and waited on even if another fails, returning
+    /// the first error.
+    pub fn stop(&mut self) -> io::Result<()> {
+        let mut result = Ok(());
+        for process in &mut self.processes {
+            if let Some(mut child) = process.child.take() {
+                let killed = child.kill();
+                let waited = child.wait();
+                process.status = match &waited {
+                    Ok(status) => ProcessStatus::Exited {
+                        exit_code: status.code(),
+                    },
+                    Err(e) => ProcessStatus::Failed {
+                        reason: e.to_string(),
+                    },
+                };
+                if let (Ok(()), Err(e)) = (&result, killed.and(waited.map(|_| ()))) {
+                    result = Err(e);
+                }
+            } else if let ProcessStatus::Backoff { exit_code } = process.status {
+                process.status = ProcessStatus::Exited { exit_code };
+            }
+            process.restart_at = None;
+        }
+        result
+    }
+
+    fn spawn(&mut self, i: usize) -> SupervisorEvent {
+        let state_dir = self.process_state_dir(&self.processes[i].spec.name);
+        let process = &mut self.processes[i];
+        process.restart_at = None;
+
+        let mut command = Command::new(&process.spec.program);
+        command
+            .args(&process.spec.args)
+            .envs(&process.spec.env)
+            .env(ENV_STATE_DIR, &state_dir)
+            .env(ENV_RESTART_COUNT, process.restarts.to_string());
+        if let Some(endpoint) = &self.bus_endpoint {
+            command.env(ENV_BUS_ENDPOINT, endpoint);
+        }
+
+        let result = std::fs::create_dir_all(&state_dir).and_then(|_| command.spawn());
+        match result {
+            Ok(child) => {
+                let pid = child.id();
+                process.child = Some(child);
+                process.status = ProcessStatus::Running { pid };
+                SupervisorEvent::Started {
+                    name: process.spec.name.clone(),
+                    pid,
+                    restarts: process.restarts,
+                }
+            }
+            Err(e) => {
+                process.status = ProcessStatus::Failed {
+                    reason: e.to_string(),
+                };
+                SupervisorEvent::SpawnFailed {
+                    name: process.spec.name.clone(),
+                    reason: e.to_string(),
+                }
+            }
+        }
+    }
+}
+
+impl Drop for Supervisor {
+    fn drop(&mut self) {
+        let _ = self.stop();
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    fn state_dir(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "nautilus-supervisor-{}-{}",
+            name,
+            std::process::id()
+        ))
+    }
+
+    fn poll_until_settled(supervisor: &mut Supervisor) -> Vec<SupervisorEvent> {
+        let mut events = Vec::new();
+        for _ in 0..500 {
+            events.extend(supervisor.poll(Instant::now()));
+            if !supervisor.is_active() {
+                break;
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+        events
+    }
+
+    #[test]
+    fn test_clean_exit_is_not_restarted_on_failure_policy() {
+        let mut supervisor = Supervisor::new(state_dir("clean"), None);
+        supervisor
+            .add(ProcessSpec::new(
+                "data",
+                ProcessRole::Data,
+                "sh",
+                &["-c", "exit 0"],
+            ))
+            .unwrap();
+
+        let started = supervisor.start();
+        let events = poll_until_settled(&mut supervisor);
+
+        assert!(matches!(
+            started[0],
+            SupervisorEvent::Started { restarts: 0, .. }
+        ));
+        assert_eq!(
+            events,
+            vec![SupervisorEvent::Exited {
+                name: "data".to_string(),
+                exit_code: Some(0)
+            }]
+        );
+        assert_eq!(
+            supervisor.statuses()[0].1,
+            ProcessStatus::Exited { exit_code: Some(0) }
+        );
+    }
+
+    #[test]
+    fn test_crashed_process_is_restarted_until_max_restarts() {
+        let mut supervisor = Supervisor::new(state_dir("crash"), Some("tcp://127.0.0.1:5555"));
+        let mut spec = ProcessSpec::new(
+            "exec-binance",
+            ProcessRole::Execution,
+            "sh",
+            &["-c", "exit 3"],
+        );
+        spec.max_restarts = 2;
+        spec.backoff = Duration::from_millis(1);
+        supervisor.add(spec).unwrap();
+
+        supervisor.start();
+        let events = poll_until_settled(&mut supervisor);
+
+        let restarts: Vec<u32> = events
+            .iter()
+            .filter_map(|e| match e {
+                SupervisorEvent::Started { restarts, .. } => Some(*restarts),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(restarts, vec![1, 2]);
+        assert_eq!(
+            events.last().unwrap(),
+            &SupervisorEvent::GaveUp {
+                name: "exec-binance".to_string(),
+                restarts: 2
+            }
+        );
+    }
+
+    #[test]
+    fn test_child_receives_state_dir_and_restart_count() {
+        let dir = state_dir("env");
+        let mut supervisor = Supervisor::new(dir.clone(), Some("tcp://127.0.0.1:5555"));
+        supervisor.add(ProcessSpec::new(
+            "strategy",
+            ProcessRole::Strategy,
+            "sh",
+            &[
+                "-c",
+                "echo \"$NAUTILUS_RESTART_COUNT $NAUTILUS_BUS_ENDPOINT\" > \"$NAUTILUS_STATE_DIR/env.txt\"",
+            ],
+        )).unwrap();
+
+        supervisor.start();
+        poll_until_settled(&mut supervisor);
+
+        let contents = std::fs::read_to_string(dir.join("strategy").join("env.txt")).unwrap();
+        assert_eq!(contents.trim(), "0 tcp://127.0.0.1:5555");
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_spawn_failure_is_reported() {
+        let mut supervisor = Supervisor::new(state_dir("missing"), None);
+        supervisor
+            .add(ProcessSpec::new(
+                "data",
+                ProcessRole::Data,
+                "/nonexistent/nautilus-node",
+                &[],
+            ))
+            .unwrap();
+
+        let events = supervisor.start();
+
+        assert!(matches!(events[0], SupervisorEvent::SpawnFailed { .. }));
+        assert!(!supervisor.is_active());
+    }
+
+    #[test]
+    fn test_stop_kills_running_processes() {
+        let mut supervisor = Supervisor::new(state_dir("stop"), None);
+        supervisor
+            .add(ProcessSpec::new(
+                "data",
+                ProcessRole::Data,
+                "sleep",
+                &["30"],
+            ))
+            .unwrap();
+        supervisor
+            .add(ProcessSpec::new(
+                "exec",
+                ProcessRole::Execution,
+                "sleep",
+                &["30"],
+            ))
+            .unwrap();
+        supervisor.start();
+
+        supervisor.stop().unwrap();
+
+        assert!(!supervisor.is_active());
+        assert!(supervisor
+            .statuses()
+            .iter()
+            .all(|(_, s)| matches!(s, ProcessStatus::Exited { .. })));
+    }
+
+    #[test]
+    fn test_stop_cancels_restart_during_backoff() {
+        let mut supervisor = Supervisor::new(state_dir("stop-backoff"), None);
+        let mut spec = ProcessSpec::new("data", ProcessRole::Data, "sh", &["-c", "exit 3"]);
+        spec.backoff = Duration::from_secs(60);
+        supervisor.add(spec).unwrap();
+        supervisor.start();
+        for _ in 0..500 {
+            supervisor.poll(Instant::now());
+            if matches!(supervisor.statuses()[0].1, ProcessStatus::Backoff { .. }) {
+                break;
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+
+        supervisor.stop().unwrap();
+
+        assert!(!supervisor.is_active());
+        assert_eq!(
+            supervisor.statuses()[0].1,
+            ProcessStatus::Exited { exit_code: Some(3) }
+        );
+        assert!(supervisor
+            .poll(Instant::now() + Duration::from_secs(120))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_duplicate_process_names_are_rejected() {
+        let mut supervisor = Supervisor::new(state_dir("dupe"), None);
+        supervisor
+            .add(ProcessSpec::new("data", ProcessRole::Data, "true", &[]))
+            .unwrap();
+
+        let result = supervisor.add(ProcessSpec::new("data", ProcessRole::Data, "true", &[]));
+
+        assert_eq!(
+            result,
+            Err(SupervisorError::DuplicateName("data".to_string()))
+        );
+        assert_eq!(supervisor.statuses().len(), 1);
+    }
+
+    #[test]
+    fn test_path_like_process_names_are_rejected() {
+        let mut supervisor = Supervisor::new(state_dir("names"), None);
+
+        for name in ["", ".", "../data", "a/b", "a\\b", ".."] {
+            let result = supervisor.add(ProcessSpec::new(name, ProcessRole::Data, "true", &[]));
+
+            assert_eq!(result, Err(SupervisorError::InvalidName(name.to_string())));
+        }
+        assert!(supervisor.statuses().is_empty());
+    }
+}
@@ -0,0 +1,108 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::ffi::c_void;
+use std::mem::ManuallyDrop;
+use std::ptr::null_mut;
+
+/// Represents a Rust `Vec` passed across the C boundary, with its element
+/// type erased.
+///
+/// The owning Rust module provides the typed function to drop it, as only
+/// Rust can free the elements and buffer.
+#[repr(C)]
+#[derive(Debug)]
+pub struct CVec {
+    /// The pointer to the first element (null when empty and unallocated).
+    pub ptr: *mut c_void,
+    /// The number of elements.
+    pub len: usize,
+    /// The allocated capacity (elements).
+    pub cap: usize,
+}
+
+impl CVec {
+    pub fn empty() -> Self {
+        CVec {
+            ptr: null_mut(),
+            len: 0,
+            cap: 0,
+        }
+    }
+
+    /// Returns the elements as a slice.
+    ///
+    /// # Safety
+    ///
+    /// - The vec must have been created from a `Vec<T>`.
+    pub unsafe fn as_slice<T>(&self) -> &[T] {
+        if self.ptr.is_null() {
+            return &[];
+        }
+        std::slice::from_raw_parts(self.ptr as *const T, self.len)
+    }
+
+    /// Returns the vec, taking back ownership of the elements.
+    ///
+    /// # Safety
+    ///
+    /// - The vec must have been created from a `Vec<T>`.
+    pub unsafe fn into_vec<T>(self) -> Vec<T> {
+        if self.ptr.is_null() {
+            return Vec::new();
+        }
+        Vec::from_raw_parts(self.ptr as *mut T, self.len, self.cap)
+    }
+}
+
+impl<T> From<Vec<T>> for CVec {
+    fn from(data: Vec<T>) -> Self {
+        if data.capacity() == 0 {
+            return CVec::empty();
+        }
+        let mut data = ManuallyDrop::new(data);
+        CVec {
+            ptr: data.as_mut_ptr() as *mut c_void,
+            len: data.len(),
+            cap: data.capacity(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::CVec;
+
+    #[test]
+    fn test_cvec_round_trip() {
+        let cvec = CVec::from(vec![1u64, 2, 3]);
+
+        assert_eq!(cvec.len, 3);
+        assert_eq!(unsafe { cvec.as_slice::<u64>() }, &[1, 2, 3]);
+        assert_eq!(unsafe { cvec.into_vec::<u64>() }, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_cvec_empty() {
+        let cvec = CVec::from(Vec::<u64>::new());
+
+        assert!(cvec.ptr.is_null());
+        assert!(unsafe { cvec.as_slice::<u64>() }.is_empty());
+        assert!(unsafe { cvec.into_vec::<u64>() }.is_empty());
+    }
+}
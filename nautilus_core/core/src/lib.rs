@@ -13,6 +13,8 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+pub mod cvec;
+pub mod progress;
 pub mod string;
 pub mod time;
 pub mod uuid;
@@ -0,0 +1,322 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Progress reporting and cooperative cancellation for long running loops
+//! such as backtests.
+//!
+//! The running loop owns a `ProgressTracker`, updating it with the number of
+//! items processed so the callback is invoked at most once per interval. A
+//! `CancellationToken` may be cancelled from any thread (or from within the
+//! callback), with the loop checking it to stop cleanly.
+
+use std::ffi::c_void;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+/// Represents the progress of a run.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Progress {
+    /// The number of items processed.
+    pub processed: u64,
+    /// The total number of items to process.
+    pub total: u64,
+    /// The percentage of items processed, in the range [0, 100].
+    pub percent: f64,
+    /// The seconds elapsed since the start of the run.
+    pub elapsed_secs: f64,
+    /// The average number of items processed per second (zero until any time
+    /// has elapsed).
+    pub events_per_sec: f64,
+    /// The estimated seconds remaining (NaN until the rate is known).
+    pub eta_secs: f64,
+}
+
+impl Progress {
+    /// Returns the progress after `elapsed` time from the counts.
+    pub fn new(processed: u64, total: u64, elapsed: Duration) -> Self {
+        let processed = processed.min(total);
+        let elapsed_secs = elapsed.as_secs_f64();
+        let percent = match total {
+            0 => 100.0,
+            _ => processed as f64 / total as f64 * 100.0,
+        };
+        let events_per_sec = match elapsed_secs > 0.0 {
+            true => processed as f64 / elapsed_secs,
+            false => 0.0,
+        };
+        let eta_secs = match (processed == total, events_per_sec > 0.0) {
+            (true, _) => 0.0,
+            (false, true) => (total - processed) as f64 / events_per_sec,
+            (false, false) => f64::NAN,
+        };
+        Progress {
+            processed,
+            total,
+            percent,
+            elapsed_secs,
+            events_per_sec,
+            eta_secs,
+        }
+    }
+}
+
+/// The callback receiving progress reports, with the user data pointer given
+/// when the tracker was created.
+pub type ProgressCallback = extern "C" fn(Progress, *mut c_void);
+
+/// Provides throttled progress reports for a run of a known number of items.
+#[derive(Debug)]
+pub struct ProgressTracker {
+    total: u64,
+    interval: Duration,
+    started: Instant,
+    last_report: Instant,
+    callback: Option<ProgressCallback>,
+    user_data: *mut c_void,
+}
+
+impl ProgressTracker {
+    pub fn new(total: u64, interval: Duration) -> Self {
+        let now = Instant::now();
+        ProgressTracker {
+            total,
+            interval,
+            started: now,
+            last_report: now,
+            callback: None,
+            user_data: std::ptr::null_mut(),
+        }
+    }
+
+    /// Returns the progress if the interval has passed since the last report
+    /// (or the run is complete), resetting the interval.
+    pub fn update(&mut self, processed: u64) -> Option<Progress> {
+        let now = Instant::now();
+        if processed < self.total && now.duration_since(self.last_report) < self.interval {
+            return None;
+        }
+        self.last_report = now;
+        Some(Progress::new(processed, self.total, now - self.started))
+    }
+
+    /// Returns the final progress of the run, regardless of the interval.
+    pub fn finish(&mut self, processed: u64) -> Progress {
+        self.last_report = Instant::now();
+        Progress::new(processed, self.total, self.last_report - self.started)
+    }
+
+    fn notify(&self, progress: Progress) {
+        if let Some(callback) = self.callback {
+            callback(progress, self.user_data);
+        }
+    }
+}
+
+/// Provides a flag for cooperatively cancelling a run.
+#[derive(Debug, Default)]
+pub struct CancellationToken {
+    cancelled: AtomicBool,
+}
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requests cancellation of the run.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Clears any cancellation request, ready for the next run.
+    pub fn reset(&self) {
+        self.cancelled.store(false, Ordering::SeqCst);
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// C API
+////////////////////////////////////////////////////////////////////////////////
+/// Returns a new tracker for `total` items calling `callback` (if not null)
+/// with `user_data` at most once per `interval_ms`. The tracker must be
+/// passed to `progress_tracker_free` to free it.
+#[no_mangle]
+pub extern "C" fn progress_tracker_new(
+    total: u64,
+    interval_ms: u64,
+    callback: Option<extern "C" fn(Progress, *mut c_void)>,
+    user_data: *mut c_void,
+) -> *mut ProgressTracker {
+    let mut tracker = ProgressTracker::new(total, Duration::from_millis(interval_ms));
+    tracker.callback = callback;
+    tracker.user_data = user_data;
+    Box::into_raw(Box::new(tracker))
+}
+
+/// Updates the tracker with the number of items processed, calling the
+/// callback if a report is due. Returns 1 if the callback was due, otherwise 0.
+#[no_mangle]
+pub extern "C" fn progress_tracker_update(tracker: &mut ProgressTracker, processed: u64) -> u8 {
+    match tracker.update(processed) {
+        Some(progress) => {
+            tracker.notify(progress);
+            1
+        }
+        None => 0,
+    }
+}
+
+/// Calls the callback with the final progress of the run.
+#[no_mangle]
+pub extern "C" fn progress_tracker_finish(tracker: &mut ProgressTracker, processed: u64) {
+    let progress = tracker.finish(processed);
+    tracker.notify(progress);
+}
+
+/// Frees the tracker.
+///
+/// # Safety
+///
+/// - `tracker` must be null or have been returned by `progress_tracker_new`
+///   and not already freed.
+#[no_mangle]
+pub unsafe extern "C" fn progress_tracker_free(tracker: *mut ProgressTracker) {
+    if !tracker.is_null() {
+        drop(Box::from_raw(tracker)); // Memory freed here
+    }
+}
+
+/// Returns a new cancellation token. The token must be passed to
+/// `cancellation_token_free` to free it.
+#[no_mangle]
+pub extern "C" fn cancellation_token_new() -> *mut CancellationToken {
+    Box::into_raw(Box::new(CancellationToken::new()))
+}
+
+#[no_mangle]
+pub extern "C" fn cancellation_token_cancel(token: &CancellationToken) {
+    token.cancel();
+}
+
+#[no_mangle]
+pub extern "C" fn cancellation_token_is_cancelled(token: &CancellationToken) -> u8 {
+    u8::from(token.is_cancelled())
+}
+
+#[no_mangle]
+pub extern "C" fn cancellation_token_reset(token: &CancellationToken) {
+    token.reset();
+}
+
+/// Frees the token.
+///
+/// # Safety
+///
+/// - `token` must be null or have been returned by `cancellation_token_new`
+///   and not already freed.
+#[no_mangle]
+pub unsafe extern "C" fn cancellation_token_free(token: *mut CancellationToken) {
+    if !token.is_null() {
+        drop(Box::from_raw(token)); // Memory freed here
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_progress_new() {
+        let progress = Progress::new(250, 1_000, Duration::from_secs(5));
+
+        assert_eq!(progress.percent, 25.0);
+        assert_eq!(progress.elapsed_secs, 5.0);
+        assert_eq!(progress.events_per_sec, 50.0);
+        assert_eq!(progress.eta_secs, 15.0);
+    }
+
+    #[test]
+    fn test_progress_new_edge_cases() {
+        let started = Progress::new(0, 1_000, Duration::ZERO);
+        let empty = Progress::new(0, 0, Duration::ZERO);
+        let overrun = Progress::new(1_200, 1_000, Duration::from_secs(1));
+
+        assert_eq!(started.events_per_sec, 0.0);
+        assert!(started.eta_secs.is_nan());
+        assert_eq!(empty.percent, 100.0);
+        assert_eq!(empty.eta_secs, 0.0);
+        assert_eq!(overrun.processed, 1_000);
+        assert_eq!(overrun.percent, 100.0);
+    }
+
+    #[test]
+    fn test_progress_tracker_throttles_until_complete() {
+        let mut tracker = ProgressTracker::new(10, Duration::from_secs(3_600));
+
+        assert_eq!(tracker.update(1), None);
+        assert_eq!(tracker.update(10).map(|p| p.percent), Some(100.0));
+        assert_eq!(tracker.finish(10).processed, 10);
+        assert_eq!(
+            ProgressTracker::new(10, Duration::ZERO)
+                .update(5)
+                .map(|p| p.processed),
+            Some(5)
+        );
+    }
+
+    extern "C" fn record(progress: Progress, user_data: *mut c_void) {
+        let reports = unsafe { &mut *(user_data as *mut Vec<Progress>) };
+        reports.push(progress);
+    }
+
+    #[test]
+    fn test_progress_tracker_c_api() {
+        let mut reports: Vec<Progress> = Vec::new();
+        let user_data = &mut reports as *mut Vec<Progress> as *mut c_void;
+
+        let tracker = progress_tracker_new(4, 3_600_000, Some(record), user_data);
+        unsafe {
+            assert_eq!(progress_tracker_update(&mut *tracker, 1), 0);
+            assert_eq!(progress_tracker_update(&mut *tracker, 4), 1);
+            progress_tracker_finish(&mut *tracker, 4);
+            progress_tracker_free(tracker);
+        }
+
+        assert_eq!(reports.len(), 2);
+        assert_eq!(reports[1].percent, 100.0);
+    }
+
+    #[test]
+    fn test_cancellation_token_c_api() {
+        let token = cancellation_token_new();
+
+        unsafe {
+            assert_eq!(cancellation_token_is_cancelled(&*token), 0);
+            cancellation_token_cancel(&*token);
+            assert_eq!(cancellation_token_is_cancelled(&*token), 1);
+            cancellation_token_reset(&*token);
+            assert_eq!(cancellation_token_is_cancelled(&*token), 0);
+            cancellation_token_free(token);
+        }
+    }
+}
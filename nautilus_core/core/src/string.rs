@@ -15,6 +15,7 @@
 
 use pyo3::types::PyString;
 use pyo3::{ffi, FromPyPointer, IntoPyPointer, Py, Python};
+use std::num::IntErrorKind;
 
 /// Returns an owned string from a valid Python object pointer.
 ///
@@ -31,7 +32,7 @@ pub unsafe fn pystr_to_string(ptr: *mut ffi::PyObject) -> String {
 /// # Safety
 ///
 /// - Assumes that since the data is originating from Rust, the GIL does not need
-/// to be acquired.
+///   to be acquired.
 /// - Assumes you are immediately returning this pointer to Python.
 #[inline(always)]
 pub unsafe fn string_to_pystr(s: &str) -> *mut ffi::PyObject {
@@ -40,16 +41,28 @@ pub unsafe fn string_to_pystr(s: &str) -> *mut ffi::PyObject {
     pystr.into_ptr()
 }
 
-pub fn precision_from_str(s: &str) -> u8 {
-    let lower_s = s.to_lowercase();
-    // Handle scientific notation
-    if lower_s.contains("e-") {
-        return lower_s.split("e-").last().unwrap().parse::<u8>().unwrap();
-    }
-    if !lower_s.contains('.') {
-        return 0;
-    }
-    return lower_s.split('.').last().unwrap().len() as u8;
+/// Returns the decimal precision inferred from the string, being the number of
+/// digits after the decimal point less any exponent (e.g. "1.25" and "1.25e-1"
+/// have precisions 2 and 3, while "2.5E3" has precision 0).
+///
+/// Returns `None` if the exponent is not an optionally signed integer.
+pub fn precision_from_str(s: &str) -> Option<u8> {
+    let (mantissa, exponent) = match s.find(['e', 'E']) {
+        Some(index) => {
+            let exponent = match s[index + 1..].parse::<i64>() {
+                Ok(exponent) => exponent,
+                Err(e) => match e.kind() {
+                    IntErrorKind::PosOverflow => i64::MAX,
+                    IntErrorKind::NegOverflow => i64::MIN,
+                    _ => return None,
+                },
+            };
+            (&s[..index], exponent)
+        }
+        None => (s, 0),
+    };
+    let decimals = mantissa.split_once('.').map_or(0, |(_, frac)| frac.len()) as i64;
+    Some(decimals.saturating_sub(exponent).clamp(0, u8::MAX as i64) as u8)
 }
 
 #[cfg(test)]
@@ -58,16 +71,32 @@ mod tests {
 
     #[test]
     fn test_precision_from_str() {
-        assert_eq!(precision_from_str(""), 0);
-        assert_eq!(precision_from_str("0"), 0);
-        assert_eq!(precision_from_str("1"), 0);
-        assert_eq!(precision_from_str("1.0"), 1);
-        assert_eq!(precision_from_str("2.1"), 1);
-        assert_eq!(precision_from_str("2.204622"), 6);
-        assert_eq!(precision_from_str("0.000000001"), 9);
-        assert_eq!(precision_from_str("1e-8"), 8);
-        assert_eq!(precision_from_str("2e-9"), 9);
-        assert_eq!(precision_from_str("1e8"), 0);
-        assert_eq!(precision_from_str("2e8"), 0);
+        assert_eq!(precision_from_str(""), Some(0));
+        assert_eq!(precision_from_str("0"), Some(0));
+        assert_eq!(precision_from_str("1"), Some(0));
+        assert_eq!(precision_from_str("1.0"), Some(1));
+        assert_eq!(precision_from_str("2.1"), Some(1));
+        assert_eq!(precision_from_str("2.204622"), Some(6));
+        assert_eq!(precision_from_str("0.000000001"), Some(9));
+        assert_eq!(precision_from_str("1e-8"), Some(8));
+        assert_eq!(precision_from_str("2e-9"), Some(9));
+        assert_eq!(precision_from_str("1e8"), Some(0));
+        assert_eq!(precision_from_str("2e8"), Some(0));
+        assert_eq!(precision_from_str("1E-8"), Some(8));
+        assert_eq!(precision_from_str("1.5e-8"), Some(9));
+        assert_eq!(precision_from_str("2.5E3"), Some(0));
+        assert_eq!(precision_from_str("2.125e+1"), Some(2));
+        assert_eq!(precision_from_str("1.25e2"), Some(0));
+        assert_eq!(precision_from_str("1e-99999999999999999999"), Some(255));
+        assert_eq!(precision_from_str("1.5e99999999999999999999"), Some(0));
+    }
+
+    #[test]
+    fn test_precision_from_str_with_malformed_exponent() {
+        assert_eq!(precision_from_str("1e"), None);
+        assert_eq!(precision_from_str("1e+"), None);
+        assert_eq!(precision_from_str("1.5e-x"), None);
+        assert_eq!(precision_from_str("1e 5"), None);
+        assert_eq!(precision_from_str("1e1_0"), None);
     }
 }
@@ -66,6 +66,27 @@ pub extern "C" fn unix_timestamp_ns() -> i64 {
         .as_nanos() as i64
 }
 
+/// Returns the UTC calendar date (year, month, day) for the given UNIX nanoseconds.
+pub fn unix_nanos_to_ymd(unix_nanos: u64) -> (i64, u32, u32) {
+    // Civil from days algorithm (Howard Hinnant)
+    let days = (unix_nanos / 86_400_000_000_000) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let doe = days.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// Returns the UTC date tag in the "YYYYMMDD" format for the given UNIX nanoseconds.
+pub fn unix_nanos_to_date_tag(unix_nanos: u64) -> String {
+    let (year, month, day) = unix_nanos_to_ymd(unix_nanos);
+    format!("{}{:02}{:02}", year, month, day)
+}
+
 // Temporary dummy function to make cbindgen generate a header
 #[no_mangle]
 pub extern "C" fn dummy_timestamp(ts: Timestamp) -> Timestamp {
@@ -102,4 +123,29 @@ mod tests {
         let result = time::unix_timestamp_ns();
         assert!(result > 1610000000000000)
     }
+
+    #[test]
+    fn test_unix_nanos_to_ymd() {
+        assert_eq!(time::unix_nanos_to_ymd(0), (1970, 1, 1));
+        assert_eq!(
+            time::unix_nanos_to_ymd(951_782_400_000_000_000),
+            (2000, 2, 29)
+        );
+        assert_eq!(
+            time::unix_nanos_to_ymd(1_609_459_199_999_999_999),
+            (2020, 12, 31)
+        );
+        assert_eq!(
+            time::unix_nanos_to_ymd(1_609_459_200_000_000_000),
+            (2021, 1, 1)
+        );
+    }
+
+    #[test]
+    fn test_unix_nanos_to_date_tag() {
+        assert_eq!(
+            time::unix_nanos_to_date_tag(1_597_400_000_000_000_000),
+            "20200814"
+        );
+    }
 }
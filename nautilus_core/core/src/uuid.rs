@@ -91,7 +91,7 @@ pub unsafe extern "C" fn uuid4_from_pystr(ptr: *mut ffi::PyObject) -> UUID4 {
 /// # Safety
 ///
 /// - Assumes that since the data is originating from Rust, the GIL does not need
-/// to be acquired.
+///   to be acquired.
 /// - Assumes you are immediately returning this pointer to Python.
 #[no_mangle]
 pub unsafe extern "C" fn uuid4_to_pystr(uuid: &UUID4) -> *mut ffi::PyObject {
@@ -1,5 +1,5 @@
 use iai::black_box;
-use nautilus_model::types::fixed::{f64_to_fixed_i64, fixed_i64_to_f64};
+use nautilus_model::types::fixed::f64_to_fixed_i64;
 
 fn iai_fixed_precision_benchmark() -> i64 {
     f64_to_fixed_i64(black_box(-0.000000001), black_box(9))
@@ -19,6 +19,23 @@ use std::env;
 use std::path::PathBuf;
 
 fn main() {
+    println!("cargo:rerun-if-env-changed=NAUTILUS_GENERATE_HEADERS");
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+    println!("cargo:rerun-if-changed=cbindgen_cython.toml");
+    println!("cargo:rerun-if-changed=src");
+
+    // The headers are committed, and only regenerated on request since
+    // expanding the identifier C API macros requires a nightly toolchain, e.g.
+    // `NAUTILUS_GENERATE_HEADERS=1 cargo +nightly build -p nautilus_model`
+    if env::var_os("NAUTILUS_GENERATE_HEADERS").is_none() {
+        return;
+    }
+    // Expanding the crate for the headers runs this script again
+    if env::var("_CBINDGEN_IS_RUNNING").is_ok() {
+        return;
+    }
+
     let crate_dir = PathBuf::from(
         env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR env var is not defined"),
     );
@@ -0,0 +1,307 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Candle pattern detection.
+//!
+//! Scans a series of candles (oldest first) for engulfing, doji, inside bar
+//! and N-bar breakout patterns, as building blocks for signal strategies.
+//! Each detection refers to the candle completing the pattern.
+
+use nautilus_core::cvec::CVec;
+
+use crate::types::price::Price;
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum CandlePattern {
+    /// A bullish candle whose body engulfs the body of a prior bearish candle.
+    BullishEngulfing = 1,
+    /// A bearish candle whose body engulfs the body of a prior bullish candle.
+    BearishEngulfing = 2,
+    /// A candle whose body is small relative to its range.
+    Doji = 3,
+    /// A candle whose range is within the range of the prior candle.
+    InsideBar = 4,
+    /// A candle closing above the highs of the prior N candles.
+    BreakoutHigh = 5,
+    /// A candle closing below the lows of the prior N candles.
+    BreakoutLow = 6,
+}
+
+/// Represents the prices of a bar for pattern detection.
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Candle {
+    pub open: Price,
+    pub high: Price,
+    pub low: Price,
+    pub close: Price,
+    /// The UNIX timestamp (nanoseconds) when the candle closed.
+    pub ts_event: i64,
+}
+
+impl Candle {
+    fn is_bullish(&self) -> bool {
+        self.close.raw > self.open.raw
+    }
+
+    fn is_bearish(&self) -> bool {
+        self.close.raw < self.open.raw
+    }
+
+    fn body(&self) -> u64 {
+        self.close.raw.abs_diff(self.open.raw)
+    }
+
+    fn range(&self) -> u64 {
+        self.high.raw.abs_diff(self.low.raw)
+    }
+}
+
+/// Represents the parameters of pattern detection.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PatternParams {
+    /// The maximum ratio of body to range for a doji, in the range [0, 1].
+    pub doji_body_ratio: f64,
+    /// The number of prior candles for a breakout (zero disables breakouts).
+    pub breakout_period: u32,
+}
+
+impl Default for PatternParams {
+    fn default() -> Self {
+        PatternParams {
+            doji_body_ratio: 0.1,
+            breakout_period: 20,
+        }
+    }
+}
+
+/// Represents a detected pattern.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct PatternDetection {
+    pub pattern: CandlePattern,
+    /// The index of the candle completing the pattern.
+    pub index: u64,
+    /// The UNIX timestamp (nanoseconds) of the candle completing the pattern.
+    pub ts_event: i64,
+}
+
+pub fn is_bullish_engulfing(prior: &Candle, candle: &Candle) -> bool {
+    prior.is_bearish()
+        && candle.is_bullish()
+        && candle.open.raw <= prior.close.raw
+        && candle.close.raw >= prior.open.raw
+        && candle.body() > prior.body()
+}
+
+pub fn is_bearish_engulfing(prior: &Candle, candle: &Candle) -> bool {
+    prior.is_bullish()
+        && candle.is_bearish()
+        && candle.open.raw >= prior.close.raw
+        && candle.close.raw <= prior.open.raw
+        && candle.body() > prior.body()
+}
+
+pub fn is_doji(candle: &Candle, body_ratio: f64) -> bool {
+    candle.body() as f64 <= candle.range() as f64 * body_ratio
+}
+
+pub fn is_inside_bar(prior: &Candle, candle: &Candle) -> bool {
+    candle.high.raw <= prior.high.raw
+        && candle.low.raw >= prior.low.raw
+        && candle.range() < prior.range()
+}
+
+/// Returns the breakout pattern of the candle against the prior candles (if
+/// any).
+pub fn breakout(priors: &[Candle], candle: &Candle) -> Option<CandlePattern> {
+    let high = priors.iter().map(|c| c.high.raw).max()?;
+    let low = priors.iter().map(|c| c.low.raw).min()?;
+    if candle.close.raw > high {
+        Some(CandlePattern::BreakoutHigh)
+    } else if candle.close.raw < low {
+        Some(CandlePattern::BreakoutLow)
+    } else {
+        None
+    }
+}
+
+/// Returns the patterns detected in the candles (oldest first), ordered by
+/// the index of the completing candle.
+pub fn detect_patterns(candles: &[Candle], params: &PatternParams) -> Vec<PatternDetection> {
+    let period = params.breakout_period as usize;
+    let mut detections = Vec::new();
+    for (i, candle) in candles.iter().enumerate() {
+        let mut detect = |pattern| {
+            detections.push(PatternDetection {
+                pattern,
+                index: i as u64,
+                ts_event: candle.ts_event,
+            })
+        };
+        if is_doji(candle, params.doji_body_ratio) {
+            detect(CandlePattern::Doji);
+        }
+        if i == 0 {
+            continue;
+        }
+        let prior = &candles[i - 1];
+        if is_bullish_engulfing(prior, candle) {
+            detect(CandlePattern::BullishEngulfing);
+        } else if is_bearish_engulfing(prior, candle) {
+            detect(CandlePattern::BearishEngulfing);
+        }
+        if is_inside_bar(prior, candle) {
+            detect(CandlePattern::InsideBar);
+        }
+        if period > 0 && i >= period {
+            if let Some(pattern) = breakout(&candles[i - period..i], candle) {
+                detect(pattern);
+            }
+        }
+    }
+    detections
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// C API
+////////////////////////////////////////////////////////////////////////////////
+/// Returns a vec of the `PatternDetection`s in the array of `len` candles
+/// (oldest first). The vec must be passed to `pattern_detections_drop` to
+/// free it.
+///
+/// # Safety
+///
+/// - `candles` must point to `len` valid candles (or be null if `len` is zero).
+#[no_mangle]
+pub unsafe extern "C" fn candle_patterns_detect(
+    candles: *const Candle,
+    len: usize,
+    params: &PatternParams,
+) -> CVec {
+    if candles.is_null() || len == 0 {
+        return CVec::empty();
+    }
+    let candles = std::slice::from_raw_parts(candles, len);
+    detect_patterns(candles, params).into()
+}
+
+/// Drops a vec of detections returned by `candle_patterns_detect`.
+///
+/// # Safety
+///
+/// - `detections` must have been returned by `candle_patterns_detect`.
+#[no_mangle]
+pub unsafe extern "C" fn pattern_detections_drop(detections: CVec) {
+    drop(detections.into_vec::<PatternDetection>()); // Memory freed here
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candle(open: f64, high: f64, low: f64, close: f64, ts_event: i64) -> Candle {
+        Candle {
+            open: Price::new(open, 2),
+            high: Price::new(high, 2),
+            low: Price::new(low, 2),
+            close: Price::new(close, 2),
+            ts_event,
+        }
+    }
+
+    #[test]
+    fn test_engulfing() {
+        let bearish = candle(10.0, 10.2, 9.4, 9.5, 1);
+        let bullish = candle(9.4, 10.4, 9.3, 10.3, 2);
+
+        assert!(is_bullish_engulfing(&bearish, &bullish));
+        assert!(!is_bearish_engulfing(&bearish, &bullish));
+        assert!(is_bearish_engulfing(
+            &candle(9.5, 10.1, 9.4, 10.0, 1),
+            &candle(10.1, 10.2, 9.3, 9.4, 2)
+        ));
+    }
+
+    #[test]
+    fn test_doji_and_inside_bar() {
+        let prior = candle(10.0, 11.0, 9.0, 10.5, 1);
+
+        assert!(is_doji(&candle(10.0, 10.5, 9.5, 10.05, 2), 0.1));
+        assert!(!is_doji(&prior, 0.1));
+        assert!(is_inside_bar(&prior, &candle(10.0, 10.8, 9.2, 10.1, 2)));
+        assert!(!is_inside_bar(&prior, &candle(10.0, 11.1, 9.2, 10.1, 2)));
+    }
+
+    #[test]
+    fn test_detect_patterns_with_breakouts() {
+        let candles = vec![
+            candle(10.0, 10.5, 9.5, 10.2, 1),
+            candle(10.2, 10.6, 9.8, 10.4, 2),
+            candle(10.4, 11.0, 10.3, 10.9, 3), // Closes above the prior highs
+            candle(10.9, 10.95, 10.35, 10.5, 4), // Inside bar
+        ];
+        let params = PatternParams {
+            doji_body_ratio: 0.0,
+            breakout_period: 2,
+        };
+
+        let detections = detect_patterns(&candles, &params);
+
+        assert_eq!(
+            detections,
+            vec![
+                PatternDetection {
+                    pattern: CandlePattern::BreakoutHigh,
+                    index: 2,
+                    ts_event: 3,
+                },
+                PatternDetection {
+                    pattern: CandlePattern::InsideBar,
+                    index: 3,
+                    ts_event: 4,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_candle_patterns_detect_c_api() {
+        let candles = [
+            candle(10.0, 10.2, 9.4, 9.5, 1),
+            candle(9.4, 10.4, 9.3, 10.3, 2),
+        ];
+
+        unsafe {
+            let detections =
+                candle_patterns_detect(candles.as_ptr(), candles.len(), &Default::default());
+            assert_eq!(detections.len, 1);
+            assert_eq!(
+                detections.as_slice::<PatternDetection>()[0].pattern,
+                CandlePattern::BullishEngulfing
+            );
+            pattern_detections_drop(detections);
+
+            let empty = candle_patterns_detect(std::ptr::null(), 0, &Default::default());
+            assert_eq!(empty.len, 0);
+            pattern_detections_drop(empty);
+        }
+    }
+}
@@ -0,0 +1,332 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Percentage of volume (POV) execution.
+//!
+//! The algorithm targets executing `participation_rate` of the traded volume
+//! observed for the instrument (including its own fills) since it started.
+//! A child order is released whenever the shortfall against the target,
+//! less the quantity already working, reaches the minimum clip, and is
+//! capped at the maximum clip and the remaining quantity.
+//!
+//! At the deadline the algorithm either releases all of the remaining
+//! quantity as a final child order, or expires leaving it unexecuted.
+
+use crate::enums::OrderSide;
+use crate::identifiers::exec_algorithm_id::ExecAlgorithmId;
+use crate::types::fixed::FIXED_PRECISION;
+use crate::types::quantity::Quantity;
+
+/// The ID of the POV execution algorithm.
+pub const POV_ALGORITHM_ID: &str = "POV";
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum PovStatus {
+    /// Releasing child orders against observed volume.
+    Running = 1,
+    /// The full quantity has been released.
+    Completed = 2,
+    /// The deadline passed with quantity unreleased.
+    Expired = 3,
+}
+
+/// Represents the parameters of a POV execution.
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PovParams {
+    pub side: OrderSide,
+    pub quantity: Quantity,
+    /// The target fraction of traded volume, in the range (0, 1].
+    pub participation_rate: f64,
+    pub min_clip: Quantity,
+    pub max_clip: Quantity,
+    /// The UNIX timestamp (nanoseconds) by which to complete.
+    pub deadline_ns: i64,
+    /// If the remaining quantity is released at the deadline (non-zero),
+    /// otherwise the execution expires.
+    pub complete_on_deadline: u8,
+}
+
+/// Provides the child order pacing of a POV execution.
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PovAlgorithm {
+    pub exec_algorithm_id: ExecAlgorithmId,
+    pub params: PovParams,
+    pub status: PovStatus,
+    /// The traded volume observed since the execution started.
+    pub market_volume: Quantity,
+    /// The quantity released in child orders not yet filled or canceled.
+    pub working: Quantity,
+    pub filled: Quantity,
+}
+
+impl PovAlgorithm {
+    pub fn new(params: PovParams) -> Self {
+        assert!(
+            params.participation_rate > 0.0 && params.participation_rate <= 1.0,
+            "`participation_rate` was not in range (0, 1], was {}",
+            params.participation_rate
+        );
+        assert!(
+            params.min_clip.raw <= params.max_clip.raw && params.max_clip.raw > 0,
+            "`min_clip` was greater than `max_clip`, or `max_clip` was zero"
+        );
+        let precision = params.quantity.precision;
+        PovAlgorithm {
+            exec_algorithm_id: ExecAlgorithmId::new(POV_ALGORITHM_ID),
+            params,
+            status: PovStatus::Running,
+            market_volume: Quantity::from_raw(0, precision),
+            working: Quantity::from_raw(0, precision),
+            filled: Quantity::from_raw(0, precision),
+        }
+    }
+
+    /// Returns the quantity not yet released in child orders.
+    pub fn unreleased(&self) -> Quantity {
+        Quantity::from_raw(
+            self.params.quantity.raw - self.working.raw - self.filled.raw,
+            self.params.quantity.precision,
+        )
+    }
+
+    /// Records a trade for the instrument, returning the child order quantity
+    /// to release (if any).
+    pub fn on_trade(&mut self, size: &Quantity, ts_event: i64) -> Option<Quantity> {
+        if let Some(child) = self.on_time(ts_event) {
+            return Some(child);
+        }
+        if self.status != PovStatus::Running {
+            return None;
+        }
+        self.market_volume.raw += size.raw;
+
+        let target = (self.market_volume.raw as f64 * self.params.participation_rate) as u64;
+        let shortfall = target.saturating_sub(self.filled.raw + self.working.raw);
+        let clip = shortfall
+            .min(self.params.max_clip.raw)
+            .min(self.unreleased().raw);
+        let clip = clip - clip % self.lot_raw();
+        if clip == 0 || (clip < self.params.min_clip.raw && clip < self.unreleased().raw) {
+            return None;
+        }
+        Some(self.release(clip))
+    }
+
+    /// Checks the deadline at `ts`, returning the final child order quantity
+    /// to release (if completing on the deadline).
+    pub fn on_time(&mut self, ts: i64) -> Option<Quantity> {
+        if self.status != PovStatus::Running || ts < self.params.deadline_ns {
+            return None;
+        }
+        if self.params.complete_on_deadline == 0 {
+            self.status = PovStatus::Expired;
+            return None;
+        }
+        Some(self.release(self.unreleased().raw))
+    }
+
+    /// Records a fill of a child order.
+    pub fn on_fill(&mut self, last_qty: &Quantity) {
+        let qty = last_qty.raw.min(self.working.raw);
+        self.working.raw -= qty;
+        self.filled.raw += qty;
+    }
+
+    /// Records the cancellation of a child order with `leaves_qty` unfilled,
+    /// returning it to the unreleased quantity.
+    pub fn on_cancel(&mut self, leaves_qty: &Quantity) {
+        self.working.raw -= leaves_qty.raw.min(self.working.raw);
+        if self.status == PovStatus::Completed && self.unreleased().raw > 0 {
+            self.status = PovStatus::Running;
+        }
+    }
+
+    fn release(&mut self, clip: u64) -> Quantity {
+        self.working.raw += clip;
+        if self.unreleased().raw == 0 {
+            self.status = PovStatus::Completed;
+        }
+        Quantity::from_raw(clip, self.params.quantity.precision)
+    }
+
+    fn lot_raw(&self) -> u64 {
+        10_u64.pow((FIXED_PRECISION - self.params.quantity.precision) as u32)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// C API
+////////////////////////////////////////////////////////////////////////////////
+#[no_mangle]
+pub extern "C" fn pov_algorithm_new(params: PovParams) -> PovAlgorithm {
+    PovAlgorithm::new(params)
+}
+
+#[no_mangle]
+pub extern "C" fn pov_algorithm_free(algorithm: PovAlgorithm) {
+    drop(algorithm); // Memory freed here
+}
+
+/// Returns the child order quantity to release for the trade (zero if none).
+#[no_mangle]
+pub extern "C" fn pov_algorithm_on_trade(
+    algorithm: &mut PovAlgorithm,
+    size: &Quantity,
+    ts_event: i64,
+) -> Quantity {
+    algorithm
+        .on_trade(size, ts_event)
+        .unwrap_or_else(|| Quantity::from_raw(0, size.precision))
+}
+
+/// Returns the final child order quantity to release at `ts` (zero if none).
+#[no_mangle]
+pub extern "C" fn pov_algorithm_on_time(algorithm: &mut PovAlgorithm, ts: i64) -> Quantity {
+    let precision = algorithm.params.quantity.precision;
+    algorithm
+        .on_time(ts)
+        .unwrap_or_else(|| Quantity::from_raw(0, precision))
+}
+
+#[no_mangle]
+pub extern "C" fn pov_algorithm_on_fill(algorithm: &mut PovAlgorithm, last_qty: &Quantity) {
+    algorithm.on_fill(last_qty);
+}
+
+#[no_mangle]
+pub extern "C" fn pov_algorithm_on_cancel(algorithm: &mut PovAlgorithm, leaves_qty: &Quantity) {
+    algorithm.on_cancel(leaves_qty);
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params() -> PovParams {
+        PovParams {
+            side: OrderSide::Buy,
+            quantity: Quantity::new(1000.0, 0),
+            participation_rate: 0.1,
+            min_clip: Quantity::new(50.0, 0),
+            max_clip: Quantity::new(200.0, 0),
+            deadline_ns: 1_000,
+            complete_on_deadline: 1,
+        }
+    }
+
+    #[test]
+    fn test_releases_child_at_min_clip() {
+        let mut pov = PovAlgorithm::new(params());
+
+        assert_eq!(pov.on_trade(&Quantity::new(300.0, 0), 1), None);
+        assert_eq!(
+            pov.on_trade(&Quantity::new(250.0, 0), 2),
+            Some(Quantity::new(55.0, 0))
+        );
+        assert_eq!(pov.working, Quantity::new(55.0, 0));
+        assert_eq!(pov.exec_algorithm_id.as_str(), "POV");
+    }
+
+    #[test]
+    fn test_caps_child_at_max_clip_and_counts_working() {
+        let mut pov = PovAlgorithm::new(params());
+
+        assert_eq!(
+            pov.on_trade(&Quantity::new(5000.0, 0), 1),
+            Some(Quantity::new(200.0, 0))
+        );
+        assert_eq!(
+            pov.on_trade(&Quantity::new(1000.0, 0), 2),
+            Some(Quantity::new(200.0, 0))
+        );
+        pov.on_fill(&Quantity::new(400.0, 0));
+
+        assert_eq!(pov.filled, Quantity::new(400.0, 0));
+        assert_eq!(pov.unreleased(), Quantity::new(600.0, 0));
+    }
+
+    #[test]
+    fn test_completes_and_reopens_on_cancel() {
+        let mut pov = PovAlgorithm::new(PovParams {
+            max_clip: Quantity::new(1000.0, 0),
+            ..params()
+        });
+
+        assert_eq!(
+            pov.on_trade(&Quantity::new(20_000.0, 0), 1),
+            Some(Quantity::new(1000.0, 0))
+        );
+        assert_eq!(pov.status, PovStatus::Completed);
+
+        pov.on_fill(&Quantity::new(700.0, 0));
+        pov.on_cancel(&Quantity::new(300.0, 0));
+
+        assert_eq!(pov.status, PovStatus::Running);
+        assert_eq!(
+            pov.on_trade(&Quantity::new(1.0, 0), 2),
+            Some(Quantity::new(300.0, 0))
+        );
+    }
+
+    #[test]
+    fn test_deadline_releases_remaining() {
+        let mut pov = PovAlgorithm::new(params());
+        pov.on_trade(&Quantity::new(1000.0, 0), 1);
+
+        assert_eq!(pov_algorithm_on_time(&mut pov, 999), Quantity::new(0.0, 0));
+        assert_eq!(
+            pov_algorithm_on_time(&mut pov, 1_000),
+            Quantity::new(900.0, 0)
+        );
+        assert_eq!(pov.status, PovStatus::Completed);
+    }
+
+    #[test]
+    fn test_deadline_expires() {
+        let mut pov = PovAlgorithm::new(PovParams {
+            complete_on_deadline: 0,
+            ..params()
+        });
+
+        assert_eq!(pov.on_trade(&Quantity::new(10_000.0, 0), 1_000), None);
+        assert_eq!(pov.status, PovStatus::Expired);
+    }
+
+    #[test]
+    fn test_child_rounded_down_to_precision() {
+        let mut pov = PovAlgorithm::new(PovParams {
+            quantity: Quantity::new(10.0, 1),
+            min_clip: Quantity::new(0.1, 1),
+            max_clip: Quantity::new(5.0, 1),
+            participation_rate: 0.3,
+            ..params()
+        });
+
+        assert_eq!(
+            pov.on_trade(&Quantity::new(1.0, 1), 1),
+            Some(Quantity::new(0.3, 1))
+        );
+        assert_eq!(
+            pov.on_trade(&Quantity::new(0.5, 1), 2),
+            Some(Quantity::new(0.1, 1))
+        );
+    }
+}
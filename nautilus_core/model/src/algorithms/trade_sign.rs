@@ -0,0 +1,496 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Trade sign classification.
+//!
+//! For trade feeds without the aggressor side, infers the direction of each
+//! trade (oldest first) from the prices and quotes:
+//!
+//! - Tick rule: a buy if the price is above the last different price, and a
+//!   sell if below.
+//! - Lee-Ready: a buy if the price is above the midpoint of the prevailing
+//!   quote, a sell if below, otherwise by the tick rule.
+//! - Bulk volume classification (BVC): rather than signing each trade,
+//!   splits the volume of each time bucket into buy and sell volume by the
+//!   standardized price change over the bucket.
+//!
+//! A reported aggressor side is always kept, and each sign records whether
+//! it was inferred.
+
+use std::cmp::Ordering;
+use std::f64::consts::SQRT_2;
+
+use nautilus_core::cvec::CVec;
+
+use crate::enums::AggressorSide;
+use crate::types::price::Price;
+use crate::types::quantity::Quantity;
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum TradeSignMethod {
+    TickRule = 1,
+    LeeReady = 2,
+}
+
+/// Represents a trade to classify.
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SignTrade {
+    pub price: Price,
+    pub size: Quantity,
+    /// The reported aggressor side, `Unknown` if not reported.
+    pub aggressor_side: AggressorSide,
+    /// The UNIX timestamp (nanoseconds) of the trade.
+    pub ts_event: i64,
+}
+
+/// Represents a quote for the Lee-Ready classification.
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SignQuote {
+    pub bid: Price,
+    pub ask: Price,
+    /// The UNIX timestamp (nanoseconds) of the quote.
+    pub ts_event: i64,
+}
+
+/// Represents the sign of a trade.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct TradeSign {
+    pub aggressor_side: AggressorSide,
+    /// If the side was inferred (non-zero) rather than reported.
+    pub inferred: u8,
+}
+
+/// Represents the classified volume of a time bucket.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct VolumeSplit {
+    /// The UNIX timestamp (nanoseconds) of the start of the bucket.
+    pub ts_event: i64,
+    pub buy_volume: f64,
+    pub sell_volume: f64,
+}
+
+fn side_from(ordering: Ordering) -> AggressorSide {
+    match ordering {
+        Ordering::Greater => AggressorSide::Buy,
+        Ordering::Less => AggressorSide::Sell,
+        Ordering::Equal => AggressorSide::Unknown,
+    }
+}
+
+/// Represents the state of the tick rule over a series of prices.
+#[derive(Default)]
+struct TickRule {
+    last_price: Option<i64>,
+    last_side: Option<AggressorSide>,
+}
+
+impl TickRule {
+    /// Returns the tick rule side of the price, being the side of the last
+    /// price change on a zero tick (`Unknown` until the first change).
+    fn update(&mut self, price: &Price) -> AggressorSide {
+        if let Some(last_price) = self.last_price {
+            match side_from(price.raw.cmp(&last_price)) {
+                AggressorSide::Unknown => {}
+                side => self.last_side = Some(side),
+            }
+        }
+        self.last_price = Some(price.raw);
+        self.last_side.unwrap_or(AggressorSide::Unknown)
+    }
+}
+
+fn sign(trade: &SignTrade, inferred: AggressorSide) -> TradeSign {
+    match trade.aggressor_side {
+        AggressorSide::Unknown => TradeSign {
+            aggressor_side: inferred,
+            inferred: (inferred != AggressorSide::Unknown) as u8,
+        },
+        reported => TradeSign {
+            aggressor_side: reported,
+            inferred: 0,
+        },
+    }
+}
+
+/// Returns the signs of the trades by the tick rule.
+pub fn tick_rule(trades: &[SignTrade]) -> Vec<TradeSign> {
+    let mut rule = TickRule::default();
+    trades
+        .iter()
+        .map(|trade| sign(trade, rule.update(&trade.price)))
+        .collect()
+}
+
+/// Returns the signs of the trades by the Lee-Ready algorithm, against the
+/// quotes (oldest first) prevailing `quote_lag_ns` before each trade.
+///
+/// Trades at the midpoint, without a prevailing quote or with a crossed
+/// quote are signed by the tick rule.
+pub fn lee_ready(trades: &[SignTrade], quotes: &[SignQuote], quote_lag_ns: i64) -> Vec<TradeSign> {
+    let mut rule = TickRule::default();
+    let mut next_quote = 0;
+    let mut quote: Option<&SignQuote> = None;
+    trades
+        .iter()
+        .map(|trade| {
+            let cutoff = trade.ts_event.saturating_sub(quote_lag_ns);
+            while next_quote < quotes.len() && quotes[next_quote].ts_event <= cutoff {
+                quote = Some(&quotes[next_quote]);
+                next_quote += 1;
+            }
+            let tick_side = rule.update(&trade.price);
+            let quote_side = match quote {
+                Some(q) if q.bid.raw <= q.ask.raw => {
+                    // Compares with the midpoint without rounding
+                    let twice_mid = q.bid.raw as i128 + q.ask.raw as i128;
+                    side_from((2 * trade.price.raw as i128).cmp(&twice_mid))
+                }
+                _ => AggressorSide::Unknown,
+            };
+            match quote_side {
+                AggressorSide::Unknown => sign(trade, tick_side),
+                side => sign(trade, side),
+            }
+        })
+        .collect()
+}
+
+/// Returns the signs of the trades by the method.
+pub fn classify_trades(
+    trades: &[SignTrade],
+    quotes: &[SignQuote],
+    method: TradeSignMethod,
+    quote_lag_ns: i64,
+) -> Vec<TradeSign> {
+    match method {
+        TradeSignMethod::TickRule => tick_rule(trades),
+        TradeSignMethod::LeeReady => lee_ready(trades, quotes, quote_lag_ns),
+    }
+}
+
+/// Returns the standard normal cumulative distribution at `x`, using the
+/// Abramowitz and Stegun approximation of `erf` (error below 1.5e-7).
+fn normal_cdf(x: f64) -> f64 {
+    let z = x.abs() / SQRT_2;
+    let t = 1.0 / (1.0 + 0.327_591_1 * z);
+    let poly = t
+        * (0.254_829_592
+            + t * (-0.284_496_736
+                + t * (1.421_413_741 + t * (-1.453_152_027 + t * 1.061_405_429))));
+    let erf = 1.0 - poly * (-z * z).exp();
+    match x >= 0.0 {
+        true => 0.5 * (1.0 + erf),
+        false => 0.5 * (1.0 - erf),
+    }
+}
+
+/// Returns the buy and sell volume of the trades (oldest first) for each
+/// bucket of `interval_ns` with trades, by bulk volume classification.
+///
+/// The buy fraction of a bucket is the normal CDF of its price change (from
+/// the prior bucket close, or the first trade for the first bucket) over the
+/// standard deviation of the price changes of all buckets.
+pub fn bulk_volume_classify(trades: &[SignTrade], interval_ns: i64) -> Vec<VolumeSplit> {
+    if interval_ns <= 0 || trades.is_empty() {
+        return Vec::new();
+    }
+
+    // Bucket start, volume and close price change of each bucket
+    let mut buckets: Vec<(i64, f64, f64)> = Vec::new();
+    let mut prior_close = trades[0].price.as_f64();
+    for trade in trades {
+        let start = trade.ts_event - trade.ts_event.rem_euclid(interval_ns);
+        match buckets.last_mut() {
+            Some(bucket) if bucket.0 == start => bucket.1 += trade.size.as_f64(),
+            _ => {
+                if let Some(bucket) = buckets.last_mut() {
+                    prior_close += bucket.2;
+                }
+                buckets.push((start, trade.size.as_f64(), 0.0));
+            }
+        }
+        let bucket = buckets.last_mut().expect("bucket pushed");
+        bucket.2 = trade.price.as_f64() - prior_close;
+    }
+
+    let n = buckets.len() as f64;
+    let mean = buckets.iter().map(|b| b.2).sum::<f64>() / n;
+    let std = (buckets.iter().map(|b| (b.2 - mean).powi(2)).sum::<f64>() / n).sqrt();
+    buckets
+        .into_iter()
+        .map(|(ts_event, volume, change)| {
+            let buy_fraction = match std > 0.0 {
+                true => normal_cdf(change / std),
+                false => 0.5,
+            };
+            VolumeSplit {
+                ts_event,
+                buy_volume: volume * buy_fraction,
+                sell_volume: volume * (1.0 - buy_fraction),
+            }
+        })
+        .collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// C API
+////////////////////////////////////////////////////////////////////////////////
+/// Writes the sign of each of the `len` trades (oldest first) by the method
+/// to `out`, returning the number of trades with an inferred side.
+///
+/// # Safety
+///
+/// - `trades` must point to `len` valid trades (or be null if `len` is zero).
+/// - `quotes` must point to `quotes_len` valid quotes (or be null if
+///   `quotes_len` is zero).
+/// - `out` must point to a buffer for at least `len` signs.
+#[no_mangle]
+pub unsafe extern "C" fn trade_sign_classify(
+    trades: *const SignTrade,
+    len: usize,
+    quotes: *const SignQuote,
+    quotes_len: usize,
+    method: TradeSignMethod,
+    quote_lag_ns: i64,
+    out: *mut TradeSign,
+) -> usize {
+    if trades.is_null() || len == 0 {
+        return 0;
+    }
+    let trades = std::slice::from_raw_parts(trades, len);
+    let quotes = match quotes.is_null() || quotes_len == 0 {
+        true => &[],
+        false => std::slice::from_raw_parts(quotes, quotes_len),
+    };
+    let signs = classify_trades(trades, quotes, method, quote_lag_ns);
+    std::slice::from_raw_parts_mut(out, len).copy_from_slice(&signs);
+    signs.iter().filter(|sign| sign.inferred != 0).count()
+}
+
+/// Returns a vec of the `VolumeSplit`s of the array of `len` trades (oldest
+/// first) by bulk volume classification. The vec must be passed to
+/// `volume_splits_drop` to free it.
+///
+/// # Safety
+///
+/// - `trades` must point to `len` valid trades (or be null if `len` is zero).
+#[no_mangle]
+pub unsafe extern "C" fn trade_sign_bulk_volume(
+    trades: *const SignTrade,
+    len: usize,
+    interval_ns: i64,
+) -> CVec {
+    if trades.is_null() || len == 0 {
+        return CVec::empty();
+    }
+    let trades = std::slice::from_raw_parts(trades, len);
+    bulk_volume_classify(trades, interval_ns).into()
+}
+
+/// Drops a vec of volume splits returned by `trade_sign_bulk_volume`.
+///
+/// # Safety
+///
+/// - `splits` must have been returned by `trade_sign_bulk_volume`.
+#[no_mangle]
+pub unsafe extern "C" fn volume_splits_drop(splits: CVec) {
+    drop(splits.into_vec::<VolumeSplit>()); // Memory freed here
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trade(price: f64, aggressor_side: AggressorSide, ts_event: i64) -> SignTrade {
+        SignTrade {
+            price: Price::new(price, 2),
+            size: Quantity::new(100.0, 0),
+            aggressor_side,
+            ts_event,
+        }
+    }
+
+    fn quote(bid: f64, ask: f64, ts_event: i64) -> SignQuote {
+        SignQuote {
+            bid: Price::new(bid, 2),
+            ask: Price::new(ask, 2),
+            ts_event,
+        }
+    }
+
+    fn sides(signs: &[TradeSign]) -> Vec<AggressorSide> {
+        signs.iter().map(|s| s.aggressor_side).collect()
+    }
+
+    #[test]
+    fn test_tick_rule_uses_last_price_change() {
+        let trades: Vec<SignTrade> = [10.0, 10.0, 10.01, 10.01, 10.0, 10.0]
+            .iter()
+            .enumerate()
+            .map(|(i, p)| trade(*p, AggressorSide::Unknown, i as i64))
+            .collect();
+
+        let signs = tick_rule(&trades);
+
+        assert_eq!(
+            sides(&signs),
+            vec![
+                AggressorSide::Unknown,
+                AggressorSide::Unknown,
+                AggressorSide::Buy,
+                AggressorSide::Buy, // Zero uptick
+                AggressorSide::Sell,
+                AggressorSide::Sell, // Zero downtick
+            ]
+        );
+        assert_eq!(signs[0].inferred, 0);
+        assert!(signs[2..].iter().all(|s| s.inferred == 1));
+    }
+
+    #[test]
+    fn test_reported_sides_are_kept() {
+        let trades = vec![
+            trade(10.0, AggressorSide::Unknown, 1),
+            trade(10.01, AggressorSide::Sell, 2),
+        ];
+
+        let signs = tick_rule(&trades);
+
+        assert_eq!(
+            signs[1],
+            TradeSign {
+                aggressor_side: AggressorSide::Sell,
+                inferred: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn test_lee_ready_quote_rule_with_tick_rule_at_midpoint() {
+        let quotes = vec![quote(9.99, 10.01, 1), quote(10.00, 10.04, 5)];
+        let trades = vec![
+            trade(10.01, AggressorSide::Unknown, 2), // Above 10.00 mid
+            trade(9.99, AggressorSide::Unknown, 3),  // Below 10.00 mid
+            trade(10.00, AggressorSide::Unknown, 4), // At mid, downtick
+            trade(10.02, AggressorSide::Unknown, 6), // At 10.02 mid, uptick
+            trade(10.01, AggressorSide::Unknown, 7), // Below 10.02 mid
+        ];
+
+        let signs = lee_ready(&trades, &quotes, 0);
+
+        assert_eq!(
+            sides(&signs),
+            vec![
+                AggressorSide::Buy,
+                AggressorSide::Sell,
+                AggressorSide::Buy,
+                AggressorSide::Buy,
+                AggressorSide::Sell,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lee_ready_quote_lag_and_missing_quotes() {
+        let quotes = vec![quote(10.00, 10.02, 10), quote(10.10, 10.12, 20)];
+        let trades = vec![
+            trade(10.05, AggressorSide::Unknown, 5),  // No quote yet
+            trade(10.05, AggressorSide::Unknown, 21), // Prevailing 10.01 mid with lag
+        ];
+
+        let lagged = lee_ready(&trades, &quotes, 5);
+        let unlagged = lee_ready(&trades, &quotes, 0);
+
+        assert_eq!(lagged[0].aggressor_side, AggressorSide::Unknown);
+        assert_eq!(lagged[1].aggressor_side, AggressorSide::Buy);
+        assert_eq!(unlagged[1].aggressor_side, AggressorSide::Sell);
+    }
+
+    #[test]
+    fn test_bulk_volume_classify() {
+        let trades = vec![
+            trade(10.00, AggressorSide::Unknown, 0),
+            trade(10.10, AggressorSide::Unknown, 5), // Bucket 0 up 0.10
+            trade(10.00, AggressorSide::Unknown, 12), // Bucket 10 down 0.10
+            trade(10.00, AggressorSide::Unknown, 31), // Bucket 30 unchanged
+        ];
+
+        let splits = bulk_volume_classify(&trades, 10);
+
+        assert_eq!(splits.len(), 3);
+        assert_eq!(
+            splits.iter().map(|s| s.ts_event).collect::<Vec<_>>(),
+            vec![0, 10, 30]
+        );
+        assert_eq!(splits[0].buy_volume + splits[0].sell_volume, 200.0);
+        assert!(splits[0].buy_volume > 150.0);
+        assert!((splits[1].buy_volume - splits[0].sell_volume / 2.0).abs() < 1e-6);
+        assert!((splits[2].buy_volume - 50.0).abs() < 1e-6);
+        assert!(bulk_volume_classify(&trades, 0).is_empty());
+    }
+
+    #[test]
+    fn test_normal_cdf() {
+        assert!((normal_cdf(0.0) - 0.5).abs() < 1e-7);
+        assert!((normal_cdf(1.0) - 0.841_344_746).abs() < 1e-6);
+        assert!((normal_cdf(-1.96) - 0.024_997_895).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_trade_sign_c_api() {
+        let trades = [
+            trade(10.00, AggressorSide::Unknown, 1),
+            trade(10.01, AggressorSide::Unknown, 2),
+            trade(10.02, AggressorSide::Buy, 3),
+        ];
+        let mut out = [TradeSign {
+            aggressor_side: AggressorSide::Unknown,
+            inferred: 0,
+        }; 3];
+
+        let inferred = unsafe {
+            trade_sign_classify(
+                trades.as_ptr(),
+                trades.len(),
+                std::ptr::null(),
+                0,
+                TradeSignMethod::LeeReady,
+                0,
+                out.as_mut_ptr(),
+            )
+        };
+        let splits = unsafe { trade_sign_bulk_volume(trades.as_ptr(), trades.len(), 10) };
+
+        assert_eq!(inferred, 1);
+        assert_eq!(
+            sides(&out),
+            vec![
+                AggressorSide::Unknown,
+                AggressorSide::Buy,
+                AggressorSide::Buy
+            ]
+        );
+        assert_eq!(splits.len, 1);
+        unsafe { volume_splits_drop(splits) };
+    }
+}
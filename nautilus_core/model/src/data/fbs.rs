@@ -0,0 +1,649 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! FlatBuffers encoding of market data for fan-out, so downstream consumers
+//! can read fields in place without deserializing. Streams publish it with
+//! `MarketDataEncoding::FlatBuffers` (`nautilus_persistence`, `stream-fbs`).
+//!
+//! The schema is maintained in `fbs/market_data.fbs`, which the builders and
+//! views below must be kept in sync with. Each message type is a root table
+//! tagged with its own file identifier.
+
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result};
+
+use flatbuffers::{
+    buffer_has_identifier, FlatBufferBuilder, Follow, ForwardsUOffset, InvalidFlatbuffer, Table,
+    VOffsetT, Verifiable, Verifier,
+};
+use nautilus_core::time::Timestamp;
+
+use crate::data::tick::{QuoteTick, TradeTick};
+use crate::enums::{BookAction, OrderSide};
+use crate::identifiers::instrument_id::InstrumentId;
+use crate::identifiers::trade_id::TradeId;
+use crate::orderbook::aggregation::LevelDelta;
+use crate::types::price::Price;
+use crate::types::quantity::Quantity;
+
+/// The FlatBuffers schema of the market data messages.
+pub const MARKET_DATA_FBS: &str = include_str!("../../fbs/market_data.fbs");
+
+pub const QUOTE_TICK_FILE_ID: &str = "NQTK";
+pub const TRADE_TICK_FILE_ID: &str = "NTTK";
+pub const LEVEL_DELTA_FILE_ID: &str = "NLVD";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FlatBufferError {
+    /// The buffer was not the expected message type.
+    UnexpectedMessage,
+    /// The buffer failed verification.
+    Invalid(String),
+    /// An enum field held an unknown discriminant.
+    InvalidEnum(&'static str, u8),
+}
+
+impl Display for FlatBufferError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            FlatBufferError::UnexpectedMessage => {
+                write!(f, "FlatBuffer was not the expected message")
+            }
+            FlatBufferError::Invalid(reason) => write!(f, "FlatBuffer invalid, {}", reason),
+            FlatBufferError::InvalidEnum(field, value) => {
+                write!(f, "FlatBuffer field {} had invalid value {}", field, value)
+            }
+        }
+    }
+}
+
+impl Error for FlatBufferError {}
+
+impl From<InvalidFlatbuffer> for FlatBufferError {
+    fn from(e: InvalidFlatbuffer) -> Self {
+        FlatBufferError::Invalid(e.to_string())
+    }
+}
+
+/// Represents the type of an encoded market data message.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MarketDataKind {
+    QuoteTick,
+    TradeTick,
+    LevelDelta,
+}
+
+/// Returns the type of the encoded message, from its file identifier.
+pub fn market_data_kind(buf: &[u8]) -> Option<MarketDataKind> {
+    [
+        (QUOTE_TICK_FILE_ID, MarketDataKind::QuoteTick),
+        (TRADE_TICK_FILE_ID, MarketDataKind::TradeTick),
+        (LEVEL_DELTA_FILE_ID, MarketDataKind::LevelDelta),
+    ]
+    .into_iter()
+    .find(|(id, _)| buf.len() >= 8 && buffer_has_identifier(buf, id, false))
+    .map(|(_, kind)| kind)
+}
+
+const fn field(index: VOffsetT) -> VOffsetT {
+    4 + 2 * index
+}
+
+fn order_side(value: u8) -> std::result::Result<OrderSide, FlatBufferError> {
+    match value {
+        1 => Ok(OrderSide::Buy),
+        2 => Ok(OrderSide::Sell),
+        _ => Err(FlatBufferError::InvalidEnum("side", value)),
+    }
+}
+
+fn book_action(value: u8) -> std::result::Result<BookAction, FlatBufferError> {
+    match value {
+        1 => Ok(BookAction::Add),
+        2 => Ok(BookAction::Update),
+        3 => Ok(BookAction::Delete),
+        4 => Ok(BookAction::Clear),
+        _ => Err(FlatBufferError::InvalidEnum("action", value)),
+    }
+}
+
+fn view<'a, T>(buf: &'a [u8], file_id: &str) -> std::result::Result<T, FlatBufferError>
+where
+    T: Follow<'a, Inner = T> + Verifiable + 'a,
+{
+    if buf.len() < 8 || !buffer_has_identifier(buf, file_id, false) {
+        return Err(FlatBufferError::UnexpectedMessage);
+    }
+    Ok(flatbuffers::root::<T>(buf)?)
+}
+
+// Reads a field of a verified table, so the offsets are in bounds
+macro_rules! table_field {
+    ($tab:expr, str, $field:expr) => {
+        unsafe {
+            $tab.get::<ForwardsUOffset<&str>>($field, None)
+                .unwrap_or_default()
+        }
+    };
+    ($tab:expr, $t:ty, $field:expr) => {
+        unsafe { $tab.get::<$t>($field, Some(0)).unwrap_or_default() }
+    };
+}
+
+macro_rules! impl_table {
+    ($name:ident) => {
+        impl<'a> Follow<'a> for $name<'a> {
+            type Inner = $name<'a>;
+
+            unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+                $name {
+                    tab: Table::new(buf, loc),
+                }
+            }
+        }
+    };
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// QuoteTick
+////////////////////////////////////////////////////////////////////////////////
+/// Provides zero-copy access to an encoded `QuoteTick`.
+#[derive(Clone, Copy)]
+pub struct QuoteTickView<'a> {
+    tab: Table<'a>,
+}
+
+impl_table!(QuoteTickView);
+
+impl<'a> QuoteTickView<'a> {
+    const INSTRUMENT_ID: VOffsetT = field(0);
+    const BID: VOffsetT = field(1);
+    const ASK: VOffsetT = field(2);
+    const PRICE_PRECISION: VOffsetT = field(3);
+    const BID_SIZE: VOffsetT = field(4);
+    const ASK_SIZE: VOffsetT = field(5);
+    const SIZE_PRECISION: VOffsetT = field(6);
+    const TS_EVENT: VOffsetT = field(7);
+    const TS_INIT: VOffsetT = field(8);
+
+    /// Returns a view of the verified buffer.
+    pub fn new(buf: &'a [u8]) -> std::result::Result<Self, FlatBufferError> {
+        view(buf, QUOTE_TICK_FILE_ID)
+    }
+
+    pub fn instrument_id(&self) -> &'a str {
+        table_field!(self.tab, str, Self::INSTRUMENT_ID)
+    }
+
+    pub fn bid(&self) -> i64 {
+        table_field!(self.tab, i64, Self::BID)
+    }
+
+    pub fn ask(&self) -> i64 {
+        table_field!(self.tab, i64, Self::ASK)
+    }
+
+    pub fn price_precision(&self) -> u8 {
+        table_field!(self.tab, u8, Self::PRICE_PRECISION)
+    }
+
+    pub fn bid_size(&self) -> u64 {
+        table_field!(self.tab, u64, Self::BID_SIZE)
+    }
+
+    pub fn ask_size(&self) -> u64 {
+        table_field!(self.tab, u64, Self::ASK_SIZE)
+    }
+
+    pub fn size_precision(&self) -> u8 {
+        table_field!(self.tab, u8, Self::SIZE_PRECISION)
+    }
+
+    pub fn ts_event(&self) -> i64 {
+        table_field!(self.tab, i64, Self::TS_EVENT)
+    }
+
+    pub fn ts_init(&self) -> i64 {
+        table_field!(self.tab, i64, Self::TS_INIT)
+    }
+
+    pub fn to_quote_tick(&self) -> QuoteTick {
+        QuoteTick {
+            instrument_id: InstrumentId::from_dotted(self.instrument_id()),
+            bid: Price::from_raw(self.bid(), self.price_precision()),
+            ask: Price::from_raw(self.ask(), self.price_precision()),
+            bid_size: Quantity::from_raw(self.bid_size(), self.size_precision()),
+            ask_size: Quantity::from_raw(self.ask_size(), self.size_precision()),
+            ts_event: Timestamp {
+                value: self.ts_event(),
+            },
+            ts_init: Timestamp {
+                value: self.ts_init(),
+            },
+        }
+    }
+}
+
+impl Verifiable for QuoteTickView<'_> {
+    fn run_verifier(v: &mut Verifier, pos: usize) -> std::result::Result<(), InvalidFlatbuffer> {
+        v.visit_table(pos)?
+            .visit_field::<ForwardsUOffset<&str>>("instrument_id", Self::INSTRUMENT_ID, true)?
+            .visit_field::<i64>("bid", Self::BID, false)?
+            .visit_field::<i64>("ask", Self::ASK, false)?
+            .visit_field::<u8>("price_precision", Self::PRICE_PRECISION, false)?
+            .visit_field::<u64>("bid_size", Self::BID_SIZE, false)?
+            .visit_field::<u64>("ask_size", Self::ASK_SIZE, false)?
+            .visit_field::<u8>("size_precision", Self::SIZE_PRECISION, false)?
+            .visit_field::<i64>("ts_event", Self::TS_EVENT, false)?
+            .visit_field::<i64>("ts_init", Self::TS_INIT, false)?
+            .finish();
+        Ok(())
+    }
+}
+
+/// Encodes the tick into the builder (which is reset first), with the
+/// message available from `builder.finished_data()`.
+///
+/// Reusing a builder across messages avoids allocating per tick.
+pub fn encode_quote_tick_into(builder: &mut FlatBufferBuilder<'_>, tick: &QuoteTick) {
+    builder.reset();
+    let instrument_id = builder.create_string(&tick.instrument_id.to_string());
+    let table = builder.start_table();
+    builder.push_slot_always(QuoteTickView::INSTRUMENT_ID, instrument_id);
+    builder.push_slot(QuoteTickView::BID, tick.bid.raw, 0);
+    builder.push_slot(QuoteTickView::ASK, tick.ask.raw, 0);
+    builder.push_slot(QuoteTickView::PRICE_PRECISION, tick.bid.precision, 0);
+    builder.push_slot(QuoteTickView::BID_SIZE, tick.bid_size.raw, 0);
+    builder.push_slot(QuoteTickView::ASK_SIZE, tick.ask_size.raw, 0);
+    builder.push_slot(QuoteTickView::SIZE_PRECISION, tick.bid_size.precision, 0);
+    builder.push_slot(QuoteTickView::TS_EVENT, tick.ts_event.value, 0);
+    builder.push_slot(QuoteTickView::TS_INIT, tick.ts_init.value, 0);
+    let root = builder.end_table(table);
+    builder.finish(root, Some(QUOTE_TICK_FILE_ID));
+}
+
+pub fn encode_quote_tick(tick: &QuoteTick) -> Vec<u8> {
+    let mut builder = FlatBufferBuilder::new();
+    encode_quote_tick_into(&mut builder, tick);
+    builder.finished_data().to_vec()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// TradeTick
+////////////////////////////////////////////////////////////////////////////////
+/// Provides zero-copy access to an encoded `TradeTick`.
+#[derive(Clone, Copy)]
+pub struct TradeTickView<'a> {
+    tab: Table<'a>,
+}
+
+impl_table!(TradeTickView);
+
+impl<'a> TradeTickView<'a> {
+    const INSTRUMENT_ID: VOffsetT = field(0);
+    const PRICE: VOffsetT = field(1);
+    const PRICE_PRECISION: VOffsetT = field(2);
+    const SIZE: VOffsetT = field(3);
+    const SIZE_PRECISION: VOffsetT = field(4);
+    const AGGRESSOR_SIDE: VOffsetT = field(5);
+    const TRADE_ID: VOffsetT = field(6);
+    const TS_EVENT: VOffsetT = field(7);
+    const TS_INIT: VOffsetT = field(8);
+
+    /// Returns a view of the verified buffer.
+    pub fn new(buf: &'a [u8]) -> std::result::Result<Self, FlatBufferError> {
+        view(buf, TRADE_TICK_FILE_ID)
+    }
+
+    pub fn instrument_id(&self) -> &'a str {
+        table_field!(self.tab, str, Self::INSTRUMENT_ID)
+    }
+
+    pub fn price(&self) -> i64 {
+        table_field!(self.tab, i64, Self::PRICE)
+    }
+
+    pub fn price_precision(&self) -> u8 {
+        table_field!(self.tab, u8, Self::PRICE_PRECISION)
+    }
+
+    pub fn size(&self) -> u64 {
+        table_field!(self.tab, u64, Self::SIZE)
+    }
+
+    pub fn size_precision(&self) -> u8 {
+        table_field!(self.tab, u8, Self::SIZE_PRECISION)
+    }
+
+    pub fn aggressor_side(&self) -> u8 {
+        table_field!(self.tab, u8, Self::AGGRESSOR_SIDE)
+    }
+
+    pub fn trade_id(&self) -> &'a str {
+        table_field!(self.tab, str, Self::TRADE_ID)
+    }
+
+    pub fn ts_event(&self) -> i64 {
+        table_field!(self.tab, i64, Self::TS_EVENT)
+    }
+
+    pub fn ts_init(&self) -> i64 {
+        table_field!(self.tab, i64, Self::TS_INIT)
+    }
+
+    pub fn to_trade_tick(&self) -> std::result::Result<TradeTick, FlatBufferError> {
+        Ok(TradeTick {
+            instrument_id: InstrumentId::from_dotted(self.instrument_id()),
+            price: Price::from_raw(self.price(), self.price_precision()),
+            size: Quantity::from_raw(self.size(), self.size_precision()),
+            aggressor_side: order_side(self.aggressor_side())?,
+            trade_id: TradeId::new(self.trade_id()),
+            ts_event: Timestamp {
+                value: self.ts_event(),
+            },
+            ts_init: Timestamp {
+                value: self.ts_init(),
+            },
+        })
+    }
+}
+
+impl Verifiable for TradeTickView<'_> {
+    fn run_verifier(v: &mut Verifier, pos: usize) -> std::result::Result<(), InvalidFlatbuffer> {
+        v.visit_table(pos)?
+            .visit_field::<ForwardsUOffset<&str>>("instrument_id", Self::INSTRUMENT_ID, true)?
+            .visit_field::<i64>("price", Self::PRICE, false)?
+            .visit_field::<u8>("price_precision", Self::PRICE_PRECISION, false)?
+            .visit_field::<u64>("size", Self::SIZE, false)?
+            .visit_field::<u8>("size_precision", Self::SIZE_PRECISION, false)?
+            .visit_field::<u8>("aggressor_side", Self::AGGRESSOR_SIDE, false)?
+            .visit_field::<ForwardsUOffset<&str>>("trade_id", Self::TRADE_ID, true)?
+            .visit_field::<i64>("ts_event", Self::TS_EVENT, false)?
+            .visit_field::<i64>("ts_init", Self::TS_INIT, false)?
+            .finish();
+        Ok(())
+    }
+}
+
+/// Encodes the tick into the builder (which is reset first), with the
+/// message available from `builder.finished_data()`.
+pub fn encode_trade_tick_into(builder: &mut FlatBufferBuilder<'_>, tick: &TradeTick) {
+    builder.reset();
+    let instrument_id = builder.create_string(&tick.instrument_id.to_string());
+    let trade_id = builder.create_string(&tick.trade_id.to_string());
+    let table = builder.start_table();
+    builder.push_slot_always(TradeTickView::INSTRUMENT_ID, instrument_id);
+    builder.push_slot(TradeTickView::PRICE, tick.price.raw, 0);
+    builder.push_slot(TradeTickView::PRICE_PRECISION, tick.price.precision, 0);
+    builder.push_slot(TradeTickView::SIZE, tick.size.raw, 0);
+    builder.push_slot(TradeTickView::SIZE_PRECISION, tick.size.precision, 0);
+    builder.push_slot(TradeTickView::AGGRESSOR_SIDE, tick.aggressor_side as u8, 0);
+    builder.push_slot_always(TradeTickView::TRADE_ID, trade_id);
+    builder.push_slot(TradeTickView::TS_EVENT, tick.ts_event.value, 0);
+    builder.push_slot(TradeTickView::TS_INIT, tick.ts_init.value, 0);
+    let root = builder.end_table(table);
+    builder.finish(root, Some(TRADE_TICK_FILE_ID));
+}
+
+pub fn encode_trade_tick(tick: &TradeTick) -> Vec<u8> {
+    let mut builder = FlatBufferBuilder::new();
+    encode_trade_tick_into(&mut builder, tick);
+    builder.finished_data().to_vec()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// LevelDelta
+////////////////////////////////////////////////////////////////////////////////
+/// Provides zero-copy access to an encoded `LevelDelta` for an instrument.
+#[derive(Clone, Copy)]
+pub struct LevelDeltaView<'a> {
+    tab: Table<'a>,
+}
+
+impl_table!(LevelDeltaView);
+
+impl<'a> LevelDeltaView<'a> {
+    const INSTRUMENT_ID: VOffsetT = field(0);
+    const ACTION: VOffsetT = field(1);
+    const SIDE: VOffsetT = field(2);
+    const PRICE: VOffsetT = field(3);
+    const PRICE_PRECISION: VOffsetT = field(4);
+    const SIZE: VOffsetT = field(5);
+    const SIZE_PRECISION: VOffsetT = field(6);
+    const TS_EVENT: VOffsetT = field(7);
+
+    /// Returns a view of the verified buffer.
+    pub fn new(buf: &'a [u8]) -> std::result::Result<Self, FlatBufferError> {
+        view(buf, LEVEL_DELTA_FILE_ID)
+    }
+
+    pub fn instrument_id(&self) -> &'a str {
+        table_field!(self.tab, str, Self::INSTRUMENT_ID)
+    }
+
+    pub fn action(&self) -> u8 {
+        table_field!(self.tab, u8, Self::ACTION)
+    }
+
+    pub fn side(&self) -> u8 {
+        table_field!(self.tab, u8, Self::SIDE)
+    }
+
+    pub fn price(&self) -> i64 {
+        table_field!(self.tab, i64, Self::PRICE)
+    }
+
+    pub fn price_precision(&self) -> u8 {
+        table_field!(self.tab, u8, Self::PRICE_PRECISION)
+    }
+
+    pub fn size(&self) -> u64 {
+        table_field!(self.tab, u64, Self::SIZE)
+    }
+
+    pub fn size_precision(&self) -> u8 {
+        table_field!(self.tab, u8, Self::SIZE_PRECISION)
+    }
+
+    pub fn ts_event(&self) -> i64 {
+        table_field!(self.tab, i64, Self::TS_EVENT)
+    }
+
+    pub fn to_level_delta(&self) -> std::result::Result<LevelDelta, FlatBufferError> {
+        Ok(LevelDelta {
+            action: book_action(self.action())?,
+            side: order_side(self.side())?,
+            price: Price::from_raw(self.price(), self.price_precision()),
+            size: Quantity::from_raw(self.size(), self.size_precision()),
+        })
+    }
+}
+
+impl Verifiable for LevelDeltaView<'_> {
+    fn run_verifier(v: &mut Verifier, pos: usize) -> std::result::Result<(), InvalidFlatbuffer> {
+        v.visit_table(pos)?
+            .visit_field::<ForwardsUOffset<&str>>("instrument_id", Self::INSTRUMENT_ID, true)?
+            .visit_field::<u8>("action", Self::ACTION, false)?
+            .visit_field::<u8>("side", Self::SIDE, false)?
+            .visit_field::<i64>("price", Self::PRICE, false)?
+            .visit_field::<u8>("price_precision", Self::PRICE_PRECISION, false)?
+            .visit_field::<u64>("size", Self::SIZE, false)?
+            .visit_field::<u8>("size_precision", Self::SIZE_PRECISION, false)?
+            .visit_field::<i64>("ts_event", Self::TS_EVENT, false)?
+            .finish();
+        Ok(())
+    }
+}
+
+/// Encodes the delta for the instrument into the builder (which is reset
+/// first), with the message available from `builder.finished_data()`.
+pub fn encode_level_delta_into(
+    builder: &mut FlatBufferBuilder<'_>,
+    instrument_id: &InstrumentId,
+    delta: &LevelDelta,
+    ts_event: i64,
+) {
+    builder.reset();
+    let instrument_id = builder.create_string(&instrument_id.to_string());
+    let table = builder.start_table();
+    builder.push_slot_always(LevelDeltaView::INSTRUMENT_ID, instrument_id);
+    builder.push_slot(LevelDeltaView::ACTION, delta.action as u8, 0);
+    builder.push_slot(LevelDeltaView::SIDE, delta.side as u8, 0);
+    builder.push_slot(LevelDeltaView::PRICE, delta.price.raw, 0);
+    builder.push_slot(LevelDeltaView::PRICE_PRECISION, delta.price.precision, 0);
+    builder.push_slot(LevelDeltaView::SIZE, delta.size.raw, 0);
+    builder.push_slot(LevelDeltaView::SIZE_PRECISION, delta.size.precision, 0);
+    builder.push_slot(LevelDeltaView::TS_EVENT, ts_event, 0);
+    let root = builder.end_table(table);
+    builder.finish(root, Some(LEVEL_DELTA_FILE_ID));
+}
+
+pub fn encode_level_delta(
+    instrument_id: &InstrumentId,
+    delta: &LevelDelta,
+    ts_event: i64,
+) -> Vec<u8> {
+    let mut builder = FlatBufferBuilder::new();
+    encode_level_delta_into(&mut builder, instrument_id, delta, ts_event);
+    builder.finished_data().to_vec()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn quote_tick() -> QuoteTick {
+        QuoteTick {
+            instrument_id: InstrumentId::from_dotted("ETH/USDT.BINANCE"),
+            bid: Price::new(10500.0, 2),
+            ask: Price::new(10501.5, 2),
+            bid_size: Quantity::new(1.25, 3),
+            ask_size: Quantity::new(0.5, 3),
+            ts_event: Timestamp { value: 1 },
+            ts_init: Timestamp { value: 2 },
+        }
+    }
+
+    fn trade_tick() -> TradeTick {
+        TradeTick {
+            instrument_id: InstrumentId::from_dotted("ETH/USDT.BINANCE"),
+            price: Price::new(10500.25, 2),
+            size: Quantity::new(2.0, 3),
+            aggressor_side: OrderSide::Sell,
+            trade_id: TradeId::new("123456"),
+            ts_event: Timestamp { value: 3 },
+            ts_init: Timestamp { value: 4 },
+        }
+    }
+
+    #[test]
+    fn test_quote_tick_round_trip() {
+        let tick = quote_tick();
+
+        let buf = encode_quote_tick(&tick);
+        let view = QuoteTickView::new(&buf).unwrap();
+
+        assert_eq!(market_data_kind(&buf), Some(MarketDataKind::QuoteTick));
+        assert_eq!(view.instrument_id(), "ETH/USDT.BINANCE");
+        assert_eq!(view.bid(), tick.bid.raw);
+        assert_eq!(view.ts_init(), 2);
+        assert_eq!(view.to_quote_tick(), tick);
+    }
+
+    #[test]
+    fn test_trade_tick_round_trip() {
+        let tick = trade_tick();
+
+        let buf = encode_trade_tick(&tick);
+        let view = TradeTickView::new(&buf).unwrap();
+
+        assert_eq!(market_data_kind(&buf), Some(MarketDataKind::TradeTick));
+        assert_eq!(view.trade_id(), "123456");
+        assert_eq!(view.aggressor_side(), OrderSide::Sell as u8);
+        assert_eq!(view.to_trade_tick(), Ok(tick));
+    }
+
+    #[test]
+    fn test_level_delta_round_trip() {
+        let instrument_id = InstrumentId::from_dotted("ETH/USDT.BINANCE");
+        let delta = LevelDelta {
+            action: BookAction::Update,
+            side: OrderSide::Buy,
+            price: Price::new(10500.0, 2),
+            size: Quantity::new(3.5, 3),
+        };
+
+        let buf = encode_level_delta(&instrument_id, &delta, 5);
+        let view = LevelDeltaView::new(&buf).unwrap();
+
+        assert_eq!(market_data_kind(&buf), Some(MarketDataKind::LevelDelta));
+        assert_eq!(view.instrument_id(), "ETH/USDT.BINANCE");
+        assert_eq!(view.ts_event(), 5);
+        assert_eq!(view.to_level_delta(), Ok(delta));
+    }
+
+    #[test]
+    fn test_builder_reuse() {
+        let mut builder = FlatBufferBuilder::new();
+        let mut tick = quote_tick();
+
+        encode_quote_tick_into(&mut builder, &tick);
+        tick.bid = Price::new(10499.0, 2);
+        encode_quote_tick_into(&mut builder, &tick);
+        let view = QuoteTickView::new(builder.finished_data()).unwrap();
+
+        assert_eq!(view.to_quote_tick(), tick);
+    }
+
+    #[test]
+    fn test_view_rejects_wrong_or_invalid_buffers() {
+        let quote = encode_quote_tick(&quote_tick());
+        let mut corrupt = quote.clone();
+        corrupt[0] = 0xff;
+
+        assert!(matches!(
+            TradeTickView::new(&quote),
+            Err(FlatBufferError::UnexpectedMessage)
+        ));
+        assert!(matches!(
+            QuoteTickView::new(&corrupt),
+            Err(FlatBufferError::Invalid(_))
+        ));
+        assert!(matches!(
+            QuoteTickView::new(&[]),
+            Err(FlatBufferError::UnexpectedMessage)
+        ));
+        assert_eq!(market_data_kind(b"short"), None);
+    }
+
+    #[test]
+    fn test_invalid_enum_values() {
+        assert_eq!(order_side(9), Err(FlatBufferError::InvalidEnum("side", 9)));
+        assert_eq!(
+            book_action(0),
+            Err(FlatBufferError::InvalidEnum("action", 0))
+        );
+    }
+
+    #[test]
+    fn test_schema_declares_tables() {
+        for table in ["QuoteTick", "TradeTick", "LevelDelta"] {
+            assert!(MARKET_DATA_FBS.contains(&format!("table {} {{", table)));
+        }
+    }
+}
@@ -13,4 +13,7 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+#[cfg(feature = "flatbuffers")]
+pub mod fbs;
+pub mod synthetic;
 pub mod tick;
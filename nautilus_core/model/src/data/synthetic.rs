@@ -0,0 +1,361 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use crate::data::tick::QuoteTick;
+use crate::identifiers::instrument_id::InstrumentId;
+use crate::identifiers::symbol::Symbol;
+use crate::identifiers::venue::{Venue, SYNTHETIC_VENUE};
+use crate::types::price::Price;
+use crate::types::quantity::Quantity;
+use nautilus_core::time::Timestamp;
+
+/// Represents an invalid synthetic cross configuration.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SyntheticCrossError {
+    /// The symbol was not of the form "BASE/QUOTE".
+    InvalidSymbol(String),
+    /// The legs do not share a currency, or do not price the cross currencies.
+    InvalidLegs(String),
+}
+
+impl Display for SyntheticCrossError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SyntheticCrossError::InvalidSymbol(symbol) => {
+                write!(f, "invalid FX symbol, was '{}'", symbol)
+            }
+            SyntheticCrossError::InvalidLegs(reason) => {
+                write!(f, "invalid synthetic cross legs, {}", reason)
+            }
+        }
+    }
+}
+
+impl Error for SyntheticCrossError {}
+
+fn split_pair(symbol: &str) -> Result<(&str, &str), SyntheticCrossError> {
+    match symbol.split_once('/') {
+        Some((base, quote)) if !base.is_empty() && !quote.is_empty() => Ok((base, quote)),
+        _ => Err(SyntheticCrossError::InvalidSymbol(symbol.to_string())),
+    }
+}
+
+/// A leg quoted either as "CCY/COMMON" or inverted as "COMMON/CCY".
+#[derive(Clone, Debug)]
+struct CrossLeg {
+    instrument_id: InstrumentId,
+    inverted: bool,
+    last: Option<QuoteTick>,
+}
+
+/// The leg quote normalized to "CCY/COMMON", with sizes in CCY.
+struct LegRates {
+    bid: f64,
+    ask: f64,
+    bid_size: f64,
+    ask_size: f64,
+}
+
+impl CrossLeg {
+    fn rates(&self) -> Option<LegRates> {
+        let tick = self.last.as_ref()?;
+        let (bid, ask) = (tick.bid.as_f64(), tick.ask.as_f64());
+        let (bid_size, ask_size) = (tick.bid_size.as_f64(), tick.ask_size.as_f64());
+        if self.inverted {
+            if bid <= 0.0 || ask <= 0.0 {
+                return None;
+            }
+            // Buying CCY for COMMON lifts the inverted offer, sizes in COMMON
+            Some(LegRates {
+                bid: 1.0 / ask,
+                ask: 1.0 / bid,
+                bid_size: ask_size * ask,
+                ask_size: bid_size * bid,
+            })
+        } else {
+            Some(LegRates {
+                bid,
+                ask,
+                bid_size,
+                ask_size,
+            })
+        }
+    }
+}
+
+/// Provides synthetic FX cross quotes derived from two legs sharing a common
+/// currency, e.g. EUR/GBP from EUR/USD and GBP/USD.
+///
+/// Cross bids are rounded down and asks rounded up to the price precision, so
+/// the synthetic spread is never narrower than the combined leg spreads.
+/// Quotes are published for the cross symbol on `SYNTHETIC_VENUE`, flagging
+/// them as synthetic.
+#[derive(Clone, Debug)]
+pub struct SyntheticCross {
+    pub instrument_id: InstrumentId,
+    pub price_precision: u8,
+    pub size_precision: u8,
+    base_leg: CrossLeg,
+    quote_leg: CrossLeg,
+}
+
+impl SyntheticCross {
+    /// Returns a synthetic cross for the "BASE/QUOTE" symbol, where
+    /// `base_leg` prices BASE and `quote_leg` prices QUOTE against a common
+    /// currency (in either direction).
+    pub fn new(
+        symbol: &str,
+        base_leg: InstrumentId,
+        quote_leg: InstrumentId,
+        price_precision: u8,
+        size_precision: u8,
+    ) -> Result<Self, SyntheticCrossError> {
+        let (base, quote) = split_pair(symbol)?;
+        let base_pair = base_leg.symbol.to_string();
+        let quote_pair = quote_leg.symbol.to_string();
+        let (base_pair, quote_pair) = (split_pair(&base_pair)?, split_pair(&quote_pair)?);
+
+        let orient = |pair: (&str, &str), ccy: &str| -> Option<(bool, String)> {
+            match pair {
+                (b, common) if b == ccy => Some((false, common.to_string())),
+                (common, q) if q == ccy => Some((true, common.to_string())),
+                _ => None,
+            }
+        };
+        let (base_inverted, base_common) = orient(base_pair, base).ok_or_else(|| {
+            SyntheticCrossError::InvalidLegs(format!("{} does not price {}", base_leg, base))
+        })?;
+        let (quote_inverted, quote_common) = orient(quote_pair, quote).ok_or_else(|| {
+            SyntheticCrossError::InvalidLegs(format!("{} does not price {}", quote_leg, quote))
+        })?;
+        if base_common != quote_common {
+            return Err(SyntheticCrossError::InvalidLegs(format!(
+                "no common currency for {} and {}",
+                base_leg, quote_leg
+            )));
+        }
+
+        Ok(SyntheticCross {
+            instrument_id: InstrumentId::new(Symbol::new(symbol), Venue::new(SYNTHETIC_VENUE)),
+            price_precision,
+            size_precision,
+            base_leg: CrossLeg {
+                instrument_id: base_leg,
+                inverted: base_inverted,
+                last: None,
+            },
+            quote_leg: CrossLeg {
+                instrument_id: quote_leg,
+                inverted: quote_inverted,
+                last: None,
+            },
+        })
+    }
+
+    /// Updates the cross with a leg quote, returning the synthetic quote once
+    /// both legs have been received. Quotes for other instruments are ignored.
+    pub fn update(&mut self, tick: &QuoteTick) -> Option<QuoteTick> {
+        if tick.instrument_id == self.base_leg.instrument_id {
+            self.base_leg.last = Some(tick.clone());
+        } else if tick.instrument_id == self.quote_leg.instrument_id {
+            self.quote_leg.last = Some(tick.clone());
+        } else {
+            return None;
+        }
+        self.quote(tick.ts_init.value)
+    }
+
+    /// Returns the current synthetic quote, if both legs are available.
+    pub fn quote(&self, ts_init: i64) -> Option<QuoteTick> {
+        let base = self.base_leg.rates()?;
+        let quote = self.quote_leg.rates()?;
+        if quote.bid <= 0.0 || quote.ask <= 0.0 {
+            return None;
+        }
+
+        let bid = base.bid / quote.ask;
+        let ask = base.ask / quote.bid;
+        let bid_size = base.bid_size.min(quote.ask_size / bid);
+        let ask_size = base.ask_size.min(quote.bid_size / ask);
+        let ts_event = self
+            .base_leg
+            .last
+            .as_ref()?
+            .ts_event
+            .value
+            .max(self.quote_leg.last.as_ref()?.ts_event.value);
+
+        Some(QuoteTick {
+            instrument_id: self.instrument_id.clone(),
+            bid: Price::new(round_down(bid, self.price_precision), self.price_precision),
+            ask: Price::new(round_up(ask, self.price_precision), self.price_precision),
+            bid_size: Quantity::new(
+                round_down(bid_size, self.size_precision),
+                self.size_precision,
+            ),
+            ask_size: Quantity::new(
+                round_down(ask_size, self.size_precision),
+                self.size_precision,
+            ),
+            ts_event: Timestamp { value: ts_event },
+            ts_init: Timestamp { value: ts_init },
+        })
+    }
+}
+
+// Tolerance for representation error so exact values are not pushed a tick
+const ROUNDING_EPSILON: f64 = 1e-9;
+
+fn round_down(value: f64, precision: u8) -> f64 {
+    let scalar = 10_f64.powi(precision as i32);
+    (value * scalar + ROUNDING_EPSILON).floor() / scalar
+}
+
+fn round_up(value: f64, precision: u8) -> f64 {
+    let scalar = 10_f64.powi(precision as i32);
+    (value * scalar - ROUNDING_EPSILON).ceil() / scalar
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::{SyntheticCross, SyntheticCrossError};
+    use crate::data::tick::QuoteTick;
+    use crate::identifiers::instrument_id::InstrumentId;
+    use crate::types::price::Price;
+    use crate::types::quantity::Quantity;
+    use nautilus_core::time::Timestamp;
+
+    fn tick(instrument_id: &str, bid: &str, ask: &str, size: &str, ts: i64) -> QuoteTick {
+        QuoteTick {
+            instrument_id: InstrumentId::from_dotted(instrument_id),
+            bid: Price::from(bid),
+            ask: Price::from(ask),
+            bid_size: Quantity::from(size),
+            ask_size: Quantity::from(size),
+            ts_event: Timestamp { value: ts },
+            ts_init: Timestamp { value: ts },
+        }
+    }
+
+    fn cross(symbol: &str, base_leg: &str, quote_leg: &str) -> SyntheticCross {
+        SyntheticCross::new(
+            symbol,
+            InstrumentId::from_dotted(base_leg),
+            InstrumentId::from_dotted(quote_leg),
+            5,
+            0,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_cross_from_usd_quoted_legs() {
+        let mut cross = cross("EUR/GBP", "EUR/USD.SIM", "GBP/USD.SIM");
+
+        let first = cross.update(&tick("EUR/USD.SIM", "1.10000", "1.10010", "1000000", 1));
+        let quote = cross
+            .update(&tick("GBP/USD.SIM", "1.25000", "1.25010", "1000000", 2))
+            .unwrap();
+
+        assert!(first.is_none());
+        assert_eq!(
+            quote.instrument_id,
+            InstrumentId::from_dotted("EUR/GBP.SYNTH")
+        );
+        // 1.10000 / 1.25010 = 0.879929.. and 1.10010 / 1.25000 = 0.88008
+        assert_eq!(quote.bid, Price::from("0.87992"));
+        assert_eq!(quote.ask, Price::from("0.88008"));
+        assert_eq!(quote.bid.precision, 5);
+        assert_eq!(quote.bid_size, Quantity::from("1000000"));
+        assert_eq!(quote.ask_size, Quantity::from("1000000"));
+        assert_eq!(quote.ts_event.value, 2);
+    }
+
+    #[test]
+    fn test_cross_with_inverted_leg() {
+        let mut cross = SyntheticCross::new(
+            "EUR/JPY",
+            InstrumentId::from_dotted("EUR/USD.SIM"),
+            InstrumentId::from_dotted("USD/JPY.SIM"),
+            3,
+            0,
+        )
+        .unwrap();
+
+        cross.update(&tick("EUR/USD.SIM", "1.10000", "1.10010", "1000000", 1));
+        let quote = cross
+            .update(&tick("USD/JPY.SIM", "150.000", "150.020", "1000000", 2))
+            .unwrap();
+
+        // 1.10000 * 150.000 = 165.000 and 1.10010 * 150.020 = 165.037002
+        assert_eq!(quote.bid, Price::from("165.000"));
+        assert_eq!(quote.ask, Price::from("165.038"));
+        // Quote leg offers 1,000,000 USD, i.e. ~909,090 EUR at the cross bid
+        assert_eq!(quote.bid_size, Quantity::from("909090"));
+    }
+
+    #[test]
+    fn test_update_ignores_other_instruments() {
+        let mut cross = cross("EUR/GBP", "EUR/USD.SIM", "GBP/USD.SIM");
+
+        let quote = cross.update(&tick("AUD/USD.SIM", "0.70000", "0.70010", "1000000", 1));
+
+        assert!(quote.is_none());
+    }
+
+    #[test]
+    fn test_new_with_invalid_legs() {
+        let unrelated = SyntheticCross::new(
+            "EUR/GBP",
+            InstrumentId::from_dotted("EUR/USD.SIM"),
+            InstrumentId::from_dotted("AUD/USD.SIM"),
+            5,
+            0,
+        );
+        let no_common = SyntheticCross::new(
+            "EUR/GBP",
+            InstrumentId::from_dotted("EUR/USD.SIM"),
+            InstrumentId::from_dotted("GBP/CHF.SIM"),
+            5,
+            0,
+        );
+        let bad_symbol = SyntheticCross::new(
+            "EURGBP",
+            InstrumentId::from_dotted("EUR/USD.SIM"),
+            InstrumentId::from_dotted("GBP/USD.SIM"),
+            5,
+            0,
+        );
+
+        assert!(matches!(
+            unrelated,
+            Err(SyntheticCrossError::InvalidLegs(_))
+        ));
+        assert!(matches!(
+            no_common,
+            Err(SyntheticCrossError::InvalidLegs(_))
+        ));
+        assert_eq!(
+            bad_symbol.unwrap_err(),
+            SyntheticCrossError::InvalidSymbol("EURGBP".to_string())
+        );
+    }
+}
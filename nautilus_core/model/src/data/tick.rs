@@ -33,6 +33,19 @@ pub struct QuoteTick {
     pub ts_init: Timestamp,
 }
 
+impl QuoteTick {
+    /// Returns whether the tick is a redelivery of the other tick, being
+    /// identical apart from the initialization timestamp.
+    pub fn is_duplicate_of(&self, other: &QuoteTick) -> bool {
+        self.instrument_id == other.instrument_id
+            && self.bid == other.bid
+            && self.ask == other.ask
+            && self.bid_size == other.bid_size
+            && self.ask_size == other.ask_size
+            && self.ts_event == other.ts_event
+    }
+}
+
 /// Represents a single trade tick in a financial market.
 #[repr(C)]
 #[derive(Clone, Hash, PartialEq, Debug)]
@@ -46,6 +59,19 @@ pub struct TradeTick {
     pub ts_init: Timestamp,
 }
 
+impl TradeTick {
+    /// Returns whether the tick is a redelivery of the other tick, being
+    /// identical apart from the initialization timestamp.
+    pub fn is_duplicate_of(&self, other: &TradeTick) -> bool {
+        self.instrument_id == other.instrument_id
+            && self.price == other.price
+            && self.size == other.size
+            && self.aggressor_side == other.aggressor_side
+            && self.trade_id == other.trade_id
+            && self.ts_event == other.ts_event
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // C API
 ////////////////////////////////////////////////////////////////////////////////
@@ -98,6 +124,11 @@ pub extern "C" fn quote_tick_from_raw(
     }
 }
 
+#[no_mangle]
+pub extern "C" fn quote_tick_is_duplicate(tick: &QuoteTick, other: &QuoteTick) -> u8 {
+    u8::from(tick.is_duplicate_of(other))
+}
+
 #[no_mangle]
 pub extern "C" fn trade_tick_free(tick: TradeTick) {
     drop(tick); // Memory freed here
@@ -125,3 +156,61 @@ pub extern "C" fn trade_tick_from_raw(
         ts_init: Timestamp { value: ts_init },
     }
 }
+
+#[no_mangle]
+pub extern "C" fn trade_tick_is_duplicate(tick: &TradeTick, other: &TradeTick) -> u8 {
+    u8::from(tick.is_duplicate_of(other))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn quote_tick(bid: i64, ts_init: i64) -> QuoteTick {
+        quote_tick_from_raw(
+            InstrumentId::from_dotted("ETHUSDT.BINANCE"),
+            bid,
+            bid + 1_000_000_000,
+            2,
+            1_000_000_000,
+            1_000_000_000,
+            0,
+            1,
+            ts_init,
+        )
+    }
+
+    fn trade_tick(trade_id: &str, ts_init: i64) -> TradeTick {
+        trade_tick_from_raw(
+            InstrumentId::from_dotted("ETHUSDT.BINANCE"),
+            100_000_000_000,
+            2,
+            1_000_000_000,
+            0,
+            OrderSide::Buy,
+            TradeId::new(trade_id),
+            1,
+            ts_init,
+        )
+    }
+
+    #[test]
+    fn test_quote_tick_is_duplicate_of_ignores_ts_init() {
+        let tick = quote_tick(100_000_000_000, 1);
+
+        assert!(quote_tick(100_000_000_000, 2).is_duplicate_of(&tick));
+        assert!(!quote_tick(100_010_000_000, 1).is_duplicate_of(&tick));
+    }
+
+    #[test]
+    fn test_trade_tick_is_duplicate_of_ignores_ts_init() {
+        let tick = trade_tick("T-1", 1);
+
+        assert!(trade_tick("T-1", 2).is_duplicate_of(&tick));
+        assert!(!trade_tick("T-2", 1).is_duplicate_of(&tick));
+        assert_eq!(trade_tick_is_duplicate(&trade_tick("T-1", 3), &tick), 1);
+    }
+}
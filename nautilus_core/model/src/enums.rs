@@ -29,6 +29,15 @@ pub enum OrderSide {
     Sell = 2,
 }
 
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+#[allow(non_camel_case_types)]
+pub enum AggressorSide {
+    Unknown = 0,
+    Buy = 1,
+    Sell = 2,
+}
+
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
 #[allow(non_camel_case_types)]
@@ -65,3 +74,11 @@ pub enum DepthType {
     Volume = 1,
     Exposure = 2,
 }
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+#[allow(non_camel_case_types)]
+pub enum OptionKind {
+    Call = 1,
+    Put = 2,
+}
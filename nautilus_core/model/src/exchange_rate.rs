@@ -0,0 +1,355 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Exchange rate calculations between currencies from currency pair quotes.
+//!
+//! The quotes form a graph of currencies, with each pair `BASE/QUOTE` giving
+//! the rate from the base to the quote currency and its inverse. A rate is
+//! calculated along the path with the fewest conversions, preferring paths
+//! through the USD then BTC hubs, then quoted rates over inverses, so the
+//! result is deterministic.
+
+use std::collections::{BTreeMap, HashMap, VecDeque};
+
+use nautilus_core::string::pystr_to_string;
+use pyo3::ffi;
+
+use crate::enums::PriceType;
+use crate::types::currency::Currency;
+
+/// The currencies preferred as intermediates of cross rates, in order.
+const HUB_CURRENCIES: [&str; 2] = ["USD", "BTC"];
+
+/// Provides exchange rate calculations between currencies from the latest
+/// bid and ask quotes of currency pairs.
+#[derive(Clone, Debug, Default)]
+pub struct ExchangeRateCalculator {
+    quotes: BTreeMap<(String, String), (f64, f64)>,
+}
+
+impl ExchangeRateCalculator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Updates the bid and ask quotes of the `BASE/QUOTE` currency pair.
+    /// Returns false (ignoring the quotes) if the symbol is not a pair.
+    pub fn update(&mut self, symbol: &str, bid: f64, ask: f64) -> bool {
+        match symbol.split_once('/') {
+            Some((base, quote)) if !base.is_empty() && !quote.is_empty() => {
+                self.quotes
+                    .insert((base.to_string(), quote.to_string()), (bid, ask));
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Removes all quotes.
+    pub fn clear(&mut self) {
+        self.quotes.clear();
+    }
+
+    /// Returns the number of currency pairs quoted.
+    pub fn len(&self) -> usize {
+        self.quotes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.quotes.is_empty()
+    }
+
+    /// Returns the rate to convert an amount of `from` currency to `to`
+    /// currency at the price type, or `None` if there are insufficient quotes
+    /// or the price type is `Last`.
+    pub fn get_rate(&self, from: &str, to: &str, price_type: PriceType) -> Option<f64> {
+        if from == to {
+            return Some(1.0);
+        }
+
+        // Adjacent currencies as (hub rank, is inverse, code, quote)
+        let mut graph: BTreeMap<&str, Vec<(usize, bool, &str, f64)>> = BTreeMap::new();
+        for ((base, quote), (bid, ask)) in &self.quotes {
+            let rate = match price_type {
+                PriceType::Bid => *bid,
+                PriceType::Ask => *ask,
+                PriceType::Mid => (bid + ask) / 2.0,
+                PriceType::Last => return None,
+            };
+            if !rate.is_finite() || rate <= 0.0 {
+                continue;
+            }
+            graph
+                .entry(base)
+                .or_default()
+                .push((hub_rank(quote), false, quote, rate));
+            graph
+                .entry(quote)
+                .or_default()
+                .push((hub_rank(base), true, base, rate));
+        }
+        for edges in graph.values_mut() {
+            edges.sort_by(|a, b| (a.0, a.1, a.2).cmp(&(b.0, b.1, b.2)));
+        }
+
+        // Breadth first search for the path with the fewest conversions
+        let mut parents: HashMap<&str, (&str, bool, f64)> = HashMap::new();
+        let mut queue = VecDeque::from([from]);
+        while let Some(code) = queue.pop_front() {
+            for (_, inverse, next, quote) in graph.get(code).into_iter().flatten() {
+                if *next == from || parents.contains_key(next) {
+                    continue;
+                }
+                parents.insert(next, (code, *inverse, *quote));
+                if *next == to {
+                    return Some(path_rate(&parents, to));
+                }
+                queue.push_back(next);
+            }
+        }
+        None
+    }
+}
+
+/// Returns the rate along the path to `to`, converting through each currency
+/// then dividing by the rate from `to` into the last, as the previous
+/// implementation did for cross rates so the results are identical.
+fn path_rate(parents: &HashMap<&str, (&str, bool, f64)>, to: &str) -> f64 {
+    let mut edges = Vec::new();
+    let mut code = to;
+    while let Some((prev, inverse, quote)) = parents.get(code) {
+        edges.push((*inverse, *quote));
+        code = prev;
+    }
+    edges.reverse();
+
+    let (last_inverse, last_quote) = edges.pop().expect("path has a conversion");
+    if edges.is_empty() {
+        return match last_inverse {
+            true => 1.0 / last_quote,
+            false => last_quote,
+        };
+    }
+    let rate = edges
+        .iter()
+        .fold(1.0, |rate, (inverse, quote)| match inverse {
+            true => rate / quote,
+            false => rate * quote,
+        });
+    match last_inverse {
+        true => rate / last_quote,
+        false => rate / (1.0 / last_quote),
+    }
+}
+
+fn hub_rank(code: &str) -> usize {
+    HUB_CURRENCIES
+        .iter()
+        .position(|hub| *hub == code)
+        .unwrap_or(HUB_CURRENCIES.len())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// C API
+////////////////////////////////////////////////////////////////////////////////
+/// Returns a new calculator with no quotes. The calculator must be passed to
+/// `exchange_rate_calculator_free` to free it.
+#[no_mangle]
+pub extern "C" fn exchange_rate_calculator_new() -> *mut ExchangeRateCalculator {
+    Box::into_raw(Box::new(ExchangeRateCalculator::new()))
+}
+
+/// Updates the bid and ask quotes of the currency pair, returning 1 if the
+/// symbol was a `BASE/QUOTE` pair, otherwise 0.
+///
+/// # Safety
+///
+/// - `symbol_ptr` must be borrowed from a valid Python UTF-8 `str`.
+#[no_mangle]
+pub unsafe extern "C" fn exchange_rate_calculator_update(
+    calculator: &mut ExchangeRateCalculator,
+    symbol_ptr: *mut ffi::PyObject,
+    bid: f64,
+    ask: f64,
+) -> u8 {
+    u8::from(calculator.update(&pystr_to_string(symbol_ptr), bid, ask))
+}
+
+#[no_mangle]
+pub extern "C" fn exchange_rate_calculator_clear(calculator: &mut ExchangeRateCalculator) {
+    calculator.clear();
+}
+
+/// Returns the exchange rate between the currencies at the price type, or
+/// zero if there are insufficient quotes or the price type is `Last`.
+#[no_mangle]
+pub extern "C" fn exchange_rate_calculator_get_rate(
+    calculator: &ExchangeRateCalculator,
+    from_currency: &Currency,
+    to_currency: &Currency,
+    price_type: PriceType,
+) -> f64 {
+    calculator
+        .get_rate(&from_currency.code, &to_currency.code, price_type)
+        .unwrap_or(0.0)
+}
+
+/// Frees the calculator.
+///
+/// # Safety
+///
+/// - `calculator` must be null or have been returned by
+///   `exchange_rate_calculator_new` and not already freed.
+#[no_mangle]
+pub unsafe extern "C" fn exchange_rate_calculator_free(calculator: *mut ExchangeRateCalculator) {
+    if !calculator.is_null() {
+        drop(Box::from_raw(calculator)); // Memory freed here
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enums::CurrencyType;
+
+    fn calculator(quotes: &[(&str, f64, f64)]) -> ExchangeRateCalculator {
+        let mut calculator = ExchangeRateCalculator::new();
+        for (symbol, bid, ask) in quotes {
+            assert!(calculator.update(symbol, *bid, *ask));
+        }
+        calculator
+    }
+
+    fn assert_rate(rate: Option<f64>, expected: f64) {
+        let rate = rate.unwrap();
+        assert!((rate - expected).abs() < 1e-12, "{} != {}", rate, expected);
+    }
+
+    #[test]
+    fn test_same_currency_is_one() {
+        assert_eq!(
+            ExchangeRateCalculator::new().get_rate("USD", "USD", PriceType::Mid),
+            Some(1.0)
+        );
+    }
+
+    #[test]
+    fn test_direct_and_inverse_rates() {
+        let calculator = calculator(&[("AUD/USD", 0.8, 0.82)]);
+
+        assert_rate(calculator.get_rate("AUD", "USD", PriceType::Bid), 0.8);
+        assert_rate(calculator.get_rate("AUD", "USD", PriceType::Ask), 0.82);
+        assert_rate(calculator.get_rate("AUD", "USD", PriceType::Mid), 0.81);
+        assert_rate(calculator.get_rate("USD", "AUD", PriceType::Bid), 1.25);
+    }
+
+    #[test]
+    fn test_cross_rate_through_common_currency() {
+        let calculator = calculator(&[("AUD/USD", 0.8, 0.8), ("USD/JPY", 110.0, 110.0)]);
+
+        assert_rate(calculator.get_rate("AUD", "JPY", PriceType::Mid), 88.0);
+        assert_rate(
+            calculator.get_rate("JPY", "AUD", PriceType::Mid),
+            1.0 / 88.0,
+        );
+    }
+
+    #[test]
+    fn test_cross_rates_match_previous_implementation() {
+        let bids = calculator(&[("USD/JPY", 110.1, 110.13), ("AUD/USD", 0.8, 0.8001)]);
+
+        assert_eq!(
+            bids.get_rate("JPY", "AUD", PriceType::Bid),
+            Some(0.011353315168029064)
+        );
+        assert_eq!(
+            bids.get_rate("AUD", "JPY", PriceType::Ask),
+            Some(88.11501299999999)
+        );
+        assert_eq!(
+            bids.get_rate("JPY", "USD", PriceType::Bid),
+            Some(0.009082652134423252)
+        );
+    }
+
+    #[test]
+    fn test_multi_hop_rate() {
+        let calculator = calculator(&[
+            ("ETH/BTC", 0.05, 0.05),
+            ("BTC/USD", 40_000.0, 40_000.0),
+            ("USD/JPY", 110.0, 110.0),
+        ]);
+
+        assert_rate(
+            calculator.get_rate("ETH", "JPY", PriceType::Mid),
+            0.05 * 40_000.0 * 110.0,
+        );
+    }
+
+    #[test]
+    fn test_prefers_hub_currency_path() {
+        // AUD to JPY through NZD would give 100
+        let calculator = calculator(&[
+            ("AUD/NZD", 1.0, 1.0),
+            ("NZD/JPY", 100.0, 100.0),
+            ("AUD/USD", 0.8, 0.8),
+            ("USD/JPY", 110.0, 110.0),
+        ]);
+
+        assert_rate(calculator.get_rate("AUD", "JPY", PriceType::Mid), 88.0);
+    }
+
+    #[test]
+    fn test_insufficient_quotes_and_last_returns_none() {
+        let calculator = calculator(&[("AUD/USD", 0.8, 0.8), ("EUR/GBP", 0.9, 0.9)]);
+
+        assert_eq!(calculator.get_rate("AUD", "GBP", PriceType::Mid), None);
+        assert_eq!(calculator.get_rate("AUD", "USD", PriceType::Last), None);
+        assert_eq!(calculator.get_rate("AUD", "CAD", PriceType::Mid), None);
+    }
+
+    #[test]
+    fn test_update_ignores_non_pair_symbols() {
+        let mut calculator = ExchangeRateCalculator::new();
+
+        assert!(!calculator.update("BTCUSDT", 1.0, 1.0));
+        assert!(!calculator.update("AUD/", 1.0, 1.0));
+        assert!(calculator.is_empty());
+    }
+
+    #[test]
+    fn test_c_api() {
+        let aud = Currency::new("AUD", 2, 36, "Australian dollar", CurrencyType::Fiat);
+        let usd = Currency::new("USD", 2, 840, "United States dollar", CurrencyType::Fiat);
+        let calculator = exchange_rate_calculator_new();
+
+        unsafe {
+            (*calculator).update("AUD/USD", 0.8, 0.8);
+            assert_eq!(
+                exchange_rate_calculator_get_rate(&*calculator, &usd, &aud, PriceType::Bid),
+                1.25
+            );
+            exchange_rate_calculator_clear(&mut *calculator);
+            assert_eq!(
+                exchange_rate_calculator_get_rate(&*calculator, &usd, &aud, PriceType::Bid),
+                0.0
+            );
+            exchange_rate_calculator_free(calculator);
+        }
+    }
+}
@@ -0,0 +1,410 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Fast backtests driven by arrays of target positions.
+//!
+//! For research iteration the user supplies a target position per bar, and
+//! the fills, costs and statistics are computed over the arrays in one call
+//! without any per-event callbacks. Each array has a value per bar:
+//!
+//! - `price`: the fill price for a target on the bar, e.g. the open of the
+//!   next bar to avoid look-ahead (NaN if no fill is possible).
+//! - `mark`: the price to value the position at, e.g. the close (NaN to keep
+//!   the last mark).
+//! - `target`: the target position (NaN to hold).
+
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result};
+use std::slice;
+
+/// Represents a fast backtest failure, with the discriminant returned as the
+/// error code by the C API.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FastBacktestError {
+    /// The input arrays were not all the same length.
+    LengthMismatch = 1,
+    /// A cost or the periods per year was negative or not finite.
+    InvalidArgument = 2,
+}
+
+impl Display for FastBacktestError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            FastBacktestError::LengthMismatch => write!(f, "input arrays differ in length"),
+            FastBacktestError::InvalidArgument => write!(f, "invalid argument"),
+        }
+    }
+}
+
+impl Error for FastBacktestError {}
+
+/// Represents the costs applied to the simulated fills.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct FillCosts {
+    /// The commission as a fraction of the fill notional.
+    pub commission_rate: f64,
+    /// The slippage as a fraction of the fill price, against the fill side.
+    pub slippage: f64,
+}
+
+impl FillCosts {
+    /// Returns the name and value of the first cost which is negative or not
+    /// finite, if any.
+    pub fn invalid_field(&self) -> Option<(&'static str, f64)> {
+        [
+            ("commission_rate", self.commission_rate),
+            ("slippage", self.slippage),
+        ]
+        .into_iter()
+        .find(|(_, v)| !v.is_finite() || *v < 0.0)
+    }
+
+    pub fn validate(&self) -> std::result::Result<(), FastBacktestError> {
+        match self.invalid_field() {
+            None => Ok(()),
+            Some(_) => Err(FastBacktestError::InvalidArgument),
+        }
+    }
+}
+
+/// Represents the summary statistics of a fast backtest.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct FastBacktestStats {
+    /// The PnL at the last bar, net of commissions.
+    pub total_pnl: f64,
+    /// The total commissions.
+    pub total_commission: f64,
+    /// The number of bars with a fill.
+    pub fills: u64,
+    /// The total notional filled.
+    pub turnover: f64,
+    /// The largest fall of the PnL from its running peak (starting at zero).
+    pub max_drawdown: f64,
+    /// The annualized Sharpe ratio of the per-bar PnL changes (NaN if fewer
+    /// than two bars or no variance).
+    pub sharpe_ratio: f64,
+}
+
+/// Represents the simulated position, fills and cumulative PnL for each bar.
+///
+/// Bars without a fill have a zero fill quantity and commission, and a NaN
+/// fill price.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FastBacktest {
+    pub position: Vec<f64>,
+    pub fill_quantity: Vec<f64>,
+    pub fill_price: Vec<f64>,
+    pub commission: Vec<f64>,
+    pub pnl: Vec<f64>,
+}
+
+fn finite(value: f64) -> Option<f64> {
+    match value.is_finite() {
+        true => Some(value),
+        false => None,
+    }
+}
+
+impl FastBacktest {
+    /// Simulates the fills for the target positions, starting flat.
+    ///
+    /// When the target differs from the position and the bar has a fill
+    /// price, the difference is filled at the price adjusted for slippage.
+    /// The PnL is the cash from the fills (less commissions) plus the
+    /// position valued at the last mark.
+    pub fn run(
+        price: &[f64],
+        mark: &[f64],
+        target: &[f64],
+        costs: &FillCosts,
+    ) -> std::result::Result<Self, FastBacktestError> {
+        costs.validate()?;
+        let bars = price.len();
+        if mark.len() != bars || target.len() != bars {
+            return Err(FastBacktestError::LengthMismatch);
+        }
+
+        let mut result = FastBacktest {
+            position: Vec::with_capacity(bars),
+            fill_quantity: Vec::with_capacity(bars),
+            fill_price: Vec::with_capacity(bars),
+            commission: Vec::with_capacity(bars),
+            pnl: Vec::with_capacity(bars),
+        };
+        let mut position = 0.0;
+        let mut cash = 0.0;
+        let mut last_mark = None;
+        for i in 0..bars {
+            let quantity = finite(target[i]).map_or(0.0, |target| target - position);
+            match finite(price[i]) {
+                Some(price) if quantity != 0.0 => {
+                    let fill_price = price * (1.0 + costs.slippage * quantity.signum());
+                    let commission = quantity.abs() * fill_price * costs.commission_rate;
+                    cash -= quantity * fill_price + commission;
+                    position += quantity;
+                    last_mark = last_mark.or(Some(fill_price));
+                    result.fill_quantity.push(quantity);
+                    result.fill_price.push(fill_price);
+                    result.commission.push(commission);
+                }
+                _ => {
+                    result.fill_quantity.push(0.0);
+                    result.fill_price.push(f64::NAN);
+                    result.commission.push(0.0);
+                }
+            }
+            if let Some(mark) = finite(mark[i]) {
+                last_mark = Some(mark);
+            }
+            result.position.push(position);
+            result.pnl.push(cash + position * last_mark.unwrap_or(0.0));
+        }
+        Ok(result)
+    }
+
+    /// Returns the summary statistics, annualizing the Sharpe ratio by the
+    /// number of bars per year (e.g. 252 for daily bars).
+    pub fn stats(&self, periods_per_year: f64) -> FastBacktestStats {
+        let mut stats = FastBacktestStats {
+            total_pnl: self.pnl.last().copied().unwrap_or(0.0),
+            total_commission: self.commission.iter().sum(),
+            sharpe_ratio: f64::NAN,
+            ..Default::default()
+        };
+        for (quantity, price) in self.fill_quantity.iter().zip(&self.fill_price) {
+            if *quantity != 0.0 {
+                stats.fills += 1;
+                stats.turnover += quantity.abs() * price;
+            }
+        }
+
+        let mut peak = 0.0_f64;
+        for pnl in &self.pnl {
+            peak = peak.max(*pnl);
+            stats.max_drawdown = stats.max_drawdown.max(peak - pnl);
+        }
+
+        let n = self.pnl.len();
+        if n >= 2 {
+            let changes: Vec<f64> = self
+                .pnl
+                .iter()
+                .scan(0.0, |last, pnl| {
+                    let change = pnl - *last;
+                    *last = *pnl;
+                    Some(change)
+                })
+                .collect();
+            let mean = changes.iter().sum::<f64>() / n as f64;
+            let variance = changes.iter().map(|c| (c - mean).powi(2)).sum::<f64>() / (n - 1) as f64;
+            if variance > 0.0 {
+                stats.sharpe_ratio = mean / variance.sqrt() * periods_per_year.sqrt();
+            }
+        }
+        stats
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// C API
+////////////////////////////////////////////////////////////////////////////////
+unsafe fn read_column<'a>(ptr: *const f64, len: usize) -> &'a [f64] {
+    match len {
+        0 => &[],
+        _ => slice::from_raw_parts(ptr, len),
+    }
+}
+
+unsafe fn write_column(out: *mut f64, values: &[f64]) {
+    if !out.is_null() && !values.is_empty() {
+        slice::from_raw_parts_mut(out, values.len()).copy_from_slice(values);
+    }
+}
+
+/// Runs a fast backtest over the `len` bars of the arrays, writing the
+/// statistics to `stats_out` and returning zero on success or the
+/// `FastBacktestError` code.
+///
+/// The position and cumulative PnL of each bar are written to `position_out`
+/// and `pnl_out` unless null.
+///
+/// # Safety
+///
+/// - `price`, `mark` and `target` must be valid pointers to `len` values
+///   (or may be null if `len` is zero).
+/// - `position_out` and `pnl_out` must be null or valid pointers to `len`
+///   values to write.
+/// - `stats_out` must be a valid pointer to write the statistics to.
+#[no_mangle]
+#[allow(clippy::too_many_arguments)]
+pub unsafe extern "C" fn fast_backtest_run(
+    price: *const f64,
+    mark: *const f64,
+    target: *const f64,
+    len: usize,
+    costs: FillCosts,
+    periods_per_year: f64,
+    position_out: *mut f64,
+    pnl_out: *mut f64,
+    stats_out: *mut FastBacktestStats,
+) -> u8 {
+    if !periods_per_year.is_finite() || periods_per_year < 0.0 {
+        return FastBacktestError::InvalidArgument as u8;
+    }
+    let price = read_column(price, len);
+    let mark = read_column(mark, len);
+    let target = read_column(target, len);
+    match FastBacktest::run(price, mark, target, &costs) {
+        Ok(backtest) => {
+            write_column(position_out, &backtest.position);
+            write_column(pnl_out, &backtest.pnl);
+            stats_out.write(backtest.stats(periods_per_year));
+            0
+        }
+        Err(e) => e as u8,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::ptr;
+
+    use super::*;
+
+    const NAN: f64 = f64::NAN;
+
+    #[test]
+    fn test_run_tracks_position_and_pnl() {
+        let backtest = FastBacktest::run(
+            &[10.0, 11.0, 12.0, 13.0],
+            &[10.5, 11.5, 12.5, 13.5],
+            &[2.0, NAN, -1.0, -1.0],
+            &FillCosts::default(),
+        )
+        .unwrap();
+
+        assert_eq!(backtest.position, vec![2.0, 2.0, -1.0, -1.0]);
+        assert_eq!(backtest.fill_quantity, vec![2.0, 0.0, -3.0, 0.0]);
+        // Bought 2 at 10, sold 3 at 12, short 1 marked at 12.5 then 13.5
+        assert_eq!(backtest.pnl, vec![1.0, 3.0, 3.5, 2.5]);
+    }
+
+    #[test]
+    fn test_run_holds_without_fill_price() {
+        let backtest =
+            FastBacktest::run(&[NAN, 5.0], &[5.0, 6.0], &[1.0, 1.0], &FillCosts::default())
+                .unwrap();
+
+        assert_eq!(backtest.position, vec![0.0, 1.0]);
+        assert!(backtest.fill_price[0].is_nan());
+        assert_eq!(backtest.pnl, vec![0.0, 1.0]);
+    }
+
+    #[test]
+    fn test_run_errors() {
+        let costs = FillCosts {
+            commission_rate: -0.1,
+            slippage: 0.0,
+        };
+
+        assert_eq!(
+            FastBacktest::run(&[1.0], &[1.0], &[], &FillCosts::default()),
+            Err(FastBacktestError::LengthMismatch)
+        );
+        assert_eq!(
+            FastBacktest::run(&[1.0], &[1.0], &[1.0], &costs),
+            Err(FastBacktestError::InvalidArgument)
+        );
+        assert_eq!(costs.invalid_field(), Some(("commission_rate", -0.1)));
+        assert_eq!(
+            FillCosts {
+                commission_rate: 0.0,
+                slippage: f64::INFINITY
+            }
+            .invalid_field(),
+            Some(("slippage", f64::INFINITY))
+        );
+    }
+
+    #[test]
+    fn test_stats() {
+        let costs = FillCosts {
+            commission_rate: 0.001,
+            slippage: 0.0,
+        };
+        let backtest = FastBacktest::run(
+            &[100.0, 100.0, 100.0, 100.0],
+            &[100.0, 102.0, 99.0, 101.0],
+            &[1.0, NAN, NAN, 0.0],
+            &costs,
+        )
+        .unwrap();
+
+        let stats = backtest.stats(252.0);
+
+        assert_eq!(stats.fills, 2);
+        assert_eq!(stats.turnover, 200.0);
+        assert!((stats.total_commission - 0.2).abs() < 1e-12);
+        assert!((stats.total_pnl - (-0.2)).abs() < 1e-12);
+        // Peak of 1.9 at 102, trough of -1.1 at 99
+        assert!((stats.max_drawdown - 3.0).abs() < 1e-12);
+        assert!(stats.sharpe_ratio < 0.0);
+    }
+
+    #[test]
+    fn test_stats_without_variance() {
+        let backtest = FastBacktest::run(&[1.0], &[1.0], &[NAN], &FillCosts::default()).unwrap();
+
+        let stats = backtest.stats(252.0);
+
+        assert_eq!(stats.total_pnl, 0.0);
+        assert_eq!(stats.fills, 0);
+        assert!(stats.sharpe_ratio.is_nan());
+    }
+
+    #[test]
+    fn test_c_api() {
+        let price = [10.0, 12.0];
+        let mark = [10.0, 12.0];
+        let target = [1.0, NAN];
+        let mut pnl = [0.0; 2];
+        let mut stats = FastBacktestStats::default();
+
+        let code = unsafe {
+            fast_backtest_run(
+                price.as_ptr(),
+                mark.as_ptr(),
+                target.as_ptr(),
+                2,
+                FillCosts::default(),
+                252.0,
+                ptr::null_mut(),
+                pnl.as_mut_ptr(),
+                &mut stats,
+            )
+        };
+
+        assert_eq!(code, 0);
+        assert_eq!(pnl, [0.0, 2.0]);
+        assert_eq!(stats.total_pnl, 2.0);
+        assert_eq!(stats.fills, 1);
+    }
+}
@@ -13,61 +13,53 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use nautilus_core::string::{pystr_to_string, string_to_pystr};
+use crate::identifiers::interned::InternedStr;
+use crate::identifiers::{identifiers_to_pylist, impl_identifier};
+use nautilus_core::cvec::CVec;
 use pyo3::ffi;
-use std::fmt::{Debug, Display, Formatter, Result};
+use std::fmt::Debug;
 
 #[repr(C)]
-#[derive(Clone, Hash, PartialEq, Debug)]
-#[allow(clippy::box_collection)] // C ABI compatibility
+#[derive(Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
 pub struct AccountId {
-    value: Box<String>,
+    value: InternedStr,
 }
 
-impl From<&str> for AccountId {
-    fn from(s: &str) -> AccountId {
-        AccountId {
-            value: Box::new(s.to_string()),
-        }
+impl_identifier!(
+    AccountId,
+    {
+        free: account_id_free,
+        from_pystr: account_id_from_pystr,
+        try_from_pystr: account_id_try_from_pystr,
+        to_pystr: account_id_to_pystr,
+        as_bytes: account_id_as_bytes,
+        hash: account_id_hash,
     }
-}
-
-impl Display for AccountId {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        write!(f, "{}", self.value)
-    }
-}
+);
 
 ////////////////////////////////////////////////////////////////////////////////
 // C API
-////////////////////////////////////////////////////////////////////////////////
-#[no_mangle]
-pub extern "C" fn account_id_free(account_id: AccountId) {
-    drop(account_id); // Memory freed here
-}
-
-/// Returns a Nautilus identifier from a valid Python object pointer.
+/// Returns a pointer to a new Python list of the identifier strings.
 ///
 /// # Safety
 ///
-/// - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+/// - `account_ids` must have been returned by `account_registry_accounts`.
+/// - Assumes that since the data is originating from Rust, the GIL does not need
+///   to be acquired.
+/// - Assumes you are immediately returning this pointer to Python.
 #[no_mangle]
-pub unsafe extern "C" fn account_id_from_pystr(ptr: *mut ffi::PyObject) -> AccountId {
-    AccountId {
-        value: Box::new(pystr_to_string(ptr)),
-    }
+pub unsafe extern "C" fn account_ids_to_pylist(account_ids: &CVec) -> *mut ffi::PyObject {
+    identifiers_to_pylist(account_ids.as_slice::<AccountId>())
 }
 
-/// Returns a pointer to a valid Python UTF-8 string.
+/// Drops a vec of identifiers returned by `account_registry_accounts`.
 ///
 /// # Safety
 ///
-/// - Assumes that since the data is originating from Rust, the GIL does not need
-/// to be acquired.
-/// - Assumes you are immediately returning this pointer to Python.
+/// - `account_ids` must have been returned by `account_registry_accounts`.
 #[no_mangle]
-pub unsafe extern "C" fn account_id_to_pystr(account_id: &AccountId) -> *mut ffi::PyObject {
-    string_to_pystr(account_id.value.as_str())
+pub unsafe extern "C" fn account_ids_drop(account_ids: CVec) {
+    drop(account_ids.into_vec::<AccountId>()); // Memory freed here
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -76,15 +68,17 @@ pub unsafe extern "C" fn account_id_to_pystr(account_id: &AccountId) -> *mut ffi
 #[cfg(test)]
 mod tests {
     use super::AccountId;
-    use crate::identifiers::account_id::{account_id_from_pystr, account_id_to_pystr};
+    use crate::identifiers::account_id::{
+        account_id_as_bytes, account_id_from_pystr, account_id_to_pystr,
+    };
     use nautilus_core::string::pystr_to_string;
     use pyo3::types::PyString;
     use pyo3::{prepare_freethreaded_python, IntoPyPointer, Python};
 
     #[test]
     fn test_account_id_from_str() {
-        let account_id1 = AccountId::from("123456789");
-        let account_id2 = AccountId::from("234567890");
+        let account_id1 = AccountId::new("123456789");
+        let account_id2 = AccountId::new("234567890");
 
         assert_eq!(account_id1, account_id1);
         assert_ne!(account_id1, account_id2);
@@ -92,7 +86,7 @@ mod tests {
 
     #[test]
     fn test_account_id_as_str() {
-        let account_id = AccountId::from("1234567890");
+        let account_id = AccountId::new("1234567890");
 
         assert_eq!(account_id.to_string(), "1234567890");
     }
@@ -114,10 +108,33 @@ mod tests {
         prepare_freethreaded_python();
         let gil = Python::acquire_gil();
         let _py = gil.python();
-        let account_id = AccountId::from("SIM-02851908");
+        let account_id = AccountId::new("SIM-02851908");
         let ptr = unsafe { account_id_to_pystr(&account_id) };
 
         let s = unsafe { pystr_to_string(ptr) };
         assert_eq!(s, "SIM-02851908")
     }
+
+    #[test]
+    fn test_account_id_try_from_str() {
+        assert!("".parse::<AccountId>().is_err());
+        assert_eq!(
+            "ABC\0".parse::<AccountId>(),
+            Err(crate::identifiers::IdentifierError::InvalidChars)
+        );
+        assert_eq!(
+            "ABC-123".parse::<AccountId>(),
+            Ok(AccountId::new("ABC-123"))
+        );
+    }
+
+    #[test]
+    fn test_account_id_as_bytes() {
+        let account_id = AccountId::new("IB-U123456789");
+
+        let view = account_id_as_bytes(&account_id);
+
+        assert_eq!(view.len, 13);
+        assert_eq!(unsafe { view.as_str() }, "IB-U123456789");
+    }
 }
@@ -0,0 +1,197 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A process wide registry of the venue owning each account.
+//!
+//! A venue may have several accounts, while an account belongs to exactly one
+//! venue, so the registry is kept as a map in each direction.
+
+use std::collections::{BTreeSet, HashMap};
+use std::sync::{Mutex, OnceLock};
+
+use nautilus_core::cvec::CVec;
+
+use crate::identifiers::account_id::AccountId;
+use crate::identifiers::venue::Venue;
+
+#[derive(Default)]
+struct AccountRegistry {
+    account_venue: HashMap<AccountId, Venue>,
+    venue_accounts: HashMap<Venue, BTreeSet<AccountId>>,
+}
+
+static ACCOUNT_REGISTRY: OnceLock<Mutex<AccountRegistry>> = OnceLock::new();
+
+fn registry() -> &'static Mutex<AccountRegistry> {
+    ACCOUNT_REGISTRY.get_or_init(|| Mutex::new(AccountRegistry::default()))
+}
+
+/// Registers the account as owned by the venue, returning the venue it was
+/// previously registered to (if any and different).
+pub fn register_account(account_id: &AccountId, venue: &Venue) -> Option<Venue> {
+    let mut registry = registry().lock().unwrap();
+    let previous = registry
+        .account_venue
+        .insert(account_id.clone(), venue.clone())
+        .filter(|previous| previous != venue);
+    if let Some(previous) = &previous {
+        remove_venue_account(&mut registry, previous, account_id);
+    }
+    registry
+        .venue_accounts
+        .entry(venue.clone())
+        .or_default()
+        .insert(account_id.clone());
+    previous
+}
+
+/// Deregisters the account, returning the venue it was registered to (if any).
+pub fn deregister_account(account_id: &AccountId) -> Option<Venue> {
+    let mut registry = registry().lock().unwrap();
+    let venue = registry.account_venue.remove(account_id)?;
+    remove_venue_account(&mut registry, &venue, account_id);
+    Some(venue)
+}
+
+fn remove_venue_account(registry: &mut AccountRegistry, venue: &Venue, account_id: &AccountId) {
+    if let Some(accounts) = registry.venue_accounts.get_mut(venue) {
+        accounts.remove(account_id);
+        if accounts.is_empty() {
+            registry.venue_accounts.remove(venue);
+        }
+    }
+}
+
+/// Returns the venue owning the account, if registered.
+pub fn account_venue(account_id: &AccountId) -> Option<Venue> {
+    registry()
+        .lock()
+        .unwrap()
+        .account_venue
+        .get(account_id)
+        .cloned()
+}
+
+/// Returns the accounts registered for the venue, sorted.
+pub fn venue_accounts(venue: &Venue) -> Vec<AccountId> {
+    registry()
+        .lock()
+        .unwrap()
+        .venue_accounts
+        .get(venue)
+        .map(|accounts| accounts.iter().cloned().collect())
+        .unwrap_or_default()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// C API
+////////////////////////////////////////////////////////////////////////////////
+#[no_mangle]
+pub extern "C" fn account_registry_register(account_id: &AccountId, venue: &Venue) {
+    register_account(account_id, venue);
+}
+
+#[no_mangle]
+pub extern "C" fn account_registry_deregister(account_id: &AccountId) {
+    deregister_account(account_id);
+}
+
+/// Returns 1 and writes the venue owning the account to `out` if registered,
+/// otherwise returns 0.
+///
+/// # Safety
+///
+/// - `out` must be a valid pointer to write the venue to.
+#[no_mangle]
+pub unsafe extern "C" fn account_registry_venue(account_id: &AccountId, out: *mut Venue) -> u8 {
+    match account_venue(account_id) {
+        Some(venue) => {
+            out.write(venue);
+            1
+        }
+        None => 0,
+    }
+}
+
+/// Returns a vec of the accounts registered for the venue, to be released with
+/// `account_ids_drop`.
+#[no_mangle]
+pub extern "C" fn account_registry_accounts(venue: &Venue) -> CVec {
+    venue_accounts(venue).into()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::mem::MaybeUninit;
+
+    #[test]
+    fn test_register_accounts_for_venue() {
+        let venue = Venue::new("REGISTRY-TEST-001");
+        let account1 = AccountId::new("REGISTRY-TEST-001-002");
+        let account2 = AccountId::new("REGISTRY-TEST-001-001");
+
+        assert_eq!(register_account(&account1, &venue), None);
+        assert_eq!(register_account(&account2, &venue), None);
+        assert_eq!(register_account(&account2, &venue), None);
+
+        assert_eq!(account_venue(&account1), Some(venue.clone()));
+        assert_eq!(venue_accounts(&venue), vec![account2, account1]);
+    }
+
+    #[test]
+    fn test_reregister_account_moves_venue() {
+        let venue1 = Venue::new("REGISTRY-TEST-002");
+        let venue2 = Venue::new("REGISTRY-TEST-003");
+        let account_id = AccountId::new("REGISTRY-TEST-002-001");
+
+        register_account(&account_id, &venue1);
+
+        assert_eq!(register_account(&account_id, &venue2), Some(venue1.clone()));
+        assert!(venue_accounts(&venue1).is_empty());
+        assert_eq!(venue_accounts(&venue2), vec![account_id.clone()]);
+        assert_eq!(deregister_account(&account_id), Some(venue2.clone()));
+        assert_eq!(account_venue(&account_id), None);
+        assert!(venue_accounts(&venue2).is_empty());
+    }
+
+    #[test]
+    fn test_c_api() {
+        let venue = Venue::new("REGISTRY-TEST-004");
+        let account_id = AccountId::new("REGISTRY-TEST-004-001");
+        let mut out = MaybeUninit::<Venue>::uninit();
+
+        assert_eq!(
+            unsafe { account_registry_venue(&account_id, out.as_mut_ptr()) },
+            0
+        );
+
+        account_registry_register(&account_id, &venue);
+        let accounts = account_registry_accounts(&venue);
+
+        assert_eq!(
+            unsafe { account_registry_venue(&account_id, out.as_mut_ptr()) },
+            1
+        );
+        assert_eq!(unsafe { out.assume_init() }, venue);
+        assert_eq!(
+            unsafe { accounts.into_vec::<AccountId>() },
+            vec![account_id]
+        );
+    }
+}
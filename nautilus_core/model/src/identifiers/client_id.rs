@@ -13,49 +13,36 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use nautilus_core::string::pystr_to_string;
-use pyo3::ffi;
-use std::fmt::{Debug, Display, Formatter, Result};
+use crate::identifiers::impl_identifier;
+use crate::identifiers::interned::InternedStr;
+use std::fmt::Debug;
 
+/// Represents a valid data or execution client ID, the key commands are routed
+/// to adapter clients by (e.g. "BINANCE").
 #[repr(C)]
-#[derive(Clone, Hash, PartialEq, Debug)]
-#[allow(clippy::box_collection)] // C ABI compatibility
+#[derive(Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
 pub struct ClientId {
-    value: Box<String>,
+    value: InternedStr,
 }
 
-impl From<&str> for ClientId {
-    fn from(s: &str) -> ClientId {
-        ClientId {
-            value: Box::new(s.to_string()),
-        }
+impl_identifier!(
+    ClientId,
+    {
+        free: client_id_free,
+        from_pystr: client_id_from_pystr,
+        try_from_pystr: client_id_try_from_pystr,
+        to_pystr: client_id_to_pystr,
+        as_bytes: client_id_as_bytes,
+        hash: client_id_hash,
     }
-}
-
-impl Display for ClientId {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        write!(f, "{}", self.value)
-    }
-}
+);
 
 ////////////////////////////////////////////////////////////////////////////////
 // C API
-////////////////////////////////////////////////////////////////////////////////
+/// Returns 1 if the client IDs are equal, otherwise 0.
 #[no_mangle]
-pub extern "C" fn client_id_free(client_id: ClientId) {
-    drop(client_id); // Memory freed here
-}
-
-/// Returns a Nautilus identifier from a valid Python object pointer.
-///
-/// # Safety
-///
-/// - `ptr` must be borrowed from a valid Python UTF-8 `str`.
-#[no_mangle]
-pub unsafe extern "C" fn client_id_from_pystr(ptr: *mut ffi::PyObject) -> ClientId {
-    ClientId {
-        value: Box::new(pystr_to_string(ptr)),
-    }
+pub extern "C" fn client_id_eq(lhs: &ClientId, rhs: &ClientId) -> u8 {
+    u8::from(lhs == rhs)
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -63,12 +50,14 @@ pub unsafe extern "C" fn client_id_from_pystr(ptr: *mut ffi::PyObject) -> Client
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
-    use super::ClientId;
+    use super::{client_id_eq, client_id_to_pystr, ClientId};
+    use nautilus_core::string::pystr_to_string;
+    use pyo3::{prepare_freethreaded_python, Python};
 
     #[test]
     fn test_client_id_from_str() {
-        let client_id1 = ClientId::from("BINANCE");
-        let client_id2 = ClientId::from("FTX");
+        let client_id1 = ClientId::new("BINANCE");
+        let client_id2 = ClientId::new("FTX");
 
         assert_eq!(client_id1, client_id1);
         assert_ne!(client_id1, client_id2);
@@ -77,8 +66,38 @@ mod tests {
 
     #[test]
     fn test_client_id_as_str() {
-        let client_id = ClientId::from("BINANCE");
+        let client_id = ClientId::new("BINANCE");
+
+        assert_eq!(client_id.as_str(), "BINANCE");
+    }
+
+    #[test]
+    fn test_client_id_try_from_str() {
+        assert!("".parse::<ClientId>().is_err());
+        assert_eq!(
+            "ABC\0".parse::<ClientId>(),
+            Err(crate::identifiers::IdentifierError::InvalidChars)
+        );
+        assert_eq!("ABC-123".parse::<ClientId>(), Ok(ClientId::new("ABC-123")));
+    }
+
+    #[test]
+    fn test_client_id_eq() {
+        let binance = ClientId::new("BINANCE");
+
+        assert_eq!(client_id_eq(&binance, &ClientId::new("BINANCE")), 1);
+        assert_eq!(client_id_eq(&binance, &ClientId::new("FTX")), 0);
+    }
+
+    #[test]
+    fn test_client_id_to_pystr() {
+        prepare_freethreaded_python();
+        let gil = Python::acquire_gil();
+        let _py = gil.python();
+        let client_id = ClientId::new("BINANCE");
+
+        let ptr = unsafe { client_id_to_pystr(&client_id) };
 
-        assert_eq!(client_id.to_string(), "BINANCE");
+        assert_eq!(unsafe { pystr_to_string(ptr) }, "BINANCE");
     }
 }
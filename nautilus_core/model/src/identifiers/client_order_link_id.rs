@@ -13,52 +13,27 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use nautilus_core::string::pystr_to_string;
-use pyo3::ffi;
-use std::fmt::{Debug, Display, Formatter, Result};
+use crate::identifiers::impl_identifier;
+use crate::identifiers::interned::InternedStr;
+use std::fmt::Debug;
 
 #[repr(C)]
-#[derive(Clone, Hash, PartialEq, Debug)]
-#[allow(clippy::box_collection)] // C ABI compatibility
+#[derive(Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
 pub struct ClientOrderLinkId {
-    value: Box<String>,
+    value: InternedStr,
 }
 
-impl From<&str> for ClientOrderLinkId {
-    fn from(s: &str) -> ClientOrderLinkId {
-        ClientOrderLinkId {
-            value: Box::new(s.to_string()),
-        }
+impl_identifier!(
+    ClientOrderLinkId,
+    {
+        free: client_order_link_id_free,
+        from_pystr: client_order_link_id_from_pystr,
+        try_from_pystr: client_order_link_id_try_from_pystr,
+        to_pystr: client_order_link_id_to_pystr,
+        as_bytes: client_order_link_id_as_bytes,
+        hash: client_order_link_id_hash,
     }
-}
-
-impl Display for ClientOrderLinkId {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        write!(f, "{}", self.value)
-    }
-}
-
-////////////////////////////////////////////////////////////////////////////////
-// C API
-////////////////////////////////////////////////////////////////////////////////
-#[no_mangle]
-pub extern "C" fn client_order_link_id_free(client_order_link_id: ClientOrderLinkId) {
-    drop(client_order_link_id); // Memory freed here
-}
-
-/// Returns a Nautilus identifier from a valid Python object pointer.
-///
-/// # Safety
-///
-/// - `ptr` must be borrowed from a valid Python UTF-8 `str`.
-#[no_mangle]
-pub unsafe extern "C" fn client_order_link_id_from_pystr(
-    ptr: *mut ffi::PyObject,
-) -> ClientOrderLinkId {
-    ClientOrderLinkId {
-        value: Box::new(pystr_to_string(ptr)),
-    }
-}
+);
 
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
@@ -69,8 +44,8 @@ mod tests {
 
     #[test]
     fn test_client_id_from_str() {
-        let client_order_link_id1 = ClientOrderLinkId::from("O-20200814-102234-001-001-1");
-        let client_order_link_id2 = ClientOrderLinkId::from("O-20200814-102234-001-001-2");
+        let client_order_link_id1 = ClientOrderLinkId::new("O-20200814-102234-001-001-1");
+        let client_order_link_id2 = ClientOrderLinkId::new("O-20200814-102234-001-001-2");
 
         assert_eq!(client_order_link_id1, client_order_link_id1);
         assert_ne!(client_order_link_id1, client_order_link_id2);
@@ -82,11 +57,24 @@ mod tests {
 
     #[test]
     fn test_client_id_as_str() {
-        let client_order_link_id = ClientOrderLinkId::from("O-20200814-102234-001-001-1");
+        let client_order_link_id = ClientOrderLinkId::new("O-20200814-102234-001-001-1");
 
         assert_eq!(
             client_order_link_id.to_string(),
             "O-20200814-102234-001-001-1"
         );
     }
+
+    #[test]
+    fn test_client_order_link_id_try_from_str() {
+        assert!("".parse::<ClientOrderLinkId>().is_err());
+        assert_eq!(
+            "ABC\0".parse::<ClientOrderLinkId>(),
+            Err(crate::identifiers::IdentifierError::InvalidChars)
+        );
+        assert_eq!(
+            "ABC-123".parse::<ClientOrderLinkId>(),
+            Ok(ClientOrderLinkId::new("ABC-123"))
+        );
+    }
 }
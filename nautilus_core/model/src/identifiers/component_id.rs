@@ -13,49 +13,104 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use nautilus_core::string::pystr_to_string;
+use crate::identifiers::interned::InternedStr;
+use crate::identifiers::{impl_identifier, validate_identifier, IdentifierError};
+use nautilus_core::string::string_to_pystr;
 use pyo3::ffi;
-use std::fmt::{Debug, Display, Formatter, Result};
+use std::fmt::Debug;
 
+/// Represents a valid component ID, which may be hierarchical with '.'
+/// separated parts (e.g. "RiskEngine.Throttler.Orders").
 #[repr(C)]
-#[derive(Clone, Hash, PartialEq, Debug)]
-#[allow(clippy::box_collection)] // C ABI compatibility
+#[derive(Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
 pub struct ComponentId {
-    value: Box<String>,
+    value: InternedStr,
 }
 
-impl From<&str> for ComponentId {
-    fn from(s: &str) -> ComponentId {
-        ComponentId {
-            value: Box::new(s.to_string()),
-        }
+impl_identifier!(
+    ComponentId,
+    validate_component_id,
+    {
+        free: component_id_free,
+        from_pystr: component_id_from_pystr,
+        try_from_pystr: component_id_try_from_pystr,
+        to_pystr: component_id_to_pystr,
+        as_bytes: component_id_as_bytes,
+        hash: component_id_hash,
+    }
+);
+
+fn validate_component_id(s: &str) -> std::result::Result<(), IdentifierError> {
+    validate_identifier(s)?;
+    if s.split('.').any(str::is_empty) {
+        return Err(IdentifierError::InvalidFormat);
     }
+    Ok(())
 }
 
-impl Display for ComponentId {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        write!(f, "{}", self.value)
+impl ComponentId {
+    /// Returns the parent component, if this is not a root component.
+    pub fn parent(&self) -> Option<ComponentId> {
+        let (parent, _) = self.value.as_str().rsplit_once('.')?;
+        Some(ComponentId::new(parent))
+    }
+
+    /// Returns the last part of the hierarchy (the whole value for a root).
+    pub fn leaf(&self) -> &str {
+        let value = self.value.as_str();
+        value.rsplit_once('.').map_or(value, |(_, leaf)| leaf)
+    }
+
+    /// Returns whether this component is within the subtree of `other`, at
+    /// any depth below it.
+    pub fn is_child_of(&self, other: &ComponentId) -> bool {
+        let (value, parent) = (self.value.as_str(), other.value.as_str());
+        value.len() > parent.len() + 1
+            && value.starts_with(parent)
+            && value.as_bytes()[parent.len()] == b'.'
     }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
 // C API
-////////////////////////////////////////////////////////////////////////////////
+/// Returns 1 and writes the parent component to `out` if there is one,
+/// otherwise returns 0.
+///
+/// # Safety
+///
+/// - `out` must be a valid pointer to write the identifier to.
 #[no_mangle]
-pub extern "C" fn component_id_free(component_id: ComponentId) {
-    drop(component_id); // Memory freed here
+pub unsafe extern "C" fn component_id_parent(
+    component_id: &ComponentId,
+    out: *mut ComponentId,
+) -> u8 {
+    match component_id.parent() {
+        Some(parent) => {
+            out.write(parent);
+            1
+        }
+        None => 0,
+    }
 }
 
-/// Returns a Nautilus identifier from a valid Python object pointer.
+/// Returns a pointer to a valid Python UTF-8 string of the last part of the
+/// hierarchy.
 ///
 /// # Safety
 ///
-/// - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+/// - Assumes that since the data is originating from Rust, the GIL does not need
+///   to be acquired.
+/// - Assumes you are immediately returning this pointer to Python.
 #[no_mangle]
-pub unsafe extern "C" fn component_id_from_pystr(ptr: *mut ffi::PyObject) -> ComponentId {
-    ComponentId {
-        value: Box::new(pystr_to_string(ptr)),
-    }
+pub unsafe extern "C" fn component_id_leaf_to_pystr(
+    component_id: &ComponentId,
+) -> *mut ffi::PyObject {
+    string_to_pystr(component_id.leaf())
+}
+
+#[no_mangle]
+pub extern "C" fn component_id_is_child_of(component_id: &ComponentId, other: &ComponentId) -> u8 {
+    u8::from(component_id.is_child_of(other))
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -63,12 +118,13 @@ pub unsafe extern "C" fn component_id_from_pystr(ptr: *mut ffi::PyObject) -> Com
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
-    use super::ComponentId;
+    use super::{component_id_is_child_of, component_id_parent, ComponentId};
+    use crate::identifiers::IdentifierError;
 
     #[test]
     fn test_component_id_from_str() {
-        let component_id1 = ComponentId::from("RiskEngine");
-        let component_id2 = ComponentId::from("DataEngine");
+        let component_id1 = ComponentId::new("RiskEngine");
+        let component_id2 = ComponentId::new("DataEngine");
 
         assert_eq!(component_id1, component_id1);
         assert_ne!(component_id1, component_id2);
@@ -77,8 +133,71 @@ mod tests {
 
     #[test]
     fn test_component_id_as_str() {
-        let component_id = ComponentId::from("RiskEngine");
+        let component_id = ComponentId::new("RiskEngine");
 
         assert_eq!(component_id.to_string(), "RiskEngine");
     }
+
+    #[test]
+    fn test_component_id_try_from_str() {
+        assert!("".parse::<ComponentId>().is_err());
+        assert_eq!(
+            "ABC\0".parse::<ComponentId>(),
+            Err(IdentifierError::InvalidChars)
+        );
+        assert_eq!(
+            "ABC-123".parse::<ComponentId>(),
+            Ok(ComponentId::new("ABC-123"))
+        );
+    }
+
+    #[test]
+    fn test_component_id_hierarchy() {
+        let component_id = ComponentId::new("RiskEngine.Throttler.Orders");
+
+        assert_eq!(
+            component_id.parent(),
+            Some(ComponentId::new("RiskEngine.Throttler"))
+        );
+        assert_eq!(component_id.leaf(), "Orders");
+        assert_eq!(ComponentId::new("RiskEngine").parent(), None);
+        assert_eq!(ComponentId::new("RiskEngine").leaf(), "RiskEngine");
+    }
+
+    #[test]
+    fn test_component_id_is_child_of() {
+        let root = ComponentId::new("RiskEngine");
+        let child = ComponentId::new("RiskEngine.Throttler");
+        let grandchild = ComponentId::new("RiskEngine.Throttler.Orders");
+
+        assert!(child.is_child_of(&root));
+        assert!(grandchild.is_child_of(&root));
+        assert!(!root.is_child_of(&root));
+        assert!(!root.is_child_of(&child));
+        assert!(!ComponentId::new("RiskEngineX.Throttler").is_child_of(&root));
+        assert_eq!(component_id_is_child_of(&grandchild, &child), 1);
+    }
+
+    #[test]
+    fn test_component_id_parse_rejects_empty_parts() {
+        for value in ["RiskEngine..Orders", ".RiskEngine", "RiskEngine."] {
+            assert_eq!(
+                value.parse::<ComponentId>(),
+                Err(IdentifierError::InvalidFormat)
+            );
+        }
+    }
+
+    #[test]
+    fn test_component_id_parent_c_api() {
+        let mut parent = ComponentId::new("None");
+
+        let has_parent =
+            unsafe { component_id_parent(&ComponentId::new("DataEngine.Cache"), &mut parent) };
+        let root = unsafe { component_id_parent(&ComponentId::new("DataEngine"), &mut parent) };
+
+        assert_eq!(has_parent, 1);
+        assert_eq!(root, 0);
+        assert_eq!(parent, ComponentId::new("DataEngine"));
+    }
 }
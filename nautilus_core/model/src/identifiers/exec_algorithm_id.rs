@@ -0,0 +1,105 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use crate::identifiers::impl_identifier;
+use crate::identifiers::interned::InternedStr;
+use std::fmt::Debug;
+
+/// Represents a valid execution algorithm ID (e.g. "TWAP", "VWAP").
+#[repr(C)]
+#[derive(Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct ExecAlgorithmId {
+    value: InternedStr,
+}
+
+impl_identifier!(
+    ExecAlgorithmId,
+    {
+        free: exec_algorithm_id_free,
+        from_pystr: exec_algorithm_id_from_pystr,
+        try_from_pystr: exec_algorithm_id_try_from_pystr,
+        to_pystr: exec_algorithm_id_to_pystr,
+        as_bytes: exec_algorithm_id_as_bytes,
+        hash: exec_algorithm_id_hash,
+    }
+);
+
+////////////////////////////////////////////////////////////////////////////////
+// C API
+/// Returns 1 if the execution algorithm IDs are equal, otherwise 0.
+#[no_mangle]
+pub extern "C" fn exec_algorithm_id_eq(lhs: &ExecAlgorithmId, rhs: &ExecAlgorithmId) -> u8 {
+    u8::from(lhs == rhs)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::{exec_algorithm_id_eq, exec_algorithm_id_to_pystr, ExecAlgorithmId};
+    use crate::identifiers::IdentifierError;
+    use nautilus_core::string::pystr_to_string;
+    use pyo3::{prepare_freethreaded_python, Python};
+
+    #[test]
+    fn test_exec_algorithm_id_from_str() {
+        let exec_algorithm_id1 = ExecAlgorithmId::new("TWAP");
+        let exec_algorithm_id2 = ExecAlgorithmId::new("VWAP");
+
+        assert_eq!(exec_algorithm_id1, exec_algorithm_id1);
+        assert_ne!(exec_algorithm_id1, exec_algorithm_id2);
+        assert_eq!(exec_algorithm_id1.to_string(), "TWAP");
+    }
+
+    #[test]
+    fn test_exec_algorithm_id_try_from_str() {
+        assert_eq!("".parse::<ExecAlgorithmId>(), Err(IdentifierError::Empty));
+        assert_eq!(
+            "TWAP\0".parse::<ExecAlgorithmId>(),
+            Err(IdentifierError::InvalidChars)
+        );
+        assert_eq!(
+            "TWAP".parse::<ExecAlgorithmId>(),
+            Ok(ExecAlgorithmId::new("TWAP"))
+        );
+    }
+
+    #[test]
+    fn test_exec_algorithm_id_eq() {
+        let twap = ExecAlgorithmId::new("TWAP");
+
+        assert_eq!(
+            exec_algorithm_id_eq(&twap, &ExecAlgorithmId::new("TWAP")),
+            1
+        );
+        assert_eq!(
+            exec_algorithm_id_eq(&twap, &ExecAlgorithmId::new("VWAP")),
+            0
+        );
+    }
+
+    #[test]
+    fn test_exec_algorithm_id_to_pystr() {
+        prepare_freethreaded_python();
+        let gil = Python::acquire_gil();
+        let _py = gil.python();
+        let exec_algorithm_id = ExecAlgorithmId::new("TWAP");
+
+        let ptr = unsafe { exec_algorithm_id_to_pystr(&exec_algorithm_id) };
+
+        assert_eq!(unsafe { pystr_to_string(ptr) }, "TWAP");
+    }
+}
@@ -0,0 +1,414 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::HashMap;
+
+use crate::identifiers::client_order_id::ClientOrderId;
+use crate::identifiers::instrument_id::InstrumentId;
+use crate::identifiers::order_list_id::OrderListId;
+use crate::identifiers::position_id::PositionId;
+use crate::identifiers::strategy_id::StrategyId;
+use crate::identifiers::trader_id::TraderId;
+use nautilus_core::time::unix_nanos_to_date_tag;
+
+/// Provides a generator for unique client order IDs, of the form
+/// "O-{YYYYMMDD}-{trader_tag}-{strategy_tag}-{count}".
+///
+/// The date is taken from the given timestamp, so IDs are deterministic for
+/// a given clock (e.g. in backtests).
+#[repr(C)]
+#[derive(Clone, Debug)]
+pub struct ClientOrderIdGenerator {
+    trader_id: TraderId,
+    strategy_id: StrategyId,
+    count: u64,
+}
+
+impl ClientOrderIdGenerator {
+    pub fn new(trader_id: TraderId, strategy_id: StrategyId, initial_count: u64) -> Self {
+        ClientOrderIdGenerator {
+            trader_id,
+            strategy_id,
+            count: initial_count,
+        }
+    }
+
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    pub fn set_count(&mut self, count: u64) {
+        self.count = count;
+    }
+
+    pub fn reset(&mut self) {
+        self.count = 0;
+    }
+
+    /// Returns the next client order ID dated from the given UNIX nanoseconds.
+    pub fn generate(&mut self, ts_now: u64) -> ClientOrderId {
+        self.count += 1;
+        ClientOrderId::new(
+            format!(
+                "O-{}-{}-{}-{}",
+                unix_nanos_to_date_tag(ts_now),
+                self.trader_id.tag(),
+                self.strategy_id.tag(),
+                self.count
+            )
+            .as_str(),
+        )
+    }
+}
+
+/// Provides a generator for unique order list IDs, of the form
+/// "OL-{YYYYMMDD}-{trader_tag}-{strategy_tag}-{count}".
+///
+/// The count increments for the lifetime of the trader session, and can be
+/// restored with `set_count` when resuming from persisted state.
+#[repr(C)]
+#[derive(Clone, Debug)]
+pub struct OrderListIdGenerator {
+    trader_id: TraderId,
+    strategy_id: StrategyId,
+    count: u64,
+}
+
+impl OrderListIdGenerator {
+    pub fn new(trader_id: TraderId, strategy_id: StrategyId, initial_count: u64) -> Self {
+        OrderListIdGenerator {
+            trader_id,
+            strategy_id,
+            count: initial_count,
+        }
+    }
+
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    pub fn set_count(&mut self, count: u64) {
+        self.count = count;
+    }
+
+    pub fn reset(&mut self) {
+        self.count = 0;
+    }
+
+    /// Returns the next order list ID dated from the given UNIX nanoseconds.
+    pub fn generate(&mut self, ts_now: u64) -> OrderListId {
+        self.count += 1;
+        OrderListId::new(
+            format!(
+                "OL-{}-{}-{}-{}",
+                unix_nanos_to_date_tag(ts_now),
+                self.trader_id.tag(),
+                self.strategy_id.tag(),
+                self.count
+            )
+            .as_str(),
+        )
+    }
+}
+
+/// Provides a generator for unique position IDs, of the form
+/// "P-{YYYYMMDD}-{instrument_id}-{count}", with an "F" suffix for positions
+/// opened by a flip.
+///
+/// Counts are tracked per instrument, so IDs are deterministic for a given
+/// sequence of fills.
+#[repr(C)]
+#[derive(Clone, Debug, Default)]
+pub struct PositionIdGenerator {
+    #[allow(clippy::box_collection)] // C ABI compatibility
+    counts: Box<HashMap<String, u64>>,
+}
+
+impl PositionIdGenerator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn count(&self, instrument_id: &InstrumentId) -> u64 {
+        *self.counts.get(&instrument_id.to_string()).unwrap_or(&0)
+    }
+
+    pub fn set_count(&mut self, instrument_id: &InstrumentId, count: u64) {
+        self.counts.insert(instrument_id.to_string(), count);
+    }
+
+    pub fn reset(&mut self) {
+        self.counts.clear();
+    }
+
+    /// Returns the next position ID for the instrument dated from the given
+    /// UNIX nanoseconds.
+    pub fn generate(
+        &mut self,
+        instrument_id: &InstrumentId,
+        ts_now: u64,
+        flipped: bool,
+    ) -> PositionId {
+        let key = instrument_id.to_string();
+        let count = self.counts.entry(key).or_insert(0);
+        *count += 1;
+        PositionId::new(
+            format!(
+                "P-{}-{}-{}{}",
+                unix_nanos_to_date_tag(ts_now),
+                instrument_id,
+                count,
+                if flipped { "F" } else { "" }
+            )
+            .as_str(),
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// C API
+////////////////////////////////////////////////////////////////////////////////
+#[no_mangle]
+pub extern "C" fn client_order_id_generator_new(
+    trader_id: TraderId,
+    strategy_id: StrategyId,
+    initial_count: u64,
+) -> ClientOrderIdGenerator {
+    ClientOrderIdGenerator::new(trader_id, strategy_id, initial_count)
+}
+
+#[no_mangle]
+pub extern "C" fn client_order_id_generator_free(generator: ClientOrderIdGenerator) {
+    drop(generator); // Memory freed here
+}
+
+#[no_mangle]
+pub extern "C" fn client_order_id_generator_generate(
+    generator: &mut ClientOrderIdGenerator,
+    ts_now: u64,
+) -> ClientOrderId {
+    generator.generate(ts_now)
+}
+
+#[no_mangle]
+pub extern "C" fn client_order_id_generator_count(generator: &ClientOrderIdGenerator) -> u64 {
+    generator.count()
+}
+
+#[no_mangle]
+pub extern "C" fn client_order_id_generator_set_count(
+    generator: &mut ClientOrderIdGenerator,
+    count: u64,
+) {
+    generator.set_count(count);
+}
+
+#[no_mangle]
+pub extern "C" fn client_order_id_generator_reset(generator: &mut ClientOrderIdGenerator) {
+    generator.reset();
+}
+
+#[no_mangle]
+pub extern "C" fn order_list_id_generator_new(
+    trader_id: TraderId,
+    strategy_id: StrategyId,
+    initial_count: u64,
+) -> OrderListIdGenerator {
+    OrderListIdGenerator::new(trader_id, strategy_id, initial_count)
+}
+
+#[no_mangle]
+pub extern "C" fn order_list_id_generator_free(generator: OrderListIdGenerator) {
+    drop(generator); // Memory freed here
+}
+
+#[no_mangle]
+pub extern "C" fn order_list_id_generator_generate(
+    generator: &mut OrderListIdGenerator,
+    ts_now: u64,
+) -> OrderListId {
+    generator.generate(ts_now)
+}
+
+#[no_mangle]
+pub extern "C" fn order_list_id_generator_count(generator: &OrderListIdGenerator) -> u64 {
+    generator.count()
+}
+
+#[no_mangle]
+pub extern "C" fn order_list_id_generator_set_count(
+    generator: &mut OrderListIdGenerator,
+    count: u64,
+) {
+    generator.set_count(count);
+}
+
+#[no_mangle]
+pub extern "C" fn order_list_id_generator_reset(generator: &mut OrderListIdGenerator) {
+    generator.reset();
+}
+
+#[no_mangle]
+pub extern "C" fn position_id_generator_new() -> PositionIdGenerator {
+    PositionIdGenerator::new()
+}
+
+#[no_mangle]
+pub extern "C" fn position_id_generator_free(generator: PositionIdGenerator) {
+    drop(generator); // Memory freed here
+}
+
+/// Returns the next position ID, where `flipped` is non-zero for a position
+/// opened by a flip.
+#[no_mangle]
+pub extern "C" fn position_id_generator_generate(
+    generator: &mut PositionIdGenerator,
+    instrument_id: &InstrumentId,
+    ts_now: u64,
+    flipped: u8,
+) -> PositionId {
+    generator.generate(instrument_id, ts_now, flipped != 0)
+}
+
+#[no_mangle]
+pub extern "C" fn position_id_generator_count(
+    generator: &PositionIdGenerator,
+    instrument_id: &InstrumentId,
+) -> u64 {
+    generator.count(instrument_id)
+}
+
+#[no_mangle]
+pub extern "C" fn position_id_generator_set_count(
+    generator: &mut PositionIdGenerator,
+    instrument_id: &InstrumentId,
+    count: u64,
+) {
+    generator.set_count(instrument_id, count);
+}
+
+#[no_mangle]
+pub extern "C" fn position_id_generator_reset(generator: &mut PositionIdGenerator) {
+    generator.reset();
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::{
+        order_list_id_generator_generate, position_id_generator_generate, ClientOrderIdGenerator,
+        OrderListIdGenerator, PositionIdGenerator,
+    };
+    use crate::identifiers::client_order_id::ClientOrderId;
+    use crate::identifiers::instrument_id::InstrumentId;
+    use crate::identifiers::order_list_id::OrderListId;
+    use crate::identifiers::position_id::PositionId;
+    use crate::identifiers::strategy_id::StrategyId;
+    use crate::identifiers::trader_id::TraderId;
+
+    const TS_20200814: u64 = 1_597_400_000_000_000_000;
+
+    fn generator(initial_count: u64) -> ClientOrderIdGenerator {
+        ClientOrderIdGenerator::new(
+            TraderId::new("TRADER-001"),
+            StrategyId::new("S-002"),
+            initial_count,
+        )
+    }
+
+    #[test]
+    fn test_generate_client_order_ids() {
+        let mut generator = generator(0);
+
+        let id1 = generator.generate(TS_20200814);
+        let id2 = generator.generate(TS_20200814);
+
+        assert_eq!(id1, ClientOrderId::new("O-20200814-001-002-1"));
+        assert_eq!(id2, ClientOrderId::new("O-20200814-001-002-2"));
+        assert_eq!(generator.count(), 2);
+    }
+
+    #[test]
+    fn test_generate_with_initial_count() {
+        let mut generator = generator(5);
+
+        assert_eq!(
+            generator.generate(TS_20200814),
+            ClientOrderId::new("O-20200814-001-002-6")
+        );
+    }
+
+    #[test]
+    fn test_set_count_and_reset() {
+        let mut generator = generator(0);
+
+        generator.set_count(9);
+        let id = generator.generate(TS_20200814);
+        generator.reset();
+
+        assert_eq!(id, ClientOrderId::new("O-20200814-001-002-10"));
+        assert_eq!(generator.count(), 0);
+        assert_eq!(
+            generator.generate(0),
+            ClientOrderId::new("O-19700101-001-002-1")
+        );
+    }
+
+    #[test]
+    fn test_generate_order_list_ids() {
+        let mut generator =
+            OrderListIdGenerator::new(TraderId::new("TRADER-001"), StrategyId::new("S-002"), 0);
+
+        let id1 = generator.generate(TS_20200814);
+        let id2 = order_list_id_generator_generate(&mut generator, TS_20200814);
+        generator.reset();
+
+        assert_eq!(id1, OrderListId::new("OL-20200814-001-002-1"));
+        assert_eq!(id2, OrderListId::new("OL-20200814-001-002-2"));
+        assert_eq!(generator.count(), 0);
+    }
+
+    #[test]
+    fn test_generate_position_ids_per_instrument() {
+        let mut generator = PositionIdGenerator::new();
+        let audusd = InstrumentId::from_dotted("AUD/USD.SIM");
+        let gbpusd = InstrumentId::from_dotted("GBP/USD.SIM");
+
+        let id1 = generator.generate(&audusd, TS_20200814, false);
+        let id2 = generator.generate(&audusd, TS_20200814, true);
+        let id3 = generator.generate(&gbpusd, TS_20200814, false);
+
+        assert_eq!(id1, PositionId::new("P-20200814-AUD/USD.SIM-1"));
+        assert_eq!(id2, PositionId::new("P-20200814-AUD/USD.SIM-2F"));
+        assert_eq!(id3, PositionId::new("P-20200814-GBP/USD.SIM-1"));
+        assert_eq!(generator.count(&audusd), 2);
+        assert_eq!(generator.count(&gbpusd), 1);
+    }
+
+    #[test]
+    fn test_position_id_generator_set_count_and_reset() {
+        let mut generator = PositionIdGenerator::new();
+        let audusd = InstrumentId::from_dotted("AUD/USD.SIM");
+
+        generator.set_count(&audusd, 4);
+        let id = position_id_generator_generate(&mut generator, &audusd, TS_20200814, 0);
+        generator.reset();
+
+        assert_eq!(id, PositionId::new("P-20200814-AUD/USD.SIM-5"));
+        assert_eq!(generator.count(&audusd), 0);
+    }
+}
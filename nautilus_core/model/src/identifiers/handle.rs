@@ -0,0 +1,319 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Stable `u64` handles for identifiers, so hot paths (and structs crossing
+//! the FFI boundary) can carry an 8-byte token in place of the identifier.
+//!
+//! Handles are assigned in registration order starting at 1 (`NULL_HANDLE` is
+//! never assigned), are unique per identifier type, and remain valid for the
+//! life of the process; registered identifiers are never released, so handles
+//! should be used for bounded sets such as symbols, venues and instruments.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::{OnceLock, RwLock};
+
+use nautilus_core::string::{pystr_to_string, string_to_pystr};
+use pyo3::{ffi, IntoPyPointer, Python};
+
+use crate::identifiers::instrument_id::InstrumentId;
+use crate::identifiers::symbol::Symbol;
+use crate::identifiers::venue::Venue;
+
+/// The handle value which is never assigned to an identifier.
+pub const NULL_HANDLE: u64 = 0;
+
+pub struct HandleRegistry<T> {
+    values: Vec<T>,
+    handles: HashMap<T, u64>,
+}
+
+impl<T: Clone + Eq + Hash> HandleRegistry<T> {
+    fn new() -> Self {
+        HandleRegistry {
+            values: Vec::new(),
+            handles: HashMap::new(),
+        }
+    }
+
+    fn get(&self, value: &T) -> Option<u64> {
+        self.handles.get(value).copied()
+    }
+
+    fn intern(&mut self, value: &T) -> u64 {
+        if let Some(handle) = self.get(value) {
+            return handle;
+        }
+        self.values.push(value.clone());
+        let handle = self.values.len() as u64;
+        self.handles.insert(value.clone(), handle);
+        handle
+    }
+
+    fn resolve(&self, handle: u64) -> Option<&T> {
+        let index = usize::try_from(handle.checked_sub(1)?).ok()?;
+        self.values.get(index)
+    }
+}
+
+/// Provides `u64` handles for an identifier type.
+pub trait IdentifierHandle: Clone + Eq + Hash + Send + Sync + 'static {
+    fn registry() -> &'static RwLock<HandleRegistry<Self>>;
+
+    /// Returns the handle for the identifier, registering it if new.
+    fn to_handle(&self) -> u64 {
+        let registry = Self::registry();
+        if let Some(handle) = registry.read().expect("handle lock poisoned").get(self) {
+            return handle;
+        }
+        registry.write().expect("handle lock poisoned").intern(self)
+    }
+
+    /// Returns the identifier for the handle, if registered.
+    fn from_handle(handle: u64) -> Option<Self> {
+        let registry = Self::registry().read().expect("handle lock poisoned");
+        registry.resolve(handle).cloned()
+    }
+
+    /// Returns the number of registered identifiers of the type.
+    fn handle_count() -> usize {
+        Self::registry()
+            .read()
+            .expect("handle lock poisoned")
+            .values
+            .len()
+    }
+}
+
+macro_rules! impl_identifier_handle {
+    ($($t:ty),*) => {
+        $(
+            impl IdentifierHandle for $t {
+                fn registry() -> &'static RwLock<HandleRegistry<Self>> {
+                    static REGISTRY: OnceLock<RwLock<HandleRegistry<$t>>> = OnceLock::new();
+                    REGISTRY.get_or_init(|| RwLock::new(HandleRegistry::new()))
+                }
+            }
+        )*
+    };
+}
+
+impl_identifier_handle!(Symbol, Venue, InstrumentId);
+
+/// Returns the Python UTF-8 string of the identifier for the handle, or a new
+/// reference to `None` if the handle is not registered.
+unsafe fn resolve_to_pystr<T: IdentifierHandle + ToString>(handle: u64) -> *mut ffi::PyObject {
+    match T::from_handle(handle) {
+        Some(value) => string_to_pystr(&value.to_string()),
+        None => Python::assume_gil_acquired().None().into_ptr(),
+    }
+}
+
+/// Writes the identifier for the handle to `out`, returning 1 if registered
+/// (otherwise 0 and `out` is unchanged).
+unsafe fn write_from_handle<T: IdentifierHandle>(handle: u64, out: *mut T) -> u8 {
+    match T::from_handle(handle) {
+        Some(value) => {
+            *out = value;
+            1
+        }
+        None => 0,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// C API
+////////////////////////////////////////////////////////////////////////////////
+/// Returns the handle for the symbol from a valid Python object pointer,
+/// registering it if new.
+///
+/// # Safety
+///
+/// - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+#[no_mangle]
+pub unsafe extern "C" fn symbol_intern(ptr: *mut ffi::PyObject) -> u64 {
+    Symbol::new(pystr_to_string(ptr).as_str()).to_handle()
+}
+
+/// Returns a pointer to a valid Python UTF-8 string of the symbol for the
+/// handle, or to `None` if not registered.
+///
+/// # Safety
+///
+/// - Assumes the GIL is held and you are immediately returning this pointer
+///   to Python.
+#[no_mangle]
+pub unsafe extern "C" fn symbol_resolve(handle: u64) -> *mut ffi::PyObject {
+    resolve_to_pystr::<Symbol>(handle)
+}
+
+#[no_mangle]
+pub extern "C" fn symbol_to_handle(symbol: &Symbol) -> u64 {
+    symbol.to_handle()
+}
+
+/// Writes the symbol for the handle to `out`, returning 1 if registered.
+///
+/// # Safety
+///
+/// - `out` must be a valid pointer to an initialized identifier, which is
+///   dropped if overwritten.
+#[no_mangle]
+pub unsafe extern "C" fn symbol_from_handle(handle: u64, out: *mut Symbol) -> u8 {
+    write_from_handle(handle, out)
+}
+
+/// Returns the handle for the venue from a valid Python object pointer,
+/// registering it if new.
+///
+/// # Safety
+///
+/// - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+#[no_mangle]
+pub unsafe extern "C" fn venue_intern(ptr: *mut ffi::PyObject) -> u64 {
+    Venue::new(pystr_to_string(ptr).as_str()).to_handle()
+}
+
+/// Returns a pointer to a valid Python UTF-8 string of the venue for the
+/// handle, or to `None` if not registered.
+///
+/// # Safety
+///
+/// - Assumes the GIL is held and you are immediately returning this pointer
+///   to Python.
+#[no_mangle]
+pub unsafe extern "C" fn venue_resolve(handle: u64) -> *mut ffi::PyObject {
+    resolve_to_pystr::<Venue>(handle)
+}
+
+#[no_mangle]
+pub extern "C" fn venue_to_handle(venue: &Venue) -> u64 {
+    venue.to_handle()
+}
+
+/// Writes the venue for the handle to `out`, returning 1 if registered.
+///
+/// # Safety
+///
+/// - `out` must be a valid pointer to an initialized identifier, which is
+///   dropped if overwritten.
+#[no_mangle]
+pub unsafe extern "C" fn venue_from_handle(handle: u64, out: *mut Venue) -> u8 {
+    write_from_handle(handle, out)
+}
+
+/// Returns the handle for the instrument ID from a valid Python object pointer
+/// in the "SYMBOL.VENUE" dotted notation, registering it if new.
+///
+/// # Safety
+///
+/// - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+#[no_mangle]
+pub unsafe extern "C" fn instrument_id_intern(ptr: *mut ffi::PyObject) -> u64 {
+    InstrumentId::from_dotted(pystr_to_string(ptr).as_str()).to_handle()
+}
+
+/// Returns a pointer to a valid Python UTF-8 string of the instrument ID for
+/// the handle, or to `None` if not registered.
+///
+/// # Safety
+///
+/// - Assumes the GIL is held and you are immediately returning this pointer
+///   to Python.
+#[no_mangle]
+pub unsafe extern "C" fn instrument_id_resolve(handle: u64) -> *mut ffi::PyObject {
+    resolve_to_pystr::<InstrumentId>(handle)
+}
+
+#[no_mangle]
+pub extern "C" fn instrument_id_to_handle(instrument_id: &InstrumentId) -> u64 {
+    instrument_id.to_handle()
+}
+
+/// Writes the instrument ID for the handle to `out`, returning 1 if registered.
+///
+/// # Safety
+///
+/// - `out` must be a valid pointer to an initialized identifier, which is
+///   dropped if overwritten.
+#[no_mangle]
+pub unsafe extern "C" fn instrument_id_from_handle(handle: u64, out: *mut InstrumentId) -> u8 {
+    write_from_handle(handle, out)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pyo3::prepare_freethreaded_python;
+
+    #[test]
+    fn test_handles_are_stable_and_unique() {
+        let aud = Symbol::new("AUD/USD-HANDLE");
+        let gbp = Symbol::new("GBP/USD-HANDLE");
+
+        let aud_handle = aud.to_handle();
+
+        assert_ne!(aud_handle, NULL_HANDLE);
+        assert_eq!(aud.clone().to_handle(), aud_handle);
+        assert_ne!(gbp.to_handle(), aud_handle);
+        assert_eq!(Symbol::from_handle(aud_handle), Some(aud));
+        assert_eq!(Symbol::from_handle(gbp.to_handle()), Some(gbp));
+        assert!(Symbol::handle_count() >= 2);
+    }
+
+    #[test]
+    fn test_handles_are_per_type() {
+        let venue = Venue::new("HANDLE-VENUE");
+        let instrument_id = InstrumentId::from_dotted("ETH/USD.HANDLE-VENUE");
+
+        let handle = instrument_id.to_handle();
+
+        assert_eq!(InstrumentId::from_handle(handle), Some(instrument_id));
+        assert_eq!(Venue::from_handle(venue.to_handle()), Some(venue));
+    }
+
+    #[test]
+    fn test_unknown_handles() {
+        assert_eq!(Symbol::from_handle(NULL_HANDLE), None);
+        assert_eq!(Venue::from_handle(u64::MAX), None);
+    }
+
+    #[test]
+    fn test_c_api_intern_and_resolve() {
+        prepare_freethreaded_python();
+        let gil = Python::acquire_gil();
+        let _py = gil.python();
+        let mut symbol = Symbol::new("0");
+
+        let handle = unsafe { symbol_intern(string_to_pystr("BTC/USDT-HANDLE")) };
+        let resolved = unsafe { pystr_to_string(symbol_resolve(handle)) };
+        let unknown = unsafe { symbol_resolve(u64::MAX) };
+        let written = unsafe { symbol_from_handle(handle, &mut symbol) };
+        let missing = unsafe { symbol_from_handle(u64::MAX, &mut symbol) };
+
+        assert_eq!(resolved, "BTC/USDT-HANDLE");
+        assert_eq!(unknown, unsafe { ffi::Py_None() });
+        assert_eq!((written, missing), (1, 0));
+        assert_eq!(symbol, Symbol::new("BTC/USDT-HANDLE"));
+        assert_eq!(symbol_to_handle(&symbol), handle);
+        assert_eq!(
+            unsafe { instrument_id_intern(string_to_pystr("BTC/USDT.HANDLE")) },
+            instrument_id_to_handle(&InstrumentId::from_dotted("BTC/USDT.HANDLE"))
+        );
+    }
+}
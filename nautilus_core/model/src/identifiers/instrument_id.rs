@@ -15,26 +15,101 @@
 
 use crate::identifiers::symbol::{symbol_from_pystr, Symbol};
 use crate::identifiers::venue::{venue_from_pystr, Venue};
+use crate::identifiers::{
+    identifiers_from_pystrs, identifiers_to_pylist, str_view, try_write_identifier,
+    IdentifierError, StrView,
+};
+use nautilus_core::cvec::CVec;
+use nautilus_core::string::{pystr_to_string, string_to_pystr};
 use pyo3::ffi;
+use std::cmp::Ordering;
 use std::fmt::{Debug, Display, Formatter, Result};
+use std::iter::once;
+use std::str::FromStr;
 
 #[repr(C)]
-#[derive(Clone, Hash, PartialEq, Debug)]
-#[allow(clippy::box_collection)] // C ABI compatibility
+#[derive(Clone, Hash, PartialEq, Eq, Debug)]
 pub struct InstrumentId {
     pub symbol: Symbol,
     pub venue: Venue,
 }
 
-impl From<&str> for InstrumentId {
-    fn from(value: &str) -> Self {
-        let pieces: Vec<&str> = value.split('.').collect();
-        assert!(pieces.len() >= 2, "malformed `InstrumentId` string");
+impl InstrumentId {
+    pub fn new(symbol: Symbol, venue: Venue) -> Self {
+        InstrumentId { symbol, venue }
+    }
+
+    /// Returns the instrument ID from the "SYMBOL.VENUE" dotted notation without
+    /// validating the parts, splitting on the last '.' as symbols may themselves
+    /// contain dots (e.g. "BRK.B.NYSE").
+    ///
+    /// # Panics
+    ///
+    /// If the value is not in the dotted notation.
+    pub fn from_dotted(value: &str) -> Self {
+        let (symbol, venue) = value
+            .rsplit_once('.')
+            .unwrap_or_else(|| panic!("malformed `InstrumentId` string, was '{}'", value));
+        assert!(
+            !symbol.is_empty() && !venue.is_empty(),
+            "malformed `InstrumentId` string, was '{}'",
+            value
+        );
         InstrumentId {
-            symbol: Symbol::from(pieces[0]),
-            venue: Venue::from(pieces[1]),
+            symbol: Symbol::new(symbol),
+            venue: Venue::new(venue),
         }
     }
+
+    /// Returns a hash combining the precomputed symbol and venue hashes.
+    pub fn precomputed_hash(&self) -> u64 {
+        self.symbol.precomputed_hash().rotate_left(5) ^ self.venue.precomputed_hash()
+    }
+
+    /// Returns whether the instrument IDs are equal ignoring the case of the
+    /// symbol and venue.
+    pub fn eq_ignore_case(&self, other: &InstrumentId) -> bool {
+        self.symbol.eq_ignore_case(&other.symbol) && self.venue.eq_ignore_case(&other.venue)
+    }
+
+    fn bytes(&self) -> impl Iterator<Item = u8> + '_ {
+        let (symbol, venue) = (self.symbol.as_str(), self.venue.as_str());
+        symbol.bytes().chain(once(b'.')).chain(venue.bytes())
+    }
+}
+
+impl PartialOrd for InstrumentId {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for InstrumentId {
+    /// Orders lexicographically on the "SYMBOL.VENUE" string, without allocating.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.bytes().cmp(other.bytes())
+    }
+}
+
+impl FromStr for InstrumentId {
+    type Err = IdentifierError;
+
+    /// Parses the "SYMBOL.VENUE" dotted notation, validating both parts.
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let (symbol, venue) = s.rsplit_once('.').ok_or(IdentifierError::InvalidFormat)?;
+        Ok(InstrumentId {
+            symbol: symbol.parse()?,
+            venue: venue.parse()?,
+        })
+    }
+}
+
+impl TryFrom<&str> for InstrumentId {
+    type Error = IdentifierError;
+
+    fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+        s.parse()
+    }
 }
 
 impl Display for InstrumentId {
@@ -66,20 +141,329 @@ pub unsafe extern "C" fn instrument_id_from_pystrs(
     InstrumentId { symbol, venue }
 }
 
+/// Returns a Nautilus identifier from a valid Python object pointer in the
+/// "SYMBOL.VENUE" dotted notation.
+///
+/// # Safety
+///
+/// - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+#[no_mangle]
+pub unsafe extern "C" fn instrument_id_from_pystr(ptr: *mut ffi::PyObject) -> InstrumentId {
+    InstrumentId::from_dotted(pystr_to_string(ptr).as_str())
+}
+
+/// Returns zero and writes a Nautilus identifier to `out` from a valid Python
+/// object pointer in the "SYMBOL.VENUE" dotted notation, otherwise returns the
+/// non-zero `IdentifierError` code.
+///
+/// # Safety
+///
+/// - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+/// - `out` must be a valid pointer to uninitialized memory to write the
+///   identifier to, as any value already there is not dropped.
+#[no_mangle]
+pub unsafe extern "C" fn instrument_id_try_from_pystr(
+    ptr: *mut ffi::PyObject,
+    out: *mut InstrumentId,
+) -> u8 {
+    try_write_identifier(&pystr_to_string(ptr), out)
+}
+
+/// Returns a pointer to a valid Python UTF-8 string in the "SYMBOL.VENUE"
+/// dotted notation.
+///
+/// # Safety
+///
+/// - Assumes that since the data is originating from Rust, the GIL does not need
+///   to be acquired.
+/// - Assumes you are immediately returning this pointer to Python.
+#[no_mangle]
+pub unsafe extern "C" fn instrument_id_to_pystr(
+    instrument_id: &InstrumentId,
+) -> *mut ffi::PyObject {
+    string_to_pystr(instrument_id.to_string().as_str())
+}
+
+/// Returns a view of the symbol value bytes (UTF-8, not NUL terminated),
+/// valid for the lifetime of the identifier, without allocating.
+#[no_mangle]
+pub extern "C" fn instrument_id_symbol_as_bytes(instrument_id: &InstrumentId) -> StrView {
+    str_view(instrument_id.symbol.as_str())
+}
+
+/// Returns a view of the venue value bytes (UTF-8, not NUL terminated),
+/// valid for the lifetime of the identifier, without allocating.
+#[no_mangle]
+pub extern "C" fn instrument_id_venue_as_bytes(instrument_id: &InstrumentId) -> StrView {
+    str_view(instrument_id.venue.as_str())
+}
+
+/// Returns the hash combining the symbol and venue hashes, precomputed when
+/// interned, for the Python `__hash__`.
+#[no_mangle]
+pub extern "C" fn instrument_id_hash(instrument_id: &InstrumentId) -> u64 {
+    instrument_id.precomputed_hash()
+}
+
+#[no_mangle]
+pub extern "C" fn instrument_id_eq_ignore_case(
+    instrument_id: &InstrumentId,
+    other: &InstrumentId,
+) -> u8 {
+    u8::from(instrument_id.eq_ignore_case(other))
+}
+
+/// Returns a clone of the identifier, e.g. an element of the vec returned by
+/// `instrument_ids_from_pylist` for ownership by Python.
+#[no_mangle]
+pub extern "C" fn instrument_id_clone(instrument_id: &InstrumentId) -> InstrumentId {
+    instrument_id.clone()
+}
+
+/// Returns a vec of Nautilus identifiers from an array of `len` valid Python
+/// object pointers (e.g. the items of a list) in the "SYMBOL.VENUE" dotted
+/// notation, acquiring the GIL once for the batch. The vec must be passed to
+/// `instrument_ids_drop` to free it.
+///
+/// # Safety
+///
+/// - `ptrs` must point to `len` pointers borrowed from valid Python UTF-8 `str`s.
+#[no_mangle]
+pub unsafe extern "C" fn instrument_ids_from_pylist(
+    ptrs: *const *mut ffi::PyObject,
+    len: usize,
+) -> CVec {
+    identifiers_from_pystrs(ptrs, len, InstrumentId::from_dotted)
+}
+
+/// Returns a pointer to a new Python list of the identifier strings in the
+/// "SYMBOL.VENUE" dotted notation.
+///
+/// # Safety
+///
+/// - `instrument_ids` must have been returned by `instrument_ids_from_pylist`.
+/// - Assumes that since the data is originating from Rust, the GIL does not need
+///   to be acquired.
+/// - Assumes you are immediately returning this pointer to Python.
+#[no_mangle]
+pub unsafe extern "C" fn instrument_ids_to_pylist(instrument_ids: &CVec) -> *mut ffi::PyObject {
+    identifiers_to_pylist(instrument_ids.as_slice::<InstrumentId>())
+}
+
+/// Drops a vec of identifiers returned by `instrument_ids_from_pylist`.
+///
+/// # Safety
+///
+/// - `instrument_ids` must have been returned by `instrument_ids_from_pylist`.
+#[no_mangle]
+pub unsafe extern "C" fn instrument_ids_drop(instrument_ids: CVec) {
+    drop(instrument_ids.into_vec::<InstrumentId>()); // Memory freed here
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
-    use super::InstrumentId;
+    use super::{
+        instrument_id_eq_ignore_case, instrument_id_hash, instrument_id_symbol_as_bytes,
+        instrument_id_venue_as_bytes, instrument_ids_drop, instrument_ids_from_pylist,
+        instrument_ids_to_pylist, InstrumentId,
+    };
+    use crate::identifiers::instrument_id::{
+        instrument_id_from_pystr, instrument_id_to_pystr, instrument_id_try_from_pystr,
+    };
+    use crate::identifiers::IdentifierError;
+    use nautilus_core::string::pystr_to_string;
+    use pyo3::types::{PyList, PyString};
+    use pyo3::{prepare_freethreaded_python, FromPyPointer, IntoPyPointer, Python};
+    use std::mem::MaybeUninit;
 
     #[test]
     fn test_instrument_id_from_str() {
-        let instrument_id1 = InstrumentId::from("ETH/USDT.BINANCE");
-        let instrument_id2 = InstrumentId::from("XBT/USD.BITMEX");
+        let instrument_id1 = InstrumentId::from_dotted("ETH/USDT.BINANCE");
+        let instrument_id2 = InstrumentId::from_dotted("XBT/USD.BITMEX");
 
         assert_eq!(instrument_id1, instrument_id1);
         assert_ne!(instrument_id1, instrument_id2);
         assert_eq!(instrument_id1.to_string(), "ETH/USDT.BINANCE")
     }
+
+    #[test]
+    fn test_instrument_id_from_str_with_dotted_symbol() {
+        let instrument_id = InstrumentId::from_dotted("BRK.B.NYSE");
+
+        assert_eq!(instrument_id.symbol.to_string(), "BRK.B");
+        assert_eq!(instrument_id.venue.to_string(), "NYSE");
+        assert_eq!(instrument_id.to_string(), "BRK.B.NYSE");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_instrument_id_from_str_when_malformed() {
+        let _ = InstrumentId::from_dotted("ETH/USDT");
+    }
+
+    #[test]
+    fn test_instrument_id_eq_ignore_case() {
+        let instrument_id = InstrumentId::from_dotted("ETH/USDT.BINANCE");
+
+        assert!(instrument_id.eq_ignore_case(&InstrumentId::from_dotted("eth/usdt.Binance")));
+        assert!(!instrument_id.eq_ignore_case(&InstrumentId::from_dotted("ETH/USDC.BINANCE")));
+        assert_eq!(
+            instrument_id_eq_ignore_case(
+                &instrument_id,
+                &InstrumentId::from_dotted("ETH/USDT.binance")
+            ),
+            1
+        );
+    }
+
+    #[test]
+    fn test_instrument_id_parse() {
+        assert_eq!(
+            "BRK.B.NYSE".parse::<InstrumentId>(),
+            Ok(InstrumentId::from_dotted("BRK.B.NYSE"))
+        );
+        assert_eq!(
+            "ETH/USDT".parse::<InstrumentId>(),
+            Err(IdentifierError::InvalidFormat)
+        );
+        assert_eq!(
+            ".BINANCE".parse::<InstrumentId>(),
+            Err(IdentifierError::Empty)
+        );
+        assert_eq!(
+            "ETH/USDT.BIN\0".parse::<InstrumentId>(),
+            Err(IdentifierError::InvalidChars)
+        );
+    }
+
+    #[test]
+    fn test_instrument_id_try_from() {
+        assert_eq!(
+            InstrumentId::try_from("BRK.B.NYSE"),
+            Ok(InstrumentId::from_dotted("BRK.B.NYSE"))
+        );
+        assert_eq!(
+            InstrumentId::try_from("ETH/USDT"),
+            Err(IdentifierError::InvalidFormat)
+        );
+    }
+
+    #[test]
+    fn test_instrument_id_try_from_pystr() {
+        prepare_freethreaded_python();
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        let valid = PyString::new(py, "ETH/USDT.BINANCE").into_ptr();
+        let invalid = PyString::new(py, "ETH/USDT").into_ptr();
+        let mut out = MaybeUninit::<InstrumentId>::uninit();
+
+        let code = unsafe { instrument_id_try_from_pystr(valid, out.as_mut_ptr()) };
+        let instrument_id = unsafe { out.assume_init() };
+        let mut invalid_out = MaybeUninit::<InstrumentId>::uninit();
+        let invalid_code =
+            unsafe { instrument_id_try_from_pystr(invalid, invalid_out.as_mut_ptr()) };
+
+        assert_eq!(code, 0);
+        assert_eq!(instrument_id, InstrumentId::from_dotted("ETH/USDT.BINANCE"));
+        assert_eq!(invalid_code, IdentifierError::InvalidFormat as u8);
+    }
+
+    #[test]
+    fn test_instrument_id_from_pystr() {
+        prepare_freethreaded_python();
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        let pystr = PyString::new(py, "ETH/USDT.BINANCE").into_ptr();
+
+        let instrument_id = unsafe { instrument_id_from_pystr(pystr) };
+
+        assert_eq!(instrument_id, InstrumentId::from_dotted("ETH/USDT.BINANCE"))
+    }
+
+    #[test]
+    fn test_instrument_id_to_pystr() {
+        prepare_freethreaded_python();
+        let gil = Python::acquire_gil();
+        let _py = gil.python();
+        let instrument_id = InstrumentId::from_dotted("ETH/USDT.BINANCE");
+        let ptr = unsafe { instrument_id_to_pystr(&instrument_id) };
+
+        let s = unsafe { pystr_to_string(ptr) };
+        assert_eq!(s, "ETH/USDT.BINANCE")
+    }
+
+    #[test]
+    fn test_instrument_id_ordering_is_lexicographic() {
+        let mut ids = [
+            InstrumentId::from_dotted("ETH/USDT.BINANCE"),
+            InstrumentId::from_dotted("BRK.B.NYSE"),
+            InstrumentId::from_dotted("BRK.NYSE"),
+            InstrumentId::from_dotted("AUD/USD.SIM"),
+        ];
+
+        ids.sort();
+
+        let sorted: Vec<String> = ids.iter().map(|id| id.to_string()).collect();
+        let mut expected = sorted.clone();
+        expected.sort();
+        assert_eq!(sorted, expected);
+        assert_eq!(sorted[1], "BRK.B.NYSE");
+    }
+
+    #[test]
+    fn test_instrument_id_as_bytes() {
+        let instrument_id = InstrumentId::from_dotted("ETH/USDT.BINANCE");
+
+        let symbol = instrument_id_symbol_as_bytes(&instrument_id);
+        let venue = instrument_id_venue_as_bytes(&instrument_id);
+
+        assert_eq!(unsafe { symbol.as_str() }, "ETH/USDT");
+        assert_eq!(unsafe { venue.as_str() }, "BINANCE");
+    }
+
+    #[test]
+    fn test_instrument_id_hash() {
+        let instrument_id1 = InstrumentId::from_dotted("ETH/USDT.BINANCE");
+        let instrument_id2 = InstrumentId::from_dotted("ETH/USDT.FTX");
+        let instrument_id3 = InstrumentId::from_dotted("BINANCE.ETH/USDT");
+
+        assert_eq!(
+            instrument_id_hash(&instrument_id1),
+            instrument_id_hash(&InstrumentId::from_dotted("ETH/USDT.BINANCE"))
+        );
+        assert_ne!(
+            instrument_id_hash(&instrument_id1),
+            instrument_id_hash(&instrument_id2)
+        );
+        assert_ne!(
+            instrument_id_hash(&instrument_id1),
+            instrument_id_hash(&instrument_id3)
+        );
+    }
+
+    #[test]
+    fn test_instrument_ids_pylist_round_trip() {
+        prepare_freethreaded_python();
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        let values: Vec<String> = (0..1000).map(|i| format!("SYM{}.SIM", i)).collect();
+        let ptrs: Vec<_> = values
+            .iter()
+            .map(|v| PyString::new(py, v).into_ptr())
+            .collect();
+
+        let instrument_ids = unsafe { instrument_ids_from_pylist(ptrs.as_ptr(), ptrs.len()) };
+        let list = unsafe { PyList::from_owned_ptr(py, instrument_ids_to_pylist(&instrument_ids)) };
+
+        assert_eq!(instrument_ids.len, 1000);
+        assert_eq!(
+            unsafe { instrument_ids.as_slice::<InstrumentId>() }[42],
+            InstrumentId::from_dotted("SYM42.SIM")
+        );
+        assert_eq!(list.extract::<Vec<String>>().unwrap(), values);
+        unsafe { instrument_ids_drop(instrument_ids) };
+    }
 }
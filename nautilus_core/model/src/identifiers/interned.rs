@@ -0,0 +1,363 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::cmp::Ordering;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::fmt::{Debug, Display, Formatter, Result};
+use std::hash::{Hash, Hasher};
+use std::sync::{Arc, Mutex, OnceLock, Weak};
+
+const MIN_PURGE_LEN: usize = 1024;
+
+/// The maximum length (bytes) of a value stored inline rather than interned.
+pub const INLINE_CAPACITY: usize = 23;
+
+fn hash_str(s: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    s.hash(&mut hasher);
+    hasher.finish()
+}
+
+struct Entry {
+    value: String,
+}
+
+struct Interner {
+    /// Entries by the hash of their value, so each value is stored only once.
+    table: HashMap<u64, Vec<Weak<Entry>>>,
+    len: usize,
+    purge_len: usize,
+}
+
+impl Interner {
+    fn intern(&mut self, s: &str, hash: u64) -> Arc<Entry> {
+        let bucket = self.table.entry(hash).or_default();
+        if let Some(existing) = bucket
+            .iter()
+            .filter_map(Weak::upgrade)
+            .find(|e| e.value == s)
+        {
+            return existing;
+        }
+        let value = Arc::new(Entry {
+            value: s.to_string(),
+        });
+        bucket.push(Arc::downgrade(&value));
+        self.len += 1;
+        if self.len >= self.purge_len {
+            // Drop entries for values no longer referenced by any identifier, so
+            // unique-per-order values (client order IDs, trade IDs) do not leak
+            self.purge();
+            self.purge_len = MIN_PURGE_LEN.max(self.len * 2);
+        }
+        value
+    }
+
+    fn purge(&mut self) {
+        self.table.retain(|_, bucket| {
+            bucket.retain(|w| w.strong_count() > 0);
+            !bucket.is_empty()
+        });
+        self.len = self.table.values().map(Vec::len).sum();
+    }
+}
+
+fn interner() -> &'static Mutex<Interner> {
+    static INTERNER: OnceLock<Mutex<Interner>> = OnceLock::new();
+    INTERNER.get_or_init(|| {
+        Mutex::new(Interner {
+            table: HashMap::new(),
+            len: 0,
+            purge_len: MIN_PURGE_LEN,
+        })
+    })
+}
+
+/// Returns the number of distinct (longer than `INLINE_CAPACITY`) values
+/// currently held by the interner.
+pub fn interned_count() -> usize {
+    let mut interner = interner().lock().expect("interner lock poisoned");
+    interner.purge();
+    interner.len
+}
+
+/// Represents an identifier string value.
+///
+/// Values of up to `INLINE_CAPACITY` bytes (most symbols, venues and
+/// account IDs) are stored inline without allocating. Longer values are
+/// interned, so all live values with the same contents share a single
+/// allocation, cloning is a reference count increment and equality is a
+/// pointer compare. The hash is computed once on construction.
+///
+/// As the representation is determined by the length, equal values always
+/// share a representation.
+#[repr(C)]
+pub struct InternedStr {
+    /// The interned entry, or null when the value is inline.
+    ptr: *const Entry,
+    hash: u64,
+    len: u8,
+    inline: [u8; INLINE_CAPACITY],
+}
+
+// SAFETY: `ptr` is null or from `Arc::into_raw` of an `Arc<Entry>`, which is `Send + Sync`
+unsafe impl Send for InternedStr {}
+unsafe impl Sync for InternedStr {}
+
+impl InternedStr {
+    pub fn new(s: &str) -> Self {
+        let hash = hash_str(s);
+        let mut inline = [0; INLINE_CAPACITY];
+        if s.len() <= INLINE_CAPACITY {
+            inline[..s.len()].copy_from_slice(s.as_bytes());
+            return InternedStr {
+                ptr: std::ptr::null(),
+                hash,
+                len: s.len() as u8,
+                inline,
+            };
+        }
+        let value = interner()
+            .lock()
+            .expect("interner lock poisoned")
+            .intern(s, hash);
+        InternedStr {
+            ptr: Arc::into_raw(value),
+            hash,
+            len: 0,
+            inline,
+        }
+    }
+
+    /// Returns whether the value is stored inline (not interned).
+    pub fn is_inline(&self) -> bool {
+        self.ptr.is_null()
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self.entry() {
+            Some(entry) => entry.value.as_str(),
+            // SAFETY: inline bytes are always copied from a `str`
+            None => unsafe { std::str::from_utf8_unchecked(&self.inline[..self.len as usize]) },
+        }
+    }
+
+    /// Returns the hash of the value, computed once on construction.
+    ///
+    /// The hash is deterministic for a given build, but not across builds.
+    pub fn precomputed_hash(&self) -> u64 {
+        self.hash
+    }
+
+    fn entry(&self) -> Option<&Entry> {
+        // SAFETY: `ptr` is null or a live `Arc<Entry>` for as long as `self` exists
+        unsafe { self.ptr.as_ref() }
+    }
+}
+
+impl Clone for InternedStr {
+    fn clone(&self) -> Self {
+        if !self.is_inline() {
+            // SAFETY: `ptr` is from `Arc::into_raw` and the `Arc` is alive via `self`
+            unsafe { Arc::increment_strong_count(self.ptr) };
+        }
+        InternedStr {
+            ptr: self.ptr,
+            hash: self.hash,
+            len: self.len,
+            inline: self.inline,
+        }
+    }
+}
+
+impl Drop for InternedStr {
+    fn drop(&mut self) {
+        if !self.is_inline() {
+            // SAFETY: `ptr` is from `Arc::into_raw` and owns one strong count
+            unsafe { Arc::decrement_strong_count(self.ptr) };
+        }
+    }
+}
+
+impl PartialEq for InternedStr {
+    fn eq(&self, other: &Self) -> bool {
+        // Inline bytes are zero padded, and zeroed for interned values
+        self.ptr == other.ptr && self.len == other.len && self.inline == other.inline
+    }
+}
+
+impl Eq for InternedStr {}
+
+impl PartialOrd for InternedStr {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for InternedStr {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if !self.is_inline() && self.ptr == other.ptr {
+            return Ordering::Equal;
+        }
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl Hash for InternedStr {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u64(self.precomputed_hash())
+    }
+}
+
+impl Debug for InternedStr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{:?}", self.as_str())
+    }
+}
+
+impl Display for InternedStr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::{InternedStr, INLINE_CAPACITY};
+    use std::cmp::Ordering;
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    #[test]
+    fn test_short_values_are_inline() {
+        let value1 = InternedStr::new("BINANCE");
+        let value2 = InternedStr::new("BINANCE");
+        let value3 = InternedStr::new("FTX");
+
+        assert!(value1.is_inline());
+        assert_eq!(value1, value2);
+        assert_ne!(value1, value3);
+        assert_eq!(value1.as_str(), "BINANCE");
+        assert_eq!(value1.clone().as_str(), "BINANCE");
+        assert_eq!(value1.to_string(), "BINANCE");
+        assert_eq!(format!("{:?}", value1), "\"BINANCE\"");
+    }
+
+    #[test]
+    fn test_inline_capacity_boundary() {
+        let inline = "A".repeat(INLINE_CAPACITY);
+        let interned = "A".repeat(INLINE_CAPACITY + 1);
+
+        assert!(InternedStr::new(&inline).is_inline());
+        assert!(!InternedStr::new(&interned).is_inline());
+        assert_eq!(InternedStr::new(&interned).as_str(), interned);
+        assert_ne!(InternedStr::new(&inline), InternedStr::new(&interned));
+        assert_eq!(InternedStr::new("").as_str(), "");
+    }
+
+    #[test]
+    fn test_equal_long_values_share_allocation() {
+        let value1 = InternedStr::new("O-20200814-102234-001-001-1");
+        let value2 = InternedStr::new("O-20200814-102234-001-001-1");
+
+        assert_eq!(value1, value2);
+        assert_eq!(value1.ptr, value2.ptr);
+    }
+
+    #[test]
+    fn test_clone_shares_allocation() {
+        let value = InternedStr::new("O-20200814-102234-001-001-1");
+
+        let cloned = value.clone();
+        drop(value);
+
+        assert_eq!(cloned.as_str(), "O-20200814-102234-001-001-1");
+        assert_eq!(cloned, InternedStr::new("O-20200814-102234-001-001-1"));
+    }
+
+    #[test]
+    fn test_value_reinterned_after_all_dropped() {
+        let value = InternedStr::new("interned-then-dropped");
+        drop(value);
+
+        let value = InternedStr::new("interned-then-dropped");
+
+        assert_eq!(value.as_str(), "interned-then-dropped");
+    }
+
+    #[test]
+    fn test_dropped_values_are_purged() {
+        let values: Vec<InternedStr> = (0..5_000)
+            .map(|i| InternedStr::new(&format!("T-PURGE-INTERNED-VALUE-{}", i)))
+            .collect();
+        drop(values);
+
+        let values: Vec<InternedStr> = (0..5_000)
+            .map(|i| InternedStr::new(&format!("T-PURGE-INTERNED-AGAIN-{}", i)))
+            .collect();
+
+        // Table is bounded by live values (plus those from concurrent tests)
+        assert!(super::interned_count() < 9_000);
+        assert_eq!(values[4_999].as_str(), "T-PURGE-INTERNED-AGAIN-4999");
+    }
+
+    #[test]
+    fn test_precomputed_hash() {
+        let value1 = InternedStr::new("HASHED-001");
+        let value2 = InternedStr::new("HASHED-002");
+        let mut hasher = DefaultHasher::new();
+        value1.hash(&mut hasher);
+
+        assert_eq!(
+            value1.precomputed_hash(),
+            InternedStr::new("HASHED-001").precomputed_hash()
+        );
+        assert_ne!(value1.precomputed_hash(), value2.precomputed_hash());
+        assert_eq!(hasher.finish(), {
+            let mut hasher = DefaultHasher::new();
+            hasher.write_u64(value1.precomputed_hash());
+            hasher.finish()
+        });
+    }
+
+    #[test]
+    fn test_precomputed_hash_matches_across_representations() {
+        let inline = InternedStr::new("BINANCE");
+        let interned = InternedStr::new("O-20200814-102234-001-001-1");
+
+        assert_eq!(inline.precomputed_hash(), super::hash_str("BINANCE"));
+        assert_eq!(inline.clone().precomputed_hash(), inline.precomputed_hash());
+        assert_eq!(
+            interned.precomputed_hash(),
+            super::hash_str("O-20200814-102234-001-001-1")
+        );
+    }
+
+    #[test]
+    fn test_ordering_is_lexicographic() {
+        let value1 = InternedStr::new("BINANCE");
+        let value2 = InternedStr::new("FTX");
+
+        assert_eq!(value1.cmp(&value1.clone()), Ordering::Equal);
+        assert!(value1 < value2);
+        assert!(InternedStr::new("FTX-2") > value2);
+        assert!(InternedStr::new("O-20200814-102234-001-001-1") > value1);
+        assert!(InternedStr::new("O-20200814-102234-001-001-1") < InternedStr::new("P"));
+    }
+}
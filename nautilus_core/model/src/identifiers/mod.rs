@@ -14,16 +14,458 @@
 // -------------------------------------------------------------------------------------------------
 
 pub mod account_id;
+pub mod account_registry;
 pub mod client_id;
 pub mod client_order_id;
 pub mod client_order_link_id;
 pub mod component_id;
+pub mod exec_algorithm_id;
+pub mod generators;
+pub mod handle;
 pub mod instrument_id;
+pub mod interned;
 pub mod order_list_id;
 pub mod position_id;
+#[cfg(feature = "serde")]
+mod serde;
 pub mod strategy_id;
+pub mod subscription_pattern;
 pub mod symbol;
+pub mod symbol_normalize;
 pub mod trade_id;
 pub mod trader_id;
 pub mod venue;
 pub mod venue_order_id;
+
+use std::borrow::Cow;
+use std::error::Error;
+use std::ffi::c_char;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use nautilus_core::cvec::CVec;
+use pyo3::types::{PyList, PyString};
+use pyo3::{ffi, FromPyPointer, IntoPyPointer, Py, Python};
+
+/// The maximum length (bytes) of an identifier value.
+pub const IDENTIFIER_MAX_LEN: usize = 255;
+
+/// Represents an identifier validation failure.
+///
+/// The discriminants are the error codes returned by the C API `try_from`
+/// functions, where zero indicates success.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum IdentifierError {
+    /// The value was empty or only whitespace.
+    Empty = 1,
+    /// The value exceeded the maximum length for the identifier type
+    /// (`IDENTIFIER_MAX_LEN` bytes unless otherwise documented).
+    TooLong = 2,
+    /// The value contained control characters (including NUL) or leading or
+    /// trailing whitespace.
+    InvalidChars = 3,
+    /// The value was not in the expected format (e.g. "SYMBOL.VENUE").
+    InvalidFormat = 4,
+}
+
+impl Display for IdentifierError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IdentifierError::Empty => write!(f, "identifier value was empty"),
+            IdentifierError::TooLong => write!(f, "identifier value exceeded the maximum length"),
+            IdentifierError::InvalidChars => write!(f, "identifier value had invalid chars"),
+            IdentifierError::InvalidFormat => write!(f, "identifier value had invalid format"),
+        }
+    }
+}
+
+impl Error for IdentifierError {}
+
+/// Checks the given value is valid for an identifier.
+pub fn validate_identifier(s: &str) -> std::result::Result<(), IdentifierError> {
+    if s.trim().is_empty() {
+        return Err(IdentifierError::Empty);
+    }
+    if s.len() > IDENTIFIER_MAX_LEN {
+        return Err(IdentifierError::TooLong);
+    }
+    if s.chars().any(char::is_control) || s.trim() != s {
+        return Err(IdentifierError::InvalidChars);
+    }
+    Ok(())
+}
+
+/// Checks the given value is a valid "NAME-TAG" identifier, with a non-empty
+/// name and tag either side of the last hyphen.
+pub fn validate_name_tag(s: &str) -> std::result::Result<(), IdentifierError> {
+    validate_identifier(s)?;
+    match s.rsplit_once('-') {
+        Some((name, tag)) if !name.is_empty() && !tag.is_empty() => Ok(()),
+        _ => Err(IdentifierError::InvalidFormat),
+    }
+}
+
+/// Returns the (name, tag) parts either side of the last hyphen, where a value
+/// without a hyphen is both its own name and tag.
+pub(crate) fn split_name_tag(s: &str) -> (&str, &str) {
+    s.rsplit_once('-').unwrap_or((s, s))
+}
+
+/// Returns the canonical (uppercased) form of an identifier value, borrowing
+/// the value if already canonical.
+pub fn canonicalize(s: &str) -> Cow<'_, str> {
+    if s.chars().all(|c| c.to_uppercase().eq(std::iter::once(c))) {
+        Cow::Borrowed(s)
+    } else {
+        Cow::Owned(s.to_uppercase())
+    }
+}
+
+/// Returns whether the values are equal in their canonical forms, without
+/// allocating.
+pub fn eq_ignore_case(a: &str, b: &str) -> bool {
+    a == b
+        || a.chars()
+            .flat_map(char::to_uppercase)
+            .eq(b.chars().flat_map(char::to_uppercase))
+}
+
+/// Represents a borrowed view of an identifier value for C consumers.
+///
+/// The bytes are UTF-8 and not NUL terminated, and remain valid for the
+/// lifetime of the identifier they were taken from.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct StrView {
+    pub ptr: *const c_char,
+    pub len: usize,
+}
+
+impl StrView {
+    /// Returns the viewed string.
+    ///
+    /// # Safety
+    ///
+    /// - The identifier the view was taken from must still be alive.
+    pub unsafe fn as_str<'a>(&self) -> &'a str {
+        let bytes = std::slice::from_raw_parts(self.ptr as *const u8, self.len);
+        std::str::from_utf8_unchecked(bytes)
+    }
+}
+
+pub(crate) fn str_view(s: &str) -> StrView {
+    StrView {
+        ptr: s.as_ptr() as *const c_char,
+        len: s.len(),
+    }
+}
+
+/// Parses the value and writes the identifier to `out`, returning zero on
+/// success or the `IdentifierError` code.
+///
+/// # Safety
+///
+/// - `out` must be a valid pointer to uninitialized memory to write the
+///   identifier to, as any value already there is overwritten without being
+///   dropped (leaking it).
+pub(crate) unsafe fn try_write_identifier<T: FromStr<Err = IdentifierError>>(
+    s: &str,
+    out: *mut T,
+) -> u8 {
+    match s.parse::<T>() {
+        Ok(identifier) => {
+            out.write(identifier);
+            0
+        }
+        Err(e) => e as u8,
+    }
+}
+
+/// Returns the identifiers converted from an array of Python `str` pointers
+/// (e.g. the items of a list), acquiring the GIL once for the whole batch.
+///
+/// # Safety
+///
+/// - `ptrs` must point to `len` pointers borrowed from valid Python `str`s.
+pub(crate) unsafe fn identifiers_from_pystrs<T>(
+    ptrs: *const *mut ffi::PyObject,
+    len: usize,
+    new: fn(&str) -> T,
+) -> CVec {
+    if len == 0 {
+        return CVec::empty();
+    }
+    let ptrs = std::slice::from_raw_parts(ptrs, len);
+    Python::with_gil(|py| {
+        let identifiers: Vec<T> = ptrs
+            .iter()
+            .map(|ptr| new(&PyString::from_borrowed_ptr(py, *ptr).to_string_lossy()))
+            .collect();
+        identifiers.into()
+    })
+}
+
+/// Returns a pointer to a new Python list of the identifier strings.
+///
+/// # Safety
+///
+/// - Assumes that since the data is originating from Rust, the GIL does not need
+///   to be acquired.
+/// - Assumes you are immediately returning this pointer to Python.
+pub(crate) unsafe fn identifiers_to_pylist<T: Display>(identifiers: &[T]) -> *mut ffi::PyObject {
+    let py = Python::assume_gil_acquired();
+    let items = identifiers
+        .iter()
+        .map(|identifier| PyString::new(py, &identifier.to_string()));
+    let list: Py<PyList> = PyList::new(py, items).into();
+    list.into_ptr()
+}
+
+/// Implements the shared behavior of an identifier newtype over a `value:
+/// InternedStr` field: `new` (interning without validation), `as_str`,
+/// `precomputed_hash`, `FromStr` and `TryFrom<&str>` (validating with the
+/// given function, by default `validate_identifier`) and `Display`.
+///
+/// The C API is generated with the given function names (`free`,
+/// `from_pystr`, `try_from_pystr`, `to_pystr`, `as_bytes` and `hash`), which
+/// cbindgen sees as it expands the crate before generating the headers.
+macro_rules! impl_identifier {
+    ($ty:ident, $c_api:tt) => {
+        impl_identifier!($ty, $crate::identifiers::validate_identifier, $c_api);
+    };
+    (
+        $ty:ident,
+        $validate:path,
+        {
+            free: $free:ident,
+            from_pystr: $from_pystr:ident,
+            try_from_pystr: $try_from_pystr:ident,
+            to_pystr: $to_pystr:ident,
+            as_bytes: $as_bytes:ident,
+            hash: $hash:ident $(,)?
+        }
+    ) => {
+        impl $ty {
+            /// Returns an identifier interning the value without validation.
+            pub fn new(value: &str) -> Self {
+                $ty {
+                    value: $crate::identifiers::interned::InternedStr::new(value),
+                }
+            }
+
+            pub fn as_str(&self) -> &str {
+                self.value.as_str()
+            }
+
+            /// Returns the hash of the value, computed when it was interned.
+            pub fn precomputed_hash(&self) -> u64 {
+                self.value.precomputed_hash()
+            }
+        }
+
+        impl std::str::FromStr for $ty {
+            type Err = $crate::identifiers::IdentifierError;
+
+            fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+                $validate(s)?;
+                Ok($ty::new(s))
+            }
+        }
+
+        impl TryFrom<&str> for $ty {
+            type Error = $crate::identifiers::IdentifierError;
+
+            fn try_from(s: &str) -> std::result::Result<Self, Self::Error> {
+                s.parse()
+            }
+        }
+
+        impl std::fmt::Display for $ty {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}", self.value)
+            }
+        }
+
+        #[no_mangle]
+        pub extern "C" fn $free(identifier: $ty) {
+            drop(identifier); // Memory freed here
+        }
+
+        /// Returns a Nautilus identifier from a valid Python object pointer.
+        ///
+        /// # Safety
+        ///
+        /// - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+        #[no_mangle]
+        pub unsafe extern "C" fn $from_pystr(ptr: *mut pyo3::ffi::PyObject) -> $ty {
+            $ty::new(nautilus_core::string::pystr_to_string(ptr).as_str())
+        }
+
+        /// Returns zero and writes a Nautilus identifier to `out` from a valid Python
+        /// object pointer, otherwise returns the non-zero `IdentifierError` code.
+        ///
+        /// # Safety
+        ///
+        /// - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+        /// - `out` must be a valid pointer to uninitialized memory to write the
+        ///   identifier to, as any value already there is not dropped.
+        #[no_mangle]
+        pub unsafe extern "C" fn $try_from_pystr(
+            ptr: *mut pyo3::ffi::PyObject,
+            out: *mut $ty,
+        ) -> u8 {
+            $crate::identifiers::try_write_identifier(
+                &nautilus_core::string::pystr_to_string(ptr),
+                out,
+            )
+        }
+
+        /// Returns a pointer to a valid Python UTF-8 string.
+        ///
+        /// # Safety
+        ///
+        /// - Assumes that since the data is originating from Rust, the GIL does not need
+        ///   to be acquired.
+        /// - Assumes you are immediately returning this pointer to Python.
+        #[no_mangle]
+        pub unsafe extern "C" fn $to_pystr(identifier: &$ty) -> *mut pyo3::ffi::PyObject {
+            nautilus_core::string::string_to_pystr(identifier.value.as_str())
+        }
+
+        /// Returns a view of the value bytes (UTF-8, not NUL terminated), valid for
+        /// the lifetime of the identifier, without allocating.
+        #[no_mangle]
+        pub extern "C" fn $as_bytes(identifier: &$ty) -> $crate::identifiers::StrView {
+            $crate::identifiers::str_view(identifier.value.as_str())
+        }
+
+        /// Returns the hash of the value, precomputed when interned, for the Python
+        /// `__hash__`.
+        #[no_mangle]
+        pub extern "C" fn $hash(identifier: &$ty) -> u64 {
+            identifier.value.precomputed_hash()
+        }
+    };
+}
+
+pub(crate) use impl_identifier;
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+    use std::collections::BTreeMap;
+
+    use super::account_id::AccountId;
+    use super::venue::Venue;
+    use super::{validate_identifier, IdentifierError, IDENTIFIER_MAX_LEN};
+
+    #[test]
+    fn test_validate_identifier_valid() {
+        assert_eq!(validate_identifier("AUD/USD"), Ok(()));
+        assert_eq!(validate_identifier("AAPL 3.45 02/09/29"), Ok(()));
+        assert_eq!(validate_identifier(&"A".repeat(IDENTIFIER_MAX_LEN)), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_identifier_empty() {
+        assert_eq!(validate_identifier(""), Err(IdentifierError::Empty));
+        assert_eq!(validate_identifier("  \t"), Err(IdentifierError::Empty));
+    }
+
+    #[test]
+    fn test_validate_identifier_too_long() {
+        let value = "A".repeat(IDENTIFIER_MAX_LEN + 1);
+
+        assert_eq!(validate_identifier(&value), Err(IdentifierError::TooLong));
+    }
+
+    #[test]
+    fn test_validate_identifier_invalid_chars() {
+        assert_eq!(
+            validate_identifier("ABC\0DEF"),
+            Err(IdentifierError::InvalidChars)
+        );
+        assert_eq!(
+            validate_identifier("ABC\n"),
+            Err(IdentifierError::InvalidChars)
+        );
+        assert_eq!(
+            validate_identifier(" ABC"),
+            Err(IdentifierError::InvalidChars)
+        );
+    }
+
+    #[test]
+    fn test_validate_name_tag() {
+        assert_eq!(super::validate_name_tag("EMACross-001"), Ok(()));
+        assert_eq!(super::validate_name_tag("EMA-Cross-001"), Ok(()));
+        assert_eq!(
+            super::validate_name_tag("EMACross"),
+            Err(IdentifierError::InvalidFormat)
+        );
+        assert_eq!(
+            super::validate_name_tag("EMACross-"),
+            Err(IdentifierError::InvalidFormat)
+        );
+        assert_eq!(
+            super::validate_name_tag("-001"),
+            Err(IdentifierError::InvalidFormat)
+        );
+        assert_eq!(super::validate_name_tag(""), Err(IdentifierError::Empty));
+    }
+
+    #[test]
+    fn test_canonicalize() {
+        assert!(matches!(
+            super::canonicalize("BINANCE"),
+            Cow::Borrowed("BINANCE")
+        ));
+        assert_eq!(super::canonicalize("Binance"), "BINANCE");
+        assert_eq!(super::canonicalize("eth-perp"), "ETH-PERP");
+        assert_eq!(super::canonicalize("straße"), "STRASSE");
+    }
+
+    #[test]
+    fn test_eq_ignore_case() {
+        assert!(super::eq_ignore_case("Binance", "BINANCE"));
+        assert!(super::eq_ignore_case("straße", "STRASSE"));
+        assert!(!super::eq_ignore_case("BINANCE", "BINANCEUS"));
+        assert!(!super::eq_ignore_case("FTX", "FTY"));
+    }
+
+    #[test]
+    fn test_identifier_error_display() {
+        assert_eq!(
+            IdentifierError::TooLong.to_string(),
+            "identifier value exceeded the maximum length"
+        );
+    }
+
+    #[test]
+    fn test_identifier_try_from_validates() {
+        assert_eq!(Venue::try_from("BINANCE"), Ok(Venue::new("BINANCE")));
+        assert_eq!(Venue::try_from(""), Err(IdentifierError::Empty));
+        assert_eq!(
+            AccountId::try_from("SIM-001\n"),
+            Err(IdentifierError::InvalidChars)
+        );
+    }
+
+    #[test]
+    fn test_identifiers_as_btree_map_keys() {
+        let map = BTreeMap::from([
+            (Venue::new("SIM"), AccountId::new("SIM-001")),
+            (Venue::new("BINANCE"), AccountId::new("BINANCE-001")),
+            (Venue::new("FTX"), AccountId::new("FTX-001")),
+        ]);
+
+        let venues: Vec<String> = map.keys().map(|v| v.to_string()).collect();
+
+        assert_eq!(venues, ["BINANCE", "FTX", "SIM"]);
+        assert!(AccountId::new("FTX-001") < AccountId::new("SIM-001"));
+    }
+}
@@ -13,62 +13,41 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use nautilus_core::string::pystr_to_string;
-use pyo3::ffi;
-use std::fmt::{Debug, Display, Formatter, Result};
+use crate::identifiers::impl_identifier;
+use crate::identifiers::interned::InternedStr;
+use std::fmt::Debug;
 
 #[repr(C)]
-#[derive(Clone, Hash, PartialEq, Debug)]
-#[allow(clippy::box_collection)] // C ABI compatibility
+#[derive(Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
 pub struct OrderListId {
-    value: Box<String>,
+    value: InternedStr,
 }
 
-impl From<&str> for OrderListId {
-    fn from(s: &str) -> OrderListId {
-        OrderListId {
-            value: Box::new(s.to_string()),
-        }
+impl_identifier!(
+    OrderListId,
+    {
+        free: order_list_id_free,
+        from_pystr: order_list_id_from_pystr,
+        try_from_pystr: order_list_id_try_from_pystr,
+        to_pystr: order_list_id_to_pystr,
+        as_bytes: order_list_id_as_bytes,
+        hash: order_list_id_hash,
     }
-}
-
-impl Display for OrderListId {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        write!(f, "{}", self.value)
-    }
-}
-
-////////////////////////////////////////////////////////////////////////////////
-// C API
-////////////////////////////////////////////////////////////////////////////////
-#[no_mangle]
-pub extern "C" fn order_list_id_free(order_list_id: OrderListId) {
-    drop(order_list_id); // Memory freed here
-}
-
-/// Returns a Nautilus identifier from a valid Python object pointer.
-///
-/// # Safety
-///
-/// - `ptr` must be borrowed from a valid Python UTF-8 `str`.
-#[no_mangle]
-pub unsafe extern "C" fn order_list_id_from_pystr(ptr: *mut ffi::PyObject) -> OrderListId {
-    OrderListId {
-        value: Box::new(pystr_to_string(ptr)),
-    }
-}
+);
 
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
-    use super::OrderListId;
+    use super::{order_list_id_to_pystr, OrderListId};
+    use nautilus_core::string::pystr_to_string;
+    use pyo3::{prepare_freethreaded_python, Python};
 
     #[test]
     fn test_order_list_id_from_str() {
-        let order_list_id1 = OrderListId::from("RiskEngine");
-        let order_list_id2 = OrderListId::from("DataEngine");
+        let order_list_id1 = OrderListId::new("RiskEngine");
+        let order_list_id2 = OrderListId::new("DataEngine");
 
         assert_eq!(order_list_id1, order_list_id1);
         assert_ne!(order_list_id1, order_list_id2);
@@ -77,8 +56,33 @@ mod tests {
 
     #[test]
     fn test_order_list_id_as_str() {
-        let order_list_id = OrderListId::from("RiskEngine");
+        let order_list_id = OrderListId::new("RiskEngine");
 
         assert_eq!(order_list_id.to_string(), "RiskEngine");
     }
+
+    #[test]
+    fn test_order_list_id_try_from_str() {
+        assert!("".parse::<OrderListId>().is_err());
+        assert_eq!(
+            "ABC\0".parse::<OrderListId>(),
+            Err(crate::identifiers::IdentifierError::InvalidChars)
+        );
+        assert_eq!(
+            "ABC-123".parse::<OrderListId>(),
+            Ok(OrderListId::new("ABC-123"))
+        );
+    }
+
+    #[test]
+    fn test_order_list_id_to_pystr() {
+        prepare_freethreaded_python();
+        let gil = Python::acquire_gil();
+        let _py = gil.python();
+        let order_list_id = OrderListId::new("OL-20200814-001-002-1");
+
+        let ptr = unsafe { order_list_id_to_pystr(&order_list_id) };
+
+        assert_eq!(unsafe { pystr_to_string(ptr) }, "OL-20200814-001-002-1");
+    }
 }
@@ -13,50 +13,27 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use nautilus_core::string::pystr_to_string;
-use pyo3::ffi;
-use std::fmt::{Debug, Display, Formatter, Result};
+use crate::identifiers::impl_identifier;
+use crate::identifiers::interned::InternedStr;
+use std::fmt::Debug;
 
 #[repr(C)]
-#[derive(Clone, Hash, PartialEq, Debug)]
-#[allow(clippy::box_collection)] // C ABI compatibility
+#[derive(Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
 pub struct PositionId {
-    value: Box<String>,
+    value: InternedStr,
 }
 
-impl From<&str> for PositionId {
-    fn from(s: &str) -> PositionId {
-        PositionId {
-            value: Box::new(s.to_string()),
-        }
+impl_identifier!(
+    PositionId,
+    {
+        free: position_id_free,
+        from_pystr: position_id_from_pystr,
+        try_from_pystr: position_id_try_from_pystr,
+        to_pystr: position_id_to_pystr,
+        as_bytes: position_id_as_bytes,
+        hash: position_id_hash,
     }
-}
-
-impl Display for PositionId {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        write!(f, "{}", self.value)
-    }
-}
-
-////////////////////////////////////////////////////////////////////////////////
-// C API
-////////////////////////////////////////////////////////////////////////////////
-#[no_mangle]
-pub extern "C" fn position_id_free(position_id: PositionId) {
-    drop(position_id); // Memory freed here
-}
-
-/// Returns a Nautilus identifier from a valid Python object pointer.
-///
-/// # Safety
-///
-/// - `ptr` must be borrowed from a valid Python UTF-8 `str`.
-#[no_mangle]
-pub unsafe extern "C" fn position_id_from_pystr(ptr: *mut ffi::PyObject) -> PositionId {
-    PositionId {
-        value: Box::new(pystr_to_string(ptr)),
-    }
-}
+);
 
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
@@ -67,8 +44,8 @@ mod tests {
 
     #[test]
     fn test_position_id_from_str() {
-        let position_id1 = PositionId::from("ETHUSDT.BINANCE-EMACross-001");
-        let position_id2 = PositionId::from("BTCUSDT.BINANCE-EMACross-002");
+        let position_id1 = PositionId::new("ETHUSDT.BINANCE-EMACross-001");
+        let position_id2 = PositionId::new("BTCUSDT.BINANCE-EMACross-002");
 
         assert_eq!(position_id1, position_id1);
         assert_ne!(position_id1, position_id2);
@@ -77,8 +54,21 @@ mod tests {
 
     #[test]
     fn test_position_id_as_str() {
-        let position_id = PositionId::from("ETHUSDT.BINANCE-EMACross-001");
+        let position_id = PositionId::new("ETHUSDT.BINANCE-EMACross-001");
 
         assert_eq!(position_id.to_string(), "ETHUSDT.BINANCE-EMACross-001");
     }
+
+    #[test]
+    fn test_position_id_try_from_str() {
+        assert!("".parse::<PositionId>().is_err());
+        assert_eq!(
+            "ABC\0".parse::<PositionId>(),
+            Err(crate::identifiers::IdentifierError::InvalidChars)
+        );
+        assert_eq!(
+            "ABC-123".parse::<PositionId>(),
+            Ok(PositionId::new("ABC-123"))
+        );
+    }
 }
@@ -0,0 +1,154 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Transparent string (de)serialization for identifiers.
+//!
+//! Deserialization applies the same validation as `FromStr`.
+
+use std::str::FromStr;
+
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::identifiers::account_id::AccountId;
+use crate::identifiers::client_id::ClientId;
+use crate::identifiers::client_order_id::ClientOrderId;
+use crate::identifiers::client_order_link_id::ClientOrderLinkId;
+use crate::identifiers::component_id::ComponentId;
+use crate::identifiers::exec_algorithm_id::ExecAlgorithmId;
+use crate::identifiers::instrument_id::InstrumentId;
+use crate::identifiers::order_list_id::OrderListId;
+use crate::identifiers::position_id::PositionId;
+use crate::identifiers::strategy_id::StrategyId;
+use crate::identifiers::symbol::Symbol;
+use crate::identifiers::trade_id::TradeId;
+use crate::identifiers::trader_id::TraderId;
+use crate::identifiers::venue::Venue;
+use crate::identifiers::venue_order_id::VenueOrderId;
+
+macro_rules! impl_serde_identifier {
+    ($($ty:ty),*) => {$(
+        impl Serialize for $ty {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_str(self)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $ty {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let value = String::deserialize(deserializer)?;
+                <$ty>::from_str(&value).map_err(D::Error::custom)
+            }
+        }
+    )*};
+}
+
+impl_serde_identifier!(
+    AccountId,
+    ClientId,
+    ClientOrderId,
+    ClientOrderLinkId,
+    ComponentId,
+    ExecAlgorithmId,
+    InstrumentId,
+    OrderListId,
+    PositionId,
+    StrategyId,
+    Symbol,
+    TradeId,
+    TraderId,
+    Venue,
+    VenueOrderId
+);
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::fmt::Debug;
+
+    use rstest::rstest;
+    use serde::de::DeserializeOwned;
+    use serde::Serialize;
+
+    use super::*;
+
+    fn assert_round_trip<T>(identifier: T, value: &str)
+    where
+        T: Serialize + DeserializeOwned + PartialEq + Debug,
+    {
+        let json = serde_json::to_string(&identifier).unwrap();
+        assert_eq!(json, format!("\"{}\"", value));
+        assert_eq!(serde_json::from_str::<T>(&json).unwrap(), identifier);
+
+        let msgpack = rmp_serde::to_vec(&identifier).unwrap();
+        assert_eq!(rmp_serde::from_slice::<T>(&msgpack).unwrap(), identifier);
+    }
+
+    #[rstest(
+        value,
+        case("SIM-001"),
+        case("BINANCE-001"),
+        case("O-20210410-022422-001-001-1")
+    )]
+    fn test_simple_identifiers_round_trip(value: &str) {
+        assert_round_trip(AccountId::new(value), value);
+        assert_round_trip(ClientId::new(value), value);
+        assert_round_trip(ClientOrderId::new(value), value);
+        assert_round_trip(ClientOrderLinkId::new(value), value);
+        assert_round_trip(ComponentId::new(value), value);
+        assert_round_trip(ExecAlgorithmId::new(value), value);
+        assert_round_trip(OrderListId::new(value), value);
+        assert_round_trip(PositionId::new(value), value);
+        assert_round_trip(StrategyId::new(value), value);
+        assert_round_trip(Symbol::new(value), value);
+        assert_round_trip(TradeId::new(value), value);
+        assert_round_trip(TraderId::new(value), value);
+        assert_round_trip(Venue::new(value), value);
+        assert_round_trip(VenueOrderId::new(value), value);
+    }
+
+    #[test]
+    fn test_instrument_id_round_trip() {
+        assert_round_trip(InstrumentId::from_dotted("BRK.B.NYSE"), "BRK.B.NYSE");
+    }
+
+    #[test]
+    fn test_identifiers_as_map_values() {
+        let accounts = HashMap::from([("SIM".to_string(), AccountId::new("SIM-001"))]);
+
+        let json = serde_json::to_string(&accounts).unwrap();
+
+        assert_eq!(json, "{\"SIM\":\"SIM-001\"}");
+        assert_eq!(
+            serde_json::from_str::<HashMap<String, AccountId>>(&json).unwrap(),
+            accounts
+        );
+    }
+
+    #[test]
+    fn test_deserialize_invalid_identifier_returns_error() {
+        let result = serde_json::from_str::<Venue>("\"\"");
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "identifier value was empty"
+        );
+        assert!(serde_json::from_str::<InstrumentId>("\"ETH/USDT\"").is_err());
+        assert!(serde_json::from_str::<Venue>("123").is_err());
+    }
+}
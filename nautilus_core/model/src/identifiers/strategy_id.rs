@@ -13,49 +13,81 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use nautilus_core::string::pystr_to_string;
+use crate::identifiers::interned::InternedStr;
+use crate::identifiers::{impl_identifier, split_name_tag, validate_name_tag, IdentifierError};
+use nautilus_core::string::string_to_pystr;
 use pyo3::ffi;
-use std::fmt::{Debug, Display, Formatter, Result};
+use std::fmt::Debug;
+
+/// The strategy ID for orders and positions from outside the system.
+pub const EXTERNAL_STRATEGY_ID: &str = "EXTERNAL";
 
 #[repr(C)]
-#[derive(Clone, Hash, PartialEq, Debug)]
-#[allow(clippy::box_collection)] // C ABI compatibility
+#[derive(Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
 pub struct StrategyId {
-    value: Box<String>,
+    value: InternedStr,
 }
 
-impl From<&str> for StrategyId {
-    fn from(s: &str) -> StrategyId {
-        StrategyId {
-            value: Box::new(s.to_string()),
-        }
+impl_identifier!(
+    StrategyId,
+    validate_strategy_id,
+    {
+        free: strategy_id_free,
+        from_pystr: strategy_id_from_pystr,
+        try_from_pystr: strategy_id_try_from_pystr,
+        to_pystr: strategy_id_to_pystr,
+        as_bytes: strategy_id_as_bytes,
+        hash: strategy_id_hash,
+    }
+);
+
+fn validate_strategy_id(s: &str) -> std::result::Result<(), IdentifierError> {
+    if s != EXTERNAL_STRATEGY_ID {
+        validate_name_tag(s)?;
     }
+    Ok(())
 }
 
-impl Display for StrategyId {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        write!(f, "{}", self.value)
+impl StrategyId {
+    /// Returns the name part preceding the last hyphen.
+    pub fn name(&self) -> &str {
+        split_name_tag(self.value.as_str()).0
+    }
+
+    /// Returns the order ID tag part following the last hyphen.
+    pub fn tag(&self) -> &str {
+        split_name_tag(self.value.as_str()).1
+    }
+
+    pub fn is_external(&self) -> bool {
+        self.value.as_str() == EXTERNAL_STRATEGY_ID
     }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
 // C API
-////////////////////////////////////////////////////////////////////////////////
+/// Returns a pointer to a valid Python UTF-8 string of the name part.
+///
+/// # Safety
+///
+/// - Assumes that since the data is originating from Rust, the GIL does not need
+///   to be acquired.
+/// - Assumes you are immediately returning this pointer to Python.
 #[no_mangle]
-pub extern "C" fn strategy_id_free(strategy_id: StrategyId) {
-    drop(strategy_id); // Memory freed here
+pub unsafe extern "C" fn strategy_id_name_to_pystr(strategy_id: &StrategyId) -> *mut ffi::PyObject {
+    string_to_pystr(strategy_id.name())
 }
 
-/// Returns a Nautilus identifier from a valid Python object pointer.
+/// Returns a pointer to a valid Python UTF-8 string of the tag part.
 ///
 /// # Safety
 ///
-/// - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+/// - Assumes that since the data is originating from Rust, the GIL does not need
+///   to be acquired.
+/// - Assumes you are immediately returning this pointer to Python.
 #[no_mangle]
-pub unsafe extern "C" fn strategy_id_from_pystr(ptr: *mut ffi::PyObject) -> StrategyId {
-    StrategyId {
-        value: Box::new(pystr_to_string(ptr)),
-    }
+pub unsafe extern "C" fn strategy_id_tag_to_pystr(strategy_id: &StrategyId) -> *mut ffi::PyObject {
+    string_to_pystr(strategy_id.tag())
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -63,12 +95,15 @@ pub unsafe extern "C" fn strategy_id_from_pystr(ptr: *mut ffi::PyObject) -> Stra
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
-    use super::StrategyId;
+    use super::{strategy_id_tag_to_pystr, StrategyId};
+    use crate::identifiers::IdentifierError;
+    use nautilus_core::string::pystr_to_string;
+    use pyo3::{prepare_freethreaded_python, Python};
 
     #[test]
     fn test_strategy_id_from_str() {
-        let strategy_id1 = StrategyId::from("EMACross-001");
-        let strategy_id2 = StrategyId::from("EMACross-002");
+        let strategy_id1 = StrategyId::new("EMACross-001");
+        let strategy_id2 = StrategyId::new("EMACross-002");
 
         assert_eq!(strategy_id1, strategy_id1);
         assert_ne!(strategy_id1, strategy_id2);
@@ -77,8 +112,57 @@ mod tests {
 
     #[test]
     fn test_strategy_id_as_str() {
-        let strategy_id = StrategyId::from("EMACross-001");
+        let strategy_id = StrategyId::new("EMACross-001");
 
         assert_eq!(strategy_id.to_string(), "EMACross-001");
     }
+
+    #[test]
+    fn test_strategy_id_try_from_str() {
+        assert!("".parse::<StrategyId>().is_err());
+        assert_eq!(
+            "ABC\0".parse::<StrategyId>(),
+            Err(crate::identifiers::IdentifierError::InvalidChars)
+        );
+        assert_eq!(
+            "ABC-123".parse::<StrategyId>(),
+            Ok(StrategyId::new("ABC-123"))
+        );
+    }
+
+    #[test]
+    fn test_strategy_id_name_and_tag() {
+        let strategy_id = StrategyId::new("EMACross-001");
+
+        assert_eq!(strategy_id.name(), "EMACross");
+        assert_eq!(strategy_id.tag(), "001");
+    }
+
+    #[test]
+    fn test_strategy_id_parse_requires_name_tag() {
+        assert_eq!(
+            "EMACross".parse::<StrategyId>(),
+            Err(IdentifierError::InvalidFormat)
+        );
+    }
+
+    #[test]
+    fn test_strategy_id_tag_to_pystr() {
+        prepare_freethreaded_python();
+        let gil = Python::acquire_gil();
+        let _py = gil.python();
+        let strategy_id = StrategyId::new("EMACross-001");
+
+        let ptr = unsafe { strategy_id_tag_to_pystr(&strategy_id) };
+
+        assert_eq!(unsafe { pystr_to_string(ptr) }, "001");
+    }
+
+    #[test]
+    fn test_strategy_id_external() {
+        let strategy_id: StrategyId = "EXTERNAL".parse().unwrap();
+
+        assert!(strategy_id.is_external());
+        assert!(!StrategyId::new("EMACross-001").is_external());
+    }
 }
@@ -0,0 +1,225 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use crate::identifiers::instrument_id::InstrumentId;
+use crate::identifiers::IdentifierError;
+use nautilus_core::string::{pystr_to_string, string_to_pystr};
+use pyo3::ffi;
+use std::fmt::{Debug, Display, Formatter, Result};
+
+/// Represents a glob pattern over "SYMBOL.VENUE" instrument IDs, where `*`
+/// matches any sequence of chars and `?` matches any single char (e.g.
+/// "BTC*.BINANCE" or "*.FTX").
+#[repr(C)]
+#[derive(Clone, Hash, PartialEq, Eq)]
+pub struct SubscriptionPattern {
+    pub value: Box<String>,
+}
+
+impl SubscriptionPattern {
+    pub fn new(value: &str) -> std::result::Result<Self, IdentifierError> {
+        if value.is_empty() {
+            return Err(IdentifierError::Empty);
+        }
+        if value.chars().any(|c| c.is_control() || c.is_whitespace()) {
+            return Err(IdentifierError::InvalidChars);
+        }
+        Ok(SubscriptionPattern {
+            value: Box::new(value.to_string()),
+        })
+    }
+
+    pub fn as_str(&self) -> &str {
+        self.value.as_str()
+    }
+
+    /// Returns whether the instrument ID matches the pattern, without
+    /// allocating.
+    pub fn matches(&self, instrument_id: &InstrumentId) -> bool {
+        let symbol = instrument_id.symbol.as_str().as_bytes();
+        let venue = instrument_id.venue.as_str().as_bytes();
+
+        // A literal venue suffix is compared directly, leaving only the symbol
+        // for the glob (venues never contain a '.')
+        if let Some((symbol_pattern, venue_pattern)) = self.value.rsplit_once('.') {
+            if !is_wildcard(venue_pattern) {
+                return venue_pattern.as_bytes() == venue
+                    && glob_match(symbol_pattern.as_bytes(), symbol.len(), |i| symbol[i]);
+            }
+        }
+
+        glob_match(
+            self.value.as_bytes(),
+            symbol.len() + 1 + venue.len(),
+            |i| match i.cmp(&symbol.len()) {
+                std::cmp::Ordering::Less => symbol[i],
+                std::cmp::Ordering::Equal => b'.',
+                std::cmp::Ordering::Greater => venue[i - symbol.len() - 1],
+            },
+        )
+    }
+}
+
+fn is_wildcard(pattern: &str) -> bool {
+    pattern.contains(['*', '?'])
+}
+
+/// Matches the glob `pattern` against the `len` bytes given by `byte_at`,
+/// backtracking only to the last `*`.
+fn glob_match(pattern: &[u8], len: usize, byte_at: impl Fn(usize) -> u8) -> bool {
+    let (mut p, mut i) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+    while i < len {
+        match pattern.get(p) {
+            Some(b'*') => {
+                backtrack = Some((p, i));
+                p += 1;
+            }
+            Some(&c) if c == b'?' || c == byte_at(i) => {
+                p += 1;
+                i += 1;
+            }
+            _ => match backtrack {
+                Some((star, matched)) => {
+                    p = star + 1;
+                    i = matched + 1;
+                    backtrack = Some((star, i));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == b'*')
+}
+
+impl From<&str> for SubscriptionPattern {
+    fn from(value: &str) -> Self {
+        SubscriptionPattern::new(value)
+            .unwrap_or_else(|e| panic!("invalid `SubscriptionPattern` '{}': {}", value, e))
+    }
+}
+
+impl Debug for SubscriptionPattern {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{:?}", self.value)
+    }
+}
+
+impl Display for SubscriptionPattern {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// C API
+////////////////////////////////////////////////////////////////////////////////
+#[no_mangle]
+pub extern "C" fn subscription_pattern_free(pattern: SubscriptionPattern) {
+    drop(pattern); // Memory freed here
+}
+
+/// Returns a `SubscriptionPattern` from a valid Python object pointer.
+///
+/// # Safety
+///
+/// - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+#[no_mangle]
+pub unsafe extern "C" fn subscription_pattern_from_pystr(
+    ptr: *mut ffi::PyObject,
+) -> SubscriptionPattern {
+    SubscriptionPattern::from(pystr_to_string(ptr).as_str())
+}
+
+/// Returns a pointer to a valid Python UTF-8 string.
+///
+/// # Safety
+///
+/// - Assumes that since the data is originating from Rust, the GIL does not need
+///   to be acquired.
+/// - Assumes you are immediately returning this pointer to Python.
+#[no_mangle]
+pub unsafe extern "C" fn subscription_pattern_to_pystr(
+    pattern: &SubscriptionPattern,
+) -> *mut ffi::PyObject {
+    string_to_pystr(pattern.as_str())
+}
+
+#[no_mangle]
+pub extern "C" fn subscription_pattern_matches(
+    pattern: &SubscriptionPattern,
+    instrument_id: &InstrumentId,
+) -> u8 {
+    pattern.matches(instrument_id) as u8
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::SubscriptionPattern;
+    use crate::identifiers::instrument_id::InstrumentId;
+    use crate::identifiers::IdentifierError;
+
+    #[test]
+    fn test_matches() {
+        let cases = [
+            ("BTC*.BINANCE", "BTCUSDT.BINANCE", true),
+            ("BTC*.BINANCE", "BTCUSDT-PERP.BINANCE", true),
+            ("BTC*.BINANCE", "ETHUSDT.BINANCE", false),
+            ("BTC*.BINANCE", "BTCUSDT.FTX", false),
+            ("*.FTX", "BTC-PERP.FTX", true),
+            ("*.FTX", "BRK.B.FTX", true),
+            ("*.FTX", "BTC-PERP.FTXUS", false),
+            ("ETH/USD.*", "ETH/USD.FTX", true),
+            ("ETH/USD.*", "ETH/USDT.FTX", false),
+            ("*USD?.*", "ETH/USDT.FTX", true),
+            ("*", "AUD/USD.SIM", true),
+            ("*.*.NYSE", "BRK.B.NYSE", true),
+            ("*.*.NYSE", "AAPL.NYSE", false),
+            ("AUD/USD.SIM", "AUD/USD.SIM", true),
+            ("AUD/USD.SIM", "AUD/USD.SIM2", false),
+        ];
+
+        for (pattern, instrument_id, expected) in cases {
+            assert_eq!(
+                SubscriptionPattern::from(pattern)
+                    .matches(&InstrumentId::from_dotted(instrument_id)),
+                expected,
+                "{} matching {}",
+                pattern,
+                instrument_id
+            );
+        }
+    }
+
+    #[test]
+    fn test_new_with_invalid_values() {
+        assert_eq!(SubscriptionPattern::new(""), Err(IdentifierError::Empty));
+        assert_eq!(
+            SubscriptionPattern::new("BTC *.FTX"),
+            Err(IdentifierError::InvalidChars)
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let pattern = SubscriptionPattern::from("*.FTX");
+
+        assert_eq!(pattern.to_string(), "*.FTX");
+        assert_eq!(format!("{:?}", pattern), "\"*.FTX\"");
+    }
+}
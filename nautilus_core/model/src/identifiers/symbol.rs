@@ -13,61 +13,189 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use nautilus_core::string::{pystr_to_string, string_to_pystr};
+use crate::identifiers::interned::InternedStr;
+use crate::identifiers::{
+    canonicalize, eq_ignore_case, identifiers_from_pystrs, identifiers_to_pylist, impl_identifier,
+};
+use nautilus_core::cvec::CVec;
+use nautilus_core::string::pystr_to_string;
 use pyo3::ffi;
+use std::borrow::Cow;
 use std::fmt::{Debug, Display, Formatter, Result};
 
 #[repr(C)]
-#[derive(Clone, Hash, PartialEq, Debug)]
-#[allow(clippy::box_collection)] // C ABI compatibility
+#[derive(Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
 pub struct Symbol {
-    value: Box<String>,
+    value: InternedStr,
 }
 
-impl From<&str> for Symbol {
-    fn from(s: &str) -> Symbol {
-        Symbol {
-            value: Box::new(s.to_string()),
+impl_identifier!(
+    Symbol,
+    {
+        free: symbol_free,
+        from_pystr: symbol_from_pystr,
+        try_from_pystr: symbol_try_from_pystr,
+        to_pystr: symbol_to_pystr,
+        as_bytes: symbol_as_bytes,
+        hash: symbol_hash,
+    }
+);
+
+impl Symbol {
+    /// Returns the symbol with its value in canonical (uppercased) form, so feeds
+    /// disagreeing on case construct equal symbols.
+    pub fn canonical(value: &str) -> Self {
+        Symbol::new(canonicalize(value).as_ref())
+    }
+
+    /// Returns whether the value is in canonical (uppercased) form.
+    pub fn is_canonical(&self) -> bool {
+        matches!(canonicalize(self.as_str()), Cow::Borrowed(_))
+    }
+
+    /// Returns whether the symbols are equal ignoring case.
+    pub fn eq_ignore_case(&self, other: &Symbol) -> bool {
+        self == other || eq_ignore_case(self.as_str(), other.as_str())
+    }
+}
+
+/// The futures month codes, January to December.
+pub const FUTURES_MONTH_CODES: [char; 12] =
+    ['F', 'G', 'H', 'J', 'K', 'M', 'N', 'Q', 'U', 'V', 'X', 'Z'];
+
+/// Represents the parts of a futures contract code such as "ESZ3" or "CLF24".
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FuturesSymbol {
+    /// The product root (e.g. "ES").
+    pub root: String,
+    /// The delivery month code (e.g. 'Z').
+    pub month_code: char,
+    /// The year as written in the code, with `year_digits` digits (e.g. 3).
+    pub year: u16,
+    pub year_digits: u8,
+}
+
+impl FuturesSymbol {
+    /// Returns the delivery month (1 to 12).
+    pub fn month(&self) -> u8 {
+        let index = FUTURES_MONTH_CODES
+            .iter()
+            .position(|c| *c == self.month_code);
+        index.map_or(0, |i| i as u8 + 1)
+    }
+
+    /// Returns the earliest full delivery year on or after the reference year
+    /// which ends in the written year digits.
+    pub fn resolve_year(&self, reference_year: u16) -> u16 {
+        let modulus = 10u16.pow(u32::from(self.year_digits));
+        let base = reference_year - reference_year % modulus;
+        if base + self.year >= reference_year {
+            base + self.year
+        } else {
+            base + modulus + self.year
         }
     }
 }
 
-impl Display for Symbol {
+impl Display for FuturesSymbol {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        write!(f, "{}", self.value)
+        write!(
+            f,
+            "{}{}{:0width$}",
+            self.root,
+            self.month_code,
+            self.year,
+            width = self.year_digits as usize
+        )
+    }
+}
+
+impl Symbol {
+    /// Returns the root, month code and year of a futures contract code
+    /// (a root, month code and one or two year digits), or `None` if the
+    /// symbol is not in that form.
+    pub fn parse_futures(&self) -> Option<FuturesSymbol> {
+        let s = self.as_str();
+        let digits = s.bytes().rev().take_while(u8::is_ascii_digit).count();
+        if !(1..=2).contains(&digits) {
+            return None;
+        }
+        let (rest, year) = s.split_at(s.len() - digits);
+        let month_code = rest.chars().last()?;
+        let root = &rest[..rest.len() - month_code.len_utf8()];
+        if !FUTURES_MONTH_CODES.contains(&month_code)
+            || root.is_empty()
+            || !root.chars().all(|c| c.is_ascii_alphanumeric())
+        {
+            return None;
+        }
+        Some(FuturesSymbol {
+            root: root.to_string(),
+            month_code,
+            year: year.parse().ok()?,
+            year_digits: digits as u8,
+        })
     }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
 // C API
-////////////////////////////////////////////////////////////////////////////////
+/// Returns a Nautilus identifier from a valid Python object pointer, with the
+/// value in canonical (uppercased) form.
+///
+/// # Safety
+///
+/// - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+#[no_mangle]
+pub unsafe extern "C" fn symbol_canonical_from_pystr(ptr: *mut ffi::PyObject) -> Symbol {
+    Symbol::canonical(pystr_to_string(ptr).as_str())
+}
+
+#[no_mangle]
+pub extern "C" fn symbol_eq_ignore_case(symbol: &Symbol, other: &Symbol) -> u8 {
+    u8::from(symbol.eq_ignore_case(other))
+}
+
+/// Returns a clone of the identifier, e.g. an element of the vec returned by
+/// `symbols_from_pylist` for ownership by Python.
 #[no_mangle]
-pub extern "C" fn symbol_free(symbol: Symbol) {
-    drop(symbol); // Memory freed here
+pub extern "C" fn symbol_clone(symbol: &Symbol) -> Symbol {
+    symbol.clone()
 }
 
-/// Returns a Nautilus identifier from a valid Python object pointer.
+/// Returns a vec of Nautilus identifiers from an array of `len` valid Python
+/// object pointers (e.g. the items of a list), acquiring the GIL once for
+/// the batch. The vec must be passed to `symbols_drop` to free it.
 ///
 /// # Safety
 ///
-/// - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+/// - `ptrs` must point to `len` pointers borrowed from valid Python UTF-8 `str`s.
 #[no_mangle]
-pub unsafe extern "C" fn symbol_from_pystr(ptr: *mut ffi::PyObject) -> Symbol {
-    Symbol {
-        value: Box::new(pystr_to_string(ptr)),
-    }
+pub unsafe extern "C" fn symbols_from_pylist(ptrs: *const *mut ffi::PyObject, len: usize) -> CVec {
+    identifiers_from_pystrs(ptrs, len, Symbol::new)
 }
 
-/// Returns a pointer to a valid Python UTF-8 string.
+/// Returns a pointer to a new Python list of the identifier strings.
 ///
 /// # Safety
 ///
+/// - `symbols` must have been returned by `symbols_from_pylist`.
 /// - Assumes that since the data is originating from Rust, the GIL does not need
-/// to be acquired.
+///   to be acquired.
 /// - Assumes you are immediately returning this pointer to Python.
 #[no_mangle]
-pub unsafe extern "C" fn symbol_to_pystr(symbol: &Symbol) -> *mut ffi::PyObject {
-    string_to_pystr(symbol.value.as_str())
+pub unsafe extern "C" fn symbols_to_pylist(symbols: &CVec) -> *mut ffi::PyObject {
+    identifiers_to_pylist(symbols.as_slice::<Symbol>())
+}
+
+/// Drops a vec of identifiers returned by `symbols_from_pylist`.
+///
+/// # Safety
+///
+/// - `symbols` must have been returned by `symbols_from_pylist`.
+#[no_mangle]
+pub unsafe extern "C" fn symbols_drop(symbols: CVec) {
+    drop(symbols.into_vec::<Symbol>()); // Memory freed here
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -75,12 +203,16 @@ pub unsafe extern "C" fn symbol_to_pystr(symbol: &Symbol) -> *mut ffi::PyObject
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
-    use super::Symbol;
+    use super::{
+        symbol_clone, symbols_drop, symbols_from_pylist, symbols_to_pylist, FuturesSymbol, Symbol,
+    };
+    use pyo3::types::{PyList, PyString};
+    use pyo3::{prepare_freethreaded_python, FromPyPointer, IntoPyPointer, Python};
 
     #[test]
     fn test_symbol_from_str() {
-        let symbol1 = Symbol::from("XRD/USD");
-        let symbol2 = Symbol::from("BTC/USD");
+        let symbol1 = Symbol::new("XRD/USD");
+        let symbol2 = Symbol::new("BTC/USD");
 
         assert_eq!(symbol1, symbol1);
         assert_ne!(symbol1, symbol2);
@@ -89,8 +221,105 @@ mod tests {
 
     #[test]
     fn test_symbol_as_str() {
-        let symbol = Symbol::from("ETH-PERP");
+        let symbol = Symbol::new("ETH-PERP");
 
         assert_eq!(symbol.to_string(), "ETH-PERP");
     }
+
+    #[test]
+    fn test_symbol_try_from_str() {
+        assert!("".parse::<Symbol>().is_err());
+        assert_eq!(
+            "ABC\0".parse::<Symbol>(),
+            Err(crate::identifiers::IdentifierError::InvalidChars)
+        );
+        assert_eq!("ABC-123".parse::<Symbol>(), Ok(Symbol::new("ABC-123")));
+    }
+
+    #[test]
+    fn test_symbol_canonical() {
+        let symbol = Symbol::canonical("eth-perp");
+
+        assert_eq!(symbol, Symbol::new("ETH-PERP"));
+        assert!(symbol.is_canonical());
+        assert!(!Symbol::new("ETH-perp").is_canonical());
+        assert!(Symbol::new("ETH-perp").eq_ignore_case(&symbol));
+        assert!(!symbol.eq_ignore_case(&Symbol::new("ETH/PERP")));
+    }
+
+    #[test]
+    fn test_parse_futures() {
+        let es = Symbol::new("ESZ3").parse_futures().unwrap();
+        let cl = Symbol::new("CLF24").parse_futures().unwrap();
+        let euro = Symbol::new("6EH25").parse_futures().unwrap();
+
+        assert_eq!(
+            es,
+            FuturesSymbol {
+                root: "ES".to_string(),
+                month_code: 'Z',
+                year: 3,
+                year_digits: 1,
+            }
+        );
+        assert_eq!(es.month(), 12);
+        assert_eq!(es.to_string(), "ESZ3");
+        assert_eq!((cl.root.as_str(), cl.month(), cl.year), ("CL", 1, 24));
+        assert_eq!(cl.to_string(), "CLF24");
+        assert_eq!((euro.root.as_str(), euro.month()), ("6E", 3));
+    }
+
+    #[test]
+    fn test_parse_futures_invalid() {
+        assert_eq!(Symbol::new("AUD/USD").parse_futures(), None);
+        assert_eq!(Symbol::new("Z3").parse_futures(), None);
+        assert_eq!(Symbol::new("ESA3").parse_futures(), None);
+        assert_eq!(Symbol::new("ESZ").parse_futures(), None);
+        assert_eq!(Symbol::new("ESZ2023").parse_futures(), None);
+        assert_eq!(Symbol::new("ES-Z3").parse_futures(), None);
+    }
+
+    #[test]
+    fn test_futures_resolve_year() {
+        let es = Symbol::new("ESZ3").parse_futures().unwrap();
+        let cl = Symbol::new("CLF24").parse_futures().unwrap();
+        let ng = Symbol::new("NGH09").parse_futures().unwrap();
+
+        assert_eq!(es.resolve_year(2023), 2023);
+        assert_eq!(es.resolve_year(2025), 2033);
+        assert_eq!(cl.resolve_year(2023), 2024);
+        assert_eq!(ng.resolve_year(2099), 2109);
+    }
+
+    #[test]
+    fn test_symbols_pylist_round_trip() {
+        prepare_freethreaded_python();
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        let values = ["AUD/USD", "ETH-PERP", "ESZ3"];
+        let ptrs: Vec<_> = values
+            .iter()
+            .map(|v| PyString::new(py, v).into_ptr())
+            .collect();
+
+        let symbols = unsafe { symbols_from_pylist(ptrs.as_ptr(), ptrs.len()) };
+        let list = unsafe { PyList::from_owned_ptr(py, symbols_to_pylist(&symbols)) };
+
+        assert_eq!(
+            unsafe { symbols.as_slice::<Symbol>() }[1],
+            Symbol::new("ETH-PERP")
+        );
+        assert_eq!(list.extract::<Vec<String>>().unwrap(), values);
+        let clone = symbol_clone(&unsafe { symbols.as_slice::<Symbol>() }[0]);
+        unsafe { symbols_drop(symbols) };
+        assert_eq!(clone.as_str(), "AUD/USD");
+    }
+
+    #[test]
+    fn test_symbols_from_empty_pylist() {
+        let symbols = unsafe { symbols_from_pylist(std::ptr::null(), 0) };
+
+        assert_eq!(symbols.len, 0);
+        unsafe { symbols_drop(symbols) };
+    }
 }
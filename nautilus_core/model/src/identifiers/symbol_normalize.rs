@@ -0,0 +1,219 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Normalization of venue and feed symbols to a canonical form.
+//!
+//! Feeds write the same market as e.g. "btc/usdt", "BTC-USDT" or "BTCUSDT", so
+//! adapters share one set of rules to canonicalize them (e.g. to "BTC/USDT").
+
+use crate::identifiers::symbol::Symbol;
+use nautilus_core::string::pystr_to_string;
+use pyo3::ffi;
+
+/// Represents the rules for normalizing symbols, applied in field order.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SymbolNormalizer {
+    /// If values are uppercased.
+    pub uppercase: bool,
+    /// The separator characters to map to `separator`.
+    pub separators: Vec<char>,
+    /// The canonical separator, or `None` to remove separators.
+    pub separator: Option<char>,
+    /// The suffixes to strip (at most one), normalized by the rules above
+    /// before matching, so "-PERP" also strips "_perp".
+    pub strip_suffixes: Vec<String>,
+    /// The quote assets used to insert the separator into values which have
+    /// none, matching the longest, e.g. "BTCUSDT" to "BTC/USDT".
+    pub quote_assets: Vec<String>,
+}
+
+impl Default for SymbolNormalizer {
+    fn default() -> Self {
+        SymbolNormalizer {
+            uppercase: true,
+            separators: vec!['/', '-', '_', ':'],
+            separator: Some('/'),
+            strip_suffixes: Vec::new(),
+            quote_assets: Vec::new(),
+        }
+    }
+}
+
+impl SymbolNormalizer {
+    /// Returns the value with the case and separator rules applied.
+    fn map_chars(&self, value: &str) -> String {
+        let mut mapped = String::with_capacity(value.len());
+        for c in value.trim().chars() {
+            if self.separators.contains(&c) {
+                if let Some(separator) = self.separator {
+                    mapped.push(separator);
+                }
+            } else if self.uppercase {
+                mapped.extend(c.to_uppercase());
+            } else {
+                mapped.push(c);
+            }
+        }
+        mapped
+    }
+
+    /// Returns the normalized value.
+    pub fn normalize_str(&self, value: &str) -> String {
+        let mut normalized = self.map_chars(value);
+        for suffix in &self.strip_suffixes {
+            let suffix = self.map_chars(suffix);
+            if !suffix.is_empty()
+                && normalized.len() > suffix.len()
+                && normalized.ends_with(&suffix)
+            {
+                normalized.truncate(normalized.len() - suffix.len());
+                break;
+            }
+        }
+        if let Some(separator) = self.separator {
+            if !normalized.contains(separator) {
+                let quote = self
+                    .quote_assets
+                    .iter()
+                    .map(|q| self.map_chars(q))
+                    .filter(|q| !q.is_empty() && normalized.len() > q.len())
+                    .filter(|q| normalized.ends_with(q.as_str()))
+                    .max_by_key(String::len);
+                if let Some(quote) = quote {
+                    normalized.insert(normalized.len() - quote.len(), separator);
+                }
+            }
+        }
+        normalized
+    }
+
+    /// Returns the normalized symbol.
+    pub fn normalize(&self, symbol: &Symbol) -> Symbol {
+        Symbol::new(self.normalize_str(symbol.as_str()).as_str())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// C API
+////////////////////////////////////////////////////////////////////////////////
+fn split_list(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Returns a Nautilus identifier normalized from a valid Python object pointer,
+/// with the default separators mapped to the `separator` character code (or
+/// removed if zero), and the comma separated suffixes and quote assets.
+///
+/// # Safety
+///
+/// - `ptr`, `strip_suffixes_ptr` and `quote_assets_ptr` must be borrowed from
+///   valid Python UTF-8 `str`s.
+#[no_mangle]
+pub unsafe extern "C" fn symbol_normalize(
+    ptr: *mut ffi::PyObject,
+    uppercase: u8,
+    separator: u32,
+    strip_suffixes_ptr: *mut ffi::PyObject,
+    quote_assets_ptr: *mut ffi::PyObject,
+) -> Symbol {
+    let normalizer = SymbolNormalizer {
+        uppercase: uppercase != 0,
+        separator: char::from_u32(separator).filter(|c| *c != '\0'),
+        strip_suffixes: split_list(&pystr_to_string(strip_suffixes_ptr)),
+        quote_assets: split_list(&pystr_to_string(quote_assets_ptr)),
+        ..Default::default()
+    };
+    Symbol::new(normalizer.normalize_str(&pystr_to_string(ptr)).as_str())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn crypto() -> SymbolNormalizer {
+        SymbolNormalizer {
+            strip_suffixes: vec!["-PERP".to_string()],
+            quote_assets: vec!["USD".to_string(), "USDT".to_string(), "BTC".to_string()],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_normalize_separators_and_case() {
+        let normalizer = crypto();
+
+        assert_eq!(normalizer.normalize_str("btc/usdt"), "BTC/USDT");
+        assert_eq!(normalizer.normalize_str("BTC-USDT"), "BTC/USDT");
+        assert_eq!(normalizer.normalize_str(" btc_usdt "), "BTC/USDT");
+    }
+
+    #[test]
+    fn test_normalize_inserts_separator_before_longest_quote() {
+        let normalizer = crypto();
+
+        assert_eq!(normalizer.normalize_str("BTCUSDT"), "BTC/USDT");
+        assert_eq!(normalizer.normalize_str("ethbtc"), "ETH/BTC");
+        assert_eq!(normalizer.normalize_str("USDT"), "USDT");
+        assert_eq!(normalizer.normalize_str("XRPEUR"), "XRPEUR");
+    }
+
+    #[test]
+    fn test_normalize_strips_suffix() {
+        let normalizer = crypto();
+
+        assert_eq!(normalizer.normalize_str("BTC-USDT-PERP"), "BTC/USDT");
+        assert_eq!(normalizer.normalize_str("btc_usdt_perp"), "BTC/USDT");
+        assert_eq!(normalizer.normalize_str("-PERP"), "/PERP");
+    }
+
+    #[test]
+    fn test_normalize_removes_separators() {
+        let normalizer = SymbolNormalizer {
+            separator: None,
+            ..crypto()
+        };
+
+        assert_eq!(normalizer.normalize_str("btc/usdt"), "BTCUSDT");
+        assert_eq!(normalizer.normalize_str("BTC-USDT-PERP"), "BTCUSDT");
+        assert_eq!(normalizer.normalize_str("BTCUSDT"), "BTCUSDT");
+    }
+
+    #[test]
+    fn test_normalize_preserves_case() {
+        let normalizer = SymbolNormalizer {
+            uppercase: false,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            normalizer.normalize(&Symbol::new("Brent-Crude")),
+            Symbol::new("Brent/Crude")
+        );
+    }
+
+    #[test]
+    fn test_split_list() {
+        assert_eq!(split_list("USD, USDT,,"), vec!["USD", "USDT"]);
+        assert!(split_list("").is_empty());
+    }
+}
@@ -13,61 +13,39 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use nautilus_core::string::{pystr_to_string, string_to_pystr};
-use pyo3::ffi;
-use std::fmt::{Debug, Display, Formatter, Result};
+use crate::identifiers::interned::InternedStr;
+use crate::identifiers::{impl_identifier, validate_identifier, IdentifierError};
+use std::fmt::Debug;
+
+/// The maximum length (characters) of a trade ID value, per the FIX `ExecID`
+/// (tag 17) field.
+pub const TRADE_ID_MAX_LEN: usize = 64;
 
 #[repr(C)]
-#[derive(Clone, Hash, PartialEq, Debug)]
-#[allow(clippy::box_collection)] // C ABI compatibility
+#[derive(Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
 pub struct TradeId {
-    value: Box<String>,
-}
-
-impl From<&str> for TradeId {
-    fn from(s: &str) -> TradeId {
-        TradeId {
-            value: Box::new(s.to_string()),
-        }
-    }
+    value: InternedStr,
 }
 
-impl Display for TradeId {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        write!(f, "{}", self.value)
+impl_identifier!(
+    TradeId,
+    validate_trade_id,
+    {
+        free: trade_id_free,
+        from_pystr: trade_id_from_pystr,
+        try_from_pystr: trade_id_try_from_pystr,
+        to_pystr: trade_id_to_pystr,
+        as_bytes: trade_id_as_bytes,
+        hash: trade_id_hash,
     }
-}
-
-////////////////////////////////////////////////////////////////////////////////
-// C API
-////////////////////////////////////////////////////////////////////////////////
-#[no_mangle]
-pub extern "C" fn trade_id_free(trade_id: TradeId) {
-    drop(trade_id); // Memory freed here
-}
+);
 
-/// Returns a Nautilus identifier from a valid Python object pointer.
-///
-/// # Safety
-///
-/// - `ptr` must be borrowed from a valid Python UTF-8 `str`.
-#[no_mangle]
-pub unsafe extern "C" fn trade_id_from_pystr(ptr: *mut ffi::PyObject) -> TradeId {
-    TradeId {
-        value: Box::new(pystr_to_string(ptr)),
+fn validate_trade_id(s: &str) -> std::result::Result<(), IdentifierError> {
+    validate_identifier(s)?;
+    if s.chars().count() > TRADE_ID_MAX_LEN {
+        return Err(IdentifierError::TooLong);
     }
-}
-
-/// Returns a pointer to a valid Python UTF-8 string.
-///
-/// # Safety
-///
-/// - Assumes that since the data is originating from Rust, the GIL does not need
-/// to be acquired.
-/// - Assumes you are immediately returning this pointer to Python.
-#[no_mangle]
-pub unsafe extern "C" fn trade_id_to_pystr(trade_id: &TradeId) -> *mut ffi::PyObject {
-    string_to_pystr(trade_id.value.as_str())
+    Ok(())
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -75,12 +53,16 @@ pub unsafe extern "C" fn trade_id_to_pystr(trade_id: &TradeId) -> *mut ffi::PyOb
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
-    use super::TradeId;
+    use super::{trade_id_try_from_pystr, TradeId, TRADE_ID_MAX_LEN};
+    use crate::identifiers::IdentifierError;
+    use nautilus_core::string::string_to_pystr;
+    use pyo3::{prepare_freethreaded_python, Python};
+    use std::mem::MaybeUninit;
 
     #[test]
     fn test_instrument_id_from_str() {
-        let trade_id1 = TradeId::from("123456789");
-        let trade_id2 = TradeId::from("234567890");
+        let trade_id1 = TradeId::new("123456789");
+        let trade_id2 = TradeId::new("234567890");
 
         assert_eq!(trade_id1, trade_id1);
         assert_ne!(trade_id1, trade_id2);
@@ -88,8 +70,52 @@ mod tests {
 
     #[test]
     fn test_trade_id_as_str() {
-        let trade_id = TradeId::from("1234567890");
+        let trade_id = TradeId::new("1234567890");
 
         assert_eq!(trade_id.to_string(), "1234567890");
     }
+
+    #[test]
+    fn test_trade_id_try_from_str() {
+        assert!("".parse::<TradeId>().is_err());
+        assert_eq!(
+            "ABC\0".parse::<TradeId>(),
+            Err(IdentifierError::InvalidChars)
+        );
+        assert_eq!("ABC-123".parse::<TradeId>(), Ok(TradeId::new("ABC-123")));
+    }
+
+    #[test]
+    fn test_trade_id_max_len() {
+        let max = "1".repeat(TRADE_ID_MAX_LEN);
+        let too_long = "1".repeat(TRADE_ID_MAX_LEN + 1);
+
+        assert_eq!(max.parse::<TradeId>(), Ok(TradeId::new(max.as_str())));
+        assert_eq!(too_long.parse::<TradeId>(), Err(IdentifierError::TooLong));
+    }
+
+    #[test]
+    fn test_trade_id_try_from_pystr() {
+        prepare_freethreaded_python();
+        let gil = Python::acquire_gil();
+        let _py = gil.python();
+        let mut out = MaybeUninit::<TradeId>::uninit();
+
+        let ok = unsafe { trade_id_try_from_pystr(string_to_pystr("T-123"), out.as_mut_ptr()) };
+        let trade_id = unsafe { out.assume_init() };
+        let mut invalid_out = MaybeUninit::<TradeId>::uninit();
+        let too_long = unsafe {
+            trade_id_try_from_pystr(
+                string_to_pystr(&"1".repeat(TRADE_ID_MAX_LEN + 1)),
+                invalid_out.as_mut_ptr(),
+            )
+        };
+        let empty =
+            unsafe { trade_id_try_from_pystr(string_to_pystr(""), invalid_out.as_mut_ptr()) };
+
+        assert_eq!(ok, 0);
+        assert_eq!(too_long, IdentifierError::TooLong as u8);
+        assert_eq!(empty, IdentifierError::Empty as u8);
+        assert_eq!(trade_id, TradeId::new("T-123"));
+    }
 }
@@ -13,49 +13,67 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use nautilus_core::string::pystr_to_string;
+use crate::identifiers::interned::InternedStr;
+use crate::identifiers::{impl_identifier, split_name_tag, validate_name_tag};
+use nautilus_core::string::string_to_pystr;
 use pyo3::ffi;
-use std::fmt::{Debug, Display, Formatter, Result};
+use std::fmt::Debug;
 
 #[repr(C)]
-#[derive(Clone, Hash, PartialEq, Debug)]
-#[allow(clippy::box_collection)] // C ABI compatibility
+#[derive(Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
 pub struct TraderId {
-    value: Box<String>,
+    value: InternedStr,
 }
 
-impl From<&str> for TraderId {
-    fn from(s: &str) -> TraderId {
-        TraderId {
-            value: Box::new(s.to_string()),
-        }
+impl_identifier!(
+    TraderId,
+    validate_name_tag,
+    {
+        free: trader_id_free,
+        from_pystr: trader_id_from_pystr,
+        try_from_pystr: trader_id_try_from_pystr,
+        to_pystr: trader_id_to_pystr,
+        as_bytes: trader_id_as_bytes,
+        hash: trader_id_hash,
+    }
+);
+
+impl TraderId {
+    /// Returns the name part preceding the last hyphen.
+    pub fn name(&self) -> &str {
+        split_name_tag(self.value.as_str()).0
     }
-}
 
-impl Display for TraderId {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        write!(f, "{}", self.value)
+    /// Returns the order ID tag part following the last hyphen.
+    pub fn tag(&self) -> &str {
+        split_name_tag(self.value.as_str()).1
     }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
 // C API
-////////////////////////////////////////////////////////////////////////////////
+/// Returns a pointer to a valid Python UTF-8 string of the name part.
+///
+/// # Safety
+///
+/// - Assumes that since the data is originating from Rust, the GIL does not need
+///   to be acquired.
+/// - Assumes you are immediately returning this pointer to Python.
 #[no_mangle]
-pub extern "C" fn trader_id_free(trader_id: TraderId) {
-    drop(trader_id); // Memory freed here
+pub unsafe extern "C" fn trader_id_name_to_pystr(trader_id: &TraderId) -> *mut ffi::PyObject {
+    string_to_pystr(trader_id.name())
 }
 
-/// Returns a Nautilus identifier from a valid Python object pointer.
+/// Returns a pointer to a valid Python UTF-8 string of the tag part.
 ///
 /// # Safety
 ///
-/// - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+/// - Assumes that since the data is originating from Rust, the GIL does not need
+///   to be acquired.
+/// - Assumes you are immediately returning this pointer to Python.
 #[no_mangle]
-pub unsafe extern "C" fn trader_id_from_pystr(ptr: *mut ffi::PyObject) -> TraderId {
-    TraderId {
-        value: Box::new(pystr_to_string(ptr)),
-    }
+pub unsafe extern "C" fn trader_id_tag_to_pystr(trader_id: &TraderId) -> *mut ffi::PyObject {
+    string_to_pystr(trader_id.tag())
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -63,12 +81,15 @@ pub unsafe extern "C" fn trader_id_from_pystr(ptr: *mut ffi::PyObject) -> Trader
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
-    use super::TraderId;
+    use super::{trader_id_tag_to_pystr, TraderId};
+    use crate::identifiers::IdentifierError;
+    use nautilus_core::string::pystr_to_string;
+    use pyo3::{prepare_freethreaded_python, Python};
 
     #[test]
     fn test_trader_id_from_str() {
-        let trader_id1 = TraderId::from("EMACross-001");
-        let trader_id2 = TraderId::from("EMACross-002");
+        let trader_id1 = TraderId::new("EMACross-001");
+        let trader_id2 = TraderId::new("EMACross-002");
 
         assert_eq!(trader_id1, trader_id1);
         assert_ne!(trader_id1, trader_id2);
@@ -77,8 +98,46 @@ mod tests {
 
     #[test]
     fn test_trader_id_as_str() {
-        let trader_id = TraderId::from("EMACross-001");
+        let trader_id = TraderId::new("EMACross-001");
 
         assert_eq!(trader_id.to_string(), "EMACross-001");
     }
+
+    #[test]
+    fn test_trader_id_try_from_str() {
+        assert!("".parse::<TraderId>().is_err());
+        assert_eq!(
+            "ABC\0".parse::<TraderId>(),
+            Err(crate::identifiers::IdentifierError::InvalidChars)
+        );
+        assert_eq!("ABC-123".parse::<TraderId>(), Ok(TraderId::new("ABC-123")));
+    }
+
+    #[test]
+    fn test_trader_id_name_and_tag() {
+        let trader_id = TraderId::new("TRADER-001");
+
+        assert_eq!(trader_id.name(), "TRADER");
+        assert_eq!(trader_id.tag(), "001");
+    }
+
+    #[test]
+    fn test_trader_id_parse_requires_name_tag() {
+        assert_eq!(
+            "TRADER".parse::<TraderId>(),
+            Err(IdentifierError::InvalidFormat)
+        );
+    }
+
+    #[test]
+    fn test_trader_id_tag_to_pystr() {
+        prepare_freethreaded_python();
+        let gil = Python::acquire_gil();
+        let _py = gil.python();
+        let trader_id = TraderId::new("TRADER-001");
+
+        let ptr = unsafe { trader_id_tag_to_pystr(&trader_id) };
+
+        assert_eq!(unsafe { pystr_to_string(ptr) }, "001");
+    }
 }
@@ -13,61 +13,180 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use nautilus_core::string::{pystr_to_string, string_to_pystr};
+use crate::identifiers::interned::InternedStr;
+use crate::identifiers::{
+    canonicalize, eq_ignore_case, identifiers_from_pystrs, identifiers_to_pylist, impl_identifier,
+};
+use nautilus_core::cvec::CVec;
+use nautilus_core::string::pystr_to_string;
 use pyo3::ffi;
-use std::fmt::{Debug, Display, Formatter, Result};
+use std::borrow::Cow;
+use std::fmt::Debug;
+
+/// The venue for simulated exchanges in backtests and sandbox trading.
+pub const SIM_VENUE: &str = "SIM";
+pub const BETFAIR_VENUE: &str = "BETFAIR";
+pub const BINANCE_VENUE: &str = "BINANCE";
+pub const FTX_VENUE: &str = "FTX";
+pub const IB_VENUE: &str = "InteractiveBrokers";
+
+/// The venue for synthetic instruments derived from other instruments' data.
+pub const SYNTHETIC_VENUE: &str = "SYNTH";
+
+/// The delimiter wrapping the names of synthetic or consolidated venues, e.g.
+/// `-CONSOLIDATED-`.
+pub const SYNTHETIC_VENUE_DELIMITER: char = '-';
+
+/// The well-known venues, whose constant values are canonical as written.
+pub const WELL_KNOWN_VENUES: [&str; 6] = [
+    SIM_VENUE,
+    BETFAIR_VENUE,
+    BINANCE_VENUE,
+    FTX_VENUE,
+    IB_VENUE,
+    SYNTHETIC_VENUE,
+];
+
+/// Returns the canonical form of a venue value, being the matching well-known
+/// venue constant if any, otherwise the uppercased value.
+fn canonicalize_venue(value: &str) -> Cow<'_, str> {
+    match WELL_KNOWN_VENUES
+        .iter()
+        .find(|name| eq_ignore_case(name, value))
+    {
+        Some(name) => Cow::Borrowed(name),
+        None => canonicalize(value),
+    }
+}
 
 #[repr(C)]
-#[derive(Clone, Hash, PartialEq, Debug)]
-#[allow(clippy::box_collection)] // C ABI compatibility
+#[derive(Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
 pub struct Venue {
-    value: Box<String>,
+    value: InternedStr,
 }
 
-impl From<&str> for Venue {
-    fn from(s: &str) -> Venue {
-        Venue {
-            value: Box::new(s.to_string()),
-        }
+impl_identifier!(
+    Venue,
+    {
+        free: venue_free,
+        from_pystr: venue_from_pystr,
+        try_from_pystr: venue_try_from_pystr,
+        to_pystr: venue_to_pystr,
+        as_bytes: venue_as_bytes,
+        hash: venue_hash,
     }
-}
+);
 
-impl Display for Venue {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        write!(f, "{}", self.value)
+impl Venue {
+    /// Returns whether the venue is synthetic or consolidated rather than a
+    /// real trading venue, being either `SYNTHETIC_VENUE` or a name wrapped in
+    /// `SYNTHETIC_VENUE_DELIMITER`.
+    pub fn is_synthetic(&self) -> bool {
+        let value = self.value.as_str();
+        value == SYNTHETIC_VENUE
+            || (value.len() > 2
+                && value.starts_with(SYNTHETIC_VENUE_DELIMITER)
+                && value.ends_with(SYNTHETIC_VENUE_DELIMITER))
+    }
+
+    /// Returns the venue with its value in canonical form, so feeds disagreeing
+    /// on case construct equal venues.
+    ///
+    /// The canonical form of a well-known venue is its constant value (e.g.
+    /// `IB_VENUE`), and of any other venue its uppercased value.
+    pub fn canonical(value: &str) -> Self {
+        Venue::new(canonicalize_venue(value).as_ref())
+    }
+
+    /// Returns whether the value is in canonical form.
+    pub fn is_canonical(&self) -> bool {
+        canonicalize_venue(self.as_str()) == self.as_str()
+    }
+
+    /// Returns whether the venues are equal ignoring case.
+    pub fn eq_ignore_case(&self, other: &Venue) -> bool {
+        self == other || eq_ignore_case(self.as_str(), other.as_str())
     }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
 // C API
 ////////////////////////////////////////////////////////////////////////////////
+// NUL terminated names of the well-known venues, matching the Rust constants
 #[no_mangle]
-pub extern "C" fn venue_free(venue: Venue) {
-    drop(venue); // Memory freed here
-}
+pub static SIM_VENUE_NAME: [u8; 4] = *b"SIM\0";
+#[no_mangle]
+pub static BETFAIR_VENUE_NAME: [u8; 8] = *b"BETFAIR\0";
+#[no_mangle]
+pub static BINANCE_VENUE_NAME: [u8; 8] = *b"BINANCE\0";
+#[no_mangle]
+pub static FTX_VENUE_NAME: [u8; 4] = *b"FTX\0";
+#[no_mangle]
+pub static IB_VENUE_NAME: [u8; 19] = *b"InteractiveBrokers\0";
+#[no_mangle]
+pub static SYNTHETIC_VENUE_NAME: [u8; 6] = *b"SYNTH\0";
 
-/// Returns a Nautilus identifier from a valid Python object pointer.
+/// Returns a Nautilus identifier from a valid Python object pointer, with the
+/// value in canonical form.
 ///
 /// # Safety
 ///
 /// - `ptr` must be borrowed from a valid Python UTF-8 `str`.
 #[no_mangle]
-pub unsafe extern "C" fn venue_from_pystr(ptr: *mut ffi::PyObject) -> Venue {
-    Venue {
-        value: Box::new(pystr_to_string(ptr)),
-    }
+pub unsafe extern "C" fn venue_canonical_from_pystr(ptr: *mut ffi::PyObject) -> Venue {
+    Venue::canonical(pystr_to_string(ptr).as_str())
 }
 
-/// Returns a pointer to a valid Python UTF-8 string.
+#[no_mangle]
+pub extern "C" fn venue_eq_ignore_case(venue: &Venue, other: &Venue) -> u8 {
+    u8::from(venue.eq_ignore_case(other))
+}
+
+#[no_mangle]
+pub extern "C" fn venue_is_synthetic(venue: &Venue) -> u8 {
+    u8::from(venue.is_synthetic())
+}
+
+/// Returns a clone of the identifier, e.g. an element of the vec returned by
+/// `venues_from_pylist` for ownership by Python.
+#[no_mangle]
+pub extern "C" fn venue_clone(venue: &Venue) -> Venue {
+    venue.clone()
+}
+
+/// Returns a vec of Nautilus identifiers from an array of `len` valid Python
+/// object pointers (e.g. the items of a list), acquiring the GIL once for
+/// the batch. The vec must be passed to `venues_drop` to free it.
 ///
 /// # Safety
 ///
+/// - `ptrs` must point to `len` pointers borrowed from valid Python UTF-8 `str`s.
+#[no_mangle]
+pub unsafe extern "C" fn venues_from_pylist(ptrs: *const *mut ffi::PyObject, len: usize) -> CVec {
+    identifiers_from_pystrs(ptrs, len, Venue::new)
+}
+
+/// Returns a pointer to a new Python list of the identifier strings.
+///
+/// # Safety
+///
+/// - `venues` must have been returned by `venues_from_pylist`.
 /// - Assumes that since the data is originating from Rust, the GIL does not need
-/// to be acquired.
+///   to be acquired.
 /// - Assumes you are immediately returning this pointer to Python.
 #[no_mangle]
-pub unsafe extern "C" fn venue_to_pystr(venue: &Venue) -> *mut ffi::PyObject {
-    string_to_pystr(venue.value.as_str())
+pub unsafe extern "C" fn venues_to_pylist(venues: &CVec) -> *mut ffi::PyObject {
+    identifiers_to_pylist(venues.as_slice::<Venue>())
+}
+
+/// Drops a vec of identifiers returned by `venues_from_pylist`.
+///
+/// # Safety
+///
+/// - `venues` must have been returned by `venues_from_pylist`.
+#[no_mangle]
+pub unsafe extern "C" fn venues_drop(venues: CVec) {
+    drop(venues.into_vec::<Venue>()); // Memory freed here
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -75,12 +194,12 @@ pub unsafe extern "C" fn venue_to_pystr(venue: &Venue) -> *mut ffi::PyObject {
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
-    use super::Venue;
+    use super::*;
 
     #[test]
     fn test_venue_from_str() {
-        let venue1 = Venue::from("FTX");
-        let venue2 = Venue::from("IDEALPRO");
+        let venue1 = Venue::new("FTX");
+        let venue2 = Venue::new("IDEALPRO");
 
         assert_eq!(venue1, venue1);
         assert_ne!(venue1, venue2);
@@ -89,8 +208,81 @@ mod tests {
 
     #[test]
     fn test_venue_as_str() {
-        let venue = Venue::from("FTX");
+        let venue = Venue::new("FTX");
 
         assert_eq!(venue.to_string(), "FTX")
     }
+
+    #[test]
+    fn test_venue_try_from_str() {
+        assert!("".parse::<Venue>().is_err());
+        assert_eq!(
+            "ABC\0".parse::<Venue>(),
+            Err(crate::identifiers::IdentifierError::InvalidChars)
+        );
+        assert_eq!("ABC-123".parse::<Venue>(), Ok(Venue::new("ABC-123")));
+    }
+
+    #[test]
+    fn test_venue_constants_match_c_names() {
+        let names: [(&str, &[u8]); 6] = [
+            (SIM_VENUE, &SIM_VENUE_NAME),
+            (BETFAIR_VENUE, &BETFAIR_VENUE_NAME),
+            (BINANCE_VENUE, &BINANCE_VENUE_NAME),
+            (FTX_VENUE, &FTX_VENUE_NAME),
+            (IB_VENUE, &IB_VENUE_NAME),
+            (SYNTHETIC_VENUE, &SYNTHETIC_VENUE_NAME),
+        ];
+
+        for (value, c_name) in names {
+            assert_eq!(c_name, [value.as_bytes(), b"\0"].concat().as_slice());
+            assert!(value.parse::<Venue>().is_ok());
+        }
+    }
+
+    #[test]
+    fn test_venue_is_synthetic() {
+        assert!(Venue::new(SYNTHETIC_VENUE).is_synthetic());
+        assert!(Venue::new("-CONSOLIDATED-").is_synthetic());
+        assert!(!Venue::new("BINANCE").is_synthetic());
+        assert!(!Venue::new("ABC-123").is_synthetic());
+        assert!(!Venue::new("-").is_synthetic());
+        assert!(!Venue::new("--").is_synthetic());
+        assert_eq!(venue_is_synthetic(&Venue::new("-NBBO-")), 1);
+        assert_eq!(venue_is_synthetic(&Venue::new("NASDAQ")), 0);
+    }
+
+    #[test]
+    fn test_venue_canonical() {
+        let venue = Venue::canonical("Binance");
+
+        assert_eq!(venue, Venue::new(BINANCE_VENUE));
+        assert!(venue.is_canonical());
+        assert!(!Venue::new("Ftx").is_canonical());
+        assert_eq!(Venue::canonical("Ftx").as_str(), FTX_VENUE);
+        assert_eq!(Venue::canonical("CoinBase").as_str(), "COINBASE");
+    }
+
+    #[test]
+    fn test_venue_canonical_well_known_venues() {
+        for name in WELL_KNOWN_VENUES {
+            let venue = Venue::new(name);
+
+            assert!(venue.is_canonical());
+            assert_eq!(Venue::canonical(name), venue);
+            assert_eq!(Venue::canonical(&name.to_lowercase()), venue);
+        }
+        assert_eq!(Venue::canonical("INTERACTIVEBROKERS").as_str(), IB_VENUE);
+    }
+
+    #[test]
+    fn test_venue_eq_ignore_case() {
+        let venue = Venue::new("Binance");
+
+        assert_ne!(venue, Venue::new(BINANCE_VENUE));
+        assert!(venue.eq_ignore_case(&Venue::new(BINANCE_VENUE)));
+        assert!(!venue.eq_ignore_case(&Venue::new("BINANCEUS")));
+        assert_eq!(venue_eq_ignore_case(&venue, &Venue::new("binance")), 1);
+        assert_eq!(venue_eq_ignore_case(&venue, &Venue::new(FTX_VENUE)), 0);
+    }
 }
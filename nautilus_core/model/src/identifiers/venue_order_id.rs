@@ -13,62 +13,41 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use nautilus_core::string::pystr_to_string;
-use pyo3::ffi;
-use std::fmt::{Debug, Display, Formatter, Result};
+use crate::identifiers::impl_identifier;
+use crate::identifiers::interned::InternedStr;
+use std::fmt::Debug;
 
 #[repr(C)]
-#[derive(Clone, Hash, PartialEq, Debug)]
-#[allow(clippy::box_collection)] // C ABI compatibility
+#[derive(Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
 pub struct VenueOrderId {
-    value: Box<String>,
+    value: InternedStr,
 }
 
-impl From<&str> for VenueOrderId {
-    fn from(s: &str) -> VenueOrderId {
-        VenueOrderId {
-            value: Box::new(s.to_string()),
-        }
+impl_identifier!(
+    VenueOrderId,
+    {
+        free: venue_order_id_free,
+        from_pystr: venue_order_id_from_pystr,
+        try_from_pystr: venue_order_id_try_from_pystr,
+        to_pystr: venue_order_id_to_pystr,
+        as_bytes: venue_order_id_as_bytes,
+        hash: venue_order_id_hash,
     }
-}
-
-impl Display for VenueOrderId {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        write!(f, "{}", self.value)
-    }
-}
-
-////////////////////////////////////////////////////////////////////////////////
-// C API
-////////////////////////////////////////////////////////////////////////////////
-#[no_mangle]
-pub extern "C" fn venue_order_id_free(venue_order_id: VenueOrderId) {
-    drop(venue_order_id); // Memory freed here
-}
-
-/// Returns a Nautilus identifier from a valid Python object pointer.
-///
-/// # Safety
-///
-/// - `ptr` must be borrowed from a valid Python UTF-8 `str`.
-#[no_mangle]
-pub unsafe extern "C" fn venue_order_id_from_pystr(ptr: *mut ffi::PyObject) -> VenueOrderId {
-    VenueOrderId {
-        value: Box::new(pystr_to_string(ptr)),
-    }
-}
+);
 
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
-    use super::VenueOrderId;
+    use super::{venue_order_id_to_pystr, VenueOrderId};
+    use nautilus_core::string::pystr_to_string;
+    use pyo3::{prepare_freethreaded_python, Python};
 
     #[test]
     fn test_venue_order_id_from_str() {
-        let venue_order_id1 = VenueOrderId::from("001");
-        let venue_order_id2 = VenueOrderId::from("002");
+        let venue_order_id1 = VenueOrderId::new("001");
+        let venue_order_id2 = VenueOrderId::new("002");
 
         assert_eq!(venue_order_id1, venue_order_id1);
         assert_ne!(venue_order_id1, venue_order_id2);
@@ -77,8 +56,33 @@ mod tests {
 
     #[test]
     fn test_venue_order_id_as_str() {
-        let venue_order_id = VenueOrderId::from("001");
+        let venue_order_id = VenueOrderId::new("001");
 
         assert_eq!(venue_order_id.to_string(), "001")
     }
+
+    #[test]
+    fn test_venue_order_id_try_from_str() {
+        assert!("".parse::<VenueOrderId>().is_err());
+        assert_eq!(
+            "ABC\0".parse::<VenueOrderId>(),
+            Err(crate::identifiers::IdentifierError::InvalidChars)
+        );
+        assert_eq!(
+            "ABC-123".parse::<VenueOrderId>(),
+            Ok(VenueOrderId::new("ABC-123"))
+        );
+    }
+
+    #[test]
+    fn test_venue_order_id_to_pystr() {
+        prepare_freethreaded_python();
+        let gil = Python::acquire_gil();
+        let _py = gil.python();
+        let venue_order_id = VenueOrderId::new("V-001");
+
+        let ptr = unsafe { venue_order_id_to_pystr(&venue_order_id) };
+
+        assert_eq!(unsafe { pystr_to_string(ptr) }, "V-001");
+    }
 }
@@ -13,8 +13,15 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+pub mod algorithms;
 pub mod data;
 pub mod enums;
+pub mod exchange_rate;
+pub mod fast_backtest;
 pub mod identifiers;
 pub mod orderbook;
+pub mod session;
+pub mod statistics;
+pub mod symbology;
 pub mod types;
+pub mod volatility;
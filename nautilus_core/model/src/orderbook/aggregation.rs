@@ -0,0 +1,348 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::HashMap;
+
+use crate::enums::{BookAction, OrderSide};
+use crate::orderbook::order::Order;
+use crate::types::price::Price;
+use crate::types::quantity::Quantity;
+
+/// Represents a change to the aggregate size at a single price level.
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LevelDelta {
+    pub action: BookAction,
+    pub side: OrderSide,
+    pub price: Price,
+    pub size: Quantity,
+}
+
+impl LevelDelta {
+    /// Returns the level as an L2 order, identified by its raw price, for
+    /// applying to an `L2_MBP` order book.
+    pub fn to_order(&self) -> Order {
+        Order::new(
+            self.price.clone(),
+            self.size.clone(),
+            self.side,
+            self.price.raw as u64,
+        )
+    }
+}
+
+/// Provides on the fly aggregation of order level (L3 MBO) deltas into price
+/// level (L2 MBP) deltas, so only changed levels need be applied to an
+/// `L2_MBP` order book.
+#[derive(Debug, Default)]
+pub struct L2Aggregator {
+    orders: HashMap<u64, (OrderSide, Price, Quantity)>,
+    levels: HashMap<(OrderSide, Price), Quantity>,
+}
+
+impl L2Aggregator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of open orders being tracked.
+    pub fn order_count(&self) -> usize {
+        self.orders.len()
+    }
+
+    /// Returns the number of non-empty price levels.
+    pub fn level_count(&self) -> usize {
+        self.levels.len()
+    }
+
+    /// Returns the aggregate size at the given price level.
+    pub fn level_size(&self, side: OrderSide, price: &Price) -> Option<&Quantity> {
+        self.levels.get(&(side, price.clone()))
+    }
+
+    /// Applies the MBO delta and returns the resulting level deltas.
+    ///
+    /// Updates and deletes for unknown order IDs produce no level deltas, and
+    /// an update moving an order between prices changes two levels. A clear
+    /// produces a `Clear` delta for each side.
+    pub fn apply(&mut self, action: BookAction, order: &Order) -> Vec<LevelDelta> {
+        let mut deltas = Vec::new();
+        match action {
+            BookAction::Add | BookAction::Update if order.size.raw == 0 => {
+                self.remove_order(order.id, &mut deltas);
+            }
+            BookAction::Add | BookAction::Update => {
+                if action == BookAction::Update && !self.orders.contains_key(&order.id) {
+                    return deltas;
+                }
+                self.remove_order(order.id, &mut deltas);
+                self.insert_order(order, &mut deltas);
+            }
+            BookAction::Delete => self.remove_order(order.id, &mut deltas),
+            BookAction::Clear => {
+                self.orders.clear();
+                self.levels.clear();
+                for side in [OrderSide::Buy, OrderSide::Sell] {
+                    deltas.push(LevelDelta {
+                        action: BookAction::Clear,
+                        side,
+                        price: Price::default(),
+                        size: Quantity::default(),
+                    });
+                }
+            }
+        }
+        deltas
+    }
+
+    fn insert_order(&mut self, order: &Order, deltas: &mut Vec<LevelDelta>) {
+        let key = (order.side, order.price.clone());
+        let (size, mut action) = match self.levels.get_mut(&key) {
+            Some(size) => {
+                size.raw = size.raw.saturating_add(order.size.raw);
+                (size.clone(), BookAction::Update)
+            }
+            None => {
+                self.levels.insert(key, order.size.clone());
+                (order.size.clone(), BookAction::Add)
+            }
+        };
+        self.orders.insert(
+            order.id,
+            (order.side, order.price.clone(), order.size.clone()),
+        );
+
+        // A level changed by removing the previous order is only a size change
+        if deltas
+            .last()
+            .is_some_and(|last| last.side == order.side && last.price == order.price)
+        {
+            deltas.pop();
+            action = BookAction::Update;
+        }
+        deltas.push(LevelDelta {
+            action,
+            side: order.side,
+            price: order.price.clone(),
+            size,
+        });
+    }
+
+    fn remove_order(&mut self, order_id: u64, deltas: &mut Vec<LevelDelta>) {
+        let (side, price, size) = match self.orders.remove(&order_id) {
+            Some(order) => order,
+            None => return,
+        };
+        let key = (side, price.clone());
+        let level = match self.levels.get_mut(&key) {
+            Some(level) => level,
+            None => return,
+        };
+        level.raw = level.raw.saturating_sub(size.raw);
+        if level.raw == 0 {
+            self.levels.remove(&key);
+            deltas.push(LevelDelta {
+                action: BookAction::Delete,
+                side,
+                price,
+                size: Quantity::from_raw(0, size.precision),
+            });
+        } else {
+            deltas.push(LevelDelta {
+                action: BookAction::Update,
+                side,
+                price,
+                size: level.clone(),
+            });
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::{L2Aggregator, LevelDelta};
+    use crate::enums::{BookAction, BookLevel, OrderSide};
+    use crate::identifiers::instrument_id::InstrumentId;
+    use crate::orderbook::book::OrderBook;
+    use crate::orderbook::order::Order;
+    use crate::types::price::Price;
+    use crate::types::quantity::Quantity;
+
+    fn order(price: &str, size: &str, side: OrderSide, id: u64) -> Order {
+        Order::new(Price::from(price), Quantity::from(size), side, id)
+    }
+
+    fn delta(action: BookAction, side: OrderSide, price: &str, size: &str) -> LevelDelta {
+        LevelDelta {
+            action,
+            side,
+            price: Price::from(price),
+            size: Quantity::from(size),
+        }
+    }
+
+    #[test]
+    fn test_add_orders_aggregates_levels() {
+        let mut aggregator = L2Aggregator::new();
+
+        let deltas1 = aggregator.apply(BookAction::Add, &order("10.0", "5", OrderSide::Buy, 1));
+        let deltas2 = aggregator.apply(BookAction::Add, &order("10.0", "3", OrderSide::Buy, 2));
+        let deltas3 = aggregator.apply(BookAction::Add, &order("10.0", "4", OrderSide::Sell, 3));
+
+        assert_eq!(
+            deltas1,
+            [delta(BookAction::Add, OrderSide::Buy, "10.0", "5")]
+        );
+        assert_eq!(
+            deltas2,
+            [delta(BookAction::Update, OrderSide::Buy, "10.0", "8")]
+        );
+        assert_eq!(
+            deltas3,
+            [delta(BookAction::Add, OrderSide::Sell, "10.0", "4")]
+        );
+        assert_eq!(aggregator.order_count(), 3);
+        assert_eq!(aggregator.level_count(), 2);
+    }
+
+    #[test]
+    fn test_update_order_size() {
+        let mut aggregator = L2Aggregator::new();
+        aggregator.apply(BookAction::Add, &order("10.0", "5", OrderSide::Buy, 1));
+
+        let deltas = aggregator.apply(BookAction::Update, &order("10.0", "2", OrderSide::Buy, 1));
+
+        assert_eq!(
+            deltas,
+            [delta(BookAction::Update, OrderSide::Buy, "10.0", "2")]
+        );
+    }
+
+    #[test]
+    fn test_update_order_price_changes_two_levels() {
+        let mut aggregator = L2Aggregator::new();
+        aggregator.apply(BookAction::Add, &order("10.0", "5", OrderSide::Buy, 1));
+        aggregator.apply(BookAction::Add, &order("10.0", "3", OrderSide::Buy, 2));
+
+        let deltas = aggregator.apply(BookAction::Update, &order("11.0", "5", OrderSide::Buy, 1));
+
+        assert_eq!(
+            deltas,
+            [
+                delta(BookAction::Update, OrderSide::Buy, "10.0", "3"),
+                delta(BookAction::Add, OrderSide::Buy, "11.0", "5"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_delete_last_order_deletes_level() {
+        let mut aggregator = L2Aggregator::new();
+        aggregator.apply(BookAction::Add, &order("10.0", "5", OrderSide::Sell, 1));
+
+        let deltas = aggregator.apply(BookAction::Delete, &order("10.0", "5", OrderSide::Sell, 1));
+        let unknown = aggregator.apply(BookAction::Delete, &order("10.0", "5", OrderSide::Sell, 9));
+
+        assert_eq!(
+            deltas,
+            [delta(BookAction::Delete, OrderSide::Sell, "10.0", "0")]
+        );
+        assert!(unknown.is_empty());
+        assert_eq!(aggregator.level_count(), 0);
+    }
+
+    #[test]
+    fn test_zero_size_update_deletes_order() {
+        let mut aggregator = L2Aggregator::new();
+        aggregator.apply(BookAction::Add, &order("10.0", "5", OrderSide::Buy, 1));
+        aggregator.apply(BookAction::Add, &order("10.0", "1", OrderSide::Buy, 2));
+
+        let deltas = aggregator.apply(BookAction::Update, &order("10.0", "0", OrderSide::Buy, 1));
+
+        assert_eq!(
+            deltas,
+            [delta(BookAction::Update, OrderSide::Buy, "10.0", "1")]
+        );
+        assert_eq!(
+            aggregator.level_size(OrderSide::Buy, &Price::from("10.0")),
+            Some(&Quantity::from("1"))
+        );
+    }
+
+    #[test]
+    fn test_clear() {
+        let mut aggregator = L2Aggregator::new();
+        aggregator.apply(BookAction::Add, &order("10.0", "5", OrderSide::Buy, 1));
+
+        let deltas = aggregator.apply(BookAction::Clear, &order("0", "0", OrderSide::Buy, 0));
+
+        assert_eq!(deltas.len(), 2);
+        assert!(deltas.iter().all(|d| d.action == BookAction::Clear));
+        assert_eq!(deltas[0].side, OrderSide::Buy);
+        assert_eq!(deltas[1].side, OrderSide::Sell);
+        assert_eq!(aggregator.order_count(), 0);
+        assert_eq!(aggregator.level_count(), 0);
+    }
+
+    #[test]
+    fn test_level_deltas_apply_to_l2_book() {
+        let mut aggregator = L2Aggregator::new();
+        let mut book = OrderBook::new(
+            InstrumentId::from_dotted("ETH/USDT.BINANCE"),
+            BookLevel::L2_MBP,
+        );
+        let mbo = [
+            (BookAction::Add, order("10.0", "5", OrderSide::Buy, 1)),
+            (BookAction::Add, order("10.0", "3", OrderSide::Buy, 2)),
+            (BookAction::Delete, order("10.0", "5", OrderSide::Buy, 1)),
+            (BookAction::Add, order("9.0", "2", OrderSide::Buy, 3)),
+            (BookAction::Add, order("11.0", "4", OrderSide::Sell, 4)),
+            (BookAction::Add, order("11.0", "1", OrderSide::Sell, 5)),
+            (BookAction::Update, order("12.0", "1", OrderSide::Sell, 5)),
+        ];
+
+        for (action, order) in mbo {
+            for delta in aggregator.apply(action, &order) {
+                match delta.action {
+                    BookAction::Add => book.add(delta.to_order(), 0),
+                    BookAction::Update => book.update(delta.to_order(), 0),
+                    BookAction::Delete => book.delete(delta.to_order(), 0),
+                    BookAction::Clear => {}
+                }
+            }
+        }
+
+        let top = book.top_of_book();
+        assert_eq!(top.bid.as_ref().unwrap().price, Price::from("10.0"));
+        assert_eq!(top.bid.unwrap().size, Quantity::from("3"));
+        assert_eq!(top.ask.as_ref().unwrap().price, Price::from("11.0"));
+        assert_eq!(top.ask.unwrap().size, Quantity::from("4"));
+        assert_eq!(
+            book.volume_at_or_better(OrderSide::Buy, &Price::from("9.0")),
+            5.0
+        );
+        assert_eq!(
+            book.volume_at_or_better(OrderSide::Sell, &Price::from("12.0")),
+            5.0
+        );
+        assert_eq!(
+            book.volume_at_or_better(OrderSide::Sell, &Price::from("11.0")),
+            4.0
+        );
+    }
+}
@@ -15,8 +15,12 @@
 
 use crate::enums::{BookLevel, OrderSide};
 use crate::identifiers::instrument_id::InstrumentId;
+use crate::orderbook::implied::{BookQuote, TopOfBook};
 use crate::orderbook::ladder::Ladder;
+use crate::orderbook::level::Level;
 use crate::orderbook::order::Order;
+use crate::types::price::Price;
+use crate::types::quantity::Quantity;
 
 #[repr(C)]
 pub struct OrderBook {
@@ -70,6 +74,30 @@ impl OrderBook {
             OrderSide::Sell => self.asks.delete(order),
         }
     }
+
+    /// Returns the total size resting on the side at or better than the price.
+    pub fn volume_at_or_better(&self, side: OrderSide, price: &Price) -> f64 {
+        match side {
+            OrderSide::Buy => self.bids.volume_at_or_better(price),
+            OrderSide::Sell => self.asks.volume_at_or_better(price),
+        }
+    }
+
+    /// Returns the best bid and ask prices with their aggregate sizes.
+    pub fn top_of_book(&self) -> TopOfBook {
+        TopOfBook {
+            bid: self.bids.top().map(level_quote),
+            ask: self.asks.top().map(level_quote),
+        }
+    }
+}
+
+fn level_quote(level: &Level) -> BookQuote {
+    let precision = level.orders.first().map_or(0, |o| o.size.precision);
+    BookQuote {
+        price: level.price.value.clone(),
+        size: Quantity::from_raw(level.orders.iter().map(|o| o.size.raw).sum(), precision),
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
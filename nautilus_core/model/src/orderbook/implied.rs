@@ -0,0 +1,293 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Implied liquidity for 1:1 calendar spreads (front minus back).
+//!
+//! Spread prices are quoted as front leg price less back leg price, so
+//! buying the spread buys the front leg and sells the back leg. Exchanges
+//! which imply prices combine resting orders in two of the three books to
+//! offer liquidity in the third:
+//!
+//! - implied in: spread bid = front bid - back ask, spread ask = front ask - back bid.
+//! - implied out (front): bid = spread bid + back bid, ask = spread ask + back ask.
+//! - implied out (back): bid = front bid - spread ask, ask = front ask - spread bid.
+//!
+//! Implied sizes are the smaller of the two contributing sizes.
+
+use crate::types::price::Price;
+use crate::types::quantity::Quantity;
+
+/// Represents a price with its available size.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BookQuote {
+    pub price: Price,
+    pub size: Quantity,
+}
+
+impl BookQuote {
+    pub fn new(price: Price, size: Quantity) -> Self {
+        BookQuote { price, size }
+    }
+}
+
+/// Represents the best bid and ask of a book, either of which may be absent.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TopOfBook {
+    pub bid: Option<BookQuote>,
+    pub ask: Option<BookQuote>,
+}
+
+/// Represents the books of a calendar spread.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum SpreadLeg {
+    Front,
+    Back,
+    Spread,
+}
+
+fn combine(a: &Option<BookQuote>, b: &Option<BookQuote>, add: bool) -> Option<BookQuote> {
+    let (a, b) = (a.as_ref()?, b.as_ref()?);
+    let raw = if add {
+        a.price.raw + b.price.raw
+    } else {
+        a.price.raw - b.price.raw
+    };
+    let precision = a.price.precision.max(b.price.precision);
+    Some(BookQuote {
+        price: Price::from_raw(raw, precision),
+        size: a.size.clone().min(b.size.clone()),
+    })
+}
+
+/// Returns the better of the direct and implied quotes, summing sizes when
+/// the prices are equal.
+fn merge(
+    direct: &Option<BookQuote>,
+    implied: &Option<BookQuote>,
+    is_bid: bool,
+) -> Option<BookQuote> {
+    match (direct, implied) {
+        (Some(d), Some(i)) if d.price == i.price => Some(BookQuote {
+            price: d.price.clone(),
+            size: Quantity::from_raw(d.size.raw + i.size.raw, d.size.precision),
+        }),
+        (Some(d), Some(i)) => {
+            let implied_better = if is_bid {
+                i.price > d.price
+            } else {
+                i.price < d.price
+            };
+            Some(if implied_better { i.clone() } else { d.clone() })
+        }
+        (Some(q), None) | (None, Some(q)) => Some(q.clone()),
+        (None, None) => None,
+    }
+}
+
+/// Returns the spread top of book implied from the outright legs.
+pub fn implied_spread(front: &TopOfBook, back: &TopOfBook) -> TopOfBook {
+    TopOfBook {
+        bid: combine(&front.bid, &back.ask, false),
+        ask: combine(&front.ask, &back.bid, false),
+    }
+}
+
+/// Returns the front leg top of book implied from the spread and back leg.
+pub fn implied_front(spread: &TopOfBook, back: &TopOfBook) -> TopOfBook {
+    TopOfBook {
+        bid: combine(&spread.bid, &back.bid, true),
+        ask: combine(&spread.ask, &back.ask, true),
+    }
+}
+
+/// Returns the back leg top of book implied from the front leg and spread.
+pub fn implied_back(front: &TopOfBook, spread: &TopOfBook) -> TopOfBook {
+    TopOfBook {
+        bid: combine(&front.bid, &spread.ask, false),
+        ask: combine(&front.ask, &spread.bid, false),
+    }
+}
+
+/// Provides the direct and implied top of book for each book of a calendar
+/// spread, for matching against exchanges which imply prices.
+#[derive(Clone, Debug, Default)]
+pub struct ImpliedBooks {
+    front: TopOfBook,
+    back: TopOfBook,
+    spread: TopOfBook,
+}
+
+impl ImpliedBooks {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Updates the direct top of book for the given leg.
+    pub fn update(&mut self, leg: SpreadLeg, top: TopOfBook) {
+        match leg {
+            SpreadLeg::Front => self.front = top,
+            SpreadLeg::Back => self.back = top,
+            SpreadLeg::Spread => self.spread = top,
+        }
+    }
+
+    /// Returns the direct top of book for the given leg.
+    pub fn direct(&self, leg: SpreadLeg) -> &TopOfBook {
+        match leg {
+            SpreadLeg::Front => &self.front,
+            SpreadLeg::Back => &self.back,
+            SpreadLeg::Spread => &self.spread,
+        }
+    }
+
+    /// Returns the top of book implied for the given leg from the other two
+    /// (direct) books.
+    pub fn implied(&self, leg: SpreadLeg) -> TopOfBook {
+        match leg {
+            SpreadLeg::Front => implied_front(&self.spread, &self.back),
+            SpreadLeg::Back => implied_back(&self.front, &self.spread),
+            SpreadLeg::Spread => implied_spread(&self.front, &self.back),
+        }
+    }
+
+    /// Returns the best available top of book for the given leg, including
+    /// implied liquidity.
+    pub fn best(&self, leg: SpreadLeg) -> TopOfBook {
+        let direct = self.direct(leg);
+        let implied = self.implied(leg);
+        TopOfBook {
+            bid: merge(&direct.bid, &implied.bid, true),
+            ask: merge(&direct.ask, &implied.ask, false),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::{implied_spread, BookQuote, ImpliedBooks, SpreadLeg, TopOfBook};
+    use crate::enums::{BookLevel, OrderSide};
+    use crate::identifiers::instrument_id::InstrumentId;
+    use crate::orderbook::book::OrderBook;
+    use crate::orderbook::order::Order;
+    use crate::types::price::Price;
+    use crate::types::quantity::Quantity;
+
+    fn quote(price: &str, size: &str) -> Option<BookQuote> {
+        Some(BookQuote::new(Price::from(price), Quantity::from(size)))
+    }
+
+    fn top(bid: (&str, &str), ask: (&str, &str)) -> TopOfBook {
+        TopOfBook {
+            bid: quote(bid.0, bid.1),
+            ask: quote(ask.0, ask.1),
+        }
+    }
+
+    fn books() -> ImpliedBooks {
+        let mut books = ImpliedBooks::new();
+        books.update(SpreadLeg::Front, top(("100.50", "10"), ("100.75", "5")));
+        books.update(SpreadLeg::Back, top(("101.00", "4"), ("101.25", "8")));
+        books
+    }
+
+    #[test]
+    fn test_implied_in_spread() {
+        let books = books();
+
+        let implied = books.implied(SpreadLeg::Spread);
+
+        assert_eq!(implied, top(("-0.75", "8"), ("-0.25", "4")));
+    }
+
+    #[test]
+    fn test_implied_out_legs() {
+        let mut books = books();
+        books.update(SpreadLeg::Spread, top(("-0.60", "2"), ("-0.40", "3")));
+
+        assert_eq!(
+            books.implied(SpreadLeg::Front),
+            top(("100.40", "2"), ("100.85", "3"))
+        );
+        assert_eq!(
+            books.implied(SpreadLeg::Back),
+            top(("100.90", "3"), ("101.35", "2"))
+        );
+    }
+
+    #[test]
+    fn test_implied_with_missing_side() {
+        let front = TopOfBook {
+            bid: quote("100.50", "10"),
+            ask: None,
+        };
+        let back = top(("101.00", "4"), ("101.25", "8"));
+
+        let implied = implied_spread(&front, &back);
+
+        assert_eq!(implied.bid, quote("-0.75", "8"));
+        assert_eq!(implied.ask, None);
+    }
+
+    #[test]
+    fn test_best_includes_implied_liquidity() {
+        let mut books = books();
+        books.update(SpreadLeg::Spread, top(("-0.75", "1"), ("-0.20", "6")));
+
+        let best = books.best(SpreadLeg::Spread);
+
+        // Equal bid prices aggregate, better implied ask replaces direct
+        assert_eq!(best, top(("-0.75", "9"), ("-0.25", "4")));
+    }
+
+    #[test]
+    fn test_order_book_top_of_book() {
+        let mut book = OrderBook::new(InstrumentId::from_dotted("ESZ2.GLBX"), BookLevel::L3_MBO);
+        book.add(
+            Order::new(
+                Price::from("100.50"),
+                Quantity::from("3"),
+                OrderSide::Buy,
+                1,
+            ),
+            0,
+        );
+        book.add(
+            Order::new(
+                Price::from("100.50"),
+                Quantity::from("2"),
+                OrderSide::Buy,
+                2,
+            ),
+            0,
+        );
+        book.add(
+            Order::new(
+                Price::from("100.25"),
+                Quantity::from("9"),
+                OrderSide::Buy,
+                3,
+            ),
+            0,
+        );
+
+        let top = book.top_of_book();
+
+        assert_eq!(top.bid, quote("100.50", "5"));
+        assert_eq!(top.ask, None);
+    }
+}
@@ -35,10 +35,7 @@ impl BookPrice {
 
 impl PartialOrd for BookPrice {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        match self.side {
-            OrderSide::Buy => Some(other.value.cmp(&self.value)),
-            OrderSide::Sell => Some(self.value.cmp(&other.value)),
-        }
+        Some(self.cmp(other))
     }
 }
 
@@ -136,11 +133,27 @@ impl Ladder {
     }
 
     pub fn volumes(&self) -> f64 {
-        return self.levels.iter().map(|(_, l)| l.volume()).sum();
+        self.levels.values().map(|l| l.volume()).sum()
+    }
+
+    /// Returns the total size of all levels in raw fixed-point units, widened
+    /// so it cannot overflow.
+    pub fn volumes_raw(&self) -> u128 {
+        self.levels.values().map(|l| l.volume_raw()).sum()
     }
 
     pub fn exposures(&self) -> f64 {
-        return self.levels.iter().map(|(_, l)| l.exposure()).sum();
+        self.levels.values().map(|l| l.exposure()).sum()
+    }
+
+    /// Returns the total size of the levels at or better than the price, being
+    /// the queue ahead of a new order joining the level.
+    pub fn volume_at_or_better(&self, price: &Price) -> f64 {
+        let book_price = BookPrice::new(price.clone(), self.side);
+        self.levels
+            .range(..=book_price)
+            .map(|(_, l)| l.volume())
+            .sum()
     }
 
     pub fn top(&self) -> Option<&Level> {
@@ -164,7 +177,7 @@ mod tests {
 
     #[test]
     fn test_book_price_bid_sorting() {
-        let mut bid_prices = vec![
+        let mut bid_prices = [
             BookPrice::new(Price::new(2.0, 0), OrderSide::Buy),
             BookPrice::new(Price::new(4.0, 0), OrderSide::Buy),
             BookPrice::new(Price::new(1.0, 0), OrderSide::Buy),
@@ -178,7 +191,7 @@ mod tests {
 
     #[test]
     fn test_book_price_ask_sorting() {
-        let mut ask_prices = vec![
+        let mut ask_prices = [
             BookPrice::new(Price::new(2.0, 0), OrderSide::Sell),
             BookPrice::new(Price::new(4.0, 0), OrderSide::Sell),
             BookPrice::new(Price::new(1.0, 0), OrderSide::Sell),
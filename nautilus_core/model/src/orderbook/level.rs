@@ -93,6 +93,12 @@ impl Level {
         sum
     }
 
+    /// Returns the total size in raw fixed-point units, widened so it cannot
+    /// overflow.
+    pub fn volume_raw(&self) -> u128 {
+        self.orders.iter().map(|o| o.size.raw as u128).sum()
+    }
+
     pub fn exposure(&self) -> f64 {
         let mut sum: f64 = 0.0;
         for o in self.orders.iter() {
@@ -112,7 +118,7 @@ impl Eq for Level {}
 
 impl PartialOrd for Level {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        self.price.partial_cmp(&other.price)
+        Some(self.cmp(other))
     }
 
     fn lt(&self, other: &Self) -> bool {
@@ -192,7 +198,7 @@ mod tests {
 
         level.add(order);
 
-        assert_eq!(level.is_empty(), false);
+        assert!(!level.is_empty());
         assert_eq!(level.len(), 1);
         assert_eq!(level.volume(), 10.0);
     }
@@ -221,6 +227,21 @@ mod tests {
         assert_eq!(level.exposure(), 60.0);
     }
 
+    #[test]
+    fn test_level_volume_raw_does_not_overflow() {
+        let mut level = Level::new(BookPrice::new(Price::new(2.00, 2), OrderSide::Buy));
+        for id in 0..3 {
+            level.add(Order::new(
+                Price::new(2.00, 2),
+                Quantity::from_raw(u64::MAX, 0),
+                OrderSide::Buy,
+                id,
+            ));
+        }
+
+        assert_eq!(level.volume_raw(), u64::MAX as u128 * 3);
+    }
+
     #[test]
     fn test_level_update_order() {
         let mut level = Level::new(BookPrice::new(Price::new(1.00, 2), OrderSide::Buy));
@@ -13,7 +13,10 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+pub mod aggregation;
 pub mod book;
+pub mod implied;
 pub mod ladder;
 pub mod level;
 pub mod order;
+pub mod queue;
@@ -0,0 +1,283 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Queue time estimation for passive orders.
+//!
+//! A passive order joins the back of the queue at its price, so it fills once
+//! the volume resting at or better than its price, plus its own quantity, has
+//! been depleted by trades and cancels. Depletion is modelled as a steady
+//! flow at the exponentially weighted rates observed for the book, giving the
+//! expected time to fill, with fills arriving memorylessly at that mean for
+//! the probability of filling within a horizon.
+
+use crate::enums::OrderSide;
+use crate::orderbook::book::OrderBook;
+use crate::types::price::Price;
+use crate::types::quantity::Quantity;
+
+/// Represents an exponentially weighted rate of volume per second.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct VolumeRate {
+    volume: f64,
+    ts_last: i64,
+}
+
+impl VolumeRate {
+    fn decayed(&self, ts: i64, half_life_ns: u64) -> f64 {
+        let elapsed_ns = ts.saturating_sub(self.ts_last).max(0) as f64;
+        self.volume * 0.5_f64.powf(elapsed_ns / half_life_ns as f64)
+    }
+
+    fn add(&mut self, size: f64, ts: i64, half_life_ns: u64) {
+        self.volume = self.decayed(ts, half_life_ns) + size;
+        self.ts_last = self.ts_last.max(ts);
+    }
+
+    /// Returns the volume per second at `ts`, being the decayed volume over
+    /// its mean lifetime.
+    fn per_second(&self, ts: i64, half_life_ns: u64) -> f64 {
+        let mean_lifetime_secs = half_life_ns as f64 / std::f64::consts::LN_2 / 1e9;
+        self.decayed(ts, half_life_ns) / mean_lifetime_secs
+    }
+}
+
+/// Represents a queue time estimate for a passive order.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct QueueEstimate {
+    /// The volume resting at or better than the order price.
+    pub queue_ahead: f64,
+    /// The expected time to fill in seconds (infinite with no observed flow).
+    pub expected_time_secs: f64,
+    /// The probability of filling within the horizon.
+    pub fill_probability: f64,
+}
+
+/// Provides queue time estimates from the trade and cancel flow observed for
+/// an order book.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct QueueEstimator {
+    pub half_life_ns: u64,
+    traded: VolumeRate,
+    canceled: VolumeRate,
+}
+
+impl QueueEstimator {
+    pub fn new(half_life_ns: u64) -> Self {
+        assert!(half_life_ns > 0, "`half_life_ns` was zero");
+        QueueEstimator {
+            half_life_ns,
+            traded: VolumeRate::default(),
+            canceled: VolumeRate::default(),
+        }
+    }
+
+    /// Records a trade at the touch.
+    pub fn on_trade(&mut self, size: f64, ts_event: i64) {
+        self.traded.add(size, ts_event, self.half_life_ns);
+    }
+
+    /// Records resting volume canceled at the touch.
+    pub fn on_cancel(&mut self, size: f64, ts_event: i64) {
+        self.canceled.add(size, ts_event, self.half_life_ns);
+    }
+
+    /// Returns the queue depletion rate (volume per second) at `ts`.
+    pub fn depletion_rate(&self, ts: i64) -> f64 {
+        self.traded.per_second(ts, self.half_life_ns)
+            + self.canceled.per_second(ts, self.half_life_ns)
+    }
+
+    /// Returns the estimate for an order of `quantity` joining the `side`
+    /// queue at `price`, filling within `horizon_secs` of `ts`.
+    pub fn estimate(
+        &self,
+        book: &OrderBook,
+        side: OrderSide,
+        price: &Price,
+        quantity: &Quantity,
+        horizon_secs: f64,
+        ts: i64,
+    ) -> QueueEstimate {
+        let queue_ahead = book.volume_at_or_better(side, price);
+        let rate = self.depletion_rate(ts);
+        let expected_time_secs = if rate > 0.0 {
+            (queue_ahead + quantity.as_f64()) / rate
+        } else {
+            f64::INFINITY
+        };
+        QueueEstimate {
+            queue_ahead,
+            expected_time_secs,
+            fill_probability: 1.0 - (-horizon_secs.max(0.0) / expected_time_secs).exp(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// C API
+////////////////////////////////////////////////////////////////////////////////
+#[no_mangle]
+pub extern "C" fn queue_estimator_new(half_life_ns: u64) -> QueueEstimator {
+    QueueEstimator::new(half_life_ns)
+}
+
+#[no_mangle]
+pub extern "C" fn queue_estimator_on_trade(
+    estimator: &mut QueueEstimator,
+    size: f64,
+    ts_event: i64,
+) {
+    estimator.on_trade(size, ts_event);
+}
+
+#[no_mangle]
+pub extern "C" fn queue_estimator_on_cancel(
+    estimator: &mut QueueEstimator,
+    size: f64,
+    ts_event: i64,
+) {
+    estimator.on_cancel(size, ts_event);
+}
+
+#[no_mangle]
+pub extern "C" fn queue_estimator_estimate(
+    estimator: &QueueEstimator,
+    book: &OrderBook,
+    side: OrderSide,
+    price: &Price,
+    quantity: &Quantity,
+    horizon_secs: f64,
+    ts: i64,
+) -> QueueEstimate {
+    estimator.estimate(book, side, price, quantity, horizon_secs, ts)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enums::BookLevel;
+    use crate::identifiers::instrument_id::InstrumentId;
+    use crate::orderbook::order::Order;
+
+    const SECOND: i64 = 1_000_000_000;
+
+    fn book() -> OrderBook {
+        let mut book = OrderBook::new(
+            InstrumentId::from_dotted("ETH/USDT.BINANCE"),
+            BookLevel::L3_MBO,
+        );
+        let orders = [
+            (100.0, 5.0, 1),
+            (100.0, 3.0, 2),
+            (99.0, 10.0, 3),
+            (101.0, 7.0, 4),
+        ];
+        for (price, size, id) in orders {
+            let side = if price > 100.0 {
+                OrderSide::Sell
+            } else {
+                OrderSide::Buy
+            };
+            book.add(
+                Order::new(Price::new(price, 2), Quantity::new(size, 0), side, id),
+                0,
+            );
+        }
+        book
+    }
+
+    #[test]
+    fn test_estimate_with_no_flow() {
+        let estimator = QueueEstimator::new(60 * SECOND as u64);
+
+        let estimate = estimator.estimate(
+            &book(),
+            OrderSide::Buy,
+            &Price::new(100.0, 2),
+            &Quantity::new(2.0, 0),
+            10.0,
+            0,
+        );
+
+        assert_eq!(estimate.queue_ahead, 8.0);
+        assert_eq!(estimate.expected_time_secs, f64::INFINITY);
+        assert_eq!(estimate.fill_probability, 0.0);
+    }
+
+    #[test]
+    fn test_estimate_queue_ahead_includes_better_levels() {
+        let estimator = QueueEstimator::new(60 * SECOND as u64);
+        let book = book();
+
+        let buy = estimator.estimate(
+            &book,
+            OrderSide::Buy,
+            &Price::new(99.0, 2),
+            &Quantity::new(1.0, 0),
+            1.0,
+            0,
+        );
+        let sell = estimator.estimate(
+            &book,
+            OrderSide::Sell,
+            &Price::new(101.5, 2),
+            &Quantity::new(1.0, 0),
+            1.0,
+            0,
+        );
+
+        assert_eq!(buy.queue_ahead, 18.0);
+        assert_eq!(sell.queue_ahead, 7.0);
+    }
+
+    #[test]
+    fn test_estimate_with_flow() {
+        let mut estimator = queue_estimator_new(SECOND as u64);
+        queue_estimator_on_trade(&mut estimator, 4.0, 0);
+        queue_estimator_on_cancel(&mut estimator, 1.0, 0);
+        let rate = estimator.depletion_rate(0);
+
+        let estimate = queue_estimator_estimate(
+            &estimator,
+            &book(),
+            OrderSide::Buy,
+            &Price::new(100.0, 2),
+            &Quantity::new(2.0, 0),
+            1.0,
+            0,
+        );
+
+        assert!((rate - 5.0 * std::f64::consts::LN_2).abs() < 1e-12);
+        assert!((estimate.expected_time_secs - 10.0 / rate).abs() < 1e-12);
+        assert!(estimate.fill_probability > 0.0 && estimate.fill_probability < 1.0);
+    }
+
+    #[test]
+    fn test_depletion_rate_decays() {
+        let mut estimator = QueueEstimator::new(SECOND as u64);
+        estimator.on_trade(8.0, 0);
+
+        assert!(
+            (estimator.depletion_rate(2 * SECOND) - estimator.depletion_rate(0) / 4.0).abs()
+                < 1e-12
+        );
+    }
+}
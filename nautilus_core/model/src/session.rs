@@ -0,0 +1,253 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Running per-instrument session statistics.
+//!
+//! The data engine records every trade tick into a process wide registry, so
+//! strategies and risk checks can query the session open, high, low, last,
+//! VWAP and volume for an instrument without aggregating bars.
+
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+
+use crate::data::tick::TradeTick;
+use crate::identifiers::instrument_id::InstrumentId;
+use crate::types::accumulator::{TurnoverAccumulator, VolumeAccumulator};
+use crate::types::fixed::FIXED_PRECISION;
+use crate::types::price::Price;
+use crate::types::quantity::Quantity;
+
+/// Represents a snapshot of the session statistics for an instrument.
+///
+/// The prices are zero when `trade_count` is zero.
+#[repr(C)]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SessionStats {
+    /// The price of the first trade of the session.
+    pub open: Price,
+    /// The highest trade price of the session.
+    pub high: Price,
+    /// The lowest trade price of the session.
+    pub low: Price,
+    /// The price of the latest trade of the session.
+    pub last: Price,
+    /// The volume weighted average trade price of the session.
+    pub vwap: Price,
+    /// The total traded volume of the session (saturating).
+    pub volume: Quantity,
+    /// The number of trades of the session.
+    pub trade_count: u64,
+    /// The UNIX timestamp (nanoseconds) when the session started.
+    pub ts_start: i64,
+    /// The UNIX timestamp (nanoseconds) of the latest trade.
+    pub ts_last: i64,
+}
+
+/// Provides the running session statistics for an instrument.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SessionStatsAccumulator {
+    open: Price,
+    high: Price,
+    low: Price,
+    last: Price,
+    volume: VolumeAccumulator,
+    turnover: TurnoverAccumulator,
+    ts_start: i64,
+    ts_last: i64,
+}
+
+impl SessionStatsAccumulator {
+    pub fn new(ts_start: i64) -> Self {
+        SessionStatsAccumulator {
+            ts_start,
+            ..Self::default()
+        }
+    }
+
+    pub fn update(&mut self, price: &Price, size: &Quantity, ts_event: i64) {
+        if self.volume.count() == 0 {
+            self.open = price.clone();
+            self.high = price.clone();
+            self.low = price.clone();
+            self.volume = VolumeAccumulator::new(size.precision);
+        } else {
+            if price > &self.high {
+                self.high = price.clone();
+            }
+            if price < &self.low {
+                self.low = price.clone();
+            }
+        }
+        self.last = price.clone();
+        self.volume.add(size);
+        self.turnover.add(price, size);
+        self.ts_last = ts_event;
+    }
+
+    pub fn update_trade(&mut self, tick: &TradeTick) {
+        self.update(&tick.price, &tick.size, tick.ts_event.value);
+    }
+
+    /// Returns the VWAP truncated to the precision of the last price.
+    fn vwap(&self) -> Price {
+        let volume = self.volume.raw();
+        if volume == 0 {
+            return Price::default();
+        }
+        // Turnover is at twice `FIXED_PRECISION`, so the quotient is at it
+        let raw = i64::try_from(self.turnover.raw() / volume as i128).unwrap_or(i64::MAX);
+        let precision = self.last.precision;
+        let truncation = 10_i64.pow((FIXED_PRECISION - precision) as u32);
+        Price::from_raw(raw / truncation * truncation, precision)
+    }
+
+    pub fn snapshot(&self) -> SessionStats {
+        SessionStats {
+            open: self.open.clone(),
+            high: self.high.clone(),
+            low: self.low.clone(),
+            last: self.last.clone(),
+            vwap: self.vwap(),
+            volume: self.volume.saturating_quantity(),
+            trade_count: self.volume.count(),
+            ts_start: self.ts_start,
+            ts_last: self.ts_last,
+        }
+    }
+}
+
+static SESSION_STATS: OnceLock<Mutex<HashMap<InstrumentId, SessionStatsAccumulator>>> =
+    OnceLock::new();
+
+fn registry() -> &'static Mutex<HashMap<InstrumentId, SessionStatsAccumulator>> {
+    SESSION_STATS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Records a trade for the instrument, starting a session at the trade if
+/// none has been started.
+pub fn record_trade(tick: &TradeTick) {
+    let mut registry = registry().lock().unwrap();
+    registry
+        .entry(tick.instrument_id.clone())
+        .or_insert_with(|| SessionStatsAccumulator::new(tick.ts_event.value))
+        .update_trade(tick);
+}
+
+/// Starts a new session for the instrument, discarding the current one.
+pub fn start_session(instrument_id: &InstrumentId, ts_start: i64) {
+    let mut registry = registry().lock().unwrap();
+    registry.insert(
+        instrument_id.clone(),
+        SessionStatsAccumulator::new(ts_start),
+    );
+}
+
+/// Returns the session statistics for the instrument, or `None` if no
+/// session has been started.
+pub fn session_stats(instrument_id: &InstrumentId) -> Option<SessionStats> {
+    let registry = registry().lock().unwrap();
+    registry
+        .get(instrument_id)
+        .map(SessionStatsAccumulator::snapshot)
+}
+
+pub fn reset_session_stats() {
+    registry().lock().unwrap().clear();
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// C API
+////////////////////////////////////////////////////////////////////////////////
+#[no_mangle]
+pub extern "C" fn session_stats_record_trade(tick: &TradeTick) {
+    record_trade(tick);
+}
+
+#[no_mangle]
+pub extern "C" fn session_stats_start(instrument_id: &InstrumentId, ts_start: i64) {
+    start_session(instrument_id, ts_start);
+}
+
+/// Returns the session statistics for the instrument (all zero if no session
+/// has been started).
+#[no_mangle]
+pub extern "C" fn session_stats_get(instrument_id: &InstrumentId) -> SessionStats {
+    session_stats(instrument_id).unwrap_or_default()
+}
+
+#[no_mangle]
+pub extern "C" fn session_stats_reset() {
+    reset_session_stats();
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accumulator_snapshot() {
+        let mut stats = SessionStatsAccumulator::new(1);
+
+        stats.update(&Price::new(100.00, 2), &Quantity::new(1.0, 0), 2);
+        stats.update(&Price::new(102.00, 2), &Quantity::new(3.0, 0), 3);
+        stats.update(&Price::new(99.50, 2), &Quantity::new(1.0, 0), 4);
+        let snapshot = stats.snapshot();
+
+        assert_eq!(snapshot.open, Price::new(100.00, 2));
+        assert_eq!(snapshot.high, Price::new(102.00, 2));
+        assert_eq!(snapshot.low, Price::new(99.50, 2));
+        assert_eq!(snapshot.last, Price::new(99.50, 2));
+        assert_eq!(snapshot.vwap, Price::new(101.10, 2)); // 505.50 / 5
+        assert_eq!(snapshot.volume, Quantity::new(5.0, 0));
+        assert_eq!(snapshot.trade_count, 3);
+        assert_eq!((snapshot.ts_start, snapshot.ts_last), (1, 4));
+    }
+
+    #[test]
+    fn test_accumulator_snapshot_when_empty() {
+        let snapshot = SessionStatsAccumulator::new(5).snapshot();
+
+        assert_eq!(snapshot.trade_count, 0);
+        assert_eq!(snapshot.vwap, Price::default());
+        assert_eq!(snapshot.ts_start, 5);
+    }
+
+    #[test]
+    fn test_registry_start_session_discards_previous() {
+        let instrument_id = InstrumentId::from_dotted("ETH/USD.SESSION-TEST");
+
+        start_session(&instrument_id, 0);
+        registry()
+            .lock()
+            .unwrap()
+            .get_mut(&instrument_id)
+            .unwrap()
+            .update(&Price::new(10.0, 1), &Quantity::new(1.0, 0), 1);
+        assert_eq!(session_stats_get(&instrument_id).trade_count, 1);
+
+        session_stats_start(&instrument_id, 10);
+        let snapshot = session_stats_get(&instrument_id);
+
+        assert_eq!(snapshot.trade_count, 0);
+        assert_eq!(snapshot.ts_start, 10);
+        assert_eq!(
+            session_stats(&InstrumentId::from_dotted("BTC/USD.SESSION-TEST")),
+            None
+        );
+    }
+}
@@ -0,0 +1,300 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Running per-instrument trading statistics.
+//!
+//! The execution engine records every fill and closed round trip into a
+//! process wide registry, which live dashboards query by instrument without
+//! going through the cache or the position history.
+
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+
+use crate::enums::OrderSide;
+use crate::identifiers::instrument_id::InstrumentId;
+use crate::types::accumulator::VolumeAccumulator;
+use crate::types::price::Price;
+use crate::types::quantity::Quantity;
+
+/// Represents a snapshot of the trading statistics for an instrument.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TradingStats {
+    /// The number of fills.
+    pub trade_count: u64,
+    /// The total quantity filled.
+    pub volume: f64,
+    /// The total commissions, in the commission currency of the instrument.
+    pub fees: f64,
+    /// The number of round trips closed with a positive realized PnL.
+    pub win_count: u64,
+    /// The number of round trips closed with a zero or negative realized PnL.
+    pub loss_count: u64,
+    /// The ratio of wins to closed round trips (zero if none).
+    pub win_rate: f64,
+    /// The average slippage (price points, positive when adverse) of fills
+    /// with an expected price (zero if none).
+    pub avg_slippage: f64,
+}
+
+/// Provides the running trading statistics for an instrument.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TradingStatsAccumulator {
+    volume: VolumeAccumulator,
+    fees: f64,
+    win_count: u64,
+    loss_count: u64,
+    slippage_sum: f64,
+    slippage_count: u64,
+}
+
+impl TradingStatsAccumulator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records a fill, measuring slippage against the expected price if
+    /// given (e.g. the order price).
+    pub fn record_fill(
+        &mut self,
+        side: OrderSide,
+        last_qty: &Quantity,
+        last_px: &Price,
+        commission: f64,
+        expected_px: Option<&Price>,
+    ) {
+        if self.volume.count() == 0 {
+            self.volume = VolumeAccumulator::new(last_qty.precision);
+        }
+        self.volume.add(last_qty);
+        self.fees += commission;
+        if let Some(expected_px) = expected_px {
+            let points = last_px.as_f64() - expected_px.as_f64();
+            self.slippage_sum += match side {
+                OrderSide::Buy => points,
+                OrderSide::Sell => -points,
+            };
+            self.slippage_count += 1;
+        }
+    }
+
+    /// Records a closed round trip with the realized PnL.
+    pub fn record_round_trip(&mut self, realized_pnl: f64) {
+        if realized_pnl > 0.0 {
+            self.win_count += 1;
+        } else {
+            self.loss_count += 1;
+        }
+    }
+
+    pub fn snapshot(&self) -> TradingStats {
+        let round_trips = self.win_count + self.loss_count;
+        TradingStats {
+            trade_count: self.volume.count(),
+            volume: self.volume.as_f64(),
+            fees: self.fees,
+            win_count: self.win_count,
+            loss_count: self.loss_count,
+            win_rate: if round_trips == 0 {
+                0.0
+            } else {
+                self.win_count as f64 / round_trips as f64
+            },
+            avg_slippage: if self.slippage_count == 0 {
+                0.0
+            } else {
+                self.slippage_sum / self.slippage_count as f64
+            },
+        }
+    }
+}
+
+static TRADING_STATS: OnceLock<Mutex<HashMap<InstrumentId, TradingStatsAccumulator>>> =
+    OnceLock::new();
+
+fn registry() -> &'static Mutex<HashMap<InstrumentId, TradingStatsAccumulator>> {
+    TRADING_STATS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Records a fill for the instrument (see `TradingStatsAccumulator::record_fill`).
+pub fn record_fill(
+    instrument_id: &InstrumentId,
+    side: OrderSide,
+    last_qty: &Quantity,
+    last_px: &Price,
+    commission: f64,
+    expected_px: Option<&Price>,
+) {
+    let mut registry = registry().lock().unwrap();
+    registry
+        .entry(instrument_id.clone())
+        .or_default()
+        .record_fill(side, last_qty, last_px, commission, expected_px);
+}
+
+/// Records a closed round trip for the instrument with the realized PnL.
+pub fn record_round_trip(instrument_id: &InstrumentId, realized_pnl: f64) {
+    let mut registry = registry().lock().unwrap();
+    registry
+        .entry(instrument_id.clone())
+        .or_default()
+        .record_round_trip(realized_pnl);
+}
+
+/// Returns the trading statistics for the instrument, or `None` if nothing has
+/// been recorded.
+pub fn trading_stats(instrument_id: &InstrumentId) -> Option<TradingStats> {
+    let registry = registry().lock().unwrap();
+    registry
+        .get(instrument_id)
+        .map(TradingStatsAccumulator::snapshot)
+}
+
+/// Returns the trading statistics for all instruments with recorded activity.
+pub fn all_trading_stats() -> HashMap<InstrumentId, TradingStats> {
+    let registry = registry().lock().unwrap();
+    registry
+        .iter()
+        .map(|(instrument_id, stats)| (instrument_id.clone(), stats.snapshot()))
+        .collect()
+}
+
+pub fn reset_trading_stats() {
+    registry().lock().unwrap().clear();
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// C API
+////////////////////////////////////////////////////////////////////////////////
+/// Records a fill for the instrument, measuring slippage against the expected
+/// price unless `expected_px` is null.
+///
+/// # Safety
+///
+/// - `expected_px` must be null or a valid pointer to a `Price`.
+#[no_mangle]
+pub unsafe extern "C" fn trading_stats_record_fill(
+    instrument_id: &InstrumentId,
+    side: OrderSide,
+    last_qty: &Quantity,
+    last_px: &Price,
+    commission: f64,
+    expected_px: *const Price,
+) {
+    record_fill(
+        instrument_id,
+        side,
+        last_qty,
+        last_px,
+        commission,
+        expected_px.as_ref(),
+    );
+}
+
+#[no_mangle]
+pub extern "C" fn trading_stats_record_round_trip(instrument_id: &InstrumentId, realized_pnl: f64) {
+    record_round_trip(instrument_id, realized_pnl);
+}
+
+/// Returns the trading statistics for the instrument (all zero if nothing has
+/// been recorded).
+#[no_mangle]
+pub extern "C" fn trading_stats_get(instrument_id: &InstrumentId) -> TradingStats {
+    trading_stats(instrument_id).unwrap_or_default()
+}
+
+#[no_mangle]
+pub extern "C" fn trading_stats_reset() {
+    reset_trading_stats();
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accumulator_snapshot() {
+        let mut stats = TradingStatsAccumulator::new();
+        let expected = Price::new(1.00000, 5);
+
+        stats.record_fill(
+            OrderSide::Buy,
+            &Quantity::new(100.0, 0),
+            &Price::new(1.00002, 5),
+            2.0,
+            Some(&expected),
+        );
+        stats.record_fill(
+            OrderSide::Sell,
+            &Quantity::new(50.0, 0),
+            &Price::new(1.00004, 5),
+            1.0,
+            Some(&expected),
+        );
+        stats.record_fill(
+            OrderSide::Sell,
+            &Quantity::new(50.0, 0),
+            &Price::new(0.99000, 5),
+            1.0,
+            None,
+        );
+        stats.record_round_trip(10.0);
+        stats.record_round_trip(-5.0);
+        stats.record_round_trip(20.0);
+        let snapshot = stats.snapshot();
+
+        assert_eq!(snapshot.trade_count, 3);
+        assert_eq!(snapshot.volume, 200.0);
+        assert_eq!(snapshot.fees, 4.0);
+        assert_eq!((snapshot.win_count, snapshot.loss_count), (2, 1));
+        assert!((snapshot.win_rate - 2.0 / 3.0).abs() < 1e-12);
+        assert!((snapshot.avg_slippage - -0.00001).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_accumulator_snapshot_when_empty() {
+        assert_eq!(
+            TradingStatsAccumulator::new().snapshot(),
+            TradingStats::default()
+        );
+    }
+
+    #[test]
+    fn test_registry_is_keyed_by_instrument() {
+        let audusd = InstrumentId::from_dotted("AUD/USD.STATS-TEST");
+        let gbpusd = InstrumentId::from_dotted("GBP/USD.STATS-TEST");
+
+        unsafe {
+            trading_stats_record_fill(
+                &audusd,
+                OrderSide::Buy,
+                &Quantity::new(100.0, 0),
+                &Price::new(1.0, 5),
+                0.5,
+                std::ptr::null(),
+            );
+        }
+        trading_stats_record_round_trip(&audusd, 1.0);
+
+        assert_eq!(trading_stats_get(&audusd).trade_count, 1);
+        assert_eq!(trading_stats_get(&audusd).win_rate, 1.0);
+        assert_eq!(trading_stats_get(&gbpusd), TradingStats::default());
+        assert_eq!(trading_stats(&gbpusd), None);
+        assert!(all_trading_stats().contains_key(&audusd));
+    }
+}
@@ -0,0 +1,473 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Mapping between canonical instrument IDs and external identifiers.
+//!
+//! An external identifier is a code within a scheme, being `FIGI`, `ISIN` or
+//! the venue name for exchange-native codes (e.g. "BTCUSDT" in "BINANCE").
+//! Each instrument has at most one code per scheme, and each code maps to a
+//! single instrument.
+//!
+//! Mappings load from CSV with an `instrument_id,scheme,code` header row, or
+//! (with the `json` feature) from a JSON object of instrument IDs to objects
+//! of scheme codes, e.g. `{"AAPL.NASDAQ": {"FIGI": "BBG000B9XRY4"}}`.
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::Path;
+use std::sync::{Mutex, OnceLock};
+
+use nautilus_core::string::{pystr_to_string, string_to_pystr};
+use pyo3::{ffi, IntoPyPointer, Python};
+
+use crate::identifiers::instrument_id::InstrumentId;
+
+pub const FIGI_SCHEME: &str = "FIGI";
+pub const ISIN_SCHEME: &str = "ISIN";
+
+const CSV_HEADER: &str = "instrument_id,scheme,code";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SymbologyError {
+    /// The external identifier is already mapped to another instrument, or the
+    /// instrument to another code in the scheme.
+    Conflict(String),
+    /// The mapping source could not be parsed.
+    Parse(String),
+    /// The mapping source could not be read.
+    Io(String),
+}
+
+impl SymbologyError {
+    /// Returns the (non-zero) error code for the C API.
+    pub fn code(&self) -> u8 {
+        match self {
+            SymbologyError::Conflict(_) => 1,
+            SymbologyError::Parse(_) => 2,
+            SymbologyError::Io(_) => 3,
+        }
+    }
+}
+
+impl Display for SymbologyError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SymbologyError::Conflict(reason) => write!(f, "Symbology conflict, {}", reason),
+            SymbologyError::Parse(reason) => write!(f, "Invalid symbology, {}", reason),
+            SymbologyError::Io(reason) => write!(f, "Cannot read symbology, {}", reason),
+        }
+    }
+}
+
+impl Error for SymbologyError {}
+
+pub type SymbologyResult<T> = Result<T, SymbologyError>;
+
+/// Provides bidirectional lookup between instrument IDs and external codes.
+#[derive(Clone, Debug, Default)]
+pub struct SymbologyMap {
+    to_instrument: HashMap<(String, String), InstrumentId>,
+    to_code: HashMap<(InstrumentId, String), String>,
+}
+
+impl SymbologyMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.to_code.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.to_code.is_empty()
+    }
+
+    /// Maps the instrument to the code in the scheme, which is a no-op if
+    /// already mapped.
+    pub fn insert(
+        &mut self,
+        instrument_id: &InstrumentId,
+        scheme: &str,
+        code: &str,
+    ) -> SymbologyResult<()> {
+        let external = (scheme.to_string(), code.to_string());
+        if let Some(existing) = self.to_instrument.get(&external) {
+            if existing != instrument_id {
+                return Err(SymbologyError::Conflict(format!(
+                    "{} '{}' already mapped to {}",
+                    scheme, code, existing
+                )));
+            }
+        }
+        let key = (instrument_id.clone(), scheme.to_string());
+        if let Some(existing) = self.to_code.get(&key) {
+            if existing != code {
+                return Err(SymbologyError::Conflict(format!(
+                    "{} already mapped to {} '{}'",
+                    instrument_id, scheme, existing
+                )));
+            }
+        }
+        self.to_instrument.insert(external, instrument_id.clone());
+        self.to_code.insert(key, code.to_string());
+        Ok(())
+    }
+
+    /// Returns the instrument ID for the code in the scheme.
+    pub fn instrument_id(&self, scheme: &str, code: &str) -> Option<&InstrumentId> {
+        self.to_instrument
+            .get(&(scheme.to_string(), code.to_string()))
+    }
+
+    /// Returns the code in the scheme for the instrument ID.
+    pub fn code(&self, instrument_id: &InstrumentId, scheme: &str) -> Option<&str> {
+        self.to_code
+            .get(&(instrument_id.clone(), scheme.to_string()))
+            .map(String::as_str)
+    }
+
+    /// Loads the mappings from CSV, returning the number of rows.
+    pub fn load_csv(&mut self, csv: &str) -> SymbologyResult<usize> {
+        let mut lines = csv
+            .lines()
+            .enumerate()
+            .filter(|(_, l)| !l.trim().is_empty());
+        match lines.next() {
+            Some((_, header)) if header.trim() == CSV_HEADER => {}
+            _ => {
+                return Err(SymbologyError::Parse(format!(
+                    "expected header '{}'",
+                    CSV_HEADER
+                )))
+            }
+        }
+        let mut count = 0;
+        for (i, line) in lines {
+            let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+            match fields[..] {
+                [instrument_id, scheme, code] if !scheme.is_empty() && !code.is_empty() => {
+                    self.insert(&parse_instrument_id(instrument_id, i + 1)?, scheme, code)?;
+                    count += 1;
+                }
+                _ => {
+                    return Err(SymbologyError::Parse(format!(
+                        "line {} was not 'instrument_id,scheme,code'",
+                        i + 1
+                    )))
+                }
+            }
+        }
+        Ok(count)
+    }
+
+    /// Loads the mappings from a JSON object, returning the number of codes.
+    #[cfg(feature = "json")]
+    pub fn load_json(&mut self, json: &str) -> SymbologyResult<usize> {
+        use serde_json::{Map, Value};
+
+        let invalid = |reason: &str| SymbologyError::Parse(reason.to_string());
+        let root: Map<String, Value> =
+            serde_json::from_str(json).map_err(|e| invalid(&e.to_string()))?;
+        let mut count = 0;
+        for (instrument_id, codes) in root {
+            let instrument_id = instrument_id
+                .parse::<InstrumentId>()
+                .map_err(|e| invalid(&format!("instrument ID '{}', {}", instrument_id, e)))?;
+            let codes = codes.as_object().ok_or_else(|| {
+                invalid(&format!("codes for {} were not an object", instrument_id))
+            })?;
+            for (scheme, code) in codes {
+                let code = code.as_str().ok_or_else(|| {
+                    invalid(&format!(
+                        "{} code for {} was not a string",
+                        scheme, instrument_id
+                    ))
+                })?;
+                self.insert(&instrument_id, scheme, code)?;
+                count += 1;
+            }
+        }
+        Ok(count)
+    }
+
+    /// Loads the mappings from a `.csv` (or with the `json` feature, `.json`)
+    /// file, returning the number of codes.
+    pub fn load_file(&mut self, path: &Path) -> SymbologyResult<usize> {
+        let content = fs::read_to_string(path).map_err(|e| SymbologyError::Io(e.to_string()))?;
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("csv") => self.load_csv(&content),
+            #[cfg(feature = "json")]
+            Some("json") => self.load_json(&content),
+            _ => Err(SymbologyError::Parse(format!(
+                "unsupported file type '{}'",
+                path.display()
+            ))),
+        }
+    }
+}
+
+fn parse_instrument_id(value: &str, line: usize) -> SymbologyResult<InstrumentId> {
+    value.parse::<InstrumentId>().map_err(|e| {
+        SymbologyError::Parse(format!("line {} instrument ID '{}', {}", line, value, e))
+    })
+}
+
+static SYMBOLOGY: OnceLock<Mutex<SymbologyMap>> = OnceLock::new();
+
+/// Returns the process wide symbology used by the C API.
+pub fn symbology() -> &'static Mutex<SymbologyMap> {
+    SYMBOLOGY.get_or_init(|| Mutex::new(SymbologyMap::new()))
+}
+
+fn result_code(result: SymbologyResult<()>) -> u8 {
+    result.err().map_or(0, |e| e.code())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// C API
+////////////////////////////////////////////////////////////////////////////////
+/// Returns zero after loading the mappings from the file at the path into the
+/// process wide symbology, otherwise the non-zero error code (1 conflict,
+/// 2 parse, 3 I/O).
+///
+/// # Safety
+///
+/// - `path_ptr` must be borrowed from a valid Python UTF-8 `str`.
+#[no_mangle]
+pub unsafe extern "C" fn symbology_load_file(path_ptr: *mut ffi::PyObject) -> u8 {
+    let path = pystr_to_string(path_ptr);
+    let mut symbology = symbology().lock().unwrap();
+    result_code(symbology.load_file(Path::new(&path)).map(|_| ()))
+}
+
+/// Returns zero after mapping the instrument to the code in the scheme,
+/// otherwise the non-zero error code (1 conflict).
+///
+/// # Safety
+///
+/// - `scheme_ptr` and `code_ptr` must be borrowed from valid Python UTF-8 `str`(s).
+#[no_mangle]
+pub unsafe extern "C" fn symbology_insert(
+    instrument_id: &InstrumentId,
+    scheme_ptr: *mut ffi::PyObject,
+    code_ptr: *mut ffi::PyObject,
+) -> u8 {
+    let (scheme, code) = (pystr_to_string(scheme_ptr), pystr_to_string(code_ptr));
+    let mut symbology = symbology().lock().unwrap();
+    result_code(symbology.insert(instrument_id, &scheme, &code))
+}
+
+/// Writes the instrument ID for the code in the scheme to `out`, returning 1
+/// if mapped (otherwise 0 and `out` is unchanged).
+///
+/// # Safety
+///
+/// - `scheme_ptr` and `code_ptr` must be borrowed from valid Python UTF-8 `str`(s).
+/// - `out` must be a valid pointer to an initialized `InstrumentId`.
+#[no_mangle]
+pub unsafe extern "C" fn symbology_instrument_id(
+    scheme_ptr: *mut ffi::PyObject,
+    code_ptr: *mut ffi::PyObject,
+    out: *mut InstrumentId,
+) -> u8 {
+    let (scheme, code) = (pystr_to_string(scheme_ptr), pystr_to_string(code_ptr));
+    match symbology().lock().unwrap().instrument_id(&scheme, &code) {
+        Some(instrument_id) => {
+            *out = instrument_id.clone();
+            1
+        }
+        None => 0,
+    }
+}
+
+/// Returns the Python UTF-8 string of the code in the scheme for the
+/// instrument ID, or a new reference to `None` if not mapped.
+///
+/// # Safety
+///
+/// - `scheme_ptr` must be borrowed from a valid Python UTF-8 `str`.
+/// - Assumes the GIL is held, as when called from Python.
+#[no_mangle]
+pub unsafe extern "C" fn symbology_code_to_pystr(
+    instrument_id: &InstrumentId,
+    scheme_ptr: *mut ffi::PyObject,
+) -> *mut ffi::PyObject {
+    let scheme = pystr_to_string(scheme_ptr);
+    match symbology().lock().unwrap().code(instrument_id, &scheme) {
+        Some(code) => string_to_pystr(code),
+        None => Python::assume_gil_acquired().None().into_ptr(),
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn symbology_clear() {
+    *symbology().lock().unwrap() = SymbologyMap::new();
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CSV: &str = "\
+instrument_id,scheme,code
+AAPL.NASDAQ,FIGI,BBG000B9XRY4
+AAPL.NASDAQ,ISIN,US0378331005
+
+BTCUSDT-PERP.BINANCE,BINANCE,BTCUSDT
+";
+
+    #[test]
+    fn test_load_csv_and_lookup_both_ways() {
+        let mut map = SymbologyMap::new();
+
+        assert_eq!(map.load_csv(CSV), Ok(3));
+        assert_eq!(
+            map.instrument_id(ISIN_SCHEME, "US0378331005"),
+            Some(&InstrumentId::from_dotted("AAPL.NASDAQ"))
+        );
+        assert_eq!(
+            map.code(
+                &InstrumentId::from_dotted("BTCUSDT-PERP.BINANCE"),
+                "BINANCE"
+            ),
+            Some("BTCUSDT")
+        );
+        assert_eq!(
+            map.code(&InstrumentId::from_dotted("AAPL.NASDAQ"), "BINANCE"),
+            None
+        );
+        assert_eq!(map.instrument_id(FIGI_SCHEME, "US0378331005"), None);
+    }
+
+    #[test]
+    fn test_insert_conflicts() {
+        let mut map = SymbologyMap::new();
+        map.load_csv(CSV).unwrap();
+
+        assert_eq!(
+            map.insert(
+                &InstrumentId::from_dotted("MSFT.NASDAQ"),
+                FIGI_SCHEME,
+                "BBG000B9XRY4"
+            )
+            .map_err(|e| e.code()),
+            Err(1)
+        );
+        assert_eq!(
+            map.insert(
+                &InstrumentId::from_dotted("AAPL.NASDAQ"),
+                FIGI_SCHEME,
+                "BBG000BPH459"
+            )
+            .map_err(|e| e.code()),
+            Err(1)
+        );
+        assert_eq!(
+            map.insert(
+                &InstrumentId::from_dotted("AAPL.NASDAQ"),
+                FIGI_SCHEME,
+                "BBG000B9XRY4"
+            ),
+            Ok(())
+        );
+        assert_eq!(map.len(), 3);
+    }
+
+    #[test]
+    fn test_load_csv_invalid() {
+        let mut map = SymbologyMap::new();
+
+        assert!(matches!(
+            map.load_csv("symbol,code\nAAPL,1\n"),
+            Err(SymbologyError::Parse(_))
+        ));
+        assert_eq!(
+            map.load_csv("instrument_id,scheme,code\nAAPL.NASDAQ,FIGI\n"),
+            Err(SymbologyError::Parse(
+                "line 2 was not 'instrument_id,scheme,code'".to_string()
+            ))
+        );
+        assert!(matches!(
+            map.load_csv("instrument_id,scheme,code\nAAPL,FIGI,BBG000B9XRY4\n"),
+            Err(SymbologyError::Parse(_))
+        ));
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_load_json() {
+        let mut map = SymbologyMap::new();
+
+        let count = map.load_json(r#"{"AAPL.NASDAQ": {"FIGI": "BBG000B9XRY4", "NASDAQ": "AAPL"}}"#);
+
+        assert_eq!(count, Ok(2));
+        assert_eq!(
+            map.instrument_id("NASDAQ", "AAPL"),
+            Some(&InstrumentId::from_dotted("AAPL.NASDAQ"))
+        );
+        assert!(matches!(
+            map.load_json(r#"{"AAPL.NASDAQ": ["BBG000B9XRY4"]}"#),
+            Err(SymbologyError::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn test_load_file_unsupported() {
+        let mut map = SymbologyMap::new();
+
+        assert!(matches!(
+            map.load_file(Path::new("missing.csv")),
+            Err(SymbologyError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn test_c_api() {
+        pyo3::prepare_freethreaded_python();
+        let gil = Python::acquire_gil();
+        let _py = gil.python();
+        let instrument_id = InstrumentId::from_dotted("ETH/USDT.SYMBOLOGY");
+        let mut out = InstrumentId::from_dotted("NULL.NULL");
+
+        unsafe {
+            let code = symbology_insert(
+                &instrument_id,
+                string_to_pystr("FTX"),
+                string_to_pystr("ETH/USDT-SYMBOLOGY"),
+            );
+            let found = symbology_instrument_id(
+                string_to_pystr("FTX"),
+                string_to_pystr("ETH/USDT-SYMBOLOGY"),
+                &mut out,
+            );
+            let mapped = pystr_to_string(symbology_code_to_pystr(
+                &instrument_id,
+                string_to_pystr("FTX"),
+            ));
+            let unmapped = symbology_code_to_pystr(&instrument_id, string_to_pystr(ISIN_SCHEME));
+
+            assert_eq!((code, found), (0, 1));
+            assert_eq!(out, instrument_id);
+            assert_eq!(mapped, "ETH/USDT-SYMBOLOGY");
+            assert_eq!(unmapped, ffi::Py_None());
+        }
+    }
+}
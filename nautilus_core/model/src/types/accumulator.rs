@@ -0,0 +1,242 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Overflow-safe accumulators for cumulative statistics.
+//!
+//! Sums are held in 128 bits and saturate rather than wrap, so long runs on
+//! high volume instruments cannot silently overflow the 64-bit mantissas of
+//! `Price` and `Quantity`.
+
+use crate::types::fixed::{FIXED_PRECISION, FIXED_SCALAR};
+use crate::types::price::Price;
+use crate::types::quantity::Quantity;
+
+/// Provides a cumulative volume, e.g. of trades for a bar or session.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct VolumeAccumulator {
+    raw: u128,
+    precision: u8,
+    count: u64,
+}
+
+impl VolumeAccumulator {
+    pub fn new(precision: u8) -> Self {
+        VolumeAccumulator {
+            raw: 0,
+            precision,
+            count: 0,
+        }
+    }
+
+    pub fn add(&mut self, qty: &Quantity) {
+        self.raw = self.raw.saturating_add(qty.raw as u128);
+        self.count = self.count.saturating_add(1);
+    }
+
+    /// Returns the total in raw fixed-point units.
+    pub fn raw(&self) -> u128 {
+        self.raw
+    }
+
+    /// Returns the number of quantities added.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    pub fn as_f64(&self) -> f64 {
+        self.raw as f64 / FIXED_SCALAR
+    }
+
+    /// Returns the total as a `Quantity`, or `None` if it exceeds the range.
+    pub fn to_quantity(&self) -> Option<Quantity> {
+        let raw = u64::try_from(self.raw).ok()?;
+        Some(Quantity::from_raw(raw, self.precision))
+    }
+
+    /// Returns the total as a `Quantity`, saturating at the maximum value.
+    pub fn saturating_quantity(&self) -> Quantity {
+        let raw = u64::try_from(self.raw).unwrap_or(u64::MAX);
+        Quantity::from_raw(raw, self.precision)
+    }
+
+    pub fn reset(&mut self) {
+        self.raw = 0;
+        self.count = 0;
+    }
+}
+
+/// Provides a cumulative turnover (sum of price * size).
+///
+/// The raw total is at twice `FIXED_PRECISION`, being the product of fixed
+/// point prices and quantities.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TurnoverAccumulator {
+    raw: i128,
+}
+
+impl TurnoverAccumulator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&mut self, price: &Price, qty: &Quantity) {
+        let notional = (price.raw as i128).saturating_mul(qty.raw as i128);
+        self.raw = self.raw.saturating_add(notional);
+    }
+
+    /// Returns the total in raw units at twice `FIXED_PRECISION`.
+    pub fn raw(&self) -> i128 {
+        self.raw
+    }
+
+    pub fn as_f64(&self) -> f64 {
+        self.raw as f64 / (FIXED_SCALAR * FIXED_SCALAR)
+    }
+
+    /// Returns the total as a `Price` at the precision, or `None` if it
+    /// exceeds the range.
+    pub fn to_price(&self, precision: u8) -> Option<Price> {
+        let scalar = 10_i128.pow(FIXED_PRECISION as u32);
+        let raw = i64::try_from(self.raw / scalar).ok()?;
+        let truncation = 10_i64.pow((FIXED_PRECISION - precision) as u32);
+        Some(Price::from_raw(raw / truncation * truncation, precision))
+    }
+
+    pub fn reset(&mut self) {
+        self.raw = 0;
+    }
+}
+
+/// Provides a running open interest, changed by opened and closed contracts.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct OpenInterestAccumulator {
+    raw: i128,
+    precision: u8,
+}
+
+impl OpenInterestAccumulator {
+    pub fn new(precision: u8) -> Self {
+        OpenInterestAccumulator { raw: 0, precision }
+    }
+
+    pub fn increase(&mut self, qty: &Quantity) {
+        self.raw = self.raw.saturating_add(qty.raw as i128);
+    }
+
+    pub fn decrease(&mut self, qty: &Quantity) {
+        self.raw = self.raw.saturating_sub(qty.raw as i128);
+    }
+
+    /// Sets the open interest from a venue published value.
+    pub fn set(&mut self, qty: &Quantity) {
+        self.raw = qty.raw as i128;
+    }
+
+    /// Returns the open interest in raw fixed-point units, which may be
+    /// negative if more contracts were closed than observed opened.
+    pub fn raw(&self) -> i128 {
+        self.raw
+    }
+
+    pub fn as_f64(&self) -> f64 {
+        self.raw as f64 / FIXED_SCALAR
+    }
+
+    /// Returns the open interest as a `Quantity`, or `None` if negative or
+    /// exceeding the range.
+    pub fn to_quantity(&self) -> Option<Quantity> {
+        let raw = u64::try_from(self.raw).ok()?;
+        Some(Quantity::from_raw(raw, self.precision))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::{OpenInterestAccumulator, TurnoverAccumulator, VolumeAccumulator};
+    use crate::types::price::Price;
+    use crate::types::quantity::Quantity;
+
+    #[test]
+    fn test_volume_accumulator_widens_past_u64() {
+        let mut volume = VolumeAccumulator::new(0);
+        let qty = Quantity::new(10_000_000_000.0, 0); // Raw 1e19
+
+        volume.add(&qty);
+        volume.add(&qty);
+
+        assert_eq!(volume.count(), 2);
+        assert_eq!(volume.raw(), 20_000_000_000_000_000_000);
+        assert_eq!(volume.as_f64(), 20_000_000_000.0);
+        assert_eq!(volume.to_quantity(), None);
+        assert_eq!(volume.saturating_quantity().raw, u64::MAX);
+    }
+
+    #[test]
+    fn test_volume_accumulator_reset() {
+        let mut volume = VolumeAccumulator::new(2);
+        volume.add(&Quantity::new(1.5, 2));
+
+        let qty = volume.to_quantity();
+        volume.reset();
+
+        assert_eq!(qty, Some(Quantity::new(1.5, 2)));
+        assert_eq!(volume.raw(), 0);
+        assert_eq!(volume.count(), 0);
+    }
+
+    #[test]
+    fn test_turnover_accumulator() {
+        let mut turnover = TurnoverAccumulator::new();
+
+        turnover.add(&Price::new(100.5, 1), &Quantity::new(2.0, 0));
+        turnover.add(&Price::new(99.25, 2), &Quantity::new(4.0, 0));
+
+        assert_eq!(turnover.as_f64(), 598.0);
+        assert_eq!(turnover.to_price(2), Some(Price::new(598.0, 2)));
+    }
+
+    #[test]
+    fn test_turnover_accumulator_saturates() {
+        let mut turnover = TurnoverAccumulator::new();
+        let price = Price::from_raw(i64::MAX, 0);
+        let qty = Quantity::from_raw(u64::MAX, 0);
+
+        for _ in 0..4 {
+            turnover.add(&price, &qty);
+        }
+
+        assert_eq!(turnover.raw(), i128::MAX);
+        assert_eq!(turnover.to_price(0), None);
+    }
+
+    #[test]
+    fn test_open_interest_accumulator() {
+        let mut open_interest = OpenInterestAccumulator::new(0);
+
+        open_interest.increase(&Quantity::new(10.0, 0));
+        open_interest.decrease(&Quantity::new(3.0, 0));
+        let oi = open_interest.to_quantity();
+        open_interest.decrease(&Quantity::new(10.0, 0));
+
+        assert_eq!(oi, Some(Quantity::new(7.0, 0)));
+        assert_eq!(open_interest.as_f64(), -3.0);
+        assert_eq!(open_interest.to_quantity(), None);
+        open_interest.set(&Quantity::new(42.0, 0));
+        assert_eq!(open_interest.to_quantity(), Some(Quantity::new(42.0, 0)));
+    }
+}
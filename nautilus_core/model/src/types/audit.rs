@@ -0,0 +1,147 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Auditing of lossy `f64` to fixed-point conversions.
+//!
+//! Disabled by default. When enabled (e.g. for debug runs), the `audited`
+//! constructors count conversions which rounded, keyed by the component
+//! performing them, to locate pricing bugs at float boundaries.
+
+use nautilus_core::string::pystr_to_string;
+use pyo3::types::IntoPyDict;
+use pyo3::{ffi, IntoPyPointer, Python};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, OnceLock};
+
+static AUDIT_ENABLED: AtomicBool = AtomicBool::new(false);
+static LOSSY_COUNTS: OnceLock<Mutex<HashMap<String, u64>>> = OnceLock::new();
+
+fn lossy_counts() -> &'static Mutex<HashMap<String, u64>> {
+    LOSSY_COUNTS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+pub fn set_conversion_audit(enabled: bool) {
+    AUDIT_ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+pub fn is_conversion_audit_enabled() -> bool {
+    AUDIT_ENABLED.load(Ordering::Relaxed)
+}
+
+/// Records a lossy conversion by the given component.
+pub fn record_lossy_conversion(component: &str) {
+    let mut counts = lossy_counts().lock().unwrap();
+    *counts.entry(component.to_string()).or_insert(0) += 1;
+}
+
+/// Returns the number of lossy conversions recorded for the component.
+pub fn lossy_conversion_count(component: &str) -> u64 {
+    *lossy_counts().lock().unwrap().get(component).unwrap_or(&0)
+}
+
+/// Returns the lossy conversion counts for all components.
+pub fn lossy_conversion_counts() -> HashMap<String, u64> {
+    lossy_counts().lock().unwrap().clone()
+}
+
+pub fn reset_conversion_audit() {
+    lossy_counts().lock().unwrap().clear();
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// C API
+////////////////////////////////////////////////////////////////////////////////
+#[no_mangle]
+pub extern "C" fn conversion_audit_set_enabled(enabled: u8) {
+    set_conversion_audit(enabled != 0);
+}
+
+#[no_mangle]
+pub extern "C" fn conversion_audit_is_enabled() -> u8 {
+    u8::from(is_conversion_audit_enabled())
+}
+
+#[no_mangle]
+pub extern "C" fn conversion_audit_reset() {
+    reset_conversion_audit();
+}
+
+/// Returns the number of lossy conversions recorded for the component.
+///
+/// # Safety
+///
+/// - `component` must be borrowed from a valid Python UTF-8 `str`.
+#[no_mangle]
+pub unsafe extern "C" fn conversion_audit_count(component: *mut ffi::PyObject) -> u64 {
+    lossy_conversion_count(&pystr_to_string(component))
+}
+
+/// Returns a pointer to a new Python dict of the lossy conversion counts keyed
+/// by component.
+///
+/// # Safety
+///
+/// - Assumes that since the data is originating from Rust, the GIL does not need
+///   to be acquired.
+/// - Assumes you are immediately returning this pointer to Python.
+#[no_mangle]
+pub unsafe extern "C" fn conversion_audit_counts_to_pydict() -> *mut ffi::PyObject {
+    let py = Python::assume_gil_acquired();
+    lossy_conversion_counts().into_py_dict(py).into_ptr()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nautilus_core::string::string_to_pystr;
+    use pyo3::types::PyDict;
+    use pyo3::{prepare_freethreaded_python, FromPyPointer};
+
+    #[test]
+    fn test_record_lossy_conversions() {
+        record_lossy_conversion("AuditTest-001");
+        record_lossy_conversion("AuditTest-001");
+        record_lossy_conversion("AuditTest-002");
+
+        assert_eq!(lossy_conversion_count("AuditTest-001"), 2);
+        assert_eq!(lossy_conversion_count("AuditTest-003"), 0);
+        assert_eq!(lossy_conversion_counts()["AuditTest-002"], 1);
+    }
+
+    #[test]
+    fn test_conversion_audit_counts_over_ffi() {
+        prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            record_lossy_conversion("AuditTest-004");
+
+            let counts = unsafe { PyDict::from_owned_ptr(py, conversion_audit_counts_to_pydict()) };
+            let count = unsafe { conversion_audit_count(string_to_pystr("AuditTest-004")) };
+
+            assert_eq!(count, 1);
+            assert_eq!(
+                counts
+                    .get_item("AuditTest-004")
+                    .unwrap()
+                    .extract::<u64>()
+                    .unwrap(),
+                1
+            );
+        });
+    }
+}
@@ -13,17 +13,76 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+use std::error::Error;
+use std::fmt;
+
+use crate::identifiers::instrument_id::InstrumentId;
 use crate::types::currency::Currency;
 use crate::types::money::Money;
-use std::fmt;
 
+/// Represents an invalid account or margin balance.
+///
+/// The discriminants are the error codes returned by the C API constructors,
+/// where zero indicates success.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BalanceError {
+    /// The balance amounts had different currencies.
+    CurrencyMismatch = 1,
+    /// A balance amount was negative.
+    Negative = 2,
+    /// The total was not equal to the locked plus free amounts.
+    TotalMismatch = 3,
+}
+
+impl fmt::Display for BalanceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BalanceError::CurrencyMismatch => write!(f, "balance currencies were not equal"),
+            BalanceError::Negative => write!(f, "balance amount was negative"),
+            BalanceError::TotalMismatch => write!(f, "total was not equal to locked + free"),
+        }
+    }
+}
+
+impl Error for BalanceError {}
+
+/// Represents an account balance denominated in a particular currency.
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct AccountBalance {
     pub currency: Currency,
+    /// The total account balance.
     pub total: Money,
+    /// The account balance locked (assigned to pending orders).
     pub locked: Money,
+    /// The account balance free for trading.
     pub free: Money,
 }
 
+impl AccountBalance {
+    /// Returns a validated balance, checking the amounts are in the same
+    /// currency, non-negative and that `total == locked + free`.
+    pub fn new(total: Money, locked: Money, free: Money) -> Result<Self, BalanceError> {
+        if locked.currency != total.currency || free.currency != total.currency {
+            return Err(BalanceError::CurrencyMismatch);
+        }
+        if total.raw() < 0 || locked.raw() < 0 || free.raw() < 0 {
+            return Err(BalanceError::Negative);
+        }
+        // Both are non-negative so the sum cannot overflow `i128`
+        if total.raw() as i128 != locked.raw() as i128 + free.raw() as i128 {
+            return Err(BalanceError::TotalMismatch);
+        }
+        Ok(AccountBalance {
+            currency: total.currency.clone(),
+            total,
+            locked,
+            free,
+        })
+    }
+}
+
 impl fmt::Display for AccountBalance {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
@@ -33,3 +92,328 @@ impl fmt::Display for AccountBalance {
         )
     }
 }
+
+/// Represents a margin balance optionally associated with a particular
+/// instrument.
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MarginBalance {
+    pub currency: Currency,
+    /// The initial (order) margin requirement.
+    pub initial: Money,
+    /// The maintenance (position) margin requirement.
+    pub maintenance: Money,
+    /// The instrument ID associated with the margin (null for account wide).
+    pub instrument_id: Option<Box<InstrumentId>>,
+}
+
+impl MarginBalance {
+    /// Returns a validated margin balance, checking the margins are in the
+    /// same currency and non-negative.
+    pub fn new(
+        initial: Money,
+        maintenance: Money,
+        instrument_id: Option<InstrumentId>,
+    ) -> Result<Self, BalanceError> {
+        if maintenance.currency != initial.currency {
+            return Err(BalanceError::CurrencyMismatch);
+        }
+        if initial.raw() < 0 || maintenance.raw() < 0 {
+            return Err(BalanceError::Negative);
+        }
+        Ok(MarginBalance {
+            currency: initial.currency.clone(),
+            initial,
+            maintenance,
+            instrument_id: instrument_id.map(Box::new),
+        })
+    }
+}
+
+impl fmt::Display for MarginBalance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.initial, self.maintenance)?;
+        match &self.instrument_id {
+            Some(instrument_id) => write!(f, " {}", instrument_id),
+            None => Ok(()),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// C API
+////////////////////////////////////////////////////////////////////////////////
+/// Returns zero and writes the validated balance to `out`, otherwise returns
+/// the non-zero `BalanceError` code.
+///
+/// # Safety
+///
+/// - `out` must be a valid pointer to write the value to.
+#[no_mangle]
+pub unsafe extern "C" fn account_balance_new(
+    total: Money,
+    locked: Money,
+    free: Money,
+    out: *mut AccountBalance,
+) -> u8 {
+    match AccountBalance::new(total, locked, free) {
+        Ok(balance) => {
+            out.write(balance);
+            0
+        }
+        Err(e) => e as u8,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn account_balance_free(balance: AccountBalance) {
+    drop(balance); // Memory freed here
+}
+
+#[no_mangle]
+pub extern "C" fn account_balance_get_currency(balance: &AccountBalance) -> Currency {
+    balance.currency.clone()
+}
+
+#[no_mangle]
+pub extern "C" fn account_balance_get_total(balance: &AccountBalance) -> Money {
+    balance.total.clone()
+}
+
+#[no_mangle]
+pub extern "C" fn account_balance_get_locked(balance: &AccountBalance) -> Money {
+    balance.locked.clone()
+}
+
+#[no_mangle]
+pub extern "C" fn account_balance_get_free(balance: &AccountBalance) -> Money {
+    balance.free.clone()
+}
+
+#[no_mangle]
+pub extern "C" fn account_balance_eq(a: &AccountBalance, b: &AccountBalance) -> u8 {
+    (a == b) as u8
+}
+
+/// Returns zero and writes the validated margin balance to `out`, otherwise
+/// returns the non-zero `BalanceError` code.
+///
+/// # Safety
+///
+/// - `out` must be a valid pointer to write the value to.
+#[no_mangle]
+pub unsafe extern "C" fn margin_balance_new(
+    initial: Money,
+    maintenance: Money,
+    instrument_id: Option<&InstrumentId>,
+    out: *mut MarginBalance,
+) -> u8 {
+    match MarginBalance::new(initial, maintenance, instrument_id.cloned()) {
+        Ok(balance) => {
+            out.write(balance);
+            0
+        }
+        Err(e) => e as u8,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn margin_balance_free(balance: MarginBalance) {
+    drop(balance); // Memory freed here
+}
+
+#[no_mangle]
+pub extern "C" fn margin_balance_get_currency(balance: &MarginBalance) -> Currency {
+    balance.currency.clone()
+}
+
+#[no_mangle]
+pub extern "C" fn margin_balance_get_initial(balance: &MarginBalance) -> Money {
+    balance.initial.clone()
+}
+
+#[no_mangle]
+pub extern "C" fn margin_balance_get_maintenance(balance: &MarginBalance) -> Money {
+    balance.maintenance.clone()
+}
+
+/// Returns the instrument ID of the margin balance (null if account wide),
+/// borrowed for the lifetime of the balance.
+#[no_mangle]
+pub extern "C" fn margin_balance_get_instrument_id(
+    balance: &MarginBalance,
+) -> Option<&InstrumentId> {
+    balance.instrument_id.as_deref()
+}
+
+#[no_mangle]
+pub extern "C" fn margin_balance_eq(a: &MarginBalance, b: &MarginBalance) -> u8 {
+    (a == b) as u8
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::mem::MaybeUninit;
+
+    use super::*;
+    use crate::enums::CurrencyType;
+
+    fn usd() -> Currency {
+        Currency::new("USD", 2, 840, "United States dollar", CurrencyType::Fiat)
+    }
+
+    #[test]
+    fn test_account_balance_new() {
+        let balance = AccountBalance::new(
+            Money::new(10.30, usd()),
+            Money::new(0.30, usd()),
+            Money::new(10.00, usd()),
+        )
+        .unwrap();
+
+        assert_eq!(balance.currency, usd());
+        assert_eq!(balance.free, Money::new(10.00, usd()));
+        assert_eq!(balance.to_string(), "USD 10.30 USD 0.30 USD 10.00 USD");
+    }
+
+    #[test]
+    fn test_account_balance_new_invalid() {
+        let aud = Currency::new("AUD", 2, 36, "Australian dollar", CurrencyType::Fiat);
+
+        assert_eq!(
+            AccountBalance::new(
+                Money::new(1.0, usd()),
+                Money::new(0.0, aud),
+                Money::new(1.0, usd()),
+            ),
+            Err(BalanceError::CurrencyMismatch)
+        );
+        assert_eq!(
+            AccountBalance::new(
+                Money::new(1.0, usd()),
+                Money::new(-1.0, usd()),
+                Money::new(2.0, usd()),
+            ),
+            Err(BalanceError::Negative)
+        );
+        assert_eq!(
+            AccountBalance::new(
+                Money::new(1.0, usd()),
+                Money::new(0.5, usd()),
+                Money::new(0.6, usd()),
+            ),
+            Err(BalanceError::TotalMismatch)
+        );
+    }
+
+    #[test]
+    fn test_account_balance_c_api() {
+        let mut out = MaybeUninit::<AccountBalance>::uninit();
+
+        unsafe {
+            let result = account_balance_new(
+                Money::new(2.0, usd()),
+                Money::new(0.5, usd()),
+                Money::new(1.5, usd()),
+                out.as_mut_ptr(),
+            );
+            assert_eq!(result, 0);
+            let balance = out.assume_init();
+
+            assert_eq!(account_balance_get_total(&balance), Money::new(2.0, usd()));
+            assert_eq!(account_balance_get_locked(&balance), Money::new(0.5, usd()));
+            assert_eq!(account_balance_get_free(&balance), Money::new(1.5, usd()));
+            assert_eq!(account_balance_get_currency(&balance), usd());
+            assert_eq!(account_balance_eq(&balance, &balance.clone()), 1);
+            account_balance_free(balance);
+
+            let mut invalid = MaybeUninit::<AccountBalance>::uninit();
+            let result = account_balance_new(
+                Money::new(2.0, usd()),
+                Money::new(0.5, usd()),
+                Money::new(1.0, usd()),
+                invalid.as_mut_ptr(),
+            );
+            assert_eq!(result, BalanceError::TotalMismatch as u8);
+        }
+    }
+
+    #[test]
+    fn test_margin_balance_new() {
+        let instrument_id = InstrumentId::from_dotted("AUD/USD.SIM");
+
+        let balance = MarginBalance::new(
+            Money::new(5.00, usd()),
+            Money::new(2.50, usd()),
+            Some(instrument_id.clone()),
+        )
+        .unwrap();
+
+        assert_eq!(balance.currency, usd());
+        assert_eq!(balance.instrument_id.as_deref(), Some(&instrument_id));
+        assert_eq!(balance.to_string(), "5.00 USD 2.50 USD AUD/USD.SIM");
+        assert_eq!(
+            MarginBalance::new(Money::new(5.00, usd()), Money::new(-1.0, usd()), None),
+            Err(BalanceError::Negative)
+        );
+        assert_eq!(
+            MarginBalance::new(
+                Money::new(5.00, usd()),
+                Money::new(
+                    1.0,
+                    Currency::new("AUD", 2, 36, "Australian dollar", CurrencyType::Fiat)
+                ),
+                None,
+            ),
+            Err(BalanceError::CurrencyMismatch)
+        );
+    }
+
+    #[test]
+    fn test_margin_balance_c_api() {
+        let instrument_id = InstrumentId::from_dotted("AUD/USD.SIM");
+        let mut out = MaybeUninit::<MarginBalance>::uninit();
+        let mut account_wide = MaybeUninit::<MarginBalance>::uninit();
+
+        unsafe {
+            assert_eq!(
+                margin_balance_new(
+                    Money::new(5.0, usd()),
+                    Money::new(2.5, usd()),
+                    Some(&instrument_id),
+                    out.as_mut_ptr(),
+                ),
+                0
+            );
+            assert_eq!(
+                margin_balance_new(
+                    Money::new(1.0, usd()),
+                    Money::new(0.5, usd()),
+                    None,
+                    account_wide.as_mut_ptr(),
+                ),
+                0
+            );
+            let balance = out.assume_init();
+            let account_wide = account_wide.assume_init();
+
+            assert_eq!(margin_balance_get_initial(&balance), Money::new(5.0, usd()));
+            assert_eq!(
+                margin_balance_get_maintenance(&balance),
+                Money::new(2.5, usd())
+            );
+            assert_eq!(margin_balance_get_currency(&balance), usd());
+            assert_eq!(
+                margin_balance_get_instrument_id(&balance),
+                Some(&instrument_id)
+            );
+            assert_eq!(margin_balance_get_instrument_id(&account_wide), None);
+            assert_eq!(margin_balance_eq(&balance, &account_wide), 0);
+            margin_balance_free(balance);
+            margin_balance_free(account_wide);
+        }
+    }
+}
@@ -16,6 +16,11 @@
 use crate::enums::CurrencyType;
 use nautilus_core::string::{pystr_to_string, string_to_pystr};
 use pyo3::ffi;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+use std::sync::{Mutex, OnceLock};
 
 #[repr(C)]
 #[derive(Eq, PartialEq, Clone, Hash, Debug)]
@@ -43,6 +48,128 @@ impl Currency {
             currency_type,
         }
     }
+
+    pub fn is_fiat(&self) -> bool {
+        self.currency_type == CurrencyType::Fiat
+    }
+
+    pub fn is_crypto(&self) -> bool {
+        self.currency_type == CurrencyType::Crypto
+    }
+
+    /// Returns the registered currency for the code (if found).
+    pub fn from_code(code: &str) -> Option<Currency> {
+        currency_map().lock().unwrap().get(code).cloned()
+    }
+
+    /// Registers the currency so it can be looked up by its code, returning
+    /// whether it was registered (an existing currency is only replaced if
+    /// `overwrite` is true).
+    pub fn register(currency: Currency, overwrite: bool) -> bool {
+        let mut map = currency_map().lock().unwrap();
+        if !overwrite && map.contains_key(currency.code.as_str()) {
+            return false;
+        }
+        map.insert(currency.code.to_string(), currency);
+        true
+    }
+}
+
+impl FromStr for Currency {
+    type Err = String;
+
+    /// Parses a registered currency from its code.
+    fn from_str(code: &str) -> Result<Self, Self::Err> {
+        Currency::from_code(code).ok_or_else(|| format!("Unknown currency code '{}'", code))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Registry
+////////////////////////////////////////////////////////////////////////////////
+
+/// The built-in fiat (ISO 4217) and crypto currencies as
+/// `(code, precision, iso4217, name, currency_type)`.
+const BUILTIN_CURRENCIES: &[(&str, u8, u16, &str, CurrencyType)] = &[
+    ("AUD", 2, 36, "Australian dollar", CurrencyType::Fiat),
+    ("BRL", 2, 986, "Brazilian real", CurrencyType::Fiat),
+    ("CAD", 2, 124, "Canadian dollar", CurrencyType::Fiat),
+    ("CHF", 2, 756, "Swiss franc", CurrencyType::Fiat),
+    ("CNY", 2, 156, "Chinese yuan", CurrencyType::Fiat),
+    ("CNH", 2, 0, "Chinese yuan (offshore)", CurrencyType::Fiat),
+    ("CZK", 2, 203, "Czech koruna", CurrencyType::Fiat),
+    ("DKK", 2, 208, "Danish krone", CurrencyType::Fiat),
+    ("EUR", 2, 978, "Euro", CurrencyType::Fiat),
+    ("GBP", 2, 826, "British Pound", CurrencyType::Fiat),
+    ("HKD", 2, 344, "Hong Kong dollar", CurrencyType::Fiat),
+    ("HUF", 2, 348, "Hungarian forint", CurrencyType::Fiat),
+    ("ILS", 2, 376, "Israeli new shekel", CurrencyType::Fiat),
+    ("INR", 2, 356, "Indian rupee", CurrencyType::Fiat),
+    ("JPY", 0, 392, "Japanese yen", CurrencyType::Fiat),
+    ("KRW", 0, 410, "South Korean won", CurrencyType::Fiat),
+    ("MXN", 2, 484, "Mexican peso", CurrencyType::Fiat),
+    ("NOK", 2, 578, "Norwegian krone", CurrencyType::Fiat),
+    ("NZD", 2, 554, "New Zealand dollar", CurrencyType::Fiat),
+    ("PLN", 2, 985, "Polish złoty", CurrencyType::Fiat),
+    ("RUB", 2, 643, "Russian ruble", CurrencyType::Fiat),
+    ("SAR", 2, 682, "Saudi riyal", CurrencyType::Fiat),
+    ("SEK", 2, 752, "Swedish krona/kronor", CurrencyType::Fiat),
+    ("SGD", 2, 702, "Singapore dollar", CurrencyType::Fiat),
+    ("THB", 2, 764, "Thai baht", CurrencyType::Fiat),
+    ("TRY", 2, 949, "Turkish lira", CurrencyType::Fiat),
+    ("USD", 2, 840, "United States dollar", CurrencyType::Fiat),
+    ("XAG", 0, 961, "Silver (one troy ounce)", CurrencyType::Fiat),
+    ("XAU", 0, 959, "Gold (one troy ounce)", CurrencyType::Fiat),
+    ("ZAR", 2, 710, "South African rand", CurrencyType::Fiat),
+    ("1INCH", 8, 0, "1inch Network", CurrencyType::Crypto),
+    ("AAVE", 8, 0, "Aave", CurrencyType::Crypto),
+    ("ACA", 8, 0, "Acala Token", CurrencyType::Crypto),
+    ("ADA", 6, 0, "Cardano", CurrencyType::Crypto),
+    ("AVAX", 8, 0, "Avalanche", CurrencyType::Crypto),
+    ("BCH", 8, 0, "Bitcoin Cash", CurrencyType::Crypto),
+    ("BNB", 8, 0, "Binance Coin", CurrencyType::Crypto),
+    ("BRZ", 8, 0, "Brazilian Digital Token", CurrencyType::Crypto),
+    ("BSV", 8, 0, "Bitcoin SV", CurrencyType::Crypto),
+    ("BTC", 8, 0, "Bitcoin", CurrencyType::Crypto),
+    ("BUSD", 8, 0, "Binance USD", CurrencyType::Crypto),
+    ("XBT", 8, 0, "Bitcoin", CurrencyType::Crypto),
+    ("DASH", 8, 0, "Dash", CurrencyType::Crypto),
+    ("DOGE", 8, 0, "Dogecoin", CurrencyType::Crypto),
+    ("DOT", 8, 0, "Polkadot", CurrencyType::Crypto),
+    ("EOS", 8, 0, "EOS", CurrencyType::Crypto),
+    ("ETH", 8, 0, "Ether", CurrencyType::Crypto),
+    ("FTT", 8, 0, "FTT", CurrencyType::Crypto),
+    ("JOE", 8, 0, "JOE", CurrencyType::Crypto),
+    ("LINK", 8, 0, "Chainlink", CurrencyType::Crypto),
+    ("LTC", 8, 0, "Litecoin", CurrencyType::Crypto),
+    ("LUNA", 8, 0, "Terra", CurrencyType::Crypto),
+    ("SOL", 8, 0, "Solana", CurrencyType::Crypto),
+    ("TRYB", 8, 0, "BiLira", CurrencyType::Crypto),
+    ("VTC", 8, 0, "Vertcoin", CurrencyType::Crypto),
+    ("XLM", 8, 0, "Stellar Lumen", CurrencyType::Crypto),
+    ("XMR", 8, 0, "Monero", CurrencyType::Crypto),
+    ("XRP", 6, 0, "Ripple", CurrencyType::Crypto),
+    ("XTZ", 6, 0, "Tezos", CurrencyType::Crypto),
+    ("USDC", 8, 0, "USD Coin", CurrencyType::Crypto),
+    ("USDT", 8, 0, "Tether", CurrencyType::Crypto),
+    ("ZEC", 8, 0, "Zcash", CurrencyType::Crypto),
+];
+
+static CURRENCY_MAP: OnceLock<Mutex<HashMap<String, Currency>>> = OnceLock::new();
+
+/// Returns the process wide currency registry, initialized with the built-in
+/// currencies.
+pub fn currency_map() -> &'static Mutex<HashMap<String, Currency>> {
+    CURRENCY_MAP.get_or_init(|| {
+        let map = BUILTIN_CURRENCIES
+            .iter()
+            .map(|(code, precision, iso4217, name, currency_type)| {
+                let currency = Currency::new(code, *precision, *iso4217, name, *currency_type);
+                (code.to_string(), currency)
+            })
+            .collect();
+        Mutex::new(map)
+    })
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -76,7 +203,7 @@ pub unsafe extern "C" fn currency_from_py(
 /// # Safety
 ///
 /// - Assumes that since the data is originating from Rust, the GIL does not need
-/// to be acquired.
+///   to be acquired.
 /// - Assumes you are immediately returning this pointer to Python.
 #[no_mangle]
 pub unsafe extern "C" fn currency_code_to_pystr(currency: &Currency) -> *mut ffi::PyObject {
@@ -88,7 +215,7 @@ pub unsafe extern "C" fn currency_code_to_pystr(currency: &Currency) -> *mut ffi
 /// # Safety
 ///
 /// - Assumes that since the data is originating from Rust, the GIL does not need
-/// to be acquired.
+///   to be acquired.
 /// - Assumes you are immediately returning this pointer to Python.
 #[no_mangle]
 pub unsafe extern "C" fn currency_name_to_pystr(currency: &Currency) -> *mut ffi::PyObject {
@@ -100,23 +227,160 @@ pub extern "C" fn currency_free(currency: Currency) {
     drop(currency); // Memory freed here
 }
 
+/// Returns 1 and writes the registered currency to `out` if found for the
+/// code, otherwise returns 0.
+///
+/// # Safety
+///
+/// - `code_ptr` must be borrowed from a valid Python UTF-8 `str`.
+/// - `out` must be a valid pointer to write the currency to.
+#[no_mangle]
+pub unsafe extern "C" fn currency_from_pystr(
+    code_ptr: *mut ffi::PyObject,
+    out: *mut Currency,
+) -> u8 {
+    match Currency::from_code(&pystr_to_string(code_ptr)) {
+        Some(currency) => {
+            out.write(currency);
+            1
+        }
+        None => 0,
+    }
+}
+
+/// Returns 1 if a currency is registered for the code, otherwise 0.
+///
+/// # Safety
+///
+/// - `code_ptr` must be borrowed from a valid Python UTF-8 `str`.
+#[no_mangle]
+pub unsafe extern "C" fn currency_exists(code_ptr: *mut ffi::PyObject) -> u8 {
+    let code = pystr_to_string(code_ptr);
+    u8::from(currency_map().lock().unwrap().contains_key(&code))
+}
+
+/// Registers a currency from valid Python object pointers and primitives so
+/// subsequent lookups by code resolve it, returning 1 if it was registered or
+/// 0 if the code already existed and `overwrite` was 0.
+///
+/// # Safety
+///
+/// - `code_ptr` and `name_ptr` must be borrowed from a valid Python UTF-8 `str`(s).
+#[no_mangle]
+pub unsafe extern "C" fn currency_register(
+    code_ptr: *mut ffi::PyObject,
+    precision: u8,
+    iso4217: u16,
+    name_ptr: *mut ffi::PyObject,
+    currency_type: CurrencyType,
+    overwrite: u8,
+) -> u8 {
+    let currency = currency_from_py(code_ptr, precision, iso4217, name_ptr, currency_type);
+    u8::from(Currency::register(currency, overwrite != 0))
+}
+
+#[no_mangle]
+pub extern "C" fn currency_hash(currency: &Currency) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    currency.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[no_mangle]
+pub extern "C" fn currency_eq(a: &Currency, b: &Currency) -> u8 {
+    u8::from(a == b)
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
+    use std::mem::MaybeUninit;
+
+    use nautilus_core::string::string_to_pystr;
+
     use crate::enums::CurrencyType;
-    use crate::types::currency::Currency;
+    use crate::types::currency::{
+        currency_exists, currency_from_pystr, currency_register, Currency,
+    };
 
     #[test]
     fn test_currency_new() {
-        let currency = Currency::new("AUD", 8, 036, "Australian dollar", CurrencyType::Fiat);
+        let currency = Currency::new("AUD", 8, 36, "Australian dollar", CurrencyType::Fiat);
 
         assert_eq!(currency, currency);
         assert_eq!(currency.code.as_str(), "AUD");
         assert_eq!(currency.precision, 8);
-        assert_eq!(currency.iso4217, 036);
+        assert_eq!(currency.iso4217, 36);
         assert_eq!(currency.name.as_str(), "Australian dollar");
         assert_eq!(currency.currency_type, CurrencyType::Fiat);
     }
+
+    #[test]
+    fn test_currency_from_str_builtin() {
+        let usd: Currency = "USD".parse().unwrap();
+        let btc: Currency = "BTC".parse().unwrap();
+
+        assert_eq!(usd.precision, 2);
+        assert_eq!(usd.iso4217, 840);
+        assert!(usd.is_fiat());
+        assert_eq!(btc.precision, 8);
+        assert!(btc.is_crypto());
+        assert!("NOPE".parse::<Currency>().is_err());
+    }
+
+    #[test]
+    fn test_currency_register_custom() {
+        let custom = Currency::new("TESTA", 4, 0, "Test token", CurrencyType::Crypto);
+        let replacement = Currency::new("TESTA", 6, 0, "Test token", CurrencyType::Crypto);
+
+        assert!(Currency::register(custom.clone(), false));
+        assert!(!Currency::register(replacement.clone(), false));
+        assert_eq!(Currency::from_code("TESTA"), Some(custom));
+        assert!(Currency::register(replacement.clone(), true));
+        assert_eq!(Currency::from_code("TESTA"), Some(replacement));
+    }
+
+    #[test]
+    fn test_currency_register_c_api() {
+        pyo3::prepare_freethreaded_python();
+        let gil = pyo3::Python::acquire_gil();
+        let _py = gil.python();
+        let mut out = MaybeUninit::<Currency>::uninit();
+
+        unsafe {
+            assert_eq!(currency_exists(string_to_pystr("TESTB")), 0);
+            assert_eq!(
+                currency_register(
+                    string_to_pystr("TESTB"),
+                    5,
+                    0,
+                    string_to_pystr("Exotic token"),
+                    CurrencyType::Crypto,
+                    0,
+                ),
+                1
+            );
+            assert_eq!(
+                currency_register(
+                    string_to_pystr("TESTB"),
+                    2,
+                    0,
+                    string_to_pystr("Exotic token"),
+                    CurrencyType::Crypto,
+                    0,
+                ),
+                0
+            );
+            assert_eq!(
+                currency_from_pystr(string_to_pystr("TESTB"), out.as_mut_ptr()),
+                1
+            );
+            assert_eq!(
+                out.assume_init(),
+                Currency::new("TESTB", 5, 0, "Exotic token", CurrencyType::Crypto)
+            );
+        }
+    }
 }
@@ -13,8 +13,102 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
 pub const FIXED_PRECISION: u8 = 9;
 pub const FIXED_SCALAR: f64 = 1000000000.0; // 10.0**FIXED_PRECISION
+const FIXED_SCALAR_I128: i128 = 1_000_000_000;
+
+/// Represents a lossy or invalid conversion of an `f64` to fixed-point.
+///
+/// The discriminants are the error codes returned by the C API `checked`
+/// functions, where zero indicates success.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FixedPointError {
+    /// The precision exceeded `FIXED_PRECISION`.
+    InvalidPrecision = 1,
+    /// The value was NaN or infinite.
+    NotFinite = 2,
+    /// The value was outside the fixed-point range.
+    Overflow = 3,
+    /// The value was negative for an unsigned type.
+    Negative = 4,
+    /// The value was not exactly representable at the precision.
+    PrecisionLoss = 5,
+    /// The value string was not a decimal number.
+    InvalidFormat = 6,
+    /// The operands of an exact operation had different precisions.
+    PrecisionMismatch = 7,
+    /// The operands of a money operation had different currencies.
+    CurrencyMismatch = 8,
+    /// The increment to round to was not positive.
+    InvalidIncrement = 9,
+    /// The divisor of a conversion was zero.
+    DivisionByZero = 10,
+}
+
+impl Display for FixedPointError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FixedPointError::InvalidPrecision => {
+                write!(f, "precision exceeded the maximum of {}", FIXED_PRECISION)
+            }
+            FixedPointError::NotFinite => write!(f, "value was not finite"),
+            FixedPointError::Overflow => write!(f, "value overflowed fixed-point range"),
+            FixedPointError::Negative => write!(f, "value was negative"),
+            FixedPointError::PrecisionLoss => write!(f, "value lost precision"),
+            FixedPointError::InvalidFormat => write!(f, "value was not a decimal number"),
+            FixedPointError::PrecisionMismatch => write!(f, "values had different precisions"),
+            FixedPointError::CurrencyMismatch => write!(f, "values had different currencies"),
+            FixedPointError::InvalidIncrement => write!(f, "increment was not positive"),
+            FixedPointError::DivisionByZero => write!(f, "divisor was zero"),
+        }
+    }
+}
+
+impl Error for FixedPointError {}
+
+/// Returns the value scaled to the precision and rounded, checking it is
+/// finite and round trips to the same `f64` (i.e. no precision is lost).
+fn checked_scaled(value: f64, precision: u8, max: f64) -> Result<f64, FixedPointError> {
+    if precision > FIXED_PRECISION {
+        return Err(FixedPointError::InvalidPrecision);
+    }
+    if !value.is_finite() {
+        return Err(FixedPointError::NotFinite);
+    }
+    let pow1 = 10_f64.powi(precision as i32);
+    let rounded = (value * pow1).round();
+    if rounded.abs() > max {
+        return Err(FixedPointError::Overflow);
+    }
+    // Division is correctly rounded, so an exact decimal round trips
+    if rounded / pow1 != value {
+        return Err(FixedPointError::PrecisionLoss);
+    }
+    Ok(rounded)
+}
+
+/// Returns the fixed-point value, or an error rather than rounding or
+/// overflowing.
+pub fn f64_to_fixed_i64_checked(value: f64, precision: u8) -> Result<i64, FixedPointError> {
+    let pow2 = 10_i64.pow(FIXED_PRECISION.saturating_sub(precision) as u32);
+    let rounded = checked_scaled(value, precision, (i64::MAX / pow2) as f64)?;
+    Ok(rounded as i64 * pow2)
+}
+
+/// Returns the fixed-point value, or an error rather than rounding or
+/// overflowing.
+pub fn f64_to_fixed_u64_checked(value: f64, precision: u8) -> Result<u64, FixedPointError> {
+    if value < 0.0 {
+        return Err(FixedPointError::Negative);
+    }
+    let pow2 = 10_u64.pow(FIXED_PRECISION.saturating_sub(precision) as u32);
+    let rounded = checked_scaled(value, precision, (u64::MAX / pow2) as f64)?;
+    Ok(rounded as u64 * pow2)
+}
 
 pub fn f64_to_fixed_i64(value: f64, precision: u8) -> i64 {
     assert!(precision <= 9);
@@ -32,6 +126,56 @@ pub fn f64_to_fixed_u64(value: f64, precision: u8) -> u64 {
     rounded * pow2
 }
 
+/// Returns the product of the fixed-point values rounded half away from zero
+/// to `FIXED_PRECISION`.
+///
+/// # Panics
+///
+/// If the product overflows the `i64` range.
+pub fn fixed_i64_mul(a: i64, b: i64) -> i64 {
+    let product = a as i128 * b as i128;
+    let half = product.signum() * FIXED_SCALAR_I128 / 2;
+    i64::try_from((product + half) / FIXED_SCALAR_I128).expect("product overflowed i64")
+}
+
+/// Returns the exact product of the fixed-point values, or an error if it is
+/// not representable at `FIXED_PRECISION` or overflows.
+pub fn fixed_i64_mul_checked(a: i64, b: i64) -> Result<i64, FixedPointError> {
+    let product = a as i128 * b as i128;
+    if product % FIXED_SCALAR_I128 != 0 {
+        return Err(FixedPointError::PrecisionLoss);
+    }
+    i64::try_from(product / FIXED_SCALAR_I128).map_err(|_| FixedPointError::Overflow)
+}
+
+/// Returns the product of the fixed-point values rounded half up to
+/// `FIXED_PRECISION`.
+///
+/// # Panics
+///
+/// If the product overflows the `u64` range.
+pub fn fixed_u64_mul(a: u64, b: u64) -> u64 {
+    let product = a as u128 * b as u128;
+    let scalar = FIXED_SCALAR_I128 as u128;
+    u64::try_from((product + scalar / 2) / scalar).expect("product overflowed u64")
+}
+
+/// Returns the exact product of the fixed-point values, or an error if it is
+/// not representable at `FIXED_PRECISION` or overflows.
+pub fn fixed_u64_mul_checked(a: u64, b: u64) -> Result<u64, FixedPointError> {
+    let product = a as u128 * b as u128;
+    let scalar = FIXED_SCALAR_I128 as u128;
+    if !product.is_multiple_of(scalar) {
+        return Err(FixedPointError::PrecisionLoss);
+    }
+    u64::try_from(product / scalar).map_err(|_| FixedPointError::Overflow)
+}
+
+/// Returns the precision of the product of values at the precisions.
+pub fn product_precision(a: u8, b: u8) -> u8 {
+    a.saturating_add(b).min(FIXED_PRECISION)
+}
+
 pub fn fixed_i64_to_f64(value: i64) -> f64 {
     (value as f64) * 0.000000001
 }
@@ -46,7 +190,9 @@ pub fn fixed_u64_to_f64(value: u64) -> f64 {
 #[cfg(test)]
 mod tests {
     use crate::types::fixed::{
-        f64_to_fixed_i64, f64_to_fixed_u64, fixed_i64_to_f64, fixed_u64_to_f64,
+        f64_to_fixed_i64, f64_to_fixed_i64_checked, f64_to_fixed_u64, f64_to_fixed_u64_checked,
+        fixed_i64_mul, fixed_i64_mul_checked, fixed_i64_to_f64, fixed_u64_mul,
+        fixed_u64_mul_checked, fixed_u64_to_f64, FixedPointError,
     };
     use rstest::*;
 
@@ -75,4 +221,66 @@ mod tests {
         let result = fixed_u64_to_f64(fixed);
         assert_eq!(result, value);
     }
+
+    #[rstest]
+    #[case(1.15, 2, Ok(1_150_000_000))]
+    #[case(-0.00812, 5, Ok(-8_120_000))]
+    #[case(0.000000001, 9, Ok(1))]
+    #[case(1.155, 2, Err(FixedPointError::PrecisionLoss))]
+    #[case(0.1, 10, Err(FixedPointError::InvalidPrecision))]
+    #[case(f64::NAN, 2, Err(FixedPointError::NotFinite))]
+    #[case(f64::INFINITY, 2, Err(FixedPointError::NotFinite))]
+    #[case(1e10, 0, Err(FixedPointError::Overflow))]
+    fn test_f64_to_fixed_i64_checked(
+        #[case] value: f64,
+        #[case] precision: u8,
+        #[case] expected: Result<i64, FixedPointError>,
+    ) {
+        assert_eq!(f64_to_fixed_i64_checked(value, precision), expected);
+    }
+
+    #[rstest]
+    #[case(1_500_000_000, 2_000_000_000, 3_000_000_000)]
+    #[case(-1_500_000_000, 10_000, -15_000)]
+    #[case(5, 500_000_000, 3)] // 2.5e-9 rounds away from zero
+    #[case(-5, 500_000_000, -3)]
+    fn test_fixed_i64_mul(#[case] a: i64, #[case] b: i64, #[case] expected: i64) {
+        assert_eq!(fixed_i64_mul(a, b), expected);
+    }
+
+    #[rstest]
+    #[case(1_500_000_000, 2_000_000_000, Ok(3_000_000_000))]
+    #[case(5, 500_000_000, Err(FixedPointError::PrecisionLoss))]
+    #[case(i64::MAX, 2_000_000_000, Err(FixedPointError::Overflow))]
+    fn test_fixed_i64_mul_checked(
+        #[case] a: i64,
+        #[case] b: i64,
+        #[case] expected: Result<i64, FixedPointError>,
+    ) {
+        assert_eq!(fixed_i64_mul_checked(a, b), expected);
+    }
+
+    #[rstest]
+    #[case(5, 500_000_000, 3)]
+    #[case(2_000_000_000, 2_000_000_000, 4_000_000_000)]
+    fn test_fixed_u64_mul(#[case] a: u64, #[case] b: u64, #[case] expected: u64) {
+        assert_eq!(fixed_u64_mul(a, b), expected);
+        assert_eq!(
+            fixed_u64_mul_checked(a, b).is_ok(),
+            (a as u128 * b as u128).is_multiple_of(1_000_000_000)
+        );
+    }
+
+    #[rstest]
+    #[case(100.5, 1, Ok(100_500_000_000))]
+    #[case(-1.0, 0, Err(FixedPointError::Negative))]
+    #[case(0.333, 2, Err(FixedPointError::PrecisionLoss))]
+    #[case(2e10, 0, Err(FixedPointError::Overflow))]
+    fn test_f64_to_fixed_u64_checked(
+        #[case] value: f64,
+        #[case] precision: u8,
+        #[case] expected: Result<u64, FixedPointError>,
+    ) {
+        assert_eq!(f64_to_fixed_u64_checked(value, precision), expected);
+    }
 }
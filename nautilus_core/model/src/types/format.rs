@@ -0,0 +1,282 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Formatting of fixed-point values for reports and logs.
+//!
+//! Values are formatted exactly from their raw fixed-point representation,
+//! with the thousands and decimal separators of a `NumberFormat`, which can
+//! be given explicitly or from one of the common locales.
+
+use std::fmt::Formatter;
+
+use nautilus_core::string::pystr_to_string;
+use pyo3::ffi;
+
+use crate::types::fixed::FIXED_PRECISION;
+use crate::types::rounding::{div_rounded, RoundingMode};
+
+/// The `NumberFormat::decimals` to format at the precision of the value.
+pub const DECIMALS_PRECISION: u8 = 255;
+
+/// Represents where the currency code of a formatted amount of money is
+/// placed.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+#[allow(non_camel_case_types)]
+pub enum CurrencyPosition {
+    /// After the amount (e.g. "1,000.00 USD").
+    Suffix = 1,
+    /// Before the amount (e.g. "USD 1,000.00").
+    Prefix = 2,
+    /// Not shown.
+    Hidden = 3,
+}
+
+/// Represents the options for formatting a fixed-point value.
+///
+/// The separators are Unicode code points, with a zero thousands separator
+/// for no grouping.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct NumberFormat {
+    pub thousands_separator: u32,
+    pub decimal_separator: u32,
+    /// The decimals to format (rounding half away from zero), or
+    /// `DECIMALS_PRECISION` for the precision of the value.
+    pub decimals: u8,
+    pub currency_position: CurrencyPosition,
+}
+
+impl Default for NumberFormat {
+    /// Returns the format with comma thousands separators and a point decimal
+    /// separator (e.g. "1,234.50 USD").
+    fn default() -> Self {
+        NumberFormat::new(Some(','), '.')
+    }
+}
+
+impl NumberFormat {
+    pub fn new(thousands_separator: Option<char>, decimal_separator: char) -> Self {
+        NumberFormat {
+            thousands_separator: thousands_separator.map_or(0, u32::from),
+            decimal_separator: u32::from(decimal_separator),
+            decimals: DECIMALS_PRECISION,
+            currency_position: CurrencyPosition::Suffix,
+        }
+    }
+
+    /// Returns the format of the locale (e.g. "de_DE" or "en-US"), or `None`
+    /// if the locale is not supported.
+    pub fn for_locale(locale: &str) -> Option<Self> {
+        let locale = locale.replace('-', "_");
+        let (thousands, decimal) = match locale.as_str() {
+            "C" | "POSIX" => (None, '.'),
+            "en_US" | "en_GB" | "en_AU" | "en_CA" | "en_NZ" | "en_SG" | "en_HK" | "ja_JP"
+            | "ko_KR" | "zh_CN" | "zh_HK" | "zh_TW" | "th_TH" | "he_IL" => (Some(','), '.'),
+            "de_DE" | "de_AT" | "es_ES" | "it_IT" | "nl_NL" | "pt_BR" | "id_ID" | "da_DK"
+            | "tr_TR" | "el_GR" => (Some('.'), ','),
+            "fr_FR" | "fr_CA" | "ru_RU" | "pl_PL" | "sv_SE" | "nb_NO" | "fi_FI" | "cs_CZ"
+            | "uk_UA" | "pt_PT" => (Some('\u{a0}'), ','),
+            "de_CH" | "fr_CH" | "it_CH" => (Some('\u{2019}'), '.'),
+            _ => return None,
+        };
+        Some(NumberFormat::new(thousands, decimal))
+    }
+
+    pub fn with_decimals(mut self, decimals: u8) -> Self {
+        self.decimals = decimals;
+        self
+    }
+
+    pub fn with_currency_position(mut self, position: CurrencyPosition) -> Self {
+        self.currency_position = position;
+        self
+    }
+
+    /// Returns the formatted raw fixed-point value of the precision.
+    pub fn format_raw(&self, raw: i64, precision: u8) -> String {
+        let decimals = match self.decimals {
+            DECIMALS_PRECISION => precision,
+            decimals => decimals,
+        }
+        .min(FIXED_PRECISION) as u32;
+        let units = div_rounded(
+            raw as i128,
+            10_i128.pow(FIXED_PRECISION as u32 - decimals),
+            RoundingMode::Nearest,
+        )
+        .expect("non-zero divisor");
+        let scalar = 10_u128.pow(decimals);
+        let digits = (units.unsigned_abs() / scalar).to_string();
+
+        let mut formatted = String::with_capacity(digits.len() * 4 / 3 + decimals as usize + 2);
+        if units < 0 {
+            formatted.push('-');
+        }
+        let thousands = char::from_u32(self.thousands_separator).filter(|c| *c != '\0');
+        for (i, c) in digits.chars().enumerate() {
+            if let Some(separator) = thousands {
+                if i > 0 && (digits.len() - i).is_multiple_of(3) {
+                    formatted.push(separator);
+                }
+            }
+            formatted.push(c);
+        }
+        if decimals > 0 {
+            formatted.push(char::from_u32(self.decimal_separator).unwrap_or('.'));
+            formatted.push_str(&format!(
+                "{:0width$}",
+                units.unsigned_abs() % scalar,
+                width = decimals as usize
+            ));
+        }
+        formatted
+    }
+
+    /// Returns the formatted amount with the currency code placed by the
+    /// currency position.
+    pub fn format_amount(&self, raw: i64, precision: u8, code: &str) -> String {
+        let amount = self.format_raw(raw, precision);
+        match self.currency_position {
+            CurrencyPosition::Suffix => format!("{} {}", amount, code),
+            CurrencyPosition::Prefix => format!("{} {}", code, amount),
+            CurrencyPosition::Hidden => amount,
+        }
+    }
+}
+
+/// Returns the format for `Display` with the formatter flags, being comma
+/// thousands separators for the alternate flag and the formatter precision as
+/// the decimals.
+pub(crate) fn display_format(f: &Formatter<'_>) -> NumberFormat {
+    let thousands = if f.alternate() { Some(',') } else { None };
+    let decimals = f.precision().map_or(DECIMALS_PRECISION, |p| {
+        p.min(FIXED_PRECISION as usize) as u8
+    });
+    NumberFormat::new(thousands, '.').with_decimals(decimals)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// C API
+////////////////////////////////////////////////////////////////////////////////
+#[no_mangle]
+pub extern "C" fn number_format_default() -> NumberFormat {
+    NumberFormat::default()
+}
+
+/// Returns zero and writes the format of the locale to `out`, otherwise
+/// returns 1 if the locale is not supported.
+///
+/// # Safety
+///
+/// - `locale_ptr` must be borrowed from a valid Python UTF-8 `str`.
+/// - `out` must be a valid pointer to write the format to.
+#[no_mangle]
+pub unsafe extern "C" fn number_format_for_locale(
+    locale_ptr: *mut ffi::PyObject,
+    out: *mut NumberFormat,
+) -> u8 {
+    match NumberFormat::for_locale(&pystr_to_string(locale_ptr)) {
+        Some(format) => {
+            out.write(format);
+            0
+        }
+        None => 1,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_raw_at_precision() {
+        let format = NumberFormat::default();
+
+        assert_eq!(format.format_raw(1_234_567_500_000_000, 2), "1,234,567.50");
+        assert_eq!(format.format_raw(-1_000_000_000_000, 0), "-1,000");
+        assert_eq!(format.format_raw(999_000_000_000, 1), "999.0");
+        assert_eq!(format.format_raw(0, 3), "0.000");
+    }
+
+    #[test]
+    fn test_format_raw_with_fixed_decimals_rounds() {
+        let format = NumberFormat::default().with_decimals(1);
+
+        assert_eq!(format.format_raw(1_250_000_000, 2), "1.3");
+        assert_eq!(format.format_raw(-1_250_000_000, 2), "-1.3");
+        assert_eq!(format.format_raw(-40_000_000, 2), "0.0");
+        assert_eq!(
+            NumberFormat::default()
+                .with_decimals(4)
+                .format_raw(1_500_000_000, 1),
+            "1.5000"
+        );
+    }
+
+    #[test]
+    fn test_format_for_locales() {
+        let raw = 1_234_567_890_000_000;
+
+        assert_eq!(
+            NumberFormat::for_locale("de_DE")
+                .unwrap()
+                .format_raw(raw, 2),
+            "1.234.567,89"
+        );
+        assert_eq!(
+            NumberFormat::for_locale("fr-FR")
+                .unwrap()
+                .format_raw(raw, 2),
+            "1\u{a0}234\u{a0}567,89"
+        );
+        assert_eq!(
+            NumberFormat::for_locale("de_CH")
+                .unwrap()
+                .format_raw(raw, 2),
+            "1\u{2019}234\u{2019}567.89"
+        );
+        assert_eq!(
+            NumberFormat::for_locale("C").unwrap().format_raw(raw, 2),
+            "1234567.89"
+        );
+        assert_eq!(NumberFormat::for_locale("xx_XX"), None);
+    }
+
+    #[test]
+    fn test_format_amount_currency_positions() {
+        let format = NumberFormat::default();
+
+        assert_eq!(
+            format.format_amount(-1_500_000_000_000, 2, "USD"),
+            "-1,500.00 USD"
+        );
+        assert_eq!(
+            format
+                .with_currency_position(CurrencyPosition::Prefix)
+                .format_amount(1_500_000_000_000, 2, "USD"),
+            "USD 1,500.00"
+        );
+        assert_eq!(
+            format
+                .with_currency_position(CurrencyPosition::Hidden)
+                .format_amount(1_500_000_000_000, 2, "USD"),
+            "1,500.00"
+        );
+    }
+}
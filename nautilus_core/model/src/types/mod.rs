@@ -13,9 +13,19 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+pub mod accumulator;
+pub mod audit;
 pub mod balance;
 pub mod currency;
 pub mod fixed;
+pub mod format;
 pub mod money;
+pub mod notional;
 pub mod price;
+pub mod price_band;
 pub mod quantity;
+pub mod rounding;
+#[cfg(feature = "serde")]
+mod serde;
+#[cfg(feature = "wide-fixed")]
+pub mod wide;
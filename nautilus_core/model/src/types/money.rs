@@ -14,14 +14,20 @@
 // -------------------------------------------------------------------------------------------------
 
 use crate::types::currency::Currency;
-use crate::types::fixed::{f64_to_fixed_i64, fixed_i64_to_f64};
+use crate::types::fixed::{
+    f64_to_fixed_i64, fixed_i64_mul_checked, fixed_i64_to_f64, FixedPointError, FIXED_PRECISION,
+};
+use crate::types::format::{display_format, NumberFormat};
+use nautilus_core::string::string_to_pystr;
+use pyo3::ffi;
 use std::cmp::Ordering;
+use std::collections::hash_map::DefaultHasher;
 use std::fmt::{Display, Formatter, Result};
 use std::hash::{Hash, Hasher};
 use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
 #[repr(C)]
-#[derive(Eq, Clone)]
+#[derive(Eq, Clone, Debug)]
 pub struct Money {
     raw: i64,
     pub currency: Currency,
@@ -39,12 +45,82 @@ impl Money {
         Money { raw, currency }
     }
 
+    pub fn raw(&self) -> i64 {
+        self.raw
+    }
+
     pub fn is_zero(&self) -> bool {
         self.raw == 0
     }
+
     pub fn as_f64(&self) -> f64 {
         fixed_i64_to_f64(self.raw)
     }
+
+    /// Returns the sum, or an error if the currencies differ or it overflows.
+    pub fn checked_add(&self, other: &Money) -> std::result::Result<Self, FixedPointError> {
+        self.check_currency(other)?;
+        let raw = self
+            .raw
+            .checked_add(other.raw)
+            .ok_or(FixedPointError::Overflow)?;
+        Ok(Money::from_raw(raw, self.currency.clone()))
+    }
+
+    /// Returns the difference, or an error if the currencies differ or it
+    /// overflows.
+    pub fn checked_sub(&self, other: &Money) -> std::result::Result<Self, FixedPointError> {
+        self.check_currency(other)?;
+        let raw = self
+            .raw
+            .checked_sub(other.raw)
+            .ok_or(FixedPointError::Overflow)?;
+        Ok(Money::from_raw(raw, self.currency.clone()))
+    }
+
+    /// Returns the product, or an error if the currencies differ, it is not
+    /// exactly representable at the currency precision or it overflows.
+    pub fn checked_mul(&self, other: &Money) -> std::result::Result<Self, FixedPointError> {
+        self.check_currency(other)?;
+        let raw = fixed_i64_mul_checked(self.raw, other.raw)?;
+        let increment =
+            10_i64.pow((FIXED_PRECISION - self.currency.precision.min(FIXED_PRECISION)) as u32);
+        if raw % increment != 0 {
+            return Err(FixedPointError::PrecisionLoss);
+        }
+        Ok(Money::from_raw(raw, self.currency.clone()))
+    }
+
+    /// Returns the negation, or an error if it overflows.
+    pub fn checked_neg(&self) -> std::result::Result<Self, FixedPointError> {
+        let raw = self.raw.checked_neg().ok_or(FixedPointError::Overflow)?;
+        Ok(Money::from_raw(raw, self.currency.clone()))
+    }
+
+    /// Returns the ordering of the amounts, or an error if the currencies
+    /// differ.
+    pub fn checked_cmp(&self, other: &Money) -> std::result::Result<Ordering, FixedPointError> {
+        self.check_currency(other)?;
+        Ok(self.raw.cmp(&other.raw))
+    }
+
+    /// Returns the amount at the currency precision with underscore thousands
+    /// separators, followed by the currency code (e.g. "1_000.00 USD").
+    pub fn to_formatted_string(&self) -> String {
+        self.format(&NumberFormat::new(Some('_'), '.'))
+    }
+
+    /// Returns the amount and currency code formatted by the format.
+    pub fn format(&self, format: &NumberFormat) -> String {
+        format.format_amount(self.raw, self.currency.precision, &self.currency.code)
+    }
+
+    fn check_currency(&self, other: &Money) -> std::result::Result<(), FixedPointError> {
+        if self.currency != other.currency {
+            return Err(FixedPointError::CurrencyMismatch);
+        }
+        Ok(())
+    }
 }
 
 impl Hash for Money {
@@ -62,7 +138,7 @@ impl PartialEq for Money {
 
 impl PartialOrd for Money {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        self.raw.partial_cmp(&other.raw)
+        Some(self.cmp(other))
     }
 
     fn lt(&self, other: &Self) -> bool {
@@ -152,6 +228,7 @@ impl SubAssign for Money {
 
 impl MulAssign for Money {
     fn mul_assign(&mut self, multiplier: Self) {
+        assert_eq!(self.currency, multiplier.currency);
         self.raw *= multiplier.raw;
     }
 }
@@ -178,7 +255,13 @@ impl Mul<f64> for Money {
 }
 
 impl Display for Money {
+    /// Formats with comma thousands separators for the alternate flag (`{:#}`)
+    /// and at the decimals of the precision (`{:.4}`) when given.
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        if f.alternate() || f.precision().is_some() {
+            let format = display_format(f);
+            return f.write_str(&self.format(&format));
+        }
         write!(
             f,
             "{:.*} {}",
@@ -213,13 +296,186 @@ pub extern "C" fn money_as_f64(money: &Money) -> f64 {
 }
 
 #[no_mangle]
-pub extern "C" fn money_add_assign(mut a: Money, b: Money) {
-    a.add_assign(b);
+pub extern "C" fn money_raw(money: &Money) -> i64 {
+    money.raw
+}
+
+/// Returns a pointer to a valid Python UTF-8 string of the currency code.
+///
+/// # Safety
+///
+/// - Assumes that since the data is originating from Rust, the GIL does not need
+///   to be acquired.
+/// - Assumes you are immediately returning this pointer to Python.
+#[no_mangle]
+pub unsafe extern "C" fn money_currency_code_to_pystr(money: &Money) -> *mut ffi::PyObject {
+    string_to_pystr(money.currency.code.as_str())
 }
 
+/// Returns a pointer to a valid Python UTF-8 string of the formatted amount
+/// and currency code (e.g. "1_000.00 USD").
+///
+/// # Safety
+///
+/// - Assumes that since the data is originating from Rust, the GIL does not need
+///   to be acquired.
+/// - Assumes you are immediately returning this pointer to Python.
 #[no_mangle]
-pub extern "C" fn money_sub_assign(mut a: Money, b: Money) {
-    a.sub_assign(b);
+pub unsafe extern "C" fn money_to_pystr(money: &Money) -> *mut ffi::PyObject {
+    string_to_pystr(&money.to_formatted_string())
+}
+
+/// Returns a pointer to a valid Python UTF-8 string of the amount and currency
+/// code formatted by the format.
+///
+/// # Safety
+///
+/// - Assumes that since the data is originating from Rust, the GIL does not need
+///   to be acquired.
+/// - Assumes you are immediately returning this pointer to Python.
+#[no_mangle]
+pub unsafe extern "C" fn money_to_formatted_pystr(
+    money: &Money,
+    format: &NumberFormat,
+) -> *mut ffi::PyObject {
+    string_to_pystr(&money.format(format))
+}
+
+#[no_mangle]
+pub extern "C" fn money_hash(money: &Money) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    money.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[no_mangle]
+pub extern "C" fn money_eq(a: &Money, b: &Money) -> u8 {
+    u8::from(a == b)
+}
+
+#[no_mangle]
+pub extern "C" fn money_ne(a: &Money, b: &Money) -> u8 {
+    u8::from(a != b)
+}
+
+/// Returns zero and writes -1, 0 or 1 to `out` as `a` is less than, equal to
+/// or greater than `b`, otherwise returns the non-zero `FixedPointError` code
+/// if the currencies differ.
+///
+/// # Safety
+///
+/// - `out` must be a valid pointer to write the value to.
+#[no_mangle]
+pub unsafe extern "C" fn money_checked_cmp(a: &Money, b: &Money, out: *mut i8) -> u8 {
+    match a.checked_cmp(b) {
+        Ok(ordering) => {
+            out.write(ordering as i8);
+            0
+        }
+        Err(e) => e as u8,
+    }
+}
+
+/// Returns zero and writes the lesser of the values (`a` if equal) to `out`,
+/// otherwise returns the non-zero `FixedPointError` code if the currencies
+/// differ.
+///
+/// # Safety
+///
+/// - `out` must be a valid pointer to write the value to.
+#[no_mangle]
+pub unsafe extern "C" fn money_checked_min(a: &Money, b: &Money, out: *mut Money) -> u8 {
+    match a.checked_cmp(b) {
+        Ok(ordering) => {
+            out.write(if ordering == Ordering::Greater { b } else { a }.clone());
+            0
+        }
+        Err(e) => e as u8,
+    }
+}
+
+/// Returns zero and writes the greater of the values (`b` if equal) to `out`,
+/// otherwise returns the non-zero `FixedPointError` code if the currencies
+/// differ.
+///
+/// # Safety
+///
+/// - `out` must be a valid pointer to write the value to.
+#[no_mangle]
+pub unsafe extern "C" fn money_checked_max(a: &Money, b: &Money, out: *mut Money) -> u8 {
+    match a.checked_cmp(b) {
+        Ok(ordering) => {
+            out.write(if ordering == Ordering::Greater { a } else { b }.clone());
+            0
+        }
+        Err(e) => e as u8,
+    }
+}
+
+/// Returns zero and writes `a + b` to `out`, otherwise returns the non-zero
+/// `FixedPointError` code if the currencies differ or the sum overflows.
+///
+/// # Safety
+///
+/// - `out` must be a valid pointer to write the value to.
+#[no_mangle]
+pub unsafe extern "C" fn money_checked_add(a: &Money, b: &Money, out: *mut Money) -> u8 {
+    write_result(a.checked_add(b), out)
+}
+
+/// Returns zero and writes `a - b` to `out`, otherwise returns the non-zero
+/// `FixedPointError` code if the currencies differ or the difference
+/// overflows.
+///
+/// # Safety
+///
+/// - `out` must be a valid pointer to write the value to.
+#[no_mangle]
+pub unsafe extern "C" fn money_checked_sub(a: &Money, b: &Money, out: *mut Money) -> u8 {
+    write_result(a.checked_sub(b), out)
+}
+
+/// Returns zero and writes `a * b` to `out`, otherwise returns the non-zero
+/// `FixedPointError` code if the currencies differ, or the product is not
+/// exact at the currency precision or overflows.
+///
+/// # Safety
+///
+/// - `out` must be a valid pointer to write the value to.
+#[no_mangle]
+pub unsafe extern "C" fn money_checked_mul(a: &Money, b: &Money, out: *mut Money) -> u8 {
+    write_result(a.checked_mul(b), out)
+}
+
+/// Returns zero and writes `-money` to `out`, otherwise returns the non-zero
+/// `FixedPointError` code if the negation overflows.
+///
+/// # Safety
+///
+/// - `out` must be a valid pointer to write the value to.
+#[no_mangle]
+pub unsafe extern "C" fn money_checked_neg(money: &Money, out: *mut Money) -> u8 {
+    write_result(money.checked_neg(), out)
+}
+
+unsafe fn write_result(result: std::result::Result<Money, FixedPointError>, out: *mut Money) -> u8 {
+    match result {
+        Ok(money) => {
+            out.write(money);
+            0
+        }
+        Err(e) => e as u8,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn money_add_assign(a: &mut Money, b: &Money) {
+    a.add_assign(b.clone());
+}
+
+#[no_mangle]
+pub extern "C" fn money_sub_assign(a: &mut Money, b: &Money) {
+    a.sub_assign(b.clone());
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -227,8 +483,11 @@ pub extern "C" fn money_sub_assign(mut a: Money, b: Money) {
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
+    use std::mem::MaybeUninit;
+
     use super::*;
     use crate::enums::CurrencyType;
+    use crate::types::format::CurrencyPosition;
 
     #[test]
     fn test_money_new_usd() {
@@ -251,29 +510,129 @@ mod tests {
         assert_eq!(money.to_string(), "10.30000000 BTC");
     }
 
-    // #[test]
-    // fn test_account_balance() {
-    //     let usd = Currency {
-    //         code: String::from("USD"),
-    //         precision: 2,
-    //         currency_type: CurrencyType::Fiat,
-    //     };
-    //     let balance = AccountBalance {
-    //         currency: usd,
-    //         total: Money {
-    //             amount: Decimal::new(103, 1),
-    //             currency: usd,
-    //         },
-    //         locked: Money {
-    //             amount: Decimal::new(0, 0),
-    //             currency: usd,
-    //         },
-    //         free: Money {
-    //             amount: Decimal::new(103, 1),
-    //             currency: usd,
-    //         },
-    //     };
-    //
-    //     assert_eq!(balance.to_string(), "10.30000000 BTC");
-    // }
+    #[test]
+    fn test_money_to_formatted_string() {
+        let usd = Currency::new("USD", 2, 840, "United States dollar", CurrencyType::Fiat);
+        let jpy = Currency::new("JPY", 0, 392, "Japanese yen", CurrencyType::Fiat);
+
+        assert_eq!(
+            Money::new(1000.0, usd.clone()).to_formatted_string(),
+            "1_000.00 USD"
+        );
+        assert_eq!(
+            Money::new(-1234567.5, usd.clone()).to_formatted_string(),
+            "-1_234_567.50 USD"
+        );
+        assert_eq!(Money::new(0.01, usd).to_formatted_string(), "0.01 USD");
+        assert_eq!(Money::new(100.0, jpy).to_formatted_string(), "100 JPY");
+    }
+
+    #[test]
+    fn test_money_display_with_options() {
+        let usd = Currency::new("USD", 2, 840, "United States dollar", CurrencyType::Fiat);
+        let money = Money::new(-1234567.5, usd);
+
+        assert_eq!(format!("{}", money), "-1234567.50 USD");
+        assert_eq!(format!("{:#}", money), "-1,234,567.50 USD");
+        assert_eq!(format!("{:.0}", money), "-1234568 USD");
+        assert_eq!(
+            money.format(
+                &NumberFormat::for_locale("de_DE")
+                    .unwrap()
+                    .with_currency_position(CurrencyPosition::Prefix)
+            ),
+            "USD -1.234.567,50"
+        );
+    }
+
+    #[test]
+    fn test_money_checked_arithmetic() {
+        let usd = Currency::new("USD", 2, 840, "United States dollar", CurrencyType::Fiat);
+        let aud = Currency::new("AUD", 2, 36, "Australian dollar", CurrencyType::Fiat);
+        let a = Money::new(10.50, usd.clone());
+        let b = Money::new(0.25, usd.clone());
+
+        assert_eq!(a.checked_add(&b), Ok(Money::new(10.75, usd.clone())));
+        assert_eq!(b.checked_sub(&a), Ok(Money::new(-10.25, usd.clone())));
+        assert_eq!(
+            a.checked_add(&Money::new(1.0, aud)),
+            Err(FixedPointError::CurrencyMismatch)
+        );
+        assert_eq!(
+            Money::from_raw(i64::MAX, usd.clone()).checked_add(&b),
+            Err(FixedPointError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_money_checked_mul_and_neg() {
+        let usd = Currency::new("USD", 2, 840, "United States dollar", CurrencyType::Fiat);
+        let a = Money::new(10.50, usd.clone());
+        let mut out = MaybeUninit::<Money>::uninit();
+
+        assert_eq!(
+            a.checked_mul(&Money::new(2.0, usd.clone())),
+            Ok(Money::new(21.0, usd.clone()))
+        );
+        assert_eq!(
+            a.checked_mul(&Money::new(0.25, usd.clone())),
+            Err(FixedPointError::PrecisionLoss)
+        );
+        assert_eq!(
+            Money::new(1_000_000_000.0, usd.clone())
+                .checked_mul(&Money::new(1_000_000_000.0, usd.clone())),
+            Err(FixedPointError::Overflow)
+        );
+        assert_eq!(a.checked_neg(), Ok(Money::new(-10.50, usd.clone())));
+        assert_eq!(
+            Money::from_raw(i64::MIN, usd.clone()).checked_neg(),
+            Err(FixedPointError::Overflow)
+        );
+
+        unsafe {
+            assert_eq!(money_checked_neg(&a, out.as_mut_ptr()), 0);
+            assert_eq!(out.assume_init_read(), Money::new(-10.50, usd.clone()));
+            assert_eq!(
+                money_checked_mul(&a, &Money::new(0.25, usd), out.as_mut_ptr()),
+                FixedPointError::PrecisionLoss as u8
+            );
+        }
+    }
+
+    #[test]
+    fn test_money_c_api_comparisons_and_min_max() {
+        let usd = Currency::new("USD", 2, 840, "United States dollar", CurrencyType::Fiat);
+        let aud = Currency::new("AUD", 2, 36, "Australian dollar", CurrencyType::Fiat);
+        let a = Money::new(10.50, usd.clone());
+        let b = Money::new(0.25, usd.clone());
+        let mut ordering: i8 = 0;
+        let mut out = MaybeUninit::<Money>::uninit();
+
+        unsafe {
+            assert_eq!(money_ne(&a, &b), 1);
+            assert_eq!(money_checked_cmp(&a, &b, &mut ordering), 0);
+            assert_eq!(ordering, 1);
+            assert_eq!(money_checked_min(&a, &b, out.as_mut_ptr()), 0);
+            assert_eq!(out.assume_init_read(), b);
+            assert_eq!(money_checked_max(&a, &b, out.as_mut_ptr()), 0);
+            assert_eq!(out.assume_init_read(), a);
+            assert_eq!(
+                money_checked_cmp(&a, &Money::new(1.0, aud.clone()), &mut ordering),
+                FixedPointError::CurrencyMismatch as u8
+            );
+            assert_eq!(
+                money_checked_max(&a, &Money::new(1.0, aud), out.as_mut_ptr()),
+                FixedPointError::CurrencyMismatch as u8
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_money_add_with_different_currencies_panics() {
+        let usd = Currency::new("USD", 2, 840, "United States dollar", CurrencyType::Fiat);
+        let aud = Currency::new("AUD", 2, 36, "Australian dollar", CurrencyType::Fiat);
+
+        let _ = Money::new(1.0, usd) + Money::new(1.0, aud);
+    }
 }
@@ -0,0 +1,470 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Conversions between quantities of contracts and their notional value.
+//!
+//! For standard contracts the notional is `quantity * multiplier * price` in
+//! the quote currency, and for inverse contracts `quantity * multiplier /
+//! price` in the base currency. The conversions are exact on the decimal
+//! values, rounding only the result.
+
+use crate::types::currency::Currency;
+use crate::types::fixed::{FixedPointError, FIXED_PRECISION};
+use crate::types::money::Money;
+use crate::types::price::Price;
+use crate::types::quantity::Quantity;
+use crate::types::rounding::{div_rounded, RoundingMode};
+
+/// Represents a fixed-point value as a mantissa and number of decimals.
+struct Decimal {
+    mantissa: i128,
+    scale: u32,
+}
+
+impl Decimal {
+    /// Returns the decimal of the raw value with the fewest decimals, up to
+    /// `FIXED_PRECISION`.
+    fn from_raw(raw: i128, precision: u8) -> Self {
+        let precision = precision.min(FIXED_PRECISION) as u32;
+        let divisor = 10_i128.pow(FIXED_PRECISION as u32 - precision);
+        match raw % divisor {
+            0 => Decimal {
+                mantissa: raw / divisor,
+                scale: precision,
+            },
+            _ => Decimal {
+                mantissa: raw,
+                scale: FIXED_PRECISION as u32,
+            },
+        }
+    }
+}
+
+/// Returns `(num * 10^num_exp) / (den * 10^den_exp)` rounded by the mode.
+fn scaled_div(
+    num: i128,
+    num_exp: u32,
+    den: i128,
+    den_exp: u32,
+    mode: RoundingMode,
+) -> Result<i128, FixedPointError> {
+    let pow10 = |exp: u32| 10_i128.checked_pow(exp).ok_or(FixedPointError::Overflow);
+    let (num, den) = if num_exp >= den_exp {
+        (num.checked_mul(pow10(num_exp - den_exp)?), Some(den))
+    } else {
+        (Some(num), den.checked_mul(pow10(den_exp - num_exp)?))
+    };
+    match (num, den) {
+        (Some(num), Some(den)) => div_rounded(num, den, mode),
+        _ => Err(FixedPointError::Overflow),
+    }
+}
+
+fn checked_product(values: &[i128]) -> Result<i128, FixedPointError> {
+    values.iter().try_fold(1_i128, |acc, v| {
+        acc.checked_mul(*v).ok_or(FixedPointError::Overflow)
+    })
+}
+
+fn money_from_units(units: i128, currency: Currency) -> Result<Money, FixedPointError> {
+    let raw = units
+        .checked_mul(10_i128.pow((FIXED_PRECISION - currency.precision) as u32))
+        .and_then(|raw| i64::try_from(raw).ok())
+        .ok_or(FixedPointError::Overflow)?;
+    Ok(Money::from_raw(raw, currency))
+}
+
+fn quantity_from_units(units: i128, precision: u8) -> Result<Quantity, FixedPointError> {
+    if units < 0 {
+        return Err(FixedPointError::Negative);
+    }
+    let raw = units
+        .checked_mul(10_i128.pow((FIXED_PRECISION - precision) as u32))
+        .and_then(|raw| u64::try_from(raw).ok())
+        .ok_or(FixedPointError::Overflow)?;
+    Ok(Quantity::from_raw(raw, precision))
+}
+
+fn check_precision(precision: u8) -> Result<(), FixedPointError> {
+    if precision > FIXED_PRECISION {
+        return Err(FixedPointError::InvalidPrecision);
+    }
+    Ok(())
+}
+
+/// Returns the notional value (`quantity * multiplier * price`) of standard
+/// contracts in the currency, rounded to its precision with ties away from
+/// zero.
+pub fn notional_value(
+    quantity: &Quantity,
+    price: &Price,
+    multiplier: &Quantity,
+    currency: Currency,
+) -> Result<Money, FixedPointError> {
+    check_precision(currency.precision)?;
+    let q = Decimal::from_raw(quantity.raw as i128, quantity.precision);
+    let m = Decimal::from_raw(multiplier.raw as i128, multiplier.precision);
+    let p = Decimal::from_raw(price.raw as i128, price.precision);
+    let units = scaled_div(
+        checked_product(&[q.mantissa, m.mantissa, p.mantissa])?,
+        currency.precision as u32,
+        1,
+        q.scale + m.scale + p.scale,
+        RoundingMode::Nearest,
+    )?;
+    money_from_units(units, currency)
+}
+
+/// Returns the notional value (`quantity * multiplier / price`) of inverse
+/// contracts in the currency, rounded to its precision with ties away from
+/// zero.
+pub fn inverse_notional_value(
+    quantity: &Quantity,
+    price: &Price,
+    multiplier: &Quantity,
+    currency: Currency,
+) -> Result<Money, FixedPointError> {
+    check_precision(currency.precision)?;
+    let q = Decimal::from_raw(quantity.raw as i128, quantity.precision);
+    let m = Decimal::from_raw(multiplier.raw as i128, multiplier.precision);
+    let p = Decimal::from_raw(price.raw as i128, price.precision);
+    let units = scaled_div(
+        checked_product(&[q.mantissa, m.mantissa])?,
+        p.scale + currency.precision as u32,
+        p.mantissa,
+        q.scale + m.scale,
+        RoundingMode::Nearest,
+    )?;
+    money_from_units(units, currency)
+}
+
+/// Returns the quantity of standard contracts (`notional / (multiplier *
+/// price)`) at the precision, rounded by the mode.
+pub fn quantity_for_notional(
+    notional: &Money,
+    price: &Price,
+    multiplier: &Quantity,
+    precision: u8,
+    mode: RoundingMode,
+) -> Result<Quantity, FixedPointError> {
+    check_precision(precision)?;
+    let n = Decimal::from_raw(notional.raw() as i128, notional.currency.precision);
+    let m = Decimal::from_raw(multiplier.raw as i128, multiplier.precision);
+    let p = Decimal::from_raw(price.raw as i128, price.precision);
+    let units = scaled_div(
+        n.mantissa,
+        precision as u32 + m.scale + p.scale,
+        checked_product(&[m.mantissa, p.mantissa])?,
+        n.scale,
+        mode,
+    )?;
+    quantity_from_units(units, precision)
+}
+
+/// Returns the quantity of inverse contracts (`notional * price /
+/// multiplier`) at the precision, rounded by the mode.
+pub fn quantity_for_inverse_notional(
+    notional: &Money,
+    price: &Price,
+    multiplier: &Quantity,
+    precision: u8,
+    mode: RoundingMode,
+) -> Result<Quantity, FixedPointError> {
+    check_precision(precision)?;
+    let n = Decimal::from_raw(notional.raw() as i128, notional.currency.precision);
+    let m = Decimal::from_raw(multiplier.raw as i128, multiplier.precision);
+    let p = Decimal::from_raw(price.raw as i128, price.precision);
+    let units = scaled_div(
+        checked_product(&[n.mantissa, p.mantissa])?,
+        precision as u32 + m.scale,
+        m.mantissa,
+        n.scale + p.scale,
+        mode,
+    )?;
+    quantity_from_units(units, precision)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// C API
+////////////////////////////////////////////////////////////////////////////////
+/// Returns zero and writes the notional value of the standard contracts to
+/// `out`, otherwise returns the non-zero `FixedPointError` code.
+///
+/// # Safety
+///
+/// - `out` must be a valid pointer to write the value to.
+#[no_mangle]
+pub unsafe extern "C" fn notional_value_from_quantity(
+    quantity: &Quantity,
+    price: &Price,
+    multiplier: &Quantity,
+    currency: &Currency,
+    out: *mut Money,
+) -> u8 {
+    match notional_value(quantity, price, multiplier, currency.clone()) {
+        Ok(money) => {
+            out.write(money);
+            0
+        }
+        Err(e) => e as u8,
+    }
+}
+
+/// Returns zero and writes the notional value of the inverse contracts to
+/// `out`, otherwise returns the non-zero `FixedPointError` code.
+///
+/// # Safety
+///
+/// - `out` must be a valid pointer to write the value to.
+#[no_mangle]
+pub unsafe extern "C" fn inverse_notional_value_from_quantity(
+    quantity: &Quantity,
+    price: &Price,
+    multiplier: &Quantity,
+    currency: &Currency,
+    out: *mut Money,
+) -> u8 {
+    match inverse_notional_value(quantity, price, multiplier, currency.clone()) {
+        Ok(money) => {
+            out.write(money);
+            0
+        }
+        Err(e) => e as u8,
+    }
+}
+
+/// Returns zero and writes the quantity of standard contracts for the
+/// notional to `out`, otherwise returns the non-zero `FixedPointError` code.
+///
+/// # Safety
+///
+/// - `out` must be a valid pointer to write the value to.
+#[no_mangle]
+pub unsafe extern "C" fn notional_value_to_quantity(
+    notional: &Money,
+    price: &Price,
+    multiplier: &Quantity,
+    precision: u8,
+    mode: RoundingMode,
+    out: *mut Quantity,
+) -> u8 {
+    match quantity_for_notional(notional, price, multiplier, precision, mode) {
+        Ok(qty) => {
+            out.write(qty);
+            0
+        }
+        Err(e) => e as u8,
+    }
+}
+
+/// Returns zero and writes the quantity of inverse contracts for the notional
+/// to `out`, otherwise returns the non-zero `FixedPointError` code.
+///
+/// # Safety
+///
+/// - `out` must be a valid pointer to write the value to.
+#[no_mangle]
+pub unsafe extern "C" fn inverse_notional_value_to_quantity(
+    notional: &Money,
+    price: &Price,
+    multiplier: &Quantity,
+    precision: u8,
+    mode: RoundingMode,
+    out: *mut Quantity,
+) -> u8 {
+    match quantity_for_inverse_notional(notional, price, multiplier, precision, mode) {
+        Ok(qty) => {
+            out.write(qty);
+            0
+        }
+        Err(e) => e as u8,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::mem::MaybeUninit;
+
+    use super::*;
+    use crate::enums::CurrencyType;
+
+    fn usd() -> Currency {
+        Currency::new("USD", 2, 840, "United States dollar", CurrencyType::Fiat)
+    }
+
+    fn btc() -> Currency {
+        Currency::new("BTC", 8, 0, "Bitcoin", CurrencyType::Crypto)
+    }
+
+    #[test]
+    fn test_notional_value_of_futures() {
+        // 3 ES contracts with a multiplier of 50 at 4,512.25
+        let notional = notional_value(
+            &Quantity::new(3.0, 0),
+            &Price::new(4512.25, 2),
+            &Quantity::new(50.0, 0),
+            usd(),
+        )
+        .unwrap();
+
+        assert_eq!(notional, Money::new(676_837.50, usd()));
+        assert_eq!(
+            quantity_for_notional(
+                &notional,
+                &Price::new(4512.25, 2),
+                &Quantity::new(50.0, 0),
+                0,
+                RoundingMode::Down,
+            ),
+            Ok(Quantity::new(3.0, 0))
+        );
+    }
+
+    #[test]
+    fn test_notional_value_rounds_to_currency_precision() {
+        let notional = notional_value(
+            &Quantity::new(1.0, 0),
+            &Price::new(0.12345, 5),
+            &Quantity::new(1.0, 0),
+            usd(),
+        );
+
+        assert_eq!(notional, Ok(Money::new(0.12, usd())));
+    }
+
+    #[test]
+    fn test_inverse_notional_value() {
+        // 100 XBTUSD contracts of 1 USD at 40,000 is 0.0025 BTC
+        let notional = inverse_notional_value(
+            &Quantity::new(100.0, 0),
+            &Price::new(40_000.0, 1),
+            &Quantity::new(1.0, 0),
+            btc(),
+        )
+        .unwrap();
+
+        assert_eq!(notional, Money::new(0.0025, btc()));
+        assert_eq!(
+            quantity_for_inverse_notional(
+                &notional,
+                &Price::new(40_000.0, 1),
+                &Quantity::new(1.0, 0),
+                0,
+                RoundingMode::Down,
+            ),
+            Ok(Quantity::new(100.0, 0))
+        );
+    }
+
+    #[test]
+    fn test_quantity_for_notional_rounding_modes() {
+        let notional = Money::new(1_000.0, usd());
+        let price = Price::new(300.0, 0);
+        let multiplier = Quantity::new(1.0, 0);
+
+        let down = quantity_for_notional(&notional, &price, &multiplier, 1, RoundingMode::Down);
+        let up = quantity_for_notional(&notional, &price, &multiplier, 1, RoundingMode::Up);
+
+        assert_eq!(down, Ok(Quantity::new(3.3, 1)));
+        assert_eq!(up, Ok(Quantity::new(3.4, 1)));
+    }
+
+    #[test]
+    fn test_notional_conversion_errors() {
+        let zero = Price::new(0.0, 1);
+        let one = Quantity::new(1.0, 0);
+
+        assert_eq!(
+            inverse_notional_value(&one, &zero, &one, btc()),
+            Err(FixedPointError::DivisionByZero)
+        );
+        assert_eq!(
+            quantity_for_notional(
+                &Money::new(-10.0, usd()),
+                &Price::new(1.0, 0),
+                &one,
+                0,
+                RoundingMode::Down
+            ),
+            Err(FixedPointError::Negative)
+        );
+        assert_eq!(
+            quantity_for_notional(
+                &Money::new(10.0, usd()),
+                &Price::new(1.0, 0),
+                &one,
+                10,
+                RoundingMode::Down
+            ),
+            Err(FixedPointError::InvalidPrecision)
+        );
+        assert_eq!(
+            notional_value(
+                &Quantity::from_raw(u64::MAX, 9),
+                &Price::from_raw(i64::MAX, 9),
+                &Quantity::from_raw(u64::MAX, 9),
+                usd()
+            ),
+            Err(FixedPointError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_notional_c_api() {
+        let mut money = MaybeUninit::<Money>::uninit();
+        let mut qty = Quantity::default();
+
+        unsafe {
+            assert_eq!(
+                notional_value_from_quantity(
+                    &Quantity::new(2.0, 0),
+                    &Price::new(1.5, 1),
+                    &Quantity::new(10.0, 0),
+                    &usd(),
+                    money.as_mut_ptr(),
+                ),
+                0
+            );
+            let money = money.assume_init();
+            assert_eq!(money, Money::new(30.0, usd()));
+            assert_eq!(
+                notional_value_to_quantity(
+                    &money,
+                    &Price::new(1.5, 1),
+                    &Quantity::new(10.0, 0),
+                    0,
+                    RoundingMode::Nearest,
+                    &mut qty,
+                ),
+                0
+            );
+            assert_eq!(qty, Quantity::new(2.0, 0));
+            assert_eq!(
+                inverse_notional_value_to_quantity(
+                    &money,
+                    &Price::new(0.0, 0),
+                    &Quantity::new(0.0, 0),
+                    0,
+                    RoundingMode::Nearest,
+                    &mut qty,
+                ),
+                FixedPointError::DivisionByZero as u8
+            );
+        }
+    }
+}
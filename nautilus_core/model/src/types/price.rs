@@ -13,12 +13,20 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use crate::types::fixed::{f64_to_fixed_i64, fixed_i64_to_f64};
-use nautilus_core::string::precision_from_str;
+use crate::types::audit::{is_conversion_audit_enabled, record_lossy_conversion};
+use crate::types::fixed::{
+    f64_to_fixed_i64, f64_to_fixed_i64_checked, fixed_i64_mul, fixed_i64_mul_checked,
+    fixed_i64_to_f64, product_precision, FixedPointError,
+};
+use crate::types::format::{display_format, NumberFormat};
+use nautilus_core::string::{precision_from_str, pystr_to_string, string_to_pystr};
+use pyo3::ffi;
 use std::cmp::Ordering;
+use std::collections::hash_map::DefaultHasher;
 use std::fmt::{Debug, Display, Formatter, Result};
 use std::hash::{Hash, Hasher};
 use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::str::FromStr;
 
 #[repr(C)]
 #[derive(Eq, Clone, Default)]
@@ -35,6 +43,27 @@ impl Price {
         }
     }
 
+    /// Returns the value at the precision, or an error if it cannot be
+    /// represented exactly (rather than rounding).
+    pub fn from_f64_checked(
+        value: f64,
+        precision: u8,
+    ) -> std::result::Result<Self, FixedPointError> {
+        Ok(Price {
+            raw: f64_to_fixed_i64_checked(value, precision)?,
+            precision,
+        })
+    }
+
+    /// Returns the value rounded to the precision as `new`, recording a lossy
+    /// conversion for the component when auditing is enabled.
+    pub fn new_audited(value: f64, precision: u8, component: &str) -> Self {
+        if is_conversion_audit_enabled() && Self::from_f64_checked(value, precision).is_err() {
+            record_lossy_conversion(component);
+        }
+        Self::new(value, precision)
+    }
+
     pub fn from_raw(raw: i64, precision: u8) -> Self {
         Price { raw, precision }
     }
@@ -46,6 +75,54 @@ impl Price {
     pub fn as_f64(&self) -> f64 {
         fixed_i64_to_f64(self.raw)
     }
+
+    /// Returns the value formatted by the format.
+    pub fn format(&self, format: &NumberFormat) -> String {
+        format.format_raw(self.raw, self.precision)
+    }
+
+    /// Returns the sum, or an error if the precisions differ or it overflows.
+    pub fn checked_add(&self, other: &Price) -> std::result::Result<Self, FixedPointError> {
+        self.check_precision(other)?;
+        let raw = self
+            .raw
+            .checked_add(other.raw)
+            .ok_or(FixedPointError::Overflow)?;
+        Ok(Price::from_raw(raw, self.precision))
+    }
+
+    /// Returns the difference, or an error if the precisions differ or it
+    /// overflows.
+    pub fn checked_sub(&self, other: &Price) -> std::result::Result<Self, FixedPointError> {
+        self.check_precision(other)?;
+        let raw = self
+            .raw
+            .checked_sub(other.raw)
+            .ok_or(FixedPointError::Overflow)?;
+        Ok(Price::from_raw(raw, self.precision))
+    }
+
+    /// Returns the product at the sum of the precisions, or an error if it is
+    /// not exactly representable at `FIXED_PRECISION` or overflows.
+    pub fn checked_mul(&self, other: &Price) -> std::result::Result<Self, FixedPointError> {
+        Ok(Price::from_raw(
+            fixed_i64_mul_checked(self.raw, other.raw)?,
+            product_precision(self.precision, other.precision),
+        ))
+    }
+
+    /// Returns the negation, or an error if it overflows.
+    pub fn checked_neg(&self) -> std::result::Result<Self, FixedPointError> {
+        let raw = self.raw.checked_neg().ok_or(FixedPointError::Overflow)?;
+        Ok(Price::from_raw(raw, self.precision))
+    }
+
+    fn check_precision(&self, other: &Price) -> std::result::Result<(), FixedPointError> {
+        if self.precision != other.precision {
+            return Err(FixedPointError::PrecisionMismatch);
+        }
+        Ok(())
+    }
 }
 
 impl From<&str> for Price {
@@ -55,7 +132,26 @@ impl From<&str> for Price {
             Ok(number) => number,
             Err(err) => panic!("Cannot parse `input` string '{}' as f64, {}", input, err),
         };
-        Price::new(float_res, precision_from_str(input))
+        let precision = match precision_from_str(input) {
+            Some(precision) => precision,
+            None => panic!("Cannot parse `input` string '{}' exponent", input),
+        };
+        Price::new(float_res, precision)
+    }
+}
+
+impl FromStr for Price {
+    type Err = FixedPointError;
+
+    /// Parses the decimal string, taking the precision from its digits after
+    /// the decimal point less any exponent (e.g. "1.2500" and "125e-2" have
+    /// precisions 4 and 2).
+    fn from_str(input: &str) -> std::result::Result<Self, Self::Err> {
+        let value = input
+            .parse::<f64>()
+            .map_err(|_| FixedPointError::InvalidFormat)?;
+        let precision = precision_from_str(input).ok_or(FixedPointError::InvalidFormat)?;
+        Price::from_f64_checked(value, precision)
     }
 }
 
@@ -73,7 +169,7 @@ impl PartialEq for Price {
 
 impl PartialOrd for Price {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        self.raw.partial_cmp(&other.raw)
+        Some(self.cmp(other))
     }
 
     fn lt(&self, other: &Self) -> bool {
@@ -109,12 +205,17 @@ impl Neg for Price {
     }
 }
 
+// The operators coerce mixed precisions to the larger precision (sums and
+// differences are exact as the raw values share `FIXED_PRECISION`), and
+// products to the sum of the precisions, rounding only beyond
+// `FIXED_PRECISION`. Use the `checked` methods to error instead.
+
 impl Add for Price {
     type Output = Self;
     fn add(self, rhs: Price) -> Self::Output {
         Price {
             raw: self.raw + rhs.raw,
-            precision: self.precision,
+            precision: self.precision.max(rhs.precision),
         }
     }
 }
@@ -124,7 +225,7 @@ impl Sub for Price {
     fn sub(self, rhs: Price) -> Self::Output {
         Price {
             raw: self.raw - rhs.raw,
-            precision: self.precision,
+            precision: self.precision.max(rhs.precision),
         }
     }
 }
@@ -133,8 +234,8 @@ impl Mul for Price {
     type Output = Self;
     fn mul(self, rhs: Price) -> Self {
         Price {
-            raw: self.raw * rhs.raw,
-            precision: self.precision,
+            raw: fixed_i64_mul(self.raw, rhs.raw),
+            precision: product_precision(self.precision, rhs.precision),
         }
     }
 }
@@ -142,18 +243,21 @@ impl Mul for Price {
 impl AddAssign for Price {
     fn add_assign(&mut self, other: Self) {
         self.raw += other.raw;
+        self.precision = self.precision.max(other.precision);
     }
 }
 
 impl SubAssign for Price {
     fn sub_assign(&mut self, other: Self) {
         self.raw -= other.raw;
+        self.precision = self.precision.max(other.precision);
     }
 }
 
 impl MulAssign for Price {
     fn mul_assign(&mut self, multiplier: Self) {
-        self.raw *= multiplier.raw;
+        self.raw = fixed_i64_mul(self.raw, multiplier.raw);
+        self.precision = product_precision(self.precision, multiplier.precision);
     }
 }
 
@@ -185,7 +289,12 @@ impl Debug for Price {
 }
 
 impl Display for Price {
+    /// Formats with comma thousands separators for the alternate flag (`{:#}`)
+    /// and at the decimals of the precision (`{:.4}`) when given.
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        if f.alternate() || f.precision().is_some() {
+            return f.write_str(&self.format(&display_format(f)));
+        }
         write!(f, "{:.*}", self.precision as usize, self.as_f64())
     }
 }
@@ -193,9 +302,46 @@ impl Display for Price {
 ////////////////////////////////////////////////////////////////////////////////
 // C API
 ////////////////////////////////////////////////////////////////////////////////
+/// Returns the value rounded to the precision, recording a lossy conversion
+/// for the `"Price"` component when auditing is enabled.
 #[no_mangle]
 pub extern "C" fn price_new(value: f64, precision: u8) -> Price {
-    Price::new(value, precision)
+    Price::new_audited(value, precision, "Price")
+}
+
+/// Returns the value rounded to the precision, recording a lossy conversion
+/// for the component when auditing is enabled.
+///
+/// # Safety
+///
+/// - `component` must be borrowed from a valid Python UTF-8 `str`.
+#[no_mangle]
+pub unsafe extern "C" fn price_new_audited(
+    value: f64,
+    precision: u8,
+    component: *mut ffi::PyObject,
+) -> Price {
+    if !is_conversion_audit_enabled() {
+        return Price::new(value, precision);
+    }
+    Price::new_audited(value, precision, &pystr_to_string(component))
+}
+
+/// Returns zero and writes the value to `out` if exactly representable at the
+/// precision, otherwise returns the non-zero `FixedPointError` code.
+///
+/// # Safety
+///
+/// - `out` must be a valid pointer to write the value to.
+#[no_mangle]
+pub unsafe extern "C" fn price_from_f64_checked(value: f64, precision: u8, out: *mut Price) -> u8 {
+    match Price::from_f64_checked(value, precision) {
+        Ok(price) => {
+            out.write(price);
+            0
+        }
+        Err(e) => e as u8,
+    }
 }
 
 #[no_mangle]
@@ -204,6 +350,7 @@ pub extern "C" fn price_from_raw(raw: i64, precision: u8) -> Price {
 }
 
 #[no_mangle]
+#[allow(clippy::drop_non_drop)]
 pub extern "C" fn price_free(price: Price) {
     drop(price); // Memory freed here
 }
@@ -213,14 +360,192 @@ pub extern "C" fn price_as_f64(price: &Price) -> f64 {
     price.as_f64()
 }
 
+/// Returns zero and writes the parsed value to `out` if the string is a
+/// decimal number exactly representable at its precision, otherwise returns
+/// the non-zero `FixedPointError` code.
+///
+/// # Safety
+///
+/// - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+/// - `out` must be a valid pointer to write the value to.
+#[no_mangle]
+pub unsafe extern "C" fn price_from_pystr(ptr: *mut ffi::PyObject, out: *mut Price) -> u8 {
+    match pystr_to_string(ptr).parse::<Price>() {
+        Ok(price) => {
+            out.write(price);
+            0
+        }
+        Err(e) => e as u8,
+    }
+}
+
+/// Returns a pointer to a valid Python UTF-8 string of the value formatted at
+/// its precision.
+///
+/// # Safety
+///
+/// - Assumes that since the data is originating from Rust, the GIL does not need
+///   to be acquired.
+/// - Assumes you are immediately returning this pointer to Python.
+#[no_mangle]
+pub unsafe extern "C" fn price_to_pystr(price: &Price) -> *mut ffi::PyObject {
+    string_to_pystr(&price.to_string())
+}
+
+/// Returns a pointer to a valid Python UTF-8 string of the value formatted by
+/// the format.
+///
+/// # Safety
+///
+/// - Assumes that since the data is originating from Rust, the GIL does not need
+///   to be acquired.
+/// - Assumes you are immediately returning this pointer to Python.
 #[no_mangle]
-pub extern "C" fn price_add_assign(mut a: Price, b: Price) {
-    a.add_assign(b);
+pub unsafe extern "C" fn price_to_formatted_pystr(
+    price: &Price,
+    format: &NumberFormat,
+) -> *mut ffi::PyObject {
+    string_to_pystr(&price.format(format))
 }
 
 #[no_mangle]
-pub extern "C" fn price_sub_assign(mut a: Price, b: Price) {
-    a.sub_assign(b);
+pub extern "C" fn price_hash(price: &Price) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    price.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[no_mangle]
+pub extern "C" fn price_eq(a: &Price, b: &Price) -> u8 {
+    u8::from(a == b)
+}
+
+/// Returns -1, 0 or 1 as `a` is less than, equal to or greater than `b`.
+#[no_mangle]
+pub extern "C" fn price_cmp(a: &Price, b: &Price) -> i8 {
+    a.cmp(b) as i8
+}
+
+#[no_mangle]
+pub extern "C" fn price_ne(a: &Price, b: &Price) -> u8 {
+    u8::from(a != b)
+}
+
+#[no_mangle]
+pub extern "C" fn price_lt(a: &Price, b: &Price) -> u8 {
+    u8::from(a < b)
+}
+
+#[no_mangle]
+pub extern "C" fn price_le(a: &Price, b: &Price) -> u8 {
+    u8::from(a <= b)
+}
+
+#[no_mangle]
+pub extern "C" fn price_gt(a: &Price, b: &Price) -> u8 {
+    u8::from(a > b)
+}
+
+#[no_mangle]
+pub extern "C" fn price_ge(a: &Price, b: &Price) -> u8 {
+    u8::from(a >= b)
+}
+
+/// Returns the lesser of the values (`a` if equal).
+#[no_mangle]
+pub extern "C" fn price_min(a: &Price, b: &Price) -> Price {
+    std::cmp::min(a, b).clone()
+}
+
+/// Returns the greater of the values (`b` if equal).
+#[no_mangle]
+pub extern "C" fn price_max(a: &Price, b: &Price) -> Price {
+    std::cmp::max(a, b).clone()
+}
+
+#[no_mangle]
+pub extern "C" fn price_add(a: &Price, b: &Price) -> Price {
+    a.clone() + b.clone()
+}
+
+#[no_mangle]
+pub extern "C" fn price_sub(a: &Price, b: &Price) -> Price {
+    a.clone() - b.clone()
+}
+
+#[no_mangle]
+pub extern "C" fn price_mul(a: &Price, b: &Price) -> Price {
+    a.clone() * b.clone()
+}
+
+#[no_mangle]
+pub extern "C" fn price_neg(price: &Price) -> Price {
+    -price.clone()
+}
+
+/// Returns zero and writes `a + b` to `out`, otherwise returns the non-zero
+/// `FixedPointError` code if the precisions differ or the sum overflows.
+///
+/// # Safety
+///
+/// - `out` must be a valid pointer to write the value to.
+#[no_mangle]
+pub unsafe extern "C" fn price_checked_add(a: &Price, b: &Price, out: *mut Price) -> u8 {
+    write_result(a.checked_add(b), out)
+}
+
+/// Returns zero and writes `a - b` to `out`, otherwise returns the non-zero
+/// `FixedPointError` code if the precisions differ or the difference
+/// overflows.
+///
+/// # Safety
+///
+/// - `out` must be a valid pointer to write the value to.
+#[no_mangle]
+pub unsafe extern "C" fn price_checked_sub(a: &Price, b: &Price, out: *mut Price) -> u8 {
+    write_result(a.checked_sub(b), out)
+}
+
+/// Returns zero and writes `a * b` to `out`, otherwise returns the non-zero
+/// `FixedPointError` code if the product loses precision or overflows.
+///
+/// # Safety
+///
+/// - `out` must be a valid pointer to write the value to.
+#[no_mangle]
+pub unsafe extern "C" fn price_checked_mul(a: &Price, b: &Price, out: *mut Price) -> u8 {
+    write_result(a.checked_mul(b), out)
+}
+
+/// Returns zero and writes `-price` to `out`, otherwise returns the non-zero
+/// `FixedPointError` code if the negation overflows.
+///
+/// # Safety
+///
+/// - `out` must be a valid pointer to write the value to.
+#[no_mangle]
+pub unsafe extern "C" fn price_checked_neg(price: &Price, out: *mut Price) -> u8 {
+    write_result(price.checked_neg(), out)
+}
+
+unsafe fn write_result(result: std::result::Result<Price, FixedPointError>, out: *mut Price) -> u8 {
+    match result {
+        Ok(price) => {
+            out.write(price);
+            0
+        }
+        Err(e) => e as u8,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn price_add_assign(a: &mut Price, b: &Price) {
+    a.add_assign(b.clone());
+}
+
+#[no_mangle]
+pub extern "C" fn price_sub_assign(a: &mut Price, b: &Price) {
+    a.sub_assign(b.clone());
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -228,7 +553,9 @@ pub extern "C" fn price_sub_assign(mut a: Price, b: Price) {
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
-    use super::Price;
+    use super::*;
+    use crate::types::audit::{lossy_conversion_count, set_conversion_audit};
+    use crate::types::fixed::FixedPointError;
 
     #[test]
     fn test_price_new() {
@@ -285,11 +612,11 @@ mod tests {
         assert_eq!(Price::new(1.0, 1), Price::new(1.0, 1));
         assert_eq!(Price::new(1.0, 1), Price::new(1.0, 2));
         assert_ne!(Price::new(1.1, 1), Price::new(1.0, 1));
-        assert!(!(Price::new(1.0, 1) > Price::new(1.0, 2)));
+        assert!(Price::new(1.0, 1) <= Price::new(1.0, 2));
         assert!(Price::new(1.1, 1) > Price::new(1.0, 1));
         assert!(Price::new(1.0, 1) >= Price::new(1.0, 1));
         assert!(Price::new(1.0, 1) >= Price::new(1.0, 2));
-        assert!(!(Price::new(1.0, 1) < Price::new(1.0, 2)));
+        assert!(Price::new(1.0, 1) >= Price::new(1.0, 2));
         assert!(Price::new(0.9, 1) < Price::new(1.0, 1));
         assert!(Price::new(0.9, 1) <= Price::new(1.0, 2));
         assert!(Price::new(0.9, 1) <= Price::new(1.0, 1));
@@ -304,6 +631,50 @@ mod tests {
         assert_eq!(price3.raw, 2011000000)
     }
 
+    #[test]
+    fn test_arithmetic_with_mixed_precisions_coerces_to_max() {
+        let sum = Price::new(1.5, 1) + Price::new(0.25, 2);
+        let difference = Price::new(1.5, 1) - Price::new(0.25, 2);
+        let mut assigned = Price::new(1.5, 1);
+        assigned += Price::new(0.001, 3);
+
+        assert_eq!((sum.raw, sum.precision), (1_750_000_000, 2));
+        assert_eq!((difference.raw, difference.precision), (1_250_000_000, 2));
+        assert_eq!((assigned.raw, assigned.precision), (1_501_000_000, 3));
+    }
+
+    #[test]
+    fn test_mul() {
+        let product = Price::new(1.5, 1) * Price::new(2.25, 2);
+        let rounded = Price::new(0.00001, 5) * Price::new(0.00005, 5);
+
+        assert_eq!((product.raw, product.precision), (3_375_000_000, 3));
+        assert_eq!((rounded.raw, rounded.precision), (1, 9)); // 5e-10 rounds up
+    }
+
+    #[test]
+    fn test_checked_arithmetic() {
+        let a = Price::new(1.5, 1);
+        let b = Price::new(0.2, 1);
+
+        assert_eq!(a.checked_add(&b), Ok(Price::new(1.7, 1)));
+        assert_eq!(b.checked_sub(&a), Ok(Price::new(-1.3, 1)));
+        assert_eq!(a.checked_mul(&b), Ok(Price::new(0.3, 2)));
+        assert_eq!(a.checked_neg(), Ok(Price::new(-1.5, 1)));
+        assert_eq!(
+            a.checked_add(&Price::new(0.25, 2)),
+            Err(FixedPointError::PrecisionMismatch)
+        );
+        assert_eq!(
+            Price::new(0.00001, 5).checked_mul(&Price::new(0.00005, 5)),
+            Err(FixedPointError::PrecisionLoss)
+        );
+        assert_eq!(
+            Price::from_raw(i64::MIN, 9).checked_neg(),
+            Err(FixedPointError::Overflow)
+        );
+    }
+
     #[test]
     fn test_add_assign() {
         let mut price = Price::new(1.000, 3);
@@ -341,4 +712,193 @@ mod tests {
         assert_eq!(price.as_f64(), 44.123456000000004);
         assert_eq!(price.to_string(), "44.123456");
     }
+
+    #[test]
+    fn test_price_display_with_options() {
+        let price = Price::from("12345.675");
+
+        assert_eq!(format!("{:#}", price), "12,345.675");
+        assert_eq!(format!("{:.2}", price), "12345.68");
+        assert_eq!(format!("{:#.1}", price), "12,345.7");
+        assert_eq!(
+            price.format(&NumberFormat::for_locale("de_DE").unwrap()),
+            "12.345,675"
+        );
+    }
+
+    #[test]
+    fn test_price_from_f64_checked() {
+        assert_eq!(Price::from_f64_checked(1.15, 2), Ok(Price::new(1.15, 2)));
+        assert_eq!(
+            Price::from_f64_checked(1.155, 2),
+            Err(FixedPointError::PrecisionLoss)
+        );
+    }
+
+    #[test]
+    fn test_price_from_f64_checked_c_api() {
+        let mut price = Price::default();
+
+        let ok = unsafe { price_from_f64_checked(-0.5, 1, &mut price) };
+        let err = unsafe { price_from_f64_checked(f64::NAN, 1, &mut price) };
+
+        assert_eq!(ok, 0);
+        assert_eq!(err, FixedPointError::NotFinite as u8);
+        assert_eq!(price, Price::new(-0.5, 1));
+    }
+
+    #[test]
+    fn test_price_new_audited_counts_lossy_conversions() {
+        set_conversion_audit(true);
+
+        let price = Price::new_audited(1.155, 2, "PriceAuditTest");
+        Price::new_audited(1.15, 2, "PriceAuditTest");
+
+        assert_eq!(price, Price::new(1.155, 2));
+        assert_eq!(lossy_conversion_count("PriceAuditTest"), 1);
+    }
+
+    #[test]
+    fn test_price_new_audited_from_pystr_component() {
+        pyo3::prepare_freethreaded_python();
+        let gil = pyo3::Python::acquire_gil();
+        let _py = gil.python();
+        set_conversion_audit(true);
+
+        let price = unsafe {
+            let component = string_to_pystr("PriceFfiAuditTest");
+            price_new_audited(1.005, 3, component);
+            price_new_audited(1.0051, 3, component)
+        };
+
+        assert_eq!(price, Price::new(1.005, 3));
+        assert_eq!(lossy_conversion_count("PriceFfiAuditTest"), 1);
+    }
+
+    #[test]
+    fn test_price_from_str() {
+        let price = "1.2500".parse::<Price>().unwrap();
+
+        assert_eq!((price.raw, price.precision), (1_250_000_000, 4));
+        assert_eq!(price.to_string(), "1.2500");
+        assert_eq!("-0.5".parse::<Price>(), Ok(Price::new(-0.5, 1)));
+        assert_eq!("1.2x".parse::<Price>(), Err(FixedPointError::InvalidFormat));
+        assert_eq!("NaN".parse::<Price>(), Err(FixedPointError::NotFinite));
+        assert_eq!(
+            "1.0000000001".parse::<Price>(),
+            Err(FixedPointError::InvalidPrecision)
+        );
+    }
+
+    #[test]
+    fn test_price_from_str_with_exponent() {
+        assert_eq!("1e-8".parse::<Price>(), Ok(Price::from_raw(10, 8)));
+        assert_eq!("2.5E3".parse::<Price>(), Ok(Price::new(2500.0, 0)));
+        assert_eq!("-1.25e-1".parse::<Price>(), Ok(Price::new(-0.125, 3)));
+        assert_eq!("1.5e+2".parse::<Price>(), Ok(Price::new(150.0, 0)));
+        assert_eq!(Price::from("5E-9"), Price::from_raw(5, 9));
+        assert_eq!(
+            "1e-10".parse::<Price>(),
+            Err(FixedPointError::InvalidPrecision)
+        );
+    }
+
+    #[test]
+    fn test_price_from_str_with_exponent_fuzz() {
+        // Deterministic xorshift, so failures are reproducible
+        let mut state: u64 = 0x9E37_79B9_7F4A_7C15;
+        let mut next = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+
+        for _ in 0..10_000 {
+            let digits = (next() % 1_000_000_000) as i64;
+            let precision = (next() % 10) as u8;
+            let sign = if next() % 2 == 0 { "" } else { "-" };
+            let scale = 10_i64.pow(precision as u32);
+            let decimal = if precision == 0 {
+                format!("{sign}{digits}")
+            } else {
+                format!(
+                    "{sign}{}.{:0width$}",
+                    digits / scale,
+                    digits % scale,
+                    width = precision as usize
+                )
+            };
+            let expected = decimal.parse::<Price>().unwrap();
+
+            // Integer mantissa with the exponent giving the precision
+            let exponent = format!("{sign}{digits}e-{precision}");
+            assert_eq!(
+                exponent.parse::<Price>(),
+                Ok(expected.clone()),
+                "{exponent}"
+            );
+
+            // Shortest round trip scientific notation of the float path
+            let float = format!("{:e}", decimal.parse::<f64>().unwrap());
+            let price = float.parse::<Price>().unwrap();
+            assert_eq!(price.raw, expected.raw, "{float}");
+            assert!(price.precision <= expected.precision, "{float}");
+        }
+    }
+
+    #[test]
+    fn test_price_c_api_arithmetic_and_comparison() {
+        let a = price_new(1.25, 2);
+        let b = price_from_raw(500_000_000, 1);
+        let mut c = a.clone();
+        price_add_assign(&mut c, &b);
+
+        assert_eq!(price_add(&a, &b), Price::new(1.75, 2));
+        assert_eq!(price_sub(&a, &b), Price::new(0.75, 2));
+        assert_eq!(price_neg(&a), Price::new(-1.25, 2));
+        assert_eq!(c, Price::new(1.75, 2));
+        price_sub_assign(&mut c, &a);
+        assert_eq!(c, b);
+        assert_eq!(price_cmp(&a, &b), 1);
+        assert_eq!(price_cmp(&b, &a), -1);
+        assert_eq!(price_eq(&a, &Price::new(1.25, 4)), 1);
+        assert_eq!(price_hash(&a), price_hash(&Price::new(1.25, 4)));
+        assert_eq!(price_as_f64(&a), 1.25);
+    }
+
+    #[test]
+    fn test_price_c_api_comparisons_and_min_max() {
+        let a = Price::new(1.25, 2);
+        let b = Price::new(1.5, 1);
+
+        assert_eq!(price_ne(&a, &b), 1);
+        assert_eq!(price_lt(&a, &b), 1);
+        assert_eq!(price_le(&a, &Price::new(1.25, 4)), 1);
+        assert_eq!(price_gt(&a, &b), 0);
+        assert_eq!(price_ge(&b, &a), 1);
+        assert_eq!(price_min(&a, &b), a);
+        assert_eq!(price_max(&a, &b), b);
+        // Equal raw values keep the precision of the chosen operand
+        assert_eq!(price_min(&a, &Price::new(1.25, 4)).precision, 2);
+        assert_eq!(price_max(&a, &Price::new(1.25, 4)).precision, 4);
+    }
+
+    #[test]
+    fn test_price_pystr_round_trip() {
+        pyo3::prepare_freethreaded_python();
+        let gil = pyo3::Python::acquire_gil();
+        let _py = gil.python();
+        let mut out = Price::default();
+
+        unsafe {
+            assert_eq!(price_from_pystr(string_to_pystr("100.050"), &mut out), 0);
+            assert_eq!(pystr_to_string(price_to_pystr(&out)), "100.050");
+            assert_eq!(
+                price_from_pystr(string_to_pystr("abc"), &mut out),
+                FixedPointError::InvalidFormat as u8
+            );
+        }
+        assert_eq!(out, Price::new(100.05, 3));
+    }
 }
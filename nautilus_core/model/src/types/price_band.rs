@@ -0,0 +1,297 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Price bands (limit-up/limit-down) around a reference price.
+//!
+//! A band is defined by percentages of, or absolute offsets from, the
+//! reference price, so simulated exchanges and risk checks can clamp or
+//! reject prices outside it with the same limits.
+
+use std::cmp::Ordering;
+
+use crate::types::fixed::{f64_to_fixed_i64_checked, FixedPointError, FIXED_PRECISION};
+use crate::types::price::Price;
+use crate::types::rounding::{div_rounded, round_raw_to_increment, RoundingMode};
+
+#[repr(C)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PriceBand {
+    /// The lowest price within the band (inclusive).
+    pub lower: Price,
+    /// The highest price within the band (inclusive).
+    pub upper: Price,
+}
+
+impl PriceBand {
+    /// Returns the band from the percentages (e.g. 7.0 for 7%) below and
+    /// above the reference price, with the limits rounded inwards to the
+    /// precision of the reference price.
+    pub fn from_percent(
+        reference: &Price,
+        lower_pct: f64,
+        upper_pct: f64,
+    ) -> Result<Self, FixedPointError> {
+        let offset = |pct: f64, mode: RoundingMode| -> Result<i64, FixedPointError> {
+            let pct_raw = f64_to_fixed_i64_checked(pct, FIXED_PRECISION)?;
+            if pct_raw < 0 {
+                return Err(FixedPointError::Negative);
+            }
+            let scale = 100 * 10_i128.pow(FIXED_PRECISION as u32);
+            let raw = div_rounded(reference.raw as i128 * pct_raw as i128, scale, mode)?;
+            i64::try_from(raw).map_err(|_| FixedPointError::Overflow)
+        };
+        let increment = increment(reference.precision)?;
+        let lower = reference
+            .raw
+            .checked_sub(offset(lower_pct, RoundingMode::Down)?)
+            .ok_or(FixedPointError::Overflow)?;
+        let upper = reference
+            .raw
+            .checked_add(offset(upper_pct, RoundingMode::Down)?)
+            .ok_or(FixedPointError::Overflow)?;
+        Ok(PriceBand {
+            lower: Price::from_raw(
+                round_raw_to_increment(lower, increment, RoundingMode::Up)?,
+                reference.precision,
+            ),
+            upper: Price::from_raw(
+                round_raw_to_increment(upper, increment, RoundingMode::Down)?,
+                reference.precision,
+            ),
+        })
+    }
+
+    /// Returns the band from the absolute offsets below and above the
+    /// reference price, at the greatest of their precisions.
+    pub fn from_offsets(
+        reference: &Price,
+        lower_offset: &Price,
+        upper_offset: &Price,
+    ) -> Result<Self, FixedPointError> {
+        if lower_offset.raw < 0 || upper_offset.raw < 0 {
+            return Err(FixedPointError::Negative);
+        }
+        let precision = reference
+            .precision
+            .max(lower_offset.precision)
+            .max(upper_offset.precision);
+        let lower = reference
+            .raw
+            .checked_sub(lower_offset.raw)
+            .ok_or(FixedPointError::Overflow)?;
+        let upper = reference
+            .raw
+            .checked_add(upper_offset.raw)
+            .ok_or(FixedPointError::Overflow)?;
+        Ok(PriceBand {
+            lower: Price::from_raw(lower, precision),
+            upper: Price::from_raw(upper, precision),
+        })
+    }
+
+    /// Returns `Less` if the price is below the band, `Greater` if above,
+    /// otherwise `Equal` (within the band).
+    pub fn compare(&self, price: &Price) -> Ordering {
+        if price.raw < self.lower.raw {
+            Ordering::Less
+        } else if price.raw > self.upper.raw {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+
+    pub fn contains(&self, price: &Price) -> bool {
+        self.compare(price) == Ordering::Equal
+    }
+
+    /// Returns the price, or the nearest limit of the band if outside it.
+    pub fn clamp(&self, price: &Price) -> Price {
+        match self.compare(price) {
+            Ordering::Less => self.lower.clone(),
+            Ordering::Greater => self.upper.clone(),
+            Ordering::Equal => price.clone(),
+        }
+    }
+}
+
+fn increment(precision: u8) -> Result<i64, FixedPointError> {
+    if precision > FIXED_PRECISION {
+        return Err(FixedPointError::InvalidPrecision);
+    }
+    Ok(10_i64.pow((FIXED_PRECISION - precision) as u32))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// C API
+////////////////////////////////////////////////////////////////////////////////
+/// Returns zero and writes the band from the percentages below and above the
+/// reference price to `out`, otherwise returns the non-zero `FixedPointError`
+/// code.
+///
+/// # Safety
+///
+/// - `out` must be a valid pointer to write the value to.
+#[no_mangle]
+pub unsafe extern "C" fn price_band_from_percent(
+    reference: &Price,
+    lower_pct: f64,
+    upper_pct: f64,
+    out: *mut PriceBand,
+) -> u8 {
+    match PriceBand::from_percent(reference, lower_pct, upper_pct) {
+        Ok(band) => {
+            out.write(band);
+            0
+        }
+        Err(e) => e as u8,
+    }
+}
+
+/// Returns zero and writes the band from the absolute offsets below and above
+/// the reference price to `out`, otherwise returns the non-zero
+/// `FixedPointError` code.
+///
+/// # Safety
+///
+/// - `out` must be a valid pointer to write the value to.
+#[no_mangle]
+pub unsafe extern "C" fn price_band_from_offsets(
+    reference: &Price,
+    lower_offset: &Price,
+    upper_offset: &Price,
+    out: *mut PriceBand,
+) -> u8 {
+    match PriceBand::from_offsets(reference, lower_offset, upper_offset) {
+        Ok(band) => {
+            out.write(band);
+            0
+        }
+        Err(e) => e as u8,
+    }
+}
+
+/// Returns -1 if the price is below the band, 1 if above, otherwise 0.
+#[no_mangle]
+pub extern "C" fn price_band_cmp(band: &PriceBand, price: &Price) -> i8 {
+    band.compare(price) as i8
+}
+
+#[no_mangle]
+pub extern "C" fn price_band_contains(band: &PriceBand, price: &Price) -> u8 {
+    band.contains(price) as u8
+}
+
+#[no_mangle]
+pub extern "C" fn price_band_clamp(band: &PriceBand, price: &Price) -> Price {
+    band.clamp(price)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_price_band_from_percent() {
+        let band = PriceBand::from_percent(&Price::new(101.37, 2), 7.0, 5.0).unwrap();
+
+        // 101.37 -/+ 7.0959 / 5.0685, rounded inwards to the cent
+        assert_eq!(band.lower, Price::new(94.28, 2));
+        assert_eq!(band.upper, Price::new(106.43, 2));
+        assert_eq!(band.lower.precision, 2);
+    }
+
+    #[test]
+    fn test_price_band_from_offsets() {
+        let band = PriceBand::from_offsets(
+            &Price::new(4000.25, 2),
+            &Price::new(200.0, 0),
+            &Price::new(0.125, 3),
+        )
+        .unwrap();
+
+        assert_eq!(band.lower, Price::new(3800.25, 2));
+        assert_eq!(band.upper, Price::new(4000.375, 3));
+        assert_eq!(band.upper.precision, 3);
+    }
+
+    #[test]
+    fn test_price_band_errors() {
+        let reference = Price::new(100.0, 1);
+
+        assert_eq!(
+            PriceBand::from_percent(&reference, -1.0, 5.0),
+            Err(FixedPointError::Negative)
+        );
+        assert_eq!(
+            PriceBand::from_percent(&reference, f64::NAN, 5.0),
+            Err(FixedPointError::NotFinite)
+        );
+        assert_eq!(
+            PriceBand::from_offsets(&reference, &Price::new(1.0, 0), &Price::new(-1.0, 0)),
+            Err(FixedPointError::Negative)
+        );
+    }
+
+    #[test]
+    fn test_price_band_compare_contains_and_clamp() {
+        let band = PriceBand::from_offsets(
+            &Price::new(100.0, 1),
+            &Price::new(5.0, 1),
+            &Price::new(5.0, 1),
+        )
+        .unwrap();
+
+        assert_eq!(band.compare(&Price::new(94.9, 1)), Ordering::Less);
+        assert_eq!(band.compare(&Price::new(95.0, 1)), Ordering::Equal);
+        assert_eq!(band.compare(&Price::new(105.1, 1)), Ordering::Greater);
+        assert!(band.contains(&Price::new(105.0, 1)));
+        assert!(!band.contains(&Price::new(105.01, 2)));
+        assert_eq!(band.clamp(&Price::new(90.0, 1)), Price::new(95.0, 1));
+        assert_eq!(band.clamp(&Price::new(110.0, 1)), Price::new(105.0, 1));
+        assert_eq!(band.clamp(&Price::new(101.5, 1)), Price::new(101.5, 1));
+    }
+
+    #[test]
+    fn test_price_band_c_api() {
+        let mut band = PriceBand {
+            lower: Price::default(),
+            upper: Price::default(),
+        };
+
+        unsafe {
+            assert_eq!(
+                price_band_from_percent(&Price::new(50.0, 2), 10.0, 10.0, &mut band),
+                0
+            );
+            assert_eq!(
+                price_band_from_percent(&Price::new(50.0, 2), -10.0, 10.0, &mut band),
+                FixedPointError::Negative as u8
+            );
+        }
+        assert_eq!(band.lower, Price::new(45.0, 2));
+        assert_eq!(band.upper, Price::new(55.0, 2));
+        assert_eq!(price_band_cmp(&band, &Price::new(44.99, 2)), -1);
+        assert_eq!(price_band_cmp(&band, &Price::new(55.01, 2)), 1);
+        assert_eq!(price_band_contains(&band, &Price::new(50.0, 0)), 1);
+        assert_eq!(
+            price_band_clamp(&band, &Price::new(60.0, 2)),
+            Price::new(55.0, 2)
+        );
+    }
+}
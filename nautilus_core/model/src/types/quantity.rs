@@ -13,12 +13,19 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use crate::types::fixed::{f64_to_fixed_u64, fixed_u64_to_f64};
-use nautilus_core::string::precision_from_str;
+use crate::types::audit::{is_conversion_audit_enabled, record_lossy_conversion};
+use crate::types::fixed::{
+    f64_to_fixed_u64, f64_to_fixed_u64_checked, fixed_u64_mul, fixed_u64_mul_checked,
+    fixed_u64_to_f64, product_precision, FixedPointError,
+};
+use nautilus_core::string::{precision_from_str, pystr_to_string, string_to_pystr};
+use pyo3::ffi;
 use std::cmp::Ordering;
+use std::collections::hash_map::DefaultHasher;
 use std::fmt::{Debug, Display, Formatter, Result};
 use std::hash::{Hash, Hasher};
 use std::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
+use std::str::FromStr;
 
 #[repr(C)]
 #[derive(Eq, Clone, Default)]
@@ -37,6 +44,27 @@ impl Quantity {
         }
     }
 
+    /// Returns the value at the precision, or an error if it cannot be
+    /// represented exactly (rather than rounding).
+    pub fn from_f64_checked(
+        value: f64,
+        precision: u8,
+    ) -> std::result::Result<Self, FixedPointError> {
+        Ok(Quantity {
+            raw: f64_to_fixed_u64_checked(value, precision)?,
+            precision,
+        })
+    }
+
+    /// Returns the value rounded to the precision as `new`, recording a lossy
+    /// conversion for the component when auditing is enabled.
+    pub fn new_audited(value: f64, precision: u8, component: &str) -> Self {
+        if is_conversion_audit_enabled() && Self::from_f64_checked(value, precision).is_err() {
+            record_lossy_conversion(component);
+        }
+        Self::new(value, precision)
+    }
+
     pub fn from_raw(raw: u64, precision: u8) -> Self {
         Quantity { raw, precision }
     }
@@ -44,9 +72,67 @@ impl Quantity {
     pub fn is_zero(&self) -> bool {
         self.raw == 0
     }
+
+    pub fn is_positive(&self) -> bool {
+        self.raw > 0
+    }
+
     pub fn as_f64(&self) -> f64 {
         fixed_u64_to_f64(self.raw)
     }
+
+    /// Returns the sum, or an error if the precisions differ or it overflows.
+    pub fn checked_add(&self, other: &Quantity) -> std::result::Result<Self, FixedPointError> {
+        self.check_precision(other)?;
+        let raw = self
+            .raw
+            .checked_add(other.raw)
+            .ok_or(FixedPointError::Overflow)?;
+        Ok(Quantity::from_raw(raw, self.precision))
+    }
+
+    /// Returns the difference, or an error if the precisions differ or it
+    /// would be negative.
+    pub fn checked_sub(&self, other: &Quantity) -> std::result::Result<Self, FixedPointError> {
+        self.check_precision(other)?;
+        let raw = self
+            .raw
+            .checked_sub(other.raw)
+            .ok_or(FixedPointError::Negative)?;
+        Ok(Quantity::from_raw(raw, self.precision))
+    }
+
+    /// Returns the product at the sum of the precisions, or an error if it is
+    /// not exactly representable at `FIXED_PRECISION` or overflows.
+    pub fn checked_mul(&self, other: &Quantity) -> std::result::Result<Self, FixedPointError> {
+        Ok(Quantity::from_raw(
+            fixed_u64_mul_checked(self.raw, other.raw)?,
+            product_precision(self.precision, other.precision),
+        ))
+    }
+
+    /// Returns whether the quantity is a whole multiple of the size increment
+    /// `step` and within the optional `min` and `max` bounds (inclusive).
+    ///
+    /// A zero `step` is never a valid increment, so returns false.
+    pub fn is_valid_for(
+        &self,
+        step: &Quantity,
+        min: Option<&Quantity>,
+        max: Option<&Quantity>,
+    ) -> bool {
+        step.raw > 0
+            && self.raw.is_multiple_of(step.raw)
+            && min.is_none_or(|min| self >= min)
+            && max.is_none_or(|max| self <= max)
+    }
+
+    fn check_precision(&self, other: &Quantity) -> std::result::Result<(), FixedPointError> {
+        if self.precision != other.precision {
+            return Err(FixedPointError::PrecisionMismatch);
+        }
+        Ok(())
+    }
 }
 
 impl From<&str> for Quantity {
@@ -56,7 +142,26 @@ impl From<&str> for Quantity {
             Ok(number) => number,
             Err(err) => panic!("Cannot parse `input` string '{}' as f64, {}", input, err),
         };
-        Quantity::new(float_res, precision_from_str(input))
+        let precision = match precision_from_str(input) {
+            Some(precision) => precision,
+            None => panic!("Cannot parse `input` string '{}' exponent", input),
+        };
+        Quantity::new(float_res, precision)
+    }
+}
+
+impl FromStr for Quantity {
+    type Err = FixedPointError;
+
+    /// Parses the non-negative decimal string, taking the precision from its
+    /// digits after the decimal point less any exponent (e.g. "0.010" and
+    /// "1E-8" have precisions 3 and 8).
+    fn from_str(input: &str) -> std::result::Result<Self, Self::Err> {
+        let value = input
+            .parse::<f64>()
+            .map_err(|_| FixedPointError::InvalidFormat)?;
+        let precision = precision_from_str(input).ok_or(FixedPointError::InvalidFormat)?;
+        Quantity::from_f64_checked(value, precision)
     }
 }
 
@@ -74,7 +179,7 @@ impl PartialEq for Quantity {
 
 impl PartialOrd for Quantity {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        self.raw.partial_cmp(&other.raw)
+        Some(self.cmp(other))
     }
 
     fn lt(&self, other: &Self) -> bool {
@@ -100,12 +205,16 @@ impl Ord for Quantity {
     }
 }
 
+// The operators coerce mixed precisions as for `Price`, to the larger
+// precision for sums and differences and the sum of the precisions for
+// products. Use the `checked` methods to error instead.
+
 impl Add for Quantity {
     type Output = Self;
     fn add(self, rhs: Self) -> Self::Output {
         Quantity {
             raw: self.raw + rhs.raw,
-            precision: self.precision,
+            precision: self.precision.max(rhs.precision),
         }
     }
 }
@@ -115,7 +224,7 @@ impl Sub for Quantity {
     fn sub(self, rhs: Self) -> Self::Output {
         Quantity {
             raw: self.raw - rhs.raw,
-            precision: self.precision,
+            precision: self.precision.max(rhs.precision),
         }
     }
 }
@@ -124,8 +233,8 @@ impl Mul for Quantity {
     type Output = Self;
     fn mul(self, rhs: Self) -> Self::Output {
         Quantity {
-            raw: self.raw * rhs.raw,
-            precision: self.precision,
+            raw: fixed_u64_mul(self.raw, rhs.raw),
+            precision: product_precision(self.precision, rhs.precision),
         }
     }
 }
@@ -133,6 +242,7 @@ impl Mul for Quantity {
 impl AddAssign for Quantity {
     fn add_assign(&mut self, other: Self) {
         self.raw += other.raw;
+        self.precision = self.precision.max(other.precision);
     }
 }
 
@@ -145,6 +255,7 @@ impl AddAssign<u64> for Quantity {
 impl SubAssign for Quantity {
     fn sub_assign(&mut self, other: Self) {
         self.raw -= other.raw;
+        self.precision = self.precision.max(other.precision);
     }
 }
 
@@ -175,9 +286,50 @@ impl Display for Quantity {
 ////////////////////////////////////////////////////////////////////////////////
 // C API
 ////////////////////////////////////////////////////////////////////////////////
+/// Returns the value rounded to the precision, recording a lossy conversion
+/// for the `"Quantity"` component when auditing is enabled.
 #[no_mangle]
 pub extern "C" fn quantity_new(value: f64, precision: u8) -> Quantity {
-    Quantity::new(value, precision)
+    Quantity::new_audited(value, precision, "Quantity")
+}
+
+/// Returns the value rounded to the precision, recording a lossy conversion
+/// for the component when auditing is enabled.
+///
+/// # Safety
+///
+/// - `component` must be borrowed from a valid Python UTF-8 `str`.
+#[no_mangle]
+pub unsafe extern "C" fn quantity_new_audited(
+    value: f64,
+    precision: u8,
+    component: *mut ffi::PyObject,
+) -> Quantity {
+    if !is_conversion_audit_enabled() {
+        return Quantity::new(value, precision);
+    }
+    Quantity::new_audited(value, precision, &pystr_to_string(component))
+}
+
+/// Returns zero and writes the value to `out` if exactly representable at the
+/// precision, otherwise returns the non-zero `FixedPointError` code.
+///
+/// # Safety
+///
+/// - `out` must be a valid pointer to write the value to.
+#[no_mangle]
+pub unsafe extern "C" fn quantity_from_f64_checked(
+    value: f64,
+    precision: u8,
+    out: *mut Quantity,
+) -> u8 {
+    match Quantity::from_f64_checked(value, precision) {
+        Ok(qty) => {
+            out.write(qty);
+            0
+        }
+        Err(e) => e as u8,
+    }
 }
 
 #[no_mangle]
@@ -186,6 +338,7 @@ pub extern "C" fn quantity_from_raw(raw: u64, precision: u8) -> Quantity {
 }
 
 #[no_mangle]
+#[allow(clippy::drop_non_drop)]
 pub extern "C" fn quantity_free(qty: Quantity) {
     drop(qty); // Memory freed here
 }
@@ -195,23 +348,219 @@ pub extern "C" fn quantity_as_f64(qty: &Quantity) -> f64 {
     qty.as_f64()
 }
 
+/// Returns zero and writes the parsed value to `out` if the string is a
+/// non-negative decimal number exactly representable at its precision,
+/// otherwise returns the non-zero `FixedPointError` code.
+///
+/// # Safety
+///
+/// - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+/// - `out` must be a valid pointer to write the value to.
 #[no_mangle]
-pub extern "C" fn quantity_add_assign(mut a: Quantity, b: Quantity) {
-    a.add_assign(b);
+pub unsafe extern "C" fn quantity_from_pystr(ptr: *mut ffi::PyObject, out: *mut Quantity) -> u8 {
+    write_result(pystr_to_string(ptr).parse::<Quantity>(), out)
+}
+
+/// Returns a pointer to a valid Python UTF-8 string of the value formatted at
+/// its precision.
+///
+/// # Safety
+///
+/// - Assumes that since the data is originating from Rust, the GIL does not need
+///   to be acquired.
+/// - Assumes you are immediately returning this pointer to Python.
+#[no_mangle]
+pub unsafe extern "C" fn quantity_to_pystr(qty: &Quantity) -> *mut ffi::PyObject {
+    string_to_pystr(&qty.to_string())
+}
+
+#[no_mangle]
+pub extern "C" fn quantity_hash(qty: &Quantity) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    qty.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[no_mangle]
+pub extern "C" fn quantity_eq(a: &Quantity, b: &Quantity) -> u8 {
+    u8::from(a == b)
+}
+
+/// Returns -1, 0 or 1 as `a` is less than, equal to or greater than `b`.
+#[no_mangle]
+pub extern "C" fn quantity_cmp(a: &Quantity, b: &Quantity) -> i8 {
+    a.cmp(b) as i8
+}
+
+#[no_mangle]
+pub extern "C" fn quantity_ne(a: &Quantity, b: &Quantity) -> u8 {
+    u8::from(a != b)
+}
+
+#[no_mangle]
+pub extern "C" fn quantity_lt(a: &Quantity, b: &Quantity) -> u8 {
+    u8::from(a < b)
+}
+
+#[no_mangle]
+pub extern "C" fn quantity_le(a: &Quantity, b: &Quantity) -> u8 {
+    u8::from(a <= b)
+}
+
+#[no_mangle]
+pub extern "C" fn quantity_gt(a: &Quantity, b: &Quantity) -> u8 {
+    u8::from(a > b)
+}
+
+#[no_mangle]
+pub extern "C" fn quantity_ge(a: &Quantity, b: &Quantity) -> u8 {
+    u8::from(a >= b)
+}
+
+/// Returns the lesser of the values (`a` if equal).
+#[no_mangle]
+pub extern "C" fn quantity_min(a: &Quantity, b: &Quantity) -> Quantity {
+    std::cmp::min(a, b).clone()
+}
+
+/// Returns the greater of the values (`b` if equal).
+#[no_mangle]
+pub extern "C" fn quantity_max(a: &Quantity, b: &Quantity) -> Quantity {
+    std::cmp::max(a, b).clone()
+}
+
+#[no_mangle]
+pub extern "C" fn quantity_is_positive(qty: &Quantity) -> u8 {
+    u8::from(qty.is_positive())
+}
+
+/// Returns zero and writes `a + b` to `out`, otherwise returns the non-zero
+/// `FixedPointError` code if the precisions differ or the sum overflows.
+///
+/// # Safety
+///
+/// - `out` must be a valid pointer to write the value to.
+#[no_mangle]
+pub unsafe extern "C" fn quantity_checked_add(
+    a: &Quantity,
+    b: &Quantity,
+    out: *mut Quantity,
+) -> u8 {
+    write_result(a.checked_add(b), out)
 }
 
+/// Returns zero and writes `a - b` to `out`, otherwise returns the non-zero
+/// `FixedPointError` code if the precisions differ or `b` exceeds `a`.
+///
+/// # Safety
+///
+/// - `out` must be a valid pointer to write the value to.
 #[no_mangle]
-pub extern "C" fn quantity_add_assign_u64(mut a: Quantity, b: u64) {
+pub unsafe extern "C" fn quantity_checked_sub(
+    a: &Quantity,
+    b: &Quantity,
+    out: *mut Quantity,
+) -> u8 {
+    write_result(a.checked_sub(b), out)
+}
+
+/// Returns zero and writes `a * b` to `out`, otherwise returns the non-zero
+/// `FixedPointError` code if the product loses precision or overflows.
+///
+/// # Safety
+///
+/// - `out` must be a valid pointer to write the value to.
+#[no_mangle]
+pub unsafe extern "C" fn quantity_checked_mul(
+    a: &Quantity,
+    b: &Quantity,
+    out: *mut Quantity,
+) -> u8 {
+    write_result(a.checked_mul(b), out)
+}
+
+unsafe fn write_result(
+    result: std::result::Result<Quantity, FixedPointError>,
+    out: *mut Quantity,
+) -> u8 {
+    match result {
+        Ok(qty) => {
+            out.write(qty);
+            0
+        }
+        Err(e) => e as u8,
+    }
+}
+
+/// Returns 1 if `qty` is a whole multiple of `step` within the bounds,
+/// otherwise 0. A null `min` or `max` means no bound.
+///
+/// # Safety
+///
+/// - `min` and `max` must each be null or a valid pointer to a `Quantity`.
+#[no_mangle]
+pub unsafe extern "C" fn quantity_is_valid_for(
+    qty: &Quantity,
+    step: &Quantity,
+    min: *const Quantity,
+    max: *const Quantity,
+) -> u8 {
+    qty.is_valid_for(step, min.as_ref(), max.as_ref()) as u8
+}
+
+/// Returns the number of the `len` quantities which are not valid for `step`
+/// and the bounds (as `quantity_is_valid_for`), writing 1 (valid) or 0
+/// (invalid) for each to `out` if it is not null.
+///
+/// # Safety
+///
+/// - `qtys` must point to `len` valid quantities (or be null if `len` is zero).
+/// - `min` and `max` must each be null or a valid pointer to a `Quantity`.
+/// - `out` must be null or point to a buffer of at least `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn quantities_validate(
+    qtys: *const Quantity,
+    len: usize,
+    step: &Quantity,
+    min: *const Quantity,
+    max: *const Quantity,
+    out: *mut u8,
+) -> usize {
+    if qtys.is_null() || len == 0 {
+        return 0;
+    }
+    let qtys = std::slice::from_raw_parts(qtys, len);
+    let (min, max) = (min.as_ref(), max.as_ref());
+    let mut invalid = 0;
+    for (i, qty) in qtys.iter().enumerate() {
+        let valid = qty.is_valid_for(step, min, max);
+        if !valid {
+            invalid += 1;
+        }
+        if !out.is_null() {
+            out.add(i).write(valid as u8);
+        }
+    }
+    invalid
+}
+
+#[no_mangle]
+pub extern "C" fn quantity_add_assign(a: &mut Quantity, b: &Quantity) {
+    a.add_assign(b.clone());
+}
+
+#[no_mangle]
+pub extern "C" fn quantity_add_assign_u64(a: &mut Quantity, b: u64) {
     a.add_assign(b);
 }
 
 #[no_mangle]
-pub extern "C" fn quantity_sub_assign(mut a: Quantity, b: Quantity) {
-    a.sub_assign(b);
+pub extern "C" fn quantity_sub_assign(a: &mut Quantity, b: &Quantity) {
+    a.sub_assign(b.clone());
 }
 
 #[no_mangle]
-pub extern "C" fn quantity_sub_assign_u64(mut a: Quantity, b: u64) {
+pub extern "C" fn quantity_sub_assign_u64(a: &mut Quantity, b: u64) {
     a.sub_assign(b);
 }
 
@@ -220,7 +569,10 @@ pub extern "C" fn quantity_sub_assign_u64(mut a: Quantity, b: u64) {
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
-    use super::Quantity;
+    use super::*;
+    use crate::types::audit::{lossy_conversion_count, set_conversion_audit};
+    use crate::types::fixed::{FixedPointError, FIXED_PRECISION};
+    use std::ptr;
 
     #[test]
     fn test_qty_new() {
@@ -272,16 +624,33 @@ mod tests {
         assert_eq!(qty.to_string(), "0.00812000");
     }
 
+    #[test]
+    fn test_qty_arithmetic_with_mixed_precisions() {
+        let sum = Quantity::new(1.5, 1) + Quantity::new(0.25, 2);
+        let product = Quantity::new(1.5, 1) * Quantity::new(2.25, 2);
+
+        assert_eq!((sum.raw, sum.precision), (1_750_000_000, 2));
+        assert_eq!((product.raw, product.precision), (3_375_000_000, 3));
+        assert_eq!(
+            Quantity::new(1.5, 1).checked_mul(&Quantity::new(2.25, 2)),
+            Ok(product)
+        );
+        assert_eq!(
+            Quantity::new(0.00001, 5).checked_mul(&Quantity::new(0.00005, 5)),
+            Err(FixedPointError::PrecisionLoss)
+        );
+    }
+
     #[test]
     fn test_qty_equality() {
         assert_eq!(Quantity::new(1.0, 1), Quantity::new(1.0, 1));
         assert_eq!(Quantity::new(1.0, 1), Quantity::new(1.0, 2));
         assert_ne!(Quantity::new(1.1, 1), Quantity::new(1.0, 1));
-        assert!(!(Quantity::new(1.0, 1) > Quantity::new(1.0, 2)));
+        assert!(Quantity::new(1.0, 1) <= Quantity::new(1.0, 2));
         assert!(Quantity::new(1.1, 1) > Quantity::new(1.0, 1));
         assert!(Quantity::new(1.0, 1) >= Quantity::new(1.0, 1));
         assert!(Quantity::new(1.0, 1) >= Quantity::new(1.0, 2));
-        assert!(!(Quantity::new(1.0, 1) < Quantity::new(1.0, 2)));
+        assert!(Quantity::new(1.0, 1) >= Quantity::new(1.0, 2));
         assert!(Quantity::new(0.9, 1) < Quantity::new(1.0, 1));
         assert!(Quantity::new(0.9, 1) <= Quantity::new(1.0, 2));
         assert!(Quantity::new(0.9, 1) <= Quantity::new(1.0, 1));
@@ -298,4 +667,229 @@ mod tests {
         assert_eq!(res, input_string);
         assert_eq!(qty.to_string(), input_string);
     }
+
+    #[test]
+    fn test_quantity_from_f64_checked() {
+        assert_eq!(
+            Quantity::from_f64_checked(100.25, 2),
+            Ok(Quantity::new(100.25, 2))
+        );
+        assert_eq!(
+            Quantity::from_f64_checked(-1.0, 0),
+            Err(FixedPointError::Negative)
+        );
+        assert_eq!(
+            Quantity::from_f64_checked(0.1234, 3),
+            Err(FixedPointError::PrecisionLoss)
+        );
+    }
+
+    #[test]
+    fn test_quantity_from_f64_checked_c_api() {
+        let mut qty = Quantity::default();
+
+        let ok = unsafe { quantity_from_f64_checked(2.5, 1, &mut qty) };
+        let err = unsafe { quantity_from_f64_checked(1.0, 10, &mut qty) };
+
+        assert_eq!(ok, 0);
+        assert_eq!(err, FixedPointError::InvalidPrecision as u8);
+        assert_eq!(qty, Quantity::new(2.5, 1));
+    }
+
+    #[test]
+    fn test_quantity_new_audited_counts_lossy_conversions() {
+        set_conversion_audit(true);
+
+        Quantity::new_audited(0.1234, 3, "QuantityAuditTest");
+        Quantity::new_audited(0.1234, 3, "QuantityAuditTest");
+
+        assert_eq!(lossy_conversion_count("QuantityAuditTest"), 2);
+    }
+
+    #[test]
+    fn test_qty_from_str() {
+        let qty = "0.010".parse::<Quantity>().unwrap();
+
+        assert_eq!((qty.raw, qty.precision), (10_000_000, 3));
+        assert_eq!(qty.to_string(), "0.010");
+        assert_eq!("-1".parse::<Quantity>(), Err(FixedPointError::Negative));
+        assert_eq!(
+            "1/2".parse::<Quantity>(),
+            Err(FixedPointError::InvalidFormat)
+        );
+    }
+
+    #[test]
+    fn test_qty_from_str_with_exponent() {
+        assert_eq!("1e-8".parse::<Quantity>(), Ok(Quantity::from_raw(10, 8)));
+        assert_eq!("2.5E3".parse::<Quantity>(), Ok(Quantity::new(2500.0, 0)));
+        assert_eq!("1.25e-1".parse::<Quantity>(), Ok(Quantity::new(0.125, 3)));
+        assert_eq!(Quantity::from("3E-2"), Quantity::new(0.03, 2));
+        assert_eq!("-1e-8".parse::<Quantity>(), Err(FixedPointError::Negative));
+    }
+
+    #[test]
+    fn test_qty_from_str_with_exponent_fuzz() {
+        // Deterministic xorshift, so failures are reproducible
+        let mut state: u64 = 0x2545_F491_4F6C_DD1D;
+        let mut next = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+
+        for _ in 0..10_000 {
+            let digits = next() % 1_000_000_000;
+            let precision = (next() % 10) as u8;
+            let expected = Quantity::from_raw(
+                digits * 10_u64.pow((FIXED_PRECISION - precision) as u32),
+                precision,
+            );
+
+            let exponent = format!("{digits}E-{precision}");
+            assert_eq!(
+                exponent.parse::<Quantity>(),
+                Ok(expected.clone()),
+                "{exponent}"
+            );
+
+            let float = format!("{:e}", expected.to_string().parse::<f64>().unwrap());
+            let qty = float.parse::<Quantity>().unwrap();
+            assert_eq!(qty.raw, expected.raw, "{float}");
+            assert!(qty.precision <= expected.precision, "{float}");
+        }
+    }
+
+    #[test]
+    fn test_qty_checked_add_and_sub() {
+        let a = Quantity::new(1.5, 1);
+        let b = Quantity::new(0.5, 1);
+
+        assert_eq!(a.checked_add(&b), Ok(Quantity::new(2.0, 1)));
+        assert_eq!(a.checked_sub(&b), Ok(Quantity::new(1.0, 1)));
+        assert_eq!(b.checked_sub(&a), Err(FixedPointError::Negative));
+        assert_eq!(
+            a.checked_add(&Quantity::new(0.5, 2)),
+            Err(FixedPointError::PrecisionMismatch)
+        );
+        assert_eq!(
+            Quantity::from_raw(u64::MAX, 0).checked_add(&Quantity::from_raw(1, 0)),
+            Err(FixedPointError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_qty_is_valid_for() {
+        let step = Quantity::new(0.5, 1);
+        let min = Quantity::new(1.0, 0);
+        let max = Quantity::new(10.0, 0);
+
+        assert!(Quantity::new(1.5, 1).is_valid_for(&step, Some(&min), Some(&max)));
+        assert!(Quantity::new(10.0, 3).is_valid_for(&step, Some(&min), Some(&max)));
+        assert!(!Quantity::new(1.25, 2).is_valid_for(&step, Some(&min), Some(&max)));
+        assert!(!Quantity::new(0.5, 1).is_valid_for(&step, Some(&min), Some(&max)));
+        assert!(!Quantity::new(10.5, 1).is_valid_for(&step, Some(&min), Some(&max)));
+        assert!(Quantity::new(10.5, 1).is_valid_for(&step, None, None));
+        assert!(!Quantity::new(1.0, 0).is_valid_for(&Quantity::new(0.0, 0), None, None));
+    }
+
+    #[test]
+    fn test_quantities_validate_c_api() {
+        let step = Quantity::new(0.5, 1);
+        let max = Quantity::new(2.0, 0);
+        let qtys = [
+            Quantity::new(0.5, 1),
+            Quantity::new(0.7, 1),
+            Quantity::new(2.0, 0),
+            Quantity::new(2.5, 1),
+        ];
+        let mut out = [9u8; 4];
+
+        unsafe {
+            assert_eq!(quantity_is_valid_for(&qtys[0], &step, ptr::null(), &max), 1);
+            assert_eq!(quantity_is_valid_for(&qtys[0], &step, &max, ptr::null()), 0);
+            assert_eq!(
+                quantities_validate(qtys.as_ptr(), 4, &step, ptr::null(), &max, out.as_mut_ptr()),
+                2
+            );
+            assert_eq!(
+                quantities_validate(
+                    qtys.as_ptr(),
+                    4,
+                    &step,
+                    ptr::null(),
+                    ptr::null(),
+                    ptr::null_mut()
+                ),
+                1
+            );
+            assert_eq!(
+                quantities_validate(
+                    ptr::null(),
+                    0,
+                    &step,
+                    ptr::null(),
+                    ptr::null(),
+                    ptr::null_mut()
+                ),
+                0
+            );
+        }
+        assert_eq!(out, [1, 0, 1, 0]);
+    }
+
+    #[test]
+    fn test_qty_c_api() {
+        let a = quantity_new(2.0, 0);
+        let b = quantity_from_raw(1_000_000_000, 0);
+        let mut out = Quantity::default();
+        let mut c = a.clone();
+        quantity_add_assign(&mut c, &b);
+        quantity_sub_assign_u64(&mut c, 500_000_000);
+
+        assert_eq!(unsafe { quantity_checked_sub(&a, &b, &mut out) }, 0);
+        assert_eq!(out, b);
+        assert_eq!(
+            unsafe { quantity_checked_sub(&b, &a, &mut out) },
+            FixedPointError::Negative as u8
+        );
+        assert_eq!(c, Quantity::new(2.5, 1));
+        assert_eq!(quantity_cmp(&a, &b), 1);
+        assert_eq!(quantity_eq(&b, &Quantity::new(1.0, 3)), 1);
+        assert_eq!(quantity_hash(&b), quantity_hash(&Quantity::new(1.0, 3)));
+        assert_eq!(quantity_is_positive(&Quantity::new(0.0, 0)), 0);
+    }
+
+    #[test]
+    fn test_qty_c_api_comparisons_and_min_max() {
+        let a = Quantity::new(0.5, 1);
+        let b = Quantity::new(2.0, 0);
+
+        assert_eq!(quantity_ne(&a, &b), 1);
+        assert_eq!(quantity_lt(&a, &b), 1);
+        assert_eq!(quantity_le(&b, &Quantity::new(2.0, 3)), 1);
+        assert_eq!(quantity_gt(&a, &b), 0);
+        assert_eq!(quantity_ge(&b, &a), 1);
+        assert_eq!(quantity_min(&a, &b), a);
+        assert_eq!(quantity_max(&a, &b), b);
+    }
+
+    #[test]
+    fn test_qty_pystr_round_trip() {
+        pyo3::prepare_freethreaded_python();
+        let gil = pyo3::Python::acquire_gil();
+        let _py = gil.python();
+        let mut out = Quantity::default();
+
+        unsafe {
+            assert_eq!(quantity_from_pystr(string_to_pystr("0.25"), &mut out), 0);
+            assert_eq!(pystr_to_string(quantity_to_pystr(&out)), "0.25");
+            assert_eq!(
+                quantity_from_pystr(string_to_pystr("-0.25"), &mut out),
+                FixedPointError::Negative as u8
+            );
+        }
+        assert_eq!(out, Quantity::new(0.25, 2));
+    }
 }
@@ -0,0 +1,222 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Rounding of fixed-point prices to a tick size.
+//!
+//! The rounding is exact on the raw values, avoiding the `f64` errors of
+//! rounding the price as a float.
+
+use std::cmp::Ordering;
+
+use crate::types::fixed::FixedPointError;
+use crate::types::price::Price;
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+#[allow(non_camel_case_types)]
+pub enum RoundingMode {
+    /// Round towards negative infinity.
+    Down = 1,
+    /// Round towards positive infinity.
+    Up = 2,
+    /// Round to the nearest tick, with ties away from zero.
+    Nearest = 3,
+    /// Round to the nearest tick, with ties to the even tick (banker's rounding).
+    HalfEven = 4,
+}
+
+/// Returns the quotient of the values rounded by the mode.
+pub(crate) fn div_rounded(
+    num: i128,
+    den: i128,
+    mode: RoundingMode,
+) -> Result<i128, FixedPointError> {
+    if den == 0 {
+        return Err(FixedPointError::DivisionByZero);
+    }
+    let (num, den) = if den < 0 { (-num, -den) } else { (num, den) };
+    let quotient = num.div_euclid(den); // Rounded down
+    let remainder = num.rem_euclid(den);
+    // Compare the remainder with half the divisor, without overflow
+    let half = remainder.cmp(&(den - remainder));
+    let round_up = match (mode, half) {
+        (RoundingMode::Down, _) => false,
+        (RoundingMode::Up, _) => remainder > 0,
+        (_, Ordering::Less) => false,
+        (_, Ordering::Greater) => true,
+        (RoundingMode::Nearest, Ordering::Equal) => num >= 0,
+        (RoundingMode::HalfEven, Ordering::Equal) => quotient % 2 != 0,
+    };
+    Ok(quotient + round_up as i128)
+}
+
+/// Returns the raw value rounded to a multiple of the raw increment.
+pub fn round_raw_to_increment(
+    raw: i64,
+    increment: i64,
+    mode: RoundingMode,
+) -> Result<i64, FixedPointError> {
+    if increment <= 0 {
+        return Err(FixedPointError::InvalidIncrement);
+    }
+    let quotient = div_rounded(raw as i128, increment as i128, mode)?;
+    quotient
+        .checked_mul(increment as i128)
+        .and_then(|raw| i64::try_from(raw).ok())
+        .ok_or(FixedPointError::Overflow)
+}
+
+impl Price {
+    /// Returns the price rounded to a multiple of the tick size, at the
+    /// precision of the tick size.
+    pub fn round_to_tick(
+        &self,
+        tick: &Price,
+        mode: RoundingMode,
+    ) -> Result<Price, FixedPointError> {
+        let raw = round_raw_to_increment(self.raw, tick.raw, mode)?;
+        Ok(Price::from_raw(raw, tick.precision))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// C API
+////////////////////////////////////////////////////////////////////////////////
+/// Returns zero and writes the raw value rounded to a multiple of the raw
+/// increment to `out`, otherwise returns the non-zero `FixedPointError` code.
+///
+/// # Safety
+///
+/// - `out` must be a valid pointer to write the value to.
+#[no_mangle]
+pub unsafe extern "C" fn rounding_round_raw(
+    raw: i64,
+    increment: i64,
+    mode: RoundingMode,
+    out: *mut i64,
+) -> u8 {
+    match round_raw_to_increment(raw, increment, mode) {
+        Ok(rounded) => {
+            out.write(rounded);
+            0
+        }
+        Err(e) => e as u8,
+    }
+}
+
+/// Returns zero and writes the price rounded to a multiple of the tick size
+/// to `out`, otherwise returns the non-zero `FixedPointError` code.
+///
+/// # Safety
+///
+/// - `out` must be a valid pointer to write the value to.
+#[no_mangle]
+pub unsafe extern "C" fn price_round_to_tick(
+    price: &Price,
+    tick: &Price,
+    mode: RoundingMode,
+    out: *mut Price,
+) -> u8 {
+    match price.round_to_tick(tick, mode) {
+        Ok(rounded) => {
+            out.write(rounded);
+            0
+        }
+        Err(e) => e as u8,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_raw_to_increment_modes() {
+        let cases = [
+            // raw, down, up, nearest, half even
+            (12, 10, 20, 10, 10),
+            (15, 10, 20, 20, 20),
+            (25, 20, 30, 30, 20),
+            (20, 20, 20, 20, 20),
+            (-15, -20, -10, -20, -20),
+            (-25, -30, -20, -30, -20),
+            (-12, -20, -10, -10, -10),
+        ];
+
+        for (raw, down, up, nearest, half_even) in cases {
+            let round = |mode| round_raw_to_increment(raw, 10, mode).unwrap();
+            assert_eq!(round(RoundingMode::Down), down, "{raw}");
+            assert_eq!(round(RoundingMode::Up), up, "{raw}");
+            assert_eq!(round(RoundingMode::Nearest), nearest, "{raw}");
+            assert_eq!(round(RoundingMode::HalfEven), half_even, "{raw}");
+        }
+    }
+
+    #[test]
+    fn test_round_raw_to_increment_errors() {
+        assert_eq!(
+            round_raw_to_increment(10, 0, RoundingMode::Down),
+            Err(FixedPointError::InvalidIncrement)
+        );
+        assert_eq!(
+            round_raw_to_increment(i64::MAX, 10, RoundingMode::Up),
+            Err(FixedPointError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_price_round_to_tick() {
+        let tick = Price::new(0.05, 2);
+
+        let rounded = Price::new(1.2345, 4)
+            .round_to_tick(&tick, RoundingMode::Nearest)
+            .unwrap();
+
+        assert_eq!(rounded, Price::new(1.25, 2));
+        assert_eq!(rounded.precision, 2);
+        assert_eq!(
+            Price::new(1.2345, 4).round_to_tick(&tick, RoundingMode::Down),
+            Ok(Price::new(1.20, 2))
+        );
+    }
+
+    #[test]
+    fn test_price_round_to_tick_c_api() {
+        let mut out = Price::default();
+        let mut raw = 0;
+
+        unsafe {
+            assert_eq!(
+                price_round_to_tick(
+                    &Price::new(100.125, 3),
+                    &Price::new(0.25, 2),
+                    RoundingMode::HalfEven,
+                    &mut out,
+                ),
+                0
+            );
+            assert_eq!(
+                rounding_round_raw(5, -1, RoundingMode::Up, &mut raw),
+                FixedPointError::InvalidIncrement as u8
+            );
+            assert_eq!(rounding_round_raw(5, 2, RoundingMode::Up, &mut raw), 0);
+        }
+        assert_eq!(out, Price::new(100.00, 2));
+        assert_eq!(raw, 6);
+    }
+}
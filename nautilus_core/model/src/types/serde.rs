@@ -0,0 +1,183 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Lossless (de)serialization for value types.
+//!
+//! `Price` and `Quantity` are written as their fixed-point raw value and
+//! precision, and `Money` as its raw value and currency code, rather than as
+//! lossy floats. A `Currency` is written as its code and deserialized from the
+//! registered currencies.
+
+use std::str::FromStr;
+
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::types::currency::Currency;
+use crate::types::fixed::{FixedPointError, FIXED_PRECISION};
+use crate::types::money::Money;
+use crate::types::price::Price;
+use crate::types::quantity::Quantity;
+
+#[derive(Serialize, Deserialize)]
+struct FixedValue<T> {
+    raw: T,
+    precision: u8,
+}
+
+#[derive(Serialize, Deserialize)]
+struct MoneyValue {
+    raw: i64,
+    currency: Currency,
+}
+
+fn check_precision<E: Error>(precision: u8) -> Result<u8, E> {
+    if precision > FIXED_PRECISION {
+        return Err(E::custom(FixedPointError::InvalidPrecision));
+    }
+    Ok(precision)
+}
+
+impl Serialize for Price {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        FixedValue {
+            raw: self.raw,
+            precision: self.precision,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Price {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = FixedValue::<i64>::deserialize(deserializer)?;
+        Ok(Price::from_raw(
+            value.raw,
+            check_precision(value.precision)?,
+        ))
+    }
+}
+
+impl Serialize for Quantity {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        FixedValue {
+            raw: self.raw,
+            precision: self.precision,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Quantity {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = FixedValue::<u64>::deserialize(deserializer)?;
+        Ok(Quantity::from_raw(
+            value.raw,
+            check_precision(value.precision)?,
+        ))
+    }
+}
+
+impl Serialize for Money {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        MoneyValue {
+            raw: self.raw(),
+            currency: self.currency.clone(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Money {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = MoneyValue::deserialize(deserializer)?;
+        Ok(Money::from_raw(value.raw, value.currency))
+    }
+}
+
+impl Serialize for Currency {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.code)
+    }
+}
+
+impl<'de> Deserialize<'de> for Currency {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let code = String::deserialize(deserializer)?;
+        Currency::from_str(&code).map_err(D::Error::custom)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::fmt::Debug;
+
+    use serde::de::DeserializeOwned;
+
+    use super::*;
+
+    fn round_trip<T>(value: &T, json: &str) -> T
+    where
+        T: Serialize + DeserializeOwned + PartialEq + Debug,
+    {
+        assert_eq!(serde_json::to_string(value).unwrap(), json);
+        let msgpack = rmp_serde::to_vec(value).unwrap();
+        assert_eq!(&rmp_serde::from_slice::<T>(&msgpack).unwrap(), value);
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn test_price_round_trip_keeps_raw_and_precision() {
+        let price = Price::new(-1.0525, 4);
+
+        let result = round_trip(&price, "{\"raw\":-1052500000,\"precision\":4}");
+
+        assert_eq!(result, price);
+        assert_eq!(result.precision, 4);
+    }
+
+    #[test]
+    fn test_quantity_round_trip_keeps_raw_and_precision() {
+        let qty = Quantity::new(100.5, 1);
+
+        let result = round_trip(&qty, "{\"raw\":100500000000,\"precision\":1}");
+
+        assert_eq!(result, qty);
+        assert_eq!(result.precision, 1);
+    }
+
+    #[test]
+    fn test_money_and_currency_round_trip() {
+        let usd = Currency::from_code("USD").unwrap();
+        let money = Money::new(1234.56, usd.clone());
+
+        let result = round_trip(&money, "{\"raw\":1234560000000,\"currency\":\"USD\"}");
+
+        assert_eq!(result, money);
+        assert_eq!(result.currency, usd);
+        assert_eq!(round_trip(&usd, "\"USD\""), usd);
+    }
+
+    #[test]
+    fn test_deserialize_invalid_values_returns_error() {
+        assert!(serde_json::from_str::<Price>("{\"raw\":1,\"precision\":10}").is_err());
+        assert!(serde_json::from_str::<Quantity>("{\"raw\":-1,\"precision\":0}").is_err());
+        assert!(serde_json::from_str::<Currency>("\"ZZZ\"").is_err());
+        assert!(serde_json::from_str::<Money>("{\"raw\":1,\"currency\":\"ZZZ\"}").is_err());
+    }
+}
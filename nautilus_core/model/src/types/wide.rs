@@ -0,0 +1,575 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Wide (128-bit) fixed-point value types for high precision instruments.
+//!
+//! Instruments with very small increments and very large notionals (e.g. BTC
+//! priced in sats) can overflow the 64-bit `Price`, `Quantity` and `Money`
+//! mantissas. The wide types hold `WIDE_FIXED_PRECISION` decimals in 128 bits,
+//! and are selected per instrument, converting to the standard types where in
+//! range.
+
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display, Formatter, Result};
+use std::hash::{Hash, Hasher};
+use std::ops::{Add, Neg, Sub};
+
+use crate::types::currency::Currency;
+use crate::types::fixed::{FixedPointError, FIXED_PRECISION};
+use crate::types::money::Money;
+use crate::types::price::Price;
+use crate::types::quantity::Quantity;
+
+pub const WIDE_FIXED_PRECISION: u8 = 18;
+pub const WIDE_FIXED_SCALAR: i128 = 1_000_000_000_000_000_000; // 10**WIDE_FIXED_PRECISION
+
+// Scale from the standard fixed-point precision to the wide precision
+const WIDE_FROM_FIXED: i128 = 10_i128.pow((WIDE_FIXED_PRECISION - FIXED_PRECISION) as u32);
+
+fn pow10(exp: u8) -> i128 {
+    10_i128.pow(exp as u32)
+}
+
+fn f64_to_wide(value: f64, precision: u8) -> i128 {
+    assert!(precision <= WIDE_FIXED_PRECISION);
+    let rounded = (value * pow10(precision) as f64).round() as i128;
+    rounded * pow10(WIDE_FIXED_PRECISION - precision)
+}
+
+/// Parses a decimal string exactly, returning the wide raw value and the
+/// precision (number of decimals).
+fn parse_wide(input: &str) -> (i128, u8) {
+    let (negative, digits) = match input.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, input),
+    };
+    let (int_part, frac_part) = digits.split_once('.').unwrap_or((digits, ""));
+    let precision = frac_part.len();
+    assert!(
+        precision <= WIDE_FIXED_PRECISION as usize,
+        "Cannot parse `input` string '{}', precision exceeded {}",
+        input,
+        WIDE_FIXED_PRECISION
+    );
+    let mantissa = format!("{}{}", int_part, frac_part)
+        .parse::<i128>()
+        .unwrap_or_else(|e| panic!("Cannot parse `input` string '{}' as i128, {}", input, e));
+    let raw = mantissa * pow10(WIDE_FIXED_PRECISION - precision as u8);
+    (if negative { -raw } else { raw }, precision as u8)
+}
+
+fn fmt_wide(f: &mut Formatter<'_>, raw: i128, precision: u8) -> Result {
+    let sign = if raw < 0 { "-" } else { "" };
+    let abs = raw.unsigned_abs();
+    let scalar = WIDE_FIXED_SCALAR as u128;
+    let int_part = abs / scalar;
+    if precision == 0 {
+        return write!(f, "{}{}", sign, int_part);
+    }
+    let frac_part = (abs % scalar) / pow10(WIDE_FIXED_PRECISION - precision) as u128;
+    write!(
+        f,
+        "{}{}.{:0width$}",
+        sign,
+        int_part,
+        frac_part,
+        width = precision as usize
+    )
+}
+
+/// Represents a price with a 128-bit fixed-point mantissa.
+#[derive(Eq, Clone, Default)]
+pub struct WidePrice {
+    pub raw: i128,
+    pub precision: u8,
+}
+
+impl WidePrice {
+    pub fn new(value: f64, precision: u8) -> Self {
+        WidePrice {
+            raw: f64_to_wide(value, precision),
+            precision,
+        }
+    }
+
+    pub fn from_raw(raw: i128, precision: u8) -> Self {
+        WidePrice { raw, precision }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.raw == 0
+    }
+
+    pub fn as_f64(&self) -> f64 {
+        self.raw as f64 / WIDE_FIXED_SCALAR as f64
+    }
+
+    /// Returns the notional value of the quantity at this price, at this
+    /// price's precision, or `None` on overflow.
+    pub fn notional(&self, quantity: &WideQuantity) -> Option<WidePrice> {
+        let qty_mantissa = quantity.raw / pow10(WIDE_FIXED_PRECISION - quantity.precision) as u128;
+        let raw =
+            self.raw.checked_mul(i128::try_from(qty_mantissa).ok()?)? / pow10(quantity.precision);
+        Some(WidePrice::from_raw(raw, self.precision))
+    }
+}
+
+impl From<&str> for WidePrice {
+    /// Parses the decimal string exactly, without an intermediate `f64`.
+    fn from(input: &str) -> Self {
+        let (raw, precision) = parse_wide(input);
+        WidePrice { raw, precision }
+    }
+}
+
+impl From<Price> for WidePrice {
+    fn from(price: Price) -> Self {
+        WidePrice {
+            raw: price.raw as i128 * WIDE_FROM_FIXED,
+            precision: price.precision,
+        }
+    }
+}
+
+impl TryFrom<WidePrice> for Price {
+    type Error = FixedPointError;
+
+    /// Returns the standard price, if in range and representable at its
+    /// precision.
+    fn try_from(price: WidePrice) -> std::result::Result<Self, Self::Error> {
+        if price.precision > FIXED_PRECISION {
+            return Err(FixedPointError::InvalidPrecision);
+        }
+        if price.raw % WIDE_FROM_FIXED != 0 {
+            return Err(FixedPointError::PrecisionLoss);
+        }
+        let raw =
+            i64::try_from(price.raw / WIDE_FROM_FIXED).map_err(|_| FixedPointError::Overflow)?;
+        Ok(Price::from_raw(raw, price.precision))
+    }
+}
+
+impl Hash for WidePrice {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.raw.hash(state)
+    }
+}
+
+impl PartialEq for WidePrice {
+    fn eq(&self, other: &Self) -> bool {
+        self.raw == other.raw
+    }
+}
+
+impl PartialOrd for WidePrice {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for WidePrice {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.raw.cmp(&other.raw)
+    }
+}
+
+impl Neg for WidePrice {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        WidePrice {
+            raw: -self.raw,
+            precision: self.precision,
+        }
+    }
+}
+
+impl Add for WidePrice {
+    type Output = Self;
+    fn add(self, rhs: WidePrice) -> Self::Output {
+        WidePrice {
+            raw: self.raw + rhs.raw,
+            precision: self.precision,
+        }
+    }
+}
+
+impl Sub for WidePrice {
+    type Output = Self;
+    fn sub(self, rhs: WidePrice) -> Self::Output {
+        WidePrice {
+            raw: self.raw - rhs.raw,
+            precision: self.precision,
+        }
+    }
+}
+
+impl Debug for WidePrice {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        fmt_wide(f, self.raw, self.precision)
+    }
+}
+
+impl Display for WidePrice {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        fmt_wide(f, self.raw, self.precision)
+    }
+}
+
+/// Represents a quantity with a 128-bit fixed-point mantissa.
+#[derive(Eq, Clone, Default)]
+pub struct WideQuantity {
+    pub raw: u128,
+    pub precision: u8,
+}
+
+impl WideQuantity {
+    pub fn new(value: f64, precision: u8) -> Self {
+        assert!(value >= 0.0);
+
+        WideQuantity {
+            raw: f64_to_wide(value, precision) as u128,
+            precision,
+        }
+    }
+
+    pub fn from_raw(raw: u128, precision: u8) -> Self {
+        WideQuantity { raw, precision }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.raw == 0
+    }
+
+    pub fn as_f64(&self) -> f64 {
+        self.raw as f64 / WIDE_FIXED_SCALAR as f64
+    }
+}
+
+impl From<&str> for WideQuantity {
+    /// Parses the decimal string exactly, without an intermediate `f64`.
+    fn from(input: &str) -> Self {
+        let (raw, precision) = parse_wide(input);
+        assert!(raw >= 0, "Cannot parse negative quantity '{}'", input);
+        WideQuantity {
+            raw: raw as u128,
+            precision,
+        }
+    }
+}
+
+impl From<Quantity> for WideQuantity {
+    fn from(qty: Quantity) -> Self {
+        WideQuantity {
+            raw: qty.raw as u128 * WIDE_FROM_FIXED as u128,
+            precision: qty.precision,
+        }
+    }
+}
+
+impl TryFrom<WideQuantity> for Quantity {
+    type Error = FixedPointError;
+
+    /// Returns the standard quantity, if in range and representable at its
+    /// precision.
+    fn try_from(qty: WideQuantity) -> std::result::Result<Self, Self::Error> {
+        if qty.precision > FIXED_PRECISION {
+            return Err(FixedPointError::InvalidPrecision);
+        }
+        if !qty.raw.is_multiple_of(WIDE_FROM_FIXED as u128) {
+            return Err(FixedPointError::PrecisionLoss);
+        }
+        let raw = u64::try_from(qty.raw / WIDE_FROM_FIXED as u128)
+            .map_err(|_| FixedPointError::Overflow)?;
+        Ok(Quantity::from_raw(raw, qty.precision))
+    }
+}
+
+impl Hash for WideQuantity {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.raw.hash(state)
+    }
+}
+
+impl PartialEq for WideQuantity {
+    fn eq(&self, other: &Self) -> bool {
+        self.raw == other.raw
+    }
+}
+
+impl PartialOrd for WideQuantity {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for WideQuantity {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.raw.cmp(&other.raw)
+    }
+}
+
+impl Add for WideQuantity {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        WideQuantity {
+            raw: self.raw + rhs.raw,
+            precision: self.precision,
+        }
+    }
+}
+
+impl Sub for WideQuantity {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        WideQuantity {
+            raw: self.raw - rhs.raw,
+            precision: self.precision,
+        }
+    }
+}
+
+impl Debug for WideQuantity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        fmt_wide(f, self.raw as i128, self.precision)
+    }
+}
+
+impl Display for WideQuantity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        fmt_wide(f, self.raw as i128, self.precision)
+    }
+}
+
+/// Represents an amount of money with a 128-bit fixed-point mantissa.
+#[derive(Eq, Clone, Debug)]
+pub struct WideMoney {
+    pub raw: i128,
+    pub currency: Currency,
+}
+
+impl WideMoney {
+    pub fn new(amount: f64, currency: Currency) -> Self {
+        WideMoney {
+            raw: f64_to_wide(amount, currency.precision),
+            currency,
+        }
+    }
+
+    pub fn from_raw(raw: i128, currency: Currency) -> Self {
+        WideMoney { raw, currency }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.raw == 0
+    }
+
+    pub fn as_f64(&self) -> f64 {
+        self.raw as f64 / WIDE_FIXED_SCALAR as f64
+    }
+
+    /// Returns the sum, or an error if the currencies differ or it overflows.
+    pub fn checked_add(&self, other: &WideMoney) -> std::result::Result<Self, FixedPointError> {
+        self.check_currency(other)?;
+        let raw = self
+            .raw
+            .checked_add(other.raw)
+            .ok_or(FixedPointError::Overflow)?;
+        Ok(WideMoney::from_raw(raw, self.currency.clone()))
+    }
+
+    /// Returns the difference, or an error if the currencies differ or it
+    /// overflows.
+    pub fn checked_sub(&self, other: &WideMoney) -> std::result::Result<Self, FixedPointError> {
+        self.check_currency(other)?;
+        let raw = self
+            .raw
+            .checked_sub(other.raw)
+            .ok_or(FixedPointError::Overflow)?;
+        Ok(WideMoney::from_raw(raw, self.currency.clone()))
+    }
+
+    fn check_currency(&self, other: &WideMoney) -> std::result::Result<(), FixedPointError> {
+        if self.currency != other.currency {
+            return Err(FixedPointError::CurrencyMismatch);
+        }
+        Ok(())
+    }
+}
+
+impl From<Money> for WideMoney {
+    fn from(money: Money) -> Self {
+        WideMoney {
+            raw: money.raw() as i128 * WIDE_FROM_FIXED,
+            currency: money.currency,
+        }
+    }
+}
+
+impl TryFrom<WideMoney> for Money {
+    type Error = FixedPointError;
+
+    /// Returns the standard money, if in range and representable at
+    /// `FIXED_PRECISION`.
+    fn try_from(money: WideMoney) -> std::result::Result<Self, Self::Error> {
+        if money.raw % WIDE_FROM_FIXED != 0 {
+            return Err(FixedPointError::PrecisionLoss);
+        }
+        let raw =
+            i64::try_from(money.raw / WIDE_FROM_FIXED).map_err(|_| FixedPointError::Overflow)?;
+        Ok(Money::from_raw(raw, money.currency))
+    }
+}
+
+impl Hash for WideMoney {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.raw.hash(state);
+        self.currency.hash(state);
+    }
+}
+
+impl PartialEq for WideMoney {
+    fn eq(&self, other: &Self) -> bool {
+        self.raw == other.raw && self.currency == other.currency
+    }
+}
+
+impl Display for WideMoney {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        fmt_wide(f, self.raw, self.currency.precision)?;
+        write!(f, " {}", self.currency.code)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::{WideMoney, WidePrice, WideQuantity};
+    use crate::enums::CurrencyType;
+    use crate::types::currency::Currency;
+    use crate::types::fixed::FixedPointError;
+    use crate::types::money::Money;
+    use crate::types::price::Price;
+    use crate::types::quantity::Quantity;
+
+    #[test]
+    fn test_wide_price_from_str_is_exact() {
+        let price = WidePrice::from("0.000000000000000001");
+
+        assert_eq!(price.raw, 1);
+        assert_eq!(price.precision, 18);
+        assert_eq!(price.to_string(), "0.000000000000000001");
+        assert_eq!(WidePrice::from("-0.50").to_string(), "-0.50");
+    }
+
+    #[test]
+    fn test_wide_price_beyond_i64_range() {
+        // 100 billion at 18 decimals overflows an i64 mantissa
+        let price = WidePrice::from("100000000000.123456789012345678");
+
+        assert_eq!(price.to_string(), "100000000000.123456789012345678");
+        assert_eq!(
+            Price::try_from(price),
+            Err(FixedPointError::InvalidPrecision)
+        );
+    }
+
+    #[test]
+    fn test_wide_price_new_and_arithmetic() {
+        let price1 = WidePrice::new(1.5, 1);
+        let price2 = WidePrice::from("0.25");
+
+        assert_eq!((price1.clone() + price2.clone()).to_string(), "1.7");
+        assert_eq!((price1 - price2).as_f64(), 1.25);
+        assert!(WidePrice::from("2") > WidePrice::from("1.999999999999999999"));
+    }
+
+    #[test]
+    fn test_wide_price_standard_conversions() {
+        let price = Price::new(1.23456, 5);
+
+        let wide = WidePrice::from(price.clone());
+
+        assert_eq!(wide.to_string(), "1.23456");
+        assert_eq!(Price::try_from(wide), Ok(price));
+        assert_eq!(
+            Price::try_from(WidePrice::from_raw(i128::MAX / 2, 2)),
+            Err(FixedPointError::PrecisionLoss)
+        );
+        assert_eq!(
+            Price::try_from(WidePrice::from("10000000000000")),
+            Err(FixedPointError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_wide_notional() {
+        let price = WidePrice::from("65432.10");
+        let qty = WideQuantity::from("1000000.00000001");
+
+        let notional = price.notional(&qty).unwrap();
+
+        assert_eq!(notional.to_string(), "65432100000.00");
+    }
+
+    #[test]
+    fn test_wide_quantity_standard_conversions() {
+        let qty = WideQuantity::from("0.00000001");
+
+        assert_eq!(qty.to_string(), "0.00000001");
+        assert_eq!(Quantity::try_from(qty), Ok(Quantity::from_raw(10, 8)));
+        assert_eq!(
+            WideQuantity::from(Quantity::new(5.0, 0)),
+            WideQuantity::from("5")
+        );
+    }
+
+    fn usdt() -> Currency {
+        Currency::new("USDT", 8, 0, "Tether", CurrencyType::Crypto)
+    }
+
+    #[test]
+    fn test_wide_money_beyond_i64_range() {
+        // 100 billion at 9 decimals overflows an i64 mantissa
+        let balance = WideMoney::from_raw(100_000_000_000 * super::WIDE_FIXED_SCALAR, usdt());
+
+        let total = balance.checked_add(&WideMoney::new(0.5, usdt())).unwrap();
+
+        assert_eq!(total.to_string(), "100000000000.50000000 USDT");
+        assert_eq!(Money::try_from(total), Err(FixedPointError::Overflow));
+        assert_eq!(
+            balance.checked_sub(&WideMoney::new(
+                1.0,
+                Currency::new("USD", 2, 840, "United States dollar", CurrencyType::Fiat)
+            )),
+            Err(FixedPointError::CurrencyMismatch)
+        );
+    }
+
+    #[test]
+    fn test_wide_money_standard_conversions() {
+        let money = Money::new(1234.5, usdt());
+
+        let wide = WideMoney::from(money.clone());
+
+        assert_eq!(wide.to_string(), "1234.50000000 USDT");
+        assert_eq!(Money::try_from(wide), Ok(money));
+        assert_eq!(
+            Money::try_from(WideMoney::from_raw(1, usdt())),
+            Err(FixedPointError::PrecisionLoss)
+        );
+    }
+}
@@ -0,0 +1,561 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Implied volatility surfaces for options.
+//!
+//! Provides Black-Scholes pricing, delta and implied volatility for European
+//! options, and a process wide registry of volatility surfaces per underlying
+//! built from option quotes. Surfaces are interpolated linearly in strike
+//! within an expiry, and linearly in total variance across expiries, with
+//! flat extrapolation outside the quoted range.
+
+use std::collections::{BTreeMap, HashMap};
+use std::f64::consts::SQRT_2;
+use std::sync::{Mutex, OnceLock};
+
+use nautilus_core::string::pystr_to_string;
+use pyo3::ffi;
+
+use crate::enums::OptionKind;
+use crate::types::fixed::fixed_i64_to_f64;
+use crate::types::price::Price;
+
+const NANOS_PER_YEAR: f64 = 365.0 * 86_400.0 * 1_000_000_000.0;
+const MIN_VOLATILITY: f64 = 1e-4;
+const MAX_VOLATILITY: f64 = 10.0;
+const BISECTION_ITERATIONS: usize = 100;
+
+/// Returns the complementary error function, with a fractional error below
+/// 1.2e-7 (Numerical Recipes `erfcc`).
+fn erfc(x: f64) -> f64 {
+    let z = x.abs();
+    let t = 1.0 / (1.0 + 0.5 * z);
+    let poly = -z * z - 1.265_512_23
+        + t * (1.000_023_68
+            + t * (0.374_091_96
+                + t * (0.096_784_18
+                    + t * (-0.186_288_06
+                        + t * (0.278_868_07
+                            + t * (-1.135_203_98
+                                + t * (1.488_515_87 + t * (-0.822_152_23 + t * 0.170_872_77))))))));
+    let r = t * poly.exp();
+    if x >= 0.0 {
+        r
+    } else {
+        2.0 - r
+    }
+}
+
+/// Returns the standard normal cumulative distribution function.
+pub fn norm_cdf(x: f64) -> f64 {
+    0.5 * erfc(-x / SQRT_2)
+}
+
+/// Returns the years from `ts_now` to `expiry_ns` (UNIX nanoseconds).
+pub fn years_to_expiry(expiry_ns: i64, ts_now: i64) -> f64 {
+    (expiry_ns - ts_now) as f64 / NANOS_PER_YEAR
+}
+
+fn d1(spot: f64, strike: f64, t: f64, rate: f64, vol: f64) -> f64 {
+    ((spot / strike).ln() + (rate + 0.5 * vol * vol) * t) / (vol * t.sqrt())
+}
+
+/// Returns the Black-Scholes price of a European option, or the discounted
+/// intrinsic value if the time to expiry or volatility is not positive.
+pub fn black_scholes_price(
+    kind: OptionKind,
+    spot: f64,
+    strike: f64,
+    t: f64,
+    rate: f64,
+    vol: f64,
+) -> f64 {
+    let discounted_strike = strike * (-rate * t.max(0.0)).exp();
+    if t <= 0.0 || vol <= 0.0 {
+        return match kind {
+            OptionKind::Call => (spot - discounted_strike).max(0.0),
+            OptionKind::Put => (discounted_strike - spot).max(0.0),
+        };
+    }
+    let d1 = d1(spot, strike, t, rate, vol);
+    let d2 = d1 - vol * t.sqrt();
+    match kind {
+        OptionKind::Call => spot * norm_cdf(d1) - discounted_strike * norm_cdf(d2),
+        OptionKind::Put => discounted_strike * norm_cdf(-d2) - spot * norm_cdf(-d1),
+    }
+}
+
+/// Returns the Black-Scholes delta of a European option.
+pub fn black_scholes_delta(
+    kind: OptionKind,
+    spot: f64,
+    strike: f64,
+    t: f64,
+    rate: f64,
+    vol: f64,
+) -> f64 {
+    let call_delta = if t <= 0.0 || vol <= 0.0 {
+        if spot > strike {
+            1.0
+        } else {
+            0.0
+        }
+    } else {
+        norm_cdf(d1(spot, strike, t, rate, vol))
+    };
+    match kind {
+        OptionKind::Call => call_delta,
+        OptionKind::Put => call_delta - 1.0,
+    }
+}
+
+/// Returns the Black-Scholes implied volatility of the option price, or `None`
+/// if the price is outside the no-arbitrage bounds (or the inputs are not
+/// positive).
+pub fn implied_volatility(
+    kind: OptionKind,
+    price: f64,
+    spot: f64,
+    strike: f64,
+    t: f64,
+    rate: f64,
+) -> Option<f64> {
+    if !(price > 0.0 && spot > 0.0 && strike > 0.0 && t > 0.0) {
+        return None;
+    }
+    let price_at = |vol| black_scholes_price(kind, spot, strike, t, rate, vol);
+    if price <= price_at(MIN_VOLATILITY) || price >= price_at(MAX_VOLATILITY) {
+        return None;
+    }
+    // The price is increasing in volatility
+    let (mut low, mut high) = (MIN_VOLATILITY, MAX_VOLATILITY);
+    for _ in 0..BISECTION_ITERATIONS {
+        let mid = 0.5 * (low + high);
+        if price_at(mid) < price {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    Some(0.5 * (low + high))
+}
+
+/// Represents an implied volatility surface by expiry and strike.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct VolatilitySurface {
+    /// The volatilities by expiry (UNIX nanoseconds) then raw strike.
+    slices: BTreeMap<i64, BTreeMap<i64, f64>>,
+}
+
+impl VolatilitySurface {
+    pub fn is_empty(&self) -> bool {
+        self.slices.is_empty()
+    }
+
+    /// Sets the volatility at the expiry and strike.
+    pub fn update(&mut self, expiry_ns: i64, strike: &Price, vol: f64) {
+        self.slices
+            .entry(expiry_ns)
+            .or_default()
+            .insert(strike.raw, vol);
+    }
+
+    /// Sets the implied volatility of the option price at the expiry and
+    /// strike, returning it (or `None` if there is no implied volatility, in
+    /// which case the surface is unchanged).
+    #[allow(clippy::too_many_arguments)]
+    pub fn update_quote(
+        &mut self,
+        kind: OptionKind,
+        expiry_ns: i64,
+        strike: &Price,
+        option_price: f64,
+        spot: f64,
+        rate: f64,
+        ts_now: i64,
+    ) -> Option<f64> {
+        let t = years_to_expiry(expiry_ns, ts_now);
+        let vol = implied_volatility(kind, option_price, spot, strike.as_f64(), t, rate)?;
+        self.update(expiry_ns, strike, vol);
+        Some(vol)
+    }
+
+    /// Removes the expiries at or before `ts_now`.
+    pub fn prune(&mut self, ts_now: i64) {
+        self.slices.retain(|expiry_ns, _| *expiry_ns > ts_now);
+    }
+
+    /// Returns the volatility of the slice at the strike, interpolated
+    /// linearly between the nearest strikes.
+    fn slice_volatility(slice: &BTreeMap<i64, f64>, strike: f64) -> Option<f64> {
+        let mut lower = None;
+        for (raw, vol) in slice {
+            let k = fixed_i64_to_f64(*raw);
+            if k >= strike {
+                return Some(match lower {
+                    Some((k0, v0)) if k > k0 => v0 + (vol - v0) * (strike - k0) / (k - k0),
+                    _ => *vol,
+                });
+            }
+            lower = Some((k, *vol));
+        }
+        lower.map(|(_, vol)| vol)
+    }
+
+    /// Returns the volatility at the expiry and strike, or `None` if the
+    /// expiry is not after `ts_now` or there are no unexpired quotes.
+    pub fn volatility(&self, expiry_ns: i64, strike: f64, ts_now: i64) -> Option<f64> {
+        if expiry_ns <= ts_now {
+            return None;
+        }
+        let live = self.slices.range(ts_now + 1..);
+        let lower = live.clone().rev().find(|(e, _)| **e <= expiry_ns);
+        let upper = live.clone().find(|(e, _)| **e >= expiry_ns);
+        match (lower, upper) {
+            (Some((e0, s0)), Some((e1, s1))) if e0 != e1 => {
+                let (t0, t1) = (years_to_expiry(*e0, ts_now), years_to_expiry(*e1, ts_now));
+                let t = years_to_expiry(expiry_ns, ts_now);
+                let w0 = Self::slice_volatility(s0, strike)?.powi(2) * t0;
+                let w1 = Self::slice_volatility(s1, strike)?.powi(2) * t1;
+                let w = w0 + (w1 - w0) * (t - t0) / (t1 - t0);
+                Some((w.max(0.0) / t).sqrt())
+            }
+            (Some((_, slice)), _) | (None, Some((_, slice))) => {
+                Self::slice_volatility(slice, strike)
+            }
+            (None, None) => None,
+        }
+    }
+
+    /// Returns the strike and volatility at the expiry where the option delta
+    /// equals `delta` (in (0, 1) for calls and (-1, 0) for puts), or `None` if
+    /// there is no such strike.
+    pub fn volatility_at_delta(
+        &self,
+        kind: OptionKind,
+        expiry_ns: i64,
+        delta: f64,
+        spot: f64,
+        rate: f64,
+        ts_now: i64,
+    ) -> Option<(f64, f64)> {
+        let t = years_to_expiry(expiry_ns, ts_now);
+        if spot <= 0.0 {
+            return None;
+        }
+        let delta_at = |strike: f64| -> Option<(f64, f64)> {
+            let vol = self.volatility(expiry_ns, strike, ts_now)?;
+            Some((black_scholes_delta(kind, spot, strike, t, rate, vol), vol))
+        };
+        // The delta is decreasing in strike, so bisect over the log strike
+        let (mut low, mut high) = ((spot * 0.01).ln(), (spot * 100.0).ln());
+        if delta_at(low.exp())?.0 < delta || delta_at(high.exp())?.0 > delta {
+            return None;
+        }
+        for _ in 0..BISECTION_ITERATIONS {
+            let mid = 0.5 * (low + high);
+            if delta_at(mid.exp())?.0 > delta {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        let strike = (0.5 * (low + high)).exp();
+        Some((strike, delta_at(strike)?.1))
+    }
+}
+
+static VOLATILITY_SURFACES: OnceLock<Mutex<HashMap<String, VolatilitySurface>>> = OnceLock::new();
+
+fn registry() -> &'static Mutex<HashMap<String, VolatilitySurface>> {
+    VOLATILITY_SURFACES.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Updates the surface for the underlying with the implied volatility of the
+/// option price, pruning expired quotes.
+#[allow(clippy::too_many_arguments)]
+pub fn update_volatility_surface(
+    underlying: &str,
+    kind: OptionKind,
+    expiry_ns: i64,
+    strike: &Price,
+    option_price: f64,
+    spot: f64,
+    rate: f64,
+    ts_now: i64,
+) -> Option<f64> {
+    let mut registry = registry().lock().unwrap();
+    let surface = registry.entry(underlying.to_string()).or_default();
+    surface.prune(ts_now);
+    surface.update_quote(kind, expiry_ns, strike, option_price, spot, rate, ts_now)
+}
+
+/// Returns a copy of the surface for the underlying (if any).
+pub fn volatility_surface(underlying: &str) -> Option<VolatilitySurface> {
+    registry().lock().unwrap().get(underlying).cloned()
+}
+
+pub fn reset_volatility_surfaces() {
+    registry().lock().unwrap().clear();
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// C API
+////////////////////////////////////////////////////////////////////////////////
+#[no_mangle]
+pub extern "C" fn option_black_scholes_price(
+    kind: OptionKind,
+    spot: f64,
+    strike: f64,
+    t: f64,
+    rate: f64,
+    vol: f64,
+) -> f64 {
+    black_scholes_price(kind, spot, strike, t, rate, vol)
+}
+
+#[no_mangle]
+pub extern "C" fn option_black_scholes_delta(
+    kind: OptionKind,
+    spot: f64,
+    strike: f64,
+    t: f64,
+    rate: f64,
+    vol: f64,
+) -> f64 {
+    black_scholes_delta(kind, spot, strike, t, rate, vol)
+}
+
+/// Returns the implied volatility of the option price (NaN if none).
+#[no_mangle]
+pub extern "C" fn option_implied_volatility(
+    kind: OptionKind,
+    price: f64,
+    spot: f64,
+    strike: f64,
+    t: f64,
+    rate: f64,
+) -> f64 {
+    implied_volatility(kind, price, spot, strike, t, rate).unwrap_or(f64::NAN)
+}
+
+/// Returns the implied volatility added to the surface for the underlying (NaN
+/// if none, in which case the surface is unchanged).
+///
+/// # Safety
+///
+/// - `underlying_ptr` must be borrowed from a valid Python UTF-8 `str`.
+#[no_mangle]
+#[allow(clippy::too_many_arguments)]
+pub unsafe extern "C" fn volatility_surface_update(
+    underlying_ptr: *mut ffi::PyObject,
+    kind: OptionKind,
+    expiry_ns: i64,
+    strike: &Price,
+    option_price: f64,
+    spot: f64,
+    rate: f64,
+    ts_now: i64,
+) -> f64 {
+    let underlying = pystr_to_string(underlying_ptr);
+    update_volatility_surface(
+        &underlying,
+        kind,
+        expiry_ns,
+        strike,
+        option_price,
+        spot,
+        rate,
+        ts_now,
+    )
+    .unwrap_or(f64::NAN)
+}
+
+/// Returns the volatility of the surface for the underlying at the expiry and
+/// strike (NaN if none).
+///
+/// # Safety
+///
+/// - `underlying_ptr` must be borrowed from a valid Python UTF-8 `str`.
+#[no_mangle]
+pub unsafe extern "C" fn volatility_surface_get(
+    underlying_ptr: *mut ffi::PyObject,
+    expiry_ns: i64,
+    strike: f64,
+    ts_now: i64,
+) -> f64 {
+    let underlying = pystr_to_string(underlying_ptr);
+    let registry = registry().lock().unwrap();
+    registry
+        .get(&underlying)
+        .and_then(|surface| surface.volatility(expiry_ns, strike, ts_now))
+        .unwrap_or(f64::NAN)
+}
+
+/// Returns the volatility of the surface for the underlying at the expiry and
+/// delta, writing the strike to `strike_out` (NaN if none).
+///
+/// # Safety
+///
+/// - `underlying_ptr` must be borrowed from a valid Python UTF-8 `str`.
+/// - `strike_out` must be a valid pointer to write the strike to.
+#[no_mangle]
+#[allow(clippy::too_many_arguments)]
+pub unsafe extern "C" fn volatility_surface_at_delta(
+    underlying_ptr: *mut ffi::PyObject,
+    kind: OptionKind,
+    expiry_ns: i64,
+    delta: f64,
+    spot: f64,
+    rate: f64,
+    ts_now: i64,
+    strike_out: *mut f64,
+) -> f64 {
+    let underlying = pystr_to_string(underlying_ptr);
+    let registry = registry().lock().unwrap();
+    let (strike, vol) = registry
+        .get(&underlying)
+        .and_then(|surface| surface.volatility_at_delta(kind, expiry_ns, delta, spot, rate, ts_now))
+        .unwrap_or((f64::NAN, f64::NAN));
+    strike_out.write(strike);
+    vol
+}
+
+#[no_mangle]
+pub extern "C" fn volatility_surfaces_reset() {
+    reset_volatility_surfaces();
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY_NS: i64 = 86_400_000_000_000;
+
+    #[test]
+    fn test_black_scholes_price_and_delta() {
+        // Hull, Options, Futures and Other Derivatives (example 15.6)
+        let call = black_scholes_price(OptionKind::Call, 42.0, 40.0, 0.5, 0.1, 0.2);
+        let put = black_scholes_price(OptionKind::Put, 42.0, 40.0, 0.5, 0.1, 0.2);
+
+        assert!((call - 4.76).abs() < 0.005);
+        assert!((put - 0.81).abs() < 0.005);
+        assert!(
+            (black_scholes_delta(OptionKind::Call, 42.0, 40.0, 0.5, 0.1, 0.2) - 0.7791).abs()
+                < 1e-4
+        );
+        assert!(
+            (black_scholes_delta(OptionKind::Put, 42.0, 40.0, 0.5, 0.1, 0.2) + 0.2209).abs() < 1e-4
+        );
+    }
+
+    #[test]
+    fn test_implied_volatility_round_trip() {
+        for kind in [OptionKind::Call, OptionKind::Put] {
+            for vol in [0.05, 0.2, 0.8] {
+                let price = black_scholes_price(kind, 100.0, 110.0, 0.25, 0.02, vol);
+                let implied = implied_volatility(kind, price, 100.0, 110.0, 0.25, 0.02).unwrap();
+
+                assert!((implied - vol).abs() < 1e-6, "{kind:?} {vol}");
+            }
+        }
+        // Below intrinsic value
+        assert_eq!(
+            implied_volatility(OptionKind::Call, 1.0, 100.0, 90.0, 0.25, 0.0),
+            None
+        );
+    }
+
+    #[test]
+    fn test_surface_interpolation() {
+        let mut surface = VolatilitySurface::default();
+        surface.update(30 * DAY_NS, &Price::new(90.0, 0), 0.30);
+        surface.update(30 * DAY_NS, &Price::new(110.0, 0), 0.20);
+        surface.update(90 * DAY_NS, &Price::new(100.0, 0), 0.40);
+
+        // Linear in strike, flat extrapolation
+        assert_eq!(surface.volatility(30 * DAY_NS, 100.0, 0), Some(0.25));
+        assert_eq!(surface.volatility(30 * DAY_NS, 80.0, 0), Some(0.30));
+        assert_eq!(surface.volatility(30 * DAY_NS, 120.0, 0), Some(0.20));
+        // Flat before the first and after the last expiry
+        assert_eq!(surface.volatility(10 * DAY_NS, 100.0, 0), Some(0.25));
+        assert_eq!(surface.volatility(120 * DAY_NS, 100.0, 0), Some(0.40));
+
+        // Linear in total variance between expiries
+        let vol = surface.volatility(60 * DAY_NS, 100.0, 0).unwrap();
+        let expected = ((0.25_f64.powi(2) * 30.0 + 0.40_f64.powi(2) * 90.0) / 2.0 / 60.0).sqrt();
+        assert!((vol - expected).abs() < 1e-12);
+
+        // Expired expiries are ignored
+        assert_eq!(
+            surface.volatility(60 * DAY_NS, 100.0, 30 * DAY_NS),
+            Some(0.40)
+        );
+        assert_eq!(surface.volatility(DAY_NS, 100.0, DAY_NS), None);
+    }
+
+    #[test]
+    fn test_surface_volatility_at_delta() {
+        let mut surface = VolatilitySurface::default();
+        surface.update(90 * DAY_NS, &Price::new(80.0, 0), 0.30);
+        surface.update(90 * DAY_NS, &Price::new(120.0, 0), 0.20);
+
+        let (strike, vol) = surface
+            .volatility_at_delta(OptionKind::Call, 90 * DAY_NS, 0.5, 100.0, 0.0, 0)
+            .unwrap();
+
+        let t = years_to_expiry(90 * DAY_NS, 0);
+        assert!(
+            (black_scholes_delta(OptionKind::Call, 100.0, strike, t, 0.0, vol) - 0.5).abs() < 1e-6
+        );
+        assert_eq!(surface.volatility(90 * DAY_NS, strike, 0), Some(vol));
+        assert_eq!(
+            surface.volatility_at_delta(OptionKind::Put, 90 * DAY_NS, 0.5, 100.0, 0.0, 0),
+            None
+        );
+    }
+
+    #[test]
+    fn test_registry_update_from_quotes() {
+        let underlying = "VOL-TEST";
+        let strike = Price::new(100.0, 2);
+        let t = years_to_expiry(30 * DAY_NS, 0);
+        let price = black_scholes_price(OptionKind::Call, 100.0, 100.0, t, 0.0, 0.25);
+
+        let vol = update_volatility_surface(
+            underlying,
+            OptionKind::Call,
+            30 * DAY_NS,
+            &strike,
+            price,
+            100.0,
+            0.0,
+            0,
+        )
+        .unwrap();
+
+        assert!((vol - 0.25).abs() < 1e-6);
+        assert_eq!(
+            volatility_surface(underlying)
+                .unwrap()
+                .volatility(30 * DAY_NS, 100.0, 0),
+            Some(vol)
+        );
+        assert_eq!(volatility_surface("VOL-TEST-NONE"), None);
+    }
+}
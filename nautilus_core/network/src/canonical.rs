@@ -0,0 +1,157 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use sha2::{Digest, Sha256};
+
+/// Returns the string percent-encoded per RFC 3986 (unreserved characters kept).
+pub fn percent_encode(s: &str) -> String {
+    let mut encoded = String::with_capacity(s.len());
+    for b in s.bytes() {
+        match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(b as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", b)),
+        }
+    }
+    encoded
+}
+
+/// Returns the query string for the given parameters.
+///
+/// If `sorted` then parameters are ordered by key (then value), as required by
+/// venues which canonicalize the query before signing; otherwise insertion
+/// order is preserved, as required by venues which sign the query verbatim.
+pub fn canonical_query_string(params: &[(&str, &str)], sorted: bool) -> String {
+    let mut pairs: Vec<(&str, &str)> = params.to_vec();
+    if sorted {
+        pairs.sort_unstable();
+    }
+    pairs
+        .iter()
+        .map(|(k, v)| format!("{}={}", percent_encode(k), percent_encode(v)))
+        .collect::<Vec<String>>()
+        .join("&")
+}
+
+/// Returns the pre-hash string `{timestamp}{METHOD}{path}{body}` used by
+/// Coinbase, FTX, OKX and similar venues.
+pub fn prehash_string(timestamp: &str, method: &str, request_path: &str, body: &str) -> String {
+    format!(
+        "{}{}{}{}",
+        timestamp,
+        method.to_uppercase(),
+        request_path,
+        body
+    )
+}
+
+/// Returns the message signed by Kraken: `path + SHA256(nonce + post_data)`.
+pub fn kraken_message(path: &str, nonce: u64, post_data: &str) -> Vec<u8> {
+    let mut hasher = Sha256::new();
+    hasher.update(nonce.to_string().as_bytes());
+    hasher.update(post_data.as_bytes());
+    let mut message = path.as_bytes().to_vec();
+    message.extend_from_slice(&hasher.finalize());
+    message
+}
+
+/// Returns whether the request timestamp is accepted by a venue enforcing a
+/// receive window, i.e. not in the future beyond `tolerance_ms` and not older
+/// than `window_ms` (all values in UNIX milliseconds).
+///
+/// A negative `window_ms` or `tolerance_ms` accepts no timestamp.
+pub fn timestamp_in_window(ts_ms: i64, server_ms: i64, window_ms: i64, tolerance_ms: i64) -> bool {
+    if window_ms < 0 || tolerance_ms < 0 {
+        return false;
+    }
+    ts_ms <= server_ms + tolerance_ms && server_ms - ts_ms <= window_ms
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::secrets::Secret;
+    use crate::signing::{HmacSha512Signer, RequestSigner, SignatureEncoding};
+
+    #[test]
+    fn test_percent_encode() {
+        assert_eq!(percent_encode("BTC-PERP"), "BTC-PERP");
+        assert_eq!(percent_encode("BTC/USD"), "BTC%2FUSD");
+        assert_eq!(percent_encode("a b&c=d"), "a%20b%26c%3Dd");
+        assert_eq!(percent_encode("~._"), "~._");
+    }
+
+    #[test]
+    fn test_canonical_query_string_preserves_order() {
+        let params = [("symbol", "LTCBTC"), ("side", "BUY"), ("timestamp", "1")];
+
+        let result = canonical_query_string(&params, false);
+
+        assert_eq!(result, "symbol=LTCBTC&side=BUY&timestamp=1");
+    }
+
+    #[test]
+    fn test_canonical_query_string_sorted() {
+        let params = [("symbol", "BTC/USD"), ("api_key", "abc"), ("limit", "10")];
+
+        let result = canonical_query_string(&params, true);
+
+        assert_eq!(result, "api_key=abc&limit=10&symbol=BTC%2FUSD");
+    }
+
+    #[test]
+    fn test_prehash_string() {
+        let result = prehash_string("1588591511721", "get", "/api/markets", "");
+
+        assert_eq!(result, "1588591511721GET/api/markets");
+    }
+
+    #[test]
+    fn test_kraken_message() {
+        // https://docs.kraken.com/rest/#section/Authentication/Headers-and-Signature
+        let signer = HmacSha512Signer::from_base64(&Secret::from(
+            "kQH5HW/8p1uGOVjbgWA7FunAmGO8lsSUXNsu3eow76sz84Q18fWxnyRzBHCd3pd5nE9qa99HAZtuZuj6F1huXg==",
+        ))
+        .unwrap();
+        let message = kraken_message(
+            "/0/private/AddOrder",
+            1616492376594,
+            "nonce=1616492376594&ordertype=limit&pair=XBTUSD&price=37500&type=buy&volume=1.25",
+        );
+
+        let signature = signer.sign_encoded(&message, SignatureEncoding::Base64);
+
+        assert!(message.starts_with(b"/0/private/AddOrder"));
+        assert_eq!(
+            signature,
+            "4/dpxb3iT4tp/ZCVEwSnEsLxx0bqyhLpdfOpc6fn7OR8+UClSV5n9E6aSS8MPtnRfp32bAb0nmbRn6H8ndwLUQ=="
+        );
+    }
+
+    #[test]
+    fn test_timestamp_in_window() {
+        assert!(timestamp_in_window(1_000, 1_000, 5_000, 1_000));
+        assert!(timestamp_in_window(1_500, 1_000, 5_000, 1_000));
+        assert!(!timestamp_in_window(2_001, 1_000, 5_000, 1_000));
+        assert!(timestamp_in_window(0, 5_000, 5_000, 1_000));
+        assert!(!timestamp_in_window(0, 5_001, 5_000, 1_000));
+        assert!(!timestamp_in_window(1_000, 1_000, -1, 1_000));
+        assert!(!timestamp_in_window(1_000, 1_000, 5_000, -1));
+    }
+}
@@ -0,0 +1,378 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! HTTP client for authenticated venue REST requests.
+
+use crate::nonce::NonceGenerator;
+use crate::time_offset::ServerTimeRegistry;
+use nautilus_core::time::unix_timestamp_ms;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+use std::time::Duration;
+use ureq::{Agent, AgentBuilder, Request};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum HttpError {
+    /// The request could not be sent or the response not read.
+    Transport(String),
+    /// The venue responded with an error status.
+    Status { status: u16, body: String },
+}
+
+impl Display for HttpError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HttpError::Transport(e) => write!(f, "request failed, {}", e),
+            HttpError::Status { status, body } => write!(f, "venue returned {}, {}", status, body),
+        }
+    }
+}
+
+impl Error for HttpError {}
+
+/// Represents the values a request is signed with for one attempt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SigningContext {
+    /// The current time on the venue's server clock in UNIX milliseconds.
+    pub timestamp_ms: i64,
+    /// The nonce for the attempt, if the client has a nonce generator.
+    pub nonce: Option<u64>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub body: String,
+}
+
+/// Provides a blocking HTTP client which signs requests against the venue's
+/// server clock, and recovers from clock-skew and nonce-reuse rejections.
+///
+/// A clock-skew rejection re-syncs the venue offset from the server time
+/// endpoint (when configured) and a nonce-reuse rejection advances the nonce
+/// generator past the rejected nonce, before the request is re-signed and
+/// retried.
+pub struct SignedHttpClient {
+    agent: Agent,
+    venue: String,
+    times: Arc<ServerTimeRegistry>,
+    nonces: Option<Arc<NonceGenerator>>,
+    server_time: Option<(String, String)>,
+    max_retries: u32,
+}
+
+impl SignedHttpClient {
+    pub fn new(venue: &str, times: Arc<ServerTimeRegistry>, timeout: Duration) -> Self {
+        SignedHttpClient {
+            agent: AgentBuilder::new().timeout(timeout).build(),
+            venue: venue.to_string(),
+            times,
+            nonces: None,
+            server_time: None,
+            max_retries: 1,
+        }
+    }
+
+    /// Signs requests with nonces from the generator.
+    pub fn with_nonces(mut self, nonces: Arc<NonceGenerator>) -> Self {
+        self.nonces = Some(nonces);
+        self
+    }
+
+    /// Re-syncs the server time from the `field` (UNIX milliseconds) of the
+    /// JSON object returned by a GET to `url`.
+    pub fn with_server_time(mut self, url: &str, field: &str) -> Self {
+        self.server_time = Some((url.to_string(), field.to_string()));
+        self
+    }
+
+    /// Sets the maximum number of re-signed retries per request (default 1).
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Updates the venue offset from the server time endpoint, returning
+    /// whether the offset was updated.
+    pub fn sync_time(&self) -> Result<bool, HttpError> {
+        let (url, field) = match &self.server_time {
+            Some(server_time) => server_time,
+            None => return Ok(false),
+        };
+        let sent_ms = unix_timestamp_ms();
+        let response = self.call(self.agent.get(url), "")?;
+        let recv_ms = unix_timestamp_ms();
+        let server_ms = serde_json::from_str::<serde_json::Value>(&response.body)
+            .ok()
+            .and_then(|v| v.get(field).and_then(serde_json::Value::as_i64));
+        Ok(match server_ms {
+            Some(server_ms) => self
+                .times
+                .get(&self.venue)
+                .update(server_ms, sent_ms, recv_ms),
+            None => false,
+        })
+    }
+
+    /// Sends the request returned by `build` for each attempt, which must sign
+    /// it with the given context, returning the first response which is not a
+    /// recoverable rejection.
+    pub fn send<F>(&self, build: F) -> Result<HttpResponse, HttpError>
+    where
+        F: Fn(&Agent, &SigningContext) -> (Request, String),
+    {
+        let mut attempt = 0;
+        loop {
+            let context = SigningContext {
+                timestamp_ms: self.times.adjust_ms(&self.venue, unix_timestamp_ms()),
+                nonce: self.nonces.as_ref().map(|n| n.next()),
+            };
+            let (request, body) = build(&self.agent, &context);
+            let result = self.call(request, &body);
+            let response_body = match &result {
+                Ok(response) => &response.body,
+                Err(HttpError::Status { body, .. }) => body,
+                Err(HttpError::Transport(_)) => return result,
+            };
+            if attempt == self.max_retries || !self.recover(response_body, &context) {
+                return result;
+            }
+            attempt += 1;
+        }
+    }
+
+    /// Returns whether the response was a recoverable rejection, after recovering.
+    ///
+    /// A skew rejection is not recovered if the time sync fails, so the
+    /// rejection itself is returned rather than the sync error.
+    fn recover(&self, body: &str, context: &SigningContext) -> bool {
+        if self.times.is_nonce_error(&self.venue, body) {
+            return match (&self.nonces, context.nonce) {
+                (Some(nonces), Some(nonce)) => {
+                    nonces.advance_past(nonce);
+                    true
+                }
+                _ => false,
+            };
+        }
+        if self.times.is_skew_error(&self.venue, body) {
+            return self.sync_time().unwrap_or(false);
+        }
+        false
+    }
+
+    fn call(&self, request: Request, body: &str) -> Result<HttpResponse, HttpError> {
+        let response = match request.send_string(body) {
+            Ok(response) => response,
+            Err(ureq::Error::Status(_, response)) => response,
+            // The transport error may contain the URL, so only its kind is kept
+            Err(ureq::Error::Transport(e)) => {
+                return Err(HttpError::Transport(e.kind().to_string()))
+            }
+        };
+        let status = response.status();
+        let body = response
+            .into_string()
+            .map_err(|e| HttpError::Transport(e.to_string()))?;
+        if status >= 400 {
+            return Err(HttpError::Status { status, body });
+        }
+        Ok(HttpResponse { status, body })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread::JoinHandle;
+
+    use super::*;
+    use crate::nonce::NonceResolution;
+
+    /// Serves one request per response, returning the base URL and the received
+    /// request lines and bodies.
+    fn serve(responses: Vec<(u16, String)>) -> (String, JoinHandle<Vec<(String, String)>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = std::thread::spawn(move || {
+            let mut requests = Vec::new();
+            for (status, response) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line == "\r\n" {
+                        break;
+                    }
+                    if let Some(value) = line.to_lowercase().strip_prefix("content-length:") {
+                        content_length = value.trim().parse().unwrap();
+                    }
+                }
+                let mut body = vec![0; content_length];
+                reader.read_exact(&mut body).unwrap();
+                requests.push((
+                    request_line.trim().to_string(),
+                    String::from_utf8(body).unwrap(),
+                ));
+                write!(
+                    reader.get_mut(),
+                    "HTTP/1.1 {} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    response.len(),
+                    response
+                )
+                .unwrap();
+            }
+            requests
+        });
+        (url, handle)
+    }
+
+    fn client(times: Arc<ServerTimeRegistry>, venue: &str) -> SignedHttpClient {
+        SignedHttpClient::new(venue, times, Duration::from_secs(5))
+    }
+
+    #[test]
+    fn test_nonce_rejection_advances_nonce_and_retries() {
+        let (url, server) = serve(vec![
+            (200, r#"{"error":["EAPI:Invalid nonce"]}"#.to_string()),
+            (200, r#"{"error":[],"result":{}}"#.to_string()),
+        ]);
+        let times = Arc::new(ServerTimeRegistry::with_defaults());
+        let nonces = Arc::new(NonceGenerator::new(NonceResolution::Milliseconds));
+        let client = client(times.clone(), "KRAKEN").with_nonces(nonces.clone());
+
+        let response = client
+            .send(|agent, context| {
+                let body = format!("nonce={}", context.nonce.unwrap());
+                (agent.post(&format!("{}/0/private/Balance", url)), body)
+            })
+            .unwrap();
+
+        let requests = server.join().unwrap();
+        let nonce = |i: usize| -> u64 { requests[i].1["nonce=".len()..].parse().unwrap() };
+        assert_eq!(response.body, r#"{"error":[],"result":{}}"#);
+        assert_eq!(requests.len(), 2);
+        assert!(nonce(1) > nonce(0));
+        assert_eq!(nonces.last(), nonce(1));
+        assert!(!times.get("KRAKEN").is_synced());
+    }
+
+    #[test]
+    fn test_skew_rejection_resyncs_offset_and_retries() {
+        let server_ms = unix_timestamp_ms() + 60_000;
+        let (url, server) = serve(vec![
+            (
+                400,
+                r#"{"code":-1021,"msg":"outside of the recvWindow"}"#.to_string(),
+            ),
+            (200, format!(r#"{{"serverTime":{}}}"#, server_ms)),
+            (200, "{}".to_string()),
+        ]);
+        let times = Arc::new(ServerTimeRegistry::with_defaults());
+        let client = client(times.clone(), "BINANCE")
+            .with_server_time(&format!("{}/api/v3/time", url), "serverTime");
+
+        let response = client
+            .send(|agent, context| {
+                let query = format!("timestamp={}", context.timestamp_ms);
+                (
+                    agent.post(&format!("{}/api/v3/order?{}", url, query)),
+                    String::new(),
+                )
+            })
+            .unwrap();
+
+        let requests = server.join().unwrap();
+        let timestamp = |i: usize| -> i64 {
+            let line = &requests[i].0;
+            let start = line.find("timestamp=").unwrap() + "timestamp=".len();
+            line[start..].split(' ').next().unwrap().parse().unwrap()
+        };
+        assert_eq!(
+            response,
+            HttpResponse {
+                status: 200,
+                body: "{}".to_string()
+            }
+        );
+        assert_eq!(requests[1].0, "GET /api/v3/time HTTP/1.1");
+        assert!(times.get("BINANCE").offset_ms() > 50_000);
+        assert!(timestamp(2) - timestamp(0) > 50_000);
+    }
+
+    #[test]
+    fn test_skew_rejection_is_returned_when_resync_fails() {
+        let body = r#"{"code":-1021,"msg":"outside of the recvWindow"}"#;
+        let (url, server) = serve(vec![
+            (400, body.to_string()),
+            (503, "Service Unavailable".to_string()),
+        ]);
+        let times = Arc::new(ServerTimeRegistry::with_defaults());
+        let client = client(times.clone(), "BINANCE")
+            .with_server_time(&format!("{}/api/v3/time", url), "serverTime");
+
+        let result = client.send(|agent, _| (agent.post(&url), String::new()));
+
+        assert_eq!(
+            result,
+            Err(HttpError::Status {
+                status: 400,
+                body: body.to_string()
+            })
+        );
+        assert_eq!(server.join().unwrap().len(), 2);
+        assert!(!times.get("BINANCE").is_synced());
+    }
+
+    #[test]
+    fn test_unrecoverable_rejection_is_returned() {
+        let body = r#"{"code":-2010,"msg":"Account has insufficient balance"}"#;
+        let (url, server) = serve(vec![(400, body.to_string())]);
+        let client = client(Arc::new(ServerTimeRegistry::with_defaults()), "BINANCE");
+
+        let result = client.send(|agent, _| (agent.post(&url), String::new()));
+
+        assert_eq!(
+            result,
+            Err(HttpError::Status {
+                status: 400,
+                body: body.to_string()
+            })
+        );
+        assert_eq!(server.join().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_skew_rejection_without_server_time_is_returned() {
+        let body = r#"{"code":-1021}"#;
+        let (url, server) = serve(vec![(400, body.to_string())]);
+        let client = client(Arc::new(ServerTimeRegistry::with_defaults()), "BINANCE");
+
+        let result = client.send(|agent, _| (agent.post(&url), String::new()));
+
+        assert!(matches!(result, Err(HttpError::Status { status: 400, .. })));
+        assert_eq!(server.join().unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,29 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+pub mod canonical;
+#[cfg(feature = "http")]
+pub mod http;
+pub mod nonce;
+pub mod notify;
+#[cfg(feature = "amqp")]
+pub mod notify_amqp;
+#[cfg(feature = "mqtt")]
+pub mod notify_mqtt;
+#[cfg(feature = "webhook")]
+pub mod notify_webhook;
+pub mod secrets;
+pub mod signing;
+pub mod time_offset;
@@ -0,0 +1,158 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_core::time::{unix_timestamp_ms, unix_timestamp_us};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum NonceResolution {
+    Milliseconds,
+    Microseconds,
+}
+
+/// Provides strictly increasing nonces for authenticated REST calls.
+///
+/// Nonces track the UNIX time at the given resolution, but are never repeated
+/// or decreased even if the system clock steps backwards or many requests are
+/// signed within the same tick. The generator is safe to share across threads.
+#[derive(Debug)]
+pub struct NonceGenerator {
+    resolution: NonceResolution,
+    last: AtomicU64,
+}
+
+impl NonceGenerator {
+    pub fn new(resolution: NonceResolution) -> Self {
+        NonceGenerator {
+            resolution,
+            last: AtomicU64::new(0),
+        }
+    }
+
+    pub fn resolution(&self) -> NonceResolution {
+        self.resolution
+    }
+
+    /// Returns the last nonce issued (zero if none issued yet).
+    pub fn last(&self) -> u64 {
+        self.last.load(Ordering::SeqCst)
+    }
+
+    /// Returns the next nonce based on the current system time.
+    pub fn next(&self) -> u64 {
+        let now = match self.resolution {
+            NonceResolution::Milliseconds => unix_timestamp_ms(),
+            NonceResolution::Microseconds => unix_timestamp_us(),
+        };
+        self.next_at(now as u64)
+    }
+
+    /// Returns the next nonce given the current time at the generator resolution.
+    pub fn next_at(&self, now: u64) -> u64 {
+        let mut last = self.last.load(Ordering::SeqCst);
+        loop {
+            let next = now.max(last.saturating_add(1));
+            match self
+                .last
+                .compare_exchange_weak(last, next, Ordering::SeqCst, Ordering::SeqCst)
+            {
+                Ok(_) => return next,
+                Err(current) => last = current,
+            }
+        }
+    }
+
+    /// Advances the generator so the next nonce is greater than `nonce`.
+    ///
+    /// Used to recover when a venue rejects a nonce as already used (for
+    /// example after a restart, or when the same API key is shared).
+    pub fn advance_past(&self, nonce: u64) {
+        self.last.fetch_max(nonce, Ordering::SeqCst);
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::thread;
+
+    #[test]
+    fn test_next_tracks_time() {
+        let generator = NonceGenerator::new(NonceResolution::Milliseconds);
+
+        let nonce = generator.next();
+
+        assert!(nonce > 1610000000000);
+        assert_eq!(generator.last(), nonce);
+    }
+
+    #[test]
+    fn test_next_at_is_strictly_increasing() {
+        let generator = NonceGenerator::new(NonceResolution::Milliseconds);
+
+        assert_eq!(generator.next_at(1_000), 1_000);
+        assert_eq!(generator.next_at(1_000), 1_001);
+        assert_eq!(generator.next_at(999), 1_002); // Clock stepped backwards
+        assert_eq!(generator.next_at(2_000), 2_000);
+    }
+
+    #[test]
+    fn test_advance_past() {
+        let generator = NonceGenerator::new(NonceResolution::Microseconds);
+        generator.next_at(1_000);
+
+        generator.advance_past(5_000);
+        generator.advance_past(10); // Never decreases
+
+        assert_eq!(generator.next_at(1_000), 5_001);
+    }
+
+    #[test]
+    fn test_advance_past_max_does_not_overflow() {
+        let generator = NonceGenerator::new(NonceResolution::Microseconds);
+
+        generator.advance_past(u64::MAX);
+
+        assert_eq!(generator.next_at(1_000), u64::MAX);
+    }
+
+    #[test]
+    fn test_next_is_unique_across_threads() {
+        let generator = Arc::new(NonceGenerator::new(NonceResolution::Milliseconds));
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let generator = generator.clone();
+                thread::spawn(move || {
+                    (0..1_000)
+                        .map(|_| generator.next_at(1))
+                        .collect::<Vec<u64>>()
+                })
+            })
+            .collect();
+
+        let mut nonces: Vec<u64> = handles
+            .into_iter()
+            .flat_map(|h| h.join().unwrap())
+            .collect();
+        nonces.sort_unstable();
+        nonces.dedup();
+
+        assert_eq!(nonces.len(), 4_000);
+    }
+}
@@ -0,0 +1,300 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Bridging of high-level notifications to external brokers and webhooks.
+//!
+//! Notifications are queued onto a [`NotificationBridge`] without blocking the
+//! caller, and delivered to a [`NotificationSink`] (an MQTT or AMQP broker, or
+//! webhook URLs) from a dedicated thread, so a slow or unreachable endpoint
+//! never stalls trading.
+
+use serde_json::json;
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NotifyError {
+    /// The underlying sink failed.
+    Backend(String),
+    /// The bridge has been closed.
+    Closed,
+}
+
+impl Display for NotifyError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            NotifyError::Backend(reason) => write!(f, "Notification sink error, {}", reason),
+            NotifyError::Closed => write!(f, "Notification bridge closed"),
+        }
+    }
+}
+
+impl Error for NotifyError {}
+
+pub type NotifyResult<T> = std::result::Result<T, NotifyError>;
+
+/// Represents the kind of a notification.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NotificationKind {
+    /// An order was filled (partially or completely).
+    Fill,
+    /// A position was opened, changed or closed.
+    PositionChanged,
+    /// A risk limit (e.g. the maximum drawdown) was breached.
+    RiskBreach,
+    /// A client disconnected from its venue.
+    Disconnected,
+}
+
+impl NotificationKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NotificationKind::Fill => "fill",
+            NotificationKind::PositionChanged => "position_changed",
+            NotificationKind::RiskBreach => "risk_breach",
+            NotificationKind::Disconnected => "disconnected",
+        }
+    }
+}
+
+impl Display for NotificationKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Represents a human readable notification, e.g. for a phone or desktop alert.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Notification {
+    pub kind: NotificationKind,
+    pub title: String,
+    pub body: String,
+    /// The UNIX timestamp (nanoseconds) when the notified event occurred.
+    pub ts_event: u64,
+}
+
+impl Notification {
+    pub fn new(kind: NotificationKind, title: &str, body: &str, ts_event: u64) -> Self {
+        Notification {
+            kind,
+            title: title.to_string(),
+            body: body.to_string(),
+            ts_event,
+        }
+    }
+
+    /// Returns the notification as a JSON object string.
+    pub fn to_json(&self) -> String {
+        json!({
+            "kind": self.kind.as_str(),
+            "title": self.title,
+            "body": self.body,
+            "ts_event": self.ts_event,
+        })
+        .to_string()
+    }
+
+    /// Returns the destination topic for the notification, being the prefix
+    /// and kind joined by the separator, e.g. `nautilus/fill` for MQTT.
+    pub fn topic(&self, prefix: &str, separator: char) -> String {
+        if prefix.is_empty() {
+            return self.kind.as_str().to_string();
+        }
+        format!("{}{}{}", prefix, separator, self.kind)
+    }
+}
+
+/// Provides the delivery of notifications to a broker.
+pub trait NotificationSink: Send {
+    /// Sends the notification, blocking until it is handed to the broker.
+    fn send(&mut self, notification: &Notification) -> NotifyResult<()>;
+}
+
+/// Provides a background bridge of notifications to a sink.
+pub struct NotificationBridge {
+    kinds: Vec<NotificationKind>,
+    tx: Option<Sender<Notification>>,
+    handle: Option<JoinHandle<()>>,
+    failed: Arc<AtomicU64>,
+}
+
+impl NotificationBridge {
+    /// Starts the bridge thread, forwarding notifications of the given kinds.
+    pub fn start<S: NotificationSink + 'static>(
+        sink: S,
+        kinds: &[NotificationKind],
+    ) -> NotifyResult<Self> {
+        let (tx, rx) = channel();
+        let failed = Arc::new(AtomicU64::new(0));
+        let thread_failed = failed.clone();
+        let handle = std::thread::Builder::new()
+            .name("notification-bridge".to_string())
+            .spawn(move || run(sink, rx, thread_failed))
+            .map_err(|e| NotifyError::Backend(e.to_string()))?;
+        Ok(NotificationBridge {
+            kinds: kinds.to_vec(),
+            tx: Some(tx),
+            handle: Some(handle),
+            failed,
+        })
+    }
+
+    /// Returns whether notifications of the kind are forwarded.
+    pub fn is_forwarded(&self, kind: NotificationKind) -> bool {
+        self.kinds.contains(&kind)
+    }
+
+    /// Queues the notification without blocking, returning whether its kind
+    /// is forwarded.
+    pub fn notify(&self, notification: Notification) -> NotifyResult<bool> {
+        if !self.is_forwarded(notification.kind) {
+            return Ok(false);
+        }
+        self.tx
+            .as_ref()
+            .ok_or(NotifyError::Closed)?
+            .send(notification)
+            .map_err(|_| NotifyError::Closed)?;
+        Ok(true)
+    }
+
+    /// Returns the number of notifications the sink failed to send.
+    pub fn failed(&self) -> u64 {
+        self.failed.load(Ordering::Relaxed)
+    }
+
+    /// Sends any queued notifications and stops the bridge thread.
+    pub fn close(&mut self) {
+        self.tx = None;
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+impl Drop for NotificationBridge {
+    fn drop(&mut self) {
+        self.close();
+    }
+}
+
+fn run<S: NotificationSink>(mut sink: S, rx: Receiver<Notification>, failed: Arc<AtomicU64>) {
+    for notification in rx {
+        if sink.send(&notification).is_err() {
+            failed.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+
+    #[derive(Clone, Default)]
+    struct MockSink {
+        sent: Arc<Mutex<Vec<Notification>>>,
+        failures: Arc<Mutex<usize>>,
+    }
+
+    impl NotificationSink for MockSink {
+        fn send(&mut self, notification: &Notification) -> NotifyResult<()> {
+            let mut failures = self.failures.lock().unwrap();
+            if *failures > 0 {
+                *failures -= 1;
+                return Err(NotifyError::Backend("broker unavailable".to_string()));
+            }
+            self.sent.lock().unwrap().push(notification.clone());
+            Ok(())
+        }
+    }
+
+    fn fill() -> Notification {
+        Notification::new(
+            NotificationKind::Fill,
+            "Order filled",
+            "BUY 100 AUD/USD.SIM @ 0.71000",
+            1,
+        )
+    }
+
+    #[test]
+    fn test_notification_to_json() {
+        let value: serde_json::Value = serde_json::from_str(&fill().to_json()).unwrap();
+
+        assert_eq!(
+            value,
+            json!({
+                "kind": "fill",
+                "title": "Order filled",
+                "body": "BUY 100 AUD/USD.SIM @ 0.71000",
+                "ts_event": 1,
+            })
+        );
+    }
+
+    #[test]
+    fn test_notification_topic() {
+        assert_eq!(fill().topic("nautilus/alerts", '/'), "nautilus/alerts/fill");
+        assert_eq!(fill().topic("nautilus", '.'), "nautilus.fill");
+        assert_eq!(fill().topic("", '/'), "fill");
+    }
+
+    #[test]
+    fn test_bridge_forwards_selected_kinds() {
+        let sink = MockSink::default();
+        let mut bridge = NotificationBridge::start(
+            sink.clone(),
+            &[NotificationKind::Fill, NotificationKind::RiskBreach],
+        )
+        .unwrap();
+        let position = Notification::new(
+            NotificationKind::PositionChanged,
+            "Position opened",
+            "LONG 100 AUD/USD.SIM",
+            2,
+        );
+
+        assert!(bridge.notify(fill()).unwrap());
+        assert!(!bridge.notify(position).unwrap());
+        bridge.close();
+
+        assert_eq!(*sink.sent.lock().unwrap(), vec![fill()]);
+        assert_eq!(bridge.notify(fill()), Err(NotifyError::Closed));
+    }
+
+    #[test]
+    fn test_bridge_counts_failed_sends() {
+        let sink = MockSink::default();
+        *sink.failures.lock().unwrap() = 1;
+        let mut bridge =
+            NotificationBridge::start(sink.clone(), &[NotificationKind::Fill]).unwrap();
+
+        bridge.notify(fill()).unwrap();
+        bridge.notify(fill()).unwrap();
+        bridge.close();
+
+        assert_eq!(sink.sent.lock().unwrap().len(), 1);
+        assert_eq!(bridge.failed(), 1);
+    }
+}
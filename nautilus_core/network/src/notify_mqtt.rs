@@ -0,0 +1,158 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! MQTT sink for the notification bridge.
+
+use crate::notify::{Notification, NotificationSink, NotifyError, NotifyResult};
+use crate::secrets::Secret;
+use rumqttc::{Client, Event, MqttOptions, Outgoing, QoS, RecvTimeoutError};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+/// The capacity of the queue of requests to the MQTT event loop.
+const REQUEST_CAPACITY: usize = 64;
+
+/// The interval at which the connection thread checks whether it is stopped.
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// The delay before reconnecting after the broker connection fails.
+const RECONNECT_DELAY: Duration = Duration::from_secs(1);
+
+/// Provides an MQTT notification sink, publishing to `{topic_prefix}/{kind}`.
+pub struct MqttSink {
+    client: Option<Client>,
+    topic_prefix: String,
+    qos: QoS,
+    stopped: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl MqttSink {
+    /// Returns the client options for the broker, with the credentials if any.
+    pub fn options(
+        client_id: &str,
+        host: &str,
+        port: u16,
+        credentials: Option<(&str, &Secret)>,
+    ) -> MqttOptions {
+        let mut options = MqttOptions::new(client_id, host, port);
+        options.set_keep_alive(Duration::from_secs(30));
+        if let Some((username, password)) = credentials {
+            options.set_credentials(username, password.expose());
+        }
+        options
+    }
+
+    /// Starts the client, which connects (and reconnects) to the broker in the
+    /// background.
+    pub fn connect(options: MqttOptions, topic_prefix: &str, qos: QoS) -> NotifyResult<Self> {
+        let (client, mut connection) = Client::new(options, REQUEST_CAPACITY);
+        let stopped = Arc::new(AtomicBool::new(false));
+        let thread_stopped = stopped.clone();
+        let handle = std::thread::Builder::new()
+            .name("notification-mqtt".to_string())
+            .spawn(move || {
+                // Polling drives the connection, reconnecting after any failure, until
+                // the disconnect is sent or the connection is idle once stopped
+                loop {
+                    match connection.recv_timeout(POLL_INTERVAL) {
+                        Ok(Ok(Event::Outgoing(Outgoing::Disconnect)))
+                        | Err(RecvTimeoutError::Disconnected) => break,
+                        Ok(Err(_)) | Err(RecvTimeoutError::Timeout)
+                            if thread_stopped.load(Ordering::Relaxed) =>
+                        {
+                            break
+                        }
+                        Ok(Err(_)) => std::thread::sleep(RECONNECT_DELAY),
+                        _ => {}
+                    }
+                }
+            })
+            .map_err(|e| NotifyError::Backend(e.to_string()))?;
+        Ok(MqttSink {
+            client: Some(client),
+            topic_prefix: topic_prefix.to_string(),
+            qos,
+            stopped,
+            handle: Some(handle),
+        })
+    }
+}
+
+impl NotificationSink for MqttSink {
+    fn send(&mut self, notification: &Notification) -> NotifyResult<()> {
+        self.client
+            .as_ref()
+            .ok_or(NotifyError::Closed)?
+            .publish(
+                notification.topic(&self.topic_prefix, '/'),
+                self.qos,
+                false,
+                notification.to_json(),
+            )
+            .map_err(|e| NotifyError::Backend(e.to_string()))
+    }
+}
+
+impl Drop for MqttSink {
+    fn drop(&mut self) {
+        if let Some(client) = self.client.take() {
+            let _ = client.try_disconnect();
+        }
+        self.stopped.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_options_with_credentials() {
+        let password = Secret::from("password");
+        let options = MqttSink::options("nautilus", "localhost", 1883, Some(("user", &password)));
+
+        assert_eq!(options.client_id(), "nautilus");
+        assert_eq!(options.broker_address(), ("localhost".to_string(), 1883));
+        assert_eq!(
+            options.credentials(),
+            Some(("user".to_string(), "password".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_drop_without_broker() {
+        // The client connects in the background, so creation needs no broker
+        let options = MqttSink::options("nautilus", "localhost", 1, None);
+        let mut sink = MqttSink::connect(options, "nautilus", QoS::AtLeastOnce).unwrap();
+        let notification = Notification::new(
+            crate::notify::NotificationKind::RiskBreach,
+            "Max drawdown breached",
+            "",
+            1,
+        );
+
+        assert!(sink.send(&notification).is_ok());
+        drop(sink);
+    }
+}
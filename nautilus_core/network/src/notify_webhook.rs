@@ -0,0 +1,306 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Webhook (HTTP callback) sink for the notification bridge.
+
+use crate::notify::{Notification, NotificationSink, NotifyError, NotifyResult};
+use crate::secrets::Secret;
+use serde_json::json;
+use std::time::{Duration, Instant};
+use ureq::{Agent, AgentBuilder};
+
+/// Represents the JSON body format expected by a webhook endpoint.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WebhookFormat {
+    /// The notification as a JSON object (see `Notification::to_json`).
+    Json,
+    /// A Slack incoming webhook message.
+    Slack,
+    /// A Discord webhook message.
+    Discord,
+    /// A Telegram bot `sendMessage` request to the chat, where the URL is
+    /// `https://api.telegram.org/bot{token}/sendMessage`.
+    Telegram { chat_id: String },
+}
+
+impl WebhookFormat {
+    /// Returns the request body for the notification.
+    pub fn body(&self, notification: &Notification) -> String {
+        match self {
+            WebhookFormat::Json => notification.to_json(),
+            WebhookFormat::Slack => json!({
+                "text": format!("*{}*\n{}", notification.title, notification.body),
+            })
+            .to_string(),
+            WebhookFormat::Discord => json!({
+                "content": format!("**{}**\n{}", notification.title, notification.body),
+            })
+            .to_string(),
+            WebhookFormat::Telegram { chat_id } => json!({
+                "chat_id": chat_id,
+                "text": format!("{}\n{}", notification.title, notification.body),
+            })
+            .to_string(),
+        }
+    }
+}
+
+/// Represents a webhook endpoint, where the URL is a secret as it typically
+/// embeds the credentials (e.g. Slack and Discord webhook tokens).
+#[derive(Clone, Debug)]
+pub struct WebhookTarget {
+    pub url: Secret,
+    pub format: WebhookFormat,
+}
+
+impl WebhookTarget {
+    pub fn new(url: Secret, format: WebhookFormat) -> Self {
+        WebhookTarget { url, format }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WebhookConfig {
+    /// The minimum interval between requests to each target, as services rate
+    /// limit webhooks (e.g. Slack to one message per second).
+    pub min_interval: Duration,
+    /// The maximum number of retries of a failed request.
+    pub max_retries: usize,
+    /// The delay before the first retry, doubling for each subsequent retry.
+    pub retry_delay: Duration,
+    /// The timeout for each request.
+    pub timeout: Duration,
+}
+
+impl Default for WebhookConfig {
+    fn default() -> Self {
+        WebhookConfig {
+            min_interval: Duration::from_secs(1),
+            max_retries: 3,
+            retry_delay: Duration::from_millis(500),
+            timeout: Duration::from_secs(10),
+        }
+    }
+}
+
+/// Provides a webhook notification sink, POSTing each notification to every
+/// target, with retries and per target rate limiting.
+///
+/// Sends block while rate limited, so notifications queue in the bridge
+/// rather than being dropped.
+pub struct WebhookSink {
+    agent: Agent,
+    targets: Vec<WebhookTarget>,
+    config: WebhookConfig,
+    last_sent: Vec<Option<Instant>>,
+}
+
+impl WebhookSink {
+    pub fn new(targets: Vec<WebhookTarget>, config: WebhookConfig) -> Self {
+        let agent = AgentBuilder::new().timeout(config.timeout).build();
+        let last_sent = vec![None; targets.len()];
+        WebhookSink {
+            agent,
+            targets,
+            config,
+            last_sent,
+        }
+    }
+
+    fn wait_for_rate_limit(&self, index: usize) {
+        if let Some(last_sent) = self.last_sent[index] {
+            let elapsed = last_sent.elapsed();
+            if elapsed < self.config.min_interval {
+                std::thread::sleep(self.config.min_interval - elapsed);
+            }
+        }
+    }
+
+    fn post(&mut self, index: usize, body: &str) -> NotifyResult<()> {
+        let mut delay = self.config.retry_delay;
+        let mut attempt = 0;
+        loop {
+            self.wait_for_rate_limit(index);
+            let result = self
+                .agent
+                .post(self.targets[index].url.expose())
+                .set("Content-Type", "application/json")
+                .send_string(body);
+            self.last_sent[index] = Some(Instant::now());
+            let error = match result {
+                Ok(_) => return Ok(()),
+                // Client errors other than rate limiting will fail again
+                Err(ureq::Error::Status(code, _)) if code != 429 && code < 500 => {
+                    return Err(NotifyError::Backend(format!("webhook returned {}", code)))
+                }
+                Err(ureq::Error::Status(code, _)) => format!("webhook returned {}", code),
+                // The transport error may contain the URL, so only its kind is kept
+                Err(ureq::Error::Transport(e)) => format!("webhook request failed, {}", e.kind()),
+            };
+            if attempt == self.config.max_retries {
+                return Err(NotifyError::Backend(error));
+            }
+            attempt += 1;
+            std::thread::sleep(delay);
+            delay *= 2;
+        }
+    }
+}
+
+impl NotificationSink for WebhookSink {
+    fn send(&mut self, notification: &Notification) -> NotifyResult<()> {
+        let mut result = Ok(());
+        for index in 0..self.targets.len() {
+            let body = self.targets[index].format.body(notification);
+            if let Err(e) = self.post(index, &body) {
+                result = Err(e);
+            }
+        }
+        result
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread::JoinHandle;
+
+    use super::*;
+    use crate::notify::NotificationKind;
+
+    fn fill() -> Notification {
+        Notification::new(
+            NotificationKind::Fill,
+            "Order filled",
+            "BUY 100 AUD/USD.SIM @ 0.71000",
+            1,
+        )
+    }
+
+    fn config() -> WebhookConfig {
+        WebhookConfig {
+            min_interval: Duration::ZERO,
+            retry_delay: Duration::from_millis(1),
+            ..Default::default()
+        }
+    }
+
+    /// Serves one request per status, returning the URL and the received bodies.
+    fn serve(statuses: Vec<u16>) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/hook", listener.local_addr().unwrap());
+        let handle = std::thread::spawn(move || {
+            let mut bodies = Vec::new();
+            for status in statuses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line == "\r\n" {
+                        break;
+                    }
+                    if let Some(value) = line.to_lowercase().strip_prefix("content-length:") {
+                        content_length = value.trim().parse().unwrap();
+                    }
+                }
+                let mut body = vec![0; content_length];
+                reader.read_exact(&mut body).unwrap();
+                bodies.push(String::from_utf8(body).unwrap());
+                write!(
+                    reader.get_mut(),
+                    "HTTP/1.1 {} X\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+                    status
+                )
+                .unwrap();
+            }
+            bodies
+        });
+        (url, handle)
+    }
+
+    #[test]
+    fn test_format_bodies() {
+        let telegram = WebhookFormat::Telegram {
+            chat_id: "-100123".to_string(),
+        };
+
+        assert_eq!(WebhookFormat::Json.body(&fill()), fill().to_json());
+        assert_eq!(
+            WebhookFormat::Slack.body(&fill()),
+            r#"{"text":"*Order filled*\nBUY 100 AUD/USD.SIM @ 0.71000"}"#
+        );
+        assert_eq!(
+            WebhookFormat::Discord.body(&fill()),
+            r#"{"content":"**Order filled**\nBUY 100 AUD/USD.SIM @ 0.71000"}"#
+        );
+        assert_eq!(
+            telegram.body(&fill()),
+            r#"{"chat_id":"-100123","text":"Order filled\nBUY 100 AUD/USD.SIM @ 0.71000"}"#
+        );
+    }
+
+    #[test]
+    fn test_send_retries_server_errors() {
+        let (url, server) = serve(vec![503, 429, 200]);
+        let target = WebhookTarget::new(Secret::new(url), WebhookFormat::Json);
+        let mut sink = WebhookSink::new(vec![target], config());
+
+        let result = sink.send(&fill());
+
+        assert_eq!(result, Ok(()));
+        assert_eq!(server.join().unwrap(), vec![fill().to_json(); 3]);
+    }
+
+    #[test]
+    fn test_send_does_not_retry_client_errors() {
+        let (url, server) = serve(vec![404]);
+        let target = WebhookTarget::new(Secret::new(url), WebhookFormat::Slack);
+        let mut sink = WebhookSink::new(vec![target], config());
+
+        let result = sink.send(&fill());
+
+        assert_eq!(
+            result,
+            Err(NotifyError::Backend("webhook returned 404".to_string()))
+        );
+        assert_eq!(server.join().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_send_waits_for_rate_limit() {
+        let (url, server) = serve(vec![200, 200]);
+        let target = WebhookTarget::new(Secret::new(url), WebhookFormat::Discord);
+        let mut sink = WebhookSink::new(
+            vec![target],
+            WebhookConfig {
+                min_interval: Duration::from_millis(50),
+                ..config()
+            },
+        );
+        let start = Instant::now();
+
+        sink.send(&fill()).unwrap();
+        sink.send(&fill()).unwrap();
+
+        assert!(start.elapsed() >= Duration::from_millis(50));
+        assert_eq!(server.join().unwrap().len(), 2);
+    }
+}
@@ -0,0 +1,333 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::env;
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter, Result};
+use std::fs;
+use std::path::PathBuf;
+use zeroize::Zeroize;
+
+/// Represents a secret value (API key, API secret, passphrase etc).
+///
+/// The value is never written by `Debug` or `Display` and is zeroed on drop, so
+/// secrets can be threaded through the system without leaking into logs.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Secret {
+    value: String,
+}
+
+impl Secret {
+    pub fn new(value: String) -> Self {
+        Secret { value }
+    }
+
+    /// Returns the underlying secret value.
+    ///
+    /// Callers are responsible for not logging or persisting the returned value.
+    pub fn expose(&self) -> &str {
+        self.value.as_str()
+    }
+
+    pub fn len(&self) -> usize {
+        self.value.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.value.is_empty()
+    }
+}
+
+impl From<&str> for Secret {
+    fn from(s: &str) -> Self {
+        Secret::new(s.to_string())
+    }
+}
+
+impl Drop for Secret {
+    fn drop(&mut self) {
+        self.value.zeroize();
+    }
+}
+
+impl Debug for Secret {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "Secret(***)")
+    }
+}
+
+impl Display for Secret {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "***")
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SecretsError {
+    /// The secret was not found by the provider.
+    NotFound(String),
+    /// The secret was found but could not be read or decoded.
+    Invalid(String, String),
+    /// The underlying secrets backend failed.
+    Backend(String),
+}
+
+impl Display for SecretsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            SecretsError::NotFound(key) => write!(f, "Secret '{}' not found", key),
+            SecretsError::Invalid(key, reason) => {
+                write!(f, "Secret '{}' is invalid, {}", key, reason)
+            }
+            SecretsError::Backend(reason) => write!(f, "Secrets backend error, {}", reason),
+        }
+    }
+}
+
+impl Error for SecretsError {}
+
+/// Provides secrets by key from some backing store.
+pub trait SecretsProvider {
+    fn get(&self, key: &str) -> std::result::Result<Secret, SecretsError>;
+}
+
+/// Provides secrets from environment variables, with an optional key prefix.
+///
+/// For example, with the prefix `"BINANCE_"` the key `"API_KEY"` is read from
+/// the `BINANCE_API_KEY` environment variable.
+#[derive(Clone, Debug, Default)]
+pub struct EnvSecretsProvider {
+    pub prefix: Option<String>,
+}
+
+impl EnvSecretsProvider {
+    pub fn new(prefix: Option<&str>) -> Self {
+        EnvSecretsProvider {
+            prefix: prefix.map(|p| p.to_string()),
+        }
+    }
+
+    fn var_name(&self, key: &str) -> String {
+        match &self.prefix {
+            None => key.to_string(),
+            Some(prefix) => format!("{}{}", prefix, key),
+        }
+    }
+}
+
+impl SecretsProvider for EnvSecretsProvider {
+    fn get(&self, key: &str) -> std::result::Result<Secret, SecretsError> {
+        let name = self.var_name(key);
+        match env::var(&name) {
+            Ok(value) => Ok(Secret::new(value)),
+            Err(env::VarError::NotPresent) => Err(SecretsError::NotFound(name)),
+            Err(env::VarError::NotUnicode(_)) => Err(SecretsError::Invalid(
+                name,
+                "value is not valid unicode".to_string(),
+            )),
+        }
+    }
+}
+
+/// Provides secrets from files in a directory, one secret per file named by key.
+///
+/// This matches the layout of mounted Docker and Kubernetes secrets. A single
+/// trailing newline is stripped from the file contents.
+#[derive(Clone, Debug)]
+pub struct FileSecretsProvider {
+    pub dir: PathBuf,
+}
+
+impl FileSecretsProvider {
+    pub fn new(dir: PathBuf) -> Self {
+        FileSecretsProvider { dir }
+    }
+}
+
+impl SecretsProvider for FileSecretsProvider {
+    fn get(&self, key: &str) -> std::result::Result<Secret, SecretsError> {
+        if key.is_empty() || key.contains(['/', '\\']) || key == ".." || key == "." {
+            return Err(SecretsError::Invalid(
+                key.to_string(),
+                "key must be a plain file name".to_string(),
+            ));
+        }
+        let path = self.dir.join(key);
+        if !path.is_file() {
+            return Err(SecretsError::NotFound(path.display().to_string()));
+        }
+        let mut value = fs::read_to_string(&path)
+            .map_err(|e| SecretsError::Invalid(path.display().to_string(), e.to_string()))?;
+        if value.ends_with('\n') {
+            value.pop();
+            if value.ends_with('\r') {
+                value.pop();
+            }
+        }
+        Ok(Secret::new(value))
+    }
+}
+
+/// Provides secrets from the OS keyring (macOS Keychain, Windows Credential
+/// Manager, Linux kernel keyutils) under the given service name.
+#[cfg(feature = "keyring")]
+#[derive(Clone, Debug)]
+pub struct KeyringSecretsProvider {
+    pub service: String,
+}
+
+#[cfg(feature = "keyring")]
+impl KeyringSecretsProvider {
+    pub fn new(service: &str) -> Self {
+        KeyringSecretsProvider {
+            service: service.to_string(),
+        }
+    }
+}
+
+#[cfg(feature = "keyring")]
+impl SecretsProvider for KeyringSecretsProvider {
+    fn get(&self, key: &str) -> std::result::Result<Secret, SecretsError> {
+        let entry = keyring::Entry::new(&self.service, key)
+            .map_err(|e| SecretsError::Backend(e.to_string()))?;
+        match entry.get_password() {
+            Ok(value) => Ok(Secret::new(value)),
+            Err(keyring::Error::NoEntry) => {
+                Err(SecretsError::NotFound(format!("{}/{}", self.service, key)))
+            }
+            Err(e) => Err(SecretsError::Backend(e.to_string())),
+        }
+    }
+}
+
+/// Provides secrets from the first of the given providers which has the key.
+///
+/// Backend errors are returned immediately rather than falling through, so a
+/// misconfigured provider is never silently skipped.
+#[derive(Default)]
+pub struct ChainedSecretsProvider {
+    providers: Vec<Box<dyn SecretsProvider + Send + Sync>>,
+}
+
+impl ChainedSecretsProvider {
+    pub fn new(providers: Vec<Box<dyn SecretsProvider + Send + Sync>>) -> Self {
+        ChainedSecretsProvider { providers }
+    }
+}
+
+impl SecretsProvider for ChainedSecretsProvider {
+    fn get(&self, key: &str) -> std::result::Result<Secret, SecretsError> {
+        for provider in &self.providers {
+            match provider.get(key) {
+                Err(SecretsError::NotFound(_)) => continue,
+                result => return result,
+            }
+        }
+        Err(SecretsError::NotFound(key.to_string()))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("nautilus-secrets-{}-{}", name, std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_secret_is_redacted() {
+        let secret = Secret::from("hunter2");
+
+        assert_eq!(secret.expose(), "hunter2");
+        assert_eq!(secret.to_string(), "***");
+        assert_eq!(format!("{:?}", secret), "Secret(***)");
+    }
+
+    #[test]
+    fn test_env_provider_with_prefix() {
+        env::set_var("NAUTILUS_TEST_SECRETS_API_KEY", "abc123");
+        let provider = EnvSecretsProvider::new(Some("NAUTILUS_TEST_SECRETS_"));
+
+        let secret = provider.get("API_KEY").unwrap();
+
+        assert_eq!(secret.expose(), "abc123");
+    }
+
+    #[test]
+    fn test_env_provider_when_missing() {
+        let provider = EnvSecretsProvider::new(None);
+
+        let result = provider.get("NAUTILUS_TEST_SECRETS_MISSING");
+
+        assert_eq!(
+            result,
+            Err(SecretsError::NotFound(
+                "NAUTILUS_TEST_SECRETS_MISSING".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_file_provider_strips_trailing_newline() {
+        let dir = temp_dir("file");
+        fs::write(dir.join("api_secret"), "s3cr3t\n").unwrap();
+        let provider = FileSecretsProvider::new(dir.clone());
+
+        let secret = provider.get("api_secret").unwrap();
+
+        assert_eq!(secret.expose(), "s3cr3t");
+        assert!(matches!(
+            provider.get("other"),
+            Err(SecretsError::NotFound(_))
+        ));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_file_provider_rejects_path_traversal() {
+        let provider = FileSecretsProvider::new(env::temp_dir());
+
+        for key in ["../etc/passwd", "..", "a\\b", ""] {
+            let result = provider.get(key);
+
+            assert_eq!(
+                result.unwrap_err(),
+                SecretsError::Invalid(key.to_string(), "key must be a plain file name".to_string())
+            );
+        }
+    }
+
+    #[test]
+    fn test_chained_provider_falls_through_not_found() {
+        let dir = temp_dir("chained");
+        fs::write(dir.join("NAUTILUS_TEST_SECRETS_CHAINED"), "from-file").unwrap();
+        let provider = ChainedSecretsProvider::new(vec![
+            Box::new(EnvSecretsProvider::new(None)),
+            Box::new(FileSecretsProvider::new(dir.clone())),
+        ]);
+
+        let secret = provider.get("NAUTILUS_TEST_SECRETS_CHAINED").unwrap();
+
+        assert_eq!(secret.expose(), "from-file");
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
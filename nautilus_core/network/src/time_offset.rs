@@ -0,0 +1,270 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_core::time::unix_timestamp_ms;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicI64, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// Tracks the offset between a venue's server clock and the local clock.
+///
+/// The offset is estimated from a server time response assuming symmetric
+/// latency, i.e. against the midpoint of the request round trip.
+#[derive(Debug, Default)]
+pub struct ServerTimeOffset {
+    offset_ms: AtomicI64,
+    synced: AtomicBool,
+}
+
+impl ServerTimeOffset {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the current estimate of `server_time - local_time` in milliseconds.
+    pub fn offset_ms(&self) -> i64 {
+        self.offset_ms.load(Ordering::SeqCst)
+    }
+
+    /// Returns whether the offset has been set from a server time at least once.
+    pub fn is_synced(&self) -> bool {
+        self.synced.load(Ordering::SeqCst)
+    }
+
+    pub fn set_offset_ms(&self, offset_ms: i64) {
+        self.offset_ms.store(offset_ms, Ordering::SeqCst);
+        self.synced.store(true, Ordering::SeqCst);
+    }
+
+    /// Updates the offset from a server time observed during a request which was
+    /// sent at `sent_ms` and received at `recv_ms` (local UNIX milliseconds).
+    ///
+    /// Returns whether the sample was used; a sample received before it was sent
+    /// (the local clock stepped backwards) is ignored.
+    pub fn update(&self, server_ms: i64, sent_ms: i64, recv_ms: i64) -> bool {
+        if recv_ms < sent_ms {
+            return false;
+        }
+        let midpoint_ms = sent_ms + (recv_ms - sent_ms) / 2;
+        self.set_offset_ms(server_ms - midpoint_ms);
+        true
+    }
+
+    /// Returns the local timestamp adjusted to the server clock.
+    pub fn adjust_ms(&self, local_ms: i64) -> i64 {
+        local_ms + self.offset_ms()
+    }
+
+    /// Returns the current time on the server clock in UNIX milliseconds.
+    pub fn now_ms(&self) -> i64 {
+        self.adjust_ms(unix_timestamp_ms())
+    }
+}
+
+/// Tracks server time offsets per venue, and recognizes clock-skew and
+/// nonce-reuse rejections.
+///
+/// When a venue rejects a request due to clock skew, the offset should be
+/// re-synced from the server time and the request re-signed with an adjusted
+/// timestamp; `on_response` performs the bookkeeping for that flow. A rejected
+/// nonce is instead recovered with `NonceGenerator::advance_past`.
+#[derive(Debug, Default)]
+pub struct ServerTimeRegistry {
+    offsets: Mutex<HashMap<String, Arc<ServerTimeOffset>>>,
+    skew_patterns: HashMap<String, Vec<String>>,
+    nonce_patterns: HashMap<String, Vec<String>>,
+}
+
+impl ServerTimeRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a registry with the clock-skew error patterns of known venues.
+    pub fn with_defaults() -> Self {
+        let mut registry = Self::new();
+        registry.add_skew_pattern("BINANCE", "\"code\":-1021");
+        registry.add_skew_pattern("BYBIT", "\"ret_code\":10002");
+        registry.add_skew_pattern("FTX", "Invalid timestamp");
+        registry.add_nonce_pattern("KRAKEN", "EAPI:Invalid nonce");
+        registry
+    }
+
+    /// Adds a substring which identifies a clock-skew error response for the venue.
+    pub fn add_skew_pattern(&mut self, venue: &str, pattern: &str) {
+        self.skew_patterns
+            .entry(venue.to_string())
+            .or_default()
+            .push(pattern.to_string());
+    }
+
+    /// Adds a substring which identifies a nonce-reuse error response for the venue.
+    pub fn add_nonce_pattern(&mut self, venue: &str, pattern: &str) {
+        self.nonce_patterns
+            .entry(venue.to_string())
+            .or_default()
+            .push(pattern.to_string());
+    }
+
+    /// Returns the shared offset tracker for the venue (created if not yet tracked).
+    pub fn get(&self, venue: &str) -> Arc<ServerTimeOffset> {
+        self.offsets
+            .lock()
+            .expect("server time registry lock poisoned")
+            .entry(venue.to_string())
+            .or_default()
+            .clone()
+    }
+
+    /// Returns the local timestamp adjusted to the venue's server clock.
+    pub fn adjust_ms(&self, venue: &str, local_ms: i64) -> i64 {
+        self.get(venue).adjust_ms(local_ms)
+    }
+
+    /// Returns whether the response body indicates a clock-skew rejection.
+    pub fn is_skew_error(&self, venue: &str, body: &str) -> bool {
+        matches_any(&self.skew_patterns, venue, body)
+    }
+
+    /// Returns whether the response body indicates a nonce-reuse rejection.
+    pub fn is_nonce_error(&self, venue: &str, body: &str) -> bool {
+        matches_any(&self.nonce_patterns, venue, body)
+    }
+
+    /// Handles a response from the venue, returning whether the request should
+    /// be retried with a fresh timestamp.
+    ///
+    /// If the response is a clock-skew rejection and the server time is known
+    /// (from a header or a follow-up time request) the offset is re-synced.
+    pub fn on_response(
+        &self,
+        venue: &str,
+        body: &str,
+        server_ms: Option<i64>,
+        sent_ms: i64,
+        recv_ms: i64,
+    ) -> bool {
+        if !self.is_skew_error(venue, body) {
+            return false;
+        }
+        if let Some(server_ms) = server_ms {
+            self.get(venue).update(server_ms, sent_ms, recv_ms);
+        }
+        true
+    }
+}
+
+fn matches_any(patterns: &HashMap<String, Vec<String>>, venue: &str, body: &str) -> bool {
+    match patterns.get(venue) {
+        None => false,
+        Some(patterns) => patterns.iter().any(|p| body.contains(p.as_str())),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_offset_defaults() {
+        let offset = ServerTimeOffset::new();
+
+        assert_eq!(offset.offset_ms(), 0);
+        assert!(!offset.is_synced());
+        assert_eq!(offset.adjust_ms(1_000), 1_000);
+    }
+
+    #[test]
+    fn test_offset_update_uses_round_trip_midpoint() {
+        let offset = ServerTimeOffset::new();
+
+        offset.update(10_500, 1_000, 1_200);
+
+        assert!(offset.is_synced());
+        assert_eq!(offset.offset_ms(), 9_400);
+        assert_eq!(offset.adjust_ms(2_000), 11_400);
+    }
+
+    #[test]
+    fn test_offset_when_server_behind() {
+        let offset = ServerTimeOffset::new();
+
+        offset.update(500, 1_000, 1_000);
+
+        assert_eq!(offset.offset_ms(), -500);
+        assert!(offset.now_ms() < unix_timestamp_ms());
+    }
+
+    #[test]
+    fn test_offset_update_ignores_reversed_round_trip() {
+        let offset = ServerTimeOffset::new();
+
+        let used = offset.update(10_500, 1_200, 1_000);
+
+        assert!(!used);
+        assert!(!offset.is_synced());
+        assert_eq!(offset.offset_ms(), 0);
+    }
+
+    #[test]
+    fn test_registry_shares_offsets_per_venue() {
+        let registry = ServerTimeRegistry::new();
+
+        registry.get("BINANCE").set_offset_ms(250);
+
+        assert_eq!(registry.get("BINANCE").offset_ms(), 250);
+        assert_eq!(registry.get("FTX").offset_ms(), 0);
+        assert_eq!(registry.adjust_ms("BINANCE", 1_000), 1_250);
+    }
+
+    #[test]
+    fn test_registry_detects_skew_errors() {
+        let registry = ServerTimeRegistry::with_defaults();
+        let body =
+            r#"{"code":-1021,"msg":"Timestamp for this request is outside of the recvWindow."}"#;
+
+        assert!(registry.is_skew_error("BINANCE", body));
+        assert!(!registry.is_skew_error("BINANCE", r#"{"code":-2010}"#));
+        assert!(!registry.is_skew_error("UNKNOWN", body));
+    }
+
+    #[test]
+    fn test_registry_detects_nonce_errors() {
+        let registry = ServerTimeRegistry::with_defaults();
+        let body = r#"{"error":["EAPI:Invalid nonce"]}"#;
+
+        assert!(registry.is_nonce_error("KRAKEN", body));
+        assert!(!registry.is_skew_error("KRAKEN", body));
+        assert!(!registry.is_nonce_error("BINANCE", body));
+    }
+
+    #[test]
+    fn test_registry_on_response_resyncs_offset() {
+        let registry = ServerTimeRegistry::with_defaults();
+
+        let body = r#"{"code":-1021}"#;
+
+        let retry = registry.on_response("BINANCE", body, Some(5_100), 1_000, 1_200);
+
+        assert!(retry);
+        assert_eq!(registry.get("BINANCE").offset_ms(), 4_000);
+        assert!(!registry.on_response("BINANCE", "{}", Some(0), 0, 0));
+        assert!(!registry.on_response("KRAKEN", "EAPI:Invalid nonce", Some(0), 0, 0));
+        assert_eq!(registry.get("BINANCE").offset_ms(), 4_000);
+    }
+}
@@ -0,0 +1,62 @@
+-- Execution and account history schema
+
+CREATE TABLE IF NOT EXISTS orders (
+    client_order_id TEXT PRIMARY KEY,
+    trader_id TEXT NOT NULL,
+    strategy_id TEXT NOT NULL,
+    instrument_id TEXT NOT NULL,
+    venue_order_id TEXT,
+    last_event TEXT NOT NULL,
+    ts_last BIGINT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS order_events (
+    event_id TEXT PRIMARY KEY,
+    client_order_id TEXT NOT NULL REFERENCES orders (client_order_id),
+    event_type TEXT NOT NULL,
+    payload BYTEA NOT NULL,
+    ts_event BIGINT NOT NULL,
+    ts_init BIGINT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS order_events_client_order_id_idx
+    ON order_events (client_order_id, ts_event);
+
+CREATE TABLE IF NOT EXISTS fills (
+    trade_id TEXT NOT NULL,
+    client_order_id TEXT NOT NULL REFERENCES orders (client_order_id),
+    venue_order_id TEXT NOT NULL,
+    position_id TEXT,
+    account_id TEXT NOT NULL,
+    instrument_id TEXT NOT NULL,
+    order_side TEXT NOT NULL,
+    last_qty NUMERIC NOT NULL,
+    last_px NUMERIC NOT NULL,
+    commission NUMERIC NOT NULL,
+    commission_currency TEXT NOT NULL,
+    liquidity_side TEXT NOT NULL,
+    ts_event BIGINT NOT NULL,
+    PRIMARY KEY (instrument_id, trade_id, client_order_id)
+);
+
+CREATE INDEX IF NOT EXISTS fills_account_id_idx ON fills (account_id, ts_event);
+
+CREATE TABLE IF NOT EXISTS account_states (
+    event_id TEXT PRIMARY KEY,
+    account_id TEXT NOT NULL,
+    account_type TEXT NOT NULL,
+    base_currency TEXT,
+    is_reported BOOLEAN NOT NULL,
+    ts_event BIGINT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS account_states_account_id_idx ON account_states (account_id, ts_event);
+
+CREATE TABLE IF NOT EXISTS account_balances (
+    event_id TEXT NOT NULL REFERENCES account_states (event_id),
+    currency TEXT NOT NULL,
+    total NUMERIC NOT NULL,
+    locked NUMERIC NOT NULL,
+    free NUMERIC NOT NULL,
+    PRIMARY KEY (event_id, currency)
+);
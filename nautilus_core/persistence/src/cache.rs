@@ -0,0 +1,396 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::collections::{BTreeMap, HashMap};
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result};
+
+const CURRENCIES: &str = "Currencies";
+const INSTRUMENTS: &str = "Instruments";
+const ACCOUNTS: &str = "Accounts";
+const ORDERS: &str = "Orders";
+const POSITIONS: &str = "Positions";
+const STRATEGIES: &str = "Strategies";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CacheDatabaseError {
+    /// The underlying store failed.
+    Backend(String),
+    /// The stored data could not be decoded.
+    Corrupt(String),
+}
+
+impl Display for CacheDatabaseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            CacheDatabaseError::Backend(reason) => write!(f, "Cache database error, {}", reason),
+            CacheDatabaseError::Corrupt(reason) => {
+                write!(f, "Cache database data corrupt, {}", reason)
+            }
+        }
+    }
+}
+
+impl Error for CacheDatabaseError {}
+
+pub type CacheResult<T> = std::result::Result<T, CacheDatabaseError>;
+
+/// Provides the storage primitives used by the cache database: plain values,
+/// append-only lists and hashes, all keyed by string (mirroring Redis).
+pub trait KeyValueStore {
+    fn set(&mut self, key: &str, value: &[u8]) -> CacheResult<()>;
+    fn get(&self, key: &str) -> CacheResult<Option<Vec<u8>>>;
+    /// Appends the value to the list at `key`, returning the new list length.
+    fn push(&mut self, key: &str, value: &[u8]) -> CacheResult<usize>;
+    fn range(&self, key: &str) -> CacheResult<Vec<Vec<u8>>>;
+    fn hset(&mut self, key: &str, field: &str, value: &[u8]) -> CacheResult<()>;
+    fn hgetall(&self, key: &str) -> CacheResult<HashMap<String, Vec<u8>>>;
+    /// Returns all keys (of any kind) starting with `prefix`, sorted.
+    fn keys(&self, prefix: &str) -> CacheResult<Vec<String>>;
+    fn delete(&mut self, key: &str) -> CacheResult<()>;
+    fn flush(&mut self) -> CacheResult<()>;
+}
+
+/// Provides an in-memory `KeyValueStore`, for testing and backtesting.
+#[derive(Debug, Default)]
+pub struct MemoryStore {
+    values: BTreeMap<String, Vec<u8>>,
+    lists: BTreeMap<String, Vec<Vec<u8>>>,
+    hashes: BTreeMap<String, HashMap<String, Vec<u8>>>,
+}
+
+impl MemoryStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl KeyValueStore for MemoryStore {
+    fn set(&mut self, key: &str, value: &[u8]) -> CacheResult<()> {
+        self.values.insert(key.to_string(), value.to_vec());
+        Ok(())
+    }
+
+    fn get(&self, key: &str) -> CacheResult<Option<Vec<u8>>> {
+        Ok(self.values.get(key).cloned())
+    }
+
+    fn push(&mut self, key: &str, value: &[u8]) -> CacheResult<usize> {
+        let list = self.lists.entry(key.to_string()).or_default();
+        list.push(value.to_vec());
+        Ok(list.len())
+    }
+
+    fn range(&self, key: &str) -> CacheResult<Vec<Vec<u8>>> {
+        Ok(self.lists.get(key).cloned().unwrap_or_default())
+    }
+
+    fn hset(&mut self, key: &str, field: &str, value: &[u8]) -> CacheResult<()> {
+        self.hashes
+            .entry(key.to_string())
+            .or_default()
+            .insert(field.to_string(), value.to_vec());
+        Ok(())
+    }
+
+    fn hgetall(&self, key: &str) -> CacheResult<HashMap<String, Vec<u8>>> {
+        Ok(self.hashes.get(key).cloned().unwrap_or_default())
+    }
+
+    fn keys(&self, prefix: &str) -> CacheResult<Vec<String>> {
+        let mut keys: Vec<String> = self
+            .values
+            .keys()
+            .chain(self.lists.keys())
+            .chain(self.hashes.keys())
+            .filter(|k| k.starts_with(prefix))
+            .cloned()
+            .collect();
+        keys.sort();
+        keys.dedup();
+        Ok(keys)
+    }
+
+    fn delete(&mut self, key: &str) -> CacheResult<()> {
+        self.values.remove(key);
+        self.lists.remove(key);
+        self.hashes.remove(key);
+        Ok(())
+    }
+
+    fn flush(&mut self) -> CacheResult<()> {
+        self.values.clear();
+        self.lists.clear();
+        self.hashes.clear();
+        Ok(())
+    }
+}
+
+/// Provides the cache database for a trader over any `KeyValueStore`.
+///
+/// Keys follow the same layout as the Redis cache database, e.g.
+/// `Trader-TESTER-000:Orders:O-123`, so stores are interchangeable. Values are
+/// the serialized objects and events, as produced by the configured serializer.
+pub struct CacheDatabase<S: KeyValueStore> {
+    store: S,
+    key_currencies: String,
+    key_instruments: String,
+    key_accounts: String,
+    key_orders: String,
+    key_positions: String,
+    key_strategies: String,
+}
+
+impl<S: KeyValueStore> CacheDatabase<S> {
+    pub fn new(trader_id: &str, store: S) -> Self {
+        let key_trader = format!("Trader-{}", trader_id);
+        CacheDatabase {
+            store,
+            key_currencies: format!("{}:{}:", key_trader, CURRENCIES),
+            key_instruments: format!("{}:{}:", key_trader, INSTRUMENTS),
+            key_accounts: format!("{}:{}:", key_trader, ACCOUNTS),
+            key_orders: format!("{}:{}:", key_trader, ORDERS),
+            key_positions: format!("{}:{}:", key_trader, POSITIONS),
+            key_strategies: format!("{}:{}:", key_trader, STRATEGIES),
+        }
+    }
+
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    pub fn flush(&mut self) -> CacheResult<()> {
+        self.store.flush()
+    }
+
+    fn ids(&self, prefix: &str) -> CacheResult<Vec<String>> {
+        Ok(self
+            .store
+            .keys(prefix)?
+            .into_iter()
+            .map(|k| k[prefix.len()..].to_string())
+            .filter(|id| !id.contains(':'))
+            .collect())
+    }
+
+    fn load_all_events(&self, prefix: &str) -> CacheResult<HashMap<String, Vec<Vec<u8>>>> {
+        let mut result = HashMap::new();
+        for id in self.ids(prefix)? {
+            let events = self.store.range(&format!("{}{}", prefix, id))?;
+            result.insert(id, events);
+        }
+        Ok(result)
+    }
+
+    pub fn add_currency(
+        &mut self,
+        code: &str,
+        fields: &HashMap<String, Vec<u8>>,
+    ) -> CacheResult<()> {
+        let key = format!("{}{}", self.key_currencies, code);
+        for (field, value) in fields {
+            self.store.hset(&key, field, value)?;
+        }
+        Ok(())
+    }
+
+    pub fn load_currency(&self, code: &str) -> CacheResult<HashMap<String, Vec<u8>>> {
+        self.store
+            .hgetall(&format!("{}{}", self.key_currencies, code))
+    }
+
+    pub fn load_currencies(&self) -> CacheResult<HashMap<String, HashMap<String, Vec<u8>>>> {
+        let mut result = HashMap::new();
+        for code in self.ids(&self.key_currencies)? {
+            let fields = self.load_currency(&code)?;
+            result.insert(code, fields);
+        }
+        Ok(result)
+    }
+
+    pub fn add_instrument(&mut self, instrument_id: &str, instrument: &[u8]) -> CacheResult<()> {
+        self.store.set(
+            &format!("{}{}", self.key_instruments, instrument_id),
+            instrument,
+        )
+    }
+
+    pub fn load_instrument(&self, instrument_id: &str) -> CacheResult<Option<Vec<u8>>> {
+        self.store
+            .get(&format!("{}{}", self.key_instruments, instrument_id))
+    }
+
+    pub fn load_instruments(&self) -> CacheResult<HashMap<String, Vec<u8>>> {
+        let mut result = HashMap::new();
+        for id in self.ids(&self.key_instruments)? {
+            if let Some(instrument) = self.load_instrument(&id)? {
+                result.insert(id, instrument);
+            }
+        }
+        Ok(result)
+    }
+
+    /// Appends the account state event, returning the number of events stored.
+    pub fn update_account(&mut self, account_id: &str, event: &[u8]) -> CacheResult<usize> {
+        self.store
+            .push(&format!("{}{}", self.key_accounts, account_id), event)
+    }
+
+    pub fn load_account(&self, account_id: &str) -> CacheResult<Vec<Vec<u8>>> {
+        self.store
+            .range(&format!("{}{}", self.key_accounts, account_id))
+    }
+
+    pub fn load_accounts(&self) -> CacheResult<HashMap<String, Vec<Vec<u8>>>> {
+        self.load_all_events(&self.key_accounts)
+    }
+
+    /// Appends the order event, returning the number of events stored.
+    pub fn update_order(&mut self, client_order_id: &str, event: &[u8]) -> CacheResult<usize> {
+        self.store
+            .push(&format!("{}{}", self.key_orders, client_order_id), event)
+    }
+
+    pub fn load_order(&self, client_order_id: &str) -> CacheResult<Vec<Vec<u8>>> {
+        self.store
+            .range(&format!("{}{}", self.key_orders, client_order_id))
+    }
+
+    pub fn load_orders(&self) -> CacheResult<HashMap<String, Vec<Vec<u8>>>> {
+        self.load_all_events(&self.key_orders)
+    }
+
+    /// Appends the position event, returning the number of events stored.
+    pub fn update_position(&mut self, position_id: &str, event: &[u8]) -> CacheResult<usize> {
+        self.store
+            .push(&format!("{}{}", self.key_positions, position_id), event)
+    }
+
+    pub fn load_position(&self, position_id: &str) -> CacheResult<Vec<Vec<u8>>> {
+        self.store
+            .range(&format!("{}{}", self.key_positions, position_id))
+    }
+
+    pub fn load_positions(&self) -> CacheResult<HashMap<String, Vec<Vec<u8>>>> {
+        self.load_all_events(&self.key_positions)
+    }
+
+    pub fn update_strategy(
+        &mut self,
+        strategy_id: &str,
+        state: &HashMap<String, Vec<u8>>,
+    ) -> CacheResult<()> {
+        let key = format!("{}{}:State", self.key_strategies, strategy_id);
+        for (field, value) in state {
+            self.store.hset(&key, field, value)?;
+        }
+        Ok(())
+    }
+
+    pub fn load_strategy(&self, strategy_id: &str) -> CacheResult<HashMap<String, Vec<u8>>> {
+        self.store
+            .hgetall(&format!("{}{}:State", self.key_strategies, strategy_id))
+    }
+
+    pub fn delete_strategy(&mut self, strategy_id: &str) -> CacheResult<()> {
+        self.store
+            .delete(&format!("{}{}:State", self.key_strategies, strategy_id))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// Asserts the behaviour every `KeyValueStore` must provide.
+    pub(crate) fn assert_store_contract<S: KeyValueStore>(store: S) {
+        let mut db = CacheDatabase::new("TESTER-000", store);
+        let mut usd = HashMap::new();
+        usd.insert("precision".to_string(), b"2".to_vec());
+        usd.insert("iso4217".to_string(), b"840".to_vec());
+
+        db.add_currency("USD", &usd).unwrap();
+        db.add_instrument("AUD/USD.SIM", b"instrument").unwrap();
+        assert_eq!(db.update_order("O-1", b"initialized").unwrap(), 1);
+        assert_eq!(db.update_order("O-1", b"submitted").unwrap(), 2);
+        db.update_order("O-2", b"initialized").unwrap();
+        db.update_account("SIM-001", b"state").unwrap();
+        db.update_position("P-1", b"filled").unwrap();
+        let mut state = HashMap::new();
+        state.insert("count".to_string(), b"1".to_vec());
+        db.update_strategy("S-001", &state).unwrap();
+
+        assert_eq!(db.load_currencies().unwrap()["USD"], usd);
+        assert_eq!(
+            db.load_instrument("AUD/USD.SIM").unwrap(),
+            Some(b"instrument".to_vec())
+        );
+        assert_eq!(db.load_instruments().unwrap().len(), 1);
+        assert_eq!(
+            db.load_order("O-1").unwrap(),
+            vec![b"initialized".to_vec(), b"submitted".to_vec()]
+        );
+        assert_eq!(db.load_orders().unwrap().len(), 2);
+        assert_eq!(
+            db.load_accounts().unwrap()["SIM-001"],
+            vec![b"state".to_vec()]
+        );
+        assert_eq!(
+            db.load_positions().unwrap()["P-1"],
+            vec![b"filled".to_vec()]
+        );
+        assert_eq!(db.load_strategy("S-001").unwrap(), state);
+        assert!(db.load_order("O-3").unwrap().is_empty());
+        assert_eq!(db.load_instrument("ETH/USD.SIM").unwrap(), None);
+
+        db.delete_strategy("S-001").unwrap();
+        assert!(db.load_strategy("S-001").unwrap().is_empty());
+
+        db.flush().unwrap();
+        assert!(db.load_orders().unwrap().is_empty());
+        assert!(db.load_currencies().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_memory_store_contract() {
+        assert_store_contract(MemoryStore::new());
+    }
+
+    #[test]
+    fn test_keys_use_redis_layout() {
+        let mut db = CacheDatabase::new("TESTER-000", MemoryStore::new());
+
+        db.update_order("O-1", b"initialized").unwrap();
+
+        assert_eq!(
+            db.store().keys("").unwrap(),
+            vec!["Trader-TESTER-000:Orders:O-1".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_traders_are_isolated() {
+        let mut db = CacheDatabase::new("TESTER-000", MemoryStore::new());
+        db.update_order("O-1", b"initialized").unwrap();
+        let store = db.store;
+
+        let other = CacheDatabase::new("TESTER-001", store);
+
+        assert!(other.load_orders().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,309 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Structured differences between two cache database snapshots, for tests,
+//! reconciliation and live versus replay validation.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{Display, Formatter, Result};
+
+use crate::cache::{CacheDatabase, CacheResult, KeyValueStore};
+
+/// Represents the kind of object in a snapshot.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SnapshotKind {
+    Currency,
+    Instrument,
+    Account,
+    Order,
+    Position,
+}
+
+impl Display for SnapshotKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let name = match self {
+            SnapshotKind::Currency => "Currency",
+            SnapshotKind::Instrument => "Instrument",
+            SnapshotKind::Account => "Account",
+            SnapshotKind::Order => "Order",
+            SnapshotKind::Position => "Position",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Represents the state of a cache database at a point in time.
+///
+/// Accounts (balances), orders and positions are their event logs; currencies
+/// are their field hashes and instruments their serialized values.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CacheSnapshot {
+    pub currencies: BTreeMap<String, BTreeMap<String, Vec<u8>>>,
+    pub instruments: BTreeMap<String, Vec<u8>>,
+    pub accounts: BTreeMap<String, Vec<Vec<u8>>>,
+    pub orders: BTreeMap<String, Vec<Vec<u8>>>,
+    pub positions: BTreeMap<String, Vec<Vec<u8>>>,
+}
+
+impl CacheSnapshot {
+    /// Returns a snapshot of the database's current state.
+    pub fn from_database<S: KeyValueStore>(db: &CacheDatabase<S>) -> CacheResult<Self> {
+        Ok(CacheSnapshot {
+            currencies: db
+                .load_currencies()?
+                .into_iter()
+                .map(|(code, fields)| (code, fields.into_iter().collect()))
+                .collect(),
+            instruments: db.load_instruments()?.into_iter().collect(),
+            accounts: db.load_accounts()?.into_iter().collect(),
+            orders: db.load_orders()?.into_iter().collect(),
+            positions: db.load_positions()?.into_iter().collect(),
+        })
+    }
+}
+
+/// Represents how an object differs from the left to the right snapshot.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SnapshotChange {
+    /// Only present in the right snapshot.
+    Added,
+    /// Only present in the left snapshot.
+    Removed,
+    /// The value (or currency fields) differ.
+    Modified,
+    /// The right event log extends the left by `count` events.
+    EventsAppended { count: usize },
+    /// The right event log is missing the last `count` left events.
+    EventsTruncated { count: usize },
+    /// The event logs differ from event `index` onwards.
+    EventsDiverged { index: usize },
+}
+
+impl Display for SnapshotChange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            SnapshotChange::Added => write!(f, "added"),
+            SnapshotChange::Removed => write!(f, "removed"),
+            SnapshotChange::Modified => write!(f, "modified"),
+            SnapshotChange::EventsAppended { count } => write!(f, "{} events appended", count),
+            SnapshotChange::EventsTruncated { count } => {
+                write!(f, "{} events truncated", count)
+            }
+            SnapshotChange::EventsDiverged { index } => {
+                write!(f, "events diverged at index {}", index)
+            }
+        }
+    }
+}
+
+/// Represents a difference for a single object.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SnapshotDifference {
+    pub kind: SnapshotKind,
+    pub id: String,
+    pub change: SnapshotChange,
+}
+
+/// Represents the differences between two snapshots, ordered by kind then ID.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SnapshotDiff {
+    pub differences: Vec<SnapshotDifference>,
+}
+
+impl SnapshotDiff {
+    pub fn is_empty(&self) -> bool {
+        self.differences.is_empty()
+    }
+
+    /// Returns the differences for the given kind of object.
+    pub fn of_kind(&self, kind: SnapshotKind) -> impl Iterator<Item = &SnapshotDifference> {
+        self.differences.iter().filter(move |d| d.kind == kind)
+    }
+}
+
+impl Display for SnapshotDiff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        if self.is_empty() {
+            return write!(f, "Snapshots identical");
+        }
+        writeln!(f, "{} differences:", self.differences.len())?;
+        for d in &self.differences {
+            writeln!(f, "  {} {}: {}", d.kind, d.id, d.change)?;
+        }
+        Ok(())
+    }
+}
+
+fn diff_maps<V>(
+    kind: SnapshotKind,
+    left: &BTreeMap<String, V>,
+    right: &BTreeMap<String, V>,
+    compare: impl Fn(&V, &V) -> Option<SnapshotChange>,
+    differences: &mut Vec<SnapshotDifference>,
+) {
+    let ids: BTreeSet<&String> = left.keys().chain(right.keys()).collect();
+    for id in ids {
+        let change = match (left.get(id), right.get(id)) {
+            (Some(l), Some(r)) => compare(l, r),
+            (Some(_), None) => Some(SnapshotChange::Removed),
+            (None, Some(_)) => Some(SnapshotChange::Added),
+            (None, None) => None,
+        };
+        if let Some(change) = change {
+            differences.push(SnapshotDifference {
+                kind,
+                id: id.clone(),
+                change,
+            });
+        }
+    }
+}
+
+fn compare_values<V: PartialEq>(left: &V, right: &V) -> Option<SnapshotChange> {
+    (left != right).then_some(SnapshotChange::Modified)
+}
+
+fn compare_events(left: &[Vec<u8>], right: &[Vec<u8>]) -> Option<SnapshotChange> {
+    match left.iter().zip(right.iter()).position(|(l, r)| l != r) {
+        Some(index) => Some(SnapshotChange::EventsDiverged { index }),
+        None if right.len() > left.len() => Some(SnapshotChange::EventsAppended {
+            count: right.len() - left.len(),
+        }),
+        None if left.len() > right.len() => Some(SnapshotChange::EventsTruncated {
+            count: left.len() - right.len(),
+        }),
+        None => None,
+    }
+}
+
+/// Returns the differences from the `left` to the `right` snapshot.
+pub fn diff_snapshots(left: &CacheSnapshot, right: &CacheSnapshot) -> SnapshotDiff {
+    let mut differences = Vec::new();
+    diff_maps(
+        SnapshotKind::Currency,
+        &left.currencies,
+        &right.currencies,
+        compare_values,
+        &mut differences,
+    );
+    diff_maps(
+        SnapshotKind::Instrument,
+        &left.instruments,
+        &right.instruments,
+        compare_values,
+        &mut differences,
+    );
+    for (kind, l, r) in [
+        (SnapshotKind::Account, &left.accounts, &right.accounts),
+        (SnapshotKind::Order, &left.orders, &right.orders),
+        (SnapshotKind::Position, &left.positions, &right.positions),
+    ] {
+        diff_maps(kind, l, r, |l, r| compare_events(l, r), &mut differences);
+    }
+    SnapshotDiff { differences }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::MemoryStore;
+
+    fn database() -> CacheDatabase<MemoryStore> {
+        let mut db = CacheDatabase::new("TESTER-000", MemoryStore::new());
+        db.add_instrument("AUD/USD.SIM", b"instrument").unwrap();
+        db.update_account("SIM-001", b"state-1").unwrap();
+        db.update_order("O-1", b"initialized").unwrap();
+        db.update_order("O-2", b"initialized").unwrap();
+        db.update_position("P-1", b"opened").unwrap();
+        db
+    }
+
+    #[test]
+    fn test_identical_snapshots() {
+        let db = database();
+        let left = CacheSnapshot::from_database(&db).unwrap();
+        let right = CacheSnapshot::from_database(&db).unwrap();
+
+        let diff = diff_snapshots(&left, &right);
+
+        assert!(diff.is_empty());
+        assert_eq!(diff.to_string(), "Snapshots identical");
+    }
+
+    #[test]
+    fn test_diff_reports_changes() {
+        let mut db = database();
+        let left = CacheSnapshot::from_database(&db).unwrap();
+        db.add_instrument("AUD/USD.SIM", b"instrument-v2").unwrap();
+        db.update_account("SIM-001", b"state-2").unwrap();
+        db.update_order("O-3", b"initialized").unwrap();
+        db.update_order("O-1", b"submitted").unwrap();
+        db.update_order("O-1", b"accepted").unwrap();
+        let right = CacheSnapshot::from_database(&db).unwrap();
+
+        let diff = diff_snapshots(&left, &right);
+
+        assert_eq!(
+            diff.differences,
+            vec![
+                SnapshotDifference {
+                    kind: SnapshotKind::Instrument,
+                    id: "AUD/USD.SIM".to_string(),
+                    change: SnapshotChange::Modified,
+                },
+                SnapshotDifference {
+                    kind: SnapshotKind::Account,
+                    id: "SIM-001".to_string(),
+                    change: SnapshotChange::EventsAppended { count: 1 },
+                },
+                SnapshotDifference {
+                    kind: SnapshotKind::Order,
+                    id: "O-1".to_string(),
+                    change: SnapshotChange::EventsAppended { count: 2 },
+                },
+                SnapshotDifference {
+                    kind: SnapshotKind::Order,
+                    id: "O-3".to_string(),
+                    change: SnapshotChange::Added,
+                },
+            ]
+        );
+        assert_eq!(diff.of_kind(SnapshotKind::Order).count(), 2);
+    }
+
+    #[test]
+    fn test_diff_reports_divergence_and_removal() {
+        let live = CacheSnapshot::from_database(&database()).unwrap();
+        let mut replay = live.clone();
+        replay
+            .orders
+            .insert("O-1".to_string(), vec![b"rejected".to_vec()]);
+        replay.positions.remove("P-1");
+        replay.accounts.insert("SIM-001".to_string(), Vec::new());
+
+        let diff = diff_snapshots(&live, &replay);
+
+        assert_eq!(
+            diff.to_string(),
+            "3 differences:\n  \
+             Account SIM-001: 1 events truncated\n  \
+             Order O-1: events diverged at index 0\n  \
+             Position P-1: removed\n"
+        );
+    }
+}
@@ -0,0 +1,221 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use crate::cache::{CacheDatabaseError, CacheResult, KeyValueStore};
+use sled::{Db, Tree};
+use std::collections::HashMap;
+use std::path::Path;
+
+const SEP: u8 = 0;
+
+fn backend_err(e: sled::Error) -> CacheDatabaseError {
+    CacheDatabaseError::Backend(e.to_string())
+}
+
+fn prefixed(key: &str) -> Vec<u8> {
+    let mut bytes = key.as_bytes().to_vec();
+    bytes.push(SEP);
+    bytes
+}
+
+fn base_key(entry: &[u8]) -> CacheResult<String> {
+    let end = entry.iter().position(|b| *b == SEP).unwrap_or(entry.len());
+    String::from_utf8(entry[..end].to_vec()).map_err(|e| CacheDatabaseError::Corrupt(e.to_string()))
+}
+
+/// Provides an embedded persistent `KeyValueStore` backed by sled, for
+/// single-host deployments without an external Redis instance.
+///
+/// List items are stored under `{key}\0{index}` (big-endian index so items
+/// iterate in insertion order) and hash fields under `{key}\0{field}`.
+pub struct SledStore {
+    db: Db,
+    values: Tree,
+    lists: Tree,
+    hashes: Tree,
+}
+
+impl SledStore {
+    pub fn open(path: &Path) -> CacheResult<Self> {
+        let db = sled::open(path).map_err(backend_err)?;
+        Self::from_db(db)
+    }
+
+    /// Returns a store backed by a temporary database removed on drop.
+    pub fn temporary() -> CacheResult<Self> {
+        let db = sled::Config::new()
+            .temporary(true)
+            .open()
+            .map_err(backend_err)?;
+        Self::from_db(db)
+    }
+
+    fn from_db(db: Db) -> CacheResult<Self> {
+        Ok(SledStore {
+            values: db.open_tree("values").map_err(backend_err)?,
+            lists: db.open_tree("lists").map_err(backend_err)?,
+            hashes: db.open_tree("hashes").map_err(backend_err)?,
+            db,
+        })
+    }
+
+    /// Flushes all dirty buffers to disk.
+    pub fn sync(&self) -> CacheResult<()> {
+        self.db.flush().map(|_| ()).map_err(backend_err)
+    }
+
+    fn remove_prefix(tree: &Tree, prefix: &[u8]) -> CacheResult<()> {
+        for entry in tree.scan_prefix(prefix).keys() {
+            tree.remove(entry.map_err(backend_err)?)
+                .map_err(backend_err)?;
+        }
+        Ok(())
+    }
+}
+
+impl KeyValueStore for SledStore {
+    fn set(&mut self, key: &str, value: &[u8]) -> CacheResult<()> {
+        self.values
+            .insert(key, value)
+            .map(|_| ())
+            .map_err(backend_err)
+    }
+
+    fn get(&self, key: &str) -> CacheResult<Option<Vec<u8>>> {
+        Ok(self
+            .values
+            .get(key)
+            .map_err(backend_err)?
+            .map(|v| v.to_vec()))
+    }
+
+    fn push(&mut self, key: &str, value: &[u8]) -> CacheResult<usize> {
+        let prefix = prefixed(key);
+        let index = match self.lists.scan_prefix(&prefix).keys().next_back() {
+            None => 0,
+            Some(last) => {
+                let last = last.map_err(backend_err)?;
+                let bytes: [u8; 8] = last[prefix.len()..].try_into().map_err(|_| {
+                    CacheDatabaseError::Corrupt(format!("list index for '{}'", key))
+                })?;
+                u64::from_be_bytes(bytes) + 1
+            }
+        };
+        let mut entry = prefix;
+        entry.extend_from_slice(&index.to_be_bytes());
+        self.lists.insert(entry, value).map_err(backend_err)?;
+        Ok(index as usize + 1)
+    }
+
+    fn range(&self, key: &str) -> CacheResult<Vec<Vec<u8>>> {
+        self.lists
+            .scan_prefix(prefixed(key))
+            .values()
+            .map(|v| v.map(|v| v.to_vec()).map_err(backend_err))
+            .collect()
+    }
+
+    fn hset(&mut self, key: &str, field: &str, value: &[u8]) -> CacheResult<()> {
+        let mut entry = prefixed(key);
+        entry.extend_from_slice(field.as_bytes());
+        self.hashes
+            .insert(entry, value)
+            .map(|_| ())
+            .map_err(backend_err)
+    }
+
+    fn hgetall(&self, key: &str) -> CacheResult<HashMap<String, Vec<u8>>> {
+        let prefix = prefixed(key);
+        let mut result = HashMap::new();
+        for item in self.hashes.scan_prefix(&prefix) {
+            let (entry, value) = item.map_err(backend_err)?;
+            let field = String::from_utf8(entry[prefix.len()..].to_vec())
+                .map_err(|e| CacheDatabaseError::Corrupt(e.to_string()))?;
+            result.insert(field, value.to_vec());
+        }
+        Ok(result)
+    }
+
+    fn keys(&self, prefix: &str) -> CacheResult<Vec<String>> {
+        let mut keys = Vec::new();
+        for tree in [&self.values, &self.lists, &self.hashes] {
+            for entry in tree.scan_prefix(prefix).keys() {
+                keys.push(base_key(&entry.map_err(backend_err)?)?);
+            }
+        }
+        keys.sort();
+        keys.dedup();
+        Ok(keys)
+    }
+
+    fn delete(&mut self, key: &str) -> CacheResult<()> {
+        self.values.remove(key).map_err(backend_err)?;
+        Self::remove_prefix(&self.lists, &prefixed(key))?;
+        Self::remove_prefix(&self.hashes, &prefixed(key))
+    }
+
+    fn flush(&mut self) -> CacheResult<()> {
+        for tree in [&self.values, &self.lists, &self.hashes] {
+            tree.clear().map_err(backend_err)?;
+        }
+        self.sync()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::tests::assert_store_contract;
+    use crate::cache::CacheDatabase;
+
+    #[test]
+    fn test_sled_store_contract() {
+        assert_store_contract(SledStore::temporary().unwrap());
+    }
+
+    #[test]
+    fn test_sled_store_persists_across_reopen() {
+        let path = std::env::temp_dir().join(format!("nautilus-sled-{}", std::process::id()));
+        {
+            let mut db = CacheDatabase::new("TESTER-000", SledStore::open(&path).unwrap());
+            db.update_order("O-1", b"initialized").unwrap();
+            db.update_order("O-1", b"submitted").unwrap();
+            db.store().sync().unwrap();
+        }
+
+        let db = CacheDatabase::new("TESTER-000", SledStore::open(&path).unwrap());
+
+        assert_eq!(
+            db.load_order("O-1").unwrap(),
+            vec![b"initialized".to_vec(), b"submitted".to_vec()]
+        );
+        drop(db);
+        std::fs::remove_dir_all(path).unwrap();
+    }
+
+    #[test]
+    fn test_list_prefix_does_not_match_longer_keys() {
+        let mut store = SledStore::temporary().unwrap();
+
+        store.push("O-1", b"a").unwrap();
+        store.push("O-10", b"b").unwrap();
+
+        assert_eq!(store.range("O-1").unwrap(), vec![b"a".to_vec()]);
+        assert_eq!(store.push("O-1", b"c").unwrap(), 2);
+    }
+}
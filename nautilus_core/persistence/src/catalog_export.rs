@@ -0,0 +1,419 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Exports catalog data and event journals to CSV or JSON lines.
+//!
+//! Sources are Parquet catalog files or datasets, and the Arrow IPC stream
+//! `.feather` journals written by live and backtest runs.
+
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use arrow::array::{Array, ArrayRef, BooleanArray, Int64Array};
+use arrow::compute::{cast, filter_record_batch};
+use arrow::datatypes::{DataType, Field, Schema, TimeUnit};
+use arrow::error::ArrowError;
+use arrow::ipc::reader::StreamReader;
+use arrow::record_batch::RecordBatch;
+
+use crate::catalog_schema::{read_parquet, CatalogMigrationError, CatalogMigrationResult};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExportFormat {
+    Csv,
+    JsonLines,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimestampFormat {
+    /// Integer UNIX nanoseconds (as stored).
+    UnixNanos,
+    /// Integer UNIX milliseconds (truncated).
+    UnixMillis,
+    /// RFC 3339 UTC strings with nanosecond precision.
+    Rfc3339,
+}
+
+/// Represents the options for an export.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExportOptions {
+    pub format: ExportFormat,
+    pub timestamp_format: TimestampFormat,
+    /// The UNIX nanosecond columns to format.
+    pub timestamp_columns: Vec<String>,
+    /// The column the `start` and `end` range applies to.
+    pub range_column: String,
+    /// The inclusive range start (UNIX nanoseconds).
+    pub start: Option<i64>,
+    /// The inclusive range end (UNIX nanoseconds).
+    pub end: Option<i64>,
+}
+
+impl ExportOptions {
+    pub fn new(format: ExportFormat) -> Self {
+        ExportOptions {
+            format,
+            timestamp_format: TimestampFormat::UnixNanos,
+            timestamp_columns: vec!["ts_event".to_string(), "ts_init".to_string()],
+            range_column: "ts_init".to_string(),
+            start: None,
+            end: None,
+        }
+    }
+
+    pub fn timestamp_format(mut self, timestamp_format: TimestampFormat) -> Self {
+        self.timestamp_format = timestamp_format;
+        self
+    }
+
+    pub fn range(mut self, start: Option<i64>, end: Option<i64>) -> Self {
+        self.start = start;
+        self.end = end;
+        self
+    }
+}
+
+fn filter_range(batch: &RecordBatch, options: &ExportOptions) -> Result<RecordBatch, ArrowError> {
+    if options.start.is_none() && options.end.is_none() {
+        return Ok(batch.clone());
+    }
+    let column = batch.column_by_name(&options.range_column).ok_or_else(|| {
+        ArrowError::SchemaError(format!("range column `{}` not found", options.range_column))
+    })?;
+    let values = cast(column, &DataType::Int64)?;
+    let values = values
+        .as_any()
+        .downcast_ref::<Int64Array>()
+        .expect("cast to Int64");
+    let start = options.start.unwrap_or(i64::MIN);
+    let end = options.end.unwrap_or(i64::MAX);
+    let mask: BooleanArray = values
+        .iter()
+        .map(|v| Some(v.is_some_and(|v| v >= start && v <= end)))
+        .collect();
+    filter_record_batch(batch, &mask)
+}
+
+fn format_timestamps(
+    batch: &RecordBatch,
+    options: &ExportOptions,
+) -> Result<RecordBatch, ArrowError> {
+    if options.timestamp_format == TimestampFormat::UnixNanos {
+        return Ok(batch.clone());
+    }
+    let schema = batch.schema();
+    let mut fields = Vec::with_capacity(schema.fields().len());
+    let mut columns: Vec<ArrayRef> = Vec::with_capacity(batch.num_columns());
+    for (field, column) in schema.fields().iter().zip(batch.columns()) {
+        if !options.timestamp_columns.contains(field.name()) {
+            fields.push(field.as_ref().clone());
+            columns.push(column.clone());
+            continue;
+        }
+        let nanos = cast(column, &DataType::Int64)?;
+        let formatted: ArrayRef = match options.timestamp_format {
+            TimestampFormat::UnixMillis => {
+                let nanos = nanos
+                    .as_any()
+                    .downcast_ref::<Int64Array>()
+                    .expect("cast to Int64");
+                Arc::new(
+                    nanos
+                        .iter()
+                        .map(|v| v.map(|v| v / 1_000_000))
+                        .collect::<Int64Array>(),
+                )
+            }
+            TimestampFormat::Rfc3339 => cast(
+                &nanos,
+                &DataType::Timestamp(TimeUnit::Nanosecond, Some("+00:00".into())),
+            )?,
+            TimestampFormat::UnixNanos => unreachable!(),
+        };
+        fields.push(Field::new(
+            field.name(),
+            formatted.data_type().clone(),
+            field.is_nullable(),
+        ));
+        columns.push(formatted);
+    }
+    RecordBatch::try_new(Arc::new(Schema::new(fields)), columns)
+}
+
+/// Writes the batches to `writer`, returning the number of rows exported.
+pub fn export_batches<W: Write>(
+    batches: &[RecordBatch],
+    options: &ExportOptions,
+    writer: W,
+) -> CatalogMigrationResult<usize> {
+    let prepared = batches
+        .iter()
+        .map(|b| format_timestamps(&filter_range(b, options)?, options))
+        .collect::<Result<Vec<_>, ArrowError>>()?;
+    let rows = prepared.iter().map(|b| b.num_rows()).sum();
+
+    match options.format {
+        ExportFormat::Csv => {
+            let mut csv = arrow::csv::Writer::new(writer);
+            for batch in &prepared {
+                csv.write(batch)?;
+            }
+        }
+        ExportFormat::JsonLines => {
+            let mut json = arrow::json::LineDelimitedWriter::new(writer);
+            for batch in &prepared {
+                json.write(batch)?;
+            }
+            json.finish()?;
+        }
+    }
+    Ok(rows)
+}
+
+fn source_files(path: &Path, files: &mut Vec<PathBuf>) -> std::io::Result<()> {
+    if path.is_file() {
+        files.push(path.to_path_buf());
+        return Ok(());
+    }
+    let mut entries: Vec<PathBuf> = fs::read_dir(path)?
+        .map(|e| e.map(|e| e.path()))
+        .collect::<std::io::Result<_>>()?;
+    entries.sort();
+    for entry in entries {
+        source_files(&entry, files)?;
+    }
+    Ok(())
+}
+
+/// Reads all batches from a Parquet file or dataset directory, or a `.feather`
+/// IPC stream journal.
+pub fn read_source(path: &Path) -> CatalogMigrationResult<Vec<RecordBatch>> {
+    let mut files = Vec::new();
+    source_files(path, &mut files)?;
+
+    let mut batches = Vec::new();
+    for file in files {
+        let extension = file.extension().and_then(|e| e.to_str());
+        match extension {
+            Some("feather") => {
+                for batch in StreamReader::try_new(File::open(&file)?, None)? {
+                    batches.push(batch?);
+                }
+            }
+            Some("parquet") => batches.extend(read_parquet(&file)?.1),
+            _ => continue,
+        }
+    }
+    Ok(batches)
+}
+
+/// Exports the source at `path` (see [`read_source`]) to `writer`, returning
+/// the number of rows exported.
+pub fn export_path<W: Write>(
+    path: &Path,
+    options: &ExportOptions,
+    writer: W,
+) -> CatalogMigrationResult<usize> {
+    let batches = read_source(path)?;
+    if batches.is_empty() && !path.exists() {
+        return Err(CatalogMigrationError::Io(format!(
+            "{} not found",
+            path.display()
+        )));
+    }
+    export_batches(&batches, options, writer)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::fs::{self, File};
+    use std::path::PathBuf;
+    use std::sync::Arc;
+
+    use arrow::array::{Int64Array, StringArray};
+    use arrow::datatypes::{DataType, Field, Schema};
+    use arrow::ipc::writer::StreamWriter;
+    use arrow::record_batch::RecordBatch;
+    use parquet::arrow::ArrowWriter;
+
+    use super::*;
+
+    const TS: i64 = 1_597_400_000_000_000_000;
+
+    fn batch() -> RecordBatch {
+        let schema = Schema::new(vec![
+            Field::new("price", DataType::Utf8, false),
+            Field::new("ts_event", DataType::Int64, false),
+            Field::new("ts_init", DataType::Int64, false),
+        ]);
+        RecordBatch::try_new(
+            Arc::new(schema),
+            vec![
+                Arc::new(StringArray::from(vec!["1.00001", "1.00002", "1.00003"])),
+                Arc::new(Int64Array::from(vec![TS, TS + 1, TS + 1_500_000])),
+                Arc::new(Int64Array::from(vec![TS, TS + 1, TS + 1_500_000])),
+            ],
+        )
+        .unwrap()
+    }
+
+    fn export(options: &ExportOptions) -> (usize, String) {
+        let mut out = Vec::new();
+        let rows = export_batches(&[batch()], options, &mut out).unwrap();
+        (rows, String::from_utf8(out).unwrap())
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "nautilus-catalog-export-{}-{}",
+            name,
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_export_csv() {
+        let (rows, csv) = export(&ExportOptions::new(ExportFormat::Csv));
+
+        assert_eq!(rows, 3);
+        assert_eq!(
+            csv.lines().take(2).collect::<Vec<_>>(),
+            [
+                "price,ts_event,ts_init",
+                "1.00001,1597400000000000000,1597400000000000000"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_export_jsonl_with_range() {
+        let options = ExportOptions::new(ExportFormat::JsonLines).range(Some(TS + 1), None);
+
+        let (rows, json) = export(&options);
+
+        assert_eq!(rows, 2);
+        assert_eq!(
+            json.lines().next().unwrap(),
+            "{\"price\":\"1.00002\",\"ts_event\":1597400000000000001,\"ts_init\":1597400000000000001}"
+        );
+    }
+
+    #[test]
+    fn test_export_with_millis_timestamps() {
+        let options =
+            ExportOptions::new(ExportFormat::Csv).timestamp_format(TimestampFormat::UnixMillis);
+
+        let (_, csv) = export(&options);
+
+        assert_eq!(
+            csv.lines().nth(3).unwrap(),
+            "1.00003,1597400000001,1597400000001"
+        );
+    }
+
+    #[test]
+    fn test_export_with_rfc3339_timestamps() {
+        let options =
+            ExportOptions::new(ExportFormat::JsonLines).timestamp_format(TimestampFormat::Rfc3339);
+
+        let (_, json) = export(&options);
+
+        assert_eq!(
+            json.lines().nth(1).unwrap(),
+            "{\"price\":\"1.00002\",\"ts_event\":\"2020-08-14T10:13:20.000000001Z\",\"ts_init\":\"2020-08-14T10:13:20.000000001Z\"}"
+        );
+    }
+
+    #[test]
+    fn test_export_with_missing_range_column_returns_error() {
+        let mut options = ExportOptions::new(ExportFormat::Csv).range(Some(0), None);
+        options.range_column = "ts_recv".to_string();
+
+        let result = export_batches(&[batch()], &options, Vec::new());
+
+        assert!(matches!(result, Err(CatalogMigrationError::Arrow(_))));
+    }
+
+    #[test]
+    fn test_export_parquet_dataset() {
+        let dir = temp_dir("parquet");
+        let path = dir
+            .join("trade_tick.parquet")
+            .join("instrument_id=AUD-USD.SIM");
+        fs::create_dir_all(&path).unwrap();
+        let batch = batch();
+        let mut writer = ArrowWriter::try_new(
+            File::create(path.join("part-0.parquet")).unwrap(),
+            batch.schema(),
+            None,
+        )
+        .unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        let mut out = Vec::new();
+        let rows = export_path(
+            &dir.join("trade_tick.parquet"),
+            &ExportOptions::new(ExportFormat::Csv).range(None, Some(TS)),
+            &mut out,
+        )
+        .unwrap();
+
+        assert_eq!(rows, 1);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_export_feather_journal() {
+        let dir = temp_dir("feather");
+        let path = dir.join("order_filled.feather");
+        let batch = batch();
+        let mut writer =
+            StreamWriter::try_new(File::create(&path).unwrap(), &batch.schema()).unwrap();
+        writer.write(&batch).unwrap();
+        writer.write(&batch).unwrap();
+        writer.finish().unwrap();
+
+        let mut out = Vec::new();
+        let rows = export_path(
+            &path,
+            &ExportOptions::new(ExportFormat::JsonLines),
+            &mut out,
+        )
+        .unwrap();
+
+        assert_eq!(rows, 6);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_export_missing_path_returns_error() {
+        let result = export_path(
+            Path::new("/nonexistent/journal.feather"),
+            &ExportOptions::new(ExportFormat::Csv),
+            Vec::new(),
+        );
+
+        assert!(matches!(result, Err(CatalogMigrationError::Io(_))));
+    }
+}
@@ -0,0 +1,805 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Parallel ingestion of input files into a hive partitioned catalog dataset.
+//!
+//! Input files are read concurrently by a pool of workers pulling from a
+//! shared queue, split by `instrument_id`, then each instrument partition is
+//! written by whichever worker is free next. Rows for an instrument keep the
+//! order of the inputs (by input position, then by row), and older schema
+//! versions are migrated on the way in.
+//!
+//! As sources can record timestamps at different precisions, the timestamp
+//! columns are normalized to UNIX nanoseconds. Each partition can also be
+//! ordered by a timestamp column with a `sequence` column numbering the rows
+//! with equal timestamps (in input order), so merges of mixed-precision
+//! sources are deterministic.
+
+use std::collections::{HashMap, VecDeque};
+use std::ffi::{c_char, c_void, CStr};
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+use std::sync::Arc;
+
+use arrow::array::{Array, ArrayRef, Int64Array, StringArray, UInt32Array, UInt64Array};
+use arrow::compute::{cast, concat_batches, take_record_batch};
+use arrow::datatypes::{DataType, Field, Schema, TimeUnit};
+use arrow::error::ArrowError;
+use arrow::record_batch::RecordBatch;
+use parquet::arrow::ArrowWriter;
+
+use crate::catalog_export::read_source;
+use crate::catalog_schema::{migrate_batch, CatalogMigrationError, CatalogMigrationResult};
+
+const PARTITION_COLUMN: &str = "instrument_id";
+
+/// The column numbering the rows of a partition with equal timestamps.
+pub const SEQUENCE_COLUMN: &str = "sequence";
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum TimestampUnit {
+    /// Inferred for each column from the magnitude of its values.
+    Auto = 1,
+    Seconds = 2,
+    Milliseconds = 3,
+    Microseconds = 4,
+    Nanoseconds = 5,
+}
+
+impl TimestampUnit {
+    /// Returns the nanoseconds per unit of the values (inferring the unit
+    /// from the largest magnitude for `Auto`).
+    fn nanos(&self, values: &Int64Array) -> i64 {
+        match self {
+            TimestampUnit::Auto => {
+                let max = values.iter().flatten().map(i64::unsigned_abs).max();
+                match max.unwrap_or(0) {
+                    0..=99_999_999_999 => 1_000_000_000,
+                    100_000_000_000..=99_999_999_999_999 => 1_000_000,
+                    100_000_000_000_000..=99_999_999_999_999_999 => 1_000,
+                    _ => 1,
+                }
+            }
+            TimestampUnit::Seconds => 1_000_000_000,
+            TimestampUnit::Milliseconds => 1_000_000,
+            TimestampUnit::Microseconds => 1_000,
+            TimestampUnit::Nanoseconds => 1,
+        }
+    }
+}
+
+/// Represents the options for an ingestion.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IngestOptions {
+    /// The unit of the integer timestamp columns (Arrow timestamp columns
+    /// are converted from their own unit).
+    pub timestamp_unit: TimestampUnit,
+    /// The timestamp columns to normalize to UNIX nanoseconds.
+    pub timestamp_columns: Vec<String>,
+    /// The timestamp column to order each partition by with a `sequence`
+    /// column (if any).
+    pub sequence_column: Option<String>,
+}
+
+impl Default for IngestOptions {
+    fn default() -> Self {
+        IngestOptions {
+            timestamp_unit: TimestampUnit::Nanoseconds,
+            timestamp_columns: vec!["ts_event".to_string(), "ts_init".to_string()],
+            sequence_column: None,
+        }
+    }
+}
+
+impl IngestOptions {
+    pub fn timestamp_unit(mut self, timestamp_unit: TimestampUnit) -> Self {
+        self.timestamp_unit = timestamp_unit;
+        self
+    }
+
+    pub fn sequence_by(mut self, column: &str) -> Self {
+        self.sequence_column = Some(column.to_string());
+        self
+    }
+}
+
+/// Represents the progress of an ingestion, reported after each input file
+/// is read and each instrument partition is written.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct IngestProgress {
+    pub files_read: usize,
+    pub files_total: usize,
+    pub instruments_written: usize,
+    pub instruments_total: usize,
+    pub rows_written: usize,
+}
+
+type Chunk = (usize, usize, RecordBatch);
+
+/// Returns the partition directory name for the instrument ID value.
+pub fn partition_dir_name(instrument_id: &str) -> String {
+    format!("{}={}", PARTITION_COLUMN, instrument_id.replace('/', "-"))
+}
+
+fn split_by_instrument(batch: &RecordBatch) -> Result<Vec<(String, RecordBatch)>, ArrowError> {
+    let index = batch.schema().index_of(PARTITION_COLUMN)?;
+    let ids = cast(batch.column(index), &DataType::Utf8)?;
+    let ids = ids
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .expect("cast to Utf8");
+
+    let mut order: Vec<String> = Vec::new();
+    let mut rows: HashMap<String, Vec<u32>> = HashMap::new();
+    for (row, id) in ids.iter().enumerate() {
+        let id =
+            id.ok_or_else(|| ArrowError::InvalidArgumentError("null `instrument_id`".to_string()))?;
+        rows.entry(id.to_string())
+            .or_insert_with(|| {
+                order.push(id.to_string());
+                Vec::new()
+            })
+            .push(row as u32);
+    }
+
+    let mut without_partition = batch.clone();
+    without_partition.remove_column(index);
+    order
+        .into_iter()
+        .map(|id| {
+            let indices = UInt32Array::from(rows.remove(&id).unwrap());
+            Ok((id, take_record_batch(&without_partition, &indices)?))
+        })
+        .collect()
+}
+
+fn normalize_timestamps(
+    batch: &RecordBatch,
+    options: &IngestOptions,
+) -> Result<RecordBatch, ArrowError> {
+    let schema = batch.schema();
+    let mut fields = Vec::with_capacity(schema.fields().len());
+    let mut columns: Vec<ArrayRef> = Vec::with_capacity(batch.num_columns());
+    for (field, column) in schema.fields().iter().zip(batch.columns()) {
+        if !options.timestamp_columns.contains(field.name()) {
+            fields.push(field.as_ref().clone());
+            columns.push(column.clone());
+            continue;
+        }
+        let values = cast(column, &DataType::Int64)?;
+        let values = values
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .expect("cast to Int64");
+        let nanos = match column.data_type() {
+            DataType::Timestamp(TimeUnit::Second, _) => 1_000_000_000,
+            DataType::Timestamp(TimeUnit::Millisecond, _) => 1_000_000,
+            DataType::Timestamp(TimeUnit::Microsecond, _) => 1_000,
+            DataType::Timestamp(TimeUnit::Nanosecond, _) => 1,
+            data_type if data_type.is_integer() => options.timestamp_unit.nanos(values),
+            data_type => {
+                return Err(ArrowError::SchemaError(format!(
+                    "timestamp column `{}` has unsupported type {}",
+                    field.name(),
+                    data_type
+                )))
+            }
+        };
+        let normalized = values
+            .iter()
+            .map(|v| {
+                v.map(|v| {
+                    v.checked_mul(nanos).ok_or_else(|| {
+                        ArrowError::ComputeError(format!(
+                            "timestamp {} in `{}` overflows as nanoseconds",
+                            v,
+                            field.name()
+                        ))
+                    })
+                })
+                .transpose()
+            })
+            .collect::<Result<Int64Array, ArrowError>>()?;
+        fields.push(
+            Field::new(field.name(), DataType::Int64, field.is_nullable())
+                .with_metadata(field.metadata().clone()),
+        );
+        columns.push(Arc::new(normalized));
+    }
+    let schema = Schema::new_with_metadata(fields, schema.metadata().clone());
+    RecordBatch::try_new(Arc::new(schema), columns)
+}
+
+/// Returns the batch stably sorted by the timestamp column, with a
+/// `sequence` column numbering the rows with equal timestamps.
+fn sequence_rows(batch: &RecordBatch, column: &str) -> Result<RecordBatch, ArrowError> {
+    let ts = batch.column_by_name(column).ok_or_else(|| {
+        ArrowError::SchemaError(format!("sequence column `{}` not found", column))
+    })?;
+    let ts = cast(ts, &DataType::Int64)?;
+    let ts = ts
+        .as_any()
+        .downcast_ref::<Int64Array>()
+        .expect("cast to Int64");
+    if ts.null_count() > 0 {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "null `{}`",
+            column
+        )));
+    }
+    let mut indices: Vec<u32> = (0..batch.num_rows() as u32).collect();
+    indices.sort_by_key(|i| ts.value(*i as usize)); // Stable for equal timestamps
+    let mut sequences = Vec::with_capacity(indices.len());
+    let mut prior: Option<(i64, u64)> = None;
+    for i in &indices {
+        let value = ts.value(*i as usize);
+        let sequence = match prior {
+            Some((prior_value, sequence)) if prior_value == value => sequence + 1,
+            _ => 0,
+        };
+        sequences.push(sequence);
+        prior = Some((value, sequence));
+    }
+
+    let mut sorted = take_record_batch(batch, &UInt32Array::from(indices))?;
+    if let Ok(index) = sorted.schema().index_of(SEQUENCE_COLUMN) {
+        sorted.remove_column(index); // Replaced
+    }
+    let schema = sorted.schema();
+    let mut fields: Vec<Field> = schema.fields().iter().map(|f| f.as_ref().clone()).collect();
+    fields.push(Field::new(SEQUENCE_COLUMN, DataType::UInt64, false));
+    let mut columns = sorted.columns().to_vec();
+    columns.push(Arc::new(UInt64Array::from(sequences)));
+    let schema = Schema::new_with_metadata(fields, schema.metadata().clone());
+    RecordBatch::try_new(Arc::new(schema), columns)
+}
+
+fn next_part_path(dir: &Path) -> std::io::Result<PathBuf> {
+    fs::create_dir_all(dir)?;
+    let mut n = 0;
+    loop {
+        let path = dir.join(format!("part-{}.parquet", n));
+        if !path.exists() {
+            return Ok(path);
+        }
+        n += 1;
+    }
+}
+
+fn write_partition(
+    dir: &Path,
+    mut chunks: Vec<Chunk>,
+    options: &IngestOptions,
+) -> CatalogMigrationResult<usize> {
+    chunks.sort_by_key(|(file, batch, _)| (*file, *batch));
+    let schema = chunks[0].2.schema();
+    let batches: Vec<RecordBatch> = chunks.into_iter().map(|(_, _, b)| b).collect();
+    let mut batch = concat_batches(&schema, &batches)?;
+    if let Some(column) = &options.sequence_column {
+        batch = sequence_rows(&batch, column)?;
+    }
+
+    let path = next_part_path(dir)?;
+    let mut writer = ArrowWriter::try_new(File::create(path)?, batch.schema(), None)?;
+    writer.write(&batch)?;
+    writer.close()?;
+    Ok(batch.num_rows())
+}
+
+fn run_workers<T, F>(threads: usize, jobs: VecDeque<T>, work: F) -> CatalogMigrationResult<()>
+where
+    T: Send,
+    F: Fn(T) -> CatalogMigrationResult<()> + Sync,
+{
+    let queue = Mutex::new(jobs);
+    let error: Mutex<Option<CatalogMigrationError>> = Mutex::new(None);
+    std::thread::scope(|scope| {
+        for _ in 0..threads.max(1) {
+            scope.spawn(|| loop {
+                if error.lock().unwrap().is_some() {
+                    return;
+                }
+                let job = queue.lock().unwrap().pop_front();
+                match job {
+                    Some(job) => {
+                        if let Err(e) = work(job) {
+                            error.lock().unwrap().get_or_insert(e);
+                        }
+                    }
+                    None => return,
+                }
+            });
+        }
+    });
+    match error.into_inner().unwrap() {
+        Some(e) => Err(e),
+        None => Ok(()),
+    }
+}
+
+/// Ingests the input files (Parquet or `.feather`) into the
+/// `{catalog_path}/data/{name}.parquet` dataset using `threads` workers,
+/// appending a new part file to each instrument partition.
+///
+/// Input batches must have the same schema after normalization, so sources
+/// of one precision should share the same `timestamp_unit` (or `Auto`).
+pub fn ingest<F>(
+    catalog_path: &str,
+    name: &str,
+    inputs: &[PathBuf],
+    threads: usize,
+    options: &IngestOptions,
+    on_progress: F,
+) -> CatalogMigrationResult<IngestProgress>
+where
+    F: Fn(&IngestProgress) + Sync,
+{
+    let dataset = Path::new(catalog_path)
+        .join("data")
+        .join(format!("{}.parquet", name));
+    let files_read = AtomicUsize::new(0);
+    let partitions: Mutex<HashMap<String, Vec<Chunk>>> = Mutex::new(HashMap::new());
+
+    run_workers(
+        threads,
+        inputs.iter().enumerate().collect(),
+        |(file_index, path)| {
+            let mut split: Vec<(String, Chunk)> = Vec::new();
+            for (batch_index, batch) in read_source(path)?.iter().enumerate() {
+                let batch = normalize_timestamps(&migrate_batch(batch)?, options)?;
+                for (id, part) in split_by_instrument(&batch)? {
+                    split.push((id, (file_index, batch_index, part)));
+                }
+            }
+            let mut partitions = partitions.lock().unwrap();
+            for (id, chunk) in split {
+                partitions.entry(id).or_default().push(chunk);
+            }
+            let instruments_total = partitions.len();
+            drop(partitions);
+
+            on_progress(&IngestProgress {
+                files_read: files_read.fetch_add(1, Ordering::SeqCst) + 1,
+                files_total: inputs.len(),
+                instruments_total,
+                ..Default::default()
+            });
+            Ok(())
+        },
+    )?;
+
+    let partitions = partitions.into_inner().unwrap();
+    let instruments_total = partitions.len();
+    let instruments_written = AtomicUsize::new(0);
+    let rows_written = AtomicUsize::new(0);
+
+    run_workers(threads, partitions.into_iter().collect(), |(id, chunks)| {
+        let rows = write_partition(&dataset.join(partition_dir_name(&id)), chunks, options)?;
+        on_progress(&IngestProgress {
+            files_read: inputs.len(),
+            files_total: inputs.len(),
+            instruments_written: instruments_written.fetch_add(1, Ordering::SeqCst) + 1,
+            instruments_total,
+            rows_written: rows_written.fetch_add(rows, Ordering::SeqCst) + rows,
+        });
+        Ok(())
+    })?;
+
+    Ok(IngestProgress {
+        files_read: inputs.len(),
+        files_total: inputs.len(),
+        instruments_written: instruments_total,
+        instruments_total,
+        rows_written: rows_written.into_inner(),
+    })
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// C API
+////////////////////////////////////////////////////////////////////////////////
+struct UserData(*mut c_void);
+
+// SAFETY: the caller guarantees the callback may be invoked from any thread
+unsafe impl Sync for UserData {}
+
+/// Ingests the input files into the catalog dataset, calling `callback` (if
+/// not null) with progress updates. The `ts_event` and `ts_init` columns are
+/// normalized from the `timestamp_unit`, and each partition is ordered by
+/// `ts_init` with a `sequence` column if `sequence` is non-zero. Returns zero
+/// on success, 1 for invalid arguments, 2 for IO errors and 3 for data
+/// errors.
+///
+/// # Safety
+///
+/// - `catalog_path`, `name` and each of the `inputs_len` `inputs` must be
+///   valid null terminated UTF-8 C strings.
+/// - `callback` must be safe to call concurrently from worker threads with
+///   `user_data`.
+#[no_mangle]
+pub unsafe extern "C" fn catalog_ingest(
+    catalog_path: *const c_char,
+    name: *const c_char,
+    inputs: *const *const c_char,
+    inputs_len: usize,
+    threads: usize,
+    timestamp_unit: TimestampUnit,
+    sequence: u8,
+    callback: Option<extern "C" fn(IngestProgress, *mut c_void)>,
+    user_data: *mut c_void,
+) -> u8 {
+    let to_str = |ptr: *const c_char| -> Option<&str> {
+        match ptr.is_null() {
+            true => None,
+            false => CStr::from_ptr(ptr).to_str().ok(),
+        }
+    };
+    let (catalog_path, name) = match (to_str(catalog_path), to_str(name)) {
+        (Some(catalog_path), Some(name)) if !inputs.is_null() || inputs_len == 0 => {
+            (catalog_path, name)
+        }
+        _ => return 1,
+    };
+    let mut paths = Vec::with_capacity(inputs_len);
+    for i in 0..inputs_len {
+        match to_str(*inputs.add(i)) {
+            Some(path) => paths.push(PathBuf::from(path)),
+            None => return 1,
+        }
+    }
+
+    let mut options = IngestOptions::default().timestamp_unit(timestamp_unit);
+    if sequence != 0 {
+        options = options.sequence_by("ts_init");
+    }
+
+    let user_data = UserData(user_data);
+    let result = ingest(catalog_path, name, &paths, threads, &options, |progress| {
+        if let Some(callback) = callback {
+            let user_data = &user_data;
+            callback(*progress, user_data.0);
+        }
+    });
+    match result {
+        Ok(_) => 0,
+        Err(CatalogMigrationError::Io(_)) => 2,
+        Err(_) => 3,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::ffi::{c_void, CString};
+    use std::fs::{self, File};
+    use std::path::PathBuf;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    use arrow::array::{ArrayRef, Int64Array, StringArray, TimestampMillisecondArray, UInt64Array};
+    use arrow::datatypes::{DataType, Field, Schema};
+    use arrow::record_batch::RecordBatch;
+    use parquet::arrow::ArrowWriter;
+
+    use super::*;
+    use crate::catalog_schema::{schema_version, CATALOG_SCHEMA_VERSION};
+
+    fn write_input(path: &Path, ids: &[&str], ts: &[i64]) {
+        write_timestamps(path, ids, Arc::new(Int64Array::from(ts.to_vec())));
+    }
+
+    fn write_timestamps(path: &Path, ids: &[&str], ts: ArrayRef) {
+        let schema = Schema::new(vec![
+            Field::new("instrument_id", DataType::Utf8, false),
+            Field::new("ts_init", ts.data_type().clone(), false),
+        ]);
+        let batch = RecordBatch::try_new(
+            Arc::new(schema),
+            vec![Arc::new(StringArray::from(ids.to_vec())), ts],
+        )
+        .unwrap();
+        let mut writer =
+            ArrowWriter::try_new(File::create(path).unwrap(), batch.schema(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+    }
+
+    fn setup(name: &str, files: usize) -> (PathBuf, Vec<PathBuf>) {
+        let root = std::env::temp_dir().join(format!(
+            "nautilus-catalog-ingest-{}-{}",
+            name,
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("inputs")).unwrap();
+        let inputs: Vec<PathBuf> = (0..files)
+            .map(|i| {
+                let path = root.join("inputs").join(format!("{}.parquet", i));
+                let base = i as i64 * 10;
+                write_input(
+                    &path,
+                    &["AUD/USD.SIM", "EUR/USD.SIM", "AUD/USD.SIM"],
+                    &[base, base + 1, base + 2],
+                );
+                path
+            })
+            .collect();
+        (root, inputs)
+    }
+
+    fn read_ts(path: &Path) -> (Vec<i64>, u32) {
+        let batches = read_source(path).unwrap();
+        let ts = batches
+            .iter()
+            .flat_map(|b| {
+                b.column_by_name("ts_init")
+                    .unwrap()
+                    .as_any()
+                    .downcast_ref::<Int64Array>()
+                    .unwrap()
+                    .values()
+                    .to_vec()
+            })
+            .collect();
+        (ts, schema_version(batches[0].schema_ref()))
+    }
+
+    #[test]
+    fn test_partition_dir_name() {
+        assert_eq!(
+            partition_dir_name("AUD/USD.SIM"),
+            "instrument_id=AUD-USD.SIM"
+        );
+    }
+
+    #[test]
+    fn test_ingest_preserves_per_instrument_order() {
+        let (root, inputs) = setup("order", 8);
+        let updates = Mutex::new(Vec::new());
+
+        let progress = ingest(
+            root.to_str().unwrap(),
+            "quote_tick",
+            &inputs,
+            4,
+            &IngestOptions::default(),
+            |p| updates.lock().unwrap().push(*p),
+        )
+        .unwrap();
+
+        let dataset = root.join("data").join("quote_tick.parquet");
+        let (aud, version) = read_ts(&dataset.join("instrument_id=AUD-USD.SIM"));
+        let (eur, _) = read_ts(&dataset.join("instrument_id=EUR-USD.SIM"));
+        let expected_aud: Vec<i64> = (0..8).flat_map(|i| [i * 10, i * 10 + 2]).collect();
+        let expected_eur: Vec<i64> = (0..8).map(|i| i * 10 + 1).collect();
+        assert_eq!(aud, expected_aud);
+        assert_eq!(eur, expected_eur);
+        assert_eq!(version, CATALOG_SCHEMA_VERSION);
+        assert_eq!(
+            progress,
+            IngestProgress {
+                files_read: 8,
+                files_total: 8,
+                instruments_written: 2,
+                instruments_total: 2,
+                rows_written: 24,
+            }
+        );
+        assert_eq!(updates.lock().unwrap().len(), 10);
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_ingest_appends_part_files() {
+        let (root, inputs) = setup("append", 1);
+
+        let options = IngestOptions::default();
+        ingest(
+            root.to_str().unwrap(),
+            "quote_tick",
+            &inputs,
+            2,
+            &options,
+            |_| {},
+        )
+        .unwrap();
+        ingest(
+            root.to_str().unwrap(),
+            "quote_tick",
+            &inputs,
+            2,
+            &options,
+            |_| {},
+        )
+        .unwrap();
+
+        let partition = root
+            .join("data")
+            .join("quote_tick.parquet")
+            .join("instrument_id=EUR-USD.SIM");
+        assert!(partition.join("part-0.parquet").exists());
+        assert!(partition.join("part-1.parquet").exists());
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_ingest_missing_input_returns_error() {
+        let (root, mut inputs) = setup("missing", 2);
+        inputs.push(root.join("inputs").join("missing.parquet"));
+
+        let options = IngestOptions::default();
+        let result = ingest(
+            root.to_str().unwrap(),
+            "quote_tick",
+            &inputs,
+            2,
+            &options,
+            |_| {},
+        );
+
+        assert!(matches!(result, Err(CatalogMigrationError::Io(_))));
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_ingest_normalizes_mixed_precision_timestamps_with_sequence() {
+        let (root, _) = setup("precision", 0);
+        let millis = root.join("inputs").join("millis.parquet");
+        let micros = root.join("inputs").join("micros.parquet");
+        let ids = ["AUD/USD.SIM", "AUD/USD.SIM"];
+        write_timestamps(
+            &millis,
+            &ids,
+            Arc::new(TimestampMillisecondArray::from(vec![
+                1_600_000_000_002,
+                1_600_000_000_001,
+            ])),
+        );
+        write_input(
+            &micros,
+            &ids,
+            &[1_600_000_000_001_000, 1_600_000_000_001_500],
+        );
+        let options = IngestOptions::default()
+            .timestamp_unit(TimestampUnit::Auto)
+            .sequence_by("ts_init");
+
+        ingest(
+            root.to_str().unwrap(),
+            "trade_tick",
+            &[millis, micros],
+            2,
+            &options,
+            |_| {},
+        )
+        .unwrap();
+
+        let partition = root
+            .join("data")
+            .join("trade_tick.parquet")
+            .join("instrument_id=AUD-USD.SIM");
+        let batches = read_source(&partition).unwrap();
+        let (ts, _) = read_ts(&partition);
+        let sequence = batches[0]
+            .column_by_name(SEQUENCE_COLUMN)
+            .unwrap()
+            .as_any()
+            .downcast_ref::<UInt64Array>()
+            .unwrap()
+            .values()
+            .to_vec();
+        assert_eq!(
+            ts,
+            vec![
+                1_600_000_000_001_000_000,
+                1_600_000_000_001_000_000,
+                1_600_000_000_001_500_000,
+                1_600_000_000_002_000_000,
+            ]
+        );
+        assert_eq!(sequence, vec![0, 1, 0, 0]);
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_timestamp_unit_auto_infers_from_magnitude() {
+        let nanos = |values: Vec<i64>| TimestampUnit::Auto.nanos(&Int64Array::from(values));
+
+        assert_eq!(nanos(vec![1_600_000_000]), 1_000_000_000);
+        assert_eq!(nanos(vec![1_600_000_000_000]), 1_000_000);
+        assert_eq!(nanos(vec![1_600_000_000_000_000]), 1_000);
+        assert_eq!(nanos(vec![1_600_000_000_000_000_000]), 1);
+    }
+
+    #[test]
+    fn test_ingest_timestamp_overflow_returns_error() {
+        let (root, inputs) = setup("overflow", 1);
+        write_input(&inputs[0], &["AUD/USD.SIM"], &[i64::MAX / 10]);
+        let options = IngestOptions::default().timestamp_unit(TimestampUnit::Seconds);
+
+        let result = ingest(
+            root.to_str().unwrap(),
+            "quote_tick",
+            &inputs,
+            1,
+            &options,
+            |_| {},
+        );
+
+        assert!(matches!(result, Err(CatalogMigrationError::Arrow(_))));
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    extern "C" fn count_progress(_progress: IngestProgress, user_data: *mut c_void) {
+        let counter = unsafe { &*(user_data as *const AtomicUsize) };
+        counter.fetch_add(1, Ordering::SeqCst);
+    }
+
+    #[test]
+    fn test_catalog_ingest_c_api() {
+        let (root, inputs) = setup("ffi", 3);
+        let catalog = CString::new(root.to_str().unwrap()).unwrap();
+        let name = CString::new("quote_tick").unwrap();
+        let inputs: Vec<CString> = inputs
+            .iter()
+            .map(|p| CString::new(p.to_str().unwrap()).unwrap())
+            .collect();
+        let input_ptrs: Vec<*const c_char> = inputs.iter().map(|p| p.as_ptr()).collect();
+        let counter = AtomicUsize::new(0);
+
+        let code = unsafe {
+            catalog_ingest(
+                catalog.as_ptr(),
+                name.as_ptr(),
+                input_ptrs.as_ptr(),
+                input_ptrs.len(),
+                2,
+                TimestampUnit::Nanoseconds,
+                1,
+                Some(count_progress),
+                &counter as *const AtomicUsize as *mut c_void,
+            )
+        };
+
+        assert_eq!(code, 0);
+        assert_eq!(counter.load(Ordering::SeqCst), 5);
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_catalog_ingest_c_api_with_null_argument() {
+        let code = unsafe {
+            catalog_ingest(
+                std::ptr::null(),
+                std::ptr::null(),
+                std::ptr::null(),
+                0,
+                1,
+                TimestampUnit::Nanoseconds,
+                0,
+                None,
+                std::ptr::null_mut(),
+            )
+        };
+
+        assert_eq!(code, 1);
+    }
+}
@@ -0,0 +1,366 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Content hashing of catalog partitions for reproducibility.
+//!
+//! A manifest at `{catalog}/manifest.json` records a SHA-256 digest for each
+//! partition (a directory of Parquet files, or a single file dataset). The
+//! manifest digest identifies the exact data a backtest ran against, and
+//! partitions can be verified against it when loaded.
+
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::{BufReader, Read};
+use std::path::{Path, PathBuf};
+
+use arrow::record_batch::RecordBatch;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::catalog_export::read_source;
+use crate::catalog_schema::{CatalogMigrationError, CatalogMigrationResult};
+
+/// The manifest file name within the catalog root.
+pub const MANIFEST_FILE: &str = "manifest.json";
+
+/// Represents the recorded digest of a partition.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartitionDigest {
+    pub sha256: String,
+    pub files: usize,
+    pub bytes: u64,
+}
+
+/// Represents the content digests of all catalog partitions, keyed by path
+/// relative to the catalog root (with '/' separators).
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CatalogManifest {
+    pub partitions: BTreeMap<String, PartitionDigest>,
+}
+
+impl CatalogManifest {
+    /// Returns the digest over all partition digests.
+    pub fn digest(&self) -> String {
+        let mut hasher = Sha256::new();
+        for (partition, digest) in &self.partitions {
+            hasher.update(partition.as_bytes());
+            hasher.update([0]);
+            hasher.update(digest.sha256.as_bytes());
+            hasher.update([0]);
+        }
+        hex::encode(hasher.finalize())
+    }
+}
+
+/// Represents a difference between the catalog and its manifest.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IntegrityIssue {
+    /// The partition content differs from its recorded digest.
+    Modified(String),
+    /// The recorded partition no longer exists.
+    Missing(String),
+    /// The partition is not recorded in the manifest.
+    Unrecorded(String),
+}
+
+fn relative_key(root: &Path, path: &Path) -> String {
+    let relative = path.strip_prefix(root).unwrap_or(path);
+    relative
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn is_data_file(path: &Path) -> bool {
+    matches!(
+        path.extension().and_then(|e| e.to_str()),
+        Some("parquet") | Some("feather")
+    )
+}
+
+/// Collects the partitions under the catalog data directory: each nested
+/// directory containing data files, and each data file directly under `data`.
+fn find_partitions(
+    data_dir: &Path,
+    dir: &Path,
+    partitions: &mut Vec<PathBuf>,
+) -> std::io::Result<()> {
+    let mut has_files = false;
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            find_partitions(data_dir, &path, partitions)?;
+        } else if is_data_file(&path) {
+            if dir == data_dir {
+                partitions.push(path);
+            } else {
+                has_files = true;
+            }
+        }
+    }
+    if has_files {
+        partitions.push(dir.to_path_buf());
+    }
+    Ok(())
+}
+
+fn data_files(partition: &Path) -> std::io::Result<Vec<PathBuf>> {
+    if partition.is_file() {
+        return Ok(vec![partition.to_path_buf()]);
+    }
+    let mut files: Vec<PathBuf> = fs::read_dir(partition)?
+        .map(|e| e.map(|e| e.path()))
+        .collect::<std::io::Result<Vec<_>>>()?
+        .into_iter()
+        .filter(|p| p.is_file() && is_data_file(p))
+        .collect();
+    files.sort();
+    Ok(files)
+}
+
+/// Returns the digest of the partition's data files (names and contents).
+pub fn hash_partition(partition: &Path) -> CatalogMigrationResult<PartitionDigest> {
+    let mut hasher = Sha256::new();
+    let mut bytes = 0;
+    let files = data_files(partition)?;
+    for file in &files {
+        let name = file.file_name().unwrap().to_string_lossy();
+        hasher.update(name.as_bytes());
+        hasher.update([0]);
+        let mut reader = BufReader::new(File::open(file)?);
+        let mut buffer = [0; 64 * 1024];
+        loop {
+            let n = reader.read(&mut buffer)?;
+            if n == 0 {
+                break;
+            }
+            hasher.update(&buffer[..n]);
+            bytes += n as u64;
+        }
+        hasher.update([0]);
+    }
+    Ok(PartitionDigest {
+        sha256: hex::encode(hasher.finalize()),
+        files: files.len(),
+        bytes,
+    })
+}
+
+/// Returns the manifest for the catalog's current contents.
+pub fn build_manifest(catalog_path: &str) -> CatalogMigrationResult<CatalogManifest> {
+    let root = Path::new(catalog_path);
+    let data_dir = root.join("data");
+    let mut partitions = Vec::new();
+    find_partitions(&data_dir, &data_dir, &mut partitions)?;
+
+    let mut manifest = CatalogManifest::default();
+    for partition in partitions {
+        manifest
+            .partitions
+            .insert(relative_key(root, &partition), hash_partition(&partition)?);
+    }
+    Ok(manifest)
+}
+
+/// Builds and writes the catalog manifest, returning it.
+pub fn write_manifest(catalog_path: &str) -> CatalogMigrationResult<CatalogManifest> {
+    let manifest = build_manifest(catalog_path)?;
+    let json = serde_json::to_string_pretty(&manifest)
+        .map_err(|e| CatalogMigrationError::Io(e.to_string()))?;
+    let path = Path::new(catalog_path).join(MANIFEST_FILE);
+    let tmp_path = path.with_extension("json.tmp");
+    fs::write(&tmp_path, json)?;
+    fs::rename(tmp_path, path)?;
+    Ok(manifest)
+}
+
+/// Returns the manifest recorded for the catalog.
+pub fn read_manifest(catalog_path: &str) -> CatalogMigrationResult<CatalogManifest> {
+    let json = fs::read_to_string(Path::new(catalog_path).join(MANIFEST_FILE))?;
+    serde_json::from_str(&json).map_err(|e| CatalogMigrationError::Corrupt(e.to_string()))
+}
+
+/// Returns the differences between the catalog and its recorded manifest.
+pub fn verify_catalog(catalog_path: &str) -> CatalogMigrationResult<Vec<IntegrityIssue>> {
+    let recorded = read_manifest(catalog_path)?;
+    let current = build_manifest(catalog_path)?;
+
+    let mut issues = Vec::new();
+    for (partition, digest) in &recorded.partitions {
+        match current.partitions.get(partition) {
+            Some(current) if current.sha256 == digest.sha256 => {}
+            Some(_) => issues.push(IntegrityIssue::Modified(partition.clone())),
+            None => issues.push(IntegrityIssue::Missing(partition.clone())),
+        }
+    }
+    for partition in current.partitions.keys() {
+        if !recorded.partitions.contains_key(partition) {
+            issues.push(IntegrityIssue::Unrecorded(partition.clone()));
+        }
+    }
+    Ok(issues)
+}
+
+/// Reads the partition after verifying it against the manifest.
+pub fn read_verified(
+    catalog_path: &str,
+    manifest: &CatalogManifest,
+    partition: &str,
+) -> CatalogMigrationResult<Vec<RecordBatch>> {
+    let recorded = manifest.partitions.get(partition).ok_or_else(|| {
+        CatalogMigrationError::Corrupt(format!("{} not recorded in manifest", partition))
+    })?;
+    let path = Path::new(catalog_path).join(partition);
+    let current = hash_partition(&path)?;
+    if current.sha256 != recorded.sha256 {
+        return Err(CatalogMigrationError::Corrupt(format!(
+            "{} digest {} did not match recorded {}",
+            partition, current.sha256, recorded.sha256
+        )));
+    }
+    read_source(&path)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::fs::{self, File, OpenOptions};
+    use std::io::Write;
+    use std::path::{Path, PathBuf};
+    use std::sync::Arc;
+
+    use arrow::array::Int64Array;
+    use arrow::datatypes::{DataType, Field, Schema};
+    use arrow::record_batch::RecordBatch;
+    use parquet::arrow::ArrowWriter;
+
+    use super::*;
+
+    const AUD: &str = "data/quote_tick.parquet/instrument_id=AUD-USD.SIM";
+    const EUR: &str = "data/quote_tick.parquet/instrument_id=EUR-USD.SIM";
+    const BARS: &str = "data/bar.parquet";
+
+    fn write(path: &Path, start: i64) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        let schema = Schema::new(vec![Field::new("ts_init", DataType::Int64, false)]);
+        let batch = RecordBatch::try_new(
+            Arc::new(schema),
+            vec![Arc::new(Int64Array::from_iter_values(start..start + 10))],
+        )
+        .unwrap();
+        let mut writer =
+            ArrowWriter::try_new(File::create(path).unwrap(), batch.schema(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+    }
+
+    fn catalog(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!(
+            "nautilus-catalog-integrity-{}-{}",
+            name,
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&root);
+        write(&root.join(AUD).join("part-0.parquet"), 0);
+        write(&root.join(AUD).join("part-1.parquet"), 10);
+        write(&root.join(EUR).join("part-0.parquet"), 0);
+        write(&root.join(BARS), 0);
+        root
+    }
+
+    #[test]
+    fn test_build_manifest() {
+        let root = catalog("build");
+
+        let manifest = build_manifest(root.to_str().unwrap()).unwrap();
+
+        let keys: Vec<&str> = manifest.partitions.keys().map(|k| k.as_str()).collect();
+        assert_eq!(keys, [BARS, AUD, EUR]);
+        assert_eq!(manifest.partitions[AUD].files, 2);
+        assert_eq!(manifest.partitions[BARS].files, 1);
+        // Same content in a differently named partition hashes identically
+        assert_eq!(
+            manifest.partitions[EUR].sha256,
+            hash_partition(&root.join(AUD).join("part-0.parquet"))
+                .unwrap()
+                .sha256
+        );
+        assert_eq!(manifest.digest().len(), 64);
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_manifest_is_deterministic() {
+        let root1 = catalog("deterministic1");
+        let root2 = catalog("deterministic2");
+
+        let digest1 = build_manifest(root1.to_str().unwrap()).unwrap().digest();
+        let digest2 = build_manifest(root2.to_str().unwrap()).unwrap().digest();
+
+        assert_eq!(digest1, digest2);
+        fs::remove_dir_all(root1).unwrap();
+        fs::remove_dir_all(root2).unwrap();
+    }
+
+    #[test]
+    fn test_verify_catalog_detects_issues() {
+        let root = catalog("verify");
+        let path = root.to_str().unwrap();
+        write_manifest(path).unwrap();
+        assert!(verify_catalog(path).unwrap().is_empty());
+
+        OpenOptions::new()
+            .append(true)
+            .open(root.join(AUD).join("part-1.parquet"))
+            .unwrap()
+            .write_all(b"corrupt")
+            .unwrap();
+        fs::remove_file(root.join(BARS)).unwrap();
+        write(
+            &root.join("data/trade_tick.parquet/instrument_id=AUD-USD.SIM/part-0.parquet"),
+            0,
+        );
+
+        assert_eq!(
+            verify_catalog(path).unwrap(),
+            vec![
+                IntegrityIssue::Missing(BARS.to_string()),
+                IntegrityIssue::Modified(AUD.to_string()),
+                IntegrityIssue::Unrecorded(
+                    "data/trade_tick.parquet/instrument_id=AUD-USD.SIM".to_string()
+                ),
+            ]
+        );
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_read_verified() {
+        let root = catalog("read");
+        let path = root.to_str().unwrap();
+        let manifest = write_manifest(path).unwrap();
+
+        let batches = read_verified(path, &manifest, AUD).unwrap();
+        write(&root.join(EUR).join("part-0.parquet"), 100);
+        let result = read_verified(path, &read_manifest(path).unwrap(), EUR);
+
+        assert_eq!(batches.iter().map(|b| b.num_rows()).sum::<usize>(), 20);
+        assert!(matches!(result, Err(CatalogMigrationError::Corrupt(_))));
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,551 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Schema versioning and migrations for catalog Parquet files.
+//!
+//! Each file records its layout version under the `nautilus.schema_version`
+//! schema metadata key (files without it are version 1). Older files can be
+//! upgraded on read with [`migrate_batch`], or rewritten in place with
+//! [`migrate_file`] / [`migrate_catalog`].
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result};
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use arrow::array::{Array, ArrayRef, Int64Array, StringArray, UInt64Array};
+use arrow::compute::cast;
+use arrow::datatypes::{DataType, Field, Schema, SchemaRef};
+use arrow::error::ArrowError;
+use arrow::record_batch::RecordBatch;
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use parquet::arrow::ArrowWriter;
+
+/// The schema metadata key holding the catalog schema version.
+pub const SCHEMA_VERSION_KEY: &str = "nautilus.schema_version";
+
+/// The catalog schema version written by this crate.
+pub const CATALOG_SCHEMA_VERSION: u32 = 3;
+
+/// The decimal places of fixed-point encoded values (as `Price` and `Quantity`).
+pub const FIXED_PRECISION: u32 = 9;
+
+const PRICE_COLUMNS: &[&str] = &["price", "bid", "ask", "open", "high", "low", "close"];
+const QUANTITY_COLUMNS: &[&str] = &["size", "bid_size", "ask_size", "volume"];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CatalogMigrationError {
+    /// The file could not be read or written.
+    Io(String),
+    /// The data could not be converted.
+    Arrow(String),
+    /// The file was written by a newer schema version.
+    UnsupportedVersion(u32),
+    /// The data did not match its recorded content hash.
+    Corrupt(String),
+}
+
+impl Display for CatalogMigrationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            CatalogMigrationError::Io(reason) => write!(f, "Catalog IO error, {}", reason),
+            CatalogMigrationError::Arrow(reason) => {
+                write!(f, "Catalog conversion error, {}", reason)
+            }
+            CatalogMigrationError::UnsupportedVersion(version) => write!(
+                f,
+                "Catalog schema version {} is newer than supported version {}",
+                version, CATALOG_SCHEMA_VERSION
+            ),
+            CatalogMigrationError::Corrupt(reason) => write!(f, "Catalog data corrupt, {}", reason),
+        }
+    }
+}
+
+impl Error for CatalogMigrationError {}
+
+impl From<ArrowError> for CatalogMigrationError {
+    fn from(e: ArrowError) -> Self {
+        CatalogMigrationError::Arrow(e.to_string())
+    }
+}
+
+impl From<parquet::errors::ParquetError> for CatalogMigrationError {
+    fn from(e: parquet::errors::ParquetError) -> Self {
+        CatalogMigrationError::Io(e.to_string())
+    }
+}
+
+impl From<std::io::Error> for CatalogMigrationError {
+    fn from(e: std::io::Error) -> Self {
+        CatalogMigrationError::Io(e.to_string())
+    }
+}
+
+pub type CatalogMigrationResult<T> = std::result::Result<T, CatalogMigrationError>;
+
+/// Represents an upgrade from `from_version` to `from_version + 1`.
+pub struct CatalogMigration {
+    pub from_version: u32,
+    pub description: &'static str,
+    pub apply: fn(&RecordBatch) -> std::result::Result<RecordBatch, ArrowError>,
+}
+
+/// The catalog migrations, in version order.
+pub const CATALOG_MIGRATIONS: &[CatalogMigration] = &[
+    CatalogMigration {
+        from_version: 1,
+        description: "Rename instrument `local_symbol` to `native_symbol`",
+        apply: rename_local_symbol,
+    },
+    CatalogMigration {
+        from_version: 2,
+        description: "Encode decimal string prices and quantities as fixed-point integers",
+        apply: encode_fixed_point,
+    },
+];
+
+/// Returns the schema version of the given schema.
+pub fn schema_version(schema: &Schema) -> u32 {
+    schema
+        .metadata()
+        .get(SCHEMA_VERSION_KEY)
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(1)
+}
+
+fn with_version(schema: &Schema, version: u32) -> Schema {
+    let mut metadata = schema.metadata().clone();
+    metadata.insert(SCHEMA_VERSION_KEY.to_string(), version.to_string());
+    Schema::new_with_metadata(schema.fields().clone(), metadata)
+}
+
+/// Returns the batch with its schema stamped as the current version.
+pub fn with_schema_version(batch: &RecordBatch) -> std::result::Result<RecordBatch, ArrowError> {
+    let schema = Arc::new(with_version(batch.schema_ref(), CATALOG_SCHEMA_VERSION));
+    batch.clone().with_schema(schema)
+}
+
+fn rename_local_symbol(batch: &RecordBatch) -> std::result::Result<RecordBatch, ArrowError> {
+    let schema = batch.schema();
+    let fields: Vec<Field> = schema
+        .fields()
+        .iter()
+        .map(|f| match f.name().as_str() {
+            "local_symbol" => f.as_ref().clone().with_name("native_symbol"),
+            _ => f.as_ref().clone(),
+        })
+        .collect();
+    let schema = Schema::new_with_metadata(fields, schema.metadata().clone());
+    RecordBatch::try_new(Arc::new(schema), batch.columns().to_vec())
+}
+
+/// Parses a decimal string into a fixed-point raw value without going through
+/// floating point, rejecting values with more than `FIXED_PRECISION` decimals.
+pub fn decimal_str_to_raw(s: &str) -> Option<i128> {
+    let (negative, digits) = match s.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, s),
+    };
+    let (int_part, frac_part) = digits.split_once('.').unwrap_or((digits, ""));
+    if int_part.is_empty() && frac_part.is_empty()
+        || frac_part.len() > FIXED_PRECISION as usize
+        || !int_part
+            .chars()
+            .chain(frac_part.chars())
+            .all(|c| c.is_ascii_digit())
+    {
+        return None;
+    }
+    let scale = 10_i128.pow(FIXED_PRECISION);
+    let int_value: i128 = if int_part.is_empty() {
+        0
+    } else {
+        int_part.parse().ok()?
+    };
+    let frac_value: i128 = if frac_part.is_empty() {
+        0
+    } else {
+        frac_part.parse::<i128>().ok()? * 10_i128.pow(FIXED_PRECISION - frac_part.len() as u32)
+    };
+    let raw = int_value.checked_mul(scale)?.checked_add(frac_value)?;
+    Some(if negative { -raw } else { raw })
+}
+
+fn encode_column(column: &ArrayRef, signed: bool) -> std::result::Result<ArrayRef, ArrowError> {
+    let strings = cast(column, &DataType::Utf8)?;
+    let strings = strings
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .expect("cast to Utf8");
+    let raws = strings
+        .iter()
+        .map(|v| match v {
+            Some(s) => decimal_str_to_raw(s)
+                .map(Some)
+                .ok_or_else(|| ArrowError::CastError(format!("invalid decimal '{}'", s))),
+            None => Ok(None),
+        })
+        .collect::<std::result::Result<Vec<Option<i128>>, ArrowError>>()?;
+    let out_of_range = |v: i128| ArrowError::CastError(format!("raw value {} out of range", v));
+    if signed {
+        let values = raws
+            .into_iter()
+            .map(|v| {
+                v.map(|v| i64::try_from(v).map_err(|_| out_of_range(v)))
+                    .transpose()
+            })
+            .collect::<std::result::Result<Int64Array, ArrowError>>()?;
+        Ok(Arc::new(values))
+    } else {
+        let values = raws
+            .into_iter()
+            .map(|v| {
+                v.map(|v| u64::try_from(v).map_err(|_| out_of_range(v)))
+                    .transpose()
+            })
+            .collect::<std::result::Result<UInt64Array, ArrowError>>()?;
+        Ok(Arc::new(values))
+    }
+}
+
+fn encode_fixed_point(batch: &RecordBatch) -> std::result::Result<RecordBatch, ArrowError> {
+    let schema = batch.schema();
+    let mut fields = Vec::with_capacity(schema.fields().len());
+    let mut columns = Vec::with_capacity(batch.num_columns());
+    for (field, column) in schema.fields().iter().zip(batch.columns()) {
+        let name = field.name().as_str();
+        let is_string = matches!(
+            column.data_type(),
+            DataType::Utf8 | DataType::LargeUtf8 | DataType::Dictionary(_, _)
+        );
+        let signed = PRICE_COLUMNS.contains(&name);
+        if is_string && (signed || QUANTITY_COLUMNS.contains(&name)) {
+            let encoded = encode_column(column, signed)?;
+            let mut metadata: HashMap<String, String> = field.metadata().clone();
+            metadata.insert("precision".to_string(), FIXED_PRECISION.to_string());
+            fields.push(
+                Field::new(name, encoded.data_type().clone(), field.is_nullable())
+                    .with_metadata(metadata),
+            );
+            columns.push(encoded);
+        } else {
+            fields.push(field.as_ref().clone());
+            columns.push(column.clone());
+        }
+    }
+    let schema = Schema::new_with_metadata(fields, schema.metadata().clone());
+    RecordBatch::try_new(Arc::new(schema), columns)
+}
+
+/// Returns the batch upgraded to the current schema version.
+pub fn migrate_batch(batch: &RecordBatch) -> CatalogMigrationResult<RecordBatch> {
+    let mut version = schema_version(batch.schema_ref());
+    if version > CATALOG_SCHEMA_VERSION {
+        return Err(CatalogMigrationError::UnsupportedVersion(version));
+    }
+    let mut batch = batch.clone();
+    for migration in CATALOG_MIGRATIONS {
+        if migration.from_version == version {
+            batch = (migration.apply)(&batch)?;
+            version += 1;
+        }
+    }
+    Ok(with_schema_version(&batch)?)
+}
+
+/// Reads all batches of the Parquet file, which carry the file schema
+/// (including its metadata).
+pub fn read_parquet(path: &Path) -> CatalogMigrationResult<(SchemaRef, Vec<RecordBatch>)> {
+    let builder = ParquetRecordBatchReaderBuilder::try_new(File::open(path)?)?;
+    let schema = builder.schema().clone();
+    let batches = builder
+        .build()?
+        .map(|batch| batch?.with_schema(schema.clone()))
+        .collect::<std::result::Result<Vec<_>, ArrowError>>()?;
+    Ok((schema, batches))
+}
+
+/// Reads the Parquet file, upgrading all batches to the current version.
+pub fn read_migrated(path: &Path) -> CatalogMigrationResult<Vec<RecordBatch>> {
+    let (_, batches) = read_parquet(path)?;
+    batches.iter().map(migrate_batch).collect()
+}
+
+/// Upgrades the Parquet file in place, returning its previous version if it
+/// was migrated.
+///
+/// The upgraded file is written alongside and then renamed over the original,
+/// so a failure never leaves a partially written file.
+pub fn migrate_file(path: &Path) -> CatalogMigrationResult<Option<u32>> {
+    let (schema, batches) = read_parquet(path)?;
+    let version = schema_version(&schema);
+    if version == CATALOG_SCHEMA_VERSION {
+        return Ok(None);
+    }
+    if version > CATALOG_SCHEMA_VERSION {
+        return Err(CatalogMigrationError::UnsupportedVersion(version));
+    }
+
+    let migrated = match batches.is_empty() {
+        true => vec![migrate_batch(&RecordBatch::new_empty(schema))?],
+        false => batches
+            .iter()
+            .map(migrate_batch)
+            .collect::<CatalogMigrationResult<Vec<_>>>()?,
+    };
+
+    let tmp_path = path.with_extension("parquet.migrating");
+    let result = (|| {
+        let mut writer =
+            ArrowWriter::try_new(File::create(&tmp_path)?, migrated[0].schema(), None)?;
+        for batch in &migrated {
+            writer.write(batch)?;
+        }
+        writer.close()?;
+        fs::rename(&tmp_path, path)?;
+        Ok(Some(version))
+    })();
+    if result.is_err() {
+        let _ = fs::remove_file(&tmp_path);
+    }
+    result
+}
+
+fn parquet_files(dir: &Path, files: &mut Vec<PathBuf>) -> std::io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            parquet_files(&path, files)?;
+        } else if path.extension().is_some_and(|e| e == "parquet") {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Upgrades every Parquet file under `{catalog_path}/data` in place,
+/// returning the migrated files with their previous versions.
+pub fn migrate_catalog(catalog_path: &str) -> CatalogMigrationResult<Vec<(PathBuf, u32)>> {
+    let mut files = Vec::new();
+    parquet_files(&Path::new(catalog_path).join("data"), &mut files)?;
+    files.sort();
+
+    let mut migrated = Vec::new();
+    for path in files {
+        if let Some(version) = migrate_file(&path)? {
+            migrated.push((path, version));
+        }
+    }
+    Ok(migrated)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::fs::{self, File};
+    use std::path::PathBuf;
+    use std::sync::Arc;
+
+    use arrow::array::{Array, Int64Array, StringArray, UInt64Array};
+    use arrow::datatypes::{DataType, Field, Schema};
+    use arrow::record_batch::RecordBatch;
+    use parquet::arrow::ArrowWriter;
+
+    use super::*;
+
+    fn legacy_trade_batch() -> RecordBatch {
+        let schema = Schema::new(vec![
+            Field::new("price", DataType::Utf8, false),
+            Field::new("size", DataType::Utf8, true),
+            Field::new("ts_init", DataType::Int64, false),
+        ]);
+        RecordBatch::try_new(
+            Arc::new(schema),
+            vec![
+                Arc::new(StringArray::from(vec!["1.00001", "-0.5"])),
+                Arc::new(StringArray::from(vec![Some("100000"), None])),
+                Arc::new(Int64Array::from(vec![1, 2])),
+            ],
+        )
+        .unwrap()
+    }
+
+    fn write(path: &PathBuf, batch: &RecordBatch) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        let mut writer =
+            ArrowWriter::try_new(File::create(path).unwrap(), batch.schema(), None).unwrap();
+        writer.write(batch).unwrap();
+        writer.close().unwrap();
+    }
+
+    fn temp_catalog(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!(
+            "nautilus-catalog-schema-{}-{}",
+            name,
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&root);
+        root
+    }
+
+    #[test]
+    fn test_migrations_are_contiguous() {
+        for (i, migration) in CATALOG_MIGRATIONS.iter().enumerate() {
+            assert_eq!(migration.from_version, i as u32 + 1);
+        }
+        assert_eq!(CATALOG_MIGRATIONS.len() as u32 + 1, CATALOG_SCHEMA_VERSION);
+    }
+
+    #[test]
+    fn test_schema_version_defaults_to_legacy() {
+        let batch = legacy_trade_batch();
+
+        assert_eq!(schema_version(batch.schema_ref()), 1);
+        assert_eq!(
+            schema_version(with_schema_version(&batch).unwrap().schema_ref()),
+            CATALOG_SCHEMA_VERSION
+        );
+    }
+
+    #[test]
+    fn test_decimal_str_to_raw() {
+        assert_eq!(decimal_str_to_raw("1.00001"), Some(1_000_010_000));
+        assert_eq!(decimal_str_to_raw("-0.5"), Some(-500_000_000));
+        assert_eq!(decimal_str_to_raw("100"), Some(100_000_000_000));
+        assert_eq!(decimal_str_to_raw(".25"), Some(250_000_000));
+        assert_eq!(decimal_str_to_raw("0.0000000001"), None);
+        assert_eq!(decimal_str_to_raw("1e5"), None);
+        assert_eq!(decimal_str_to_raw(""), None);
+    }
+
+    #[test]
+    fn test_migrate_batch_renames_local_symbol() {
+        let schema = Schema::new(vec![Field::new("local_symbol", DataType::Utf8, false)]);
+        let batch = RecordBatch::try_new(
+            Arc::new(schema),
+            vec![Arc::new(StringArray::from(vec!["AUD/USD"]))],
+        )
+        .unwrap();
+
+        let migrated = migrate_batch(&batch).unwrap();
+
+        assert_eq!(migrated.schema().field(0).name(), "native_symbol");
+        assert_eq!(
+            schema_version(migrated.schema_ref()),
+            CATALOG_SCHEMA_VERSION
+        );
+    }
+
+    #[test]
+    fn test_migrate_batch_encodes_fixed_point() {
+        let migrated = migrate_batch(&legacy_trade_batch()).unwrap();
+
+        let prices = migrated
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .unwrap();
+        let sizes = migrated
+            .column(1)
+            .as_any()
+            .downcast_ref::<UInt64Array>()
+            .unwrap();
+        assert_eq!(prices.values(), &[1_000_010_000, -500_000_000]);
+        assert_eq!(sizes.value(0), 100_000_000_000_000);
+        assert!(sizes.is_null(1));
+        assert_eq!(
+            migrated.schema().field(0).metadata(),
+            &HashMap::from([("precision".to_string(), "9".to_string())])
+        );
+    }
+
+    #[test]
+    fn test_migrate_batch_is_idempotent() {
+        let migrated = migrate_batch(&legacy_trade_batch()).unwrap();
+
+        assert_eq!(migrate_batch(&migrated).unwrap(), migrated);
+    }
+
+    #[test]
+    fn test_migrate_batch_with_newer_version_returns_error() {
+        let batch = legacy_trade_batch();
+        let schema = with_version(batch.schema_ref(), CATALOG_SCHEMA_VERSION + 1);
+        let batch = batch.with_schema(Arc::new(schema)).unwrap();
+
+        assert_eq!(
+            migrate_batch(&batch),
+            Err(CatalogMigrationError::UnsupportedVersion(
+                CATALOG_SCHEMA_VERSION + 1
+            ))
+        );
+    }
+
+    #[test]
+    fn test_migrate_batch_with_invalid_decimal_returns_error() {
+        let schema = Schema::new(vec![Field::new("price", DataType::Utf8, false)]);
+        let batch = RecordBatch::try_new(
+            Arc::new(schema),
+            vec![Arc::new(StringArray::from(vec!["abc"]))],
+        )
+        .unwrap();
+
+        assert!(matches!(
+            migrate_batch(&batch),
+            Err(CatalogMigrationError::Arrow(_))
+        ));
+    }
+
+    #[test]
+    fn test_migrate_catalog_in_place() {
+        let root = temp_catalog("in-place");
+        let path = root
+            .join("data")
+            .join("trade_tick.parquet")
+            .join("instrument_id=AUD-USD.SIM")
+            .join("part-0.parquet");
+        write(&path, &legacy_trade_batch());
+
+        let migrated = migrate_catalog(root.to_str().unwrap()).unwrap();
+        let again = migrate_catalog(root.to_str().unwrap()).unwrap();
+        let batches = read_migrated(&path).unwrap();
+
+        assert_eq!(migrated, vec![(path.clone(), 1)]);
+        assert!(again.is_empty());
+        assert_eq!(batches[0], migrate_batch(&legacy_trade_batch()).unwrap());
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_read_migrated_leaves_file_unchanged() {
+        let root = temp_catalog("on-read");
+        let path = root.join("data").join("trade_tick.parquet");
+        write(&path, &legacy_trade_batch());
+
+        let batches = read_migrated(&path).unwrap();
+
+        assert_eq!(
+            schema_version(batches[0].schema_ref()),
+            CATALOG_SCHEMA_VERSION
+        );
+        assert_eq!(migrate_file(&path).unwrap(), Some(1));
+        fs::remove_dir_all(root).unwrap();
+    }
+}
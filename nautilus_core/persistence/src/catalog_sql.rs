@@ -0,0 +1,430 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! SQL queries over the Parquet data catalog.
+//!
+//! Each `{catalog}/data/{name}.parquet` dataset is registered as a table named
+//! `{name}` (e.g. `trade_tick`), with any hive style partition directories
+//! (e.g. `instrument_id=AUD-USD.SIM`) exposed as string columns.
+
+use std::error::Error;
+use std::ffi::{c_char, CStr};
+use std::fmt::{Display, Formatter, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use arrow::ffi_stream::FFI_ArrowArrayStream;
+use arrow::record_batch::{RecordBatch, RecordBatchIterator};
+use datafusion::arrow::datatypes::DataType;
+use datafusion::datasource::file_format::parquet::ParquetFormat;
+use datafusion::datasource::listing::ListingOptions;
+use datafusion::prelude::{ParquetReadOptions, SessionContext};
+use tokio::runtime::Runtime;
+
+const PARQUET_EXT: &str = ".parquet";
+
+/// Represents a catalog SQL failure.
+///
+/// The `code` of each variant is returned by the C API, where zero indicates
+/// success.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CatalogSqlError {
+    /// An argument was null or not valid UTF-8.
+    InvalidArgument(String),
+    /// The catalog could not be read or registered.
+    Catalog(String),
+    /// The query failed to plan or execute.
+    Query(String),
+}
+
+impl CatalogSqlError {
+    pub fn code(&self) -> u8 {
+        match self {
+            CatalogSqlError::InvalidArgument(_) => 1,
+            CatalogSqlError::Catalog(_) => 2,
+            CatalogSqlError::Query(_) => 3,
+        }
+    }
+}
+
+impl Display for CatalogSqlError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            CatalogSqlError::InvalidArgument(reason) => write!(f, "Invalid argument, {}", reason),
+            CatalogSqlError::Catalog(reason) => write!(f, "Catalog error, {}", reason),
+            CatalogSqlError::Query(reason) => write!(f, "Query error, {}", reason),
+        }
+    }
+}
+
+impl Error for CatalogSqlError {}
+
+pub type CatalogSqlResult<T> = std::result::Result<T, CatalogSqlError>;
+
+/// Returns the hive partition column names found along the first path of
+/// `key=value` directories under `dir`.
+fn partition_columns(dir: &Path) -> Vec<String> {
+    let mut columns = Vec::new();
+    let mut current = dir.to_path_buf();
+    loop {
+        let next = fs::read_dir(&current).ok().and_then(|entries| {
+            let mut dirs: Vec<PathBuf> = entries
+                .filter_map(|e| e.ok().map(|e| e.path()))
+                .filter(|p| p.is_dir())
+                .collect();
+            dirs.sort();
+            dirs.into_iter().find(|p| {
+                p.file_name()
+                    .and_then(|n| n.to_str())
+                    .is_some_and(|n| n.contains('='))
+            })
+        });
+        match next {
+            Some(path) => {
+                let name = path.file_name().unwrap().to_string_lossy().to_string();
+                columns.push(name.split_once('=').unwrap().0.to_string());
+                current = path;
+            }
+            None => return columns,
+        }
+    }
+}
+
+/// Provides SQL queries over a Parquet data catalog.
+pub struct CatalogSql {
+    ctx: SessionContext,
+    runtime: Runtime,
+    tables: Vec<String>,
+}
+
+impl CatalogSql {
+    /// Registers every dataset under `{catalog_path}/data`.
+    pub fn new(catalog_path: &str) -> CatalogSqlResult<Self> {
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .map_err(|e| CatalogSqlError::Catalog(e.to_string()))?;
+        let ctx = SessionContext::new();
+        let data_dir = Path::new(catalog_path).join("data");
+        let entries =
+            fs::read_dir(&data_dir).map_err(|e| CatalogSqlError::Catalog(e.to_string()))?;
+
+        let mut datasets: Vec<PathBuf> = entries
+            .filter_map(|e| e.ok().map(|e| e.path()))
+            .filter(|p| p.to_string_lossy().ends_with(PARQUET_EXT))
+            .collect();
+        datasets.sort();
+
+        let mut tables = Vec::new();
+        for path in datasets {
+            let name = path
+                .file_name()
+                .unwrap()
+                .to_string_lossy()
+                .trim_end_matches(PARQUET_EXT)
+                .to_string();
+            let location = path.to_string_lossy().to_string();
+            let result = if path.is_dir() {
+                let partition_cols = partition_columns(&path)
+                    .into_iter()
+                    .map(|c| (c, DataType::Utf8))
+                    .collect();
+                let options = ListingOptions::new(std::sync::Arc::new(ParquetFormat::default()))
+                    .with_file_extension(PARQUET_EXT)
+                    .with_table_partition_cols(partition_cols);
+                runtime.block_on(ctx.register_listing_table(
+                    name.as_str(),
+                    format!("{}/", location),
+                    options,
+                    None,
+                    None,
+                ))
+            } else {
+                runtime.block_on(ctx.register_parquet(
+                    name.as_str(),
+                    &location,
+                    ParquetReadOptions::default(),
+                ))
+            };
+            result.map_err(|e| CatalogSqlError::Catalog(format!("{}: {}", name, e)))?;
+            tables.push(name);
+        }
+
+        Ok(CatalogSql {
+            ctx,
+            runtime,
+            tables,
+        })
+    }
+
+    /// Returns the registered table names, sorted.
+    pub fn tables(&self) -> &[String] {
+        &self.tables
+    }
+
+    /// Returns the underlying session for custom registrations and async use.
+    pub fn context(&self) -> &SessionContext {
+        &self.ctx
+    }
+
+    /// Runs the SQL query, blocking until all result batches are collected.
+    pub fn query(&self, sql: &str) -> CatalogSqlResult<Vec<RecordBatch>> {
+        self.runtime.block_on(async {
+            let df = self
+                .ctx
+                .sql(sql)
+                .await
+                .map_err(|e| CatalogSqlError::Query(e.to_string()))?;
+            df.collect()
+                .await
+                .map_err(|e| CatalogSqlError::Query(e.to_string()))
+        })
+    }
+}
+
+fn query_stream(catalog_path: &str, sql: &str) -> CatalogSqlResult<FFI_ArrowArrayStream> {
+    let catalog = CatalogSql::new(catalog_path)?;
+    let batches = catalog.query(sql)?;
+    let schema = match batches.first() {
+        Some(batch) => batch.schema(),
+        None => catalog
+            .runtime
+            .block_on(catalog.ctx.sql(sql))
+            .map_err(|e| CatalogSqlError::Query(e.to_string()))?
+            .schema()
+            .inner()
+            .clone(),
+    };
+    let reader = RecordBatchIterator::new(batches.into_iter().map(Ok), schema);
+    Ok(FFI_ArrowArrayStream::new(Box::new(reader)))
+}
+
+unsafe fn c_str_arg<'a>(ptr: *const c_char, name: &str) -> CatalogSqlResult<&'a str> {
+    if ptr.is_null() {
+        return Err(CatalogSqlError::InvalidArgument(format!(
+            "`{}` was null",
+            name
+        )));
+    }
+    CStr::from_ptr(ptr)
+        .to_str()
+        .map_err(|_| CatalogSqlError::InvalidArgument(format!("`{}` was not UTF-8", name)))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// C API
+////////////////////////////////////////////////////////////////////////////////
+/// Runs the SQL query against the catalog and writes the results to `out` as
+/// an Arrow C stream, returning zero on success or the `CatalogSqlError` code.
+///
+/// # Safety
+///
+/// - `catalog_path` and `sql` must be valid null terminated UTF-8 C strings.
+/// - `out` must be a valid pointer to write the stream to, which the caller
+///   then owns (e.g. `pyarrow.RecordBatchReader._import_from_c`).
+#[no_mangle]
+pub unsafe extern "C" fn catalog_sql_query(
+    catalog_path: *const c_char,
+    sql: *const c_char,
+    out: *mut FFI_ArrowArrayStream,
+) -> u8 {
+    let result = c_str_arg(catalog_path, "catalog_path")
+        .and_then(|path| Ok((path, c_str_arg(sql, "sql")?)))
+        .and_then(|(path, sql)| query_stream(path, sql));
+    match result {
+        Ok(stream) => {
+            out.write(stream);
+            0
+        }
+        Err(e) => e.code(),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::ffi::CString;
+    use std::fs::{self, File};
+    use std::path::PathBuf;
+    use std::sync::Arc;
+
+    use arrow::array::{Array, Float64Array, Int64Array, StringArray, UInt64Array};
+    use arrow::datatypes::{DataType, Field, Schema};
+    use arrow::ffi_stream::{ArrowArrayStreamReader, FFI_ArrowArrayStream};
+    use arrow::record_batch::RecordBatch;
+    use datafusion::parquet::arrow::ArrowWriter;
+
+    use super::{catalog_sql_query, CatalogSql, CatalogSqlError};
+
+    fn write_parquet(path: PathBuf, prices: &[f64], ts: &[u64]) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("price", DataType::Float64, false),
+            Field::new("ts_init", DataType::UInt64, false),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(Float64Array::from(prices.to_vec())),
+                Arc::new(UInt64Array::from(ts.to_vec())),
+            ],
+        )
+        .unwrap();
+        let mut writer = ArrowWriter::try_new(File::create(path).unwrap(), schema, None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+    }
+
+    fn catalog(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!(
+            "nautilus-catalog-sql-{}-{}",
+            name,
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&root);
+        let data = root.join("data");
+        let ticks = data.join("trade_tick.parquet");
+        write_parquet(
+            ticks
+                .join("instrument_id=AUD-USD.SIM")
+                .join("part-0.parquet"),
+            &[1.0, 2.0, 3.0],
+            &[1, 2, 3],
+        );
+        write_parquet(
+            ticks
+                .join("instrument_id=EUR-USD.SIM")
+                .join("part-0.parquet"),
+            &[10.0, 20.0],
+            &[2, 3],
+        );
+        write_parquet(data.join("bar.parquet"), &[5.0], &[1]);
+        root
+    }
+
+    #[test]
+    fn test_registers_catalog_tables() {
+        let root = catalog("tables");
+
+        let sql = CatalogSql::new(root.to_str().unwrap()).unwrap();
+
+        assert_eq!(sql.tables(), ["bar", "trade_tick"]);
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_query_aggregates_across_partitions() {
+        let root = catalog("aggregate");
+        let sql = CatalogSql::new(root.to_str().unwrap()).unwrap();
+
+        let batches = sql
+            .query(
+                "SELECT instrument_id, COUNT(*) AS n, SUM(price) AS total FROM trade_tick \
+                 GROUP BY instrument_id ORDER BY instrument_id",
+            )
+            .unwrap();
+
+        let batch = &batches[0];
+        let ids = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        let counts = batch
+            .column(1)
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .unwrap();
+        let totals = batch
+            .column(2)
+            .as_any()
+            .downcast_ref::<Float64Array>()
+            .unwrap();
+        assert_eq!(ids.value(0), "AUD-USD.SIM");
+        assert_eq!(ids.value(1), "EUR-USD.SIM");
+        assert_eq!(counts.values(), &[3, 2]);
+        assert_eq!(totals.values(), &[6.0, 30.0]);
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_query_joins_across_instruments() {
+        let root = catalog("join");
+        let sql = CatalogSql::new(root.to_str().unwrap()).unwrap();
+
+        let batches = sql
+            .query(
+                "SELECT a.ts_init, b.price / a.price AS ratio \
+                 FROM trade_tick a JOIN trade_tick b ON a.ts_init = b.ts_init \
+                 WHERE a.instrument_id = 'AUD-USD.SIM' AND b.instrument_id = 'EUR-USD.SIM' \
+                 ORDER BY a.ts_init",
+            )
+            .unwrap();
+
+        let ratios = batches[0]
+            .column(1)
+            .as_any()
+            .downcast_ref::<Float64Array>()
+            .unwrap();
+        assert_eq!(ratios.values(), &[5.0, 20.0 / 3.0]);
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_query_with_invalid_sql_returns_error() {
+        let root = catalog("invalid");
+        let sql = CatalogSql::new(root.to_str().unwrap()).unwrap();
+
+        let result = sql.query("SELECT * FROM quote_tick");
+
+        assert!(matches!(result, Err(CatalogSqlError::Query(_))));
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_missing_catalog_returns_error() {
+        let result = CatalogSql::new("/nonexistent/catalog");
+
+        assert!(matches!(result, Err(CatalogSqlError::Catalog(_))));
+    }
+
+    #[test]
+    fn test_catalog_sql_query_c_api() {
+        let root = catalog("ffi");
+        let path = CString::new(root.to_str().unwrap()).unwrap();
+        let query = CString::new("SELECT price FROM trade_tick WHERE price > 2").unwrap();
+        let mut stream = FFI_ArrowArrayStream::empty();
+
+        let code = unsafe { catalog_sql_query(path.as_ptr(), query.as_ptr(), &mut stream) };
+        let reader = ArrowArrayStreamReader::try_new(stream).unwrap();
+        let rows: usize = reader.map(|b| b.unwrap().num_rows()).sum();
+
+        assert_eq!(code, 0);
+        assert_eq!(rows, 3);
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_catalog_sql_query_c_api_with_null_argument() {
+        let query = CString::new("SELECT 1").unwrap();
+        let mut stream = FFI_ArrowArrayStream::empty();
+
+        let code = unsafe { catalog_sql_query(std::ptr::null(), query.as_ptr(), &mut stream) };
+
+        assert_eq!(code, 1);
+    }
+}
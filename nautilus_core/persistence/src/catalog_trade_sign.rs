@@ -0,0 +1,298 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Trade sign classification of catalog trade batches.
+//!
+//! For trade data without the aggressor side, the `aggressor_side` column of
+//! a batch of trades (at ingestion or query time) is filled in by the trade
+//! sign classification of the model crate, and whether each side was
+//! inferred is recorded in the `aggressor_side_inferred` column.
+//!
+//! Price columns may be fixed-point integers or decimal strings, and missing
+//! sides are `UNKNOWN` or null.
+
+use std::sync::Arc;
+
+use arrow::array::{Array, ArrayRef, BooleanArray, Int64Array, StringArray};
+use arrow::compute::cast;
+use arrow::datatypes::{DataType, Field, Schema};
+use arrow::error::ArrowError;
+use arrow::record_batch::RecordBatch;
+use nautilus_model::algorithms::trade_sign::{
+    classify_trades, SignQuote, SignTrade, TradeSignMethod,
+};
+use nautilus_model::enums::AggressorSide;
+use nautilus_model::types::price::Price;
+use nautilus_model::types::quantity::Quantity;
+
+use crate::catalog_schema::{decimal_str_to_raw, FIXED_PRECISION};
+
+/// The column recording whether the aggressor side of a trade was inferred.
+pub const INFERRED_COLUMN: &str = "aggressor_side_inferred";
+
+const SIDE_COLUMN: &str = "aggressor_side";
+
+fn column<'a>(batch: &'a RecordBatch, name: &str) -> Result<&'a ArrayRef, ArrowError> {
+    batch
+        .column_by_name(name)
+        .ok_or_else(|| ArrowError::SchemaError(format!("missing column `{}`", name)))
+}
+
+fn non_null<T: Copy>(
+    name: &str,
+    values: impl Iterator<Item = Option<T>>,
+) -> Result<Vec<T>, ArrowError> {
+    values
+        .map(|v| {
+            v.ok_or_else(|| ArrowError::InvalidArgumentError(format!("null in column `{}`", name)))
+        })
+        .collect()
+}
+
+fn prices(batch: &RecordBatch, name: &str) -> Result<Vec<Price>, ArrowError> {
+    let array = column(batch, name)?;
+    let raws = match array.data_type() {
+        DataType::Int64 => {
+            let raws = array.as_any().downcast_ref::<Int64Array>().expect("Int64");
+            non_null(name, raws.iter())?
+        }
+        _ => {
+            let strings = cast(array, &DataType::Utf8)?;
+            let strings = strings
+                .as_any()
+                .downcast_ref::<StringArray>()
+                .expect("cast to Utf8");
+            let raws = strings.iter().map(|v| {
+                v.and_then(decimal_str_to_raw)
+                    .and_then(|raw| i64::try_from(raw).ok())
+            });
+            non_null(name, raws)?
+        }
+    };
+    Ok(raws
+        .into_iter()
+        .map(|raw| Price::from_raw(raw, FIXED_PRECISION as u8))
+        .collect())
+}
+
+fn timestamps(batch: &RecordBatch) -> Result<Vec<i64>, ArrowError> {
+    let array = cast(column(batch, "ts_event")?, &DataType::Int64)?;
+    let array = array
+        .as_any()
+        .downcast_ref::<Int64Array>()
+        .expect("cast to Int64");
+    non_null("ts_event", array.iter())
+}
+
+fn sides(batch: &RecordBatch) -> Result<Vec<AggressorSide>, ArrowError> {
+    let strings = cast(column(batch, SIDE_COLUMN)?, &DataType::Utf8)?;
+    let strings = strings
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .expect("cast to Utf8");
+    Ok(strings
+        .iter()
+        .map(|side| match side {
+            Some("BUY") => AggressorSide::Buy,
+            Some("SELL") => AggressorSide::Sell,
+            _ => AggressorSide::Unknown,
+        })
+        .collect())
+}
+
+fn side_str(side: AggressorSide) -> &'static str {
+    match side {
+        AggressorSide::Unknown => "UNKNOWN",
+        AggressorSide::Buy => "BUY",
+        AggressorSide::Sell => "SELL",
+    }
+}
+
+/// Returns the trades (oldest first) with the unknown aggressor sides
+/// classified by the method, against the `bid`, `ask` and `ts_event` columns
+/// of the quotes (oldest first) for Lee-Ready.
+///
+/// The `aggressor_side` column keeps its data type, and the
+/// `aggressor_side_inferred` column is added (or replaced).
+pub fn classify_trade_batch(
+    trades: &RecordBatch,
+    quotes: Option<&RecordBatch>,
+    method: TradeSignMethod,
+    quote_lag_ns: i64,
+) -> Result<RecordBatch, ArrowError> {
+    let sign_trades: Vec<SignTrade> = prices(trades, "price")?
+        .into_iter()
+        .zip(sides(trades)?)
+        .zip(timestamps(trades)?)
+        .map(|((price, aggressor_side), ts_event)| SignTrade {
+            price,
+            size: Quantity::default(), // Not used to sign trades
+            aggressor_side,
+            ts_event,
+        })
+        .collect();
+    let sign_quotes: Vec<SignQuote> = match quotes {
+        Some(quotes) => prices(quotes, "bid")?
+            .into_iter()
+            .zip(prices(quotes, "ask")?)
+            .zip(timestamps(quotes)?)
+            .map(|((bid, ask), ts_event)| SignQuote { bid, ask, ts_event })
+            .collect(),
+        None => Vec::new(),
+    };
+    let signs = classify_trades(&sign_trades, &sign_quotes, method, quote_lag_ns);
+
+    let side_type = column(trades, SIDE_COLUMN)?.data_type().clone();
+    let side_strings: StringArray = signs
+        .iter()
+        .map(|sign| Some(side_str(sign.aggressor_side)))
+        .collect();
+    let side_array = cast(&(Arc::new(side_strings) as ArrayRef), &side_type)?;
+    let inferred_array: ArrayRef = Arc::new(
+        signs
+            .iter()
+            .map(|sign| Some(sign.inferred != 0))
+            .collect::<BooleanArray>(),
+    );
+
+    let schema = trades.schema();
+    let mut fields = Vec::with_capacity(schema.fields().len() + 1);
+    let mut columns = Vec::with_capacity(schema.fields().len() + 1);
+    for (field, array) in schema.fields().iter().zip(trades.columns()) {
+        match field.name().as_str() {
+            SIDE_COLUMN => {
+                fields.push(field.as_ref().clone());
+                columns.push(side_array.clone());
+            }
+            INFERRED_COLUMN => {} // Replaced
+            _ => {
+                fields.push(field.as_ref().clone());
+                columns.push(array.clone());
+            }
+        }
+    }
+    fields.push(Field::new(INFERRED_COLUMN, DataType::Boolean, false));
+    columns.push(inferred_array);
+    let schema = Schema::new_with_metadata(fields, schema.metadata().clone());
+    RecordBatch::try_new(Arc::new(schema), columns)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use arrow::array::DictionaryArray;
+    use arrow::datatypes::Int8Type;
+
+    use super::*;
+
+    fn trades_batch(prices: Vec<&str>, sides: Vec<Option<&str>>) -> RecordBatch {
+        let ts_event: Vec<i64> = (1..=prices.len() as i64).map(|i| i * 10).collect();
+        let sides: DictionaryArray<Int8Type> = sides.into_iter().collect();
+        let schema = Schema::new(vec![
+            Field::new("price", DataType::Utf8, false),
+            Field::new(
+                SIDE_COLUMN,
+                DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Utf8)),
+                true,
+            ),
+            Field::new("ts_event", DataType::Int64, false),
+        ]);
+        RecordBatch::try_new(
+            Arc::new(schema),
+            vec![
+                Arc::new(StringArray::from(prices)),
+                Arc::new(sides),
+                Arc::new(Int64Array::from(ts_event)),
+            ],
+        )
+        .unwrap()
+    }
+
+    fn side_values(batch: &RecordBatch) -> Vec<String> {
+        let strings = cast(batch.column_by_name(SIDE_COLUMN).unwrap(), &DataType::Utf8).unwrap();
+        let strings = strings.as_any().downcast_ref::<StringArray>().unwrap();
+        strings.iter().map(|s| s.unwrap().to_string()).collect()
+    }
+
+    fn inferred_values(batch: &RecordBatch) -> Vec<bool> {
+        let inferred = batch.column_by_name(INFERRED_COLUMN).unwrap();
+        let inferred = inferred.as_any().downcast_ref::<BooleanArray>().unwrap();
+        inferred.iter().map(|v| v.unwrap()).collect()
+    }
+
+    #[test]
+    fn test_classify_trade_batch_by_tick_rule() {
+        let trades = trades_batch(
+            vec!["1.0000", "1.0001", "1.0000", "1.0000"],
+            vec![Some("UNKNOWN"), None, Some("BUY"), Some("UNKNOWN")],
+        );
+
+        let result = classify_trade_batch(&trades, None, TradeSignMethod::TickRule, 0).unwrap();
+
+        assert_eq!(
+            result
+                .schema()
+                .field_with_name(SIDE_COLUMN)
+                .unwrap()
+                .data_type(),
+            trades
+                .schema()
+                .field_with_name(SIDE_COLUMN)
+                .unwrap()
+                .data_type()
+        );
+        assert_eq!(side_values(&result), vec!["UNKNOWN", "BUY", "BUY", "SELL"]);
+        assert_eq!(inferred_values(&result), vec![false, true, false, true]);
+    }
+
+    #[test]
+    fn test_classify_trade_batch_by_lee_ready_with_raw_quotes() {
+        let trades = trades_batch(vec!["1.0000", "1.0002"], vec![None, None]);
+        let quotes = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![
+                Field::new("bid", DataType::Int64, false),
+                Field::new("ask", DataType::Int64, false),
+                Field::new("ts_event", DataType::Int64, false),
+            ])),
+            vec![
+                Arc::new(Int64Array::from(vec![999_900_000, 1_000_200_000])),
+                Arc::new(Int64Array::from(vec![1_000_000_000, 1_000_400_000])),
+                Arc::new(Int64Array::from(vec![5, 15])),
+            ],
+        )
+        .unwrap();
+
+        let result =
+            classify_trade_batch(&trades, Some(&quotes), TradeSignMethod::LeeReady, 0).unwrap();
+        let reclassified =
+            classify_trade_batch(&result, Some(&quotes), TradeSignMethod::LeeReady, 0).unwrap();
+
+        assert_eq!(side_values(&result), vec!["BUY", "SELL"]);
+        assert_eq!(inferred_values(&result), vec![true, true]);
+        assert_eq!(reclassified.num_columns(), result.num_columns());
+    }
+
+    #[test]
+    fn test_classify_trade_batch_with_missing_column_returns_error() {
+        let trades = trades_batch(vec!["1.0"], vec![None]);
+        let quotes = trades.clone();
+
+        let result = classify_trade_batch(&trades, Some(&quotes), TradeSignMethod::LeeReady, 0);
+
+        assert!(matches!(result, Err(ArrowError::SchemaError(_))));
+    }
+}
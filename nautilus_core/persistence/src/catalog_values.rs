@@ -0,0 +1,296 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Arrow fields and arrays for value types.
+//!
+//! `Price`, `Quantity` and `Money` columns hold the fixed-point raw values
+//! (as the migrated catalog columns), with the value precision and currency
+//! recorded in the field metadata, rather than as lossy `f64` columns.
+//! `Currency` columns hold the currency codes.
+
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use arrow::array::{Array, Int64Array, StringArray, UInt64Array};
+use arrow::datatypes::{DataType, Field};
+use arrow::error::ArrowError;
+use nautilus_model::types::currency::Currency;
+use nautilus_model::types::fixed::FIXED_PRECISION;
+use nautilus_model::types::money::Money;
+use nautilus_model::types::price::Price;
+use nautilus_model::types::quantity::Quantity;
+
+/// The field metadata key holding the decimal places of the raw values.
+pub const RAW_PRECISION_KEY: &str = "precision";
+
+/// The field metadata key holding the precision of the values.
+pub const VALUE_PRECISION_KEY: &str = "nautilus.precision";
+
+/// The field metadata key holding the currency code of money values.
+pub const CURRENCY_KEY: &str = "nautilus.currency";
+
+fn fixed_field(name: &str, data_type: DataType, precision: u8) -> Field {
+    Field::new(name, data_type, false).with_metadata(HashMap::from([
+        (RAW_PRECISION_KEY.to_string(), FIXED_PRECISION.to_string()),
+        (VALUE_PRECISION_KEY.to_string(), precision.to_string()),
+    ]))
+}
+
+/// Returns the field for a column of prices with the precision.
+pub fn price_field(name: &str, precision: u8) -> Field {
+    fixed_field(name, DataType::Int64, precision)
+}
+
+/// Returns the field for a column of quantities with the precision.
+pub fn quantity_field(name: &str, precision: u8) -> Field {
+    fixed_field(name, DataType::UInt64, precision)
+}
+
+/// Returns the field for a column of money in the currency.
+pub fn money_field(name: &str, currency: &Currency) -> Field {
+    let field = fixed_field(name, DataType::Int64, currency.precision);
+    let mut metadata = field.metadata().clone();
+    metadata.insert(CURRENCY_KEY.to_string(), currency.code.to_string());
+    field.with_metadata(metadata)
+}
+
+/// Returns the field for a column of currency codes.
+pub fn currency_field(name: &str) -> Field {
+    Field::new(name, DataType::Utf8, false)
+}
+
+/// Returns the precision of the values of the field, or `FIXED_PRECISION`
+/// if not recorded (as for migrated columns).
+pub fn value_precision(field: &Field) -> Result<u8, ArrowError> {
+    match field.metadata().get(VALUE_PRECISION_KEY) {
+        Some(value) => value
+            .parse::<u8>()
+            .ok()
+            .filter(|precision| *precision <= FIXED_PRECISION)
+            .ok_or_else(|| {
+                ArrowError::SchemaError(format!(
+                    "invalid precision '{}' for field '{}'",
+                    value,
+                    field.name()
+                ))
+            }),
+        None => Ok(FIXED_PRECISION),
+    }
+}
+
+fn downcast<'a, T: 'static>(field: &Field, array: &'a dyn Array) -> Result<&'a T, ArrowError> {
+    if array.null_count() > 0 {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "null values in field '{}'",
+            field.name()
+        )));
+    }
+    array.as_any().downcast_ref::<T>().ok_or_else(|| {
+        ArrowError::CastError(format!(
+            "unexpected data type {} for field '{}'",
+            array.data_type(),
+            field.name()
+        ))
+    })
+}
+
+pub fn prices_to_array(prices: &[Price]) -> Int64Array {
+    prices
+        .iter()
+        .map(|price| price.raw)
+        .collect::<Vec<_>>()
+        .into()
+}
+
+pub fn quantities_to_array(quantities: &[Quantity]) -> UInt64Array {
+    quantities
+        .iter()
+        .map(|qty| qty.raw)
+        .collect::<Vec<_>>()
+        .into()
+}
+
+/// Returns the raw values of the money, or an error if the currencies are
+/// not all `currency` (the currency of the field).
+pub fn money_to_array(money: &[Money], currency: &Currency) -> Result<Int64Array, ArrowError> {
+    money
+        .iter()
+        .map(|m| match m.currency == *currency {
+            true => Ok(m.raw()),
+            false => Err(ArrowError::InvalidArgumentError(format!(
+                "money in {} for a {} column",
+                m.currency.code, currency.code
+            ))),
+        })
+        .collect::<Result<Vec<_>, ArrowError>>()
+        .map(Int64Array::from)
+}
+
+pub fn currencies_to_array(currencies: &[Currency]) -> StringArray {
+    currencies
+        .iter()
+        .map(|currency| Some(currency.code.as_str()))
+        .collect()
+}
+
+/// Returns the prices of the column, at the precision of the field.
+pub fn prices_from_array(field: &Field, array: &dyn Array) -> Result<Vec<Price>, ArrowError> {
+    let precision = value_precision(field)?;
+    let raws = downcast::<Int64Array>(field, array)?;
+    Ok(raws
+        .values()
+        .iter()
+        .map(|raw| Price::from_raw(*raw, precision))
+        .collect())
+}
+
+/// Returns the quantities of the column, at the precision of the field.
+pub fn quantities_from_array(
+    field: &Field,
+    array: &dyn Array,
+) -> Result<Vec<Quantity>, ArrowError> {
+    let precision = value_precision(field)?;
+    let raws = downcast::<UInt64Array>(field, array)?;
+    Ok(raws
+        .values()
+        .iter()
+        .map(|raw| Quantity::from_raw(*raw, precision))
+        .collect())
+}
+
+/// Returns the money of the column, in the registered currency of the field.
+pub fn money_from_array(field: &Field, array: &dyn Array) -> Result<Vec<Money>, ArrowError> {
+    let code = field.metadata().get(CURRENCY_KEY).ok_or_else(|| {
+        ArrowError::SchemaError(format!("no currency for field '{}'", field.name()))
+    })?;
+    let currency = Currency::from_str(code).map_err(ArrowError::SchemaError)?;
+    let raws = downcast::<Int64Array>(field, array)?;
+    Ok(raws
+        .values()
+        .iter()
+        .map(|raw| Money::from_raw(*raw, currency.clone()))
+        .collect())
+}
+
+/// Returns the registered currencies of the column of codes.
+pub fn currencies_from_array(
+    field: &Field,
+    array: &dyn Array,
+) -> Result<Vec<Currency>, ArrowError> {
+    downcast::<StringArray>(field, array)?
+        .iter()
+        .map(|code| Currency::from_str(code.unwrap_or_default()).map_err(ArrowError::CastError))
+        .collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow::datatypes::Schema;
+    use arrow::record_batch::RecordBatch;
+
+    use super::*;
+
+    fn usd() -> Currency {
+        Currency::from_code("USD").unwrap()
+    }
+
+    #[test]
+    fn test_value_columns_round_trip_through_batch() {
+        let prices = vec![Price::new(1.00001, 5), Price::new(-0.5, 5)];
+        let quantities = vec![Quantity::new(100.0, 0), Quantity::new(0.0, 0)];
+        let money = vec![Money::new(10.25, usd()), Money::new(-3.5, usd())];
+        let currencies = vec![usd(), Currency::from_code("BTC").unwrap()];
+        let schema = Schema::new(vec![
+            price_field("price", 5),
+            quantity_field("size", 0),
+            money_field("commission", &usd()),
+            currency_field("currency"),
+        ]);
+
+        let batch = RecordBatch::try_new(
+            Arc::new(schema),
+            vec![
+                Arc::new(prices_to_array(&prices)),
+                Arc::new(quantities_to_array(&quantities)),
+                Arc::new(money_to_array(&money, &usd()).unwrap()),
+                Arc::new(currencies_to_array(&currencies)),
+            ],
+        )
+        .unwrap();
+        let schema = batch.schema();
+
+        let price_result = prices_from_array(schema.field(0), batch.column(0)).unwrap();
+        let qty_result = quantities_from_array(schema.field(1), batch.column(1)).unwrap();
+        assert_eq!(price_result, prices);
+        assert!(price_result.iter().all(|p| p.precision == 5));
+        assert_eq!(qty_result, quantities);
+        assert!(qty_result.iter().all(|q| q.precision == 0));
+        assert_eq!(
+            money_from_array(schema.field(2), batch.column(2)).unwrap(),
+            money
+        );
+        assert_eq!(
+            currencies_from_array(schema.field(3), batch.column(3)).unwrap(),
+            currencies
+        );
+    }
+
+    #[test]
+    fn test_fields_record_raw_and_value_precision() {
+        let field = money_field("commission", &Currency::from_code("JPY").unwrap());
+
+        assert_eq!(field.data_type(), &DataType::Int64);
+        assert_eq!(field.metadata()[RAW_PRECISION_KEY], "9");
+        assert_eq!(field.metadata()[VALUE_PRECISION_KEY], "0");
+        assert_eq!(field.metadata()[CURRENCY_KEY], "JPY");
+    }
+
+    #[test]
+    fn test_prices_from_migrated_column_use_fixed_precision() {
+        let field = Field::new("price", DataType::Int64, false);
+        let array = Int64Array::from(vec![1_000_010_000]);
+
+        let prices = prices_from_array(&field, &array).unwrap();
+
+        assert_eq!(prices, vec![Price::new(1.00001, 5)]);
+        assert_eq!(prices[0].precision, FIXED_PRECISION);
+    }
+
+    #[test]
+    fn test_invalid_columns_return_errors() {
+        let eur = Currency::from_code("EUR").unwrap();
+        let nulls = Int64Array::from(vec![Some(1), None]);
+        let no_currency = price_field("commission", 2);
+        let bad_precision = Field::new("price", DataType::Int64, false).with_metadata(
+            HashMap::from([(VALUE_PRECISION_KEY.to_string(), "10".to_string())]),
+        );
+
+        assert!(money_to_array(&[Money::new(1.0, eur)], &usd()).is_err());
+        assert!(prices_from_array(&price_field("price", 2), &nulls).is_err());
+        assert!(quantities_from_array(&quantity_field("size", 0), &nulls).is_err());
+        assert!(money_from_array(&no_currency, &Int64Array::from(vec![1])).is_err());
+        assert!(prices_from_array(&bad_precision, &Int64Array::from(vec![1])).is_err());
+        assert!(currencies_from_array(
+            &currency_field("currency"),
+            &StringArray::from(vec!["ZZZ"])
+        )
+        .is_err());
+    }
+}
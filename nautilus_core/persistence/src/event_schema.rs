@@ -0,0 +1,428 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Versioned MessagePack encoding of events.
+//!
+//! Events are written as a `[event_type, schema_version, payload]` envelope,
+//! where the payload is the event serialized as a map of field names. When an
+//! event struct evolves, its registered version is bumped and the changes from
+//! the previous version are recorded as [`FieldChange`]s, which are applied in
+//! order to upgrade older payloads (e.g. from journals or Redis) on decode.
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result};
+
+use rmpv::Value;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EventSchemaError {
+    /// The event could not be serialized.
+    Encode(String),
+    /// The bytes were not a valid envelope or payload.
+    Decode(String),
+    /// The event type was not registered.
+    UnknownEvent(String),
+    /// The event was not of the expected type.
+    UnexpectedEvent { expected: String, found: String },
+    /// The event was written by a newer schema version.
+    UnsupportedVersion { event_type: String, version: u32 },
+    /// No migration was registered from the schema version.
+    MissingMigration { event_type: String, version: u32 },
+}
+
+impl Display for EventSchemaError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            EventSchemaError::Encode(reason) => write!(f, "Event encode error, {}", reason),
+            EventSchemaError::Decode(reason) => write!(f, "Event decode error, {}", reason),
+            EventSchemaError::UnknownEvent(event_type) => {
+                write!(f, "Event type {} not registered", event_type)
+            }
+            EventSchemaError::UnexpectedEvent { expected, found } => {
+                write!(f, "Expected event type {}, found {}", expected, found)
+            }
+            EventSchemaError::UnsupportedVersion {
+                event_type,
+                version,
+            } => write!(
+                f,
+                "Event {} schema version {} is newer than supported",
+                event_type, version
+            ),
+            EventSchemaError::MissingMigration {
+                event_type,
+                version,
+            } => write!(
+                f,
+                "Event {} has no migration from schema version {}",
+                event_type, version
+            ),
+        }
+    }
+}
+
+impl Error for EventSchemaError {}
+
+pub type EventSchemaResult<T> = std::result::Result<T, EventSchemaError>;
+
+/// Represents a change to an event's fields between two schema versions.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FieldChange {
+    /// The field was renamed.
+    Rename { from: String, to: String },
+    /// The field was added, with the value for older events.
+    Add { field: String, default: Value },
+    /// The field was removed.
+    Remove { field: String },
+}
+
+impl FieldChange {
+    pub fn rename(from: &str, to: &str) -> Self {
+        FieldChange::Rename {
+            from: from.to_string(),
+            to: to.to_string(),
+        }
+    }
+
+    pub fn add(field: &str, default: impl Into<Value>) -> Self {
+        FieldChange::Add {
+            field: field.to_string(),
+            default: default.into(),
+        }
+    }
+
+    pub fn remove(field: &str) -> Self {
+        FieldChange::Remove {
+            field: field.to_string(),
+        }
+    }
+
+    fn apply(&self, fields: &mut Vec<(Value, Value)>) {
+        let position = |fields: &[(Value, Value)], name: &str| {
+            fields.iter().position(|(k, _)| k.as_str() == Some(name))
+        };
+        match self {
+            FieldChange::Rename { from, to } => {
+                if let Some(i) = position(fields, from) {
+                    fields[i].0 = Value::from(to.as_str());
+                }
+            }
+            FieldChange::Add { field, default } => {
+                if position(fields, field).is_none() {
+                    fields.push((Value::from(field.as_str()), default.clone()));
+                }
+            }
+            FieldChange::Remove { field } => {
+                if let Some(i) = position(fields, field) {
+                    fields.remove(i);
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct EventSchema {
+    version: u32,
+    // Keyed by the version being upgraded from
+    migrations: HashMap<u32, Vec<FieldChange>>,
+}
+
+/// Provides versioned encoding and decoding of registered event types.
+#[derive(Debug, Default)]
+pub struct EventSchemaRegistry {
+    schemas: HashMap<String, EventSchema>,
+}
+
+impl EventSchemaRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the event type at its current schema version (starting at 1).
+    pub fn register(&mut self, event_type: &str, version: u32) {
+        self.schemas
+            .entry(event_type.to_string())
+            .or_default()
+            .version = version;
+    }
+
+    /// Registers the field changes upgrading the event type's payloads from
+    /// the given schema version to the next.
+    pub fn add_migration(
+        &mut self,
+        event_type: &str,
+        from_version: u32,
+        changes: Vec<FieldChange>,
+    ) {
+        self.schemas
+            .entry(event_type.to_string())
+            .or_default()
+            .migrations
+            .insert(from_version, changes);
+    }
+
+    /// Returns the current schema version of the event type.
+    pub fn version(&self, event_type: &str) -> Option<u32> {
+        self.schemas.get(event_type).map(|s| s.version)
+    }
+
+    /// Returns the event encoded at its current schema version.
+    pub fn encode<T: Serialize>(&self, event_type: &str, event: &T) -> EventSchemaResult<Vec<u8>> {
+        let version = self
+            .version(event_type)
+            .ok_or_else(|| EventSchemaError::UnknownEvent(event_type.to_string()))?;
+        let payload =
+            rmp_serde::to_vec_named(event).map_err(|e| EventSchemaError::Encode(e.to_string()))?;
+        let payload = read_value(&payload)?;
+        let envelope = Value::Array(vec![Value::from(event_type), Value::from(version), payload]);
+        write_value(&envelope)
+    }
+
+    /// Returns the event decoded from the bytes, upgrading older payloads to
+    /// the current schema version.
+    pub fn decode<T: DeserializeOwned>(
+        &self,
+        event_type: &str,
+        bytes: &[u8],
+    ) -> EventSchemaResult<T> {
+        let (found, version, payload) = split_envelope(read_value(bytes)?)?;
+        if found != event_type {
+            return Err(EventSchemaError::UnexpectedEvent {
+                expected: event_type.to_string(),
+                found,
+            });
+        }
+        let payload = self.upgrade(event_type, version, payload)?;
+        rmp_serde::from_slice(&write_value(&payload)?)
+            .map_err(|e| EventSchemaError::Decode(e.to_string()))
+    }
+
+    /// Returns the payload upgraded from the schema version to the current.
+    pub fn upgrade(
+        &self,
+        event_type: &str,
+        version: u32,
+        payload: Value,
+    ) -> EventSchemaResult<Value> {
+        let schema = self
+            .schemas
+            .get(event_type)
+            .ok_or_else(|| EventSchemaError::UnknownEvent(event_type.to_string()))?;
+        if version > schema.version {
+            return Err(EventSchemaError::UnsupportedVersion {
+                event_type: event_type.to_string(),
+                version,
+            });
+        }
+        let mut fields = match payload {
+            Value::Map(fields) => fields,
+            _ => return Err(EventSchemaError::Decode("payload not a map".to_string())),
+        };
+        for from_version in version..schema.version {
+            let changes = schema.migrations.get(&from_version).ok_or_else(|| {
+                EventSchemaError::MissingMigration {
+                    event_type: event_type.to_string(),
+                    version: from_version,
+                }
+            })?;
+            changes.iter().for_each(|c| c.apply(&mut fields));
+        }
+        Ok(Value::Map(fields))
+    }
+}
+
+/// Returns the event type and schema version of the encoded event, for
+/// dispatching to the matching `decode`.
+pub fn peek_event_type(bytes: &[u8]) -> EventSchemaResult<(String, u32)> {
+    let (event_type, version, _) = split_envelope(read_value(bytes)?)?;
+    Ok((event_type, version))
+}
+
+fn read_value(mut bytes: &[u8]) -> EventSchemaResult<Value> {
+    rmpv::decode::read_value(&mut bytes).map_err(|e| EventSchemaError::Decode(e.to_string()))
+}
+
+fn write_value(value: &Value) -> EventSchemaResult<Vec<u8>> {
+    let mut buf = Vec::new();
+    rmpv::encode::write_value(&mut buf, value)
+        .map_err(|e| EventSchemaError::Encode(e.to_string()))?;
+    Ok(buf)
+}
+
+fn split_envelope(value: Value) -> EventSchemaResult<(String, u32, Value)> {
+    let invalid = || EventSchemaError::Decode("invalid event envelope".to_string());
+    let mut parts = match value {
+        Value::Array(parts) if parts.len() == 3 => parts.into_iter(),
+        _ => return Err(invalid()),
+    };
+    let event_type = parts.next().and_then(|v| v.as_str().map(str::to_string));
+    let version = parts.next().and_then(|v| v.as_u64());
+    match (
+        event_type,
+        version.and_then(|v| u32::try_from(v).ok()),
+        parts.next(),
+    ) {
+        (Some(event_type), Some(version), Some(payload)) => Ok((event_type, version, payload)),
+        _ => Err(invalid()),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct OrderFilledV1 {
+        client_order_id: String,
+        last_px: String,
+        fee: String,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct OrderFilledV3 {
+        client_order_id: String,
+        last_px: String,
+        commission: String,
+        liquidity_side: String,
+    }
+
+    fn registry() -> EventSchemaRegistry {
+        let mut registry = EventSchemaRegistry::new();
+        registry.register("OrderFilled", 3);
+        registry.add_migration(
+            "OrderFilled",
+            1,
+            vec![FieldChange::rename("fee", "commission")],
+        );
+        registry.add_migration(
+            "OrderFilled",
+            2,
+            vec![FieldChange::add("liquidity_side", "NONE")],
+        );
+        registry
+    }
+
+    fn filled_v3() -> OrderFilledV3 {
+        OrderFilledV3 {
+            client_order_id: "O-1".to_string(),
+            last_px: "1.00010".to_string(),
+            commission: "2.00 USD".to_string(),
+            liquidity_side: "MAKER".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_round_trip_current_version() {
+        let registry = registry();
+        let event = filled_v3();
+
+        let bytes = registry.encode("OrderFilled", &event).unwrap();
+
+        assert_eq!(
+            peek_event_type(&bytes).unwrap(),
+            ("OrderFilled".to_string(), 3)
+        );
+        assert_eq!(
+            registry
+                .decode::<OrderFilledV3>("OrderFilled", &bytes)
+                .unwrap(),
+            event
+        );
+    }
+
+    #[test]
+    fn test_decode_upgrades_older_version() {
+        let mut old = EventSchemaRegistry::new();
+        old.register("OrderFilled", 1);
+        let bytes = old
+            .encode(
+                "OrderFilled",
+                &OrderFilledV1 {
+                    client_order_id: "O-1".to_string(),
+                    last_px: "1.00010".to_string(),
+                    fee: "2.00 USD".to_string(),
+                },
+            )
+            .unwrap();
+
+        let event: OrderFilledV3 = registry().decode("OrderFilled", &bytes).unwrap();
+
+        assert_eq!(event.commission, "2.00 USD");
+        assert_eq!(event.liquidity_side, "NONE");
+    }
+
+    #[test]
+    fn test_decode_newer_version_fails() {
+        let mut new = registry();
+        new.register("OrderFilled", 4);
+        let bytes = new.encode("OrderFilled", &filled_v3()).unwrap();
+
+        let result = registry().decode::<OrderFilledV3>("OrderFilled", &bytes);
+
+        assert_eq!(
+            result,
+            Err(EventSchemaError::UnsupportedVersion {
+                event_type: "OrderFilled".to_string(),
+                version: 4
+            })
+        );
+    }
+
+    #[test]
+    fn test_decode_missing_migration_fails() {
+        let mut old = EventSchemaRegistry::new();
+        old.register("OrderFilled", 1);
+        let bytes = old.encode("OrderFilled", &filled_v3()).unwrap();
+        let mut new = EventSchemaRegistry::new();
+        new.register("OrderFilled", 2);
+
+        let result = new.decode::<OrderFilledV3>("OrderFilled", &bytes);
+
+        assert_eq!(
+            result,
+            Err(EventSchemaError::MissingMigration {
+                event_type: "OrderFilled".to_string(),
+                version: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_decode_unexpected_or_unknown_event() {
+        let registry = registry();
+        let bytes = registry.encode("OrderFilled", &filled_v3()).unwrap();
+
+        assert!(matches!(
+            registry.decode::<OrderFilledV3>("OrderCanceled", &bytes),
+            Err(EventSchemaError::UnexpectedEvent { .. })
+        ));
+        assert_eq!(
+            registry.encode("OrderCanceled", &filled_v3()),
+            Err(EventSchemaError::UnknownEvent("OrderCanceled".to_string()))
+        );
+        assert!(matches!(
+            registry.decode::<OrderFilledV3>("OrderFilled", b"\x01"),
+            Err(EventSchemaError::Decode(_))
+        ));
+    }
+}
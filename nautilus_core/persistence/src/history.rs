@@ -0,0 +1,366 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Execution and account history persisted into a normalized SQL schema.
+//!
+//! Records are queued onto a [`HistoryWriter`] which batches and writes them
+//! from a dedicated thread, so the trading path never blocks on the database.
+
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result};
+use std::sync::mpsc::{channel, Receiver, RecvTimeoutError, Sender};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+/// Represents a versioned schema migration.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Migration {
+    pub version: u32,
+    pub name: &'static str,
+    pub sql: &'static str,
+}
+
+/// The schema migrations for the history database, in version order.
+pub const MIGRATIONS: &[Migration] = &[Migration {
+    version: 1,
+    name: "execution_history",
+    sql: include_str!("../migrations/0001_execution_history.sql"),
+}];
+
+/// Returns the migrations not yet applied, in version order.
+pub fn pending_migrations(applied: &[u32]) -> Vec<&'static Migration> {
+    MIGRATIONS
+        .iter()
+        .filter(|m| !applied.contains(&m.version))
+        .collect()
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum HistoryError {
+    /// The underlying database failed.
+    Backend(String),
+    /// The writer has been closed.
+    Closed,
+}
+
+impl Display for HistoryError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            HistoryError::Backend(reason) => write!(f, "History database error, {}", reason),
+            HistoryError::Closed => write!(f, "History writer closed"),
+        }
+    }
+}
+
+impl Error for HistoryError {}
+
+pub type HistoryResult<T> = std::result::Result<T, HistoryError>;
+
+/// Represents an order event, also maintaining the latest state of the order.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OrderEventRecord {
+    pub event_id: String,
+    pub trader_id: String,
+    pub strategy_id: String,
+    pub instrument_id: String,
+    pub client_order_id: String,
+    pub venue_order_id: Option<String>,
+    pub event_type: String,
+    pub payload: Vec<u8>,
+    pub ts_event: u64,
+    pub ts_init: u64,
+}
+
+/// Represents an order fill, with decimal values in their string form.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FillRecord {
+    pub trade_id: String,
+    pub client_order_id: String,
+    pub venue_order_id: String,
+    pub position_id: Option<String>,
+    pub account_id: String,
+    pub instrument_id: String,
+    pub order_side: String,
+    pub last_qty: String,
+    pub last_px: String,
+    pub commission: String,
+    pub commission_currency: String,
+    pub liquidity_side: String,
+    pub ts_event: u64,
+}
+
+/// Represents a single currency balance of an account state.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AccountBalanceRecord {
+    pub currency: String,
+    pub total: String,
+    pub locked: String,
+    pub free: String,
+}
+
+/// Represents an account state event with its balances.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AccountStateRecord {
+    pub event_id: String,
+    pub account_id: String,
+    pub account_type: String,
+    pub base_currency: Option<String>,
+    pub is_reported: bool,
+    pub balances: Vec<AccountBalanceRecord>,
+    pub ts_event: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum HistoryRecord {
+    OrderEvent(OrderEventRecord),
+    Fill(FillRecord),
+    AccountState(AccountStateRecord),
+}
+
+/// Provides the database operations used by the history writer.
+///
+/// Writes must be idempotent, as a failed batch is retried in full.
+pub trait HistorySink: Send {
+    /// Applies any pending migrations, returning the number applied.
+    fn migrate(&mut self) -> HistoryResult<usize>;
+    /// Writes the batch of records atomically.
+    fn write_batch(&mut self, records: &[HistoryRecord]) -> HistoryResult<()>;
+}
+
+#[allow(clippy::large_enum_variant)] // Records are moved once into the writer
+enum Command {
+    Record(HistoryRecord),
+    Flush(Sender<HistoryResult<()>>),
+}
+
+/// Provides a background writer which batches history records into a sink.
+pub struct HistoryWriter {
+    tx: Option<Sender<Command>>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl HistoryWriter {
+    /// Migrates the sink and starts the writer thread.
+    ///
+    /// Buffered records are written once `batch_size` is reached, or after
+    /// `interval` has elapsed without a new record.
+    pub fn start<S: HistorySink + 'static>(
+        mut sink: S,
+        batch_size: usize,
+        interval: Duration,
+    ) -> HistoryResult<Self> {
+        sink.migrate()?;
+        let (tx, rx) = channel();
+        let handle = std::thread::Builder::new()
+            .name("history-writer".to_string())
+            .spawn(move || run(sink, rx, batch_size.max(1), interval))
+            .map_err(|e| HistoryError::Backend(e.to_string()))?;
+        Ok(HistoryWriter {
+            tx: Some(tx),
+            handle: Some(handle),
+        })
+    }
+
+    /// Queues the record for writing without blocking.
+    pub fn send(&self, record: HistoryRecord) -> HistoryResult<()> {
+        self.tx
+            .as_ref()
+            .ok_or(HistoryError::Closed)?
+            .send(Command::Record(record))
+            .map_err(|_| HistoryError::Closed)
+    }
+
+    /// Blocks until all queued records are written, returning any write error.
+    pub fn flush(&self) -> HistoryResult<()> {
+        let (reply_tx, reply_rx) = channel();
+        self.tx
+            .as_ref()
+            .ok_or(HistoryError::Closed)?
+            .send(Command::Flush(reply_tx))
+            .map_err(|_| HistoryError::Closed)?;
+        reply_rx.recv().map_err(|_| HistoryError::Closed)?
+    }
+
+    /// Flushes and stops the writer thread.
+    pub fn close(&mut self) -> HistoryResult<()> {
+        let result = self.flush();
+        self.tx = None;
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+        result
+    }
+}
+
+impl Drop for HistoryWriter {
+    fn drop(&mut self) {
+        if self.handle.is_some() {
+            let _ = self.close();
+        }
+    }
+}
+
+fn run<S: HistorySink>(mut sink: S, rx: Receiver<Command>, batch_size: usize, interval: Duration) {
+    let mut buffer: Vec<HistoryRecord> = Vec::new();
+    let mut write = |buffer: &mut Vec<HistoryRecord>| -> HistoryResult<()> {
+        if buffer.is_empty() {
+            return Ok(());
+        }
+        // Failed batches stay buffered and are retried on the next write
+        sink.write_batch(buffer)?;
+        buffer.clear();
+        Ok(())
+    };
+
+    loop {
+        match rx.recv_timeout(interval) {
+            Ok(Command::Record(record)) => {
+                buffer.push(record);
+                if buffer.len() >= batch_size {
+                    let _ = write(&mut buffer);
+                }
+            }
+            Ok(Command::Flush(reply)) => {
+                let _ = reply.send(write(&mut buffer));
+            }
+            Err(RecvTimeoutError::Timeout) => {
+                let _ = write(&mut buffer);
+            }
+            Err(RecvTimeoutError::Disconnected) => {
+                let _ = write(&mut buffer);
+                return;
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    use super::*;
+
+    #[derive(Clone, Default)]
+    struct MockSink {
+        batches: Arc<Mutex<Vec<Vec<HistoryRecord>>>>,
+        failures: Arc<Mutex<usize>>,
+        migrated: Arc<Mutex<bool>>,
+    }
+
+    impl HistorySink for MockSink {
+        fn migrate(&mut self) -> HistoryResult<usize> {
+            *self.migrated.lock().unwrap() = true;
+            Ok(MIGRATIONS.len())
+        }
+
+        fn write_batch(&mut self, records: &[HistoryRecord]) -> HistoryResult<()> {
+            let mut failures = self.failures.lock().unwrap();
+            if *failures > 0 {
+                *failures -= 1;
+                return Err(HistoryError::Backend("connection lost".to_string()));
+            }
+            self.batches.lock().unwrap().push(records.to_vec());
+            Ok(())
+        }
+    }
+
+    fn fill(trade_id: &str) -> HistoryRecord {
+        HistoryRecord::Fill(FillRecord {
+            trade_id: trade_id.to_string(),
+            client_order_id: "O-123456".to_string(),
+            venue_order_id: "1".to_string(),
+            position_id: None,
+            account_id: "SIM-001".to_string(),
+            instrument_id: "AUD/USD.SIM".to_string(),
+            order_side: "BUY".to_string(),
+            last_qty: "100000".to_string(),
+            last_px: "1.00001".to_string(),
+            commission: "2.00".to_string(),
+            commission_currency: "USD".to_string(),
+            liquidity_side: "TAKER".to_string(),
+            ts_event: 0,
+        })
+    }
+
+    #[test]
+    fn test_migrations_are_ordered_and_unique() {
+        let versions: Vec<u32> = MIGRATIONS.iter().map(|m| m.version).collect();
+        let mut sorted = versions.clone();
+        sorted.sort_unstable();
+        sorted.dedup();
+
+        assert_eq!(versions, sorted);
+        assert!(MIGRATIONS[0]
+            .sql
+            .contains("CREATE TABLE IF NOT EXISTS fills"));
+    }
+
+    #[test]
+    fn test_pending_migrations() {
+        assert_eq!(pending_migrations(&[]).len(), MIGRATIONS.len());
+        assert!(pending_migrations(&[1]).is_empty());
+    }
+
+    #[test]
+    fn test_writer_batches_records() {
+        let sink = MockSink::default();
+        let writer = HistoryWriter::start(sink.clone(), 2, Duration::from_secs(60)).unwrap();
+
+        writer.send(fill("1")).unwrap();
+        writer.send(fill("2")).unwrap();
+        writer.send(fill("3")).unwrap();
+        writer.flush().unwrap();
+
+        let batches = sink.batches.lock().unwrap();
+        assert!(*sink.migrated.lock().unwrap());
+        assert_eq!(batches.len(), 2);
+        assert_eq!(batches[0], vec![fill("1"), fill("2")]);
+        assert_eq!(batches[1], vec![fill("3")]);
+    }
+
+    #[test]
+    fn test_writer_retries_failed_batch() {
+        let sink = MockSink::default();
+        *sink.failures.lock().unwrap() = 1;
+        let writer = HistoryWriter::start(sink.clone(), 10, Duration::from_secs(60)).unwrap();
+
+        writer.send(fill("1")).unwrap();
+        let first = writer.flush();
+        let second = writer.flush();
+
+        assert_eq!(
+            first,
+            Err(HistoryError::Backend("connection lost".to_string()))
+        );
+        assert_eq!(second, Ok(()));
+        assert_eq!(*sink.batches.lock().unwrap(), vec![vec![fill("1")]]);
+    }
+
+    #[test]
+    fn test_writer_flushes_on_close() {
+        let sink = MockSink::default();
+        let mut writer = HistoryWriter::start(sink.clone(), 10, Duration::from_secs(60)).unwrap();
+
+        writer.send(fill("1")).unwrap();
+        writer.close().unwrap();
+
+        assert_eq!(sink.batches.lock().unwrap().len(), 1);
+        assert_eq!(writer.send(fill("2")), Err(HistoryError::Closed));
+    }
+}
@@ -0,0 +1,198 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use postgres::{Client, NoTls, Transaction};
+
+use crate::history::{
+    pending_migrations, AccountStateRecord, FillRecord, HistoryError, HistoryRecord, HistoryResult,
+    HistorySink, OrderEventRecord,
+};
+
+fn backend_error(e: postgres::Error) -> HistoryError {
+    HistoryError::Backend(e.to_string())
+}
+
+/// Provides a PostgreSQL history sink.
+pub struct PostgresHistorySink {
+    client: Client,
+}
+
+impl PostgresHistorySink {
+    /// Connects using a libpq style connection string, e.g.
+    /// `host=localhost user=nautilus dbname=nautilus`.
+    pub fn connect(params: &str) -> HistoryResult<Self> {
+        let client = Client::connect(params, NoTls).map_err(backend_error)?;
+        Ok(PostgresHistorySink { client })
+    }
+
+    pub fn from_client(client: Client) -> Self {
+        PostgresHistorySink { client }
+    }
+}
+
+fn write_order_event(tx: &mut Transaction, r: &OrderEventRecord) -> Result<(), postgres::Error> {
+    tx.execute(
+        "INSERT INTO orders (client_order_id, trader_id, strategy_id, instrument_id, \
+         venue_order_id, last_event, ts_last) VALUES ($1, $2, $3, $4, $5, $6, $7) \
+         ON CONFLICT (client_order_id) DO UPDATE SET \
+         venue_order_id = COALESCE(EXCLUDED.venue_order_id, orders.venue_order_id), \
+         last_event = EXCLUDED.last_event, ts_last = EXCLUDED.ts_last \
+         WHERE EXCLUDED.ts_last >= orders.ts_last",
+        &[
+            &r.client_order_id,
+            &r.trader_id,
+            &r.strategy_id,
+            &r.instrument_id,
+            &r.venue_order_id,
+            &r.event_type,
+            &(r.ts_event as i64),
+        ],
+    )?;
+    tx.execute(
+        "INSERT INTO order_events (event_id, client_order_id, event_type, payload, \
+         ts_event, ts_init) VALUES ($1, $2, $3, $4, $5, $6) ON CONFLICT DO NOTHING",
+        &[
+            &r.event_id,
+            &r.client_order_id,
+            &r.event_type,
+            &r.payload,
+            &(r.ts_event as i64),
+            &(r.ts_init as i64),
+        ],
+    )?;
+    Ok(())
+}
+
+fn write_fill(tx: &mut Transaction, r: &FillRecord) -> Result<(), postgres::Error> {
+    tx.execute(
+        "INSERT INTO fills (trade_id, client_order_id, venue_order_id, position_id, \
+         account_id, instrument_id, order_side, last_qty, last_px, commission, \
+         commission_currency, liquidity_side, ts_event) VALUES ($1, $2, $3, $4, $5, $6, \
+         $7, $8::text::numeric, $9::text::numeric, $10::text::numeric, $11, $12, $13) \
+         ON CONFLICT DO NOTHING",
+        &[
+            &r.trade_id,
+            &r.client_order_id,
+            &r.venue_order_id,
+            &r.position_id,
+            &r.account_id,
+            &r.instrument_id,
+            &r.order_side,
+            &r.last_qty,
+            &r.last_px,
+            &r.commission,
+            &r.commission_currency,
+            &r.liquidity_side,
+            &(r.ts_event as i64),
+        ],
+    )?;
+    Ok(())
+}
+
+fn write_account_state(
+    tx: &mut Transaction,
+    r: &AccountStateRecord,
+) -> Result<(), postgres::Error> {
+    let inserted = tx.execute(
+        "INSERT INTO account_states (event_id, account_id, account_type, base_currency, \
+         is_reported, ts_event) VALUES ($1, $2, $3, $4, $5, $6) ON CONFLICT DO NOTHING",
+        &[
+            &r.event_id,
+            &r.account_id,
+            &r.account_type,
+            &r.base_currency,
+            &r.is_reported,
+            &(r.ts_event as i64),
+        ],
+    )?;
+    if inserted == 0 {
+        return Ok(()); // Already written by a previous attempt
+    }
+    for balance in &r.balances {
+        tx.execute(
+            "INSERT INTO account_balances (event_id, currency, total, locked, free) \
+             VALUES ($1, $2, $3::text::numeric, $4::text::numeric, $5::text::numeric)",
+            &[
+                &r.event_id,
+                &balance.currency,
+                &balance.total,
+                &balance.locked,
+                &balance.free,
+            ],
+        )?;
+    }
+    Ok(())
+}
+
+impl HistorySink for PostgresHistorySink {
+    fn migrate(&mut self) -> HistoryResult<usize> {
+        self.client
+            .batch_execute(
+                "CREATE TABLE IF NOT EXISTS schema_migrations (\
+                 version INTEGER PRIMARY KEY, name TEXT NOT NULL, \
+                 applied_at TIMESTAMPTZ NOT NULL DEFAULT now())",
+            )
+            .map_err(backend_error)?;
+        let applied: Vec<u32> = self
+            .client
+            .query("SELECT version FROM schema_migrations", &[])
+            .map_err(backend_error)?
+            .iter()
+            .map(|row| row.get::<_, i32>(0) as u32)
+            .collect();
+
+        let pending = pending_migrations(&applied);
+        for migration in &pending {
+            let mut tx = self.client.transaction().map_err(backend_error)?;
+            tx.batch_execute(migration.sql).map_err(backend_error)?;
+            tx.execute(
+                "INSERT INTO schema_migrations (version, name) VALUES ($1, $2)",
+                &[&(migration.version as i32), &migration.name],
+            )
+            .map_err(backend_error)?;
+            tx.commit().map_err(backend_error)?;
+        }
+        Ok(pending.len())
+    }
+
+    fn write_batch(&mut self, records: &[HistoryRecord]) -> HistoryResult<()> {
+        let mut tx = self.client.transaction().map_err(backend_error)?;
+        for record in records {
+            match record {
+                HistoryRecord::OrderEvent(r) => write_order_event(&mut tx, r),
+                HistoryRecord::Fill(r) => write_fill(&mut tx, r),
+                HistoryRecord::AccountState(r) => write_account_state(&mut tx, r),
+            }
+            .map_err(backend_error)?;
+        }
+        tx.commit().map_err(backend_error)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::PostgresHistorySink;
+    use crate::history::HistoryError;
+
+    #[test]
+    fn test_connect_with_invalid_params_returns_error() {
+        let result = PostgresHistorySink::connect("host=/nonexistent/socket/dir user=nautilus");
+
+        assert!(matches!(result, Err(HistoryError::Backend(_))));
+    }
+}
@@ -0,0 +1,406 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Protocol Buffers codecs for history records, for interop with non-Python
+//! services. The schema is maintained in `proto/history.proto`, which the
+//! message types below must be kept in sync with.
+
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result};
+
+use prost::Message;
+
+use crate::history::{
+    AccountBalanceRecord, AccountStateRecord, FillRecord, HistoryRecord, OrderEventRecord,
+};
+
+/// The Protocol Buffers schema of the history messages.
+pub const HISTORY_PROTO: &str = include_str!("../proto/history.proto");
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ProtoError {
+    /// The bytes were not a valid message.
+    Decode(String),
+    /// The message had no record set.
+    MissingRecord,
+}
+
+impl Display for ProtoError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            ProtoError::Decode(reason) => write!(f, "Protobuf decode error, {}", reason),
+            ProtoError::MissingRecord => write!(f, "Protobuf message had no record"),
+        }
+    }
+}
+
+impl Error for ProtoError {}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct OrderEventMessage {
+    #[prost(string, tag = "1")]
+    pub event_id: String,
+    #[prost(string, tag = "2")]
+    pub trader_id: String,
+    #[prost(string, tag = "3")]
+    pub strategy_id: String,
+    #[prost(string, tag = "4")]
+    pub instrument_id: String,
+    #[prost(string, tag = "5")]
+    pub client_order_id: String,
+    #[prost(string, optional, tag = "6")]
+    pub venue_order_id: Option<String>,
+    #[prost(string, tag = "7")]
+    pub event_type: String,
+    #[prost(bytes = "vec", tag = "8")]
+    pub payload: Vec<u8>,
+    #[prost(uint64, tag = "9")]
+    pub ts_event: u64,
+    #[prost(uint64, tag = "10")]
+    pub ts_init: u64,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct FillMessage {
+    #[prost(string, tag = "1")]
+    pub trade_id: String,
+    #[prost(string, tag = "2")]
+    pub client_order_id: String,
+    #[prost(string, tag = "3")]
+    pub venue_order_id: String,
+    #[prost(string, optional, tag = "4")]
+    pub position_id: Option<String>,
+    #[prost(string, tag = "5")]
+    pub account_id: String,
+    #[prost(string, tag = "6")]
+    pub instrument_id: String,
+    #[prost(string, tag = "7")]
+    pub order_side: String,
+    #[prost(string, tag = "8")]
+    pub last_qty: String,
+    #[prost(string, tag = "9")]
+    pub last_px: String,
+    #[prost(string, tag = "10")]
+    pub commission: String,
+    #[prost(string, tag = "11")]
+    pub commission_currency: String,
+    #[prost(string, tag = "12")]
+    pub liquidity_side: String,
+    #[prost(uint64, tag = "13")]
+    pub ts_event: u64,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct AccountBalanceMessage {
+    #[prost(string, tag = "1")]
+    pub currency: String,
+    #[prost(string, tag = "2")]
+    pub total: String,
+    #[prost(string, tag = "3")]
+    pub locked: String,
+    #[prost(string, tag = "4")]
+    pub free: String,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct AccountStateMessage {
+    #[prost(string, tag = "1")]
+    pub event_id: String,
+    #[prost(string, tag = "2")]
+    pub account_id: String,
+    #[prost(string, tag = "3")]
+    pub account_type: String,
+    #[prost(string, optional, tag = "4")]
+    pub base_currency: Option<String>,
+    #[prost(bool, tag = "5")]
+    pub is_reported: bool,
+    #[prost(message, repeated, tag = "6")]
+    pub balances: Vec<AccountBalanceMessage>,
+    #[prost(uint64, tag = "7")]
+    pub ts_event: u64,
+}
+
+#[derive(Clone, PartialEq, prost::Oneof)]
+pub enum HistoryRecordKind {
+    #[prost(message, tag = "1")]
+    OrderEvent(OrderEventMessage),
+    #[prost(message, tag = "2")]
+    Fill(FillMessage),
+    #[prost(message, tag = "3")]
+    AccountState(AccountStateMessage),
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct HistoryRecordMessage {
+    #[prost(oneof = "HistoryRecordKind", tags = "1, 2, 3")]
+    pub record: Option<HistoryRecordKind>,
+}
+
+impl From<OrderEventRecord> for OrderEventMessage {
+    fn from(r: OrderEventRecord) -> Self {
+        OrderEventMessage {
+            event_id: r.event_id,
+            trader_id: r.trader_id,
+            strategy_id: r.strategy_id,
+            instrument_id: r.instrument_id,
+            client_order_id: r.client_order_id,
+            venue_order_id: r.venue_order_id,
+            event_type: r.event_type,
+            payload: r.payload,
+            ts_event: r.ts_event,
+            ts_init: r.ts_init,
+        }
+    }
+}
+
+impl From<OrderEventMessage> for OrderEventRecord {
+    fn from(m: OrderEventMessage) -> Self {
+        OrderEventRecord {
+            event_id: m.event_id,
+            trader_id: m.trader_id,
+            strategy_id: m.strategy_id,
+            instrument_id: m.instrument_id,
+            client_order_id: m.client_order_id,
+            venue_order_id: m.venue_order_id,
+            event_type: m.event_type,
+            payload: m.payload,
+            ts_event: m.ts_event,
+            ts_init: m.ts_init,
+        }
+    }
+}
+
+impl From<FillRecord> for FillMessage {
+    fn from(r: FillRecord) -> Self {
+        FillMessage {
+            trade_id: r.trade_id,
+            client_order_id: r.client_order_id,
+            venue_order_id: r.venue_order_id,
+            position_id: r.position_id,
+            account_id: r.account_id,
+            instrument_id: r.instrument_id,
+            order_side: r.order_side,
+            last_qty: r.last_qty,
+            last_px: r.last_px,
+            commission: r.commission,
+            commission_currency: r.commission_currency,
+            liquidity_side: r.liquidity_side,
+            ts_event: r.ts_event,
+        }
+    }
+}
+
+impl From<FillMessage> for FillRecord {
+    fn from(m: FillMessage) -> Self {
+        FillRecord {
+            trade_id: m.trade_id,
+            client_order_id: m.client_order_id,
+            venue_order_id: m.venue_order_id,
+            position_id: m.position_id,
+            account_id: m.account_id,
+            instrument_id: m.instrument_id,
+            order_side: m.order_side,
+            last_qty: m.last_qty,
+            last_px: m.last_px,
+            commission: m.commission,
+            commission_currency: m.commission_currency,
+            liquidity_side: m.liquidity_side,
+            ts_event: m.ts_event,
+        }
+    }
+}
+
+impl From<AccountStateRecord> for AccountStateMessage {
+    fn from(r: AccountStateRecord) -> Self {
+        AccountStateMessage {
+            event_id: r.event_id,
+            account_id: r.account_id,
+            account_type: r.account_type,
+            base_currency: r.base_currency,
+            is_reported: r.is_reported,
+            balances: r
+                .balances
+                .into_iter()
+                .map(|b| AccountBalanceMessage {
+                    currency: b.currency,
+                    total: b.total,
+                    locked: b.locked,
+                    free: b.free,
+                })
+                .collect(),
+            ts_event: r.ts_event,
+        }
+    }
+}
+
+impl From<AccountStateMessage> for AccountStateRecord {
+    fn from(m: AccountStateMessage) -> Self {
+        AccountStateRecord {
+            event_id: m.event_id,
+            account_id: m.account_id,
+            account_type: m.account_type,
+            base_currency: m.base_currency,
+            is_reported: m.is_reported,
+            balances: m
+                .balances
+                .into_iter()
+                .map(|b| AccountBalanceRecord {
+                    currency: b.currency,
+                    total: b.total,
+                    locked: b.locked,
+                    free: b.free,
+                })
+                .collect(),
+            ts_event: m.ts_event,
+        }
+    }
+}
+
+impl From<HistoryRecord> for HistoryRecordMessage {
+    fn from(record: HistoryRecord) -> Self {
+        let record = match record {
+            HistoryRecord::OrderEvent(r) => HistoryRecordKind::OrderEvent(r.into()),
+            HistoryRecord::Fill(r) => HistoryRecordKind::Fill(r.into()),
+            HistoryRecord::AccountState(r) => HistoryRecordKind::AccountState(r.into()),
+        };
+        HistoryRecordMessage {
+            record: Some(record),
+        }
+    }
+}
+
+impl TryFrom<HistoryRecordMessage> for HistoryRecord {
+    type Error = ProtoError;
+
+    fn try_from(message: HistoryRecordMessage) -> std::result::Result<Self, Self::Error> {
+        match message.record.ok_or(ProtoError::MissingRecord)? {
+            HistoryRecordKind::OrderEvent(m) => Ok(HistoryRecord::OrderEvent(m.into())),
+            HistoryRecordKind::Fill(m) => Ok(HistoryRecord::Fill(m.into())),
+            HistoryRecordKind::AccountState(m) => Ok(HistoryRecord::AccountState(m.into())),
+        }
+    }
+}
+
+/// Returns the record encoded as a `nautilus.history.v1.HistoryRecord`.
+pub fn encode_history_record(record: &HistoryRecord) -> Vec<u8> {
+    HistoryRecordMessage::from(record.clone()).encode_to_vec()
+}
+
+/// Returns the record decoded from a `nautilus.history.v1.HistoryRecord`.
+pub fn decode_history_record(bytes: &[u8]) -> std::result::Result<HistoryRecord, ProtoError> {
+    let message =
+        HistoryRecordMessage::decode(bytes).map_err(|e| ProtoError::Decode(e.to_string()))?;
+    HistoryRecord::try_from(message)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fill() -> FillRecord {
+        FillRecord {
+            trade_id: "T-1".to_string(),
+            client_order_id: "O-1".to_string(),
+            venue_order_id: "V-1".to_string(),
+            position_id: None,
+            account_id: "SIM-001".to_string(),
+            instrument_id: "AUD/USD.SIM".to_string(),
+            order_side: "BUY".to_string(),
+            last_qty: "100000".to_string(),
+            last_px: "0.70010".to_string(),
+            commission: "2.00".to_string(),
+            commission_currency: "USD".to_string(),
+            liquidity_side: "TAKER".to_string(),
+            ts_event: 1_000,
+        }
+    }
+
+    #[test]
+    fn test_round_trip_records() {
+        let records = [
+            HistoryRecord::OrderEvent(OrderEventRecord {
+                event_id: "E-1".to_string(),
+                trader_id: "TRADER-001".to_string(),
+                strategy_id: "S-001".to_string(),
+                instrument_id: "AUD/USD.SIM".to_string(),
+                client_order_id: "O-1".to_string(),
+                venue_order_id: Some("V-1".to_string()),
+                event_type: "OrderAccepted".to_string(),
+                payload: vec![1, 2, 3],
+                ts_event: 1,
+                ts_init: 2,
+            }),
+            HistoryRecord::Fill(fill()),
+            HistoryRecord::AccountState(AccountStateRecord {
+                event_id: "E-2".to_string(),
+                account_id: "SIM-001".to_string(),
+                account_type: "CASH".to_string(),
+                base_currency: Some("USD".to_string()),
+                is_reported: true,
+                balances: vec![AccountBalanceRecord {
+                    currency: "USD".to_string(),
+                    total: "1000000.00".to_string(),
+                    locked: "0.00".to_string(),
+                    free: "1000000.00".to_string(),
+                }],
+                ts_event: 3,
+            }),
+        ];
+
+        for record in records {
+            let bytes = encode_history_record(&record);
+
+            assert_eq!(decode_history_record(&bytes), Ok(record));
+        }
+    }
+
+    #[test]
+    fn test_optional_fields_are_distinguished_from_empty() {
+        let mut record = fill();
+        record.position_id = Some(String::new());
+
+        let decoded = decode_history_record(&encode_history_record(&HistoryRecord::Fill(record)));
+
+        match decoded {
+            Ok(HistoryRecord::Fill(f)) => assert_eq!(f.position_id, Some(String::new())),
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        assert_eq!(decode_history_record(&[]), Err(ProtoError::MissingRecord));
+        assert!(matches!(
+            decode_history_record(&[0x0a, 0xff]),
+            Err(ProtoError::Decode(_))
+        ));
+    }
+
+    #[test]
+    fn test_schema_declares_messages() {
+        for message in [
+            "OrderEvent",
+            "Fill",
+            "AccountBalance",
+            "AccountState",
+            "HistoryRecord",
+        ] {
+            assert!(HISTORY_PROTO.contains(&format!("message {} {{", message)));
+        }
+    }
+}
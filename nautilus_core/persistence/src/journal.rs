@@ -0,0 +1,420 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Per-trade journal of round trips matched from fills, exported to CSV or
+//! JSON lines.
+//!
+//! A trade runs from the first fill opening a position on an instrument until
+//! the position is flat again, where a fill reversing the position closes the
+//! trade and opens the next with the remaining quantity.
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result};
+use std::io::Write;
+
+use serde::Serialize;
+
+use crate::history::FillRecord;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum JournalError {
+    /// A fill could not be matched (e.g. an unparsable quantity or price).
+    InvalidFill(String, String),
+    /// Writing the export failed.
+    Io(String),
+}
+
+impl Display for JournalError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            JournalError::InvalidFill(trade_id, reason) => {
+                write!(f, "Invalid fill '{}', {}", trade_id, reason)
+            }
+            JournalError::Io(reason) => write!(f, "Journal export error, {}", reason),
+        }
+    }
+}
+
+impl Error for JournalError {}
+
+pub type JournalResult<T> = std::result::Result<T, JournalError>;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JournalFormat {
+    Csv,
+    JsonLines,
+}
+
+/// Represents a completed round trip trade.
+///
+/// Prices are average fill prices, and PnL is in quote currency units before
+/// any contract multiplier and commissions.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct JournalEntry {
+    pub instrument_id: String,
+    /// The side of the position, "LONG" or "SHORT".
+    pub side: String,
+    /// The maximum absolute position quantity during the trade.
+    pub quantity: f64,
+    pub ts_entry: u64,
+    pub ts_exit: u64,
+    pub avg_px_entry: f64,
+    pub avg_px_exit: f64,
+    pub realized_pnl: f64,
+    /// The sum of commissions, in their (assumed single) currency.
+    pub commissions: f64,
+    /// The maximum adverse excursion, as a price distance from the entry.
+    pub mae: f64,
+    /// The maximum favorable excursion, as a price distance from the entry.
+    pub mfe: f64,
+    pub tags: Vec<String>,
+}
+
+#[derive(Debug)]
+struct OpenTrade {
+    is_long: bool,
+    /// The signed position quantity (positive when long).
+    position: f64,
+    max_quantity: f64,
+    ts_entry: u64,
+    entry_qty: f64,
+    entry_notional: f64,
+    exit_qty: f64,
+    exit_notional: f64,
+    commissions: f64,
+    mae: f64,
+    mfe: f64,
+    tags: Vec<String>,
+}
+
+impl OpenTrade {
+    fn avg_px_entry(&self) -> f64 {
+        self.entry_notional / self.entry_qty
+    }
+
+    fn observe(&mut self, price: f64) {
+        let move_px = if self.is_long {
+            price - self.avg_px_entry()
+        } else {
+            self.avg_px_entry() - price
+        };
+        self.mfe = self.mfe.max(move_px);
+        self.mae = self.mae.max(-move_px);
+    }
+
+    fn close(self, instrument_id: &str, ts_exit: u64) -> JournalEntry {
+        let (avg_px_entry, avg_px_exit) = (self.avg_px_entry(), self.exit_notional / self.exit_qty);
+        let (side, direction) = if self.is_long {
+            ("LONG", 1.0)
+        } else {
+            ("SHORT", -1.0)
+        };
+        JournalEntry {
+            instrument_id: instrument_id.to_string(),
+            side: side.to_string(),
+            quantity: self.max_quantity,
+            ts_entry: self.ts_entry,
+            ts_exit,
+            avg_px_entry,
+            avg_px_exit,
+            realized_pnl: direction * (avg_px_exit - avg_px_entry) * self.exit_qty,
+            commissions: self.commissions,
+            mae: self.mae,
+            mfe: self.mfe,
+            tags: self.tags,
+        }
+    }
+}
+
+/// Provides the matching of fills into round trip trades per instrument.
+#[derive(Debug, Default)]
+pub struct TradeJournal {
+    open: HashMap<String, OpenTrade>,
+    pending_tags: HashMap<String, Vec<String>>,
+    entries: Vec<JournalEntry>,
+}
+
+impl TradeJournal {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Tags the open trade on the instrument, or the next trade opened if
+    /// flat (e.g. with the name of the strategy signal which triggered it).
+    pub fn add_tag(&mut self, instrument_id: &str, tag: &str) {
+        match self.open.get_mut(instrument_id) {
+            Some(trade) => trade.tags.push(tag.to_string()),
+            None => self
+                .pending_tags
+                .entry(instrument_id.to_string())
+                .or_default()
+                .push(tag.to_string()),
+        }
+    }
+
+    /// Updates the excursions of the open trade on the instrument with a
+    /// market price (e.g. the mid or last trade price).
+    pub fn update_price(&mut self, instrument_id: &str, price: f64) {
+        if let Some(trade) = self.open.get_mut(instrument_id) {
+            trade.observe(price);
+        }
+    }
+
+    /// Matches the fill, returning the number of trades it closed.
+    pub fn add_fill(&mut self, fill: &FillRecord) -> JournalResult<usize> {
+        let parse = |field: &str, value: &str| {
+            value.parse::<f64>().map_err(|e| {
+                JournalError::InvalidFill(fill.trade_id.clone(), format!("{} {}", field, e))
+            })
+        };
+        let qty = parse("last_qty", &fill.last_qty)?;
+        let px = parse("last_px", &fill.last_px)?;
+        let commission = parse("commission", &fill.commission)?;
+        let signed_qty = match fill.order_side.as_str() {
+            "BUY" => qty,
+            "SELL" => -qty,
+            side => {
+                return Err(JournalError::InvalidFill(
+                    fill.trade_id.clone(),
+                    format!("order_side {}", side),
+                ))
+            }
+        };
+
+        let mut remaining = signed_qty;
+        let mut closed = 0;
+        while remaining != 0.0 {
+            let share = commission * remaining.abs() / qty;
+            let trade = match self.open.get_mut(&fill.instrument_id) {
+                Some(trade) => trade,
+                None => {
+                    let tags = self
+                        .pending_tags
+                        .remove(&fill.instrument_id)
+                        .unwrap_or_default();
+                    self.open.insert(
+                        fill.instrument_id.clone(),
+                        OpenTrade {
+                            is_long: remaining > 0.0,
+                            position: remaining,
+                            max_quantity: remaining.abs(),
+                            ts_entry: fill.ts_event,
+                            entry_qty: remaining.abs(),
+                            entry_notional: remaining.abs() * px,
+                            exit_qty: 0.0,
+                            exit_notional: 0.0,
+                            commissions: share,
+                            mae: 0.0,
+                            mfe: 0.0,
+                            tags,
+                        },
+                    );
+                    break;
+                }
+            };
+            if trade.position.signum() == remaining.signum() {
+                trade.position += remaining;
+                trade.max_quantity = trade.max_quantity.max(trade.position.abs());
+                trade.entry_qty += remaining.abs();
+                trade.entry_notional += remaining.abs() * px;
+                trade.commissions += share;
+                trade.observe(px);
+                break;
+            }
+            trade.observe(px);
+            let reduce = remaining.abs().min(trade.position.abs());
+            trade.position -= reduce * trade.position.signum();
+            trade.exit_qty += reduce;
+            trade.exit_notional += reduce * px;
+            trade.commissions += commission * reduce / qty;
+            remaining -= reduce * remaining.signum();
+            if trade.position == 0.0 {
+                let trade = self.open.remove(&fill.instrument_id).expect("open trade");
+                self.entries
+                    .push(trade.close(&fill.instrument_id, fill.ts_event));
+                closed += 1;
+            }
+        }
+        Ok(closed)
+    }
+
+    /// Returns the completed trades, in the order they closed.
+    pub fn entries(&self) -> &[JournalEntry] {
+        &self.entries
+    }
+
+    /// Writes the completed trades to `writer`, returning the number written.
+    pub fn export<W: Write>(&self, writer: &mut W, format: JournalFormat) -> JournalResult<usize> {
+        let io_error = |e: std::io::Error| JournalError::Io(e.to_string());
+        match format {
+            JournalFormat::Csv => {
+                writeln!(
+                    writer,
+                    "instrument_id,side,quantity,ts_entry,ts_exit,avg_px_entry,avg_px_exit,\
+                     realized_pnl,commissions,mae,mfe,tags"
+                )
+                .map_err(io_error)?;
+                for e in &self.entries {
+                    writeln!(
+                        writer,
+                        "{},{},{},{},{},{},{},{},{},{},{},{}",
+                        csv_field(&e.instrument_id),
+                        e.side,
+                        e.quantity,
+                        e.ts_entry,
+                        e.ts_exit,
+                        e.avg_px_entry,
+                        e.avg_px_exit,
+                        e.realized_pnl,
+                        e.commissions,
+                        e.mae,
+                        e.mfe,
+                        csv_field(&e.tags.join(";")),
+                    )
+                    .map_err(io_error)?;
+                }
+            }
+            JournalFormat::JsonLines => {
+                for e in &self.entries {
+                    serde_json::to_writer(&mut *writer, e)
+                        .map_err(|e| JournalError::Io(e.to_string()))?;
+                    writeln!(writer).map_err(io_error)?;
+                }
+            }
+        }
+        Ok(self.entries.len())
+    }
+}
+
+/// Returns the value quoted for CSV if it contains a delimiter or quote.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fill(trade_id: &str, side: &str, qty: &str, px: &str, ts_event: u64) -> FillRecord {
+        FillRecord {
+            trade_id: trade_id.to_string(),
+            client_order_id: format!("O-{}", trade_id),
+            venue_order_id: format!("V-{}", trade_id),
+            position_id: None,
+            account_id: "SIM-001".to_string(),
+            instrument_id: "AUD/USD.SIM".to_string(),
+            order_side: side.to_string(),
+            last_qty: qty.to_string(),
+            last_px: px.to_string(),
+            commission: "1.0".to_string(),
+            commission_currency: "USD".to_string(),
+            liquidity_side: "TAKER".to_string(),
+            ts_event,
+        }
+    }
+
+    #[test]
+    fn test_round_trip_long() {
+        let mut journal = TradeJournal::new();
+        journal.add_tag("AUD/USD.SIM", "ema_cross");
+
+        assert_eq!(journal.add_fill(&fill("1", "BUY", "100", "1.0", 1)), Ok(0));
+        assert_eq!(journal.add_fill(&fill("2", "BUY", "100", "2.0", 2)), Ok(0));
+        journal.update_price("AUD/USD.SIM", 1.25);
+        journal.update_price("AUD/USD.SIM", 2.5);
+        assert_eq!(journal.add_fill(&fill("3", "SELL", "200", "2.0", 3)), Ok(1));
+
+        let entry = &journal.entries()[0];
+        assert_eq!(entry.side, "LONG");
+        assert_eq!(entry.quantity, 200.0);
+        assert_eq!((entry.ts_entry, entry.ts_exit), (1, 3));
+        assert_eq!((entry.avg_px_entry, entry.avg_px_exit), (1.5, 2.0));
+        assert_eq!(entry.realized_pnl, 100.0);
+        assert_eq!(entry.commissions, 3.0);
+        assert_eq!((entry.mae, entry.mfe), (0.25, 1.0));
+        assert_eq!(entry.tags, vec!["ema_cross"]);
+    }
+
+    #[test]
+    fn test_reversal_splits_trades() {
+        let mut journal = TradeJournal::new();
+
+        journal
+            .add_fill(&fill("1", "SELL", "100", "2.0", 1))
+            .unwrap();
+        assert_eq!(journal.add_fill(&fill("2", "BUY", "150", "1.0", 2)), Ok(1));
+        assert_eq!(journal.add_fill(&fill("3", "SELL", "50", "3.0", 3)), Ok(1));
+
+        let entries = journal.entries();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].side, "SHORT");
+        assert_eq!(entries[0].realized_pnl, 100.0);
+        assert!((entries[0].commissions - 1.0 - 2.0 / 3.0).abs() < 1e-9);
+        assert_eq!(entries[1].side, "LONG");
+        assert_eq!(entries[1].quantity, 50.0);
+        assert_eq!(entries[1].realized_pnl, 100.0);
+        assert_eq!(entries[1].ts_entry, 2);
+    }
+
+    #[test]
+    fn test_invalid_fill() {
+        let mut journal = TradeJournal::new();
+
+        assert!(matches!(
+            journal.add_fill(&fill("1", "HOLD", "100", "1.0", 1)),
+            Err(JournalError::InvalidFill(..))
+        ));
+        assert!(matches!(
+            journal.add_fill(&fill("2", "BUY", "abc", "1.0", 1)),
+            Err(JournalError::InvalidFill(..))
+        ));
+    }
+
+    #[test]
+    fn test_export_csv_and_json_lines() {
+        let mut journal = TradeJournal::new();
+        journal
+            .add_fill(&fill("1", "BUY", "100", "1.0", 1))
+            .unwrap();
+        journal.add_tag("AUD/USD.SIM", "breakout,retest");
+        journal
+            .add_fill(&fill("2", "SELL", "100", "1.5", 2))
+            .unwrap();
+
+        let mut csv = Vec::new();
+        let mut json = Vec::new();
+
+        assert_eq!(journal.export(&mut csv, JournalFormat::Csv), Ok(1));
+        assert_eq!(journal.export(&mut json, JournalFormat::JsonLines), Ok(1));
+        let csv = String::from_utf8(csv).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(
+            lines[1],
+            "AUD/USD.SIM,LONG,100,1,2,1,1.5,50,2,0,0.5,\"breakout,retest\""
+        );
+        let value: serde_json::Value = serde_json::from_slice(&json).unwrap();
+        assert_eq!(value["realized_pnl"], 50.0);
+        assert_eq!(value["tags"][0], "breakout,retest");
+    }
+}
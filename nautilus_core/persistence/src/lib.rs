@@ -0,0 +1,51 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+pub mod cache;
+pub mod cache_diff;
+#[cfg(feature = "cache-sled")]
+pub mod cache_sled;
+#[cfg(feature = "catalog")]
+pub mod catalog_export;
+#[cfg(feature = "catalog")]
+pub mod catalog_ingest;
+#[cfg(feature = "catalog")]
+pub mod catalog_integrity;
+#[cfg(feature = "catalog")]
+pub mod catalog_prefetch;
+#[cfg(feature = "catalog")]
+pub mod catalog_schema;
+#[cfg(feature = "sql")]
+pub mod catalog_sql;
+#[cfg(feature = "catalog")]
+pub mod catalog_trade_sign;
+#[cfg(feature = "catalog")]
+pub mod catalog_values;
+#[cfg(feature = "msgpack")]
+pub mod event_schema;
+pub mod history;
+#[cfg(feature = "postgres")]
+pub mod history_postgres;
+#[cfg(feature = "protobuf")]
+pub mod history_proto;
+#[cfg(feature = "journal")]
+pub mod journal;
+pub mod stream;
+#[cfg(feature = "kafka")]
+pub mod stream_kafka;
+#[cfg(feature = "stream-fbs")]
+pub mod stream_market_data;
+#[cfg(feature = "catalog")]
+pub mod vectorized;
@@ -0,0 +1,456 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Publishing of message bus topics to external streaming platforms.
+//!
+//! Messages are filtered on the calling thread against the configured topic
+//! patterns, then queued onto a [`StreamPublisher`] which sends them to a
+//! [`StreamProducer`] backend from a dedicated thread.
+
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StreamError {
+    /// The underlying producer failed.
+    Backend(String),
+    /// The publisher has been closed.
+    Closed,
+}
+
+impl Display for StreamError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            StreamError::Backend(reason) => write!(f, "Stream producer error, {}", reason),
+            StreamError::Closed => write!(f, "Stream publisher closed"),
+        }
+    }
+}
+
+impl Error for StreamError {}
+
+pub type StreamResult<T> = std::result::Result<T, StreamError>;
+
+/// Represents the delivery guarantee for published messages.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DeliveryGuarantee {
+    /// Messages are sent once, and dropped if the send fails.
+    AtMostOnce,
+    /// Failed sends are retried, and flushes wait for acknowledgement.
+    AtLeastOnce,
+}
+
+/// Represents how published messages are keyed for partitioning.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Partitioning {
+    /// Messages are not keyed, and spread across partitions by the backend.
+    None,
+    /// Messages are keyed by instrument ID, preserving per instrument order.
+    Instrument,
+    /// Messages are keyed by their message bus topic.
+    Topic,
+}
+
+/// Represents a message published on the message bus.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StreamMessage {
+    pub topic: String,
+    pub instrument_id: Option<String>,
+    pub payload: Vec<u8>,
+    pub ts_event: u64,
+}
+
+impl StreamMessage {
+    /// Returns the partition key for the message.
+    pub fn key(&self, partitioning: Partitioning) -> Option<&str> {
+        match partitioning {
+            Partitioning::None => None,
+            Partitioning::Instrument => self.instrument_id.as_deref(),
+            Partitioning::Topic => Some(self.topic.as_str()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StreamConfig {
+    /// The message bus topic patterns to publish, where `*` matches any
+    /// sequence of characters and `?` any single character.
+    pub topics: Vec<String>,
+    /// The prefix of the destination topic names.
+    pub topic_prefix: String,
+    pub partitioning: Partitioning,
+    pub delivery: DeliveryGuarantee,
+    /// The maximum number of retries of a failed send (`AtLeastOnce` only).
+    pub max_retries: usize,
+    /// The timeout for producer flushes.
+    pub flush_timeout: Duration,
+}
+
+impl Default for StreamConfig {
+    fn default() -> Self {
+        StreamConfig {
+            topics: vec!["*".to_string()],
+            topic_prefix: String::new(),
+            partitioning: Partitioning::Instrument,
+            delivery: DeliveryGuarantee::AtLeastOnce,
+            max_retries: 3,
+            flush_timeout: Duration::from_secs(10),
+        }
+    }
+}
+
+/// Returns whether the topic matches the pattern, where `*` matches any
+/// sequence of characters and `?` any single character.
+pub fn is_matching(topic: &str, pattern: &str) -> bool {
+    let (topic, pattern) = (topic.as_bytes(), pattern.as_bytes());
+    let (mut t, mut p) = (0, 0);
+    // Position of the last `*` in the pattern, and the topic position it matched from
+    let mut star: Option<(usize, usize)> = None;
+    while t < topic.len() {
+        if p < pattern.len() && (pattern[p] == b'?' || pattern[p] == topic[t]) {
+            t += 1;
+            p += 1;
+        } else if p < pattern.len() && pattern[p] == b'*' {
+            star = Some((p, t));
+            p += 1;
+        } else if let Some((star_p, star_t)) = star {
+            p = star_p + 1;
+            t = star_t + 1;
+            star = Some((star_p, star_t + 1));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|c| *c == b'*')
+}
+
+/// Returns the destination topic name for the message bus topic, with any
+/// characters other than ASCII alphanumerics, '.', '_' and '-' replaced by '_'.
+pub fn destination_topic(prefix: &str, topic: &str) -> String {
+    prefix
+        .chars()
+        .chain(topic.chars())
+        .map(|c| match c {
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '.' | '_' | '-' => c,
+            _ => '_',
+        })
+        .collect()
+}
+
+/// Provides the backend operations used by the stream publisher.
+pub trait StreamProducer: Send {
+    /// Sends the message, which may be buffered by the backend.
+    fn send(
+        &mut self,
+        topic: &str,
+        key: Option<&str>,
+        payload: &[u8],
+        ts_event: u64,
+    ) -> StreamResult<()>;
+    /// Blocks until all sent messages are acknowledged, or the timeout elapses.
+    fn flush(&mut self, timeout: Duration) -> StreamResult<()>;
+}
+
+enum Command {
+    Message(StreamMessage),
+    Flush(Sender<StreamResult<()>>),
+}
+
+/// Provides a background publisher of message bus topics to a producer.
+pub struct StreamPublisher {
+    config: StreamConfig,
+    tx: Option<Sender<Command>>,
+    handle: Option<JoinHandle<()>>,
+    dropped: Arc<AtomicU64>,
+}
+
+impl StreamPublisher {
+    /// Starts the publisher thread.
+    pub fn start<P: StreamProducer + 'static>(
+        producer: P,
+        config: StreamConfig,
+    ) -> StreamResult<Self> {
+        let (tx, rx) = channel();
+        let dropped = Arc::new(AtomicU64::new(0));
+        let thread_config = config.clone();
+        let thread_dropped = dropped.clone();
+        let handle = std::thread::Builder::new()
+            .name("stream-publisher".to_string())
+            .spawn(move || run(producer, rx, thread_config, thread_dropped))
+            .map_err(|e| StreamError::Backend(e.to_string()))?;
+        Ok(StreamPublisher {
+            config,
+            tx: Some(tx),
+            handle: Some(handle),
+            dropped,
+        })
+    }
+
+    /// Returns whether messages on the topic are published.
+    pub fn is_published(&self, topic: &str) -> bool {
+        self.config.topics.iter().any(|p| is_matching(topic, p))
+    }
+
+    /// Queues the message for publishing without blocking, returning whether
+    /// its topic is published.
+    pub fn publish(&self, message: StreamMessage) -> StreamResult<bool> {
+        if !self.is_published(&message.topic) {
+            return Ok(false);
+        }
+        self.tx
+            .as_ref()
+            .ok_or(StreamError::Closed)?
+            .send(Command::Message(message))
+            .map_err(|_| StreamError::Closed)?;
+        Ok(true)
+    }
+
+    /// Returns the number of messages dropped after failed sends.
+    pub fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+
+    /// Blocks until all queued messages are sent and flushed by the producer.
+    pub fn flush(&self) -> StreamResult<()> {
+        let (reply_tx, reply_rx) = channel();
+        self.tx
+            .as_ref()
+            .ok_or(StreamError::Closed)?
+            .send(Command::Flush(reply_tx))
+            .map_err(|_| StreamError::Closed)?;
+        reply_rx.recv().map_err(|_| StreamError::Closed)?
+    }
+
+    /// Flushes and stops the publisher thread.
+    pub fn close(&mut self) -> StreamResult<()> {
+        let result = self.flush();
+        self.tx = None;
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+        result
+    }
+}
+
+impl Drop for StreamPublisher {
+    fn drop(&mut self) {
+        if self.handle.is_some() {
+            let _ = self.close();
+        }
+    }
+}
+
+fn run<P: StreamProducer>(
+    mut producer: P,
+    rx: Receiver<Command>,
+    config: StreamConfig,
+    dropped: Arc<AtomicU64>,
+) {
+    let attempts = match config.delivery {
+        DeliveryGuarantee::AtMostOnce => 1,
+        DeliveryGuarantee::AtLeastOnce => config.max_retries + 1,
+    };
+    for command in rx {
+        match command {
+            Command::Message(message) => {
+                let topic = destination_topic(&config.topic_prefix, &message.topic);
+                let key = message.key(config.partitioning);
+                let sent = (0..attempts).any(|_| {
+                    producer
+                        .send(&topic, key, &message.payload, message.ts_event)
+                        .is_ok()
+                });
+                if !sent {
+                    dropped.fetch_add(1, Ordering::Relaxed);
+                }
+            }
+            Command::Flush(reply) => {
+                let _ = reply.send(producer.flush(config.flush_timeout));
+            }
+        }
+    }
+    let _ = producer.flush(config.flush_timeout);
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+
+    /// Represents a message sent to the mock producer, as
+    /// (topic, key, payload, ts_event).
+    type SentMessage = (String, Option<String>, Vec<u8>, u64);
+
+    #[derive(Clone, Default)]
+    struct MockProducer {
+        pub sent: Arc<Mutex<Vec<SentMessage>>>,
+        pub failures: Arc<Mutex<usize>>,
+        pub flushes: Arc<Mutex<usize>>,
+    }
+
+    impl StreamProducer for MockProducer {
+        fn send(
+            &mut self,
+            topic: &str,
+            key: Option<&str>,
+            payload: &[u8],
+            ts_event: u64,
+        ) -> StreamResult<()> {
+            let mut failures = self.failures.lock().unwrap();
+            if *failures > 0 {
+                *failures -= 1;
+                return Err(StreamError::Backend("queue full".to_string()));
+            }
+            self.sent.lock().unwrap().push((
+                topic.to_string(),
+                key.map(str::to_string),
+                payload.to_vec(),
+                ts_event,
+            ));
+            Ok(())
+        }
+
+        fn flush(&mut self, _timeout: Duration) -> StreamResult<()> {
+            *self.flushes.lock().unwrap() += 1;
+            Ok(())
+        }
+    }
+
+    fn quote(topic: &str, instrument_id: &str) -> StreamMessage {
+        StreamMessage {
+            topic: topic.to_string(),
+            instrument_id: Some(instrument_id.to_string()),
+            payload: b"quote".to_vec(),
+            ts_event: 1,
+        }
+    }
+
+    #[test]
+    fn test_is_matching() {
+        assert!(is_matching("data.quotes.BINANCE.ETHUSDT", "*"));
+        assert!(is_matching("data.quotes.BINANCE.ETHUSDT", "data.quotes.*"));
+        assert!(is_matching(
+            "data.quotes.BINANCE.ETHUSDT",
+            "data.*.BINANCE.*"
+        ));
+        assert!(is_matching("events.order.S-001", "events.order.S-00?"));
+        assert!(is_matching("events.order", "events.order*"));
+        assert!(!is_matching("data.trades.BINANCE.ETHUSDT", "data.quotes.*"));
+        assert!(!is_matching("events.order.S-0010", "events.order.S-00?"));
+        assert!(!is_matching("", "?"));
+    }
+
+    #[test]
+    fn test_destination_topic() {
+        assert_eq!(
+            destination_topic("nautilus.", "data.quotes.BINANCE.ETH/USDT"),
+            "nautilus.data.quotes.BINANCE.ETH_USDT"
+        );
+    }
+
+    #[test]
+    fn test_message_keys() {
+        let message = quote("data.quotes.SIM.AUD/USD", "AUD/USD.SIM");
+
+        assert_eq!(message.key(Partitioning::None), None);
+        assert_eq!(message.key(Partitioning::Instrument), Some("AUD/USD.SIM"));
+        assert_eq!(
+            message.key(Partitioning::Topic),
+            Some("data.quotes.SIM.AUD/USD")
+        );
+    }
+
+    #[test]
+    fn test_publisher_filters_and_keys_by_instrument() {
+        let producer = MockProducer::default();
+        let config = StreamConfig {
+            topics: vec!["data.quotes.*".to_string()],
+            topic_prefix: "nautilus.".to_string(),
+            ..Default::default()
+        };
+        let publisher = StreamPublisher::start(producer.clone(), config).unwrap();
+
+        let quote_published = publisher
+            .publish(quote("data.quotes.SIM.AUD/USD", "AUD/USD.SIM"))
+            .unwrap();
+        let trade_published = publisher
+            .publish(quote("data.trades.SIM.AUD/USD", "AUD/USD.SIM"))
+            .unwrap();
+        publisher.flush().unwrap();
+
+        assert!(quote_published);
+        assert!(!trade_published);
+        assert_eq!(
+            *producer.sent.lock().unwrap(),
+            vec![(
+                "nautilus.data.quotes.SIM.AUD_USD".to_string(),
+                Some("AUD/USD.SIM".to_string()),
+                b"quote".to_vec(),
+                1
+            )]
+        );
+        assert_eq!(*producer.flushes.lock().unwrap(), 1);
+    }
+
+    #[test]
+    fn test_at_least_once_retries_failed_sends() {
+        let producer = MockProducer::default();
+        *producer.failures.lock().unwrap() = 2;
+        let publisher = StreamPublisher::start(producer.clone(), StreamConfig::default()).unwrap();
+
+        publisher
+            .publish(quote("data.quotes.SIM.AUD/USD", "AUD/USD.SIM"))
+            .unwrap();
+        publisher.flush().unwrap();
+
+        assert_eq!(producer.sent.lock().unwrap().len(), 1);
+        assert_eq!(publisher.dropped(), 0);
+    }
+
+    #[test]
+    fn test_at_most_once_drops_failed_sends() {
+        let producer = MockProducer::default();
+        *producer.failures.lock().unwrap() = 1;
+        let config = StreamConfig {
+            delivery: DeliveryGuarantee::AtMostOnce,
+            ..Default::default()
+        };
+        let mut publisher = StreamPublisher::start(producer.clone(), config).unwrap();
+
+        publisher
+            .publish(quote("data.quotes.SIM.AUD/USD", "AUD/USD.SIM"))
+            .unwrap();
+        publisher
+            .publish(quote("data.quotes.SIM.GBP/USD", "GBP/USD.SIM"))
+            .unwrap();
+        publisher.close().unwrap();
+
+        assert_eq!(producer.sent.lock().unwrap().len(), 1);
+        assert_eq!(publisher.dropped(), 1);
+        assert_eq!(
+            publisher.publish(quote("data.quotes.SIM.AUD/USD", "AUD/USD.SIM")),
+            Err(StreamError::Closed)
+        );
+    }
+}
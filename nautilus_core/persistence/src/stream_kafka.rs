@@ -0,0 +1,179 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Kafka backend for the stream publisher.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use rdkafka::config::ClientConfig;
+use rdkafka::producer::{BaseProducer, BaseRecord, DeliveryResult, Producer, ProducerContext};
+use rdkafka::ClientContext;
+
+use crate::stream::{DeliveryGuarantee, StreamError, StreamProducer, StreamResult};
+
+fn backend_error(e: rdkafka::error::KafkaError) -> StreamError {
+    StreamError::Backend(e.to_string())
+}
+
+/// Counts messages which failed delivery after the client's own retries.
+#[derive(Default)]
+pub struct DeliveryCounter {
+    failed: Arc<AtomicU64>,
+}
+
+impl ClientContext for DeliveryCounter {}
+
+impl ProducerContext for DeliveryCounter {
+    type DeliveryOpaque = ();
+
+    fn delivery(&self, delivery_result: &DeliveryResult<'_>, _: Self::DeliveryOpaque) {
+        if delivery_result.is_err() {
+            self.failed.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
+
+/// Provides a Kafka stream producer.
+pub struct KafkaProducer {
+    producer: BaseProducer<DeliveryCounter>,
+    failed: Arc<AtomicU64>,
+    reported: u64,
+}
+
+impl KafkaProducer {
+    /// Returns the client configuration for the brokers and delivery
+    /// guarantee, with the additional client properties applied last.
+    pub fn client_config(
+        brokers: &str,
+        delivery: DeliveryGuarantee,
+        properties: &[(&str, &str)],
+    ) -> ClientConfig {
+        let mut config = ClientConfig::new();
+        config.set("bootstrap.servers", brokers);
+        match delivery {
+            DeliveryGuarantee::AtMostOnce => {
+                config.set("acks", "0").set("message.send.max.retries", "0");
+            }
+            DeliveryGuarantee::AtLeastOnce => {
+                config.set("acks", "all").set("enable.idempotence", "true");
+            }
+        }
+        for (key, value) in properties {
+            config.set(*key, *value);
+        }
+        config
+    }
+
+    /// Connects to the comma separated brokers, e.g. `localhost:9092`.
+    pub fn connect(
+        brokers: &str,
+        delivery: DeliveryGuarantee,
+        properties: &[(&str, &str)],
+    ) -> StreamResult<Self> {
+        let counter = DeliveryCounter::default();
+        let failed = counter.failed.clone();
+        let producer = Self::client_config(brokers, delivery, properties)
+            .create_with_context(counter)
+            .map_err(backend_error)?;
+        Ok(KafkaProducer {
+            producer,
+            failed,
+            reported: 0,
+        })
+    }
+}
+
+impl StreamProducer for KafkaProducer {
+    fn send(
+        &mut self,
+        topic: &str,
+        key: Option<&str>,
+        payload: &[u8],
+        ts_event: u64,
+    ) -> StreamResult<()> {
+        let mut record = BaseRecord::to(topic)
+            .payload(payload)
+            .timestamp((ts_event / 1_000_000) as i64);
+        if let Some(key) = key {
+            record = record.key(key);
+        }
+        let result = self
+            .producer
+            .send(record)
+            .map_err(|(e, _)| backend_error(e));
+        // Serve delivery callbacks and free queue space
+        self.producer.poll(Duration::ZERO);
+        result
+    }
+
+    fn flush(&mut self, timeout: Duration) -> StreamResult<()> {
+        self.producer.flush(timeout).map_err(backend_error)?;
+        let failed = self.failed.load(Ordering::Relaxed);
+        let new_failures = failed - self.reported;
+        self.reported = failed;
+        if new_failures > 0 {
+            return Err(StreamError::Backend(format!(
+                "{} messages failed delivery",
+                new_failures
+            )));
+        }
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_client_config_at_least_once() {
+        let config = KafkaProducer::client_config(
+            "localhost:9092",
+            DeliveryGuarantee::AtLeastOnce,
+            &[("linger.ms", "5")],
+        );
+
+        assert_eq!(config.get("bootstrap.servers"), Some("localhost:9092"));
+        assert_eq!(config.get("acks"), Some("all"));
+        assert_eq!(config.get("enable.idempotence"), Some("true"));
+        assert_eq!(config.get("linger.ms"), Some("5"));
+    }
+
+    #[test]
+    fn test_client_config_properties_override() {
+        let config = KafkaProducer::client_config(
+            "localhost:9092",
+            DeliveryGuarantee::AtMostOnce,
+            &[("acks", "1")],
+        );
+
+        assert_eq!(config.get("acks"), Some("1"));
+        assert_eq!(config.get("message.send.max.retries"), Some("0"));
+    }
+
+    #[test]
+    fn test_connect_is_lazy() {
+        // The client connects in the background, so creation needs no broker
+        let producer =
+            KafkaProducer::connect("localhost:1", DeliveryGuarantee::AtMostOnce, &[]).unwrap();
+
+        assert_eq!(producer.reported, 0);
+    }
+}
@@ -0,0 +1,328 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Publishing of market data to streams in a selectable encoding.
+//!
+//! FlatBuffers payloads can be read in place by consumers with the views in
+//! `nautilus_model::data::fbs`, while JSON payloads carry the same fields for
+//! consumers without the schema.
+
+use nautilus_model::data::fbs::{encode_level_delta, encode_quote_tick, encode_trade_tick};
+use nautilus_model::data::tick::{QuoteTick, TradeTick};
+use nautilus_model::identifiers::instrument_id::InstrumentId;
+use nautilus_model::orderbook::aggregation::LevelDelta;
+use serde_json::json;
+
+use crate::stream::{StreamMessage, StreamPublisher, StreamResult};
+
+/// Represents the encoding of published market data payloads.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MarketDataEncoding {
+    /// A JSON object of the raw fixed-point values and precisions.
+    Json,
+    /// A FlatBuffers table per `fbs/market_data.fbs`.
+    FlatBuffers,
+}
+
+/// Represents market data to publish.
+#[derive(Clone, Debug, PartialEq)]
+pub enum MarketData {
+    Quote(QuoteTick),
+    Trade(TradeTick),
+    LevelDelta {
+        instrument_id: InstrumentId,
+        delta: LevelDelta,
+        ts_event: i64,
+    },
+}
+
+impl MarketData {
+    pub fn instrument_id(&self) -> &InstrumentId {
+        match self {
+            MarketData::Quote(tick) => &tick.instrument_id,
+            MarketData::Trade(tick) => &tick.instrument_id,
+            MarketData::LevelDelta { instrument_id, .. } => instrument_id,
+        }
+    }
+
+    pub fn ts_event(&self) -> i64 {
+        match self {
+            MarketData::Quote(tick) => tick.ts_event.value,
+            MarketData::Trade(tick) => tick.ts_event.value,
+            MarketData::LevelDelta { ts_event, .. } => *ts_event,
+        }
+    }
+
+    /// Returns the message bus topic the data is published on.
+    pub fn topic(&self) -> String {
+        let kind = match self {
+            MarketData::Quote(_) => "data.quotes",
+            MarketData::Trade(_) => "data.trades",
+            MarketData::LevelDelta { .. } => "data.book.deltas",
+        };
+        let instrument_id = self.instrument_id();
+        format!("{}.{}.{}", kind, instrument_id.venue, instrument_id.symbol)
+    }
+
+    /// Returns the data encoded as a payload.
+    pub fn encode(&self, encoding: MarketDataEncoding) -> Vec<u8> {
+        match encoding {
+            MarketDataEncoding::FlatBuffers => match self {
+                MarketData::Quote(tick) => encode_quote_tick(tick),
+                MarketData::Trade(tick) => encode_trade_tick(tick),
+                MarketData::LevelDelta {
+                    instrument_id,
+                    delta,
+                    ts_event,
+                } => encode_level_delta(instrument_id, delta, *ts_event),
+            },
+            MarketDataEncoding::Json => self.to_json().to_string().into_bytes(),
+        }
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        match self {
+            MarketData::Quote(tick) => json!({
+                "type": "QuoteTick",
+                "instrument_id": tick.instrument_id.to_string(),
+                "bid": tick.bid.raw,
+                "ask": tick.ask.raw,
+                "price_precision": tick.bid.precision,
+                "bid_size": tick.bid_size.raw,
+                "ask_size": tick.ask_size.raw,
+                "size_precision": tick.bid_size.precision,
+                "ts_event": tick.ts_event.value,
+                "ts_init": tick.ts_init.value,
+            }),
+            MarketData::Trade(tick) => json!({
+                "type": "TradeTick",
+                "instrument_id": tick.instrument_id.to_string(),
+                "price": tick.price.raw,
+                "price_precision": tick.price.precision,
+                "size": tick.size.raw,
+                "size_precision": tick.size.precision,
+                "aggressor_side": tick.aggressor_side as u8,
+                "trade_id": tick.trade_id.to_string(),
+                "ts_event": tick.ts_event.value,
+                "ts_init": tick.ts_init.value,
+            }),
+            MarketData::LevelDelta {
+                instrument_id,
+                delta,
+                ts_event,
+            } => json!({
+                "type": "LevelDelta",
+                "instrument_id": instrument_id.to_string(),
+                "action": delta.action as u8,
+                "side": delta.side as u8,
+                "price": delta.price.raw,
+                "price_precision": delta.price.precision,
+                "size": delta.size.raw,
+                "size_precision": delta.size.precision,
+                "ts_event": ts_event,
+            }),
+        }
+    }
+
+    /// Returns the stream message for the data, encoded as a payload.
+    pub fn to_stream_message(&self, encoding: MarketDataEncoding) -> StreamMessage {
+        StreamMessage {
+            topic: self.topic(),
+            instrument_id: Some(self.instrument_id().to_string()),
+            payload: self.encode(encoding),
+            ts_event: self.ts_event() as u64,
+        }
+    }
+}
+
+impl StreamPublisher {
+    /// Queues the market data for publishing in the encoding, returning whether
+    /// its topic is published.
+    ///
+    /// The data is only encoded if its topic is published.
+    pub fn publish_market_data(
+        &self,
+        data: &MarketData,
+        encoding: MarketDataEncoding,
+    ) -> StreamResult<bool> {
+        if !self.is_published(&data.topic()) {
+            return Ok(false);
+        }
+        self.publish(data.to_stream_message(encoding))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    use nautilus_core::time::Timestamp;
+    use nautilus_model::data::fbs::{
+        market_data_kind, LevelDeltaView, MarketDataKind, QuoteTickView, TradeTickView,
+    };
+    use nautilus_model::enums::{BookAction, OrderSide};
+    use nautilus_model::identifiers::trade_id::TradeId;
+    use nautilus_model::types::price::Price;
+    use nautilus_model::types::quantity::Quantity;
+
+    use super::*;
+    use crate::stream::{StreamConfig, StreamProducer};
+
+    /// Represents a message sent to the recording producer, as (topic, payload).
+    type SentMessage = (String, Vec<u8>);
+
+    #[derive(Clone, Default)]
+    struct RecordingProducer {
+        sent: Arc<Mutex<Vec<SentMessage>>>,
+    }
+
+    impl StreamProducer for RecordingProducer {
+        fn send(
+            &mut self,
+            topic: &str,
+            _key: Option<&str>,
+            payload: &[u8],
+            _ts_event: u64,
+        ) -> StreamResult<()> {
+            self.sent
+                .lock()
+                .unwrap()
+                .push((topic.to_string(), payload.to_vec()));
+            Ok(())
+        }
+
+        fn flush(&mut self, _timeout: Duration) -> StreamResult<()> {
+            Ok(())
+        }
+    }
+
+    fn instrument_id() -> InstrumentId {
+        InstrumentId::from_dotted("ETH/USDT.BINANCE")
+    }
+
+    fn quote() -> QuoteTick {
+        QuoteTick {
+            instrument_id: instrument_id(),
+            bid: Price::new(1500.25, 2),
+            ask: Price::new(1500.50, 2),
+            bid_size: Quantity::new(1.5, 3),
+            ask_size: Quantity::new(2.0, 3),
+            ts_event: Timestamp { value: 1 },
+            ts_init: Timestamp { value: 2 },
+        }
+    }
+
+    fn trade() -> TradeTick {
+        TradeTick {
+            instrument_id: instrument_id(),
+            price: Price::new(1500.25, 2),
+            size: Quantity::new(0.25, 3),
+            aggressor_side: OrderSide::Buy,
+            trade_id: TradeId::new("T-001"),
+            ts_event: Timestamp { value: 3 },
+            ts_init: Timestamp { value: 4 },
+        }
+    }
+
+    fn delta() -> LevelDelta {
+        LevelDelta {
+            action: BookAction::Update,
+            side: OrderSide::Sell,
+            price: Price::new(1500.50, 2),
+            size: Quantity::new(3.0, 3),
+        }
+    }
+
+    fn publish(data: &[MarketData], encoding: MarketDataEncoding) -> Vec<SentMessage> {
+        let producer = RecordingProducer::default();
+        let config = StreamConfig {
+            topics: vec!["data.quotes.*".to_string(), "data.book.*".to_string()],
+            ..Default::default()
+        };
+        let mut publisher = StreamPublisher::start(producer.clone(), config).unwrap();
+        for item in data {
+            publisher.publish_market_data(item, encoding).unwrap();
+        }
+        publisher.close().unwrap();
+        let sent = producer.sent.lock().unwrap().clone();
+        sent
+    }
+
+    #[test]
+    fn test_topic() {
+        assert_eq!(
+            MarketData::Quote(quote()).topic(),
+            "data.quotes.BINANCE.ETH/USDT"
+        );
+        assert_eq!(
+            MarketData::Trade(trade()).topic(),
+            "data.trades.BINANCE.ETH/USDT"
+        );
+    }
+
+    #[test]
+    fn test_publish_flatbuffers_round_trips_through_views() {
+        let data = [
+            MarketData::Quote(quote()),
+            MarketData::Trade(trade()),
+            MarketData::LevelDelta {
+                instrument_id: instrument_id(),
+                delta: delta(),
+                ts_event: 5,
+            },
+        ];
+
+        let sent = publish(&data, MarketDataEncoding::FlatBuffers);
+
+        // Trades are not published by the topic patterns
+        assert_eq!(sent.len(), 2);
+        assert_eq!(sent[0].0, "data.quotes.BINANCE.ETH_USDT");
+        assert_eq!(
+            market_data_kind(&sent[0].1),
+            Some(MarketDataKind::QuoteTick)
+        );
+        assert_eq!(
+            QuoteTickView::new(&sent[0].1).unwrap().to_quote_tick(),
+            quote()
+        );
+        assert_eq!(sent[1].0, "data.book.deltas.BINANCE.ETH_USDT");
+        let view = LevelDeltaView::new(&sent[1].1).unwrap();
+        assert_eq!(view.to_level_delta().unwrap(), delta());
+        assert_eq!(view.instrument_id(), "ETH/USDT.BINANCE");
+        assert_eq!(view.ts_event(), 5);
+        let trade_view = TradeTickView::new(&data[1].encode(MarketDataEncoding::FlatBuffers))
+            .unwrap()
+            .to_trade_tick()
+            .unwrap();
+        assert_eq!(trade_view, trade());
+    }
+
+    #[test]
+    fn test_publish_json() {
+        let sent = publish(&[MarketData::Quote(quote())], MarketDataEncoding::Json);
+
+        let value: serde_json::Value = serde_json::from_slice(&sent[0].1).unwrap();
+        assert_eq!(value["type"], "QuoteTick");
+        assert_eq!(value["instrument_id"], "ETH/USDT.BINANCE");
+        assert_eq!(value["bid"], quote().bid.raw);
+        assert_eq!(value["price_precision"], 2);
+        assert_eq!(value["ts_init"], 2);
+        assert_eq!(market_data_kind(&sent[0].1), None);
+    }
+}
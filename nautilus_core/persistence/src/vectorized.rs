@@ -0,0 +1,358 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Vectorized fill simulation over columns of bars and signals.
+//!
+//! Research strategies compute their signals for an entire backtest in bulk,
+//! so rather than handling per-event callbacks the fills are simulated over
+//! the columns in one call. The input batch has the columns:
+//!
+//! - `ts_event`: the row timestamps (Int64).
+//! - `price`: the fill price for a signal on the row, e.g. the open of the
+//!   next bar to avoid look-ahead (Float64, null if no fill is possible).
+//! - `mark`: the price to value the position at, e.g. the close (Float64).
+//! - `target`: the target position from the signal (Float64, null to hold).
+//!
+//! Integer columns are cast to Float64, and the fills are simulated by the
+//! `FastBacktest` of the model crate with nulls as NaN.
+
+use std::error::Error;
+use std::fmt::{Display, Formatter, Result};
+use std::sync::Arc;
+
+use arrow::array::{Array, ArrayRef, Float64Array, StructArray};
+use arrow::compute::cast;
+use arrow::datatypes::{DataType, Field, Schema};
+use arrow::ffi::{from_ffi, FFI_ArrowArray, FFI_ArrowSchema};
+use arrow::ffi_stream::FFI_ArrowArrayStream;
+use arrow::record_batch::{RecordBatch, RecordBatchIterator};
+use nautilus_model::fast_backtest::FastBacktest;
+pub use nautilus_model::fast_backtest::FillCosts;
+
+/// Represents a vectorized simulation failure.
+///
+/// The `code` of each variant is returned by the C API, where zero indicates
+/// success.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum VectorizedError {
+    /// An argument was null or not valid.
+    InvalidArgument(String),
+    /// An input column was missing or of an unsupported type.
+    Schema(String),
+    /// The input could not be imported or the output built.
+    Arrow(String),
+}
+
+impl VectorizedError {
+    pub fn code(&self) -> u8 {
+        match self {
+            VectorizedError::InvalidArgument(_) => 1,
+            VectorizedError::Schema(_) => 2,
+            VectorizedError::Arrow(_) => 3,
+        }
+    }
+}
+
+impl Display for VectorizedError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            VectorizedError::InvalidArgument(reason) => write!(f, "Invalid argument, {}", reason),
+            VectorizedError::Schema(reason) => write!(f, "Schema error, {}", reason),
+            VectorizedError::Arrow(reason) => write!(f, "Arrow error, {}", reason),
+        }
+    }
+}
+
+impl Error for VectorizedError {}
+
+pub type VectorizedResult<T> = std::result::Result<T, VectorizedError>;
+
+/// Returns the schema of the simulation output, with a row per input row.
+pub fn fills_schema() -> Schema {
+    Schema::new(vec![
+        Field::new("ts_event", DataType::Int64, false),
+        Field::new("position", DataType::Float64, false),
+        Field::new("fill_quantity", DataType::Float64, false),
+        Field::new("fill_price", DataType::Float64, true),
+        Field::new("commission", DataType::Float64, false),
+        Field::new("pnl", DataType::Float64, false),
+    ])
+}
+
+fn float_column(batch: &RecordBatch, name: &str) -> VectorizedResult<Float64Array> {
+    let column = batch
+        .column_by_name(name)
+        .ok_or_else(|| VectorizedError::Schema(format!("missing column `{}`", name)))?;
+    let column = cast(column, &DataType::Float64)
+        .map_err(|e| VectorizedError::Schema(format!("column `{}`: {}", name, e)))?;
+    Ok(column
+        .as_any()
+        .downcast_ref::<Float64Array>()
+        .expect("cast to Float64")
+        .clone())
+}
+
+fn values(array: &Float64Array) -> Vec<f64> {
+    array.iter().map(|v| v.unwrap_or(f64::NAN)).collect()
+}
+
+/// Returns the simulated position, fills and cumulative PnL for each row of
+/// the batch, starting flat.
+///
+/// When the target differs from the position and the row has a fill price,
+/// the difference is filled at the price adjusted for slippage. The PnL is
+/// the cash from the fills (less commissions) plus the position valued at
+/// the last mark.
+pub fn simulate_fills(batch: &RecordBatch, costs: &FillCosts) -> VectorizedResult<RecordBatch> {
+    let ts_event = batch
+        .column_by_name("ts_event")
+        .ok_or_else(|| VectorizedError::Schema("missing column `ts_event`".to_string()))?;
+    let ts_event = cast(ts_event, &DataType::Int64)
+        .map_err(|e| VectorizedError::Schema(format!("column `ts_event`: {}", e)))?;
+    let price = values(&float_column(batch, "price")?);
+    let mark = values(&float_column(batch, "mark")?);
+    let target = values(&float_column(batch, "target")?);
+    if let Some((field, value)) = costs.invalid_field() {
+        return Err(VectorizedError::InvalidArgument(format!(
+            "`{}` was {}",
+            field, value
+        )));
+    }
+    let fills = FastBacktest::run(&price, &mark, &target, costs)
+        .map_err(|e| VectorizedError::InvalidArgument(e.to_string()))?;
+    let fill_prices: Float64Array = fills
+        .fill_price
+        .iter()
+        .map(|p| p.is_finite().then_some(*p))
+        .collect();
+
+    let columns: Vec<ArrayRef> = vec![
+        ts_event,
+        Arc::new(Float64Array::from(fills.position)),
+        Arc::new(Float64Array::from(fills.fill_quantity)),
+        Arc::new(fill_prices),
+        Arc::new(Float64Array::from(fills.commission)),
+        Arc::new(Float64Array::from(fills.pnl)),
+    ];
+    RecordBatch::try_new(Arc::new(fills_schema()), columns)
+        .map_err(|e| VectorizedError::Arrow(e.to_string()))
+}
+
+unsafe fn import_batch(
+    array: *mut FFI_ArrowArray,
+    schema: *const FFI_ArrowSchema,
+) -> VectorizedResult<RecordBatch> {
+    if array.is_null() || schema.is_null() {
+        return Err(VectorizedError::InvalidArgument(
+            "`array` or `schema` was null".to_string(),
+        ));
+    }
+    let data = from_ffi(FFI_ArrowArray::from_raw(array), &*schema)
+        .map_err(|e| VectorizedError::Arrow(e.to_string()))?;
+    if !matches!(data.data_type(), DataType::Struct(_)) {
+        return Err(VectorizedError::Schema(
+            "input was not a record batch".to_string(),
+        ));
+    }
+    Ok(RecordBatch::from(StructArray::from(data)))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// C API
+////////////////////////////////////////////////////////////////////////////////
+/// Simulates the fills for the record batch exported through the Arrow C data
+/// interface (e.g. `pyarrow.RecordBatch._export_to_c`), writing the output to
+/// `out` as an Arrow C stream and returning zero on success or the
+/// `VectorizedError` code.
+///
+/// # Safety
+///
+/// - `array` and `schema` must be valid pointers to the exported batch, and
+///   the array is released (moved) by this call.
+/// - `out` must be a valid pointer to write the stream to, which the caller
+///   then owns (e.g. `pyarrow.RecordBatchReader._import_from_c`).
+#[no_mangle]
+pub unsafe extern "C" fn vectorized_simulate_fills(
+    array: *mut FFI_ArrowArray,
+    schema: *const FFI_ArrowSchema,
+    commission_rate: f64,
+    slippage: f64,
+    out: *mut FFI_ArrowArrayStream,
+) -> u8 {
+    let costs = FillCosts {
+        commission_rate,
+        slippage,
+    };
+    let result = import_batch(array, schema).and_then(|batch| simulate_fills(&batch, &costs));
+    match result {
+        Ok(fills) => {
+            let reader = RecordBatchIterator::new(vec![Ok(fills)], Arc::new(fills_schema()));
+            out.write(FFI_ArrowArrayStream::new(Box::new(reader)));
+            0
+        }
+        Err(e) => e.code(),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow::array::{Array, Float64Array, Int64Array, StructArray};
+    use arrow::datatypes::{DataType, Field, Schema};
+    use arrow::ffi::to_ffi;
+    use arrow::ffi_stream::{ArrowArrayStreamReader, FFI_ArrowArrayStream};
+    use arrow::record_batch::RecordBatch;
+
+    use super::*;
+
+    fn batch(price: Vec<Option<f64>>, mark: Vec<f64>, target: Vec<Option<f64>>) -> RecordBatch {
+        let rows = price.len() as i64;
+        RecordBatch::try_new(
+            Arc::new(Schema::new(vec![
+                Field::new("ts_event", DataType::Int64, false),
+                Field::new("price", DataType::Float64, true),
+                Field::new("mark", DataType::Float64, false),
+                Field::new("target", DataType::Float64, true),
+            ])),
+            vec![
+                Arc::new(Int64Array::from_iter_values(0..rows)),
+                Arc::new(Float64Array::from(price)),
+                Arc::new(Float64Array::from(mark)),
+                Arc::new(Float64Array::from(target)),
+            ],
+        )
+        .unwrap()
+    }
+
+    fn column(batch: &RecordBatch, name: &str) -> Vec<Option<f64>> {
+        batch
+            .column_by_name(name)
+            .unwrap()
+            .as_any()
+            .downcast_ref::<Float64Array>()
+            .unwrap()
+            .iter()
+            .collect()
+    }
+
+    #[test]
+    fn test_simulate_fills_tracks_position_and_pnl() {
+        let input = batch(
+            vec![Some(10.0), Some(11.0), Some(12.0), Some(13.0)],
+            vec![10.5, 11.5, 12.5, 13.5],
+            vec![Some(2.0), None, Some(-1.0), Some(-1.0)],
+        );
+
+        let fills = simulate_fills(&input, &FillCosts::default()).unwrap();
+
+        assert_eq!(fills.schema().as_ref(), &fills_schema());
+        assert_eq!(
+            column(&fills, "position"),
+            vec![Some(2.0), Some(2.0), Some(-1.0), Some(-1.0)]
+        );
+        assert_eq!(
+            column(&fills, "fill_quantity"),
+            vec![Some(2.0), Some(0.0), Some(-3.0), Some(0.0)]
+        );
+        assert_eq!(
+            column(&fills, "fill_price"),
+            vec![Some(10.0), None, Some(12.0), None]
+        );
+        // Bought 2 at 10, sold 3 at 12, short 1 marked at 12.5 then 13.5
+        assert_eq!(
+            column(&fills, "pnl"),
+            vec![Some(1.0), Some(3.0), Some(3.5), Some(2.5)]
+        );
+    }
+
+    #[test]
+    fn test_simulate_fills_applies_costs() {
+        let input = batch(
+            vec![Some(100.0), Some(100.0)],
+            vec![100.0, 100.0],
+            vec![Some(1.0), Some(0.0)],
+        );
+        let costs = FillCosts {
+            commission_rate: 0.001,
+            slippage: 0.01,
+        };
+
+        let fills = simulate_fills(&input, &costs).unwrap();
+
+        assert_eq!(column(&fills, "fill_price"), vec![Some(101.0), Some(99.0)]);
+        assert_eq!(column(&fills, "commission"), vec![Some(0.101), Some(0.099)]);
+        let pnl = column(&fills, "pnl");
+        assert!((pnl[1].unwrap() - (-2.0 - 0.2)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_simulate_fills_holds_without_fill_price() {
+        let input = batch(
+            vec![None, Some(5.0)],
+            vec![5.0, 6.0],
+            vec![Some(1.0), Some(1.0)],
+        );
+
+        let fills = simulate_fills(&input, &FillCosts::default()).unwrap();
+
+        assert_eq!(column(&fills, "position"), vec![Some(0.0), Some(1.0)]);
+        assert_eq!(column(&fills, "pnl"), vec![Some(0.0), Some(1.0)]);
+    }
+
+    #[test]
+    fn test_simulate_fills_errors() {
+        let input = batch(vec![Some(1.0)], vec![1.0], vec![Some(1.0)]);
+        let missing = input.project(&[0, 1, 2]).unwrap();
+        let costs = FillCosts {
+            commission_rate: -0.1,
+            slippage: 0.0,
+        };
+
+        assert_eq!(
+            simulate_fills(&missing, &FillCosts::default()).unwrap_err(),
+            VectorizedError::Schema("missing column `target`".to_string())
+        );
+        assert_eq!(
+            simulate_fills(&input, &costs).unwrap_err(),
+            VectorizedError::InvalidArgument("`commission_rate` was -0.1".to_string())
+        );
+        assert_eq!(simulate_fills(&input, &costs).unwrap_err().code(), 1);
+    }
+
+    #[test]
+    fn test_c_api_round_trip() {
+        let input = batch(
+            vec![Some(10.0), Some(12.0)],
+            vec![10.0, 12.0],
+            vec![Some(1.0), None],
+        );
+        let (mut array, schema) = to_ffi(&StructArray::from(input).into_data()).unwrap();
+        let mut stream = FFI_ArrowArrayStream::empty();
+
+        let code = unsafe { vectorized_simulate_fills(&mut array, &schema, 0.0, 0.0, &mut stream) };
+
+        assert_eq!(code, 0);
+        let batches: Vec<RecordBatch> = ArrowArrayStreamReader::try_new(stream)
+            .unwrap()
+            .map(|b| b.unwrap())
+            .collect();
+        assert_eq!(batches.len(), 1);
+        assert_eq!(column(&batches[0], "pnl"), vec![Some(0.0), Some(2.0)]);
+    }
+}
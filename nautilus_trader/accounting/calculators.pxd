@@ -15,12 +15,15 @@
 
 from cpython.datetime cimport date
 
+from nautilus_trader.core.rust.model cimport ExchangeRateCalculator_t
 from nautilus_trader.model.c_enums.price_type cimport PriceType
 from nautilus_trader.model.currency cimport Currency
 from nautilus_trader.model.identifiers cimport InstrumentId
 
 
 cdef class ExchangeRateCalculator:
+    cdef ExchangeRateCalculator_t *_mem
+
     cpdef double get_rate(
         self,
         Currency from_currency,
@@ -14,11 +14,17 @@
 # -------------------------------------------------------------------------------------------------
 
 from decimal import Decimal
-from itertools import permutations
 
 import pandas as pd
 
+from cpython.object cimport PyObject
+
 from nautilus_trader.core.correctness cimport Condition
+from nautilus_trader.core.rust.model cimport exchange_rate_calculator_clear
+from nautilus_trader.core.rust.model cimport exchange_rate_calculator_free
+from nautilus_trader.core.rust.model cimport exchange_rate_calculator_get_rate
+from nautilus_trader.core.rust.model cimport exchange_rate_calculator_new
+from nautilus_trader.core.rust.model cimport exchange_rate_calculator_update
 from nautilus_trader.model.c_enums.price_type cimport PriceType
 from nautilus_trader.model.c_enums.price_type cimport PriceTypeParser
 from nautilus_trader.model.currency cimport Currency
@@ -32,6 +38,12 @@ cdef class ExchangeRateCalculator:
     An exchange rate is the value of one asset versus that of another.
     """
 
+    def __init__(self):
+        self._mem = exchange_rate_calculator_new()
+
+    def __del__(self) -> None:
+        exchange_rate_calculator_free(self._mem)  # Freed by Rust
+
     cpdef double get_rate(
         self,
         Currency from_currency,
@@ -82,104 +94,27 @@ cdef class ExchangeRateCalculator:
         if from_currency == to_currency:
             return 1.0  # No conversion necessary
 
-        if price_type == PriceType.BID:
-            calculation_quotes = bid_quotes
-        elif price_type == PriceType.ASK:
-            calculation_quotes = ask_quotes
-        elif price_type == PriceType.MID:
-            calculation_quotes = {
-                s: (bid_quotes[s] + ask_quotes[s]) / 2.0 for s in bid_quotes
-            }  # type: dict[str, Decimal]
-        else:
+        if price_type != PriceType.BID and price_type != PriceType.ASK and price_type != PriceType.MID:
             raise ValueError(f"Cannot calculate exchange rate for PriceType."
                              f"{PriceTypeParser.to_str(price_type)}")
 
+        exchange_rate_calculator_clear(self._mem)
+
         cdef str symbol
-        cdef tuple pieces
-        cdef str code_lhs
-        cdef str code_rhs
-        cdef set codes = set()
-        cdef dict exchange_rates = {}
-
-        # Build quote table
-        for symbol, quote in calculation_quotes.items():
-            # Get instrument_id codes
-            pieces = symbol.partition('/')
-            code_lhs = pieces[0]
-            code_rhs = pieces[2]
-            codes.add(code_lhs)
-            codes.add(code_rhs)
-
-            # Add currency dictionaries if they do not already exist
-            if code_lhs not in exchange_rates:
-                exchange_rates[code_lhs] = {}
-            if code_rhs not in exchange_rates:
-                exchange_rates[code_rhs] = {}
-            # Add currency rates
-            exchange_rates[code_lhs][code_lhs] = 1.0
-            exchange_rates[code_rhs][code_rhs] = 1.0
-            exchange_rates[code_lhs][code_rhs] = quote
-
-        # Generate possible currency pairs from all symbols
-        cdef set code_perms = set(permutations(codes, 2))
-
-        # Calculate currency inverses
-        cdef dict exchange_rates_perm0
-        cdef dict exchange_rates_perm1
-        for perm in code_perms:
-            exchange_rates_perm0 = exchange_rates.get(perm[0])
-            exchange_rates_perm1 = exchange_rates.get(perm[1])
-            if exchange_rates_perm0 is None or exchange_rates_perm1 is None:
-                continue
-            if perm[0] not in exchange_rates_perm1:
-                # Search for inverse
-                if perm[1] in exchange_rates_perm0:
-                    exchange_rates_perm1[perm[0]] = 1.0 / exchange_rates_perm0[perm[1]]
-            if perm[1] not in exchange_rates_perm0:
-                # Search for inverse
-                if perm[0] in exchange_rates_perm1:
-                    exchange_rates_perm0[perm[1]] = 1.0 / exchange_rates_perm1[perm[0]]
-
-        cdef dict quotes = exchange_rates.get(from_currency.code)
-        if quotes:
-            xrate = quotes.get(to_currency.code)
-            if xrate is not None:
-                return xrate
-
-        # Exchange rate not yet calculated
-        # Continue to calculate remaining exchange rates
-        cdef dict exchange_rates_code
-        for perm in code_perms:
-            if perm[0] in exchange_rates[perm[1]]:
-                continue
-            # Search for common currency
-            for code in codes:
-                exchange_rates_perm0 = exchange_rates.get(perm[0])
-                exchange_rates_perm1 = exchange_rates.get(perm[1])
-                exchange_rates_code = exchange_rates.get(code)
-                if exchange_rates_perm0 is None or exchange_rates_perm1 is None or exchange_rates_code is None:
-                    continue
-                if code in exchange_rates_perm0 and code in exchange_rates_perm1:
-                    common_rate1 = exchange_rates_perm0[code]
-                    common_rate2 = exchange_rates_perm1[code]
-                    exchange_rates_perm1[perm[0]] = common_rate2 / common_rate1
-                    # Check inverse and calculate if not found
-                    if perm[1] not in exchange_rates_perm0:
-                        exchange_rates_perm0[perm[1]] = common_rate1 / common_rate2
-                elif perm[0] in exchange_rates_code and perm[1] in exchange_rates_code:
-                    common_rate1 = exchange_rates_code[perm[0]]
-                    common_rate2 = exchange_rates_code[perm[1]]
-                    exchange_rates_perm1[perm[0]] = common_rate2 / common_rate1
-                    # Check inverse and calculate if not found
-                    if perm[1] not in exchange_rates[perm[0]]:
-                        exchange_rates_perm0[perm[1]] = common_rate1 / common_rate2
-
-        quotes = exchange_rates.get(from_currency.code)
-        if quotes is None:
-            # Not enough data
-            return 0.0
-
-        return quotes.get(to_currency.code, 0.0)
+        for symbol, bid in bid_quotes.items():
+            exchange_rate_calculator_update(
+                self._mem,
+                <PyObject *>symbol,
+                bid,
+                ask_quotes[symbol],
+            )
+
+        return exchange_rate_calculator_get_rate(
+            self._mem,
+            &from_currency._mem,
+            &to_currency._mem,
+            price_type,
+        )
 
 
 cdef class RolloverInterestCalculator:
@@ -0,0 +1,302 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+"""
+Provides live basis and carry analytics between futures contracts and their
+underlying spot instrument, published as generic data for carry strategies.
+"""
+
+from typing import Dict, List, Optional
+
+import pandas as pd
+
+from nautilus_trader.common.actor import Actor
+from nautilus_trader.config import ActorConfig
+from nautilus_trader.core.data import Data
+from nautilus_trader.model.data.base import DataType
+from nautilus_trader.model.data.tick import QuoteTick
+from nautilus_trader.model.enums import PriceType
+from nautilus_trader.model.identifiers import InstrumentId
+from nautilus_trader.model.instruments.future import Future
+
+
+DAYS_PER_YEAR = 365.0
+NANOS_PER_DAY = 86_400_000_000_000
+
+
+def basis(future_price: float, spot_price: float) -> float:
+    """
+    Return the basis of the future over the spot (future - spot).
+
+    Parameters
+    ----------
+    future_price : float
+        The future price.
+    spot_price : float
+        The spot price.
+
+    Returns
+    -------
+    float
+
+    """
+    return future_price - spot_price
+
+
+def annualized_carry(future_price: float, spot_price: float, days_to_expiry: float) -> float:
+    """
+    Return the annualized carry of the future over the spot, being the simple
+    return of the basis scaled to a 365 day year.
+
+    Parameters
+    ----------
+    future_price : float
+        The future price.
+    spot_price : float
+        The spot price (> 0).
+    days_to_expiry : float
+        The days to the expiry of the future (> 0).
+
+    Returns
+    -------
+    float
+
+    """
+    return (future_price / spot_price - 1.0) * DAYS_PER_YEAR / days_to_expiry
+
+
+def roll_yield(near_price: float, far_price: float, days_between: float) -> float:
+    """
+    Return the annualized roll yield from rolling the near future to the far
+    future, which is positive when the curve is in backwardation.
+
+    Parameters
+    ----------
+    near_price : float
+        The near future price.
+    far_price : float
+        The far future price (> 0).
+    days_between : float
+        The days between the expiries of the futures (> 0).
+
+    Returns
+    -------
+    float
+
+    """
+    return (near_price / far_price - 1.0) * DAYS_PER_YEAR / days_between
+
+
+class BasisData(Data):
+    """
+    Represents the basis and carry of a future over its underlying spot
+    instrument.
+
+    Parameters
+    ----------
+    future_id : InstrumentId
+        The future instrument ID.
+    spot_id : InstrumentId
+        The spot instrument ID.
+    future_price : float
+        The future price.
+    spot_price : float
+        The spot price.
+    basis : float
+        The basis (future - spot).
+    carry : float, optional
+        The annualized carry (``None`` if the future has expired).
+    roll_yield : float, optional
+        The annualized roll yield to the next future (``None`` if there is no
+        next future or it has no price).
+    days_to_expiry : float
+        The days to the expiry of the future.
+    ts_event : int64
+        The UNIX timestamp (nanoseconds) when the data event occurred.
+    ts_init : int64
+        The UNIX timestamp (nanoseconds) when the data object was initialized.
+    """
+
+    def __init__(
+        self,
+        future_id: InstrumentId,
+        spot_id: InstrumentId,
+        future_price: float,
+        spot_price: float,
+        basis: float,
+        carry: Optional[float],
+        roll_yield: Optional[float],
+        days_to_expiry: float,
+        ts_event: int,
+        ts_init: int,
+    ):
+        super().__init__(ts_event, ts_init)
+        self.future_id = future_id
+        self.spot_id = spot_id
+        self.future_price = future_price
+        self.spot_price = spot_price
+        self.basis = basis
+        self.carry = carry
+        self.roll_yield = roll_yield
+        self.days_to_expiry = days_to_expiry
+
+    def __repr__(self) -> str:
+        return (
+            f"{type(self).__name__}("
+            f"future_id={self.future_id}, "
+            f"spot_id={self.spot_id}, "
+            f"basis={self.basis}, "
+            f"carry={self.carry}, "
+            f"roll_yield={self.roll_yield}, "
+            f"days_to_expiry={self.days_to_expiry})"
+        )
+
+
+class BasisAnalyticsConfig(ActorConfig):
+    """
+    Configuration for ``BasisAnalytics`` instances.
+
+    spot_instrument_id : str
+        The spot instrument ID.
+    future_instrument_ids : List[str]
+        The future instrument IDs on the spot instrument.
+    price_type : PriceType, default MID
+        The price type to extract from quote ticks.
+    """
+
+    spot_instrument_id: str
+    future_instrument_ids: List[str]
+    price_type: PriceType = PriceType.MID
+
+
+class BasisAnalytics(Actor):
+    """
+    Provides live basis, annualized carry and roll yield between futures and
+    their underlying spot instrument.
+
+    On each quote tick for the spot or a future, `BasisData` is published for
+    every affected future with the data type ``DataType(BasisData, metadata=
+    {"spot_instrument_id": <spot_instrument_id>})``. The roll yield of a
+    future is to the next future by expiry.
+
+    Parameters
+    ----------
+    config : BasisAnalyticsConfig
+        The configuration for the instance.
+    """
+
+    def __init__(self, config: BasisAnalyticsConfig):
+        super().__init__(config)
+        self.spot_id = InstrumentId.from_str(config.spot_instrument_id)
+        self.future_ids = [InstrumentId.from_str(i) for i in config.future_instrument_ids]
+        self.price_type = config.price_type
+        self.data_type = DataType(
+            BasisData,
+            metadata={"spot_instrument_id": config.spot_instrument_id},
+        )
+
+        self._expiries: Dict[InstrumentId, int] = {}
+        self._prices: Dict[InstrumentId, float] = {}
+
+    def on_start(self):
+        for future_id in self.future_ids:
+            future = self.cache.instrument(future_id)
+            if not isinstance(future, Future):
+                self.log.error(f"Could not find future for {future_id}.")
+                self.stop()
+                return
+            self._expiries[future_id] = pd.Timestamp(future.expiry_date, tz="UTC").value
+
+        # Order the futures by expiry for the roll yields
+        self.future_ids.sort(key=self._expiries.__getitem__)
+
+        self.subscribe_quote_ticks(self.spot_id)
+        for future_id in self.future_ids:
+            self.subscribe_quote_ticks(future_id)
+
+    def on_stop(self):
+        self.unsubscribe_quote_ticks(self.spot_id)
+        for future_id in self.future_ids:
+            self.unsubscribe_quote_ticks(future_id)
+
+    def on_reset(self):
+        self._prices.clear()
+
+    def on_quote_tick(self, tick: QuoteTick):
+        self._prices[tick.instrument_id] = tick.extract_price(self.price_type).as_double()
+
+        if tick.instrument_id == self.spot_id:
+            affected = self.future_ids
+        else:
+            # The roll yield of the prior future is to this future
+            index = self.future_ids.index(tick.instrument_id)
+            affected = self.future_ids[max(index - 1, 0) : index + 1]
+
+        for future_id in affected:
+            data = self.calculate(future_id, tick.ts_event, tick.ts_init)
+            if data is not None:
+                self.publish_data(self.data_type, data)
+
+    def calculate(self, future_id: InstrumentId, ts_event: int, ts_init: int) -> Optional[BasisData]:
+        """
+        Return the basis data for the given future from the latest prices.
+
+        Parameters
+        ----------
+        future_id : InstrumentId
+            The future instrument ID.
+        ts_event : int64
+            The UNIX timestamp (nanoseconds) when the data event occurred.
+        ts_init : int64
+            The UNIX timestamp (nanoseconds) when the data object was initialized.
+
+        Returns
+        -------
+        BasisData or ``None``
+            ``None`` if there is no price for the future or spot.
+
+        """
+        spot_price = self._prices.get(self.spot_id)
+        future_price = self._prices.get(future_id)
+        if not spot_price or future_price is None:
+            return None
+
+        expiry_ns = self._expiries[future_id]
+        days_to_expiry = (expiry_ns - ts_event) / NANOS_PER_DAY
+        carry = None
+        if days_to_expiry > 0:
+            carry = annualized_carry(future_price, spot_price, days_to_expiry)
+
+        next_roll_yield = None
+        index = self.future_ids.index(future_id)
+        if index + 1 < len(self.future_ids):
+            next_id = self.future_ids[index + 1]
+            next_price = self._prices.get(next_id)
+            days_between = (self._expiries[next_id] - expiry_ns) / NANOS_PER_DAY
+            if next_price and days_between > 0:
+                next_roll_yield = roll_yield(future_price, next_price, days_between)
+
+        return BasisData(
+            future_id=future_id,
+            spot_id=self.spot_id,
+            future_price=future_price,
+            spot_price=spot_price,
+            basis=basis(future_price, spot_price),
+            carry=carry,
+            roll_yield=next_roll_yield,
+            days_to_expiry=days_to_expiry,
+            ts_event=ts_event,
+            ts_init=ts_init,
+        )
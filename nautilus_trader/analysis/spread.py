@@ -0,0 +1,296 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+"""
+Provides pair trading analytics between two instruments, tracking a rolling
+hedge ratio, the spread z-score and synthetic spread quotes published as
+generic data for spread strategies.
+"""
+
+from collections import deque
+from typing import Deque, Dict, Optional, Sequence, Tuple
+
+from nautilus_trader.common.actor import Actor
+from nautilus_trader.config import ActorConfig
+from nautilus_trader.core.data import Data
+from nautilus_trader.model.data.base import DataType
+from nautilus_trader.model.data.tick import QuoteTick
+from nautilus_trader.model.enums import PriceType
+from nautilus_trader.model.identifiers import InstrumentId
+
+
+def hedge_ratio(prices_a: Sequence[float], prices_b: Sequence[float]) -> Optional[float]:
+    """
+    Return the hedge ratio of leg A against leg B, being the ordinary least
+    squares slope of the leg A prices regressed on the leg B prices.
+
+    Parameters
+    ----------
+    prices_a : Sequence[float]
+        The leg A prices.
+    prices_b : Sequence[float]
+        The leg B prices, paired with the leg A prices.
+
+    Returns
+    -------
+    float or ``None``
+        ``None`` if there are fewer than two pairs or the leg B prices are
+        constant.
+
+    """
+    count = len(prices_b)
+    if count < 2:
+        return None
+    mean_a = sum(prices_a) / count
+    mean_b = sum(prices_b) / count
+    cov = sum((a - mean_a) * (b - mean_b) for a, b in zip(prices_a, prices_b))
+    var = sum((b - mean_b) ** 2 for b in prices_b)
+    if var == 0:
+        return None
+    return cov / var
+
+
+def z_score(value: float, values: Sequence[float]) -> Optional[float]:
+    """
+    Return the number of (population) standard deviations of the value from
+    the mean of the values.
+
+    Parameters
+    ----------
+    value : float
+        The value.
+    values : Sequence[float]
+        The values (including the value).
+
+    Returns
+    -------
+    float or ``None``
+        ``None`` if the values are empty or constant.
+
+    """
+    count = len(values)
+    if count == 0:
+        return None
+    mean = sum(values) / count
+    std = (sum((v - mean) ** 2 for v in values) / count) ** 0.5
+    if std == 0:
+        return None
+    return (value - mean) / std
+
+
+class SpreadData(Data):
+    """
+    Represents the spread (A - hedge_ratio * B) of an instrument pair.
+
+    Parameters
+    ----------
+    instrument_id_a : InstrumentId
+        The leg A instrument ID.
+    instrument_id_b : InstrumentId
+        The leg B instrument ID.
+    hedge_ratio : float
+        The units of leg B hedging one unit of leg A.
+    spread : float
+        The spread of the leg prices.
+    z_score : float, optional
+        The z-score of the spread over the lookback (``None`` if constant).
+    bid : float
+        The synthetic spread bid (sell leg A, hedge with leg B).
+    ask : float
+        The synthetic spread ask (buy leg A, hedge with leg B).
+    ts_event : int64
+        The UNIX timestamp (nanoseconds) when the data event occurred.
+    ts_init : int64
+        The UNIX timestamp (nanoseconds) when the data object was initialized.
+    """
+
+    def __init__(
+        self,
+        instrument_id_a: InstrumentId,
+        instrument_id_b: InstrumentId,
+        hedge_ratio: float,
+        spread: float,
+        z_score: Optional[float],
+        bid: float,
+        ask: float,
+        ts_event: int,
+        ts_init: int,
+    ):
+        super().__init__(ts_event, ts_init)
+        self.instrument_id_a = instrument_id_a
+        self.instrument_id_b = instrument_id_b
+        self.hedge_ratio = hedge_ratio
+        self.spread = spread
+        self.z_score = z_score
+        self.bid = bid
+        self.ask = ask
+
+    def __repr__(self) -> str:
+        return (
+            f"{type(self).__name__}("
+            f"instrument_id_a={self.instrument_id_a}, "
+            f"instrument_id_b={self.instrument_id_b}, "
+            f"hedge_ratio={self.hedge_ratio}, "
+            f"spread={self.spread}, "
+            f"z_score={self.z_score}, "
+            f"bid={self.bid}, "
+            f"ask={self.ask})"
+        )
+
+
+class SpreadTrackerConfig(ActorConfig):
+    """
+    Configuration for ``SpreadTracker`` instances.
+
+    instrument_id_a : str
+        The leg A instrument ID.
+    instrument_id_b : str
+        The leg B instrument ID.
+    lookback : int, default 100
+        The number of paired prices for the hedge ratio and z-score.
+    price_type : PriceType, default MID
+        The price type to extract from quote ticks for the hedge ratio.
+    """
+
+    instrument_id_a: str
+    instrument_id_b: str
+    lookback: int = 100
+    price_type: PriceType = PriceType.MID
+
+
+class SpreadTracker(Actor):
+    """
+    Provides a rolling hedge ratio, spread z-score and synthetic spread quotes
+    for an instrument pair.
+
+    On each quote tick for either leg (once both have quoted) the paired
+    prices are recorded, and once the hedge ratio is defined `SpreadData` is
+    published with the data type ``DataType(SpreadData, metadata=
+    {"instrument_id_a": <instrument_id_a>, "instrument_id_b":
+    <instrument_id_b>})``.
+
+    Parameters
+    ----------
+    config : SpreadTrackerConfig
+        The configuration for the instance.
+
+    Raises
+    ------
+    ValueError
+        If `config.lookback` is less than 2.
+    """
+
+    def __init__(self, config: SpreadTrackerConfig):
+        if config.lookback < 2:
+            raise ValueError(f"lookback was {config.lookback}, must be >= 2")
+        super().__init__(config)
+        self.instrument_id_a = InstrumentId.from_str(config.instrument_id_a)
+        self.instrument_id_b = InstrumentId.from_str(config.instrument_id_b)
+        self.price_type = config.price_type
+        self.data_type = DataType(
+            SpreadData,
+            metadata={
+                "instrument_id_a": config.instrument_id_a,
+                "instrument_id_b": config.instrument_id_b,
+            },
+        )
+
+        self._quotes: Dict[InstrumentId, QuoteTick] = {}
+        self._pairs: Deque[Tuple[float, float]] = deque(maxlen=config.lookback)
+
+    def on_start(self):
+        self.subscribe_quote_ticks(self.instrument_id_a)
+        self.subscribe_quote_ticks(self.instrument_id_b)
+
+    def on_stop(self):
+        self.unsubscribe_quote_ticks(self.instrument_id_a)
+        self.unsubscribe_quote_ticks(self.instrument_id_b)
+
+    def on_reset(self):
+        self._quotes.clear()
+        self._pairs.clear()
+
+    def on_quote_tick(self, tick: QuoteTick):
+        self._quotes[tick.instrument_id] = tick
+        quote_a = self._quotes.get(self.instrument_id_a)
+        quote_b = self._quotes.get(self.instrument_id_b)
+        if quote_a is None or quote_b is None:
+            return
+
+        self._pairs.append(
+            (
+                quote_a.extract_price(self.price_type).as_double(),
+                quote_b.extract_price(self.price_type).as_double(),
+            ),
+        )
+
+        data = self.calculate(tick.ts_event, tick.ts_init)
+        if data is not None:
+            self.publish_data(self.data_type, data)
+
+    def calculate(self, ts_event: int, ts_init: int) -> Optional[SpreadData]:
+        """
+        Return the spread data from the recorded prices and latest quotes.
+
+        Parameters
+        ----------
+        ts_event : int64
+            The UNIX timestamp (nanoseconds) when the data event occurred.
+        ts_init : int64
+            The UNIX timestamp (nanoseconds) when the data object was initialized.
+
+        Returns
+        -------
+        SpreadData or ``None``
+            ``None`` if either leg has not quoted or the hedge ratio is not
+            yet defined.
+
+        """
+        quote_a = self._quotes.get(self.instrument_id_a)
+        quote_b = self._quotes.get(self.instrument_id_b)
+        if quote_a is None or quote_b is None:
+            return None
+
+        prices_a = [a for a, _ in self._pairs]
+        prices_b = [b for _, b in self._pairs]
+        ratio = hedge_ratio(prices_a, prices_b)
+        if ratio is None:
+            return None
+
+        spreads = [a - ratio * b for a, b in self._pairs]
+
+        # Selling the spread sells leg A and buys the hedge (sells if negative)
+        bid_a = quote_a.bid.as_double()
+        ask_a = quote_a.ask.as_double()
+        bid_b = quote_b.bid.as_double()
+        ask_b = quote_b.ask.as_double()
+        if ratio >= 0:
+            bid = bid_a - ratio * ask_b
+            ask = ask_a - ratio * bid_b
+        else:
+            bid = bid_a - ratio * bid_b
+            ask = ask_a - ratio * ask_b
+
+        return SpreadData(
+            instrument_id_a=self.instrument_id_a,
+            instrument_id_b=self.instrument_id_b,
+            hedge_ratio=ratio,
+            spread=spreads[-1],
+            z_score=z_score(spreads[-1], spreads),
+            bid=bid,
+            ask=ask,
+            ts_event=ts_event,
+            ts_init=ts_init,
+        )
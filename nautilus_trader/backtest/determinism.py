@@ -0,0 +1,354 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+"""
+Provides a determinism validator which runs the same backtest twice and
+compares the hashes of the event streams at checkpoints, to catch
+nondeterminism (e.g. from parallel code paths) by the first divergence.
+"""
+
+import hashlib
+from dataclasses import dataclass
+from typing import Any, List, Optional
+
+from nautilus_trader.backtest.engine import BacktestEngine
+from nautilus_trader.backtest.engine import BacktestEngineConfig
+from nautilus_trader.backtest.node import BacktestNode
+from nautilus_trader.config import BacktestDataConfig
+from nautilus_trader.config import BacktestRunConfig
+from nautilus_trader.config import BacktestVenueConfig
+from nautilus_trader.msgbus.bus import MessageBus
+
+
+# The topics of the events determined by the backtest (system events are
+# excluded as components may be started with the live clock)
+EVENT_TOPICS = ("events.order*", "events.position*", "events.account*")
+
+# Event fields which are random per run, so excluded from the hashes
+NONDETERMINISTIC_FIELDS = ("event_id",)
+
+
+@dataclass(frozen=True)
+class DeterminismCheckpoint:
+    """
+    Represents the hash of an event stream up to and including an event.
+
+    Parameters
+    ----------
+    event_count : int
+        The number of events hashed.
+    ts_event : int
+        The UNIX timestamp (nanoseconds) of the last event hashed.
+    digest : str
+        The hex digest of the events hashed.
+    """
+
+    event_count: int
+    ts_event: int
+    digest: str
+
+
+@dataclass(frozen=True)
+class DeterminismDivergence:
+    """
+    Represents the first checkpoint at which two event streams diverged.
+
+    The divergent event is after the `event_count` of the prior checkpoint
+    (zero if none), up to and including the event of the checkpoint.
+
+    Parameters
+    ----------
+    index : int
+        The index of the checkpoint.
+    expected : DeterminismCheckpoint, optional
+        The checkpoint of the first run (``None`` if its stream ended).
+    actual : DeterminismCheckpoint, optional
+        The checkpoint of the second run (``None`` if its stream ended).
+    """
+
+    index: int
+    expected: Optional[DeterminismCheckpoint]
+    actual: Optional[DeterminismCheckpoint]
+
+
+@dataclass(frozen=True)
+class DeterminismReport:
+    """
+    Represents the result of comparing the event streams of two runs.
+
+    Parameters
+    ----------
+    expected : List[DeterminismCheckpoint]
+        The checkpoints of the first run.
+    actual : List[DeterminismCheckpoint]
+        The checkpoints of the second run.
+    divergence : DeterminismDivergence, optional
+        The first divergence (``None`` if the runs were identical).
+    """
+
+    expected: List[DeterminismCheckpoint]
+    actual: List[DeterminismCheckpoint]
+    divergence: Optional[DeterminismDivergence]
+
+    @property
+    def is_deterministic(self) -> bool:
+        """
+        If the event streams of the runs were identical.
+
+        Returns
+        -------
+        bool
+
+        """
+        return self.divergence is None
+
+
+def event_bytes(event: Any) -> bytes:
+    """
+    Return the canonical bytes of the event for hashing, being its dictionary
+    representation (without any random fields) with sorted keys, otherwise
+    its string representation.
+
+    Parameters
+    ----------
+    event : object
+        The event.
+
+    Returns
+    -------
+    bytes
+
+    """
+    to_dict = getattr(type(event), "to_dict", None)
+    if to_dict is None:
+        return repr(event).encode()
+    values = to_dict(event)
+    return repr(
+        sorted((k, v) for k, v in values.items() if k not in NONDETERMINISTIC_FIELDS),
+    ).encode()
+
+
+def find_divergence(
+    expected: List[DeterminismCheckpoint],
+    actual: List[DeterminismCheckpoint],
+) -> Optional[DeterminismDivergence]:
+    """
+    Return the first divergence between the checkpoints of two runs.
+
+    Parameters
+    ----------
+    expected : List[DeterminismCheckpoint]
+        The checkpoints of the first run.
+    actual : List[DeterminismCheckpoint]
+        The checkpoints of the second run.
+
+    Returns
+    -------
+    DeterminismDivergence or ``None``
+        ``None`` if the checkpoints are identical.
+
+    """
+    for index in range(max(len(expected), len(actual))):
+        a = expected[index] if index < len(expected) else None
+        b = actual[index] if index < len(actual) else None
+        if a != b:
+            return DeterminismDivergence(index=index, expected=a, actual=b)
+    return None
+
+
+class EventStreamHasher:
+    """
+    Provides a running hash of the events published on a message bus, with a
+    checkpoint every `checkpoint_interval` events.
+
+    Parameters
+    ----------
+    checkpoint_interval : int, default 1000
+        The number of events between checkpoints.
+
+    Raises
+    ------
+    ValueError
+        If `checkpoint_interval` is not positive.
+    """
+
+    def __init__(self, checkpoint_interval: int = 1000):
+        if checkpoint_interval <= 0:
+            raise ValueError(f"checkpoint_interval was {checkpoint_interval}, must be > 0")
+        self.checkpoint_interval = checkpoint_interval
+        self.checkpoints: List[DeterminismCheckpoint] = []
+        self.event_count = 0
+
+        self._hash = hashlib.sha256()
+        self._ts_last = 0
+
+    def subscribe(self, msgbus: MessageBus) -> None:
+        """
+        Subscribe to the backtest event topics of the message bus.
+
+        Parameters
+        ----------
+        msgbus : MessageBus
+            The message bus to hash the events of.
+
+        """
+        for topic in EVENT_TOPICS:
+            msgbus.subscribe(topic=topic, handler=self.on_event)
+
+    def on_event(self, event: Any) -> None:
+        """
+        Hash the event, adding a checkpoint if due.
+
+        Parameters
+        ----------
+        event : object
+            The event to hash.
+
+        """
+        self._hash.update(event_bytes(event))
+        self.event_count += 1
+        self._ts_last = getattr(event, "ts_event", self._ts_last)
+        if self.event_count % self.checkpoint_interval == 0:
+            self._checkpoint()
+
+    def finish(self) -> List[DeterminismCheckpoint]:
+        """
+        Add the final checkpoint (if any events since the last) and return
+        the checkpoints.
+
+        Returns
+        -------
+        List[DeterminismCheckpoint]
+
+        """
+        last_count = self.checkpoints[-1].event_count if self.checkpoints else 0
+        if self.event_count > last_count or not self.checkpoints:
+            self._checkpoint()
+        return self.checkpoints
+
+    def _checkpoint(self) -> None:
+        self.checkpoints.append(
+            DeterminismCheckpoint(
+                event_count=self.event_count,
+                ts_event=self._ts_last,
+                digest=self._hash.copy().hexdigest(),
+            ),
+        )
+
+
+class _HashingBacktestNode(BacktestNode):
+    def __init__(self, config: BacktestRunConfig, checkpoint_interval: int):
+        super().__init__([config])
+        self.hasher = EventStreamHasher(checkpoint_interval)
+
+    def _create_engine(
+        self,
+        run_config_id: str,
+        config: BacktestEngineConfig,
+        venue_configs: List[BacktestVenueConfig],
+        data_configs: List[BacktestDataConfig],
+    ) -> BacktestEngine:
+        engine = super()._create_engine(run_config_id, config, venue_configs, data_configs)
+        self.hasher.subscribe(engine.kernel.msgbus)
+        return engine
+
+
+class DeterminismValidator:
+    """
+    Provides validation that a backtest is deterministic, by running it twice
+    and comparing the hashes of the event streams at checkpoints.
+
+    The second run may use an alternate configuration expected to give the
+    same results (e.g. with a different number of worker threads), to
+    validate that the configuration does not change the outcome.
+
+    Parameters
+    ----------
+    config : BacktestRunConfig
+        The backtest run configuration.
+    alternate_config : BacktestRunConfig, optional
+        The configuration for the second run (``None`` to repeat `config`).
+    checkpoint_interval : int, default 1000
+        The number of events between checkpoints.
+    """
+
+    def __init__(
+        self,
+        config: BacktestRunConfig,
+        alternate_config: Optional[BacktestRunConfig] = None,
+        checkpoint_interval: int = 1000,
+    ):
+        self.config = config
+        self.alternate_config = alternate_config or config
+        self.checkpoint_interval = checkpoint_interval
+
+    def run(self) -> DeterminismReport:
+        """
+        Run the backtest twice and compare the event streams.
+
+        Returns
+        -------
+        DeterminismReport
+
+        """
+        checkpoints = []
+        for config in (self.config, self.alternate_config):
+            node = _HashingBacktestNode(config, self.checkpoint_interval)
+            try:
+                node.run()
+            finally:
+                node.dispose()
+            checkpoints.append(node.hasher.finish())
+        return DeterminismReport(
+            expected=checkpoints[0],
+            actual=checkpoints[1],
+            divergence=find_divergence(checkpoints[0], checkpoints[1]),
+        )
+
+    @staticmethod
+    def compare_engines(
+        engine: BacktestEngine,
+        other: BacktestEngine,
+        checkpoint_interval: int = 1000,
+    ) -> DeterminismReport:
+        """
+        Run the two prepared engines (with the same data, venues and
+        strategies) and compare the event streams.
+
+        Parameters
+        ----------
+        engine : BacktestEngine
+            The engine for the first run.
+        other : BacktestEngine
+            The engine for the second run.
+        checkpoint_interval : int, default 1000
+            The number of events between checkpoints.
+
+        Returns
+        -------
+        DeterminismReport
+
+        """
+        checkpoints = []
+        for e in (engine, other):
+            hasher = EventStreamHasher(checkpoint_interval)
+            hasher.subscribe(e.kernel.msgbus)
+            e.run()
+            checkpoints.append(hasher.finish())
+        return DeterminismReport(
+            expected=checkpoints[0],
+            actual=checkpoints[1],
+            divergence=find_divergence(checkpoints[0], checkpoints[1]),
+        )
@@ -21,6 +21,8 @@ from nautilus_trader.common.logging cimport Logger
 from nautilus_trader.common.logging cimport LoggerAdapter
 from nautilus_trader.common.uuid cimport UUIDFactory
 from nautilus_trader.core.data cimport Data
+from nautilus_trader.core.rust.core cimport CancellationToken
+from nautilus_trader.core.rust.core cimport ProgressTracker
 from nautilus_trader.core.uuid cimport UUID4
 from nautilus_trader.system.kernel cimport NautilusKernel
 
@@ -37,6 +39,10 @@ cdef class BacktestEngine:
     cdef list _data
     cdef int64_t _data_len
     cdef int64_t _index
+    cdef CancellationToken *_cancellation_token
+    cdef ProgressTracker *_progress_tracker
+    cdef object _progress_callback
+    cdef object _progress_error
 
     cdef readonly NautilusKernel kernel
     """The internal kernel for the engine.\n\n:returns: `NautilusKernel`"""
@@ -15,7 +15,7 @@
 
 import pickle
 from decimal import Decimal
-from typing import Dict, List, Optional, Union
+from typing import Callable, Dict, List, Optional, Union
 
 import pandas as pd
 
@@ -30,6 +30,7 @@ from nautilus_trader.config import RiskEngineConfig
 
 from cpython.datetime cimport datetime
 from libc.stdint cimport int64_t
+from libc.stdint cimport uint64_t
 
 from nautilus_trader.backtest.data_client cimport BacktestDataClient
 from nautilus_trader.backtest.data_client cimport BacktestMarketDataClient
@@ -37,6 +38,7 @@ from nautilus_trader.backtest.exchange cimport SimulatedExchange
 from nautilus_trader.backtest.execution_client cimport BacktestExecClient
 from nautilus_trader.backtest.models cimport FillModel
 from nautilus_trader.backtest.models cimport LatencyModel
+from nautilus_trader.backtest.models cimport MarketImpactModel
 from nautilus_trader.backtest.modules cimport SimulationModule
 from nautilus_trader.cache.base cimport CacheFacade
 from nautilus_trader.common.actor cimport Actor
@@ -51,6 +53,16 @@ from nautilus_trader.core.correctness cimport Condition
 from nautilus_trader.core.data cimport Data
 from nautilus_trader.core.datetime cimport maybe_dt_to_unix_nanos
 from nautilus_trader.core.datetime cimport unix_nanos_to_dt
+from nautilus_trader.core.rust.core cimport Progress
+from nautilus_trader.core.rust.core cimport cancellation_token_cancel
+from nautilus_trader.core.rust.core cimport cancellation_token_free
+from nautilus_trader.core.rust.core cimport cancellation_token_is_cancelled
+from nautilus_trader.core.rust.core cimport cancellation_token_new
+from nautilus_trader.core.rust.core cimport cancellation_token_reset
+from nautilus_trader.core.rust.core cimport progress_tracker_finish
+from nautilus_trader.core.rust.core cimport progress_tracker_free
+from nautilus_trader.core.rust.core cimport progress_tracker_new
+from nautilus_trader.core.rust.core cimport progress_tracker_update
 from nautilus_trader.model.c_enums.account_type cimport AccountType
 from nautilus_trader.model.c_enums.aggregation_source cimport AggregationSource
 from nautilus_trader.model.c_enums.book_type cimport BookType
@@ -109,6 +121,11 @@ cdef class BacktestEngine:
         self._data_len = 0
         self._index = 0
 
+        # Progress and cancellation
+        self._cancellation_token = cancellation_token_new()
+        self._progress_callback = None
+        self._progress_error = None
+
         # Timing
         self.run_started: Optional[datetime] = None
         self.run_finished: Optional[datetime] = None
@@ -146,6 +163,9 @@ cdef class BacktestEngine:
             logger=self._logger,
         )
 
+    def __del__(self) -> None:
+        cancellation_token_free(self._cancellation_token)  # Freed by Rust
+
     @property
     def trader_id(self) -> TraderId:
         """
@@ -366,6 +386,7 @@ cdef class BacktestEngine:
         list modules=None,
         FillModel fill_model=None,
         LatencyModel latency_model=None,
+        MarketImpactModel impact_model=None,
         BookType book_type=BookType.L1_TBBO,
         routing: bool=False,
         bar_execution: bool = False,
@@ -399,6 +420,8 @@ cdef class BacktestEngine:
             The fill model for the exchange.
         latency_model : LatencyModel, optional
             The latency model for the exchange.
+        impact_model : MarketImpactModel, optional
+            The market impact model for large orders on the exchange.
         book_type : BookType
             The default order book type for fill modelling.
         routing : bool
@@ -439,6 +462,7 @@ cdef class BacktestEngine:
             cache=self.kernel.cache,
             fill_model=fill_model,
             latency_model=latency_model,
+            impact_model=impact_model,
             book_type=book_type,
             clock=self.kernel.clock,
             logger=self.kernel.logger,
@@ -584,6 +608,8 @@ cdef class BacktestEngine:
         start: Union[datetime, str, int]=None,
         end: Union[datetime, str, int]=None,
         run_config_id: str=None,
+        progress_callback: Optional[Callable[[dict], None]]=None,
+        int progress_interval_ms=1000,
     ) -> None:
         """
         Run a backtest.
@@ -601,6 +627,13 @@ cdef class BacktestEngine:
             to the end of the data.
         run_config_id : str, optional
             The tokenized `BacktestRunConfig` ID.
+        progress_callback : Callable[[dict], None], optional
+            The callback for progress reports, receiving a dict with the
+            `processed` and `total` data counts, `percent`, `elapsed_secs`,
+            `events_per_sec` and `eta_secs` (NaN until the rate is known).
+            It's called at most once per interval, and once when the run ends.
+        progress_interval_ms : int, default 1000
+            The minimum milliseconds between progress reports.
 
         Raises
         ------
@@ -610,7 +643,7 @@ cdef class BacktestEngine:
             If the `start` is >= the `end` datetime.
 
         """
-        self._run(start, end, run_config_id)
+        self._run(start, end, run_config_id, progress_callback, progress_interval_ms)
         self._end()
 
     def run_streaming(
@@ -618,6 +651,8 @@ cdef class BacktestEngine:
         start: Union[datetime, str, int]=None,
         end: Union[datetime, str, int]=None,
         run_config_id: str=None,
+        progress_callback: Optional[Callable[[dict], None]]=None,
+        int progress_interval_ms=1000,
     ):
         """
         Run a backtest in streaming mode.
@@ -642,6 +677,10 @@ cdef class BacktestEngine:
             to the end of the data.
         run_config_id : str, optional
             The tokenized backtest run configuration ID.
+        progress_callback : Callable[[dict], None], optional
+            The callback for progress reports of the current batch (see `run`).
+        progress_interval_ms : int, default 1000
+            The minimum milliseconds between progress reports.
 
         Raises
         ------
@@ -651,7 +690,7 @@ cdef class BacktestEngine:
             If the `start` is >= the `end` datetime.
 
         """
-        self._run(start, end, run_config_id)
+        self._run(start, end, run_config_id, progress_callback, progress_interval_ms)
 
     def end_streaming(self):
         """
@@ -665,6 +704,29 @@ cdef class BacktestEngine:
         """
         self._end()
 
+    def cancel(self) -> None:
+        """
+        Cancel the current backtest run.
+
+        The run stops before processing the next data point, then ends as
+        normal. May be called from another thread, or from within a progress
+        callback or component handler.
+
+        """
+        cancellation_token_cancel(self._cancellation_token)
+
+    @property
+    def cancelled(self) -> bool:
+        """
+        If the last backtest run was cancelled.
+
+        Returns
+        -------
+        bool
+
+        """
+        return <bint>cancellation_token_is_cancelled(self._cancellation_token)
+
     def get_result(self):
         """
         Return the backtest result from the last run.
@@ -703,6 +765,8 @@ cdef class BacktestEngine:
         start: Union[datetime, str, int]=None,
         end: Union[datetime, str, int]=None,
         run_config_id: str=None,
+        progress_callback: Optional[Callable[[dict], None]]=None,
+        int progress_interval_ms=1000,
     ):
         cdef int64_t start_ns
         cdef int64_t end_ns
@@ -759,25 +823,59 @@ cdef class BacktestEngine:
                 self._index = i
                 break
 
+        # Set progress reporting up to the last data point in range
+        cancellation_token_reset(self._cancellation_token)
+        cdef uint64_t processed = 0
+        cdef int64_t end_index = self._index
+        if progress_callback is not None:
+            for i in range(self._data_len - 1, self._index - 1, -1):
+                if self._data[i].ts_init <= end_ns:
+                    end_index = i + 1
+                    break
+            self._progress_callback = progress_callback
+            self._progress_error = None
+            self._progress_tracker = progress_tracker_new(
+                end_index - self._index,
+                progress_interval_ms,
+                _on_progress,
+                <void *>self,
+            )
+
         # -- MAIN BACKTEST LOOP -----------------------------------------------#
         cdef Data data = self._next()
-        while data is not None:
-            if data.ts_init > end_ns:
-                break
-            self._advance_time(data.ts_init)
-            if isinstance(data, OrderBookData):
-                self._exchanges[data.instrument_id.venue].process_order_book(data)
-            elif isinstance(data, QuoteTick):
-                self._exchanges[data.instrument_id.venue].process_quote_tick(data)
-            elif isinstance(data, TradeTick):
-                self._exchanges[data.instrument_id.venue].process_trade_tick(data)
-            elif isinstance(data, Bar):
-                self._exchanges[data.type.instrument_id.venue].process_bar(data)
-            self.kernel.data_engine.process(data)
-            for exchange in self._exchanges.values():
-                exchange.process(data.ts_init)
-            self.iteration += 1
-            data = self._next()
+        try:
+            while data is not None:
+                if data.ts_init > end_ns:
+                    break
+                if cancellation_token_is_cancelled(self._cancellation_token):
+                    self._log.warning("Backtest run cancelled.")
+                    break
+                self._advance_time(data.ts_init)
+                if isinstance(data, OrderBookData):
+                    self._exchanges[data.instrument_id.venue].process_order_book(data)
+                elif isinstance(data, QuoteTick):
+                    self._exchanges[data.instrument_id.venue].process_quote_tick(data)
+                elif isinstance(data, TradeTick):
+                    self._exchanges[data.instrument_id.venue].process_trade_tick(data)
+                elif isinstance(data, Bar):
+                    self._exchanges[data.type.instrument_id.venue].process_bar(data)
+                self.kernel.data_engine.process(data)
+                for exchange in self._exchanges.values():
+                    exchange.process(data.ts_init)
+                self.iteration += 1
+                processed += 1
+                if self._progress_tracker != NULL:
+                    progress_tracker_update(self._progress_tracker, processed)
+                data = self._next()
+            if self._progress_tracker != NULL:
+                progress_tracker_finish(self._progress_tracker, processed)
+        finally:
+            progress_tracker_free(self._progress_tracker)
+            self._progress_tracker = NULL
+            self._progress_callback = None
+        if self._progress_error is not None:
+            error, self._progress_error = self._progress_error, None
+            raise error
         # ---------------------------------------------------------------------#
         # Process remaining messages
         for exchange in self._exchanges.values():
@@ -962,3 +1060,13 @@ cdef class BacktestEngine:
                 logger=self.kernel.logger,
             )
             self.kernel.data_engine.register_client(client)
+
+
+cdef void _on_progress(Progress progress, void *user_data) with gil:
+    # Errors cancel the run, to be raised by the engine once the loop exits
+    cdef BacktestEngine engine = <BacktestEngine>user_data
+    try:
+        engine._progress_callback(progress)
+    except Exception as e:
+        engine._progress_error = e
+        cancellation_token_cancel(engine._cancellation_token)
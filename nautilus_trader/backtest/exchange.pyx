@@ -26,6 +26,7 @@ from nautilus_trader.accounting.accounts.base cimport Account
 from nautilus_trader.backtest.execution_client cimport BacktestExecClient
 from nautilus_trader.backtest.models cimport FillModel
 from nautilus_trader.backtest.models cimport LatencyModel
+from nautilus_trader.backtest.models cimport MarketImpactModel
 from nautilus_trader.backtest.modules cimport SimulationModule
 from nautilus_trader.cache.base cimport CacheFacade
 from nautilus_trader.common.clock cimport TestClock
@@ -104,6 +105,8 @@ cdef class SimulatedExchange:
         The fill model for the exchange.
     latency_model : LatencyModel, optional
         The latency model for the exchange.
+    impact_model : MarketImpactModel, optional
+        The market impact model for aggressive fills on the exchange.
     clock : TestClock
         The clock for the exchange.
     logger : Logger
@@ -148,6 +151,7 @@ cdef class SimulatedExchange:
         Logger logger not None,
         FillModel fill_model not None,
         LatencyModel latency_model=None,
+        MarketImpactModel impact_model=None,
         BookType book_type=BookType.L1_TBBO,
         bint bar_execution=False,
         bint reject_stop_orders=True,
@@ -187,6 +191,7 @@ cdef class SimulatedExchange:
         self.reject_stop_orders = reject_stop_orders
         self.fill_model = fill_model
         self.latency_model = latency_model
+        self.impact_model = impact_model
         self._bar_execution = bar_execution
 
         # Load modules
@@ -457,6 +462,22 @@ cdef class SimulatedExchange:
 
         self._log.info("Changed latency model.")
 
+    cpdef void set_impact_model(self, MarketImpactModel impact_model) except *:
+        """
+        Change the market impact model for this exchange.
+
+        Parameters
+        ----------
+        impact_model : MarketImpactModel
+            The market impact model to set.
+
+        """
+        Condition.not_none(impact_model, "impact_model")
+
+        self.impact_model = impact_model
+
+        self._log.info("Changed market impact model.")
+
     cpdef void initialize_account(self) except *:
         """
         Initialize the account to the starting balances.
@@ -875,6 +896,9 @@ cdef class SimulatedExchange:
         for module in self.modules:
             module.reset()
 
+        if self.impact_model is not None:
+            self.impact_model.reset()
+
         self._generate_fresh_account_state()
 
         self._books.clear()
@@ -1485,6 +1509,15 @@ cdef class SimulatedExchange:
 
         cdef Instrument instrument = self.instruments[order.instrument_id]
 
+        # Market impact applies to the fill prices of aggressive orders
+        cdef double impact_multiplier = 1.0
+        if self.impact_model is not None and liquidity_side == LiquiditySide.TAKER:
+            impact_multiplier = self.impact_model.price_multiplier(
+                order.instrument_id,
+                order.side,
+                order.quantity.as_f64_c(),
+            )
+
         cdef:
             uint64_t raw_org_qty
             uint64_t raw_adj_qty
@@ -1513,6 +1546,8 @@ cdef class SimulatedExchange:
                     fill_px = fill_px.sub(instrument.price_increment)
                 else:  # pragma: no cover (design-time error)
                     raise ValueError(f"invalid OrderSide, was {order.side}")
+            if impact_multiplier != 1.0:
+                fill_px = instrument.make_price(fill_px.as_f64_c() * impact_multiplier)
             if order.is_reduce_only and fill_qty._mem.raw > position.quantity._mem.raw:
                 # Adjust fill to honor reduce only execution
                 raw_org_qty = fill_qty._mem.raw
@@ -1556,6 +1591,8 @@ cdef class SimulatedExchange:
                 fill_px = fill_px.sub(instrument.price_increment)
             else:  # pragma: no cover (design-time error)
                 raise ValueError(f"invalid OrderSide, was {order.side}")
+            if impact_multiplier != 1.0:
+                fill_px = instrument.make_price(fill_px.as_f64_c() * impact_multiplier)
 
             self._fill_order(
                 instrument=instrument,
@@ -0,0 +1,35 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from libc.stdint cimport uint64_t
+
+
+cdef class FastBacktestResult:
+    cdef readonly object positions
+    """The position at each bar.\n\n:returns: `numpy.ndarray`"""
+    cdef readonly object pnl
+    """The cumulative PnL at each bar, net of commissions.\n\n:returns: `numpy.ndarray`"""
+    cdef readonly double total_pnl
+    """The PnL at the last bar, net of commissions.\n\n:returns: `double`"""
+    cdef readonly double total_commission
+    """The total commissions.\n\n:returns: `double`"""
+    cdef readonly uint64_t fills
+    """The number of bars with a fill.\n\n:returns: `uint64_t`"""
+    cdef readonly double turnover
+    """The total notional filled.\n\n:returns: `double`"""
+    cdef readonly double max_drawdown
+    """The largest fall of the PnL from its running peak.\n\n:returns: `double`"""
+    cdef readonly double sharpe_ratio
+    """The annualized Sharpe ratio of the per-bar PnL changes (NaN if undefined).\n\n:returns: `double`"""
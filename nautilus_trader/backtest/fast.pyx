@@ -0,0 +1,159 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+"""
+Provides a fast backtest mode driven by target position arrays.
+
+The user supplies a target position for each bar, and the fills, costs and
+statistics are computed by the Rust core over the arrays in one call, without
+any per-event callbacks. This allows rapid research iteration on signals
+before validating them with a full event-driven backtest.
+"""
+
+from typing import Optional, Sequence
+
+import numpy as np
+import pyarrow as pa
+
+from nautilus_trader.backtest.vectorized import fill_inputs
+
+from libc.stdint cimport uint8_t
+
+from nautilus_trader.core.rust.model cimport FastBacktestStats
+from nautilus_trader.core.rust.model cimport FillCosts
+from nautilus_trader.core.rust.model cimport fast_backtest_run
+
+
+cdef class FastBacktestResult:
+    """
+    Represents the result of a fast backtest.
+    """
+
+    def __init__(self, positions not None, pnl not None, dict stats not None):
+        self.positions = positions
+        self.pnl = pnl
+        self.total_pnl = stats["total_pnl"]
+        self.total_commission = stats["total_commission"]
+        self.fills = stats["fills"]
+        self.turnover = stats["turnover"]
+        self.max_drawdown = stats["max_drawdown"]
+        self.sharpe_ratio = stats["sharpe_ratio"]
+
+    def __repr__(self) -> str:
+        return (
+            f"{type(self).__name__}("
+            f"total_pnl={self.total_pnl}, "
+            f"total_commission={self.total_commission}, "
+            f"fills={self.fills}, "
+            f"turnover={self.turnover}, "
+            f"max_drawdown={self.max_drawdown}, "
+            f"sharpe_ratio={self.sharpe_ratio})"
+        )
+
+
+cdef double[::1] _float_column(batch, str name):
+    return np.ascontiguousarray(
+        batch.column(name).to_numpy(zero_copy_only=False),
+        dtype=np.float64,
+    )
+
+
+cdef inline double *_data(double[::1] values):
+    return &values[0] if values.shape[0] > 0 else NULL
+
+
+def run_fast_backtest(
+    bars: pa.Table,
+    targets: Sequence[Optional[float]],
+    double commission_rate=0.0,
+    double slippage=0.0,
+    double periods_per_year=252.0,
+    str price_column not None="open",
+    str mark_column not None="close",
+    int lag=1,
+) -> FastBacktestResult:
+    """
+    Run a fast backtest of the target positions over the bars.
+
+    Starting flat, the target on each bar fills at the price of the bar `lag`
+    bars later (adjusted for slippage against the fill side), and the position
+    is valued at the mark of each bar.
+
+    Parameters
+    ----------
+    bars : pa.Table
+        The bars (see `bar_columns`).
+    targets : Sequence[float, optional]
+        The target position for each bar (``None`` or NaN to hold).
+    commission_rate : double, default 0
+        The commission as a fraction of the fill notional.
+    slippage : double, default 0
+        The slippage as a fraction of the fill price.
+    periods_per_year : double, default 252
+        The bars per year for annualizing the Sharpe ratio.
+    price_column : str, default "open"
+        The bar column to fill at.
+    mark_column : str, default "close"
+        The bar column to value the position at.
+    lag : int, default 1
+        The bars between a signal and its fill (>= 0).
+
+    Returns
+    -------
+    FastBacktestResult
+
+    Raises
+    ------
+    ValueError
+        If `targets` is not the same length as `bars`.
+    ValueError
+        If `lag` is negative.
+    ValueError
+        If `commission_rate`, `slippage` or `periods_per_year` is negative
+        or not finite.
+
+    """
+    batch = fill_inputs(bars, targets, price_column, mark_column, lag)
+
+    cdef double[::1] price = _float_column(batch, "price")
+    cdef double[::1] mark = _float_column(batch, "mark")
+    cdef double[::1] target = _float_column(batch, "target")
+    positions = np.empty(batch.num_rows, dtype=np.float64)
+    pnl = np.empty(batch.num_rows, dtype=np.float64)
+    cdef double[::1] positions_view = positions
+    cdef double[::1] pnl_view = pnl
+
+    cdef FillCosts costs
+    costs.commission_rate = commission_rate
+    costs.slippage = slippage
+    cdef FastBacktestStats stats
+    cdef uint8_t code = fast_backtest_run(
+        _data(price),
+        _data(mark),
+        _data(target),
+        batch.num_rows,
+        costs,
+        periods_per_year,
+        _data(positions_view),
+        _data(pnl_view),
+        &stats,
+    )
+    if code != 0:
+        raise ValueError(
+            f"invalid argument, `commission_rate` was {commission_rate}, "
+            f"`slippage` was {slippage}, `periods_per_year` was {periods_per_year}",
+        )
+
+    return FastBacktestResult(positions, pnl, stats)
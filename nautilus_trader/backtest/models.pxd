@@ -13,6 +13,9 @@
 #  limitations under the License.
 # -------------------------------------------------------------------------------------------------
 
+from nautilus_trader.model.c_enums.order_side cimport OrderSide
+from nautilus_trader.model.identifiers cimport InstrumentId
+
 
 cdef class FillModel:
     cdef readonly double prob_fill_on_limit
@@ -38,3 +41,31 @@ cdef class LatencyModel:
     """The latency (nanoseconds) for order update messages to reach the exchange.\n\n:returns: `int`"""
     cdef readonly int cancel_latency_nanos
     """The latency (nanoseconds) for order cancel messages to reach the exchange.\n\n:returns: `int`"""
+
+
+cdef class MarketImpactModel:
+    cdef readonly double temporary_coef
+    """The temporary impact coefficient.\n\n:returns: `double`"""
+    cdef readonly double permanent_coef
+    """The permanent impact coefficient.\n\n:returns: `double`"""
+    cdef readonly double participation_threshold
+    """The participation above which impact applies.\n\n:returns: `double`"""
+
+    cdef dict _reference_volumes
+    cdef dict _permanent_impacts
+
+    cpdef void set_reference_volume(self, InstrumentId instrument_id, double volume) except *
+    cpdef double participation(self, InstrumentId instrument_id, double quantity) except *
+    cpdef double temporary_impact(self, double participation) except *
+    cpdef double permanent_impact(self, double participation) except *
+    cpdef double permanent_offset(self, InstrumentId instrument_id) except *
+    cpdef double price_multiplier(self, InstrumentId instrument_id, OrderSide side, double quantity) except *
+    cpdef void reset(self) except *
+
+
+cdef class LinearImpactModel(MarketImpactModel):
+    pass
+
+
+cdef class SquareRootImpactModel(MarketImpactModel):
+    pass
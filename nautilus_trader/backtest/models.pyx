@@ -15,9 +15,12 @@
 
 import random
 
+from libc.math cimport sqrt
 from libc.stdint cimport int64_t
 
 from nautilus_trader.core.correctness cimport Condition
+from nautilus_trader.model.c_enums.order_side cimport OrderSide
+from nautilus_trader.model.identifiers cimport InstrumentId
 
 
 cdef int64_t NANOSECONDS_IN_MILLISECOND = 1_000_000
@@ -154,3 +157,271 @@ cdef class LatencyModel:
         self.insert_latency_nanos = base_latency_nanos + insert_latency_nanos
         self.update_latency_nanos = base_latency_nanos + update_latency_nanos
         self.cancel_latency_nanos = base_latency_nanos + cancel_latency_nanos
+
+
+cdef class MarketImpactModel:
+    """
+    The abstract base class for market impact models of simulated fills.
+
+    Orders whose quantity exceeds the participation threshold of the reference
+    (e.g. average daily) volume for the instrument incur temporary impact on
+    their own fill prices, and permanent impact which shifts the fill prices
+    of all subsequent orders for the instrument. Impacts are fractions of the
+    fill price, as functions of participation (order quantity over reference
+    volume).
+
+    Parameters
+    ----------
+    temporary_coef : double
+        The temporary impact coefficient.
+    permanent_coef : double
+        The permanent impact coefficient.
+    participation_threshold : double, default 0.01
+        The participation above which impact applies.
+    reference_volumes : dict[InstrumentId, double], optional
+        The reference volume for each instrument (no impact applies without one).
+
+    Raises
+    ------
+    ValueError
+        If `temporary_coef` is negative (< 0).
+    ValueError
+        If `permanent_coef` is negative (< 0).
+    ValueError
+        If `participation_threshold` is not within range [0, 1].
+
+    Warnings
+    --------
+    This class should not be used directly, but through a concrete subclass.
+    """
+
+    def __init__(
+        self,
+        double temporary_coef,
+        double permanent_coef,
+        double participation_threshold=0.01,
+        dict reference_volumes=None,
+    ):
+        Condition.not_negative(temporary_coef, "temporary_coef")
+        Condition.not_negative(permanent_coef, "permanent_coef")
+        Condition.in_range(participation_threshold, 0.0, 1.0, "participation_threshold")
+
+        self.temporary_coef = temporary_coef
+        self.permanent_coef = permanent_coef
+        self.participation_threshold = participation_threshold
+        self._reference_volumes = {}
+        self._permanent_impacts = {}
+
+        cdef InstrumentId instrument_id
+        if reference_volumes is not None:
+            for instrument_id, volume in reference_volumes.items():
+                self.set_reference_volume(instrument_id, volume)
+
+    cpdef void set_reference_volume(self, InstrumentId instrument_id, double volume) except *:
+        """
+        Set the reference volume for the given instrument ID.
+
+        Parameters
+        ----------
+        instrument_id : InstrumentId
+            The instrument ID for the volume.
+        volume : double
+            The reference volume.
+
+        Raises
+        ------
+        ValueError
+            If `volume` is not positive (> 0).
+
+        """
+        Condition.not_none(instrument_id, "instrument_id")
+        Condition.positive(volume, "volume")
+
+        self._reference_volumes[instrument_id] = volume
+
+    cpdef double participation(self, InstrumentId instrument_id, double quantity) except *:
+        """
+        Return the participation of the quantity in the reference volume for
+        the given instrument ID.
+
+        Parameters
+        ----------
+        instrument_id : InstrumentId
+            The instrument ID for the participation.
+        quantity : double
+            The order quantity.
+
+        Returns
+        -------
+        double
+            Zero if no reference volume is set.
+
+        """
+        cdef double volume = self._reference_volumes.get(instrument_id, 0.0)
+        if volume == 0.0:
+            return 0.0
+        return quantity / volume
+
+    cpdef double temporary_impact(self, double participation) except *:
+        """Abstract method (implement in subclass)."""
+        raise NotImplementedError("method must be implemented in the subclass")  # pragma: no cover
+
+    cpdef double permanent_impact(self, double participation) except *:
+        """Abstract method (implement in subclass)."""
+        raise NotImplementedError("method must be implemented in the subclass")  # pragma: no cover
+
+    cpdef double permanent_offset(self, InstrumentId instrument_id) except *:
+        """
+        Return the accumulated permanent impact for the given instrument ID, as
+        a signed fraction of price (positive after net buying).
+
+        Parameters
+        ----------
+        instrument_id : InstrumentId
+            The instrument ID for the offset.
+
+        Returns
+        -------
+        double
+
+        """
+        return self._permanent_impacts.get(instrument_id, 0.0)
+
+    cpdef double price_multiplier(
+        self,
+        InstrumentId instrument_id,
+        OrderSide side,
+        double quantity,
+    ) except *:
+        """
+        Return the multiplier to apply to the fill prices of an order, and
+        accumulate its permanent impact for subsequent orders.
+
+        Parameters
+        ----------
+        instrument_id : InstrumentId
+            The instrument ID for the order.
+        side : OrderSide {``BUY``, ``SELL``}
+            The order side.
+        quantity : double
+            The order quantity.
+
+        Returns
+        -------
+        double
+
+        """
+        cdef double offset = self.permanent_offset(instrument_id)
+        cdef double participation = self.participation(instrument_id, quantity)
+        if participation <= self.participation_threshold:
+            return 1.0 + offset
+
+        cdef double sign = 1.0 if side == OrderSide.BUY else -1.0
+        self._permanent_impacts[instrument_id] = offset + sign * self.permanent_impact(participation)
+
+        return 1.0 + offset + sign * self.temporary_impact(participation)
+
+    cpdef void reset(self) except *:
+        """
+        Reset the accumulated permanent impacts.
+        """
+        self._permanent_impacts.clear()
+
+
+cdef class LinearImpactModel(MarketImpactModel):
+    """
+    Provides a market impact model with impacts linear in participation.
+
+    Parameters
+    ----------
+    temporary_coef : double
+        The temporary impact coefficient.
+    permanent_coef : double
+        The permanent impact coefficient.
+    participation_threshold : double, default 0.01
+        The participation above which impact applies.
+    reference_volumes : dict[InstrumentId, double], optional
+        The reference volume for each instrument (no impact applies without one).
+    """
+
+    cpdef double temporary_impact(self, double participation) except *:
+        """
+        Return the temporary impact for the participation, as a fraction of price.
+
+        Parameters
+        ----------
+        participation : double
+            The order participation in the reference volume.
+
+        Returns
+        -------
+        double
+
+        """
+        return self.temporary_coef * participation
+
+    cpdef double permanent_impact(self, double participation) except *:
+        """
+        Return the permanent impact for the participation, as a fraction of price.
+
+        Parameters
+        ----------
+        participation : double
+            The order participation in the reference volume.
+
+        Returns
+        -------
+        double
+
+        """
+        return self.permanent_coef * participation
+
+
+cdef class SquareRootImpactModel(MarketImpactModel):
+    """
+    Provides a market impact model with temporary impact in the square root of
+    participation, and permanent impact linear in participation.
+
+    Parameters
+    ----------
+    temporary_coef : double
+        The temporary impact coefficient (typically scaled by volatility).
+    permanent_coef : double
+        The permanent impact coefficient.
+    participation_threshold : double, default 0.01
+        The participation above which impact applies.
+    reference_volumes : dict[InstrumentId, double], optional
+        The reference volume for each instrument (no impact applies without one).
+    """
+
+    cpdef double temporary_impact(self, double participation) except *:
+        """
+        Return the temporary impact for the participation, as a fraction of price.
+
+        Parameters
+        ----------
+        participation : double
+            The order participation in the reference volume.
+
+        Returns
+        -------
+        double
+
+        """
+        return self.temporary_coef * sqrt(participation)
+
+    cpdef double permanent_impact(self, double participation) except *:
+        """
+        Return the permanent impact for the participation, as a fraction of price.
+
+        Parameters
+        ----------
+        participation : double
+            The order participation in the reference volume.
+
+        Returns
+        -------
+        double
+
+        """
+        return self.permanent_coef * participation
@@ -13,12 +13,16 @@
 #  limitations under the License.
 # -------------------------------------------------------------------------------------------------
 
+from cpython.datetime cimport date
 from cpython.datetime cimport datetime
 from libc.stdint cimport int64_t
 
 from nautilus_trader.accounting.calculators cimport RolloverInterestCalculator
 from nautilus_trader.backtest.exchange cimport SimulatedExchange
 from nautilus_trader.common.logging cimport LoggerAdapter
+from nautilus_trader.model.data.cash_flow cimport CashFlow
+from nautilus_trader.model.instruments.base cimport Instrument
+from nautilus_trader.model.objects cimport Price
 
 
 cdef class SimulationModule:
@@ -39,3 +43,37 @@ cdef class FXRolloverInterestModule(SimulationModule):
     cdef int _day_number
 
     cdef void _apply_rollover_interest(self, datetime timestamp, int iso_week_day) except *
+
+
+cdef class FuturesSettlementModule(SimulationModule):
+    cdef object _timezone
+    cdef object _settlement_time
+    cdef frozenset _session_days
+    cdef bint _reset_sessions
+    cdef date _session_date
+    cdef datetime _settlement_datetime
+    cdef bint _settled
+    cdef dict _settlement_totals
+    cdef int _settlement_count
+
+    cdef Price _settlement_price(self, Instrument instrument)
+    cdef void _settle(self, int64_t now_ns) except *
+
+
+cdef class CashFlowModule(SimulationModule):
+    cdef list _cash_flows
+    cdef int _index
+    cdef dict _totals
+
+    cdef void _apply_cash_flow(self, CashFlow cash_flow) except *
+
+
+cdef class CashInterestModule(SimulationModule):
+    cdef dict _rates
+    cdef dict _day_counts
+    cdef object _accrual_time
+    cdef object _timezone
+    cdef date _accrual_date
+    cdef dict _interest_totals
+
+    cdef void _accrue_interest(self, int days) except *
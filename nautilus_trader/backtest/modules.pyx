@@ -13,20 +13,29 @@
 #  limitations under the License.
 # -------------------------------------------------------------------------------------------------
 
+from cpython.datetime cimport date
 from cpython.datetime cimport datetime
 from libc.stdint cimport int64_t
 
+import datetime as dt
 from decimal import Decimal
 
 import pandas as pd
 import pytz
 
+from nautilus_trader.accounting.accounts.base cimport Account
 from nautilus_trader.accounting.calculators cimport RolloverInterestCalculator
 from nautilus_trader.backtest.exchange cimport SimulatedExchange
 from nautilus_trader.core.correctness cimport Condition
+from nautilus_trader.core.rust.model cimport SessionStats
+from nautilus_trader.core.rust.model cimport session_stats_get
+from nautilus_trader.data.session cimport start_session
 from nautilus_trader.model.c_enums.asset_class cimport AssetClass
+from nautilus_trader.model.c_enums.asset_type cimport AssetType
+from nautilus_trader.model.c_enums.cash_flow_type cimport CashFlowTypeParser
 from nautilus_trader.model.c_enums.price_type cimport PriceType
 from nautilus_trader.model.currency cimport Currency
+from nautilus_trader.model.data.cash_flow cimport CashFlow
 from nautilus_trader.model.identifiers cimport InstrumentId
 from nautilus_trader.model.instruments.base cimport Instrument
 from nautilus_trader.model.objects cimport Money
@@ -35,6 +44,12 @@ from nautilus_trader.model.orderbook.book cimport OrderBook
 from nautilus_trader.model.position cimport Position
 
 
+cdef dict _DAY_COUNT_DENOMINATORS = {
+    "ACT/360": 360,
+    "ACT/365": 365,
+}
+
+
 cdef class SimulationModule:
     """
     The abstract base class for all simulation modules.
@@ -206,3 +221,382 @@ cdef class FXRolloverInterestModule(SimulationModule):
         self._rollover_applied = False
         self._rollover_totals = {}
         self._day_number = 0
+
+
+cdef class FuturesSettlementModule(SimulationModule):
+    """
+    Provides a daily settlement simulation module for futures.
+
+    At the end of each session (the settlement time on a session day of the
+    venue calendar) every open futures position is marked to its settlement
+    price. The variation margin since the last settlement is realized in the
+    position and credited to (or debited from) the account, and the session
+    statistics of the venue instruments are reset for the next session.
+
+    The settlement price is the last trade of the session, otherwise the mid
+    (or best bid or ask) of the order book.
+
+    Parameters
+    ----------
+    settlement_time : datetime.time, default 17:00
+        The local time of day the sessions settle.
+    timezone : str, default "US/Eastern"
+        The timezone of the venue session calendar.
+    session_days : tuple[int], default (1, 2, 3, 4, 5)
+        The ISO weekdays of the sessions (Monday is 1).
+    reset_sessions : bool, default True
+        If the session statistics of the venue instruments are reset on
+        settlement.
+
+    Raises
+    ------
+    ValueError
+        If `session_days` contains a value not in [1, 7].
+    """
+
+    def __init__(
+        self,
+        settlement_time not None: dt.time=dt.time(17),
+        str timezone not None="US/Eastern",
+        tuple session_days not None=(1, 2, 3, 4, 5),
+        bint reset_sessions=True,
+    ):
+        for day in session_days:
+            Condition.in_range_int(day, 1, 7, "day")
+        super().__init__()
+
+        self._timezone = pytz.timezone(timezone)
+        self._settlement_time = settlement_time
+        self._session_days = frozenset(session_days)
+        self._reset_sessions = reset_sessions
+        self._session_date = None  # Initialized on first process
+        self._settlement_datetime = None  # Initialized on first process
+        self._settled = False
+        self._settlement_totals = {}
+        self._settlement_count = 0
+
+    cpdef void process(self, int64_t now_ns) except *:
+        """
+        Process the given tick through the module.
+
+        Parameters
+        ----------
+        now_ns : int64
+            The current time in the simulated exchange.
+
+        """
+        cdef datetime now = pd.Timestamp(now_ns, tz="UTC")
+        cdef datetime local = now.astimezone(self._timezone)
+        if self._session_date != local.date():
+            self._session_date = local.date()
+            self._settled = False
+            self._settlement_datetime = self._timezone.localize(
+                dt.datetime.combine(self._session_date, self._settlement_time),
+            ).astimezone(pytz.utc)
+
+        # Check for and apply any settlement
+        if not self._settled and now >= self._settlement_datetime:
+            if self._session_date.isoweekday() in self._session_days:
+                self._settle(now_ns)
+            self._settled = True
+
+    cdef Price _settlement_price(self, Instrument instrument):
+        cdef SessionStats stats = session_stats_get(&instrument.id._mem)
+        if stats.trade_count > 0:
+            return Price.from_raw_c(stats.last.raw, stats.last.precision)
+
+        cdef OrderBook book = self._exchange.get_book(instrument.id)
+        px = book.midpoint()
+        if px is None:
+            px = book.best_bid_price()
+        if px is None:
+            px = book.best_ask_price()
+        if px is None:
+            return None
+        return Price(float(px), precision=instrument.price_precision)
+
+    cdef void _settle(self, int64_t now_ns) except *:
+        cdef list open_positions = self._exchange.cache.positions_open(venue=self._exchange.id)
+
+        cdef dict settlement_prices = {}  # type: dict[InstrumentId, Price]
+        cdef Position position
+        cdef Instrument instrument
+        cdef Price settlement_price
+        cdef Money variation_margin
+        cdef Currency currency
+        cdef double amount
+        cdef double xrate
+        for position in open_positions:
+            instrument = self._exchange.instruments[position.instrument_id]
+            if instrument.asset_type != AssetType.FUTURE:
+                continue  # Only applicable to futures
+
+            if instrument.id not in settlement_prices:
+                settlement_prices[instrument.id] = self._settlement_price(instrument)
+            settlement_price = settlement_prices[instrument.id]
+            if settlement_price is None:
+                continue  # No market to settle at
+
+            variation_margin = position.settle(settlement_price)
+            amount = variation_margin.as_f64_c()
+
+            if self._exchange.base_currency is not None:
+                currency = self._exchange.base_currency
+                xrate = self._exchange.cache.get_xrate(
+                    venue=instrument.id.venue,
+                    from_currency=variation_margin.currency,
+                    to_currency=currency,
+                    price_type=PriceType.MID,
+                )
+                amount *= xrate
+            else:
+                currency = variation_margin.currency
+
+            self._settlement_totals[currency] = Money(
+                self._settlement_totals.get(currency, 0.0) + amount,
+                currency,
+            )
+            self._exchange.adjust_account(Money(amount, currency))
+
+        self._settlement_count += 1
+
+        if self._reset_sessions:
+            for instrument_id in self._exchange.instruments:
+                start_session(instrument_id, now_ns)
+
+    cpdef void log_diagnostics(self, LoggerAdapter log) except *:
+        """
+        Log diagnostics out to the `BacktestEngine` logger.
+
+        Parameters
+        ----------
+        log : LoggerAdapter
+            The logger to log to.
+
+        """
+        settlement_totals = ', '.join([b.to_str() for b in self._settlement_totals.values()])
+        log.info(f"Settlements: {self._settlement_count}")
+        log.info(f"Variation margin (totals): {settlement_totals}")
+
+    cpdef void reset(self) except *:
+        self._session_date = None  # Initialized on first process
+        self._settlement_datetime = None  # Initialized on first process
+        self._settled = False
+        self._settlement_totals = {}
+        self._settlement_count = 0
+
+
+cdef class CashFlowModule(SimulationModule):
+    """
+    Provides a scheduled cash flow simulation module, paying cash dividends
+    and bond coupons to the account for positions held at the ex-date.
+
+    Each cash flow is applied when the exchange reaches its `ts_event`, to
+    the open positions of the instrument at the venue: the amount per unit
+    times the signed position quantity and multiplier (so short positions
+    pay the cash flow).
+
+    Parameters
+    ----------
+    cash_flows : list[CashFlow]
+        The cash flows for the module (e.g. from `DataCatalog.cash_flows`).
+    """
+
+    def __init__(self, list cash_flows not None):
+        Condition.list_type(cash_flows, CashFlow, "cash_flows")
+        super().__init__()
+
+        self._cash_flows = sorted(cash_flows, key=lambda c: c.ts_event)
+        self._index = 0
+        self._totals = {}  # type: dict[CashFlowType, dict[Currency, Money]]
+
+    cpdef void process(self, int64_t now_ns) except *:
+        """
+        Process the given tick through the module.
+
+        Parameters
+        ----------
+        now_ns : int64
+            The current time in the simulated exchange.
+
+        """
+        cdef CashFlow cash_flow
+        while self._index < len(self._cash_flows):
+            cash_flow = self._cash_flows[self._index]
+            if cash_flow.ts_event > now_ns:
+                break
+            self._apply_cash_flow(cash_flow)
+            self._index += 1
+
+    cdef void _apply_cash_flow(self, CashFlow cash_flow) except *:
+        cdef Instrument instrument = self._exchange.instruments.get(cash_flow.instrument_id)
+        if instrument is None:
+            return  # Not traded at the venue
+
+        cdef list open_positions = self._exchange.cache.positions_open(
+            venue=self._exchange.id,
+            instrument_id=cash_flow.instrument_id,
+        )
+
+        cdef Currency currency = cash_flow.amount.currency
+        cdef double xrate = 1.0
+        if self._exchange.base_currency is not None:
+            currency = self._exchange.base_currency
+            xrate = self._exchange.cache.get_xrate(
+                venue=instrument.id.venue,
+                from_currency=cash_flow.amount.currency,
+                to_currency=currency,
+                price_type=PriceType.MID,
+            )
+
+        cdef dict totals = self._totals.setdefault(cash_flow.cash_flow_type, {})
+        cdef Position position
+        cdef double amount
+        for position in open_positions:
+            amount = (
+                cash_flow.amount.as_f64_c()
+                * position.net_qty
+                * instrument.multiplier.as_f64_c()
+                * xrate
+            )
+            totals[currency] = Money(totals.get(currency, 0.0) + amount, currency)
+            self._exchange.adjust_account(Money(amount, currency))
+
+    cpdef void log_diagnostics(self, LoggerAdapter log) except *:
+        """
+        Log diagnostics out to the `BacktestEngine` logger.
+
+        Parameters
+        ----------
+        log : LoggerAdapter
+            The logger to log to.
+
+        """
+        for cash_flow_type, totals in self._totals.items():
+            cash_flow_totals = ', '.join([b.to_str() for b in totals.values()])
+            log.info(f"Cash flows {CashFlowTypeParser.to_str(cash_flow_type)} (totals): {cash_flow_totals}")
+
+    cpdef void reset(self) except *:
+        self._index = 0
+        self._totals = {}
+
+
+cdef class CashInterestModule(SimulationModule):
+    """
+    Provides an interest accrual simulation module for idle cash, crediting
+    interest on the free (uninvested) account balances of each configured
+    currency once a day.
+
+    Interest accrues on the actual days elapsed since the previous accrual
+    (from the first day processed), divided by the days per year of the
+    currency day count convention.
+
+    Parameters
+    ----------
+    rates : dict[Currency, float]
+        The annual interest rates per currency (e.g. 0.05 for 5%).
+    day_counts : dict[Currency, str], optional
+        The day count conventions per currency, 'ACT/360' (the default for
+        any currency not given) or 'ACT/365'.
+    accrual_time : datetime.time, default 00:00
+        The local time of day interest is accrued.
+    timezone : str, default "UTC"
+        The timezone of the accrual time.
+
+    Raises
+    ------
+    ValueError
+        If `day_counts` contains a convention not 'ACT/360' or 'ACT/365'.
+    """
+
+    def __init__(
+        self,
+        dict rates not None,
+        dict day_counts=None,
+        accrual_time not None: dt.time=dt.time(0),
+        str timezone not None="UTC",
+    ):
+        if day_counts is None:
+            day_counts = {}
+        Condition.dict_types(rates, Currency, float, "rates")
+        Condition.dict_types(day_counts, Currency, str, "day_counts")
+        for day_count in day_counts.values():
+            Condition.is_in(
+                day_count,
+                _DAY_COUNT_DENOMINATORS,
+                "day_count",
+                "_DAY_COUNT_DENOMINATORS",
+                ValueError,
+            )
+        super().__init__()
+
+        self._rates = rates
+        self._day_counts = day_counts
+        self._accrual_time = accrual_time
+        self._timezone = pytz.timezone(timezone)
+        self._accrual_date = None  # Initialized on first process
+        self._interest_totals = {}
+
+    cpdef void process(self, int64_t now_ns) except *:
+        """
+        Process the given tick through the module.
+
+        Parameters
+        ----------
+        now_ns : int64
+            The current time in the simulated exchange.
+
+        """
+        cdef datetime now = pd.Timestamp(now_ns, tz="UTC")
+        cdef datetime local = now.astimezone(self._timezone)
+        cdef date accrual_date = local.date()
+        if local.time() < self._accrual_time:
+            accrual_date -= dt.timedelta(days=1)  # Not yet accrued today
+
+        if self._accrual_date is None:
+            self._accrual_date = accrual_date
+        elif accrual_date > self._accrual_date:
+            self._accrue_interest((accrual_date - self._accrual_date).days)
+            self._accrual_date = accrual_date
+
+    cdef void _accrue_interest(self, int days) except *:
+        cdef Account account = self._exchange.cache.account_for_venue(self._exchange.id)
+        if account is None:
+            return  # No account to accrue interest to
+
+        cdef Currency currency
+        cdef Money balance
+        cdef double interest
+        for currency, rate in self._rates.items():
+            balance = account.balance_free(currency)
+            if balance is None or balance.as_f64_c() <= 0.0:
+                continue  # No idle cash
+
+            interest = (
+                balance.as_f64_c()
+                * rate
+                * days
+                / _DAY_COUNT_DENOMINATORS[self._day_counts.get(currency, "ACT/360")]
+            )
+            self._interest_totals[currency] = Money(
+                self._interest_totals.get(currency, 0.0) + interest,
+                currency,
+            )
+            self._exchange.adjust_account(Money(interest, currency))
+
+    cpdef void log_diagnostics(self, LoggerAdapter log) except *:
+        """
+        Log diagnostics out to the `BacktestEngine` logger.
+
+        Parameters
+        ----------
+        log : LoggerAdapter
+            The logger to log to.
+
+        """
+        interest_totals = ', '.join([b.to_str() for b in self._interest_totals.values()])
+        log.info(f"Cash interest (totals): {interest_totals}")
+
+    cpdef void reset(self) except *:
+        self._accrual_date = None  # Initialized on first process
+        self._interest_totals = {}
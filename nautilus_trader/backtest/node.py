@@ -13,12 +13,15 @@
 #  limitations under the License.
 # -------------------------------------------------------------------------------------------------
 
+from concurrent.futures import ThreadPoolExecutor
 from typing import Dict, List, Optional
 
 import pandas as pd
 
 from nautilus_trader.backtest.engine import BacktestEngine
 from nautilus_trader.backtest.engine import BacktestEngineConfig
+from nautilus_trader.backtest.partition import merge_results
+from nautilus_trader.backtest.partition import partition_run_config
 from nautilus_trader.backtest.results import BacktestResult
 from nautilus_trader.config import BacktestDataConfig
 from nautilus_trader.config import BacktestRunConfig
@@ -49,6 +52,9 @@ class BacktestNode:
     ----------
     configs : list[BacktestRunConfig]
         The backtest run configurations.
+    max_workers : int, optional
+        The maximum threads for running the partitions of a run configuration
+        with `partition_instruments` set (``None`` for the executor default).
 
     Raises
     ------
@@ -58,16 +64,23 @@ class BacktestNode:
         If `configs` contains a type other than `BacktestRunConfig`.
     """
 
-    def __init__(self, configs: List[BacktestRunConfig]):
+    def __init__(
+        self,
+        configs: List[BacktestRunConfig],
+        max_workers: Optional[int] = None,
+    ):
         PyCondition.not_none(configs, "configs")
         PyCondition.not_empty(configs, "configs")
         PyCondition.list_type(configs, BacktestRunConfig, "configs")
+        if max_workers is not None:
+            PyCondition.positive_int(max_workers, "max_workers")
 
         self._validate_configs(configs)
 
         # Configuration
         self._configs: List[BacktestRunConfig] = configs
         self._engines: Dict[str, BacktestEngine] = {}
+        self._max_workers = max_workers
 
     @property
     def configs(self) -> List[BacktestRunConfig]:
@@ -86,6 +99,9 @@ class BacktestNode:
         Return the backtest engine associated with the given run config ID
         (if found).
 
+        The engines of a partitioned run are associated with the run config ID
+        suffixed with the partition index, e.g. ``"<run_config_id>-0"``.
+
         Parameters
         ----------
         run_config_id : str
@@ -113,6 +129,9 @@ class BacktestNode:
         """
         Execute a group of backtest run configs synchronously.
 
+        The partitions of a run config with `partition_instruments` set are run
+        on separate threads, and their results merged in partition order.
+
         Returns
         -------
         list[BacktestResult]
@@ -122,17 +141,48 @@ class BacktestNode:
         results: List[BacktestResult] = []
         for config in self._configs:
             config.check()  # Check all values set
-            result = self._run(
-                run_config_id=config.id,
-                engine_config=config.engine,
-                venue_configs=config.venues,
-                data_configs=config.data,
-                batch_size_bytes=config.batch_size_bytes,
-            )
+            partitions = partition_run_config(config)
+            if len(partitions) == 1:
+                result = self._run(
+                    run_config_id=config.id,
+                    engine_config=config.engine,
+                    venue_configs=config.venues,
+                    data_configs=config.data,
+                    batch_size_bytes=config.batch_size_bytes,
+                )
+            else:
+                result = self._run_partitions(config.id, partitions)
             results.append(result)
 
         return results
 
+    def _run_partitions(
+        self,
+        run_config_id: str,
+        partitions: List[BacktestRunConfig],
+    ) -> BacktestResult:
+        run_config_ids = [f"{run_config_id}-{i}" for i in range(len(partitions))]
+
+        def run_partition(index: int) -> BacktestResult:
+            partition = partitions[index]
+            return self._run(
+                run_config_id=run_config_ids[index],
+                engine_config=partition.engine,
+                venue_configs=partition.venues,
+                data_configs=partition.data,
+                batch_size_bytes=partition.batch_size_bytes,
+            )
+
+        with ThreadPoolExecutor(max_workers=self._max_workers) as executor:
+            # Results are in partition order regardless of completion order
+            results = list(executor.map(run_partition, range(len(partitions))))
+
+        # Register the engines in partition order
+        for partition_run_config_id in run_config_ids:
+            self._engines[partition_run_config_id] = self._engines.pop(partition_run_config_id)
+
+        return merge_results(run_config_id, results)
+
     def _validate_configs(self, configs: List[BacktestRunConfig]):
         venue_ids: List[Venue] = []
         for config in configs:
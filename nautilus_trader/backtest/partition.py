@@ -0,0 +1,208 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+"""
+Provides the partitioning of backtest runs into groups of independent
+instruments, which can run concurrently and be merged deterministically.
+"""
+
+from typing import Dict, List, Optional, Set
+
+from nautilus_trader.backtest.results import BacktestResult
+from nautilus_trader.config import BacktestRunConfig
+from nautilus_trader.config import ImportableStrategyConfig
+from nautilus_trader.model.data.bar import BarType
+from nautilus_trader.model.identifiers import InstrumentId
+
+
+INSTRUMENT_ID_KEYS = ("instrument_id", "instrument_ids")
+BAR_TYPE_KEYS = ("bar_type", "bar_types")
+
+
+def strategy_instrument_ids(config: ImportableStrategyConfig) -> Set[str]:
+    """
+    Return the instrument IDs referenced by the given strategy configuration,
+    from its instrument ID and bar type values.
+
+    Parameters
+    ----------
+    config : ImportableStrategyConfig
+        The strategy configuration.
+
+    Returns
+    -------
+    set[str]
+
+    """
+    instrument_ids: Set[str] = set()
+    for key, value in config.config.items():
+        values = value if isinstance(value, (list, tuple)) else [value]
+        values = [str(v) for v in values if v is not None]
+        if key in INSTRUMENT_ID_KEYS:
+            instrument_ids.update(values)
+        elif key in BAR_TYPE_KEYS:
+            instrument_ids.update(str(BarType.from_str(v).instrument_id) for v in values)
+    return instrument_ids
+
+
+class _DisjointSet:
+    def __init__(self):
+        self._parents: Dict[str, str] = {}
+
+    def find(self, item: str) -> str:
+        parent = self._parents.setdefault(item, item)
+        if parent != item:
+            parent = self._parents[item] = self.find(parent)
+        return parent
+
+    def union(self, a: str, b: str) -> None:
+        root_a = self.find(a)
+        root_b = self.find(b)
+        if root_a != root_b:
+            # Keep the least root for a deterministic representation
+            self._parents[max(root_a, root_b)] = min(root_a, root_b)
+
+    def groups(self) -> List[List[str]]:
+        groups: Dict[str, List[str]] = {}
+        for item in self._parents:
+            groups.setdefault(self.find(item), []).append(item)
+        return sorted(sorted(members) for members in groups.values())
+
+
+def partition_run_config(config: BacktestRunConfig) -> List[BacktestRunConfig]:
+    """
+    Return the given run configuration split into partitions of independent
+    instruments, ordered by their least instrument ID.
+
+    Instruments traded by a common strategy are in the same partition. The run
+    is not partitioned (returned as the only partition) unless
+    `partition_instruments` is set, or if it has actors, strategies with no
+    known instruments, or data not associated with an instrument, as these
+    may depend on any instrument.
+
+    Parameters
+    ----------
+    config : BacktestRunConfig
+        The run configuration to partition.
+
+    Returns
+    -------
+    list[BacktestRunConfig]
+
+    """
+    engine = config.engine
+    if not config.partition_instruments or engine is None or engine.actors:
+        return [config]
+
+    instruments = _DisjointSet()
+    strategy_ids: List[List[str]] = []
+    for strategy in engine.strategies:
+        instrument_ids = sorted(strategy_instrument_ids(strategy))
+        if not instrument_ids:
+            return [config]  # Strategy may depend on any instrument
+        for instrument_id in instrument_ids:
+            instruments.union(instrument_ids[0], instrument_id)
+        strategy_ids.append(instrument_ids)
+
+    for data_config in config.data:
+        if data_config.instrument_id is None:
+            return [config]  # Data may be shared by all instruments
+        instruments.find(data_config.instrument_id)
+
+    groups = instruments.groups()
+    if len(groups) <= 1:
+        return [config]
+
+    partitions: List[BacktestRunConfig] = []
+    for members in groups:
+        root = instruments.find(members[0])
+        venue_names = {InstrumentId.from_str(i).venue.value for i in members}
+        partitions.append(
+            config.replace(
+                engine=engine.copy(
+                    update={
+                        "strategies": [
+                            s
+                            for s, ids in zip(engine.strategies, strategy_ids)
+                            if instruments.find(ids[0]) == root
+                        ],
+                    },
+                ),
+                venues=[v for v in config.venues if v.name in venue_names],
+                data=[d for d in config.data if instruments.find(d.instrument_id) == root],
+                partition_instruments=False,
+            ),
+        )
+
+    return partitions
+
+
+def _min(values: List[Optional[int]]) -> Optional[int]:
+    values = [v for v in values if v is not None]
+    return min(values) if values else None
+
+
+def _max(values: List[Optional[int]]) -> Optional[int]:
+    values = [v for v in values if v is not None]
+    return max(values) if values else None
+
+
+def merge_results(run_config_id: str, results: List[BacktestResult]) -> BacktestResult:
+    """
+    Return the result of a partitioned run merged from the results of its
+    partitions, in partition order.
+
+    The counts and PnLs are summed and the times span all partitions. The
+    other performance statistics can't be merged from the partition results,
+    so are only available from the `partitions` of the merged result.
+
+    Parameters
+    ----------
+    run_config_id : str
+        The ID of the partitioned run configuration.
+    results : list[BacktestResult]
+        The results of the partitions, in partition order.
+
+    Returns
+    -------
+    BacktestResult
+
+    """
+    stats_pnls: Dict[str, Dict[str, float]] = {}
+    for result in results:
+        for currency, stats in result.stats_pnls.items():
+            pnls = stats_pnls.setdefault(currency, {"PnL": 0.0})
+            pnls["PnL"] += stats.get("PnL", 0.0)
+
+    first = results[0]
+    return BacktestResult(
+        trader_id=first.trader_id,
+        machine_id=first.machine_id,
+        run_config_id=run_config_id,
+        instance_id=first.instance_id,
+        run_id=first.run_id,
+        run_started=_min([r.run_started for r in results]),
+        run_finished=_max([r.run_finished for r in results]),
+        backtest_start=_min([r.backtest_start for r in results]),
+        backtest_end=_max([r.backtest_end for r in results]),
+        elapsed_time=max(r.elapsed_time for r in results),
+        iterations=sum(r.iterations for r in results),
+        total_events=sum(r.total_events for r in results),
+        total_orders=sum(r.total_orders for r in results),
+        total_positions=sum(r.total_positions for r in results),
+        stats_pnls=stats_pnls,
+        stats_returns={},
+        partitions=list(results),
+    )
@@ -14,13 +14,16 @@
 # -------------------------------------------------------------------------------------------------
 
 from dataclasses import dataclass
-from typing import Dict, Optional
+from dataclasses import field
+from typing import Dict, List, Optional
 
 
 @dataclass
 class BacktestResult:
     """
     Represents the results of a single complete backtest run.
+
+    The results of a partitioned run also hold the results of each partition.
     """
 
     trader_id: str
@@ -39,6 +42,7 @@ class BacktestResult:
     total_positions: int
     stats_pnls: Dict[str, Dict[str, float]]
     stats_returns: Dict[str, float]
+    partitions: List["BacktestResult"] = field(default_factory=list)
 
     # account_balances: pd.DataFrame
     # fills_report: pd.DataFrame
@@ -0,0 +1,200 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+"""
+Provides column-oriented access to the bars of a backtest for vectorized
+research, delivering the bars and indicator values as Arrow arrays
+in one call rather than through per-event callbacks.
+
+The batch from `fill_inputs` is the input of the `vectorized_simulate_fills`
+function of the persistence library, which simulates the fills from the
+vectorized signals. To run a full backtest of the target positions with
+statistics from Python, see `nautilus_trader.backtest.fast`.
+"""
+
+from dataclasses import dataclass
+from typing import Dict, Optional, Sequence, Tuple
+
+import pyarrow as pa
+
+from nautilus_trader.indicators.base.indicator import Indicator
+from nautilus_trader.model.data.bar import BarType
+from nautilus_trader.persistence.catalog import DataCatalog
+
+
+BAR_PRICE_COLUMNS = ("open", "high", "low", "close", "volume")
+
+BARS_SCHEMA = pa.schema(
+    [
+        pa.field("ts_event", pa.int64(), nullable=False),
+        pa.field("ts_init", pa.int64(), nullable=False),
+        *[pa.field(name, pa.float64(), nullable=False) for name in BAR_PRICE_COLUMNS],
+    ],
+)
+
+
+def bar_columns(
+    catalog: DataCatalog,
+    bar_type: BarType,
+    start=None,
+    end=None,
+) -> pa.Table:
+    """
+    Return all bars of the bar type from the catalog as an Arrow table.
+
+    Parameters
+    ----------
+    catalog : DataCatalog
+        The data catalog to read.
+    bar_type : BarType
+        The bar type to read.
+    start : datetime or str or int, optional
+        The start of the bars to read (by `ts_init`).
+    end : datetime or str or int, optional
+        The end of the bars to read (by `ts_init`).
+
+    Returns
+    -------
+    pa.Table
+        The bars in `ts_init` order, with the `BARS_SCHEMA` columns.
+
+    """
+    df = catalog.bars(
+        instrument_ids=[str(bar_type.instrument_id)],
+        start=start,
+        end=end,
+    )
+    if df.empty:
+        return BARS_SCHEMA.empty_table()
+
+    df = df[df["bar_type"].astype(str) == str(bar_type)].sort_values("ts_init", kind="stable")
+    return pa.Table.from_arrays(
+        [
+            pa.array(df["ts_event"], type=pa.int64()),
+            pa.array(df["ts_init"], type=pa.int64()),
+            *[pa.array(df[name].astype(float), type=pa.float64()) for name in BAR_PRICE_COLUMNS],
+        ],
+        schema=BARS_SCHEMA,
+    )
+
+
+@dataclass
+class IndicatorColumn:
+    """
+    Represents an indicator to compute as a column over the bars.
+
+    Parameters
+    ----------
+    indicator : Indicator
+        The indicator, updated with `update_raw` for each bar.
+    inputs : Tuple[str, ...], default ("close",)
+        The bar columns passed to `update_raw`, in argument order.
+    output : str, default "value"
+        The indicator attribute for the column values.
+    """
+
+    indicator: Indicator
+    inputs: Tuple[str, ...] = ("close",)
+    output: str = "value"
+
+
+def indicator_columns(bars: pa.Table, columns: Dict[str, IndicatorColumn]) -> pa.Table:
+    """
+    Return the bars with the given indicator columns appended.
+
+    Each indicator is updated once per bar in order, with the value null until
+    the indicator is initialized.
+
+    Parameters
+    ----------
+    bars : pa.Table
+        The bars (see `bar_columns`).
+    columns : Dict[str, IndicatorColumn]
+        The indicators to compute, by column name.
+
+    Returns
+    -------
+    pa.Table
+
+    """
+    for name, column in columns.items():
+        inputs = [bars.column(i).to_pylist() for i in column.inputs]
+        values = []
+        for args in zip(*inputs):
+            column.indicator.update_raw(*args)
+            if column.indicator.initialized:
+                values.append(float(getattr(column.indicator, column.output)))
+            else:
+                values.append(None)
+        bars = bars.append_column(name, pa.array(values, type=pa.float64()))
+    return bars
+
+
+def fill_inputs(
+    bars: pa.Table,
+    targets: Sequence[Optional[float]],
+    price_column: str = "open",
+    mark_column: str = "close",
+    lag: int = 1,
+) -> pa.RecordBatch:
+    """
+    Return the input batch for simulating fills from the target positions of
+    vectorized signals.
+
+    The target on each bar fills at the price of the bar `lag` bars later, so
+    signals computed from the close of a bar don't trade on it.
+
+    Parameters
+    ----------
+    bars : pa.Table
+        The bars (see `bar_columns`).
+    targets : Sequence[float, optional]
+        The target position for each bar (``None`` to hold).
+    price_column : str, default "open"
+        The bar column to fill at.
+    mark_column : str, default "close"
+        The bar column to value the position at.
+    lag : int, default 1
+        The bars between a signal and its fill (>= 0).
+
+    Returns
+    -------
+    pa.RecordBatch
+        With `ts_event`, `price`, `mark` and `target` columns.
+
+    Raises
+    ------
+    ValueError
+        If `targets` is not the same length as `bars`.
+    ValueError
+        If `lag` is negative.
+
+    """
+    if len(targets) != bars.num_rows:
+        raise ValueError(f"`targets` length {len(targets)} was not {bars.num_rows}")
+    if lag < 0:
+        raise ValueError(f"`lag` was negative, was {lag}")
+
+    prices = bars.column(price_column).to_pylist()
+    prices = prices[lag:] + [None] * min(lag, len(prices))
+    return pa.RecordBatch.from_arrays(
+        [
+            bars.column("ts_event").combine_chunks(),
+            pa.array(prices, type=pa.float64()),
+            bars.column(mark_column).combine_chunks(),
+            pa.array(targets, type=pa.float64()),
+        ],
+        names=["ts_event", "price", "mark", "target"],
+    )
@@ -275,12 +275,18 @@ class BacktestRunConfig(Partialable):
         The data configurations for the backtest run.
     batch_size_bytes : optional
         The batch block size in bytes (will then run in streaming mode).
+    partition_instruments : bool, default False
+        If instruments not traded by a common strategy should run as
+        independent partitions of the run. Each partition has its own venue
+        accounts from the starting balances, so this should only be set when
+        there are no portfolio constraints across the instruments.
     """
 
     engine: Optional[BacktestEngineConfig] = None
     venues: Optional[List[BacktestVenueConfig]] = None
     data: Optional[List[BacktestDataConfig]] = None
     batch_size_bytes: Optional[int] = None
+    partition_instruments: bool = False
 
     @property
     def id(self):
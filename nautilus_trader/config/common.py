@@ -119,10 +119,14 @@ class DataEngineConfig(pydantic.BaseModel):
 
     Parameters
     ----------
+    filter_duplicate_ticks : bool, default False
+        If quote and trade ticks identical to the previous tick for the
+        instrument (apart from `ts_init`) should be dropped at ingress.
     debug : bool
         If debug mode is active (will provide extra debug logging).
     """
 
+    filter_duplicate_ticks: bool = False
     debug: bool = False
 
 
@@ -139,6 +143,16 @@ class RiskEngineConfig(pydantic.BaseModel):
     max_notional_per_order : Dict[str, str]
         The maximum notional value of an order per instrument ID.
         The value should be a valid decimal format.
+    strategy_allocations : Dict[str, str]
+        The fraction of account total balance allocated to each strategy ID.
+        The value should be a valid decimal format in the range (0, 1].
+    max_data_age : str, optional
+        The maximum age of the latest quote or trade for an instrument before
+        its market data is considered stale (e.g. "00:00:05").
+        If ``None`` then market data staleness is not checked.
+    deny_stale_data : bool, default True
+        If orders are denied when market data is stale (otherwise only a
+        warning is logged).
     debug : bool
         If debug mode is active (will provide extra debug logging).
     """
@@ -146,6 +160,9 @@ class RiskEngineConfig(pydantic.BaseModel):
     bypass: bool = False
     max_order_rate: ConstrainedStr = ConstrainedStr("100/00:00:01")
     max_notional_per_order: Dict[str, str] = {}
+    strategy_allocations: Dict[str, str] = {}
+    max_data_age: Optional[str] = None
+    deny_stale_data: bool = True
     debug: bool = False
 
 
@@ -287,12 +304,20 @@ class StrategyConfig(pydantic.BaseModel):
     oms_type : OMSType, optional
         The order management system type for the strategy. This will determine
         how the `ExecutionEngine` handles position IDs (see docs).
+    save_state_interval_secs : PositiveInt, optional
+        The interval (seconds) at which the strategy state is saved to the cache
+        database while running. If None then only saved on stop.
+    state_version : PositiveInt, default 1
+        The version of the saved strategy state, to be incremented when its
+        format changes so older saved states are migrated on load.
 
     """
 
     strategy_id: Optional[str] = None
     order_id_tag: str = "000"
     oms_type: Optional[str] = None
+    save_state_interval_secs: Optional[PositiveInt] = None
+    state_version: PositiveInt = 1
 
 
 class ImportableStrategyConfig(pydantic.BaseModel):
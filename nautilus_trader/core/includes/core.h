@@ -5,8 +5,49 @@
 #include <stdint.h>
 #include <Python.h>
 
+/**
+ * Provides a flag for cooperatively cancelling a run.
+ */
+typedef struct CancellationToken CancellationToken;
+
+/**
+ * Provides throttled progress reports for a run of a known number of items.
+ */
+typedef struct ProgressTracker ProgressTracker;
+
 typedef struct String String;
 
+/**
+ * Represents the progress of a run.
+ */
+typedef struct Progress {
+    /**
+     * The number of items processed.
+     */
+    uint64_t processed;
+    /**
+     * The total number of items to process.
+     */
+    uint64_t total;
+    /**
+     * The percentage of items processed, in the range [0, 100].
+     */
+    double percent;
+    /**
+     * The seconds elapsed since the start of the run.
+     */
+    double elapsed_secs;
+    /**
+     * The average number of items processed per second (zero until any time
+     * has elapsed).
+     */
+    double events_per_sec;
+    /**
+     * The estimated seconds remaining (NaN until the rate is known).
+     */
+    double eta_secs;
+} Progress;
+
 /**
  * Represents a timestamp in UNIX nanoseconds.
  */
@@ -18,6 +59,81 @@ typedef struct UUID4_t {
     struct String *value;
 } UUID4_t;
 
+/**
+ * Represents a Rust `Vec` passed across the C boundary, with its element
+ * type erased.
+ *
+ * The owning Rust module provides the typed function to drop it, as only
+ * Rust can free the elements and buffer.
+ */
+typedef struct CVec {
+    /**
+     * The pointer to the first element (null when empty and unallocated).
+     */
+    void *ptr;
+    /**
+     * The number of elements.
+     */
+    uintptr_t len;
+    /**
+     * The allocated capacity (elements).
+     */
+    uintptr_t cap;
+} CVec;
+
+/**
+ * Returns a new tracker for `total` items calling `callback` (if not null)
+ * with `user_data` at most once per `interval_ms`. The tracker must be
+ * passed to `progress_tracker_free` to free it.
+ */
+struct ProgressTracker *progress_tracker_new(uint64_t total,
+                                             uint64_t interval_ms,
+                                             void (*callback)(struct Progress, void*),
+                                             void *user_data);
+
+/**
+ * Updates the tracker with the number of items processed, calling the
+ * callback if a report is due. Returns 1 if the callback was due, otherwise 0.
+ */
+uint8_t progress_tracker_update(struct ProgressTracker *tracker, uint64_t processed);
+
+/**
+ * Calls the callback with the final progress of the run.
+ */
+void progress_tracker_finish(struct ProgressTracker *tracker, uint64_t processed);
+
+/**
+ * Frees the tracker.
+ *
+ * # Safety
+ *
+ * - `tracker` must be null or have been returned by `progress_tracker_new`
+ *   and not already freed.
+ */
+void progress_tracker_free(struct ProgressTracker *tracker);
+
+/**
+ * Returns a new cancellation token. The token must be passed to
+ * `cancellation_token_free` to free it.
+ */
+struct CancellationToken *cancellation_token_new(void);
+
+void cancellation_token_cancel(const struct CancellationToken *token);
+
+uint8_t cancellation_token_is_cancelled(const struct CancellationToken *token);
+
+void cancellation_token_reset(const struct CancellationToken *token);
+
+/**
+ * Frees the token.
+ *
+ * # Safety
+ *
+ * - `token` must be null or have been returned by `cancellation_token_new`
+ *   and not already freed.
+ */
+void cancellation_token_free(struct CancellationToken *token);
+
 /**
  * Returns the current seconds since the UNIX epoch.
  */
@@ -59,7 +175,7 @@ struct UUID4_t uuid4_from_pystr(PyObject *ptr);
  * # Safety
  *
  * - Assumes that since the data is originating from Rust, the GIL does not need
- * to be acquired.
+ *   to be acquired.
  * - Assumes you are immediately returning this pointer to Python.
  */
 PyObject *uuid4_to_pystr(const struct UUID4_t *uuid);
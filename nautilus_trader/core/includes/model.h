@@ -5,55 +5,449 @@
 #include <stdint.h>
 #include <Python.h>
 
+/**
+ * The maximum length (bytes) of an identifier value.
+ */
+#define IDENTIFIER_MAX_LEN 255
+
+/**
+ * The handle value which is never assigned to an identifier.
+ */
+#define NULL_HANDLE 0
+
+/**
+ * The maximum length (bytes) of a value stored inline rather than interned.
+ */
+#define INLINE_CAPACITY 23
+
+/**
+ * The maximum length (characters) of a trade ID value, per the FIX `ExecID`
+ * (tag 17) field.
+ */
+#define TRADE_ID_MAX_LEN 64
+
+/**
+ * The delimiter wrapping the names of synthetic or consolidated venues, e.g.
+ * `-CONSOLIDATED-`.
+ */
+#define SYNTHETIC_VENUE_DELIMITER '-'
+
 #define FIXED_PRECISION 9
 
 #define FIXED_SCALAR 1000000000.0
 
+/**
+ * The `NumberFormat::decimals` to format at the precision of the value.
+ */
+#define DECIMALS_PRECISION 255
+
+typedef enum AggressorSide {
+    AggressorSide_Unknown = 0,
+    AggressorSide_Buy = 1,
+    AggressorSide_Sell = 2,
+} AggressorSide;
+
+/**
+ * Represents an invalid account or margin balance.
+ *
+ * The discriminants are the error codes returned by the C API constructors,
+ * where zero indicates success.
+ */
+typedef enum BalanceError {
+    /**
+     * The balance amounts had different currencies.
+     */
+    BalanceError_CurrencyMismatch = 1,
+    /**
+     * A balance amount was negative.
+     */
+    BalanceError_Negative = 2,
+    /**
+     * The total was not equal to the locked plus free amounts.
+     */
+    BalanceError_TotalMismatch = 3,
+} BalanceError;
+
 typedef enum BookLevel {
-    L1_TBBO = 1,
-    L2_MBP = 2,
-    L3_MBO = 3,
+    BookLevel_L1_TBBO = 1,
+    BookLevel_L2_MBP = 2,
+    BookLevel_L3_MBO = 3,
 } BookLevel;
 
+typedef enum CandlePattern {
+    /**
+     * A bullish candle whose body engulfs the body of a prior bearish candle.
+     */
+    CandlePattern_BullishEngulfing = 1,
+    /**
+     * A bearish candle whose body engulfs the body of a prior bullish candle.
+     */
+    CandlePattern_BearishEngulfing = 2,
+    /**
+     * A candle whose body is small relative to its range.
+     */
+    CandlePattern_Doji = 3,
+    /**
+     * A candle whose range is within the range of the prior candle.
+     */
+    CandlePattern_InsideBar = 4,
+    /**
+     * A candle closing above the highs of the prior N candles.
+     */
+    CandlePattern_BreakoutHigh = 5,
+    /**
+     * A candle closing below the lows of the prior N candles.
+     */
+    CandlePattern_BreakoutLow = 6,
+} CandlePattern;
+
+/**
+ * Represents where the currency code of a formatted amount of money is
+ * placed.
+ */
+typedef enum CurrencyPosition {
+    /**
+     * After the amount (e.g. "1,000.00 USD").
+     */
+    CurrencyPosition_Suffix = 1,
+    /**
+     * Before the amount (e.g. "USD 1,000.00").
+     */
+    CurrencyPosition_Prefix = 2,
+    /**
+     * Not shown.
+     */
+    CurrencyPosition_Hidden = 3,
+} CurrencyPosition;
+
 typedef enum CurrencyType {
-    Crypto,
-    Fiat,
+    CurrencyType_Crypto,
+    CurrencyType_Fiat,
 } CurrencyType;
 
+/**
+ * Represents a fast backtest failure, with the discriminant returned as the
+ * error code by the C API.
+ */
+typedef enum FastBacktestError {
+    /**
+     * The input arrays were not all the same length.
+     */
+    FastBacktestError_LengthMismatch = 1,
+    /**
+     * A cost or the periods per year was negative or not finite.
+     */
+    FastBacktestError_InvalidArgument = 2,
+} FastBacktestError;
+
+/**
+ * Represents a lossy or invalid conversion of an `f64` to fixed-point.
+ *
+ * The discriminants are the error codes returned by the C API `checked`
+ * functions, where zero indicates success.
+ */
+typedef enum FixedPointError {
+    /**
+     * The precision exceeded `FIXED_PRECISION`.
+     */
+    FixedPointError_InvalidPrecision = 1,
+    /**
+     * The value was NaN or infinite.
+     */
+    FixedPointError_NotFinite = 2,
+    /**
+     * The value was outside the fixed-point range.
+     */
+    FixedPointError_Overflow = 3,
+    /**
+     * The value was negative for an unsigned type.
+     */
+    FixedPointError_Negative = 4,
+    /**
+     * The value was not exactly representable at the precision.
+     */
+    FixedPointError_PrecisionLoss = 5,
+    /**
+     * The value string was not a decimal number.
+     */
+    FixedPointError_InvalidFormat = 6,
+    /**
+     * The operands of an exact operation had different precisions.
+     */
+    FixedPointError_PrecisionMismatch = 7,
+    /**
+     * The operands of a money operation had different currencies.
+     */
+    FixedPointError_CurrencyMismatch = 8,
+    /**
+     * The increment to round to was not positive.
+     */
+    FixedPointError_InvalidIncrement = 9,
+    /**
+     * The divisor of a conversion was zero.
+     */
+    FixedPointError_DivisionByZero = 10,
+} FixedPointError;
+
+/**
+ * Represents an identifier validation failure.
+ *
+ * The discriminants are the error codes returned by the C API `try_from`
+ * functions, where zero indicates success.
+ */
+typedef enum IdentifierError {
+    /**
+     * The value was empty or only whitespace.
+     */
+    IdentifierError_Empty = 1,
+    /**
+     * The value exceeded the maximum length for the identifier type
+     * (`IDENTIFIER_MAX_LEN` bytes unless otherwise documented).
+     */
+    IdentifierError_TooLong = 2,
+    /**
+     * The value contained control characters (including NUL) or leading or
+     * trailing whitespace.
+     */
+    IdentifierError_InvalidChars = 3,
+    /**
+     * The value was not in the expected format (e.g. "SYMBOL.VENUE").
+     */
+    IdentifierError_InvalidFormat = 4,
+} IdentifierError;
+
+typedef enum OptionKind {
+    OptionKind_Call = 1,
+    OptionKind_Put = 2,
+} OptionKind;
+
 typedef enum OrderSide {
-    Buy = 1,
-    Sell = 2,
+    OrderSide_Buy = 1,
+    OrderSide_Sell = 2,
 } OrderSide;
 
+typedef enum PovStatus {
+    /**
+     * Releasing child orders against observed volume.
+     */
+    PovStatus_Running = 1,
+    /**
+     * The full quantity has been released.
+     */
+    PovStatus_Completed = 2,
+    /**
+     * The deadline passed with quantity unreleased.
+     */
+    PovStatus_Expired = 3,
+} PovStatus;
+
+typedef enum PriceType {
+    PriceType_Bid = 1,
+    PriceType_Ask = 2,
+    PriceType_Mid = 3,
+    PriceType_Last = 4,
+} PriceType;
+
+typedef enum RoundingMode {
+    /**
+     * Round towards negative infinity.
+     */
+    RoundingMode_Down = 1,
+    /**
+     * Round towards positive infinity.
+     */
+    RoundingMode_Up = 2,
+    /**
+     * Round to the nearest tick, with ties away from zero.
+     */
+    RoundingMode_Nearest = 3,
+    /**
+     * Round to the nearest tick, with ties to the even tick (banker's rounding).
+     */
+    RoundingMode_HalfEven = 4,
+} RoundingMode;
+
+typedef enum TradeSignMethod {
+    TradeSignMethod_TickRule = 1,
+    TradeSignMethod_LeeReady = 2,
+} TradeSignMethod;
+
 typedef struct BTreeMap_BookPrice__Level BTreeMap_BookPrice__Level;
 
-typedef struct HashMap_u64__BookPrice HashMap_u64__BookPrice;
+typedef struct Entry Entry;
 
-typedef struct String String;
+/**
+ * Provides exchange rate calculations between currencies from the latest
+ * bid and ask quotes of currency pairs.
+ */
+typedef struct ExchangeRateCalculator_t ExchangeRateCalculator_t;
 
-typedef struct Symbol_t {
-    struct String *value;
-} Symbol_t;
+typedef struct HashMap_String__u64 HashMap_String__u64;
 
-typedef struct Venue_t {
-    struct String *value;
-} Venue_t;
+typedef struct HashMap_u64__BookPrice HashMap_u64__BookPrice;
 
-typedef struct InstrumentId_t {
-    struct Symbol_t symbol;
-    struct Venue_t venue;
-} InstrumentId_t;
+typedef struct String String;
 
 typedef struct Price_t {
     int64_t raw;
     uint8_t precision;
 } Price_t;
 
+/**
+ * Represents the prices of a bar for pattern detection.
+ */
+typedef struct Candle {
+    struct Price_t open;
+    struct Price_t high;
+    struct Price_t low;
+    struct Price_t close;
+    /**
+     * The UNIX timestamp (nanoseconds) when the candle closed.
+     */
+    int64_t ts_event;
+} Candle;
+
+/**
+ * Represents the parameters of pattern detection.
+ */
+typedef struct PatternParams {
+    /**
+     * The maximum ratio of body to range for a doji, in the range [0, 1].
+     */
+    double doji_body_ratio;
+    /**
+     * The number of prior candles for a breakout (zero disables breakouts).
+     */
+    uint32_t breakout_period;
+} PatternParams;
+
+/**
+ * Represents an identifier string value.
+ *
+ * Values of up to `INLINE_CAPACITY` bytes (most symbols, venues and
+ * account IDs) are stored inline without allocating. Longer values are
+ * interned, so all live values with the same contents share a single
+ * allocation, cloning is a reference count increment and equality is a
+ * pointer compare. The hash is computed once on construction.
+ *
+ * As the representation is determined by the length, equal values always
+ * share a representation.
+ */
+typedef struct InternedStr {
+    /**
+     * The interned entry, or null when the value is inline.
+     */
+    const struct Entry *ptr;
+    uint64_t hash;
+    uint8_t len;
+    uint8_t inline_[INLINE_CAPACITY];
+} InternedStr;
+
+/**
+ * Represents a valid execution algorithm ID (e.g. "TWAP", "VWAP").
+ */
+typedef struct ExecAlgorithmId_t {
+    struct InternedStr value;
+} ExecAlgorithmId_t;
+
 typedef struct Quantity_t {
     uint64_t raw;
     uint8_t precision;
 } Quantity_t;
 
+/**
+ * Represents the parameters of a POV execution.
+ */
+typedef struct PovParams {
+    enum OrderSide side;
+    struct Quantity_t quantity;
+    /**
+     * The target fraction of traded volume, in the range (0, 1].
+     */
+    double participation_rate;
+    struct Quantity_t min_clip;
+    struct Quantity_t max_clip;
+    /**
+     * The UNIX timestamp (nanoseconds) by which to complete.
+     */
+    int64_t deadline_ns;
+    /**
+     * If the remaining quantity is released at the deadline (non-zero),
+     * otherwise the execution expires.
+     */
+    uint8_t complete_on_deadline;
+} PovParams;
+
+/**
+ * Provides the child order pacing of a POV execution.
+ */
+typedef struct PovAlgorithm {
+    struct ExecAlgorithmId_t exec_algorithm_id;
+    struct PovParams params;
+    enum PovStatus status;
+    /**
+     * The traded volume observed since the execution started.
+     */
+    struct Quantity_t market_volume;
+    /**
+     * The quantity released in child orders not yet filled or canceled.
+     */
+    struct Quantity_t working;
+    struct Quantity_t filled;
+} PovAlgorithm;
+
+/**
+ * Represents a trade to classify.
+ */
+typedef struct SignTrade {
+    struct Price_t price;
+    struct Quantity_t size;
+    /**
+     * The reported aggressor side, `Unknown` if not reported.
+     */
+    enum AggressorSide aggressor_side;
+    /**
+     * The UNIX timestamp (nanoseconds) of the trade.
+     */
+    int64_t ts_event;
+} SignTrade;
+
+/**
+ * Represents a quote for the Lee-Ready classification.
+ */
+typedef struct SignQuote {
+    struct Price_t bid;
+    struct Price_t ask;
+    /**
+     * The UNIX timestamp (nanoseconds) of the quote.
+     */
+    int64_t ts_event;
+} SignQuote;
+
+/**
+ * Represents the sign of a trade.
+ */
+typedef struct TradeSign {
+    enum AggressorSide aggressor_side;
+    /**
+     * If the side was inferred (non-zero) rather than reported.
+     */
+    uint8_t inferred;
+} TradeSign;
+
+typedef struct Symbol_t {
+    struct InternedStr value;
+} Symbol_t;
+
+typedef struct Venue_t {
+    struct InternedStr value;
+} Venue_t;
+
+typedef struct InstrumentId_t {
+    struct Symbol_t symbol;
+    struct Venue_t venue;
+} InstrumentId_t;
+
 /**
  * Represents a single quote tick in a financial market.
  */
@@ -68,7 +462,7 @@ typedef struct QuoteTick_t {
 } QuoteTick_t;
 
 typedef struct TradeId_t {
-    struct String *value;
+    struct InternedStr value;
 } TradeId_t;
 
 /**
@@ -84,44 +478,163 @@ typedef struct TradeTick_t {
     Timestamp ts_init;
 } TradeTick_t;
 
+typedef struct Currency_t {
+    struct String *code;
+    uint8_t precision;
+    uint16_t iso4217;
+    struct String *name;
+    enum CurrencyType currency_type;
+} Currency_t;
+
+/**
+ * Represents the costs applied to the simulated fills.
+ */
+typedef struct FillCosts {
+    /**
+     * The commission as a fraction of the fill notional.
+     */
+    double commission_rate;
+    /**
+     * The slippage as a fraction of the fill price, against the fill side.
+     */
+    double slippage;
+} FillCosts;
+
+/**
+ * Represents the summary statistics of a fast backtest.
+ */
+typedef struct FastBacktestStats {
+    /**
+     * The PnL at the last bar, net of commissions.
+     */
+    double total_pnl;
+    /**
+     * The total commissions.
+     */
+    double total_commission;
+    /**
+     * The number of bars with a fill.
+     */
+    uint64_t fills;
+    /**
+     * The total notional filled.
+     */
+    double turnover;
+    /**
+     * The largest fall of the PnL from its running peak (starting at zero).
+     */
+    double max_drawdown;
+    /**
+     * The annualized Sharpe ratio of the per-bar PnL changes (NaN if fewer
+     * than two bars or no variance).
+     */
+    double sharpe_ratio;
+} FastBacktestStats;
+
 typedef struct AccountId_t {
-    struct String *value;
+    struct InternedStr value;
 } AccountId_t;
 
+/**
+ * Represents a borrowed view of an identifier value for C consumers.
+ *
+ * The bytes are UTF-8 and not NUL terminated, and remain valid for the
+ * lifetime of the identifier they were taken from.
+ */
+typedef struct StrView {
+    const char *ptr;
+    uintptr_t len;
+} StrView;
+
+/**
+ * Represents a valid data or execution client ID, the key commands are routed
+ * to adapter clients by (e.g. "BINANCE").
+ */
 typedef struct ClientId_t {
-    struct String *value;
+    struct InternedStr value;
 } ClientId_t;
 
 typedef struct ClientOrderId_t {
-    struct String *value;
+    struct InternedStr value;
 } ClientOrderId_t;
 
 typedef struct ClientOrderLinkId_t {
-    struct String *value;
+    struct InternedStr value;
 } ClientOrderLinkId_t;
 
+/**
+ * Represents a valid component ID, which may be hierarchical with '.'
+ * separated parts (e.g. "RiskEngine.Throttler.Orders").
+ */
 typedef struct ComponentId_t {
-    struct String *value;
+    struct InternedStr value;
 } ComponentId_t;
 
+typedef struct TraderId_t {
+    struct InternedStr value;
+} TraderId_t;
+
+typedef struct StrategyId_t {
+    struct InternedStr value;
+} StrategyId_t;
+
+/**
+ * Provides a generator for unique client order IDs, of the form
+ * "O-{YYYYMMDD}-{trader_tag}-{strategy_tag}-{count}".
+ *
+ * The date is taken from the given timestamp, so IDs are deterministic for
+ * a given clock (e.g. in backtests).
+ */
+typedef struct ClientOrderIdGenerator {
+    struct TraderId_t trader_id;
+    struct StrategyId_t strategy_id;
+    uint64_t count;
+} ClientOrderIdGenerator;
+
+/**
+ * Provides a generator for unique order list IDs, of the form
+ * "OL-{YYYYMMDD}-{trader_tag}-{strategy_tag}-{count}".
+ *
+ * The count increments for the lifetime of the trader session, and can be
+ * restored with `set_count` when resuming from persisted state.
+ */
+typedef struct OrderListIdGenerator {
+    struct TraderId_t trader_id;
+    struct StrategyId_t strategy_id;
+    uint64_t count;
+} OrderListIdGenerator;
+
 typedef struct OrderListId_t {
-    struct String *value;
+    struct InternedStr value;
 } OrderListId_t;
 
+/**
+ * Provides a generator for unique position IDs, of the form
+ * "P-{YYYYMMDD}-{instrument_id}-{count}", with an "F" suffix for positions
+ * opened by a flip.
+ *
+ * Counts are tracked per instrument, so IDs are deterministic for a given
+ * sequence of fills.
+ */
+typedef struct PositionIdGenerator {
+    struct HashMap_String__u64 *counts;
+} PositionIdGenerator;
+
 typedef struct PositionId_t {
-    struct String *value;
+    struct InternedStr value;
 } PositionId_t;
 
-typedef struct StrategyId_t {
-    struct String *value;
-} StrategyId_t;
-
-typedef struct TraderId_t {
+/**
+ * Represents a glob pattern over "SYMBOL.VENUE" instrument IDs, where `*`
+ * matches any sequence of chars and `?` matches any single char (e.g.
+ * "BTC*.BINANCE" or "*.FTX").
+ */
+typedef struct SubscriptionPattern {
     struct String *value;
-} TraderId_t;
+} SubscriptionPattern;
 
 typedef struct VenueOrderId_t {
-    struct String *value;
+    struct InternedStr value;
 } VenueOrderId_t;
 
 typedef struct Ladder {
@@ -139,19 +652,301 @@ typedef struct OrderBook {
     int64_t ts_last;
 } OrderBook;
 
-typedef struct Currency_t {
-    struct String *code;
-    uint8_t precision;
-    uint16_t iso4217;
-    struct String *name;
-    enum CurrencyType currency_type;
-} Currency_t;
+/**
+ * Represents an exponentially weighted rate of volume per second.
+ */
+typedef struct VolumeRate {
+    double volume;
+    int64_t ts_last;
+} VolumeRate;
+
+/**
+ * Provides queue time estimates from the trade and cancel flow observed for
+ * an order book.
+ */
+typedef struct QueueEstimator {
+    uint64_t half_life_ns;
+    struct VolumeRate traded;
+    struct VolumeRate canceled;
+} QueueEstimator;
+
+/**
+ * Represents a queue time estimate for a passive order.
+ */
+typedef struct QueueEstimate {
+    /**
+     * The volume resting at or better than the order price.
+     */
+    double queue_ahead;
+    /**
+     * The expected time to fill in seconds (infinite with no observed flow).
+     */
+    double expected_time_secs;
+    /**
+     * The probability of filling within the horizon.
+     */
+    double fill_probability;
+} QueueEstimate;
+
+/**
+ * Represents a snapshot of the session statistics for an instrument.
+ *
+ * The prices are zero when `trade_count` is zero.
+ */
+typedef struct SessionStats {
+    /**
+     * The price of the first trade of the session.
+     */
+    struct Price_t open;
+    /**
+     * The highest trade price of the session.
+     */
+    struct Price_t high;
+    /**
+     * The lowest trade price of the session.
+     */
+    struct Price_t low;
+    /**
+     * The price of the latest trade of the session.
+     */
+    struct Price_t last;
+    /**
+     * The volume weighted average trade price of the session.
+     */
+    struct Price_t vwap;
+    /**
+     * The total traded volume of the session (saturating).
+     */
+    struct Quantity_t volume;
+    /**
+     * The number of trades of the session.
+     */
+    uint64_t trade_count;
+    /**
+     * The UNIX timestamp (nanoseconds) when the session started.
+     */
+    int64_t ts_start;
+    /**
+     * The UNIX timestamp (nanoseconds) of the latest trade.
+     */
+    int64_t ts_last;
+} SessionStats;
+
+/**
+ * Represents a snapshot of the trading statistics for an instrument.
+ */
+typedef struct TradingStats {
+    /**
+     * The number of fills.
+     */
+    uint64_t trade_count;
+    /**
+     * The total quantity filled.
+     */
+    double volume;
+    /**
+     * The total commissions, in the commission currency of the instrument.
+     */
+    double fees;
+    /**
+     * The number of round trips closed with a positive realized PnL.
+     */
+    uint64_t win_count;
+    /**
+     * The number of round trips closed with a zero or negative realized PnL.
+     */
+    uint64_t loss_count;
+    /**
+     * The ratio of wins to closed round trips (zero if none).
+     */
+    double win_rate;
+    /**
+     * The average slippage (price points, positive when adverse) of fills
+     * with an expected price (zero if none).
+     */
+    double avg_slippage;
+} TradingStats;
 
 typedef struct Money_t {
     int64_t raw;
     struct Currency_t currency;
 } Money_t;
 
+/**
+ * Represents an account balance denominated in a particular currency.
+ */
+typedef struct AccountBalance_t {
+    struct Currency_t currency;
+    /**
+     * The total account balance.
+     */
+    struct Money_t total;
+    /**
+     * The account balance locked (assigned to pending orders).
+     */
+    struct Money_t locked;
+    /**
+     * The account balance free for trading.
+     */
+    struct Money_t free;
+} AccountBalance_t;
+
+/**
+ * Represents a margin balance optionally associated with a particular
+ * instrument.
+ */
+typedef struct MarginBalance_t {
+    struct Currency_t currency;
+    /**
+     * The initial (order) margin requirement.
+     */
+    struct Money_t initial;
+    /**
+     * The maintenance (position) margin requirement.
+     */
+    struct Money_t maintenance;
+    /**
+     * The instrument ID associated with the margin (null for account wide).
+     */
+    struct InstrumentId_t *instrument_id;
+} MarginBalance_t;
+
+/**
+ * Represents the options for formatting a fixed-point value.
+ *
+ * The separators are Unicode code points, with a zero thousands separator
+ * for no grouping.
+ */
+typedef struct NumberFormat {
+    uint32_t thousands_separator;
+    uint32_t decimal_separator;
+    /**
+     * The decimals to format (rounding half away from zero), or
+     * `DECIMALS_PRECISION` for the precision of the value.
+     */
+    uint8_t decimals;
+    enum CurrencyPosition currency_position;
+} NumberFormat;
+
+typedef struct PriceBand {
+    /**
+     * The lowest price within the band (inclusive).
+     */
+    struct Price_t lower;
+    /**
+     * The highest price within the band (inclusive).
+     */
+    struct Price_t upper;
+} PriceBand;
+
+/**
+ * Represents a detected pattern.
+ */
+typedef struct PatternDetection {
+    enum CandlePattern pattern;
+    /**
+     * The index of the candle completing the pattern.
+     */
+    uint64_t index;
+    /**
+     * The UNIX timestamp (nanoseconds) of the candle completing the pattern.
+     */
+    int64_t ts_event;
+} PatternDetection;
+
+extern const uint8_t SIM_VENUE_NAME[4];
+
+extern const uint8_t BETFAIR_VENUE_NAME[8];
+
+extern const uint8_t BINANCE_VENUE_NAME[8];
+
+extern const uint8_t FTX_VENUE_NAME[4];
+
+extern const uint8_t IB_VENUE_NAME[19];
+
+extern const uint8_t SYNTHETIC_VENUE_NAME[6];
+
+/**
+ * Returns a vec of the `PatternDetection`s in the array of `len` candles
+ * (oldest first). The vec must be passed to `pattern_detections_drop` to
+ * free it.
+ *
+ * # Safety
+ *
+ * - `candles` must point to `len` valid candles (or be null if `len` is zero).
+ */
+CVec candle_patterns_detect(const struct Candle *candles,
+                            uintptr_t len,
+                            const struct PatternParams *params);
+
+/**
+ * Drops a vec of detections returned by `candle_patterns_detect`.
+ *
+ * # Safety
+ *
+ * - `detections` must have been returned by `candle_patterns_detect`.
+ */
+void pattern_detections_drop(CVec detections);
+
+struct PovAlgorithm pov_algorithm_new(struct PovParams params);
+
+void pov_algorithm_free(struct PovAlgorithm algorithm);
+
+/**
+ * Returns the child order quantity to release for the trade (zero if none).
+ */
+struct Quantity_t pov_algorithm_on_trade(struct PovAlgorithm *algorithm,
+                                         const struct Quantity_t *size,
+                                         int64_t ts_event);
+
+/**
+ * Returns the final child order quantity to release at `ts` (zero if none).
+ */
+struct Quantity_t pov_algorithm_on_time(struct PovAlgorithm *algorithm, int64_t ts);
+
+void pov_algorithm_on_fill(struct PovAlgorithm *algorithm, const struct Quantity_t *last_qty);
+
+void pov_algorithm_on_cancel(struct PovAlgorithm *algorithm, const struct Quantity_t *leaves_qty);
+
+/**
+ * Writes the sign of each of the `len` trades (oldest first) by the method
+ * to `out`, returning the number of trades with an inferred side.
+ *
+ * # Safety
+ *
+ * - `trades` must point to `len` valid trades (or be null if `len` is zero).
+ * - `quotes` must point to `quotes_len` valid quotes (or be null if
+ *   `quotes_len` is zero).
+ * - `out` must point to a buffer for at least `len` signs.
+ */
+uintptr_t trade_sign_classify(const struct SignTrade *trades,
+                              uintptr_t len,
+                              const struct SignQuote *quotes,
+                              uintptr_t quotes_len,
+                              enum TradeSignMethod method,
+                              int64_t quote_lag_ns,
+                              struct TradeSign *out);
+
+/**
+ * Returns a vec of the `VolumeSplit`s of the array of `len` trades (oldest
+ * first) by bulk volume classification. The vec must be passed to
+ * `volume_splits_drop` to free it.
+ *
+ * # Safety
+ *
+ * - `trades` must point to `len` valid trades (or be null if `len` is zero).
+ */
+CVec trade_sign_bulk_volume(const struct SignTrade *trades, uintptr_t len, int64_t interval_ns);
+
+/**
+ * Drops a vec of volume splits returned by `trade_sign_bulk_volume`.
+ *
+ * # Safety
+ *
+ * - `splits` must have been returned by `trade_sign_bulk_volume`.
+ */
+void volume_splits_drop(CVec splits);
+
 void quote_tick_free(struct QuoteTick_t tick);
 
 struct QuoteTick_t quote_tick_new(struct InstrumentId_t instrument_id,
@@ -172,291 +967,2158 @@ struct QuoteTick_t quote_tick_from_raw(struct InstrumentId_t instrument_id,
                                        int64_t ts_event,
                                        int64_t ts_init);
 
-void trade_tick_free(struct TradeTick_t tick);
+uint8_t quote_tick_is_duplicate(const struct QuoteTick_t *tick, const struct QuoteTick_t *other);
+
+void trade_tick_free(struct TradeTick_t tick);
+
+struct TradeTick_t trade_tick_from_raw(struct InstrumentId_t instrument_id,
+                                       int64_t price,
+                                       uint8_t price_prec,
+                                       uint64_t size,
+                                       uint8_t size_prec,
+                                       enum OrderSide aggressor_side,
+                                       struct TradeId_t trade_id,
+                                       int64_t ts_event,
+                                       int64_t ts_init);
+
+uint8_t trade_tick_is_duplicate(const struct TradeTick_t *tick, const struct TradeTick_t *other);
+
+/**
+ * Returns a new calculator with no quotes. The calculator must be passed to
+ * `exchange_rate_calculator_free` to free it.
+ */
+struct ExchangeRateCalculator_t *exchange_rate_calculator_new(void);
+
+/**
+ * Updates the bid and ask quotes of the currency pair, returning 1 if the
+ * symbol was a `BASE/QUOTE` pair, otherwise 0.
+ *
+ * # Safety
+ *
+ * - `symbol_ptr` must be borrowed from a valid Python UTF-8 `str`.
+ */
+uint8_t exchange_rate_calculator_update(struct ExchangeRateCalculator_t *calculator,
+                                        PyObject *symbol_ptr,
+                                        double bid,
+                                        double ask);
+
+void exchange_rate_calculator_clear(struct ExchangeRateCalculator_t *calculator);
+
+/**
+ * Returns the exchange rate between the currencies at the price type, or
+ * zero if there are insufficient quotes or the price type is `Last`.
+ */
+double exchange_rate_calculator_get_rate(const struct ExchangeRateCalculator_t *calculator,
+                                         const struct Currency_t *from_currency,
+                                         const struct Currency_t *to_currency,
+                                         enum PriceType price_type);
+
+/**
+ * Frees the calculator.
+ *
+ * # Safety
+ *
+ * - `calculator` must be null or have been returned by
+ *   `exchange_rate_calculator_new` and not already freed.
+ */
+void exchange_rate_calculator_free(struct ExchangeRateCalculator_t *calculator);
+
+/**
+ * Runs a fast backtest over the `len` bars of the arrays, writing the
+ * statistics to `stats_out` and returning zero on success or the
+ * `FastBacktestError` code.
+ *
+ * The position and cumulative PnL of each bar are written to `position_out`
+ * and `pnl_out` unless null.
+ *
+ * # Safety
+ *
+ * - `price`, `mark` and `target` must be valid pointers to `len` values
+ *   (or may be null if `len` is zero).
+ * - `position_out` and `pnl_out` must be null or valid pointers to `len`
+ *   values to write.
+ * - `stats_out` must be a valid pointer to write the statistics to.
+ */
+uint8_t fast_backtest_run(const double *price,
+                          const double *mark,
+                          const double *target,
+                          uintptr_t len,
+                          struct FillCosts costs,
+                          double periods_per_year,
+                          double *position_out,
+                          double *pnl_out,
+                          struct FastBacktestStats *stats_out);
+
+void account_id_free(struct AccountId_t identifier);
+
+/**
+ * Returns a Nautilus identifier from a valid Python object pointer.
+ *
+ * # Safety
+ *
+ * - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+ */
+struct AccountId_t account_id_from_pystr(PyObject *ptr);
+
+/**
+ * Returns zero and writes a Nautilus identifier to `out` from a valid Python
+ * object pointer, otherwise returns the non-zero `IdentifierError` code.
+ *
+ * # Safety
+ *
+ * - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+ * - `out` must be a valid pointer to uninitialized memory to write the
+ *   identifier to, as any value already there is not dropped.
+ */
+uint8_t account_id_try_from_pystr(PyObject *ptr, struct AccountId_t *out);
+
+/**
+ * Returns a pointer to a valid Python UTF-8 string.
+ *
+ * # Safety
+ *
+ * - Assumes that since the data is originating from Rust, the GIL does not need
+ *   to be acquired.
+ * - Assumes you are immediately returning this pointer to Python.
+ */
+PyObject *account_id_to_pystr(const struct AccountId_t *identifier);
+
+/**
+ * Returns a view of the value bytes (UTF-8, not NUL terminated), valid for
+ * the lifetime of the identifier, without allocating.
+ */
+struct StrView account_id_as_bytes(const struct AccountId_t *identifier);
+
+/**
+ * Returns the hash of the value, precomputed when interned, for the Python
+ * `__hash__`.
+ */
+uint64_t account_id_hash(const struct AccountId_t *identifier);
+
+/**
+ * Returns a pointer to a new Python list of the identifier strings.
+ *
+ * # Safety
+ *
+ * - `account_ids` must have been returned by `account_registry_accounts`.
+ * - Assumes that since the data is originating from Rust, the GIL does not need
+ *   to be acquired.
+ * - Assumes you are immediately returning this pointer to Python.
+ */
+PyObject *account_ids_to_pylist(const CVec *account_ids);
+
+/**
+ * Drops a vec of identifiers returned by `account_registry_accounts`.
+ *
+ * # Safety
+ *
+ * - `account_ids` must have been returned by `account_registry_accounts`.
+ */
+void account_ids_drop(CVec account_ids);
+
+void account_registry_register(const struct AccountId_t *account_id, const struct Venue_t *venue);
+
+void account_registry_deregister(const struct AccountId_t *account_id);
+
+/**
+ * Returns 1 and writes the venue owning the account to `out` if registered,
+ * otherwise returns 0.
+ *
+ * # Safety
+ *
+ * - `out` must be a valid pointer to write the venue to.
+ */
+uint8_t account_registry_venue(const struct AccountId_t *account_id, struct Venue_t *out);
+
+/**
+ * Returns a vec of the accounts registered for the venue, to be released with
+ * `account_ids_drop`.
+ */
+CVec account_registry_accounts(const struct Venue_t *venue);
+
+void client_id_free(struct ClientId_t identifier);
+
+/**
+ * Returns a Nautilus identifier from a valid Python object pointer.
+ *
+ * # Safety
+ *
+ * - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+ */
+struct ClientId_t client_id_from_pystr(PyObject *ptr);
+
+/**
+ * Returns zero and writes a Nautilus identifier to `out` from a valid Python
+ * object pointer, otherwise returns the non-zero `IdentifierError` code.
+ *
+ * # Safety
+ *
+ * - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+ * - `out` must be a valid pointer to uninitialized memory to write the
+ *   identifier to, as any value already there is not dropped.
+ */
+uint8_t client_id_try_from_pystr(PyObject *ptr, struct ClientId_t *out);
+
+/**
+ * Returns a pointer to a valid Python UTF-8 string.
+ *
+ * # Safety
+ *
+ * - Assumes that since the data is originating from Rust, the GIL does not need
+ *   to be acquired.
+ * - Assumes you are immediately returning this pointer to Python.
+ */
+PyObject *client_id_to_pystr(const struct ClientId_t *identifier);
+
+/**
+ * Returns a view of the value bytes (UTF-8, not NUL terminated), valid for
+ * the lifetime of the identifier, without allocating.
+ */
+struct StrView client_id_as_bytes(const struct ClientId_t *identifier);
+
+/**
+ * Returns the hash of the value, precomputed when interned, for the Python
+ * `__hash__`.
+ */
+uint64_t client_id_hash(const struct ClientId_t *identifier);
+
+/**
+ * Returns 1 if the client IDs are equal, otherwise 0.
+ */
+uint8_t client_id_eq(const struct ClientId_t *lhs, const struct ClientId_t *rhs);
+
+void client_order_id_free(struct ClientOrderId_t identifier);
+
+/**
+ * Returns a Nautilus identifier from a valid Python object pointer.
+ *
+ * # Safety
+ *
+ * - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+ */
+struct ClientOrderId_t client_order_id_from_pystr(PyObject *ptr);
+
+/**
+ * Returns zero and writes a Nautilus identifier to `out` from a valid Python
+ * object pointer, otherwise returns the non-zero `IdentifierError` code.
+ *
+ * # Safety
+ *
+ * - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+ * - `out` must be a valid pointer to uninitialized memory to write the
+ *   identifier to, as any value already there is not dropped.
+ */
+uint8_t client_order_id_try_from_pystr(PyObject *ptr, struct ClientOrderId_t *out);
+
+/**
+ * Returns a pointer to a valid Python UTF-8 string.
+ *
+ * # Safety
+ *
+ * - Assumes that since the data is originating from Rust, the GIL does not need
+ *   to be acquired.
+ * - Assumes you are immediately returning this pointer to Python.
+ */
+PyObject *client_order_id_to_pystr(const struct ClientOrderId_t *identifier);
+
+/**
+ * Returns a view of the value bytes (UTF-8, not NUL terminated), valid for
+ * the lifetime of the identifier, without allocating.
+ */
+struct StrView client_order_id_as_bytes(const struct ClientOrderId_t *identifier);
+
+/**
+ * Returns the hash of the value, precomputed when interned, for the Python
+ * `__hash__`.
+ */
+uint64_t client_order_id_hash(const struct ClientOrderId_t *identifier);
+
+void client_order_link_id_free(struct ClientOrderLinkId_t identifier);
+
+/**
+ * Returns a Nautilus identifier from a valid Python object pointer.
+ *
+ * # Safety
+ *
+ * - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+ */
+struct ClientOrderLinkId_t client_order_link_id_from_pystr(PyObject *ptr);
+
+/**
+ * Returns zero and writes a Nautilus identifier to `out` from a valid Python
+ * object pointer, otherwise returns the non-zero `IdentifierError` code.
+ *
+ * # Safety
+ *
+ * - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+ * - `out` must be a valid pointer to uninitialized memory to write the
+ *   identifier to, as any value already there is not dropped.
+ */
+uint8_t client_order_link_id_try_from_pystr(PyObject *ptr, struct ClientOrderLinkId_t *out);
+
+/**
+ * Returns a pointer to a valid Python UTF-8 string.
+ *
+ * # Safety
+ *
+ * - Assumes that since the data is originating from Rust, the GIL does not need
+ *   to be acquired.
+ * - Assumes you are immediately returning this pointer to Python.
+ */
+PyObject *client_order_link_id_to_pystr(const struct ClientOrderLinkId_t *identifier);
+
+/**
+ * Returns a view of the value bytes (UTF-8, not NUL terminated), valid for
+ * the lifetime of the identifier, without allocating.
+ */
+struct StrView client_order_link_id_as_bytes(const struct ClientOrderLinkId_t *identifier);
+
+/**
+ * Returns the hash of the value, precomputed when interned, for the Python
+ * `__hash__`.
+ */
+uint64_t client_order_link_id_hash(const struct ClientOrderLinkId_t *identifier);
+
+void component_id_free(struct ComponentId_t identifier);
+
+/**
+ * Returns a Nautilus identifier from a valid Python object pointer.
+ *
+ * # Safety
+ *
+ * - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+ */
+struct ComponentId_t component_id_from_pystr(PyObject *ptr);
+
+/**
+ * Returns zero and writes a Nautilus identifier to `out` from a valid Python
+ * object pointer, otherwise returns the non-zero `IdentifierError` code.
+ *
+ * # Safety
+ *
+ * - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+ * - `out` must be a valid pointer to uninitialized memory to write the
+ *   identifier to, as any value already there is not dropped.
+ */
+uint8_t component_id_try_from_pystr(PyObject *ptr, struct ComponentId_t *out);
+
+/**
+ * Returns a pointer to a valid Python UTF-8 string.
+ *
+ * # Safety
+ *
+ * - Assumes that since the data is originating from Rust, the GIL does not need
+ *   to be acquired.
+ * - Assumes you are immediately returning this pointer to Python.
+ */
+PyObject *component_id_to_pystr(const struct ComponentId_t *identifier);
+
+/**
+ * Returns a view of the value bytes (UTF-8, not NUL terminated), valid for
+ * the lifetime of the identifier, without allocating.
+ */
+struct StrView component_id_as_bytes(const struct ComponentId_t *identifier);
+
+/**
+ * Returns the hash of the value, precomputed when interned, for the Python
+ * `__hash__`.
+ */
+uint64_t component_id_hash(const struct ComponentId_t *identifier);
+
+/**
+ * Returns 1 and writes the parent component to `out` if there is one,
+ * otherwise returns 0.
+ *
+ * # Safety
+ *
+ * - `out` must be a valid pointer to write the identifier to.
+ */
+uint8_t component_id_parent(const struct ComponentId_t *component_id, struct ComponentId_t *out);
+
+/**
+ * Returns a pointer to a valid Python UTF-8 string of the last part of the
+ * hierarchy.
+ *
+ * # Safety
+ *
+ * - Assumes that since the data is originating from Rust, the GIL does not need
+ *   to be acquired.
+ * - Assumes you are immediately returning this pointer to Python.
+ */
+PyObject *component_id_leaf_to_pystr(const struct ComponentId_t *component_id);
+
+uint8_t component_id_is_child_of(const struct ComponentId_t *component_id,
+                                 const struct ComponentId_t *other);
+
+void exec_algorithm_id_free(struct ExecAlgorithmId_t identifier);
+
+/**
+ * Returns a Nautilus identifier from a valid Python object pointer.
+ *
+ * # Safety
+ *
+ * - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+ */
+struct ExecAlgorithmId_t exec_algorithm_id_from_pystr(PyObject *ptr);
+
+/**
+ * Returns zero and writes a Nautilus identifier to `out` from a valid Python
+ * object pointer, otherwise returns the non-zero `IdentifierError` code.
+ *
+ * # Safety
+ *
+ * - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+ * - `out` must be a valid pointer to uninitialized memory to write the
+ *   identifier to, as any value already there is not dropped.
+ */
+uint8_t exec_algorithm_id_try_from_pystr(PyObject *ptr, struct ExecAlgorithmId_t *out);
+
+/**
+ * Returns a pointer to a valid Python UTF-8 string.
+ *
+ * # Safety
+ *
+ * - Assumes that since the data is originating from Rust, the GIL does not need
+ *   to be acquired.
+ * - Assumes you are immediately returning this pointer to Python.
+ */
+PyObject *exec_algorithm_id_to_pystr(const struct ExecAlgorithmId_t *identifier);
+
+/**
+ * Returns a view of the value bytes (UTF-8, not NUL terminated), valid for
+ * the lifetime of the identifier, without allocating.
+ */
+struct StrView exec_algorithm_id_as_bytes(const struct ExecAlgorithmId_t *identifier);
+
+/**
+ * Returns the hash of the value, precomputed when interned, for the Python
+ * `__hash__`.
+ */
+uint64_t exec_algorithm_id_hash(const struct ExecAlgorithmId_t *identifier);
+
+/**
+ * Returns 1 if the execution algorithm IDs are equal, otherwise 0.
+ */
+uint8_t exec_algorithm_id_eq(const struct ExecAlgorithmId_t *lhs,
+                             const struct ExecAlgorithmId_t *rhs);
+
+struct ClientOrderIdGenerator client_order_id_generator_new(struct TraderId_t trader_id,
+                                                            struct StrategyId_t strategy_id,
+                                                            uint64_t initial_count);
+
+void client_order_id_generator_free(struct ClientOrderIdGenerator generator);
+
+struct ClientOrderId_t client_order_id_generator_generate(struct ClientOrderIdGenerator *generator,
+                                                          uint64_t ts_now);
+
+uint64_t client_order_id_generator_count(const struct ClientOrderIdGenerator *generator);
+
+void client_order_id_generator_set_count(struct ClientOrderIdGenerator *generator, uint64_t count);
+
+void client_order_id_generator_reset(struct ClientOrderIdGenerator *generator);
+
+struct OrderListIdGenerator order_list_id_generator_new(struct TraderId_t trader_id,
+                                                        struct StrategyId_t strategy_id,
+                                                        uint64_t initial_count);
+
+void order_list_id_generator_free(struct OrderListIdGenerator generator);
+
+struct OrderListId_t order_list_id_generator_generate(struct OrderListIdGenerator *generator,
+                                                      uint64_t ts_now);
+
+uint64_t order_list_id_generator_count(const struct OrderListIdGenerator *generator);
+
+void order_list_id_generator_set_count(struct OrderListIdGenerator *generator, uint64_t count);
+
+void order_list_id_generator_reset(struct OrderListIdGenerator *generator);
+
+struct PositionIdGenerator position_id_generator_new(void);
+
+void position_id_generator_free(struct PositionIdGenerator generator);
+
+/**
+ * Returns the next position ID, where `flipped` is non-zero for a position
+ * opened by a flip.
+ */
+struct PositionId_t position_id_generator_generate(struct PositionIdGenerator *generator,
+                                                   const struct InstrumentId_t *instrument_id,
+                                                   uint64_t ts_now,
+                                                   uint8_t flipped);
+
+uint64_t position_id_generator_count(const struct PositionIdGenerator *generator,
+                                     const struct InstrumentId_t *instrument_id);
+
+void position_id_generator_set_count(struct PositionIdGenerator *generator,
+                                     const struct InstrumentId_t *instrument_id,
+                                     uint64_t count);
+
+void position_id_generator_reset(struct PositionIdGenerator *generator);
+
+/**
+ * Returns the handle for the symbol from a valid Python object pointer,
+ * registering it if new.
+ *
+ * # Safety
+ *
+ * - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+ */
+uint64_t symbol_intern(PyObject *ptr);
+
+/**
+ * Returns a pointer to a valid Python UTF-8 string of the symbol for the
+ * handle, or to `None` if not registered.
+ *
+ * # Safety
+ *
+ * - Assumes the GIL is held and you are immediately returning this pointer
+ *   to Python.
+ */
+PyObject *symbol_resolve(uint64_t handle);
+
+uint64_t symbol_to_handle(const struct Symbol_t *symbol);
+
+/**
+ * Writes the symbol for the handle to `out`, returning 1 if registered.
+ *
+ * # Safety
+ *
+ * - `out` must be a valid pointer to an initialized identifier, which is
+ *   dropped if overwritten.
+ */
+uint8_t symbol_from_handle(uint64_t handle, struct Symbol_t *out);
+
+/**
+ * Returns the handle for the venue from a valid Python object pointer,
+ * registering it if new.
+ *
+ * # Safety
+ *
+ * - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+ */
+uint64_t venue_intern(PyObject *ptr);
+
+/**
+ * Returns a pointer to a valid Python UTF-8 string of the venue for the
+ * handle, or to `None` if not registered.
+ *
+ * # Safety
+ *
+ * - Assumes the GIL is held and you are immediately returning this pointer
+ *   to Python.
+ */
+PyObject *venue_resolve(uint64_t handle);
+
+uint64_t venue_to_handle(const struct Venue_t *venue);
+
+/**
+ * Writes the venue for the handle to `out`, returning 1 if registered.
+ *
+ * # Safety
+ *
+ * - `out` must be a valid pointer to an initialized identifier, which is
+ *   dropped if overwritten.
+ */
+uint8_t venue_from_handle(uint64_t handle, struct Venue_t *out);
+
+/**
+ * Returns the handle for the instrument ID from a valid Python object pointer
+ * in the "SYMBOL.VENUE" dotted notation, registering it if new.
+ *
+ * # Safety
+ *
+ * - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+ */
+uint64_t instrument_id_intern(PyObject *ptr);
+
+/**
+ * Returns a pointer to a valid Python UTF-8 string of the instrument ID for
+ * the handle, or to `None` if not registered.
+ *
+ * # Safety
+ *
+ * - Assumes the GIL is held and you are immediately returning this pointer
+ *   to Python.
+ */
+PyObject *instrument_id_resolve(uint64_t handle);
+
+uint64_t instrument_id_to_handle(const struct InstrumentId_t *instrument_id);
+
+/**
+ * Writes the instrument ID for the handle to `out`, returning 1 if registered.
+ *
+ * # Safety
+ *
+ * - `out` must be a valid pointer to an initialized identifier, which is
+ *   dropped if overwritten.
+ */
+uint8_t instrument_id_from_handle(uint64_t handle, struct InstrumentId_t *out);
+
+void instrument_id_free(struct InstrumentId_t instrument_id);
+
+/**
+ * Returns a Nautilus identifier from valid Python object pointers.
+ *
+ * # Safety
+ *
+ * - `symbol_ptr` and `venue_ptr` must be borrowed from a valid Python UTF-8 `str`(s).
+ */
+struct InstrumentId_t instrument_id_from_pystrs(PyObject *symbol_ptr, PyObject *venue_ptr);
+
+/**
+ * Returns a Nautilus identifier from a valid Python object pointer in the
+ * "SYMBOL.VENUE" dotted notation.
+ *
+ * # Safety
+ *
+ * - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+ */
+struct InstrumentId_t instrument_id_from_pystr(PyObject *ptr);
+
+/**
+ * Returns zero and writes a Nautilus identifier to `out` from a valid Python
+ * object pointer in the "SYMBOL.VENUE" dotted notation, otherwise returns the
+ * non-zero `IdentifierError` code.
+ *
+ * # Safety
+ *
+ * - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+ * - `out` must be a valid pointer to uninitialized memory to write the
+ *   identifier to, as any value already there is not dropped.
+ */
+uint8_t instrument_id_try_from_pystr(PyObject *ptr, struct InstrumentId_t *out);
+
+/**
+ * Returns a pointer to a valid Python UTF-8 string in the "SYMBOL.VENUE"
+ * dotted notation.
+ *
+ * # Safety
+ *
+ * - Assumes that since the data is originating from Rust, the GIL does not need
+ *   to be acquired.
+ * - Assumes you are immediately returning this pointer to Python.
+ */
+PyObject *instrument_id_to_pystr(const struct InstrumentId_t *instrument_id);
+
+/**
+ * Returns a view of the symbol value bytes (UTF-8, not NUL terminated),
+ * valid for the lifetime of the identifier, without allocating.
+ */
+struct StrView instrument_id_symbol_as_bytes(const struct InstrumentId_t *instrument_id);
+
+/**
+ * Returns a view of the venue value bytes (UTF-8, not NUL terminated),
+ * valid for the lifetime of the identifier, without allocating.
+ */
+struct StrView instrument_id_venue_as_bytes(const struct InstrumentId_t *instrument_id);
+
+/**
+ * Returns the hash combining the symbol and venue hashes, precomputed when
+ * interned, for the Python `__hash__`.
+ */
+uint64_t instrument_id_hash(const struct InstrumentId_t *instrument_id);
+
+uint8_t instrument_id_eq_ignore_case(const struct InstrumentId_t *instrument_id,
+                                     const struct InstrumentId_t *other);
+
+/**
+ * Returns a clone of the identifier, e.g. an element of the vec returned by
+ * `instrument_ids_from_pylist` for ownership by Python.
+ */
+struct InstrumentId_t instrument_id_clone(const struct InstrumentId_t *instrument_id);
+
+/**
+ * Returns a vec of Nautilus identifiers from an array of `len` valid Python
+ * object pointers (e.g. the items of a list) in the "SYMBOL.VENUE" dotted
+ * notation, acquiring the GIL once for the batch. The vec must be passed to
+ * `instrument_ids_drop` to free it.
+ *
+ * # Safety
+ *
+ * - `ptrs` must point to `len` pointers borrowed from valid Python UTF-8 `str`s.
+ */
+CVec instrument_ids_from_pylist(PyObject *const *ptrs, uintptr_t len);
+
+/**
+ * Returns a pointer to a new Python list of the identifier strings in the
+ * "SYMBOL.VENUE" dotted notation.
+ *
+ * # Safety
+ *
+ * - `instrument_ids` must have been returned by `instrument_ids_from_pylist`.
+ * - Assumes that since the data is originating from Rust, the GIL does not need
+ *   to be acquired.
+ * - Assumes you are immediately returning this pointer to Python.
+ */
+PyObject *instrument_ids_to_pylist(const CVec *instrument_ids);
+
+/**
+ * Drops a vec of identifiers returned by `instrument_ids_from_pylist`.
+ *
+ * # Safety
+ *
+ * - `instrument_ids` must have been returned by `instrument_ids_from_pylist`.
+ */
+void instrument_ids_drop(CVec instrument_ids);
+
+void order_list_id_free(struct OrderListId_t identifier);
+
+/**
+ * Returns a Nautilus identifier from a valid Python object pointer.
+ *
+ * # Safety
+ *
+ * - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+ */
+struct OrderListId_t order_list_id_from_pystr(PyObject *ptr);
+
+/**
+ * Returns zero and writes a Nautilus identifier to `out` from a valid Python
+ * object pointer, otherwise returns the non-zero `IdentifierError` code.
+ *
+ * # Safety
+ *
+ * - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+ * - `out` must be a valid pointer to uninitialized memory to write the
+ *   identifier to, as any value already there is not dropped.
+ */
+uint8_t order_list_id_try_from_pystr(PyObject *ptr, struct OrderListId_t *out);
+
+/**
+ * Returns a pointer to a valid Python UTF-8 string.
+ *
+ * # Safety
+ *
+ * - Assumes that since the data is originating from Rust, the GIL does not need
+ *   to be acquired.
+ * - Assumes you are immediately returning this pointer to Python.
+ */
+PyObject *order_list_id_to_pystr(const struct OrderListId_t *identifier);
+
+/**
+ * Returns a view of the value bytes (UTF-8, not NUL terminated), valid for
+ * the lifetime of the identifier, without allocating.
+ */
+struct StrView order_list_id_as_bytes(const struct OrderListId_t *identifier);
+
+/**
+ * Returns the hash of the value, precomputed when interned, for the Python
+ * `__hash__`.
+ */
+uint64_t order_list_id_hash(const struct OrderListId_t *identifier);
+
+void position_id_free(struct PositionId_t identifier);
+
+/**
+ * Returns a Nautilus identifier from a valid Python object pointer.
+ *
+ * # Safety
+ *
+ * - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+ */
+struct PositionId_t position_id_from_pystr(PyObject *ptr);
+
+/**
+ * Returns zero and writes a Nautilus identifier to `out` from a valid Python
+ * object pointer, otherwise returns the non-zero `IdentifierError` code.
+ *
+ * # Safety
+ *
+ * - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+ * - `out` must be a valid pointer to uninitialized memory to write the
+ *   identifier to, as any value already there is not dropped.
+ */
+uint8_t position_id_try_from_pystr(PyObject *ptr, struct PositionId_t *out);
+
+/**
+ * Returns a pointer to a valid Python UTF-8 string.
+ *
+ * # Safety
+ *
+ * - Assumes that since the data is originating from Rust, the GIL does not need
+ *   to be acquired.
+ * - Assumes you are immediately returning this pointer to Python.
+ */
+PyObject *position_id_to_pystr(const struct PositionId_t *identifier);
+
+/**
+ * Returns a view of the value bytes (UTF-8, not NUL terminated), valid for
+ * the lifetime of the identifier, without allocating.
+ */
+struct StrView position_id_as_bytes(const struct PositionId_t *identifier);
+
+/**
+ * Returns the hash of the value, precomputed when interned, for the Python
+ * `__hash__`.
+ */
+uint64_t position_id_hash(const struct PositionId_t *identifier);
+
+void strategy_id_free(struct StrategyId_t identifier);
+
+/**
+ * Returns a Nautilus identifier from a valid Python object pointer.
+ *
+ * # Safety
+ *
+ * - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+ */
+struct StrategyId_t strategy_id_from_pystr(PyObject *ptr);
+
+/**
+ * Returns zero and writes a Nautilus identifier to `out` from a valid Python
+ * object pointer, otherwise returns the non-zero `IdentifierError` code.
+ *
+ * # Safety
+ *
+ * - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+ * - `out` must be a valid pointer to uninitialized memory to write the
+ *   identifier to, as any value already there is not dropped.
+ */
+uint8_t strategy_id_try_from_pystr(PyObject *ptr, struct StrategyId_t *out);
+
+/**
+ * Returns a pointer to a valid Python UTF-8 string.
+ *
+ * # Safety
+ *
+ * - Assumes that since the data is originating from Rust, the GIL does not need
+ *   to be acquired.
+ * - Assumes you are immediately returning this pointer to Python.
+ */
+PyObject *strategy_id_to_pystr(const struct StrategyId_t *identifier);
+
+/**
+ * Returns a view of the value bytes (UTF-8, not NUL terminated), valid for
+ * the lifetime of the identifier, without allocating.
+ */
+struct StrView strategy_id_as_bytes(const struct StrategyId_t *identifier);
+
+/**
+ * Returns the hash of the value, precomputed when interned, for the Python
+ * `__hash__`.
+ */
+uint64_t strategy_id_hash(const struct StrategyId_t *identifier);
+
+/**
+ * Returns a pointer to a valid Python UTF-8 string of the name part.
+ *
+ * # Safety
+ *
+ * - Assumes that since the data is originating from Rust, the GIL does not need
+ *   to be acquired.
+ * - Assumes you are immediately returning this pointer to Python.
+ */
+PyObject *strategy_id_name_to_pystr(const struct StrategyId_t *strategy_id);
+
+/**
+ * Returns a pointer to a valid Python UTF-8 string of the tag part.
+ *
+ * # Safety
+ *
+ * - Assumes that since the data is originating from Rust, the GIL does not need
+ *   to be acquired.
+ * - Assumes you are immediately returning this pointer to Python.
+ */
+PyObject *strategy_id_tag_to_pystr(const struct StrategyId_t *strategy_id);
+
+void subscription_pattern_free(struct SubscriptionPattern pattern);
+
+/**
+ * Returns a `SubscriptionPattern` from a valid Python object pointer.
+ *
+ * # Safety
+ *
+ * - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+ */
+struct SubscriptionPattern subscription_pattern_from_pystr(PyObject *ptr);
+
+/**
+ * Returns a pointer to a valid Python UTF-8 string.
+ *
+ * # Safety
+ *
+ * - Assumes that since the data is originating from Rust, the GIL does not need
+ *   to be acquired.
+ * - Assumes you are immediately returning this pointer to Python.
+ */
+PyObject *subscription_pattern_to_pystr(const struct SubscriptionPattern *pattern);
+
+uint8_t subscription_pattern_matches(const struct SubscriptionPattern *pattern,
+                                     const struct InstrumentId_t *instrument_id);
+
+void symbol_free(struct Symbol_t identifier);
+
+/**
+ * Returns a Nautilus identifier from a valid Python object pointer.
+ *
+ * # Safety
+ *
+ * - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+ */
+struct Symbol_t symbol_from_pystr(PyObject *ptr);
+
+/**
+ * Returns zero and writes a Nautilus identifier to `out` from a valid Python
+ * object pointer, otherwise returns the non-zero `IdentifierError` code.
+ *
+ * # Safety
+ *
+ * - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+ * - `out` must be a valid pointer to uninitialized memory to write the
+ *   identifier to, as any value already there is not dropped.
+ */
+uint8_t symbol_try_from_pystr(PyObject *ptr, struct Symbol_t *out);
+
+/**
+ * Returns a pointer to a valid Python UTF-8 string.
+ *
+ * # Safety
+ *
+ * - Assumes that since the data is originating from Rust, the GIL does not need
+ *   to be acquired.
+ * - Assumes you are immediately returning this pointer to Python.
+ */
+PyObject *symbol_to_pystr(const struct Symbol_t *identifier);
+
+/**
+ * Returns a view of the value bytes (UTF-8, not NUL terminated), valid for
+ * the lifetime of the identifier, without allocating.
+ */
+struct StrView symbol_as_bytes(const struct Symbol_t *identifier);
+
+/**
+ * Returns the hash of the value, precomputed when interned, for the Python
+ * `__hash__`.
+ */
+uint64_t symbol_hash(const struct Symbol_t *identifier);
+
+/**
+ * Returns a Nautilus identifier from a valid Python object pointer, with the
+ * value in canonical (uppercased) form.
+ *
+ * # Safety
+ *
+ * - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+ */
+struct Symbol_t symbol_canonical_from_pystr(PyObject *ptr);
+
+uint8_t symbol_eq_ignore_case(const struct Symbol_t *symbol, const struct Symbol_t *other);
+
+/**
+ * Returns a clone of the identifier, e.g. an element of the vec returned by
+ * `symbols_from_pylist` for ownership by Python.
+ */
+struct Symbol_t symbol_clone(const struct Symbol_t *symbol);
+
+/**
+ * Returns a vec of Nautilus identifiers from an array of `len` valid Python
+ * object pointers (e.g. the items of a list), acquiring the GIL once for
+ * the batch. The vec must be passed to `symbols_drop` to free it.
+ *
+ * # Safety
+ *
+ * - `ptrs` must point to `len` pointers borrowed from valid Python UTF-8 `str`s.
+ */
+CVec symbols_from_pylist(PyObject *const *ptrs, uintptr_t len);
+
+/**
+ * Returns a pointer to a new Python list of the identifier strings.
+ *
+ * # Safety
+ *
+ * - `symbols` must have been returned by `symbols_from_pylist`.
+ * - Assumes that since the data is originating from Rust, the GIL does not need
+ *   to be acquired.
+ * - Assumes you are immediately returning this pointer to Python.
+ */
+PyObject *symbols_to_pylist(const CVec *symbols);
+
+/**
+ * Drops a vec of identifiers returned by `symbols_from_pylist`.
+ *
+ * # Safety
+ *
+ * - `symbols` must have been returned by `symbols_from_pylist`.
+ */
+void symbols_drop(CVec symbols);
+
+/**
+ * Returns a Nautilus identifier normalized from a valid Python object pointer,
+ * with the default separators mapped to the `separator` character code (or
+ * removed if zero), and the comma separated suffixes and quote assets.
+ *
+ * # Safety
+ *
+ * - `ptr`, `strip_suffixes_ptr` and `quote_assets_ptr` must be borrowed from
+ *   valid Python UTF-8 `str`s.
+ */
+struct Symbol_t symbol_normalize(PyObject *ptr,
+                                 uint8_t uppercase,
+                                 uint32_t separator,
+                                 PyObject *strip_suffixes_ptr,
+                                 PyObject *quote_assets_ptr);
+
+void trade_id_free(struct TradeId_t identifier);
+
+/**
+ * Returns a Nautilus identifier from a valid Python object pointer.
+ *
+ * # Safety
+ *
+ * - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+ */
+struct TradeId_t trade_id_from_pystr(PyObject *ptr);
+
+/**
+ * Returns zero and writes a Nautilus identifier to `out` from a valid Python
+ * object pointer, otherwise returns the non-zero `IdentifierError` code.
+ *
+ * # Safety
+ *
+ * - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+ * - `out` must be a valid pointer to uninitialized memory to write the
+ *   identifier to, as any value already there is not dropped.
+ */
+uint8_t trade_id_try_from_pystr(PyObject *ptr, struct TradeId_t *out);
+
+/**
+ * Returns a pointer to a valid Python UTF-8 string.
+ *
+ * # Safety
+ *
+ * - Assumes that since the data is originating from Rust, the GIL does not need
+ *   to be acquired.
+ * - Assumes you are immediately returning this pointer to Python.
+ */
+PyObject *trade_id_to_pystr(const struct TradeId_t *identifier);
+
+/**
+ * Returns a view of the value bytes (UTF-8, not NUL terminated), valid for
+ * the lifetime of the identifier, without allocating.
+ */
+struct StrView trade_id_as_bytes(const struct TradeId_t *identifier);
+
+/**
+ * Returns the hash of the value, precomputed when interned, for the Python
+ * `__hash__`.
+ */
+uint64_t trade_id_hash(const struct TradeId_t *identifier);
+
+void trader_id_free(struct TraderId_t identifier);
+
+/**
+ * Returns a Nautilus identifier from a valid Python object pointer.
+ *
+ * # Safety
+ *
+ * - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+ */
+struct TraderId_t trader_id_from_pystr(PyObject *ptr);
+
+/**
+ * Returns zero and writes a Nautilus identifier to `out` from a valid Python
+ * object pointer, otherwise returns the non-zero `IdentifierError` code.
+ *
+ * # Safety
+ *
+ * - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+ * - `out` must be a valid pointer to uninitialized memory to write the
+ *   identifier to, as any value already there is not dropped.
+ */
+uint8_t trader_id_try_from_pystr(PyObject *ptr, struct TraderId_t *out);
+
+/**
+ * Returns a pointer to a valid Python UTF-8 string.
+ *
+ * # Safety
+ *
+ * - Assumes that since the data is originating from Rust, the GIL does not need
+ *   to be acquired.
+ * - Assumes you are immediately returning this pointer to Python.
+ */
+PyObject *trader_id_to_pystr(const struct TraderId_t *identifier);
+
+/**
+ * Returns a view of the value bytes (UTF-8, not NUL terminated), valid for
+ * the lifetime of the identifier, without allocating.
+ */
+struct StrView trader_id_as_bytes(const struct TraderId_t *identifier);
+
+/**
+ * Returns the hash of the value, precomputed when interned, for the Python
+ * `__hash__`.
+ */
+uint64_t trader_id_hash(const struct TraderId_t *identifier);
+
+/**
+ * Returns a pointer to a valid Python UTF-8 string of the name part.
+ *
+ * # Safety
+ *
+ * - Assumes that since the data is originating from Rust, the GIL does not need
+ *   to be acquired.
+ * - Assumes you are immediately returning this pointer to Python.
+ */
+PyObject *trader_id_name_to_pystr(const struct TraderId_t *trader_id);
+
+/**
+ * Returns a pointer to a valid Python UTF-8 string of the tag part.
+ *
+ * # Safety
+ *
+ * - Assumes that since the data is originating from Rust, the GIL does not need
+ *   to be acquired.
+ * - Assumes you are immediately returning this pointer to Python.
+ */
+PyObject *trader_id_tag_to_pystr(const struct TraderId_t *trader_id);
+
+void venue_free(struct Venue_t identifier);
+
+/**
+ * Returns a Nautilus identifier from a valid Python object pointer.
+ *
+ * # Safety
+ *
+ * - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+ */
+struct Venue_t venue_from_pystr(PyObject *ptr);
+
+/**
+ * Returns zero and writes a Nautilus identifier to `out` from a valid Python
+ * object pointer, otherwise returns the non-zero `IdentifierError` code.
+ *
+ * # Safety
+ *
+ * - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+ * - `out` must be a valid pointer to uninitialized memory to write the
+ *   identifier to, as any value already there is not dropped.
+ */
+uint8_t venue_try_from_pystr(PyObject *ptr, struct Venue_t *out);
+
+/**
+ * Returns a pointer to a valid Python UTF-8 string.
+ *
+ * # Safety
+ *
+ * - Assumes that since the data is originating from Rust, the GIL does not need
+ *   to be acquired.
+ * - Assumes you are immediately returning this pointer to Python.
+ */
+PyObject *venue_to_pystr(const struct Venue_t *identifier);
+
+/**
+ * Returns a view of the value bytes (UTF-8, not NUL terminated), valid for
+ * the lifetime of the identifier, without allocating.
+ */
+struct StrView venue_as_bytes(const struct Venue_t *identifier);
+
+/**
+ * Returns the hash of the value, precomputed when interned, for the Python
+ * `__hash__`.
+ */
+uint64_t venue_hash(const struct Venue_t *identifier);
+
+/**
+ * Returns a Nautilus identifier from a valid Python object pointer, with the
+ * value in canonical form.
+ *
+ * # Safety
+ *
+ * - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+ */
+struct Venue_t venue_canonical_from_pystr(PyObject *ptr);
+
+uint8_t venue_eq_ignore_case(const struct Venue_t *venue, const struct Venue_t *other);
+
+uint8_t venue_is_synthetic(const struct Venue_t *venue);
+
+/**
+ * Returns a clone of the identifier, e.g. an element of the vec returned by
+ * `venues_from_pylist` for ownership by Python.
+ */
+struct Venue_t venue_clone(const struct Venue_t *venue);
+
+/**
+ * Returns a vec of Nautilus identifiers from an array of `len` valid Python
+ * object pointers (e.g. the items of a list), acquiring the GIL once for
+ * the batch. The vec must be passed to `venues_drop` to free it.
+ *
+ * # Safety
+ *
+ * - `ptrs` must point to `len` pointers borrowed from valid Python UTF-8 `str`s.
+ */
+CVec venues_from_pylist(PyObject *const *ptrs, uintptr_t len);
+
+/**
+ * Returns a pointer to a new Python list of the identifier strings.
+ *
+ * # Safety
+ *
+ * - `venues` must have been returned by `venues_from_pylist`.
+ * - Assumes that since the data is originating from Rust, the GIL does not need
+ *   to be acquired.
+ * - Assumes you are immediately returning this pointer to Python.
+ */
+PyObject *venues_to_pylist(const CVec *venues);
+
+/**
+ * Drops a vec of identifiers returned by `venues_from_pylist`.
+ *
+ * # Safety
+ *
+ * - `venues` must have been returned by `venues_from_pylist`.
+ */
+void venues_drop(CVec venues);
+
+void venue_order_id_free(struct VenueOrderId_t identifier);
+
+/**
+ * Returns a Nautilus identifier from a valid Python object pointer.
+ *
+ * # Safety
+ *
+ * - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+ */
+struct VenueOrderId_t venue_order_id_from_pystr(PyObject *ptr);
+
+/**
+ * Returns zero and writes a Nautilus identifier to `out` from a valid Python
+ * object pointer, otherwise returns the non-zero `IdentifierError` code.
+ *
+ * # Safety
+ *
+ * - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+ * - `out` must be a valid pointer to uninitialized memory to write the
+ *   identifier to, as any value already there is not dropped.
+ */
+uint8_t venue_order_id_try_from_pystr(PyObject *ptr, struct VenueOrderId_t *out);
+
+/**
+ * Returns a pointer to a valid Python UTF-8 string.
+ *
+ * # Safety
+ *
+ * - Assumes that since the data is originating from Rust, the GIL does not need
+ *   to be acquired.
+ * - Assumes you are immediately returning this pointer to Python.
+ */
+PyObject *venue_order_id_to_pystr(const struct VenueOrderId_t *identifier);
+
+/**
+ * Returns a view of the value bytes (UTF-8, not NUL terminated), valid for
+ * the lifetime of the identifier, without allocating.
+ */
+struct StrView venue_order_id_as_bytes(const struct VenueOrderId_t *identifier);
+
+/**
+ * Returns the hash of the value, precomputed when interned, for the Python
+ * `__hash__`.
+ */
+uint64_t venue_order_id_hash(const struct VenueOrderId_t *identifier);
+
+struct OrderBook order_book_new(struct InstrumentId_t instrument_id, enum BookLevel book_level);
+
+struct QueueEstimator queue_estimator_new(uint64_t half_life_ns);
+
+void queue_estimator_on_trade(struct QueueEstimator *estimator, double size, int64_t ts_event);
+
+void queue_estimator_on_cancel(struct QueueEstimator *estimator, double size, int64_t ts_event);
+
+struct QueueEstimate queue_estimator_estimate(const struct QueueEstimator *estimator,
+                                              const struct OrderBook *book,
+                                              enum OrderSide side,
+                                              const struct Price_t *price,
+                                              const struct Quantity_t *quantity,
+                                              double horizon_secs,
+                                              int64_t ts);
+
+void session_stats_record_trade(const struct TradeTick_t *tick);
+
+void session_stats_start(const struct InstrumentId_t *instrument_id, int64_t ts_start);
+
+/**
+ * Returns the session statistics for the instrument (all zero if no session
+ * has been started).
+ */
+struct SessionStats session_stats_get(const struct InstrumentId_t *instrument_id);
+
+void session_stats_reset(void);
+
+/**
+ * Records a fill for the instrument, measuring slippage against the expected
+ * price unless `expected_px` is null.
+ *
+ * # Safety
+ *
+ * - `expected_px` must be null or a valid pointer to a `Price`.
+ */
+void trading_stats_record_fill(const struct InstrumentId_t *instrument_id,
+                               enum OrderSide side,
+                               const struct Quantity_t *last_qty,
+                               const struct Price_t *last_px,
+                               double commission,
+                               const struct Price_t *expected_px);
+
+void trading_stats_record_round_trip(const struct InstrumentId_t *instrument_id,
+                                     double realized_pnl);
+
+/**
+ * Returns the trading statistics for the instrument (all zero if nothing has
+ * been recorded).
+ */
+struct TradingStats trading_stats_get(const struct InstrumentId_t *instrument_id);
+
+void trading_stats_reset(void);
+
+/**
+ * Returns zero after loading the mappings from the file at the path into the
+ * process wide symbology, otherwise the non-zero error code (1 conflict,
+ * 2 parse, 3 I/O).
+ *
+ * # Safety
+ *
+ * - `path_ptr` must be borrowed from a valid Python UTF-8 `str`.
+ */
+uint8_t symbology_load_file(PyObject *path_ptr);
+
+/**
+ * Returns zero after mapping the instrument to the code in the scheme,
+ * otherwise the non-zero error code (1 conflict).
+ *
+ * # Safety
+ *
+ * - `scheme_ptr` and `code_ptr` must be borrowed from valid Python UTF-8 `str`(s).
+ */
+uint8_t symbology_insert(const struct InstrumentId_t *instrument_id,
+                         PyObject *scheme_ptr,
+                         PyObject *code_ptr);
+
+/**
+ * Writes the instrument ID for the code in the scheme to `out`, returning 1
+ * if mapped (otherwise 0 and `out` is unchanged).
+ *
+ * # Safety
+ *
+ * - `scheme_ptr` and `code_ptr` must be borrowed from valid Python UTF-8 `str`(s).
+ * - `out` must be a valid pointer to an initialized `InstrumentId`.
+ */
+uint8_t symbology_instrument_id(PyObject *scheme_ptr,
+                                PyObject *code_ptr,
+                                struct InstrumentId_t *out);
+
+/**
+ * Returns the Python UTF-8 string of the code in the scheme for the
+ * instrument ID, or a new reference to `None` if not mapped.
+ *
+ * # Safety
+ *
+ * - `scheme_ptr` must be borrowed from a valid Python UTF-8 `str`.
+ * - Assumes the GIL is held, as when called from Python.
+ */
+PyObject *symbology_code_to_pystr(const struct InstrumentId_t *instrument_id, PyObject *scheme_ptr);
+
+void symbology_clear(void);
+
+void conversion_audit_set_enabled(uint8_t enabled);
+
+uint8_t conversion_audit_is_enabled(void);
+
+void conversion_audit_reset(void);
+
+/**
+ * Returns the number of lossy conversions recorded for the component.
+ *
+ * # Safety
+ *
+ * - `component` must be borrowed from a valid Python UTF-8 `str`.
+ */
+uint64_t conversion_audit_count(PyObject *component);
+
+/**
+ * Returns a pointer to a new Python dict of the lossy conversion counts keyed
+ * by component.
+ *
+ * # Safety
+ *
+ * - Assumes that since the data is originating from Rust, the GIL does not need
+ *   to be acquired.
+ * - Assumes you are immediately returning this pointer to Python.
+ */
+PyObject *conversion_audit_counts_to_pydict(void);
+
+/**
+ * Returns zero and writes the validated balance to `out`, otherwise returns
+ * the non-zero `BalanceError` code.
+ *
+ * # Safety
+ *
+ * - `out` must be a valid pointer to write the value to.
+ */
+uint8_t account_balance_new(struct Money_t total,
+                            struct Money_t locked,
+                            struct Money_t free,
+                            struct AccountBalance_t *out);
+
+void account_balance_free(struct AccountBalance_t balance);
+
+struct Currency_t account_balance_get_currency(const struct AccountBalance_t *balance);
+
+struct Money_t account_balance_get_total(const struct AccountBalance_t *balance);
+
+struct Money_t account_balance_get_locked(const struct AccountBalance_t *balance);
+
+struct Money_t account_balance_get_free(const struct AccountBalance_t *balance);
+
+uint8_t account_balance_eq(const struct AccountBalance_t *a, const struct AccountBalance_t *b);
+
+/**
+ * Returns zero and writes the validated margin balance to `out`, otherwise
+ * returns the non-zero `BalanceError` code.
+ *
+ * # Safety
+ *
+ * - `out` must be a valid pointer to write the value to.
+ */
+uint8_t margin_balance_new(struct Money_t initial,
+                           struct Money_t maintenance,
+                           const struct InstrumentId_t *instrument_id,
+                           struct MarginBalance_t *out);
+
+void margin_balance_free(struct MarginBalance_t balance);
+
+struct Currency_t margin_balance_get_currency(const struct MarginBalance_t *balance);
+
+struct Money_t margin_balance_get_initial(const struct MarginBalance_t *balance);
+
+struct Money_t margin_balance_get_maintenance(const struct MarginBalance_t *balance);
+
+/**
+ * Returns the instrument ID of the margin balance (null if account wide),
+ * borrowed for the lifetime of the balance.
+ */
+const struct InstrumentId_t *margin_balance_get_instrument_id(const struct MarginBalance_t *balance);
+
+uint8_t margin_balance_eq(const struct MarginBalance_t *a, const struct MarginBalance_t *b);
+
+/**
+ * Returns a `Currency` from valid Python object pointers and primitives.
+ *
+ * # Safety
+ *
+ * - `code_ptr` and `name_ptr` must be borrowed from a valid Python UTF-8 `str`(s).
+ */
+struct Currency_t currency_from_py(PyObject *code_ptr,
+                                   uint8_t precision,
+                                   uint16_t iso4217,
+                                   PyObject *name_ptr,
+                                   enum CurrencyType currency_type);
+
+/**
+ * Returns a pointer to a valid Python UTF-8 string.
+ *
+ * # Safety
+ *
+ * - Assumes that since the data is originating from Rust, the GIL does not need
+ *   to be acquired.
+ * - Assumes you are immediately returning this pointer to Python.
+ */
+PyObject *currency_code_to_pystr(const struct Currency_t *currency);
+
+/**
+ * Returns a pointer to a valid Python UTF-8 string.
+ *
+ * # Safety
+ *
+ * - Assumes that since the data is originating from Rust, the GIL does not need
+ *   to be acquired.
+ * - Assumes you are immediately returning this pointer to Python.
+ */
+PyObject *currency_name_to_pystr(const struct Currency_t *currency);
+
+void currency_free(struct Currency_t currency);
+
+/**
+ * Returns 1 and writes the registered currency to `out` if found for the
+ * code, otherwise returns 0.
+ *
+ * # Safety
+ *
+ * - `code_ptr` must be borrowed from a valid Python UTF-8 `str`.
+ * - `out` must be a valid pointer to write the currency to.
+ */
+uint8_t currency_from_pystr(PyObject *code_ptr, struct Currency_t *out);
+
+/**
+ * Returns 1 if a currency is registered for the code, otherwise 0.
+ *
+ * # Safety
+ *
+ * - `code_ptr` must be borrowed from a valid Python UTF-8 `str`.
+ */
+uint8_t currency_exists(PyObject *code_ptr);
+
+/**
+ * Registers a currency from valid Python object pointers and primitives so
+ * subsequent lookups by code resolve it, returning 1 if it was registered or
+ * 0 if the code already existed and `overwrite` was 0.
+ *
+ * # Safety
+ *
+ * - `code_ptr` and `name_ptr` must be borrowed from a valid Python UTF-8 `str`(s).
+ */
+uint8_t currency_register(PyObject *code_ptr,
+                          uint8_t precision,
+                          uint16_t iso4217,
+                          PyObject *name_ptr,
+                          enum CurrencyType currency_type,
+                          uint8_t overwrite);
+
+uint64_t currency_hash(const struct Currency_t *currency);
+
+uint8_t currency_eq(const struct Currency_t *a, const struct Currency_t *b);
+
+struct NumberFormat number_format_default(void);
+
+/**
+ * Returns zero and writes the format of the locale to `out`, otherwise
+ * returns 1 if the locale is not supported.
+ *
+ * # Safety
+ *
+ * - `locale_ptr` must be borrowed from a valid Python UTF-8 `str`.
+ * - `out` must be a valid pointer to write the format to.
+ */
+uint8_t number_format_for_locale(PyObject *locale_ptr, struct NumberFormat *out);
+
+struct Money_t money_new(double amount, struct Currency_t currency);
 
-struct TradeTick_t trade_tick_from_raw(struct InstrumentId_t instrument_id,
-                                       int64_t price,
-                                       uint8_t price_prec,
-                                       uint64_t size,
-                                       uint8_t size_prec,
-                                       enum OrderSide aggressor_side,
-                                       struct TradeId_t trade_id,
-                                       int64_t ts_event,
-                                       int64_t ts_init);
+struct Money_t money_from_raw(int64_t raw, struct Currency_t currency);
+
+void money_free(struct Money_t money);
 
-void account_id_free(struct AccountId_t account_id);
+double money_as_f64(const struct Money_t *money);
+
+int64_t money_raw(const struct Money_t *money);
 
 /**
- * Returns a Nautilus identifier from a valid Python object pointer.
+ * Returns a pointer to a valid Python UTF-8 string of the currency code.
  *
  * # Safety
  *
- * - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+ * - Assumes that since the data is originating from Rust, the GIL does not need
+ *   to be acquired.
+ * - Assumes you are immediately returning this pointer to Python.
  */
-struct AccountId_t account_id_from_pystr(PyObject *ptr);
+PyObject *money_currency_code_to_pystr(const struct Money_t *money);
 
 /**
- * Returns a pointer to a valid Python UTF-8 string.
+ * Returns a pointer to a valid Python UTF-8 string of the formatted amount
+ * and currency code (e.g. "1_000.00 USD").
  *
  * # Safety
  *
  * - Assumes that since the data is originating from Rust, the GIL does not need
- * to be acquired.
+ *   to be acquired.
  * - Assumes you are immediately returning this pointer to Python.
  */
-PyObject *account_id_to_pystr(const struct AccountId_t *account_id);
-
-void client_id_free(struct ClientId_t client_id);
+PyObject *money_to_pystr(const struct Money_t *money);
 
 /**
- * Returns a Nautilus identifier from a valid Python object pointer.
+ * Returns a pointer to a valid Python UTF-8 string of the amount and currency
+ * code formatted by the format.
  *
  * # Safety
  *
- * - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+ * - Assumes that since the data is originating from Rust, the GIL does not need
+ *   to be acquired.
+ * - Assumes you are immediately returning this pointer to Python.
  */
-struct ClientId_t client_id_from_pystr(PyObject *ptr);
+PyObject *money_to_formatted_pystr(const struct Money_t *money, const struct NumberFormat *format);
+
+uint64_t money_hash(const struct Money_t *money);
+
+uint8_t money_eq(const struct Money_t *a, const struct Money_t *b);
 
-void client_order_id_free(struct ClientOrderId_t client_order_id);
+uint8_t money_ne(const struct Money_t *a, const struct Money_t *b);
 
 /**
- * Returns a Nautilus identifier from a valid Python object pointer.
+ * Returns zero and writes -1, 0 or 1 to `out` as `a` is less than, equal to
+ * or greater than `b`, otherwise returns the non-zero `FixedPointError` code
+ * if the currencies differ.
  *
  * # Safety
  *
- * - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+ * - `out` must be a valid pointer to write the value to.
  */
-struct ClientOrderId_t client_order_id_from_pystr(PyObject *ptr);
+uint8_t money_checked_cmp(const struct Money_t *a, const struct Money_t *b, int8_t *out);
 
-void client_order_link_id_free(struct ClientOrderLinkId_t client_order_link_id);
+/**
+ * Returns zero and writes the lesser of the values (`a` if equal) to `out`,
+ * otherwise returns the non-zero `FixedPointError` code if the currencies
+ * differ.
+ *
+ * # Safety
+ *
+ * - `out` must be a valid pointer to write the value to.
+ */
+uint8_t money_checked_min(const struct Money_t *a, const struct Money_t *b, struct Money_t *out);
 
 /**
- * Returns a Nautilus identifier from a valid Python object pointer.
+ * Returns zero and writes the greater of the values (`b` if equal) to `out`,
+ * otherwise returns the non-zero `FixedPointError` code if the currencies
+ * differ.
  *
  * # Safety
  *
- * - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+ * - `out` must be a valid pointer to write the value to.
  */
-struct ClientOrderLinkId_t client_order_link_id_from_pystr(PyObject *ptr);
+uint8_t money_checked_max(const struct Money_t *a, const struct Money_t *b, struct Money_t *out);
 
-void component_id_free(struct ComponentId_t component_id);
+/**
+ * Returns zero and writes `a + b` to `out`, otherwise returns the non-zero
+ * `FixedPointError` code if the currencies differ or the sum overflows.
+ *
+ * # Safety
+ *
+ * - `out` must be a valid pointer to write the value to.
+ */
+uint8_t money_checked_add(const struct Money_t *a, const struct Money_t *b, struct Money_t *out);
 
 /**
- * Returns a Nautilus identifier from a valid Python object pointer.
+ * Returns zero and writes `a - b` to `out`, otherwise returns the non-zero
+ * `FixedPointError` code if the currencies differ or the difference
+ * overflows.
  *
  * # Safety
  *
- * - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+ * - `out` must be a valid pointer to write the value to.
  */
-struct ComponentId_t component_id_from_pystr(PyObject *ptr);
+uint8_t money_checked_sub(const struct Money_t *a, const struct Money_t *b, struct Money_t *out);
 
-void instrument_id_free(struct InstrumentId_t instrument_id);
+/**
+ * Returns zero and writes `a * b` to `out`, otherwise returns the non-zero
+ * `FixedPointError` code if the currencies differ, or the product is not
+ * exact at the currency precision or overflows.
+ *
+ * # Safety
+ *
+ * - `out` must be a valid pointer to write the value to.
+ */
+uint8_t money_checked_mul(const struct Money_t *a, const struct Money_t *b, struct Money_t *out);
 
 /**
- * Returns a Nautilus identifier from valid Python object pointers.
+ * Returns zero and writes `-money` to `out`, otherwise returns the non-zero
+ * `FixedPointError` code if the negation overflows.
  *
  * # Safety
  *
- * - `symbol_ptr` and `venue_ptr` must be borrowed from a valid Python UTF-8 `str`(s).
+ * - `out` must be a valid pointer to write the value to.
  */
-struct InstrumentId_t instrument_id_from_pystrs(PyObject *symbol_ptr, PyObject *venue_ptr);
+uint8_t money_checked_neg(const struct Money_t *money, struct Money_t *out);
 
-void order_list_id_free(struct OrderListId_t order_list_id);
+void money_add_assign(struct Money_t *a, const struct Money_t *b);
+
+void money_sub_assign(struct Money_t *a, const struct Money_t *b);
 
 /**
- * Returns a Nautilus identifier from a valid Python object pointer.
+ * Returns zero and writes the notional value of the standard contracts to
+ * `out`, otherwise returns the non-zero `FixedPointError` code.
  *
  * # Safety
  *
- * - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+ * - `out` must be a valid pointer to write the value to.
  */
-struct OrderListId_t order_list_id_from_pystr(PyObject *ptr);
-
-void position_id_free(struct PositionId_t position_id);
+uint8_t notional_value_from_quantity(const struct Quantity_t *quantity,
+                                     const struct Price_t *price,
+                                     const struct Quantity_t *multiplier,
+                                     const struct Currency_t *currency,
+                                     struct Money_t *out);
 
 /**
- * Returns a Nautilus identifier from a valid Python object pointer.
+ * Returns zero and writes the notional value of the inverse contracts to
+ * `out`, otherwise returns the non-zero `FixedPointError` code.
  *
  * # Safety
  *
- * - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+ * - `out` must be a valid pointer to write the value to.
  */
-struct PositionId_t position_id_from_pystr(PyObject *ptr);
+uint8_t inverse_notional_value_from_quantity(const struct Quantity_t *quantity,
+                                             const struct Price_t *price,
+                                             const struct Quantity_t *multiplier,
+                                             const struct Currency_t *currency,
+                                             struct Money_t *out);
 
-void strategy_id_free(struct StrategyId_t strategy_id);
+/**
+ * Returns zero and writes the quantity of standard contracts for the
+ * notional to `out`, otherwise returns the non-zero `FixedPointError` code.
+ *
+ * # Safety
+ *
+ * - `out` must be a valid pointer to write the value to.
+ */
+uint8_t notional_value_to_quantity(const struct Money_t *notional,
+                                   const struct Price_t *price,
+                                   const struct Quantity_t *multiplier,
+                                   uint8_t precision,
+                                   enum RoundingMode mode,
+                                   struct Quantity_t *out);
 
 /**
- * Returns a Nautilus identifier from a valid Python object pointer.
+ * Returns zero and writes the quantity of inverse contracts for the notional
+ * to `out`, otherwise returns the non-zero `FixedPointError` code.
  *
  * # Safety
  *
- * - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+ * - `out` must be a valid pointer to write the value to.
  */
-struct StrategyId_t strategy_id_from_pystr(PyObject *ptr);
+uint8_t inverse_notional_value_to_quantity(const struct Money_t *notional,
+                                           const struct Price_t *price,
+                                           const struct Quantity_t *multiplier,
+                                           uint8_t precision,
+                                           enum RoundingMode mode,
+                                           struct Quantity_t *out);
 
-void symbol_free(struct Symbol_t symbol);
+/**
+ * Returns the value rounded to the precision, recording a lossy conversion
+ * for the `"Price"` component when auditing is enabled.
+ */
+struct Price_t price_new(double value, uint8_t precision);
 
 /**
- * Returns a Nautilus identifier from a valid Python object pointer.
+ * Returns the value rounded to the precision, recording a lossy conversion
+ * for the component when auditing is enabled.
  *
  * # Safety
  *
- * - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+ * - `component` must be borrowed from a valid Python UTF-8 `str`.
  */
-struct Symbol_t symbol_from_pystr(PyObject *ptr);
+struct Price_t price_new_audited(double value, uint8_t precision, PyObject *component);
 
 /**
- * Returns a pointer to a valid Python UTF-8 string.
+ * Returns zero and writes the value to `out` if exactly representable at the
+ * precision, otherwise returns the non-zero `FixedPointError` code.
  *
  * # Safety
  *
- * - Assumes that since the data is originating from Rust, the GIL does not need
- * to be acquired.
- * - Assumes you are immediately returning this pointer to Python.
+ * - `out` must be a valid pointer to write the value to.
  */
-PyObject *symbol_to_pystr(const struct Symbol_t *symbol);
+uint8_t price_from_f64_checked(double value, uint8_t precision, struct Price_t *out);
+
+struct Price_t price_from_raw(int64_t raw, uint8_t precision);
 
-void trade_id_free(struct TradeId_t trade_id);
+void price_free(struct Price_t price);
+
+double price_as_f64(const struct Price_t *price);
 
 /**
- * Returns a Nautilus identifier from a valid Python object pointer.
+ * Returns zero and writes the parsed value to `out` if the string is a
+ * decimal number exactly representable at its precision, otherwise returns
+ * the non-zero `FixedPointError` code.
  *
  * # Safety
  *
  * - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+ * - `out` must be a valid pointer to write the value to.
  */
-struct TradeId_t trade_id_from_pystr(PyObject *ptr);
+uint8_t price_from_pystr(PyObject *ptr, struct Price_t *out);
 
 /**
- * Returns a pointer to a valid Python UTF-8 string.
+ * Returns a pointer to a valid Python UTF-8 string of the value formatted at
+ * its precision.
+ *
+ * # Safety
+ *
+ * - Assumes that since the data is originating from Rust, the GIL does not need
+ *   to be acquired.
+ * - Assumes you are immediately returning this pointer to Python.
+ */
+PyObject *price_to_pystr(const struct Price_t *price);
+
+/**
+ * Returns a pointer to a valid Python UTF-8 string of the value formatted by
+ * the format.
  *
  * # Safety
  *
  * - Assumes that since the data is originating from Rust, the GIL does not need
- * to be acquired.
+ *   to be acquired.
  * - Assumes you are immediately returning this pointer to Python.
  */
-PyObject *trade_id_to_pystr(const struct TradeId_t *trade_id);
+PyObject *price_to_formatted_pystr(const struct Price_t *price, const struct NumberFormat *format);
 
-void trader_id_free(struct TraderId_t trader_id);
+uint64_t price_hash(const struct Price_t *price);
+
+uint8_t price_eq(const struct Price_t *a, const struct Price_t *b);
 
 /**
- * Returns a Nautilus identifier from a valid Python object pointer.
+ * Returns -1, 0 or 1 as `a` is less than, equal to or greater than `b`.
+ */
+int8_t price_cmp(const struct Price_t *a, const struct Price_t *b);
+
+uint8_t price_ne(const struct Price_t *a, const struct Price_t *b);
+
+uint8_t price_lt(const struct Price_t *a, const struct Price_t *b);
+
+uint8_t price_le(const struct Price_t *a, const struct Price_t *b);
+
+uint8_t price_gt(const struct Price_t *a, const struct Price_t *b);
+
+uint8_t price_ge(const struct Price_t *a, const struct Price_t *b);
+
+/**
+ * Returns the lesser of the values (`a` if equal).
+ */
+struct Price_t price_min(const struct Price_t *a, const struct Price_t *b);
+
+/**
+ * Returns the greater of the values (`b` if equal).
+ */
+struct Price_t price_max(const struct Price_t *a, const struct Price_t *b);
+
+struct Price_t price_add(const struct Price_t *a, const struct Price_t *b);
+
+struct Price_t price_sub(const struct Price_t *a, const struct Price_t *b);
+
+struct Price_t price_mul(const struct Price_t *a, const struct Price_t *b);
+
+struct Price_t price_neg(const struct Price_t *price);
+
+/**
+ * Returns zero and writes `a + b` to `out`, otherwise returns the non-zero
+ * `FixedPointError` code if the precisions differ or the sum overflows.
  *
  * # Safety
  *
- * - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+ * - `out` must be a valid pointer to write the value to.
  */
-struct TraderId_t trader_id_from_pystr(PyObject *ptr);
+uint8_t price_checked_add(const struct Price_t *a, const struct Price_t *b, struct Price_t *out);
 
-void venue_free(struct Venue_t venue);
+/**
+ * Returns zero and writes `a - b` to `out`, otherwise returns the non-zero
+ * `FixedPointError` code if the precisions differ or the difference
+ * overflows.
+ *
+ * # Safety
+ *
+ * - `out` must be a valid pointer to write the value to.
+ */
+uint8_t price_checked_sub(const struct Price_t *a, const struct Price_t *b, struct Price_t *out);
 
 /**
- * Returns a Nautilus identifier from a valid Python object pointer.
+ * Returns zero and writes `a * b` to `out`, otherwise returns the non-zero
+ * `FixedPointError` code if the product loses precision or overflows.
  *
  * # Safety
  *
- * - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+ * - `out` must be a valid pointer to write the value to.
  */
-struct Venue_t venue_from_pystr(PyObject *ptr);
+uint8_t price_checked_mul(const struct Price_t *a, const struct Price_t *b, struct Price_t *out);
 
 /**
- * Returns a pointer to a valid Python UTF-8 string.
+ * Returns zero and writes `-price` to `out`, otherwise returns the non-zero
+ * `FixedPointError` code if the negation overflows.
  *
  * # Safety
  *
- * - Assumes that since the data is originating from Rust, the GIL does not need
- * to be acquired.
- * - Assumes you are immediately returning this pointer to Python.
+ * - `out` must be a valid pointer to write the value to.
  */
-PyObject *venue_to_pystr(const struct Venue_t *venue);
+uint8_t price_checked_neg(const struct Price_t *price, struct Price_t *out);
+
+void price_add_assign(struct Price_t *a, const struct Price_t *b);
 
-void venue_order_id_free(struct VenueOrderId_t venue_order_id);
+void price_sub_assign(struct Price_t *a, const struct Price_t *b);
 
 /**
- * Returns a Nautilus identifier from a valid Python object pointer.
+ * Returns zero and writes the band from the percentages below and above the
+ * reference price to `out`, otherwise returns the non-zero `FixedPointError`
+ * code.
  *
  * # Safety
  *
- * - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+ * - `out` must be a valid pointer to write the value to.
  */
-struct VenueOrderId_t venue_order_id_from_pystr(PyObject *ptr);
+uint8_t price_band_from_percent(const struct Price_t *reference,
+                                double lower_pct,
+                                double upper_pct,
+                                struct PriceBand *out);
 
-struct OrderBook order_book_new(struct InstrumentId_t instrument_id, enum BookLevel book_level);
+/**
+ * Returns zero and writes the band from the absolute offsets below and above
+ * the reference price to `out`, otherwise returns the non-zero
+ * `FixedPointError` code.
+ *
+ * # Safety
+ *
+ * - `out` must be a valid pointer to write the value to.
+ */
+uint8_t price_band_from_offsets(const struct Price_t *reference,
+                                const struct Price_t *lower_offset,
+                                const struct Price_t *upper_offset,
+                                struct PriceBand *out);
 
 /**
- * Returns a `Currency` from valid Python object pointers and primitives.
+ * Returns -1 if the price is below the band, 1 if above, otherwise 0.
+ */
+int8_t price_band_cmp(const struct PriceBand *band, const struct Price_t *price);
+
+uint8_t price_band_contains(const struct PriceBand *band, const struct Price_t *price);
+
+struct Price_t price_band_clamp(const struct PriceBand *band, const struct Price_t *price);
+
+/**
+ * Returns the value rounded to the precision, recording a lossy conversion
+ * for the `"Quantity"` component when auditing is enabled.
+ */
+struct Quantity_t quantity_new(double value, uint8_t precision);
+
+/**
+ * Returns the value rounded to the precision, recording a lossy conversion
+ * for the component when auditing is enabled.
  *
  * # Safety
  *
- * - `code_ptr` and `name_ptr` must be borrowed from a valid Python UTF-8 `str`(s).
+ * - `component` must be borrowed from a valid Python UTF-8 `str`.
  */
-struct Currency_t currency_from_py(PyObject *code_ptr,
-                                   uint8_t precision,
-                                   uint16_t iso4217,
-                                   PyObject *name_ptr,
-                                   enum CurrencyType currency_type);
+struct Quantity_t quantity_new_audited(double value, uint8_t precision, PyObject *component);
 
 /**
- * Returns a pointer to a valid Python UTF-8 string.
+ * Returns zero and writes the value to `out` if exactly representable at the
+ * precision, otherwise returns the non-zero `FixedPointError` code.
  *
  * # Safety
  *
- * - Assumes that since the data is originating from Rust, the GIL does not need
- * to be acquired.
- * - Assumes you are immediately returning this pointer to Python.
+ * - `out` must be a valid pointer to write the value to.
  */
-PyObject *currency_code_to_pystr(const struct Currency_t *currency);
+uint8_t quantity_from_f64_checked(double value, uint8_t precision, struct Quantity_t *out);
+
+struct Quantity_t quantity_from_raw(uint64_t raw, uint8_t precision);
+
+void quantity_free(struct Quantity_t qty);
+
+double quantity_as_f64(const struct Quantity_t *qty);
 
 /**
- * Returns a pointer to a valid Python UTF-8 string.
+ * Returns zero and writes the parsed value to `out` if the string is a
+ * non-negative decimal number exactly representable at its precision,
+ * otherwise returns the non-zero `FixedPointError` code.
+ *
+ * # Safety
+ *
+ * - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+ * - `out` must be a valid pointer to write the value to.
+ */
+uint8_t quantity_from_pystr(PyObject *ptr, struct Quantity_t *out);
+
+/**
+ * Returns a pointer to a valid Python UTF-8 string of the value formatted at
+ * its precision.
  *
  * # Safety
  *
  * - Assumes that since the data is originating from Rust, the GIL does not need
- * to be acquired.
+ *   to be acquired.
  * - Assumes you are immediately returning this pointer to Python.
  */
-PyObject *currency_name_to_pystr(const struct Currency_t *currency);
+PyObject *quantity_to_pystr(const struct Quantity_t *qty);
 
-void currency_free(struct Currency_t currency);
+uint64_t quantity_hash(const struct Quantity_t *qty);
 
-struct Money_t money_new(double amount, struct Currency_t currency);
+uint8_t quantity_eq(const struct Quantity_t *a, const struct Quantity_t *b);
 
-struct Money_t money_from_raw(int64_t raw, struct Currency_t currency);
+/**
+ * Returns -1, 0 or 1 as `a` is less than, equal to or greater than `b`.
+ */
+int8_t quantity_cmp(const struct Quantity_t *a, const struct Quantity_t *b);
 
-void money_free(struct Money_t money);
+uint8_t quantity_ne(const struct Quantity_t *a, const struct Quantity_t *b);
 
-double money_as_f64(const struct Money_t *money);
+uint8_t quantity_lt(const struct Quantity_t *a, const struct Quantity_t *b);
 
-void money_add_assign(struct Money_t a, struct Money_t b);
+uint8_t quantity_le(const struct Quantity_t *a, const struct Quantity_t *b);
 
-void money_sub_assign(struct Money_t a, struct Money_t b);
+uint8_t quantity_gt(const struct Quantity_t *a, const struct Quantity_t *b);
 
-struct Price_t price_new(double value, uint8_t precision);
+uint8_t quantity_ge(const struct Quantity_t *a, const struct Quantity_t *b);
 
-struct Price_t price_from_raw(int64_t raw, uint8_t precision);
+/**
+ * Returns the lesser of the values (`a` if equal).
+ */
+struct Quantity_t quantity_min(const struct Quantity_t *a, const struct Quantity_t *b);
 
-void price_free(struct Price_t price);
+/**
+ * Returns the greater of the values (`b` if equal).
+ */
+struct Quantity_t quantity_max(const struct Quantity_t *a, const struct Quantity_t *b);
 
-double price_as_f64(const struct Price_t *price);
+uint8_t quantity_is_positive(const struct Quantity_t *qty);
 
-void price_add_assign(struct Price_t a, struct Price_t b);
+/**
+ * Returns zero and writes `a + b` to `out`, otherwise returns the non-zero
+ * `FixedPointError` code if the precisions differ or the sum overflows.
+ *
+ * # Safety
+ *
+ * - `out` must be a valid pointer to write the value to.
+ */
+uint8_t quantity_checked_add(const struct Quantity_t *a,
+                             const struct Quantity_t *b,
+                             struct Quantity_t *out);
 
-void price_sub_assign(struct Price_t a, struct Price_t b);
+/**
+ * Returns zero and writes `a - b` to `out`, otherwise returns the non-zero
+ * `FixedPointError` code if the precisions differ or `b` exceeds `a`.
+ *
+ * # Safety
+ *
+ * - `out` must be a valid pointer to write the value to.
+ */
+uint8_t quantity_checked_sub(const struct Quantity_t *a,
+                             const struct Quantity_t *b,
+                             struct Quantity_t *out);
 
-struct Quantity_t quantity_new(double value, uint8_t precision);
+/**
+ * Returns zero and writes `a * b` to `out`, otherwise returns the non-zero
+ * `FixedPointError` code if the product loses precision or overflows.
+ *
+ * # Safety
+ *
+ * - `out` must be a valid pointer to write the value to.
+ */
+uint8_t quantity_checked_mul(const struct Quantity_t *a,
+                             const struct Quantity_t *b,
+                             struct Quantity_t *out);
 
-struct Quantity_t quantity_from_raw(uint64_t raw, uint8_t precision);
+/**
+ * Returns 1 if `qty` is a whole multiple of `step` within the bounds,
+ * otherwise 0. A null `min` or `max` means no bound.
+ *
+ * # Safety
+ *
+ * - `min` and `max` must each be null or a valid pointer to a `Quantity`.
+ */
+uint8_t quantity_is_valid_for(const struct Quantity_t *qty,
+                              const struct Quantity_t *step,
+                              const struct Quantity_t *min,
+                              const struct Quantity_t *max);
 
-void quantity_free(struct Quantity_t qty);
+/**
+ * Returns the number of the `len` quantities which are not valid for `step`
+ * and the bounds (as `quantity_is_valid_for`), writing 1 (valid) or 0
+ * (invalid) for each to `out` if it is not null.
+ *
+ * # Safety
+ *
+ * - `qtys` must point to `len` valid quantities (or be null if `len` is zero).
+ * - `min` and `max` must each be null or a valid pointer to a `Quantity`.
+ * - `out` must be null or point to a buffer of at least `len` bytes.
+ */
+uintptr_t quantities_validate(const struct Quantity_t *qtys,
+                              uintptr_t len,
+                              const struct Quantity_t *step,
+                              const struct Quantity_t *min,
+                              const struct Quantity_t *max,
+                              uint8_t *out);
 
-double quantity_as_f64(const struct Quantity_t *qty);
+void quantity_add_assign(struct Quantity_t *a, const struct Quantity_t *b);
 
-void quantity_add_assign(struct Quantity_t a, struct Quantity_t b);
+void quantity_add_assign_u64(struct Quantity_t *a, uint64_t b);
 
-void quantity_add_assign_u64(struct Quantity_t a, uint64_t b);
+void quantity_sub_assign(struct Quantity_t *a, const struct Quantity_t *b);
 
-void quantity_sub_assign(struct Quantity_t a, struct Quantity_t b);
+void quantity_sub_assign_u64(struct Quantity_t *a, uint64_t b);
 
-void quantity_sub_assign_u64(struct Quantity_t a, uint64_t b);
+/**
+ * Returns zero and writes the raw value rounded to a multiple of the raw
+ * increment to `out`, otherwise returns the non-zero `FixedPointError` code.
+ *
+ * # Safety
+ *
+ * - `out` must be a valid pointer to write the value to.
+ */
+uint8_t rounding_round_raw(int64_t raw, int64_t increment, enum RoundingMode mode, int64_t *out);
+
+/**
+ * Returns zero and writes the price rounded to a multiple of the tick size
+ * to `out`, otherwise returns the non-zero `FixedPointError` code.
+ *
+ * # Safety
+ *
+ * - `out` must be a valid pointer to write the value to.
+ */
+uint8_t price_round_to_tick(const struct Price_t *price,
+                            const struct Price_t *tick,
+                            enum RoundingMode mode,
+                            struct Price_t *out);
+
+double option_black_scholes_price(enum OptionKind kind,
+                                  double spot,
+                                  double strike,
+                                  double t,
+                                  double rate,
+                                  double vol);
+
+double option_black_scholes_delta(enum OptionKind kind,
+                                  double spot,
+                                  double strike,
+                                  double t,
+                                  double rate,
+                                  double vol);
+
+/**
+ * Returns the implied volatility of the option price (NaN if none).
+ */
+double option_implied_volatility(enum OptionKind kind,
+                                 double price,
+                                 double spot,
+                                 double strike,
+                                 double t,
+                                 double rate);
+
+/**
+ * Returns the implied volatility added to the surface for the underlying (NaN
+ * if none, in which case the surface is unchanged).
+ *
+ * # Safety
+ *
+ * - `underlying_ptr` must be borrowed from a valid Python UTF-8 `str`.
+ */
+double volatility_surface_update(PyObject *underlying_ptr,
+                                 enum OptionKind kind,
+                                 int64_t expiry_ns,
+                                 const struct Price_t *strike,
+                                 double option_price,
+                                 double spot,
+                                 double rate,
+                                 int64_t ts_now);
+
+/**
+ * Returns the volatility of the surface for the underlying at the expiry and
+ * strike (NaN if none).
+ *
+ * # Safety
+ *
+ * - `underlying_ptr` must be borrowed from a valid Python UTF-8 `str`.
+ */
+double volatility_surface_get(PyObject *underlying_ptr,
+                              int64_t expiry_ns,
+                              double strike,
+                              int64_t ts_now);
+
+/**
+ * Returns the volatility of the surface for the underlying at the expiry and
+ * delta, writing the strike to `strike_out` (NaN if none).
+ *
+ * # Safety
+ *
+ * - `underlying_ptr` must be borrowed from a valid Python UTF-8 `str`.
+ * - `strike_out` must be a valid pointer to write the strike to.
+ */
+double volatility_surface_at_delta(PyObject *underlying_ptr,
+                                   enum OptionKind kind,
+                                   int64_t expiry_ns,
+                                   double delta,
+                                   double spot,
+                                   double rate,
+                                   int64_t ts_now,
+                                   double *strike_out);
+
+void volatility_surfaces_reset(void);
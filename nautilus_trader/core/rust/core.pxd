@@ -1,13 +1,37 @@
 # Warning, this file is autogenerated by cbindgen. Don't modify this manually. */
 
 from cpython.object cimport PyObject
-from libc.stdint cimport uintptr_t, uint8_t, int64_t
+from libc.stdint cimport uintptr_t, uint8_t, uint64_t, int64_t
 
 cdef extern from "../includes/core.h":
 
+    # Provides a flag for cooperatively cancelling a run.
+    cdef struct CancellationToken:
+        pass
+
+    # Provides throttled progress reports for a run of a known number of items.
+    cdef struct ProgressTracker:
+        pass
+
     cdef struct String:
         pass
 
+    # Represents the progress of a run.
+    cdef struct Progress:
+        # The number of items processed.
+        uint64_t processed;
+        # The total number of items to process.
+        uint64_t total;
+        # The percentage of items processed, in the range [0, 100].
+        double percent;
+        # The seconds elapsed since the start of the run.
+        double elapsed_secs;
+        # The average number of items processed per second (zero until any time
+        # has elapsed).
+        double events_per_sec;
+        # The estimated seconds remaining (NaN until the rate is known).
+        double eta_secs;
+
     # Represents a timestamp in UNIX nanoseconds.
     cdef struct Timestamp:
         int64_t value;
@@ -15,6 +39,60 @@ cdef extern from "../includes/core.h":
     cdef struct UUID4_t:
         String *value;
 
+    # Represents a Rust `Vec` passed across the C boundary, with its element
+    # type erased.
+    #
+    # The owning Rust module provides the typed function to drop it, as only
+    # Rust can free the elements and buffer.
+    cdef struct CVec:
+        # The pointer to the first element (null when empty and unallocated).
+        void *ptr;
+        # The number of elements.
+        uintptr_t len;
+        # The allocated capacity (elements).
+        uintptr_t cap;
+
+    # Returns a new tracker for `total` items calling `callback` (if not null)
+    # with `user_data` at most once per `interval_ms`. The tracker must be
+    # passed to `progress_tracker_free` to free it.
+    ProgressTracker *progress_tracker_new(uint64_t total,
+                                          uint64_t interval_ms,
+                                          void (*callback)(Progress, void*),
+                                          void *user_data);
+
+    # Updates the tracker with the number of items processed, calling the
+    # callback if a report is due. Returns 1 if the callback was due, otherwise 0.
+    uint8_t progress_tracker_update(ProgressTracker *tracker, uint64_t processed);
+
+    # Calls the callback with the final progress of the run.
+    void progress_tracker_finish(ProgressTracker *tracker, uint64_t processed);
+
+    # Frees the tracker.
+    #
+    # # Safety
+    #
+    # - `tracker` must be null or have been returned by `progress_tracker_new`
+    #   and not already freed.
+    void progress_tracker_free(ProgressTracker *tracker);
+
+    # Returns a new cancellation token. The token must be passed to
+    # `cancellation_token_free` to free it.
+    CancellationToken *cancellation_token_new();
+
+    void cancellation_token_cancel(const CancellationToken *token);
+
+    uint8_t cancellation_token_is_cancelled(const CancellationToken *token);
+
+    void cancellation_token_reset(const CancellationToken *token);
+
+    # Frees the token.
+    #
+    # # Safety
+    #
+    # - `token` must be null or have been returned by `cancellation_token_new`
+    #   and not already freed.
+    void cancellation_token_free(CancellationToken *token);
+
     # Returns the current seconds since the UNIX epoch.
     double unix_timestamp();
 
@@ -45,6 +123,6 @@ cdef extern from "../includes/core.h":
     # # Safety
     #
     # - Assumes that since the data is originating from Rust, the GIL does not need
-    # to be acquired.
+    #   to be acquired.
     # - Assumes you are immediately returning this pointer to Python.
     PyObject *uuid4_to_pystr(const UUID4_t *uuid);
@@ -1,55 +1,293 @@
 # Warning, this file is autogenerated by cbindgen. Don't modify this manually. */
 
 from cpython.object cimport PyObject
-from libc.stdint cimport uint8_t, uint16_t, uint64_t, int64_t
-from nautilus_trader.core.rust.core cimport Timestamp
+from libc.stdint cimport uintptr_t, uint8_t, uint16_t, uint32_t, uint64_t, int8_t, int64_t
+from nautilus_trader.core.rust.core cimport CVec, Timestamp
 
 cdef extern from "../includes/model.h":
 
+    # The maximum length (bytes) of an identifier value.
+    const uintptr_t IDENTIFIER_MAX_LEN # = 255
+
+    # The handle value which is never assigned to an identifier.
+    const uint64_t NULL_HANDLE # = 0
+
+    # The maximum length (bytes) of a value stored inline rather than interned.
+    const uintptr_t INLINE_CAPACITY # = 23
+
+    # The maximum length (characters) of a trade ID value, per the FIX `ExecID`
+    # (tag 17) field.
+    const uintptr_t TRADE_ID_MAX_LEN # = 64
+
+    # The delimiter wrapping the names of synthetic or consolidated venues, e.g.
+    # `-CONSOLIDATED-`.
+    const uint32_t SYNTHETIC_VENUE_DELIMITER # = '-'
+
     const uint8_t FIXED_PRECISION # = 9
 
     const double FIXED_SCALAR # = 1000000000.0
 
+    # The `NumberFormat::decimals` to format at the precision of the value.
+    const uint8_t DECIMALS_PRECISION # = 255
+
+    cdef enum AggressorSide:
+        AggressorSide_Unknown # = 0,
+        AggressorSide_Buy # = 1,
+        AggressorSide_Sell # = 2,
+
+    # Represents an invalid account or margin balance.
+    #
+    # The discriminants are the error codes returned by the C API constructors,
+    # where zero indicates success.
+    cdef enum BalanceError:
+        # The balance amounts had different currencies.
+        BalanceError_CurrencyMismatch # = 1,
+        # A balance amount was negative.
+        BalanceError_Negative # = 2,
+        # The total was not equal to the locked plus free amounts.
+        BalanceError_TotalMismatch # = 3,
+
     cdef enum BookLevel:
-        L1_TBBO # = 1,
-        L2_MBP # = 2,
-        L3_MBO # = 3,
+        BookLevel_L1_TBBO # = 1,
+        BookLevel_L2_MBP # = 2,
+        BookLevel_L3_MBO # = 3,
+
+    cdef enum CandlePattern:
+        # A bullish candle whose body engulfs the body of a prior bearish candle.
+        CandlePattern_BullishEngulfing # = 1,
+        # A bearish candle whose body engulfs the body of a prior bullish candle.
+        CandlePattern_BearishEngulfing # = 2,
+        # A candle whose body is small relative to its range.
+        CandlePattern_Doji # = 3,
+        # A candle whose range is within the range of the prior candle.
+        CandlePattern_InsideBar # = 4,
+        # A candle closing above the highs of the prior N candles.
+        CandlePattern_BreakoutHigh # = 5,
+        # A candle closing below the lows of the prior N candles.
+        CandlePattern_BreakoutLow # = 6,
+
+    # Represents where the currency code of a formatted amount of money is
+    # placed.
+    cdef enum CurrencyPosition:
+        # After the amount (e.g. "1,000.00 USD").
+        CurrencyPosition_Suffix # = 1,
+        # Before the amount (e.g. "USD 1,000.00").
+        CurrencyPosition_Prefix # = 2,
+        # Not shown.
+        CurrencyPosition_Hidden # = 3,
 
     cdef enum CurrencyType:
-        Crypto,
-        Fiat,
+        CurrencyType_Crypto,
+        CurrencyType_Fiat,
+
+    # Represents a fast backtest failure, with the discriminant returned as the
+    # error code by the C API.
+    cdef enum FastBacktestError:
+        # The input arrays were not all the same length.
+        FastBacktestError_LengthMismatch # = 1,
+        # A cost or the periods per year was negative or not finite.
+        FastBacktestError_InvalidArgument # = 2,
+
+    # Represents a lossy or invalid conversion of an `f64` to fixed-point.
+    #
+    # The discriminants are the error codes returned by the C API `checked`
+    # functions, where zero indicates success.
+    cdef enum FixedPointError:
+        # The precision exceeded `FIXED_PRECISION`.
+        FixedPointError_InvalidPrecision # = 1,
+        # The value was NaN or infinite.
+        FixedPointError_NotFinite # = 2,
+        # The value was outside the fixed-point range.
+        FixedPointError_Overflow # = 3,
+        # The value was negative for an unsigned type.
+        FixedPointError_Negative # = 4,
+        # The value was not exactly representable at the precision.
+        FixedPointError_PrecisionLoss # = 5,
+        # The value string was not a decimal number.
+        FixedPointError_InvalidFormat # = 6,
+        # The operands of an exact operation had different precisions.
+        FixedPointError_PrecisionMismatch # = 7,
+        # The operands of a money operation had different currencies.
+        FixedPointError_CurrencyMismatch # = 8,
+        # The increment to round to was not positive.
+        FixedPointError_InvalidIncrement # = 9,
+        # The divisor of a conversion was zero.
+        FixedPointError_DivisionByZero # = 10,
+
+    # Represents an identifier validation failure.
+    #
+    # The discriminants are the error codes returned by the C API `try_from`
+    # functions, where zero indicates success.
+    cdef enum IdentifierError:
+        # The value was empty or only whitespace.
+        IdentifierError_Empty # = 1,
+        # The value exceeded the maximum length for the identifier type
+        # (`IDENTIFIER_MAX_LEN` bytes unless otherwise documented).
+        IdentifierError_TooLong # = 2,
+        # The value contained control characters (including NUL) or leading or
+        # trailing whitespace.
+        IdentifierError_InvalidChars # = 3,
+        # The value was not in the expected format (e.g. "SYMBOL.VENUE").
+        IdentifierError_InvalidFormat # = 4,
+
+    cdef enum OptionKind:
+        OptionKind_Call # = 1,
+        OptionKind_Put # = 2,
 
     cdef enum OrderSide:
-        Buy # = 1,
-        Sell # = 2,
+        OrderSide_Buy # = 1,
+        OrderSide_Sell # = 2,
+
+    cdef enum PovStatus:
+        # Releasing child orders against observed volume.
+        PovStatus_Running # = 1,
+        # The full quantity has been released.
+        PovStatus_Completed # = 2,
+        # The deadline passed with quantity unreleased.
+        PovStatus_Expired # = 3,
+
+    cdef enum PriceType:
+        PriceType_Bid # = 1,
+        PriceType_Ask # = 2,
+        PriceType_Mid # = 3,
+        PriceType_Last # = 4,
+
+    cdef enum RoundingMode:
+        # Round towards negative infinity.
+        RoundingMode_Down # = 1,
+        # Round towards positive infinity.
+        RoundingMode_Up # = 2,
+        # Round to the nearest tick, with ties away from zero.
+        RoundingMode_Nearest # = 3,
+        # Round to the nearest tick, with ties to the even tick (banker's rounding).
+        RoundingMode_HalfEven # = 4,
+
+    cdef enum TradeSignMethod:
+        TradeSignMethod_TickRule # = 1,
+        TradeSignMethod_LeeReady # = 2,
 
     cdef struct BTreeMap_BookPrice__Level:
         pass
 
-    cdef struct HashMap_u64__BookPrice:
+    cdef struct Entry:
         pass
 
-    cdef struct String:
+    # Provides exchange rate calculations between currencies from the latest
+    # bid and ask quotes of currency pairs.
+    cdef struct ExchangeRateCalculator_t:
         pass
 
-    cdef struct Symbol_t:
-        String *value;
+    cdef struct HashMap_String__u64:
+        pass
 
-    cdef struct Venue_t:
-        String *value;
+    cdef struct HashMap_u64__BookPrice:
+        pass
 
-    cdef struct InstrumentId_t:
-        Symbol_t symbol;
-        Venue_t venue;
+    cdef struct String:
+        pass
 
     cdef struct Price_t:
         int64_t raw;
         uint8_t precision;
 
+    # Represents the prices of a bar for pattern detection.
+    cdef struct Candle:
+        Price_t open;
+        Price_t high;
+        Price_t low;
+        Price_t close;
+        # The UNIX timestamp (nanoseconds) when the candle closed.
+        int64_t ts_event;
+
+    # Represents the parameters of pattern detection.
+    cdef struct PatternParams:
+        # The maximum ratio of body to range for a doji, in the range [0, 1].
+        double doji_body_ratio;
+        # The number of prior candles for a breakout (zero disables breakouts).
+        uint32_t breakout_period;
+
+    # Represents an identifier string value.
+    #
+    # Values of up to `INLINE_CAPACITY` bytes (most symbols, venues and
+    # account IDs) are stored inline without allocating. Longer values are
+    # interned, so all live values with the same contents share a single
+    # allocation, cloning is a reference count increment and equality is a
+    # pointer compare. The hash is computed once on construction.
+    #
+    # As the representation is determined by the length, equal values always
+    # share a representation.
+    cdef struct InternedStr:
+        # The interned entry, or null when the value is inline.
+        const Entry *ptr;
+        uint64_t hash;
+        uint8_t len;
+        uint8_t inline_[INLINE_CAPACITY];
+
+    # Represents a valid execution algorithm ID (e.g. "TWAP", "VWAP").
+    cdef struct ExecAlgorithmId_t:
+        InternedStr value;
+
     cdef struct Quantity_t:
         uint64_t raw;
         uint8_t precision;
 
+    # Represents the parameters of a POV execution.
+    cdef struct PovParams:
+        OrderSide side;
+        Quantity_t quantity;
+        # The target fraction of traded volume, in the range (0, 1].
+        double participation_rate;
+        Quantity_t min_clip;
+        Quantity_t max_clip;
+        # The UNIX timestamp (nanoseconds) by which to complete.
+        int64_t deadline_ns;
+        # If the remaining quantity is released at the deadline (non-zero),
+        # otherwise the execution expires.
+        uint8_t complete_on_deadline;
+
+    # Provides the child order pacing of a POV execution.
+    cdef struct PovAlgorithm:
+        ExecAlgorithmId_t exec_algorithm_id;
+        PovParams params;
+        PovStatus status;
+        # The traded volume observed since the execution started.
+        Quantity_t market_volume;
+        # The quantity released in child orders not yet filled or canceled.
+        Quantity_t working;
+        Quantity_t filled;
+
+    # Represents a trade to classify.
+    cdef struct SignTrade:
+        Price_t price;
+        Quantity_t size;
+        # The reported aggressor side, `Unknown` if not reported.
+        AggressorSide aggressor_side;
+        # The UNIX timestamp (nanoseconds) of the trade.
+        int64_t ts_event;
+
+    # Represents a quote for the Lee-Ready classification.
+    cdef struct SignQuote:
+        Price_t bid;
+        Price_t ask;
+        # The UNIX timestamp (nanoseconds) of the quote.
+        int64_t ts_event;
+
+    # Represents the sign of a trade.
+    cdef struct TradeSign:
+        AggressorSide aggressor_side;
+        # If the side was inferred (non-zero) rather than reported.
+        uint8_t inferred;
+
+    cdef struct Symbol_t:
+        InternedStr value;
+
+    cdef struct Venue_t:
+        InternedStr value;
+
+    cdef struct InstrumentId_t:
+        Symbol_t symbol;
+        Venue_t venue;
+
     # Represents a single quote tick in a financial market.
     cdef struct QuoteTick_t:
         InstrumentId_t instrument_id;
@@ -61,7 +299,7 @@ cdef extern from "../includes/model.h":
         Timestamp ts_init;
 
     cdef struct TradeId_t:
-        String *value;
+        InternedStr value;
 
     # Represents a single trade tick in a financial market.
     cdef struct TradeTick_t:
@@ -73,35 +311,112 @@ cdef extern from "../includes/model.h":
         Timestamp ts_event;
         Timestamp ts_init;
 
+    cdef struct Currency_t:
+        String *code;
+        uint8_t precision;
+        uint16_t iso4217;
+        String *name;
+        CurrencyType currency_type;
+
+    # Represents the costs applied to the simulated fills.
+    cdef struct FillCosts:
+        # The commission as a fraction of the fill notional.
+        double commission_rate;
+        # The slippage as a fraction of the fill price, against the fill side.
+        double slippage;
+
+    # Represents the summary statistics of a fast backtest.
+    cdef struct FastBacktestStats:
+        # The PnL at the last bar, net of commissions.
+        double total_pnl;
+        # The total commissions.
+        double total_commission;
+        # The number of bars with a fill.
+        uint64_t fills;
+        # The total notional filled.
+        double turnover;
+        # The largest fall of the PnL from its running peak (starting at zero).
+        double max_drawdown;
+        # The annualized Sharpe ratio of the per-bar PnL changes (NaN if fewer
+        # than two bars or no variance).
+        double sharpe_ratio;
+
     cdef struct AccountId_t:
-        String *value;
+        InternedStr value;
 
+    # Represents a borrowed view of an identifier value for C consumers.
+    #
+    # The bytes are UTF-8 and not NUL terminated, and remain valid for the
+    # lifetime of the identifier they were taken from.
+    cdef struct StrView:
+        const char *ptr;
+        uintptr_t len;
+
+    # Represents a valid data or execution client ID, the key commands are routed
+    # to adapter clients by (e.g. "BINANCE").
     cdef struct ClientId_t:
-        String *value;
+        InternedStr value;
 
     cdef struct ClientOrderId_t:
-        String *value;
+        InternedStr value;
 
     cdef struct ClientOrderLinkId_t:
-        String *value;
+        InternedStr value;
 
+    # Represents a valid component ID, which may be hierarchical with '.'
+    # separated parts (e.g. "RiskEngine.Throttler.Orders").
     cdef struct ComponentId_t:
-        String *value;
+        InternedStr value;
+
+    cdef struct TraderId_t:
+        InternedStr value;
+
+    cdef struct StrategyId_t:
+        InternedStr value;
+
+    # Provides a generator for unique client order IDs, of the form
+    # "O-{YYYYMMDD}-{trader_tag}-{strategy_tag}-{count}".
+    #
+    # The date is taken from the given timestamp, so IDs are deterministic for
+    # a given clock (e.g. in backtests).
+    cdef struct ClientOrderIdGenerator:
+        TraderId_t trader_id;
+        StrategyId_t strategy_id;
+        uint64_t count;
+
+    # Provides a generator for unique order list IDs, of the form
+    # "OL-{YYYYMMDD}-{trader_tag}-{strategy_tag}-{count}".
+    #
+    # The count increments for the lifetime of the trader session, and can be
+    # restored with `set_count` when resuming from persisted state.
+    cdef struct OrderListIdGenerator:
+        TraderId_t trader_id;
+        StrategyId_t strategy_id;
+        uint64_t count;
 
     cdef struct OrderListId_t:
-        String *value;
+        InternedStr value;
 
-    cdef struct PositionId_t:
-        String *value;
+    # Provides a generator for unique position IDs, of the form
+    # "P-{YYYYMMDD}-{instrument_id}-{count}", with an "F" suffix for positions
+    # opened by a flip.
+    #
+    # Counts are tracked per instrument, so IDs are deterministic for a given
+    # sequence of fills.
+    cdef struct PositionIdGenerator:
+        HashMap_String__u64 *counts;
 
-    cdef struct StrategyId_t:
-        String *value;
+    cdef struct PositionId_t:
+        InternedStr value;
 
-    cdef struct TraderId_t:
+    # Represents a glob pattern over "SYMBOL.VENUE" instrument IDs, where `*`
+    # matches any sequence of chars and `?` matches any single char (e.g.
+    # "BTC*.BINANCE" or "*.FTX").
+    cdef struct SubscriptionPattern:
         String *value;
 
     cdef struct VenueOrderId_t:
-        String *value;
+        InternedStr value;
 
     cdef struct Ladder:
         OrderSide side;
@@ -116,17 +431,196 @@ cdef extern from "../includes/model.h":
         OrderSide last_side;
         int64_t ts_last;
 
-    cdef struct Currency_t:
-        String *code;
-        uint8_t precision;
-        uint16_t iso4217;
-        String *name;
-        CurrencyType currency_type;
+    # Represents an exponentially weighted rate of volume per second.
+    cdef struct VolumeRate:
+        double volume;
+        int64_t ts_last;
+
+    # Provides queue time estimates from the trade and cancel flow observed for
+    # an order book.
+    cdef struct QueueEstimator:
+        uint64_t half_life_ns;
+        VolumeRate traded;
+        VolumeRate canceled;
+
+    # Represents a queue time estimate for a passive order.
+    cdef struct QueueEstimate:
+        # The volume resting at or better than the order price.
+        double queue_ahead;
+        # The expected time to fill in seconds (infinite with no observed flow).
+        double expected_time_secs;
+        # The probability of filling within the horizon.
+        double fill_probability;
+
+    # Represents a snapshot of the session statistics for an instrument.
+    #
+    # The prices are zero when `trade_count` is zero.
+    cdef struct SessionStats:
+        # The price of the first trade of the session.
+        Price_t open;
+        # The highest trade price of the session.
+        Price_t high;
+        # The lowest trade price of the session.
+        Price_t low;
+        # The price of the latest trade of the session.
+        Price_t last;
+        # The volume weighted average trade price of the session.
+        Price_t vwap;
+        # The total traded volume of the session (saturating).
+        Quantity_t volume;
+        # The number of trades of the session.
+        uint64_t trade_count;
+        # The UNIX timestamp (nanoseconds) when the session started.
+        int64_t ts_start;
+        # The UNIX timestamp (nanoseconds) of the latest trade.
+        int64_t ts_last;
+
+    # Represents a snapshot of the trading statistics for an instrument.
+    cdef struct TradingStats:
+        # The number of fills.
+        uint64_t trade_count;
+        # The total quantity filled.
+        double volume;
+        # The total commissions, in the commission currency of the instrument.
+        double fees;
+        # The number of round trips closed with a positive realized PnL.
+        uint64_t win_count;
+        # The number of round trips closed with a zero or negative realized PnL.
+        uint64_t loss_count;
+        # The ratio of wins to closed round trips (zero if none).
+        double win_rate;
+        # The average slippage (price points, positive when adverse) of fills
+        # with an expected price (zero if none).
+        double avg_slippage;
 
     cdef struct Money_t:
         int64_t raw;
         Currency_t currency;
 
+    # Represents an account balance denominated in a particular currency.
+    cdef struct AccountBalance_t:
+        Currency_t currency;
+        # The total account balance.
+        Money_t total;
+        # The account balance locked (assigned to pending orders).
+        Money_t locked;
+        # The account balance free for trading.
+        Money_t free;
+
+    # Represents a margin balance optionally associated with a particular
+    # instrument.
+    cdef struct MarginBalance_t:
+        Currency_t currency;
+        # The initial (order) margin requirement.
+        Money_t initial;
+        # The maintenance (position) margin requirement.
+        Money_t maintenance;
+        # The instrument ID associated with the margin (null for account wide).
+        InstrumentId_t *instrument_id;
+
+    # Represents the options for formatting a fixed-point value.
+    #
+    # The separators are Unicode code points, with a zero thousands separator
+    # for no grouping.
+    cdef struct NumberFormat:
+        uint32_t thousands_separator;
+        uint32_t decimal_separator;
+        # The decimals to format (rounding half away from zero), or
+        # `DECIMALS_PRECISION` for the precision of the value.
+        uint8_t decimals;
+        CurrencyPosition currency_position;
+
+    cdef struct PriceBand:
+        # The lowest price within the band (inclusive).
+        Price_t lower;
+        # The highest price within the band (inclusive).
+        Price_t upper;
+
+    # Represents a detected pattern.
+    cdef struct PatternDetection:
+        CandlePattern pattern;
+        # The index of the candle completing the pattern.
+        uint64_t index;
+        # The UNIX timestamp (nanoseconds) of the candle completing the pattern.
+        int64_t ts_event;
+
+    extern const uint8_t SIM_VENUE_NAME[4];
+
+    extern const uint8_t BETFAIR_VENUE_NAME[8];
+
+    extern const uint8_t BINANCE_VENUE_NAME[8];
+
+    extern const uint8_t FTX_VENUE_NAME[4];
+
+    extern const uint8_t IB_VENUE_NAME[19];
+
+    extern const uint8_t SYNTHETIC_VENUE_NAME[6];
+
+    # Returns a vec of the `PatternDetection`s in the array of `len` candles
+    # (oldest first). The vec must be passed to `pattern_detections_drop` to
+    # free it.
+    #
+    # # Safety
+    #
+    # - `candles` must point to `len` valid candles (or be null if `len` is zero).
+    CVec candle_patterns_detect(const Candle *candles, uintptr_t len, const PatternParams *params);
+
+    # Drops a vec of detections returned by `candle_patterns_detect`.
+    #
+    # # Safety
+    #
+    # - `detections` must have been returned by `candle_patterns_detect`.
+    void pattern_detections_drop(CVec detections);
+
+    PovAlgorithm pov_algorithm_new(PovParams params);
+
+    void pov_algorithm_free(PovAlgorithm algorithm);
+
+    # Returns the child order quantity to release for the trade (zero if none).
+    Quantity_t pov_algorithm_on_trade(PovAlgorithm *algorithm,
+                                      const Quantity_t *size,
+                                      int64_t ts_event);
+
+    # Returns the final child order quantity to release at `ts` (zero if none).
+    Quantity_t pov_algorithm_on_time(PovAlgorithm *algorithm, int64_t ts);
+
+    void pov_algorithm_on_fill(PovAlgorithm *algorithm, const Quantity_t *last_qty);
+
+    void pov_algorithm_on_cancel(PovAlgorithm *algorithm, const Quantity_t *leaves_qty);
+
+    # Writes the sign of each of the `len` trades (oldest first) by the method
+    # to `out`, returning the number of trades with an inferred side.
+    #
+    # # Safety
+    #
+    # - `trades` must point to `len` valid trades (or be null if `len` is zero).
+    # - `quotes` must point to `quotes_len` valid quotes (or be null if
+    #   `quotes_len` is zero).
+    # - `out` must point to a buffer for at least `len` signs.
+    uintptr_t trade_sign_classify(const SignTrade *trades,
+                                  uintptr_t len,
+                                  const SignQuote *quotes,
+                                  uintptr_t quotes_len,
+                                  TradeSignMethod method,
+                                  int64_t quote_lag_ns,
+                                  TradeSign *out);
+
+    # Returns a vec of the `VolumeSplit`s of the array of `len` trades (oldest
+    # first) by bulk volume classification. The vec must be passed to
+    # `volume_splits_drop` to free it.
+    #
+    # # Safety
+    #
+    # - `trades` must point to `len` valid trades (or be null if `len` is zero).
+    CVec trade_sign_bulk_volume(const SignTrade *trades, uintptr_t len, int64_t interval_ns);
+
+    # Drops a vec of volume splits returned by `trade_sign_bulk_volume`.
+    #
+    # # Safety
+    #
+    # - `splits` must have been returned by `trade_sign_bulk_volume`.
+    void volume_splits_drop(CVec splits);
+
     void quote_tick_free(QuoteTick_t tick);
 
     QuoteTick_t quote_tick_new(InstrumentId_t instrument_id,
@@ -147,6 +641,8 @@ cdef extern from "../includes/model.h":
                                     int64_t ts_event,
                                     int64_t ts_init);
 
+    uint8_t quote_tick_is_duplicate(const QuoteTick_t *tick, const QuoteTick_t *other);
+
     void trade_tick_free(TradeTick_t tick);
 
     TradeTick_t trade_tick_from_raw(InstrumentId_t instrument_id,
@@ -159,237 +655,1752 @@ cdef extern from "../includes/model.h":
                                     int64_t ts_event,
                                     int64_t ts_init);
 
-    void account_id_free(AccountId_t account_id);
+    uint8_t trade_tick_is_duplicate(const TradeTick_t *tick, const TradeTick_t *other);
 
-    # Returns a Nautilus identifier from a valid Python object pointer.
+    # Returns a new calculator with no quotes. The calculator must be passed to
+    # `exchange_rate_calculator_free` to free it.
+    ExchangeRateCalculator_t *exchange_rate_calculator_new();
+
+    # Updates the bid and ask quotes of the currency pair, returning 1 if the
+    # symbol was a `BASE/QUOTE` pair, otherwise 0.
     #
     # # Safety
     #
-    # - `ptr` must be borrowed from a valid Python UTF-8 `str`.
-    AccountId_t account_id_from_pystr(PyObject *ptr);
-
-    # Returns a pointer to a valid Python UTF-8 string.
+    # - `symbol_ptr` must be borrowed from a valid Python UTF-8 `str`.
+    uint8_t exchange_rate_calculator_update(ExchangeRateCalculator_t *calculator,
+                                            PyObject *symbol_ptr,
+                                            double bid,
+                                            double ask);
+
+    void exchange_rate_calculator_clear(ExchangeRateCalculator_t *calculator);
+
+    # Returns the exchange rate between the currencies at the price type, or
+    # zero if there are insufficient quotes or the price type is `Last`.
+    double exchange_rate_calculator_get_rate(const ExchangeRateCalculator_t *calculator,
+                                             const Currency_t *from_currency,
+                                             const Currency_t *to_currency,
+                                             PriceType price_type);
+
+    # Frees the calculator.
     #
     # # Safety
     #
-    # - Assumes that since the data is originating from Rust, the GIL does not need
-    # to be acquired.
-    # - Assumes you are immediately returning this pointer to Python.
-    PyObject *account_id_to_pystr(const AccountId_t *account_id);
+    # - `calculator` must be null or have been returned by
+    #   `exchange_rate_calculator_new` and not already freed.
+    void exchange_rate_calculator_free(ExchangeRateCalculator_t *calculator);
 
-    void client_id_free(ClientId_t client_id);
+    # Runs a fast backtest over the `len` bars of the arrays, writing the
+    # statistics to `stats_out` and returning zero on success or the
+    # `FastBacktestError` code.
+    #
+    # The position and cumulative PnL of each bar are written to `position_out`
+    # and `pnl_out` unless null.
+    #
+    # # Safety
+    #
+    # - `price`, `mark` and `target` must be valid pointers to `len` values
+    #   (or may be null if `len` is zero).
+    # - `position_out` and `pnl_out` must be null or valid pointers to `len`
+    #   values to write.
+    # - `stats_out` must be a valid pointer to write the statistics to.
+    uint8_t fast_backtest_run(const double *price,
+                              const double *mark,
+                              const double *target,
+                              uintptr_t len,
+                              FillCosts costs,
+                              double periods_per_year,
+                              double *position_out,
+                              double *pnl_out,
+                              FastBacktestStats *stats_out);
+
+    void account_id_free(AccountId_t identifier);
 
     # Returns a Nautilus identifier from a valid Python object pointer.
     #
     # # Safety
     #
     # - `ptr` must be borrowed from a valid Python UTF-8 `str`.
-    ClientId_t client_id_from_pystr(PyObject *ptr);
-
-    void client_order_id_free(ClientOrderId_t client_order_id);
+    AccountId_t account_id_from_pystr(PyObject *ptr);
 
-    # Returns a Nautilus identifier from a valid Python object pointer.
+    # Returns zero and writes a Nautilus identifier to `out` from a valid Python
+    # object pointer, otherwise returns the non-zero `IdentifierError` code.
     #
     # # Safety
     #
     # - `ptr` must be borrowed from a valid Python UTF-8 `str`.
-    ClientOrderId_t client_order_id_from_pystr(PyObject *ptr);
-
-    void client_order_link_id_free(ClientOrderLinkId_t client_order_link_id);
+    # - `out` must be a valid pointer to uninitialized memory to write the
+    #   identifier to, as any value already there is not dropped.
+    uint8_t account_id_try_from_pystr(PyObject *ptr, AccountId_t *out);
 
-    # Returns a Nautilus identifier from a valid Python object pointer.
+    # Returns a pointer to a valid Python UTF-8 string.
     #
     # # Safety
     #
-    # - `ptr` must be borrowed from a valid Python UTF-8 `str`.
-    ClientOrderLinkId_t client_order_link_id_from_pystr(PyObject *ptr);
+    # - Assumes that since the data is originating from Rust, the GIL does not need
+    #   to be acquired.
+    # - Assumes you are immediately returning this pointer to Python.
+    PyObject *account_id_to_pystr(const AccountId_t *identifier);
 
-    void component_id_free(ComponentId_t component_id);
+    # Returns a view of the value bytes (UTF-8, not NUL terminated), valid for
+    # the lifetime of the identifier, without allocating.
+    StrView account_id_as_bytes(const AccountId_t *identifier);
 
-    # Returns a Nautilus identifier from a valid Python object pointer.
+    # Returns the hash of the value, precomputed when interned, for the Python
+    # `__hash__`.
+    uint64_t account_id_hash(const AccountId_t *identifier);
+
+    # Returns a pointer to a new Python list of the identifier strings.
     #
     # # Safety
     #
-    # - `ptr` must be borrowed from a valid Python UTF-8 `str`.
-    ComponentId_t component_id_from_pystr(PyObject *ptr);
+    # - `account_ids` must have been returned by `account_registry_accounts`.
+    # - Assumes that since the data is originating from Rust, the GIL does not need
+    #   to be acquired.
+    # - Assumes you are immediately returning this pointer to Python.
+    PyObject *account_ids_to_pylist(const CVec *account_ids);
 
-    void instrument_id_free(InstrumentId_t instrument_id);
+    # Drops a vec of identifiers returned by `account_registry_accounts`.
+    #
+    # # Safety
+    #
+    # - `account_ids` must have been returned by `account_registry_accounts`.
+    void account_ids_drop(CVec account_ids);
 
-    # Returns a Nautilus identifier from valid Python object pointers.
+    void account_registry_register(const AccountId_t *account_id, const Venue_t *venue);
+
+    void account_registry_deregister(const AccountId_t *account_id);
+
+    # Returns 1 and writes the venue owning the account to `out` if registered,
+    # otherwise returns 0.
     #
     # # Safety
     #
-    # - `symbol_ptr` and `venue_ptr` must be borrowed from a valid Python UTF-8 `str`(s).
-    InstrumentId_t instrument_id_from_pystrs(PyObject *symbol_ptr, PyObject *venue_ptr);
+    # - `out` must be a valid pointer to write the venue to.
+    uint8_t account_registry_venue(const AccountId_t *account_id, Venue_t *out);
+
+    # Returns a vec of the accounts registered for the venue, to be released with
+    # `account_ids_drop`.
+    CVec account_registry_accounts(const Venue_t *venue);
 
-    void order_list_id_free(OrderListId_t order_list_id);
+    void client_id_free(ClientId_t identifier);
 
     # Returns a Nautilus identifier from a valid Python object pointer.
     #
     # # Safety
     #
     # - `ptr` must be borrowed from a valid Python UTF-8 `str`.
-    OrderListId_t order_list_id_from_pystr(PyObject *ptr);
-
-    void position_id_free(PositionId_t position_id);
+    ClientId_t client_id_from_pystr(PyObject *ptr);
 
-    # Returns a Nautilus identifier from a valid Python object pointer.
+    # Returns zero and writes a Nautilus identifier to `out` from a valid Python
+    # object pointer, otherwise returns the non-zero `IdentifierError` code.
     #
     # # Safety
     #
     # - `ptr` must be borrowed from a valid Python UTF-8 `str`.
-    PositionId_t position_id_from_pystr(PyObject *ptr);
+    # - `out` must be a valid pointer to uninitialized memory to write the
+    #   identifier to, as any value already there is not dropped.
+    uint8_t client_id_try_from_pystr(PyObject *ptr, ClientId_t *out);
+
+    # Returns a pointer to a valid Python UTF-8 string.
+    #
+    # # Safety
+    #
+    # - Assumes that since the data is originating from Rust, the GIL does not need
+    #   to be acquired.
+    # - Assumes you are immediately returning this pointer to Python.
+    PyObject *client_id_to_pystr(const ClientId_t *identifier);
 
-    void strategy_id_free(StrategyId_t strategy_id);
+    # Returns a view of the value bytes (UTF-8, not NUL terminated), valid for
+    # the lifetime of the identifier, without allocating.
+    StrView client_id_as_bytes(const ClientId_t *identifier);
+
+    # Returns the hash of the value, precomputed when interned, for the Python
+    # `__hash__`.
+    uint64_t client_id_hash(const ClientId_t *identifier);
+
+    # Returns 1 if the client IDs are equal, otherwise 0.
+    uint8_t client_id_eq(const ClientId_t *lhs, const ClientId_t *rhs);
+
+    void client_order_id_free(ClientOrderId_t identifier);
 
     # Returns a Nautilus identifier from a valid Python object pointer.
     #
     # # Safety
     #
     # - `ptr` must be borrowed from a valid Python UTF-8 `str`.
-    StrategyId_t strategy_id_from_pystr(PyObject *ptr);
-
-    void symbol_free(Symbol_t symbol);
+    ClientOrderId_t client_order_id_from_pystr(PyObject *ptr);
 
-    # Returns a Nautilus identifier from a valid Python object pointer.
+    # Returns zero and writes a Nautilus identifier to `out` from a valid Python
+    # object pointer, otherwise returns the non-zero `IdentifierError` code.
     #
     # # Safety
     #
     # - `ptr` must be borrowed from a valid Python UTF-8 `str`.
-    Symbol_t symbol_from_pystr(PyObject *ptr);
+    # - `out` must be a valid pointer to uninitialized memory to write the
+    #   identifier to, as any value already there is not dropped.
+    uint8_t client_order_id_try_from_pystr(PyObject *ptr, ClientOrderId_t *out);
 
     # Returns a pointer to a valid Python UTF-8 string.
     #
     # # Safety
     #
     # - Assumes that since the data is originating from Rust, the GIL does not need
-    # to be acquired.
+    #   to be acquired.
     # - Assumes you are immediately returning this pointer to Python.
-    PyObject *symbol_to_pystr(const Symbol_t *symbol);
+    PyObject *client_order_id_to_pystr(const ClientOrderId_t *identifier);
 
-    void trade_id_free(TradeId_t trade_id);
+    # Returns a view of the value bytes (UTF-8, not NUL terminated), valid for
+    # the lifetime of the identifier, without allocating.
+    StrView client_order_id_as_bytes(const ClientOrderId_t *identifier);
+
+    # Returns the hash of the value, precomputed when interned, for the Python
+    # `__hash__`.
+    uint64_t client_order_id_hash(const ClientOrderId_t *identifier);
+
+    void client_order_link_id_free(ClientOrderLinkId_t identifier);
 
     # Returns a Nautilus identifier from a valid Python object pointer.
     #
     # # Safety
     #
     # - `ptr` must be borrowed from a valid Python UTF-8 `str`.
-    TradeId_t trade_id_from_pystr(PyObject *ptr);
+    ClientOrderLinkId_t client_order_link_id_from_pystr(PyObject *ptr);
+
+    # Returns zero and writes a Nautilus identifier to `out` from a valid Python
+    # object pointer, otherwise returns the non-zero `IdentifierError` code.
+    #
+    # # Safety
+    #
+    # - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+    # - `out` must be a valid pointer to uninitialized memory to write the
+    #   identifier to, as any value already there is not dropped.
+    uint8_t client_order_link_id_try_from_pystr(PyObject *ptr, ClientOrderLinkId_t *out);
 
     # Returns a pointer to a valid Python UTF-8 string.
     #
     # # Safety
     #
     # - Assumes that since the data is originating from Rust, the GIL does not need
-    # to be acquired.
+    #   to be acquired.
     # - Assumes you are immediately returning this pointer to Python.
-    PyObject *trade_id_to_pystr(const TradeId_t *trade_id);
+    PyObject *client_order_link_id_to_pystr(const ClientOrderLinkId_t *identifier);
+
+    # Returns a view of the value bytes (UTF-8, not NUL terminated), valid for
+    # the lifetime of the identifier, without allocating.
+    StrView client_order_link_id_as_bytes(const ClientOrderLinkId_t *identifier);
 
-    void trader_id_free(TraderId_t trader_id);
+    # Returns the hash of the value, precomputed when interned, for the Python
+    # `__hash__`.
+    uint64_t client_order_link_id_hash(const ClientOrderLinkId_t *identifier);
+
+    void component_id_free(ComponentId_t identifier);
 
     # Returns a Nautilus identifier from a valid Python object pointer.
     #
     # # Safety
     #
     # - `ptr` must be borrowed from a valid Python UTF-8 `str`.
-    TraderId_t trader_id_from_pystr(PyObject *ptr);
-
-    void venue_free(Venue_t venue);
+    ComponentId_t component_id_from_pystr(PyObject *ptr);
 
-    # Returns a Nautilus identifier from a valid Python object pointer.
+    # Returns zero and writes a Nautilus identifier to `out` from a valid Python
+    # object pointer, otherwise returns the non-zero `IdentifierError` code.
     #
     # # Safety
     #
     # - `ptr` must be borrowed from a valid Python UTF-8 `str`.
-    Venue_t venue_from_pystr(PyObject *ptr);
+    # - `out` must be a valid pointer to uninitialized memory to write the
+    #   identifier to, as any value already there is not dropped.
+    uint8_t component_id_try_from_pystr(PyObject *ptr, ComponentId_t *out);
 
     # Returns a pointer to a valid Python UTF-8 string.
     #
     # # Safety
     #
     # - Assumes that since the data is originating from Rust, the GIL does not need
-    # to be acquired.
+    #   to be acquired.
     # - Assumes you are immediately returning this pointer to Python.
-    PyObject *venue_to_pystr(const Venue_t *venue);
+    PyObject *component_id_to_pystr(const ComponentId_t *identifier);
 
-    void venue_order_id_free(VenueOrderId_t venue_order_id);
+    # Returns a view of the value bytes (UTF-8, not NUL terminated), valid for
+    # the lifetime of the identifier, without allocating.
+    StrView component_id_as_bytes(const ComponentId_t *identifier);
 
-    # Returns a Nautilus identifier from a valid Python object pointer.
+    # Returns the hash of the value, precomputed when interned, for the Python
+    # `__hash__`.
+    uint64_t component_id_hash(const ComponentId_t *identifier);
+
+    # Returns 1 and writes the parent component to `out` if there is one,
+    # otherwise returns 0.
     #
     # # Safety
     #
-    # - `ptr` must be borrowed from a valid Python UTF-8 `str`.
-    VenueOrderId_t venue_order_id_from_pystr(PyObject *ptr);
+    # - `out` must be a valid pointer to write the identifier to.
+    uint8_t component_id_parent(const ComponentId_t *component_id, ComponentId_t *out);
 
-    OrderBook order_book_new(InstrumentId_t instrument_id, BookLevel book_level);
+    # Returns a pointer to a valid Python UTF-8 string of the last part of the
+    # hierarchy.
+    #
+    # # Safety
+    #
+    # - Assumes that since the data is originating from Rust, the GIL does not need
+    #   to be acquired.
+    # - Assumes you are immediately returning this pointer to Python.
+    PyObject *component_id_leaf_to_pystr(const ComponentId_t *component_id);
 
-    # Returns a `Currency` from valid Python object pointers and primitives.
+    uint8_t component_id_is_child_of(const ComponentId_t *component_id, const ComponentId_t *other);
+
+    void exec_algorithm_id_free(ExecAlgorithmId_t identifier);
+
+    # Returns a Nautilus identifier from a valid Python object pointer.
     #
     # # Safety
     #
-    # - `code_ptr` and `name_ptr` must be borrowed from a valid Python UTF-8 `str`(s).
-    Currency_t currency_from_py(PyObject *code_ptr,
-                                uint8_t precision,
-                                uint16_t iso4217,
-                                PyObject *name_ptr,
-                                CurrencyType currency_type);
+    # - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+    ExecAlgorithmId_t exec_algorithm_id_from_pystr(PyObject *ptr);
 
-    # Returns a pointer to a valid Python UTF-8 string.
+    # Returns zero and writes a Nautilus identifier to `out` from a valid Python
+    # object pointer, otherwise returns the non-zero `IdentifierError` code.
     #
     # # Safety
     #
-    # - Assumes that since the data is originating from Rust, the GIL does not need
-    # to be acquired.
-    # - Assumes you are immediately returning this pointer to Python.
-    PyObject *currency_code_to_pystr(const Currency_t *currency);
+    # - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+    # - `out` must be a valid pointer to uninitialized memory to write the
+    #   identifier to, as any value already there is not dropped.
+    uint8_t exec_algorithm_id_try_from_pystr(PyObject *ptr, ExecAlgorithmId_t *out);
 
     # Returns a pointer to a valid Python UTF-8 string.
     #
     # # Safety
     #
     # - Assumes that since the data is originating from Rust, the GIL does not need
-    # to be acquired.
+    #   to be acquired.
     # - Assumes you are immediately returning this pointer to Python.
-    PyObject *currency_name_to_pystr(const Currency_t *currency);
+    PyObject *exec_algorithm_id_to_pystr(const ExecAlgorithmId_t *identifier);
 
-    void currency_free(Currency_t currency);
+    # Returns a view of the value bytes (UTF-8, not NUL terminated), valid for
+    # the lifetime of the identifier, without allocating.
+    StrView exec_algorithm_id_as_bytes(const ExecAlgorithmId_t *identifier);
 
-    Money_t money_new(double amount, Currency_t currency);
+    # Returns the hash of the value, precomputed when interned, for the Python
+    # `__hash__`.
+    uint64_t exec_algorithm_id_hash(const ExecAlgorithmId_t *identifier);
 
-    Money_t money_from_raw(int64_t raw, Currency_t currency);
+    # Returns 1 if the execution algorithm IDs are equal, otherwise 0.
+    uint8_t exec_algorithm_id_eq(const ExecAlgorithmId_t *lhs, const ExecAlgorithmId_t *rhs);
 
-    void money_free(Money_t money);
+    ClientOrderIdGenerator client_order_id_generator_new(TraderId_t trader_id,
+                                                         StrategyId_t strategy_id,
+                                                         uint64_t initial_count);
 
-    double money_as_f64(const Money_t *money);
+    void client_order_id_generator_free(ClientOrderIdGenerator generator);
 
-    void money_add_assign(Money_t a, Money_t b);
+    ClientOrderId_t client_order_id_generator_generate(ClientOrderIdGenerator *generator,
+                                                       uint64_t ts_now);
 
-    void money_sub_assign(Money_t a, Money_t b);
+    uint64_t client_order_id_generator_count(const ClientOrderIdGenerator *generator);
 
-    Price_t price_new(double value, uint8_t precision);
+    void client_order_id_generator_set_count(ClientOrderIdGenerator *generator, uint64_t count);
 
-    Price_t price_from_raw(int64_t raw, uint8_t precision);
+    void client_order_id_generator_reset(ClientOrderIdGenerator *generator);
 
-    void price_free(Price_t price);
+    OrderListIdGenerator order_list_id_generator_new(TraderId_t trader_id,
+                                                     StrategyId_t strategy_id,
+                                                     uint64_t initial_count);
 
-    double price_as_f64(const Price_t *price);
+    void order_list_id_generator_free(OrderListIdGenerator generator);
 
-    void price_add_assign(Price_t a, Price_t b);
+    OrderListId_t order_list_id_generator_generate(OrderListIdGenerator *generator,
+                                                   uint64_t ts_now);
 
-    void price_sub_assign(Price_t a, Price_t b);
+    uint64_t order_list_id_generator_count(const OrderListIdGenerator *generator);
 
-    Quantity_t quantity_new(double value, uint8_t precision);
+    void order_list_id_generator_set_count(OrderListIdGenerator *generator, uint64_t count);
 
-    Quantity_t quantity_from_raw(uint64_t raw, uint8_t precision);
+    void order_list_id_generator_reset(OrderListIdGenerator *generator);
 
-    void quantity_free(Quantity_t qty);
+    PositionIdGenerator position_id_generator_new();
 
-    double quantity_as_f64(const Quantity_t *qty);
+    void position_id_generator_free(PositionIdGenerator generator);
 
-    void quantity_add_assign(Quantity_t a, Quantity_t b);
+    # Returns the next position ID, where `flipped` is non-zero for a position
+    # opened by a flip.
+    PositionId_t position_id_generator_generate(PositionIdGenerator *generator,
+                                                const InstrumentId_t *instrument_id,
+                                                uint64_t ts_now,
+                                                uint8_t flipped);
 
-    void quantity_add_assign_u64(Quantity_t a, uint64_t b);
+    uint64_t position_id_generator_count(const PositionIdGenerator *generator,
+                                         const InstrumentId_t *instrument_id);
 
-    void quantity_sub_assign(Quantity_t a, Quantity_t b);
+    void position_id_generator_set_count(PositionIdGenerator *generator,
+                                         const InstrumentId_t *instrument_id,
+                                         uint64_t count);
 
-    void quantity_sub_assign_u64(Quantity_t a, uint64_t b);
+    void position_id_generator_reset(PositionIdGenerator *generator);
+
+    # Returns the handle for the symbol from a valid Python object pointer,
+    # registering it if new.
+    #
+    # # Safety
+    #
+    # - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+    uint64_t symbol_intern(PyObject *ptr);
+
+    # Returns a pointer to a valid Python UTF-8 string of the symbol for the
+    # handle, or to `None` if not registered.
+    #
+    # # Safety
+    #
+    # - Assumes the GIL is held and you are immediately returning this pointer
+    #   to Python.
+    PyObject *symbol_resolve(uint64_t handle);
+
+    uint64_t symbol_to_handle(const Symbol_t *symbol);
+
+    # Writes the symbol for the handle to `out`, returning 1 if registered.
+    #
+    # # Safety
+    #
+    # - `out` must be a valid pointer to an initialized identifier, which is
+    #   dropped if overwritten.
+    uint8_t symbol_from_handle(uint64_t handle, Symbol_t *out);
+
+    # Returns the handle for the venue from a valid Python object pointer,
+    # registering it if new.
+    #
+    # # Safety
+    #
+    # - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+    uint64_t venue_intern(PyObject *ptr);
+
+    # Returns a pointer to a valid Python UTF-8 string of the venue for the
+    # handle, or to `None` if not registered.
+    #
+    # # Safety
+    #
+    # - Assumes the GIL is held and you are immediately returning this pointer
+    #   to Python.
+    PyObject *venue_resolve(uint64_t handle);
+
+    uint64_t venue_to_handle(const Venue_t *venue);
+
+    # Writes the venue for the handle to `out`, returning 1 if registered.
+    #
+    # # Safety
+    #
+    # - `out` must be a valid pointer to an initialized identifier, which is
+    #   dropped if overwritten.
+    uint8_t venue_from_handle(uint64_t handle, Venue_t *out);
+
+    # Returns the handle for the instrument ID from a valid Python object pointer
+    # in the "SYMBOL.VENUE" dotted notation, registering it if new.
+    #
+    # # Safety
+    #
+    # - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+    uint64_t instrument_id_intern(PyObject *ptr);
+
+    # Returns a pointer to a valid Python UTF-8 string of the instrument ID for
+    # the handle, or to `None` if not registered.
+    #
+    # # Safety
+    #
+    # - Assumes the GIL is held and you are immediately returning this pointer
+    #   to Python.
+    PyObject *instrument_id_resolve(uint64_t handle);
+
+    uint64_t instrument_id_to_handle(const InstrumentId_t *instrument_id);
+
+    # Writes the instrument ID for the handle to `out`, returning 1 if registered.
+    #
+    # # Safety
+    #
+    # - `out` must be a valid pointer to an initialized identifier, which is
+    #   dropped if overwritten.
+    uint8_t instrument_id_from_handle(uint64_t handle, InstrumentId_t *out);
+
+    void instrument_id_free(InstrumentId_t instrument_id);
+
+    # Returns a Nautilus identifier from valid Python object pointers.
+    #
+    # # Safety
+    #
+    # - `symbol_ptr` and `venue_ptr` must be borrowed from a valid Python UTF-8 `str`(s).
+    InstrumentId_t instrument_id_from_pystrs(PyObject *symbol_ptr, PyObject *venue_ptr);
+
+    # Returns a Nautilus identifier from a valid Python object pointer in the
+    # "SYMBOL.VENUE" dotted notation.
+    #
+    # # Safety
+    #
+    # - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+    InstrumentId_t instrument_id_from_pystr(PyObject *ptr);
+
+    # Returns zero and writes a Nautilus identifier to `out` from a valid Python
+    # object pointer in the "SYMBOL.VENUE" dotted notation, otherwise returns the
+    # non-zero `IdentifierError` code.
+    #
+    # # Safety
+    #
+    # - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+    # - `out` must be a valid pointer to uninitialized memory to write the
+    #   identifier to, as any value already there is not dropped.
+    uint8_t instrument_id_try_from_pystr(PyObject *ptr, InstrumentId_t *out);
+
+    # Returns a pointer to a valid Python UTF-8 string in the "SYMBOL.VENUE"
+    # dotted notation.
+    #
+    # # Safety
+    #
+    # - Assumes that since the data is originating from Rust, the GIL does not need
+    #   to be acquired.
+    # - Assumes you are immediately returning this pointer to Python.
+    PyObject *instrument_id_to_pystr(const InstrumentId_t *instrument_id);
+
+    # Returns a view of the symbol value bytes (UTF-8, not NUL terminated),
+    # valid for the lifetime of the identifier, without allocating.
+    StrView instrument_id_symbol_as_bytes(const InstrumentId_t *instrument_id);
+
+    # Returns a view of the venue value bytes (UTF-8, not NUL terminated),
+    # valid for the lifetime of the identifier, without allocating.
+    StrView instrument_id_venue_as_bytes(const InstrumentId_t *instrument_id);
+
+    # Returns the hash combining the symbol and venue hashes, precomputed when
+    # interned, for the Python `__hash__`.
+    uint64_t instrument_id_hash(const InstrumentId_t *instrument_id);
+
+    uint8_t instrument_id_eq_ignore_case(const InstrumentId_t *instrument_id,
+                                         const InstrumentId_t *other);
+
+    # Returns a clone of the identifier, e.g. an element of the vec returned by
+    # `instrument_ids_from_pylist` for ownership by Python.
+    InstrumentId_t instrument_id_clone(const InstrumentId_t *instrument_id);
+
+    # Returns a vec of Nautilus identifiers from an array of `len` valid Python
+    # object pointers (e.g. the items of a list) in the "SYMBOL.VENUE" dotted
+    # notation, acquiring the GIL once for the batch. The vec must be passed to
+    # `instrument_ids_drop` to free it.
+    #
+    # # Safety
+    #
+    # - `ptrs` must point to `len` pointers borrowed from valid Python UTF-8 `str`s.
+    CVec instrument_ids_from_pylist(PyObject *const *ptrs, uintptr_t len);
+
+    # Returns a pointer to a new Python list of the identifier strings in the
+    # "SYMBOL.VENUE" dotted notation.
+    #
+    # # Safety
+    #
+    # - `instrument_ids` must have been returned by `instrument_ids_from_pylist`.
+    # - Assumes that since the data is originating from Rust, the GIL does not need
+    #   to be acquired.
+    # - Assumes you are immediately returning this pointer to Python.
+    PyObject *instrument_ids_to_pylist(const CVec *instrument_ids);
+
+    # Drops a vec of identifiers returned by `instrument_ids_from_pylist`.
+    #
+    # # Safety
+    #
+    # - `instrument_ids` must have been returned by `instrument_ids_from_pylist`.
+    void instrument_ids_drop(CVec instrument_ids);
+
+    void order_list_id_free(OrderListId_t identifier);
+
+    # Returns a Nautilus identifier from a valid Python object pointer.
+    #
+    # # Safety
+    #
+    # - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+    OrderListId_t order_list_id_from_pystr(PyObject *ptr);
+
+    # Returns zero and writes a Nautilus identifier to `out` from a valid Python
+    # object pointer, otherwise returns the non-zero `IdentifierError` code.
+    #
+    # # Safety
+    #
+    # - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+    # - `out` must be a valid pointer to uninitialized memory to write the
+    #   identifier to, as any value already there is not dropped.
+    uint8_t order_list_id_try_from_pystr(PyObject *ptr, OrderListId_t *out);
+
+    # Returns a pointer to a valid Python UTF-8 string.
+    #
+    # # Safety
+    #
+    # - Assumes that since the data is originating from Rust, the GIL does not need
+    #   to be acquired.
+    # - Assumes you are immediately returning this pointer to Python.
+    PyObject *order_list_id_to_pystr(const OrderListId_t *identifier);
+
+    # Returns a view of the value bytes (UTF-8, not NUL terminated), valid for
+    # the lifetime of the identifier, without allocating.
+    StrView order_list_id_as_bytes(const OrderListId_t *identifier);
+
+    # Returns the hash of the value, precomputed when interned, for the Python
+    # `__hash__`.
+    uint64_t order_list_id_hash(const OrderListId_t *identifier);
+
+    void position_id_free(PositionId_t identifier);
+
+    # Returns a Nautilus identifier from a valid Python object pointer.
+    #
+    # # Safety
+    #
+    # - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+    PositionId_t position_id_from_pystr(PyObject *ptr);
+
+    # Returns zero and writes a Nautilus identifier to `out` from a valid Python
+    # object pointer, otherwise returns the non-zero `IdentifierError` code.
+    #
+    # # Safety
+    #
+    # - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+    # - `out` must be a valid pointer to uninitialized memory to write the
+    #   identifier to, as any value already there is not dropped.
+    uint8_t position_id_try_from_pystr(PyObject *ptr, PositionId_t *out);
+
+    # Returns a pointer to a valid Python UTF-8 string.
+    #
+    # # Safety
+    #
+    # - Assumes that since the data is originating from Rust, the GIL does not need
+    #   to be acquired.
+    # - Assumes you are immediately returning this pointer to Python.
+    PyObject *position_id_to_pystr(const PositionId_t *identifier);
+
+    # Returns a view of the value bytes (UTF-8, not NUL terminated), valid for
+    # the lifetime of the identifier, without allocating.
+    StrView position_id_as_bytes(const PositionId_t *identifier);
+
+    # Returns the hash of the value, precomputed when interned, for the Python
+    # `__hash__`.
+    uint64_t position_id_hash(const PositionId_t *identifier);
+
+    void strategy_id_free(StrategyId_t identifier);
+
+    # Returns a Nautilus identifier from a valid Python object pointer.
+    #
+    # # Safety
+    #
+    # - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+    StrategyId_t strategy_id_from_pystr(PyObject *ptr);
+
+    # Returns zero and writes a Nautilus identifier to `out` from a valid Python
+    # object pointer, otherwise returns the non-zero `IdentifierError` code.
+    #
+    # # Safety
+    #
+    # - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+    # - `out` must be a valid pointer to uninitialized memory to write the
+    #   identifier to, as any value already there is not dropped.
+    uint8_t strategy_id_try_from_pystr(PyObject *ptr, StrategyId_t *out);
+
+    # Returns a pointer to a valid Python UTF-8 string.
+    #
+    # # Safety
+    #
+    # - Assumes that since the data is originating from Rust, the GIL does not need
+    #   to be acquired.
+    # - Assumes you are immediately returning this pointer to Python.
+    PyObject *strategy_id_to_pystr(const StrategyId_t *identifier);
+
+    # Returns a view of the value bytes (UTF-8, not NUL terminated), valid for
+    # the lifetime of the identifier, without allocating.
+    StrView strategy_id_as_bytes(const StrategyId_t *identifier);
+
+    # Returns the hash of the value, precomputed when interned, for the Python
+    # `__hash__`.
+    uint64_t strategy_id_hash(const StrategyId_t *identifier);
+
+    # Returns a pointer to a valid Python UTF-8 string of the name part.
+    #
+    # # Safety
+    #
+    # - Assumes that since the data is originating from Rust, the GIL does not need
+    #   to be acquired.
+    # - Assumes you are immediately returning this pointer to Python.
+    PyObject *strategy_id_name_to_pystr(const StrategyId_t *strategy_id);
+
+    # Returns a pointer to a valid Python UTF-8 string of the tag part.
+    #
+    # # Safety
+    #
+    # - Assumes that since the data is originating from Rust, the GIL does not need
+    #   to be acquired.
+    # - Assumes you are immediately returning this pointer to Python.
+    PyObject *strategy_id_tag_to_pystr(const StrategyId_t *strategy_id);
+
+    void subscription_pattern_free(SubscriptionPattern pattern);
+
+    # Returns a `SubscriptionPattern` from a valid Python object pointer.
+    #
+    # # Safety
+    #
+    # - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+    SubscriptionPattern subscription_pattern_from_pystr(PyObject *ptr);
+
+    # Returns a pointer to a valid Python UTF-8 string.
+    #
+    # # Safety
+    #
+    # - Assumes that since the data is originating from Rust, the GIL does not need
+    #   to be acquired.
+    # - Assumes you are immediately returning this pointer to Python.
+    PyObject *subscription_pattern_to_pystr(const SubscriptionPattern *pattern);
+
+    uint8_t subscription_pattern_matches(const SubscriptionPattern *pattern,
+                                         const InstrumentId_t *instrument_id);
+
+    void symbol_free(Symbol_t identifier);
+
+    # Returns a Nautilus identifier from a valid Python object pointer.
+    #
+    # # Safety
+    #
+    # - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+    Symbol_t symbol_from_pystr(PyObject *ptr);
+
+    # Returns zero and writes a Nautilus identifier to `out` from a valid Python
+    # object pointer, otherwise returns the non-zero `IdentifierError` code.
+    #
+    # # Safety
+    #
+    # - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+    # - `out` must be a valid pointer to uninitialized memory to write the
+    #   identifier to, as any value already there is not dropped.
+    uint8_t symbol_try_from_pystr(PyObject *ptr, Symbol_t *out);
+
+    # Returns a pointer to a valid Python UTF-8 string.
+    #
+    # # Safety
+    #
+    # - Assumes that since the data is originating from Rust, the GIL does not need
+    #   to be acquired.
+    # - Assumes you are immediately returning this pointer to Python.
+    PyObject *symbol_to_pystr(const Symbol_t *identifier);
+
+    # Returns a view of the value bytes (UTF-8, not NUL terminated), valid for
+    # the lifetime of the identifier, without allocating.
+    StrView symbol_as_bytes(const Symbol_t *identifier);
+
+    # Returns the hash of the value, precomputed when interned, for the Python
+    # `__hash__`.
+    uint64_t symbol_hash(const Symbol_t *identifier);
+
+    # Returns a Nautilus identifier from a valid Python object pointer, with the
+    # value in canonical (uppercased) form.
+    #
+    # # Safety
+    #
+    # - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+    Symbol_t symbol_canonical_from_pystr(PyObject *ptr);
+
+    uint8_t symbol_eq_ignore_case(const Symbol_t *symbol, const Symbol_t *other);
+
+    # Returns a clone of the identifier, e.g. an element of the vec returned by
+    # `symbols_from_pylist` for ownership by Python.
+    Symbol_t symbol_clone(const Symbol_t *symbol);
+
+    # Returns a vec of Nautilus identifiers from an array of `len` valid Python
+    # object pointers (e.g. the items of a list), acquiring the GIL once for
+    # the batch. The vec must be passed to `symbols_drop` to free it.
+    #
+    # # Safety
+    #
+    # - `ptrs` must point to `len` pointers borrowed from valid Python UTF-8 `str`s.
+    CVec symbols_from_pylist(PyObject *const *ptrs, uintptr_t len);
+
+    # Returns a pointer to a new Python list of the identifier strings.
+    #
+    # # Safety
+    #
+    # - `symbols` must have been returned by `symbols_from_pylist`.
+    # - Assumes that since the data is originating from Rust, the GIL does not need
+    #   to be acquired.
+    # - Assumes you are immediately returning this pointer to Python.
+    PyObject *symbols_to_pylist(const CVec *symbols);
+
+    # Drops a vec of identifiers returned by `symbols_from_pylist`.
+    #
+    # # Safety
+    #
+    # - `symbols` must have been returned by `symbols_from_pylist`.
+    void symbols_drop(CVec symbols);
+
+    # Returns a Nautilus identifier normalized from a valid Python object pointer,
+    # with the default separators mapped to the `separator` character code (or
+    # removed if zero), and the comma separated suffixes and quote assets.
+    #
+    # # Safety
+    #
+    # - `ptr`, `strip_suffixes_ptr` and `quote_assets_ptr` must be borrowed from
+    #   valid Python UTF-8 `str`s.
+    Symbol_t symbol_normalize(PyObject *ptr,
+                              uint8_t uppercase,
+                              uint32_t separator,
+                              PyObject *strip_suffixes_ptr,
+                              PyObject *quote_assets_ptr);
+
+    void trade_id_free(TradeId_t identifier);
+
+    # Returns a Nautilus identifier from a valid Python object pointer.
+    #
+    # # Safety
+    #
+    # - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+    TradeId_t trade_id_from_pystr(PyObject *ptr);
+
+    # Returns zero and writes a Nautilus identifier to `out` from a valid Python
+    # object pointer, otherwise returns the non-zero `IdentifierError` code.
+    #
+    # # Safety
+    #
+    # - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+    # - `out` must be a valid pointer to uninitialized memory to write the
+    #   identifier to, as any value already there is not dropped.
+    uint8_t trade_id_try_from_pystr(PyObject *ptr, TradeId_t *out);
+
+    # Returns a pointer to a valid Python UTF-8 string.
+    #
+    # # Safety
+    #
+    # - Assumes that since the data is originating from Rust, the GIL does not need
+    #   to be acquired.
+    # - Assumes you are immediately returning this pointer to Python.
+    PyObject *trade_id_to_pystr(const TradeId_t *identifier);
+
+    # Returns a view of the value bytes (UTF-8, not NUL terminated), valid for
+    # the lifetime of the identifier, without allocating.
+    StrView trade_id_as_bytes(const TradeId_t *identifier);
+
+    # Returns the hash of the value, precomputed when interned, for the Python
+    # `__hash__`.
+    uint64_t trade_id_hash(const TradeId_t *identifier);
+
+    void trader_id_free(TraderId_t identifier);
+
+    # Returns a Nautilus identifier from a valid Python object pointer.
+    #
+    # # Safety
+    #
+    # - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+    TraderId_t trader_id_from_pystr(PyObject *ptr);
+
+    # Returns zero and writes a Nautilus identifier to `out` from a valid Python
+    # object pointer, otherwise returns the non-zero `IdentifierError` code.
+    #
+    # # Safety
+    #
+    # - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+    # - `out` must be a valid pointer to uninitialized memory to write the
+    #   identifier to, as any value already there is not dropped.
+    uint8_t trader_id_try_from_pystr(PyObject *ptr, TraderId_t *out);
+
+    # Returns a pointer to a valid Python UTF-8 string.
+    #
+    # # Safety
+    #
+    # - Assumes that since the data is originating from Rust, the GIL does not need
+    #   to be acquired.
+    # - Assumes you are immediately returning this pointer to Python.
+    PyObject *trader_id_to_pystr(const TraderId_t *identifier);
+
+    # Returns a view of the value bytes (UTF-8, not NUL terminated), valid for
+    # the lifetime of the identifier, without allocating.
+    StrView trader_id_as_bytes(const TraderId_t *identifier);
+
+    # Returns the hash of the value, precomputed when interned, for the Python
+    # `__hash__`.
+    uint64_t trader_id_hash(const TraderId_t *identifier);
+
+    # Returns a pointer to a valid Python UTF-8 string of the name part.
+    #
+    # # Safety
+    #
+    # - Assumes that since the data is originating from Rust, the GIL does not need
+    #   to be acquired.
+    # - Assumes you are immediately returning this pointer to Python.
+    PyObject *trader_id_name_to_pystr(const TraderId_t *trader_id);
+
+    # Returns a pointer to a valid Python UTF-8 string of the tag part.
+    #
+    # # Safety
+    #
+    # - Assumes that since the data is originating from Rust, the GIL does not need
+    #   to be acquired.
+    # - Assumes you are immediately returning this pointer to Python.
+    PyObject *trader_id_tag_to_pystr(const TraderId_t *trader_id);
+
+    void venue_free(Venue_t identifier);
+
+    # Returns a Nautilus identifier from a valid Python object pointer.
+    #
+    # # Safety
+    #
+    # - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+    Venue_t venue_from_pystr(PyObject *ptr);
+
+    # Returns zero and writes a Nautilus identifier to `out` from a valid Python
+    # object pointer, otherwise returns the non-zero `IdentifierError` code.
+    #
+    # # Safety
+    #
+    # - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+    # - `out` must be a valid pointer to uninitialized memory to write the
+    #   identifier to, as any value already there is not dropped.
+    uint8_t venue_try_from_pystr(PyObject *ptr, Venue_t *out);
+
+    # Returns a pointer to a valid Python UTF-8 string.
+    #
+    # # Safety
+    #
+    # - Assumes that since the data is originating from Rust, the GIL does not need
+    #   to be acquired.
+    # - Assumes you are immediately returning this pointer to Python.
+    PyObject *venue_to_pystr(const Venue_t *identifier);
+
+    # Returns a view of the value bytes (UTF-8, not NUL terminated), valid for
+    # the lifetime of the identifier, without allocating.
+    StrView venue_as_bytes(const Venue_t *identifier);
+
+    # Returns the hash of the value, precomputed when interned, for the Python
+    # `__hash__`.
+    uint64_t venue_hash(const Venue_t *identifier);
+
+    # Returns a Nautilus identifier from a valid Python object pointer, with the
+    # value in canonical form.
+    #
+    # # Safety
+    #
+    # - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+    Venue_t venue_canonical_from_pystr(PyObject *ptr);
+
+    uint8_t venue_eq_ignore_case(const Venue_t *venue, const Venue_t *other);
+
+    uint8_t venue_is_synthetic(const Venue_t *venue);
+
+    # Returns a clone of the identifier, e.g. an element of the vec returned by
+    # `venues_from_pylist` for ownership by Python.
+    Venue_t venue_clone(const Venue_t *venue);
+
+    # Returns a vec of Nautilus identifiers from an array of `len` valid Python
+    # object pointers (e.g. the items of a list), acquiring the GIL once for
+    # the batch. The vec must be passed to `venues_drop` to free it.
+    #
+    # # Safety
+    #
+    # - `ptrs` must point to `len` pointers borrowed from valid Python UTF-8 `str`s.
+    CVec venues_from_pylist(PyObject *const *ptrs, uintptr_t len);
+
+    # Returns a pointer to a new Python list of the identifier strings.
+    #
+    # # Safety
+    #
+    # - `venues` must have been returned by `venues_from_pylist`.
+    # - Assumes that since the data is originating from Rust, the GIL does not need
+    #   to be acquired.
+    # - Assumes you are immediately returning this pointer to Python.
+    PyObject *venues_to_pylist(const CVec *venues);
+
+    # Drops a vec of identifiers returned by `venues_from_pylist`.
+    #
+    # # Safety
+    #
+    # - `venues` must have been returned by `venues_from_pylist`.
+    void venues_drop(CVec venues);
+
+    void venue_order_id_free(VenueOrderId_t identifier);
+
+    # Returns a Nautilus identifier from a valid Python object pointer.
+    #
+    # # Safety
+    #
+    # - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+    VenueOrderId_t venue_order_id_from_pystr(PyObject *ptr);
+
+    # Returns zero and writes a Nautilus identifier to `out` from a valid Python
+    # object pointer, otherwise returns the non-zero `IdentifierError` code.
+    #
+    # # Safety
+    #
+    # - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+    # - `out` must be a valid pointer to uninitialized memory to write the
+    #   identifier to, as any value already there is not dropped.
+    uint8_t venue_order_id_try_from_pystr(PyObject *ptr, VenueOrderId_t *out);
+
+    # Returns a pointer to a valid Python UTF-8 string.
+    #
+    # # Safety
+    #
+    # - Assumes that since the data is originating from Rust, the GIL does not need
+    #   to be acquired.
+    # - Assumes you are immediately returning this pointer to Python.
+    PyObject *venue_order_id_to_pystr(const VenueOrderId_t *identifier);
+
+    # Returns a view of the value bytes (UTF-8, not NUL terminated), valid for
+    # the lifetime of the identifier, without allocating.
+    StrView venue_order_id_as_bytes(const VenueOrderId_t *identifier);
+
+    # Returns the hash of the value, precomputed when interned, for the Python
+    # `__hash__`.
+    uint64_t venue_order_id_hash(const VenueOrderId_t *identifier);
+
+    OrderBook order_book_new(InstrumentId_t instrument_id, BookLevel book_level);
+
+    QueueEstimator queue_estimator_new(uint64_t half_life_ns);
+
+    void queue_estimator_on_trade(QueueEstimator *estimator, double size, int64_t ts_event);
+
+    void queue_estimator_on_cancel(QueueEstimator *estimator, double size, int64_t ts_event);
+
+    QueueEstimate queue_estimator_estimate(const QueueEstimator *estimator,
+                                           const OrderBook *book,
+                                           OrderSide side,
+                                           const Price_t *price,
+                                           const Quantity_t *quantity,
+                                           double horizon_secs,
+                                           int64_t ts);
+
+    void session_stats_record_trade(const TradeTick_t *tick);
+
+    void session_stats_start(const InstrumentId_t *instrument_id, int64_t ts_start);
+
+    # Returns the session statistics for the instrument (all zero if no session
+    # has been started).
+    SessionStats session_stats_get(const InstrumentId_t *instrument_id);
+
+    void session_stats_reset();
+
+    # Records a fill for the instrument, measuring slippage against the expected
+    # price unless `expected_px` is null.
+    #
+    # # Safety
+    #
+    # - `expected_px` must be null or a valid pointer to a `Price`.
+    void trading_stats_record_fill(const InstrumentId_t *instrument_id,
+                                   OrderSide side,
+                                   const Quantity_t *last_qty,
+                                   const Price_t *last_px,
+                                   double commission,
+                                   const Price_t *expected_px);
+
+    void trading_stats_record_round_trip(const InstrumentId_t *instrument_id, double realized_pnl);
+
+    # Returns the trading statistics for the instrument (all zero if nothing has
+    # been recorded).
+    TradingStats trading_stats_get(const InstrumentId_t *instrument_id);
+
+    void trading_stats_reset();
+
+    # Returns zero after loading the mappings from the file at the path into the
+    # process wide symbology, otherwise the non-zero error code (1 conflict,
+    # 2 parse, 3 I/O).
+    #
+    # # Safety
+    #
+    # - `path_ptr` must be borrowed from a valid Python UTF-8 `str`.
+    uint8_t symbology_load_file(PyObject *path_ptr);
+
+    # Returns zero after mapping the instrument to the code in the scheme,
+    # otherwise the non-zero error code (1 conflict).
+    #
+    # # Safety
+    #
+    # - `scheme_ptr` and `code_ptr` must be borrowed from valid Python UTF-8 `str`(s).
+    uint8_t symbology_insert(const InstrumentId_t *instrument_id,
+                             PyObject *scheme_ptr,
+                             PyObject *code_ptr);
+
+    # Writes the instrument ID for the code in the scheme to `out`, returning 1
+    # if mapped (otherwise 0 and `out` is unchanged).
+    #
+    # # Safety
+    #
+    # - `scheme_ptr` and `code_ptr` must be borrowed from valid Python UTF-8 `str`(s).
+    # - `out` must be a valid pointer to an initialized `InstrumentId`.
+    uint8_t symbology_instrument_id(PyObject *scheme_ptr, PyObject *code_ptr, InstrumentId_t *out);
+
+    # Returns the Python UTF-8 string of the code in the scheme for the
+    # instrument ID, or a new reference to `None` if not mapped.
+    #
+    # # Safety
+    #
+    # - `scheme_ptr` must be borrowed from a valid Python UTF-8 `str`.
+    # - Assumes the GIL is held, as when called from Python.
+    PyObject *symbology_code_to_pystr(const InstrumentId_t *instrument_id, PyObject *scheme_ptr);
+
+    void symbology_clear();
+
+    void conversion_audit_set_enabled(uint8_t enabled);
+
+    uint8_t conversion_audit_is_enabled();
+
+    void conversion_audit_reset();
+
+    # Returns the number of lossy conversions recorded for the component.
+    #
+    # # Safety
+    #
+    # - `component` must be borrowed from a valid Python UTF-8 `str`.
+    uint64_t conversion_audit_count(PyObject *component);
+
+    # Returns a pointer to a new Python dict of the lossy conversion counts keyed
+    # by component.
+    #
+    # # Safety
+    #
+    # - Assumes that since the data is originating from Rust, the GIL does not need
+    #   to be acquired.
+    # - Assumes you are immediately returning this pointer to Python.
+    PyObject *conversion_audit_counts_to_pydict();
+
+    # Returns zero and writes the validated balance to `out`, otherwise returns
+    # the non-zero `BalanceError` code.
+    #
+    # # Safety
+    #
+    # - `out` must be a valid pointer to write the value to.
+    uint8_t account_balance_new(Money_t total, Money_t locked, Money_t free, AccountBalance_t *out);
+
+    void account_balance_free(AccountBalance_t balance);
+
+    Currency_t account_balance_get_currency(const AccountBalance_t *balance);
+
+    Money_t account_balance_get_total(const AccountBalance_t *balance);
+
+    Money_t account_balance_get_locked(const AccountBalance_t *balance);
+
+    Money_t account_balance_get_free(const AccountBalance_t *balance);
+
+    uint8_t account_balance_eq(const AccountBalance_t *a, const AccountBalance_t *b);
+
+    # Returns zero and writes the validated margin balance to `out`, otherwise
+    # returns the non-zero `BalanceError` code.
+    #
+    # # Safety
+    #
+    # - `out` must be a valid pointer to write the value to.
+    uint8_t margin_balance_new(Money_t initial,
+                               Money_t maintenance,
+                               const InstrumentId_t *instrument_id,
+                               MarginBalance_t *out);
+
+    void margin_balance_free(MarginBalance_t balance);
+
+    Currency_t margin_balance_get_currency(const MarginBalance_t *balance);
+
+    Money_t margin_balance_get_initial(const MarginBalance_t *balance);
+
+    Money_t margin_balance_get_maintenance(const MarginBalance_t *balance);
+
+    # Returns the instrument ID of the margin balance (null if account wide),
+    # borrowed for the lifetime of the balance.
+    const InstrumentId_t *margin_balance_get_instrument_id(const MarginBalance_t *balance);
+
+    uint8_t margin_balance_eq(const MarginBalance_t *a, const MarginBalance_t *b);
+
+    # Returns a `Currency` from valid Python object pointers and primitives.
+    #
+    # # Safety
+    #
+    # - `code_ptr` and `name_ptr` must be borrowed from a valid Python UTF-8 `str`(s).
+    Currency_t currency_from_py(PyObject *code_ptr,
+                                uint8_t precision,
+                                uint16_t iso4217,
+                                PyObject *name_ptr,
+                                CurrencyType currency_type);
+
+    # Returns a pointer to a valid Python UTF-8 string.
+    #
+    # # Safety
+    #
+    # - Assumes that since the data is originating from Rust, the GIL does not need
+    #   to be acquired.
+    # - Assumes you are immediately returning this pointer to Python.
+    PyObject *currency_code_to_pystr(const Currency_t *currency);
+
+    # Returns a pointer to a valid Python UTF-8 string.
+    #
+    # # Safety
+    #
+    # - Assumes that since the data is originating from Rust, the GIL does not need
+    #   to be acquired.
+    # - Assumes you are immediately returning this pointer to Python.
+    PyObject *currency_name_to_pystr(const Currency_t *currency);
+
+    void currency_free(Currency_t currency);
+
+    # Returns 1 and writes the registered currency to `out` if found for the
+    # code, otherwise returns 0.
+    #
+    # # Safety
+    #
+    # - `code_ptr` must be borrowed from a valid Python UTF-8 `str`.
+    # - `out` must be a valid pointer to write the currency to.
+    uint8_t currency_from_pystr(PyObject *code_ptr, Currency_t *out);
+
+    # Returns 1 if a currency is registered for the code, otherwise 0.
+    #
+    # # Safety
+    #
+    # - `code_ptr` must be borrowed from a valid Python UTF-8 `str`.
+    uint8_t currency_exists(PyObject *code_ptr);
+
+    # Registers a currency from valid Python object pointers and primitives so
+    # subsequent lookups by code resolve it, returning 1 if it was registered or
+    # 0 if the code already existed and `overwrite` was 0.
+    #
+    # # Safety
+    #
+    # - `code_ptr` and `name_ptr` must be borrowed from a valid Python UTF-8 `str`(s).
+    uint8_t currency_register(PyObject *code_ptr,
+                              uint8_t precision,
+                              uint16_t iso4217,
+                              PyObject *name_ptr,
+                              CurrencyType currency_type,
+                              uint8_t overwrite);
+
+    uint64_t currency_hash(const Currency_t *currency);
+
+    uint8_t currency_eq(const Currency_t *a, const Currency_t *b);
+
+    NumberFormat number_format_default();
+
+    # Returns zero and writes the format of the locale to `out`, otherwise
+    # returns 1 if the locale is not supported.
+    #
+    # # Safety
+    #
+    # - `locale_ptr` must be borrowed from a valid Python UTF-8 `str`.
+    # - `out` must be a valid pointer to write the format to.
+    uint8_t number_format_for_locale(PyObject *locale_ptr, NumberFormat *out);
+
+    Money_t money_new(double amount, Currency_t currency);
+
+    Money_t money_from_raw(int64_t raw, Currency_t currency);
+
+    void money_free(Money_t money);
+
+    double money_as_f64(const Money_t *money);
+
+    int64_t money_raw(const Money_t *money);
+
+    # Returns a pointer to a valid Python UTF-8 string of the currency code.
+    #
+    # # Safety
+    #
+    # - Assumes that since the data is originating from Rust, the GIL does not need
+    #   to be acquired.
+    # - Assumes you are immediately returning this pointer to Python.
+    PyObject *money_currency_code_to_pystr(const Money_t *money);
+
+    # Returns a pointer to a valid Python UTF-8 string of the formatted amount
+    # and currency code (e.g. "1_000.00 USD").
+    #
+    # # Safety
+    #
+    # - Assumes that since the data is originating from Rust, the GIL does not need
+    #   to be acquired.
+    # - Assumes you are immediately returning this pointer to Python.
+    PyObject *money_to_pystr(const Money_t *money);
+
+    # Returns a pointer to a valid Python UTF-8 string of the amount and currency
+    # code formatted by the format.
+    #
+    # # Safety
+    #
+    # - Assumes that since the data is originating from Rust, the GIL does not need
+    #   to be acquired.
+    # - Assumes you are immediately returning this pointer to Python.
+    PyObject *money_to_formatted_pystr(const Money_t *money, const NumberFormat *format);
+
+    uint64_t money_hash(const Money_t *money);
+
+    uint8_t money_eq(const Money_t *a, const Money_t *b);
+
+    uint8_t money_ne(const Money_t *a, const Money_t *b);
+
+    # Returns zero and writes -1, 0 or 1 to `out` as `a` is less than, equal to
+    # or greater than `b`, otherwise returns the non-zero `FixedPointError` code
+    # if the currencies differ.
+    #
+    # # Safety
+    #
+    # - `out` must be a valid pointer to write the value to.
+    uint8_t money_checked_cmp(const Money_t *a, const Money_t *b, int8_t *out);
+
+    # Returns zero and writes the lesser of the values (`a` if equal) to `out`,
+    # otherwise returns the non-zero `FixedPointError` code if the currencies
+    # differ.
+    #
+    # # Safety
+    #
+    # - `out` must be a valid pointer to write the value to.
+    uint8_t money_checked_min(const Money_t *a, const Money_t *b, Money_t *out);
+
+    # Returns zero and writes the greater of the values (`b` if equal) to `out`,
+    # otherwise returns the non-zero `FixedPointError` code if the currencies
+    # differ.
+    #
+    # # Safety
+    #
+    # - `out` must be a valid pointer to write the value to.
+    uint8_t money_checked_max(const Money_t *a, const Money_t *b, Money_t *out);
+
+    # Returns zero and writes `a + b` to `out`, otherwise returns the non-zero
+    # `FixedPointError` code if the currencies differ or the sum overflows.
+    #
+    # # Safety
+    #
+    # - `out` must be a valid pointer to write the value to.
+    uint8_t money_checked_add(const Money_t *a, const Money_t *b, Money_t *out);
+
+    # Returns zero and writes `a - b` to `out`, otherwise returns the non-zero
+    # `FixedPointError` code if the currencies differ or the difference
+    # overflows.
+    #
+    # # Safety
+    #
+    # - `out` must be a valid pointer to write the value to.
+    uint8_t money_checked_sub(const Money_t *a, const Money_t *b, Money_t *out);
+
+    # Returns zero and writes `a * b` to `out`, otherwise returns the non-zero
+    # `FixedPointError` code if the currencies differ, or the product is not
+    # exact at the currency precision or overflows.
+    #
+    # # Safety
+    #
+    # - `out` must be a valid pointer to write the value to.
+    uint8_t money_checked_mul(const Money_t *a, const Money_t *b, Money_t *out);
+
+    # Returns zero and writes `-money` to `out`, otherwise returns the non-zero
+    # `FixedPointError` code if the negation overflows.
+    #
+    # # Safety
+    #
+    # - `out` must be a valid pointer to write the value to.
+    uint8_t money_checked_neg(const Money_t *money, Money_t *out);
+
+    void money_add_assign(Money_t *a, const Money_t *b);
+
+    void money_sub_assign(Money_t *a, const Money_t *b);
+
+    # Returns zero and writes the notional value of the standard contracts to
+    # `out`, otherwise returns the non-zero `FixedPointError` code.
+    #
+    # # Safety
+    #
+    # - `out` must be a valid pointer to write the value to.
+    uint8_t notional_value_from_quantity(const Quantity_t *quantity,
+                                         const Price_t *price,
+                                         const Quantity_t *multiplier,
+                                         const Currency_t *currency,
+                                         Money_t *out);
+
+    # Returns zero and writes the notional value of the inverse contracts to
+    # `out`, otherwise returns the non-zero `FixedPointError` code.
+    #
+    # # Safety
+    #
+    # - `out` must be a valid pointer to write the value to.
+    uint8_t inverse_notional_value_from_quantity(const Quantity_t *quantity,
+                                                 const Price_t *price,
+                                                 const Quantity_t *multiplier,
+                                                 const Currency_t *currency,
+                                                 Money_t *out);
+
+    # Returns zero and writes the quantity of standard contracts for the
+    # notional to `out`, otherwise returns the non-zero `FixedPointError` code.
+    #
+    # # Safety
+    #
+    # - `out` must be a valid pointer to write the value to.
+    uint8_t notional_value_to_quantity(const Money_t *notional,
+                                       const Price_t *price,
+                                       const Quantity_t *multiplier,
+                                       uint8_t precision,
+                                       RoundingMode mode,
+                                       Quantity_t *out);
+
+    # Returns zero and writes the quantity of inverse contracts for the notional
+    # to `out`, otherwise returns the non-zero `FixedPointError` code.
+    #
+    # # Safety
+    #
+    # - `out` must be a valid pointer to write the value to.
+    uint8_t inverse_notional_value_to_quantity(const Money_t *notional,
+                                               const Price_t *price,
+                                               const Quantity_t *multiplier,
+                                               uint8_t precision,
+                                               RoundingMode mode,
+                                               Quantity_t *out);
+
+    # Returns the value rounded to the precision, recording a lossy conversion
+    # for the `"Price"` component when auditing is enabled.
+    Price_t price_new(double value, uint8_t precision);
+
+    # Returns the value rounded to the precision, recording a lossy conversion
+    # for the component when auditing is enabled.
+    #
+    # # Safety
+    #
+    # - `component` must be borrowed from a valid Python UTF-8 `str`.
+    Price_t price_new_audited(double value, uint8_t precision, PyObject *component);
+
+    # Returns zero and writes the value to `out` if exactly representable at the
+    # precision, otherwise returns the non-zero `FixedPointError` code.
+    #
+    # # Safety
+    #
+    # - `out` must be a valid pointer to write the value to.
+    uint8_t price_from_f64_checked(double value, uint8_t precision, Price_t *out);
+
+    Price_t price_from_raw(int64_t raw, uint8_t precision);
+
+    void price_free(Price_t price);
+
+    double price_as_f64(const Price_t *price);
+
+    # Returns zero and writes the parsed value to `out` if the string is a
+    # decimal number exactly representable at its precision, otherwise returns
+    # the non-zero `FixedPointError` code.
+    #
+    # # Safety
+    #
+    # - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+    # - `out` must be a valid pointer to write the value to.
+    uint8_t price_from_pystr(PyObject *ptr, Price_t *out);
+
+    # Returns a pointer to a valid Python UTF-8 string of the value formatted at
+    # its precision.
+    #
+    # # Safety
+    #
+    # - Assumes that since the data is originating from Rust, the GIL does not need
+    #   to be acquired.
+    # - Assumes you are immediately returning this pointer to Python.
+    PyObject *price_to_pystr(const Price_t *price);
+
+    # Returns a pointer to a valid Python UTF-8 string of the value formatted by
+    # the format.
+    #
+    # # Safety
+    #
+    # - Assumes that since the data is originating from Rust, the GIL does not need
+    #   to be acquired.
+    # - Assumes you are immediately returning this pointer to Python.
+    PyObject *price_to_formatted_pystr(const Price_t *price, const NumberFormat *format);
+
+    uint64_t price_hash(const Price_t *price);
+
+    uint8_t price_eq(const Price_t *a, const Price_t *b);
+
+    # Returns -1, 0 or 1 as `a` is less than, equal to or greater than `b`.
+    int8_t price_cmp(const Price_t *a, const Price_t *b);
+
+    uint8_t price_ne(const Price_t *a, const Price_t *b);
+
+    uint8_t price_lt(const Price_t *a, const Price_t *b);
+
+    uint8_t price_le(const Price_t *a, const Price_t *b);
+
+    uint8_t price_gt(const Price_t *a, const Price_t *b);
+
+    uint8_t price_ge(const Price_t *a, const Price_t *b);
+
+    # Returns the lesser of the values (`a` if equal).
+    Price_t price_min(const Price_t *a, const Price_t *b);
+
+    # Returns the greater of the values (`b` if equal).
+    Price_t price_max(const Price_t *a, const Price_t *b);
+
+    Price_t price_add(const Price_t *a, const Price_t *b);
+
+    Price_t price_sub(const Price_t *a, const Price_t *b);
+
+    Price_t price_mul(const Price_t *a, const Price_t *b);
+
+    Price_t price_neg(const Price_t *price);
+
+    # Returns zero and writes `a + b` to `out`, otherwise returns the non-zero
+    # `FixedPointError` code if the precisions differ or the sum overflows.
+    #
+    # # Safety
+    #
+    # - `out` must be a valid pointer to write the value to.
+    uint8_t price_checked_add(const Price_t *a, const Price_t *b, Price_t *out);
+
+    # Returns zero and writes `a - b` to `out`, otherwise returns the non-zero
+    # `FixedPointError` code if the precisions differ or the difference
+    # overflows.
+    #
+    # # Safety
+    #
+    # - `out` must be a valid pointer to write the value to.
+    uint8_t price_checked_sub(const Price_t *a, const Price_t *b, Price_t *out);
+
+    # Returns zero and writes `a * b` to `out`, otherwise returns the non-zero
+    # `FixedPointError` code if the product loses precision or overflows.
+    #
+    # # Safety
+    #
+    # - `out` must be a valid pointer to write the value to.
+    uint8_t price_checked_mul(const Price_t *a, const Price_t *b, Price_t *out);
+
+    # Returns zero and writes `-price` to `out`, otherwise returns the non-zero
+    # `FixedPointError` code if the negation overflows.
+    #
+    # # Safety
+    #
+    # - `out` must be a valid pointer to write the value to.
+    uint8_t price_checked_neg(const Price_t *price, Price_t *out);
+
+    void price_add_assign(Price_t *a, const Price_t *b);
+
+    void price_sub_assign(Price_t *a, const Price_t *b);
+
+    # Returns zero and writes the band from the percentages below and above the
+    # reference price to `out`, otherwise returns the non-zero `FixedPointError`
+    # code.
+    #
+    # # Safety
+    #
+    # - `out` must be a valid pointer to write the value to.
+    uint8_t price_band_from_percent(const Price_t *reference,
+                                    double lower_pct,
+                                    double upper_pct,
+                                    PriceBand *out);
+
+    # Returns zero and writes the band from the absolute offsets below and above
+    # the reference price to `out`, otherwise returns the non-zero
+    # `FixedPointError` code.
+    #
+    # # Safety
+    #
+    # - `out` must be a valid pointer to write the value to.
+    uint8_t price_band_from_offsets(const Price_t *reference,
+                                    const Price_t *lower_offset,
+                                    const Price_t *upper_offset,
+                                    PriceBand *out);
+
+    # Returns -1 if the price is below the band, 1 if above, otherwise 0.
+    int8_t price_band_cmp(const PriceBand *band, const Price_t *price);
+
+    uint8_t price_band_contains(const PriceBand *band, const Price_t *price);
+
+    Price_t price_band_clamp(const PriceBand *band, const Price_t *price);
+
+    # Returns the value rounded to the precision, recording a lossy conversion
+    # for the `"Quantity"` component when auditing is enabled.
+    Quantity_t quantity_new(double value, uint8_t precision);
+
+    # Returns the value rounded to the precision, recording a lossy conversion
+    # for the component when auditing is enabled.
+    #
+    # # Safety
+    #
+    # - `component` must be borrowed from a valid Python UTF-8 `str`.
+    Quantity_t quantity_new_audited(double value, uint8_t precision, PyObject *component);
+
+    # Returns zero and writes the value to `out` if exactly representable at the
+    # precision, otherwise returns the non-zero `FixedPointError` code.
+    #
+    # # Safety
+    #
+    # - `out` must be a valid pointer to write the value to.
+    uint8_t quantity_from_f64_checked(double value, uint8_t precision, Quantity_t *out);
+
+    Quantity_t quantity_from_raw(uint64_t raw, uint8_t precision);
+
+    void quantity_free(Quantity_t qty);
+
+    double quantity_as_f64(const Quantity_t *qty);
+
+    # Returns zero and writes the parsed value to `out` if the string is a
+    # non-negative decimal number exactly representable at its precision,
+    # otherwise returns the non-zero `FixedPointError` code.
+    #
+    # # Safety
+    #
+    # - `ptr` must be borrowed from a valid Python UTF-8 `str`.
+    # - `out` must be a valid pointer to write the value to.
+    uint8_t quantity_from_pystr(PyObject *ptr, Quantity_t *out);
+
+    # Returns a pointer to a valid Python UTF-8 string of the value formatted at
+    # its precision.
+    #
+    # # Safety
+    #
+    # - Assumes that since the data is originating from Rust, the GIL does not need
+    #   to be acquired.
+    # - Assumes you are immediately returning this pointer to Python.
+    PyObject *quantity_to_pystr(const Quantity_t *qty);
+
+    uint64_t quantity_hash(const Quantity_t *qty);
+
+    uint8_t quantity_eq(const Quantity_t *a, const Quantity_t *b);
+
+    # Returns -1, 0 or 1 as `a` is less than, equal to or greater than `b`.
+    int8_t quantity_cmp(const Quantity_t *a, const Quantity_t *b);
+
+    uint8_t quantity_ne(const Quantity_t *a, const Quantity_t *b);
+
+    uint8_t quantity_lt(const Quantity_t *a, const Quantity_t *b);
+
+    uint8_t quantity_le(const Quantity_t *a, const Quantity_t *b);
+
+    uint8_t quantity_gt(const Quantity_t *a, const Quantity_t *b);
+
+    uint8_t quantity_ge(const Quantity_t *a, const Quantity_t *b);
+
+    # Returns the lesser of the values (`a` if equal).
+    Quantity_t quantity_min(const Quantity_t *a, const Quantity_t *b);
+
+    # Returns the greater of the values (`b` if equal).
+    Quantity_t quantity_max(const Quantity_t *a, const Quantity_t *b);
+
+    uint8_t quantity_is_positive(const Quantity_t *qty);
+
+    # Returns zero and writes `a + b` to `out`, otherwise returns the non-zero
+    # `FixedPointError` code if the precisions differ or the sum overflows.
+    #
+    # # Safety
+    #
+    # - `out` must be a valid pointer to write the value to.
+    uint8_t quantity_checked_add(const Quantity_t *a, const Quantity_t *b, Quantity_t *out);
+
+    # Returns zero and writes `a - b` to `out`, otherwise returns the non-zero
+    # `FixedPointError` code if the precisions differ or `b` exceeds `a`.
+    #
+    # # Safety
+    #
+    # - `out` must be a valid pointer to write the value to.
+    uint8_t quantity_checked_sub(const Quantity_t *a, const Quantity_t *b, Quantity_t *out);
+
+    # Returns zero and writes `a * b` to `out`, otherwise returns the non-zero
+    # `FixedPointError` code if the product loses precision or overflows.
+    #
+    # # Safety
+    #
+    # - `out` must be a valid pointer to write the value to.
+    uint8_t quantity_checked_mul(const Quantity_t *a, const Quantity_t *b, Quantity_t *out);
+
+    # Returns 1 if `qty` is a whole multiple of `step` within the bounds,
+    # otherwise 0. A null `min` or `max` means no bound.
+    #
+    # # Safety
+    #
+    # - `min` and `max` must each be null or a valid pointer to a `Quantity`.
+    uint8_t quantity_is_valid_for(const Quantity_t *qty,
+                                  const Quantity_t *step,
+                                  const Quantity_t *min,
+                                  const Quantity_t *max);
+
+    # Returns the number of the `len` quantities which are not valid for `step`
+    # and the bounds (as `quantity_is_valid_for`), writing 1 (valid) or 0
+    # (invalid) for each to `out` if it is not null.
+    #
+    # # Safety
+    #
+    # - `qtys` must point to `len` valid quantities (or be null if `len` is zero).
+    # - `min` and `max` must each be null or a valid pointer to a `Quantity`.
+    # - `out` must be null or point to a buffer of at least `len` bytes.
+    uintptr_t quantities_validate(const Quantity_t *qtys,
+                                  uintptr_t len,
+                                  const Quantity_t *step,
+                                  const Quantity_t *min,
+                                  const Quantity_t *max,
+                                  uint8_t *out);
+
+    void quantity_add_assign(Quantity_t *a, const Quantity_t *b);
+
+    void quantity_add_assign_u64(Quantity_t *a, uint64_t b);
+
+    void quantity_sub_assign(Quantity_t *a, const Quantity_t *b);
+
+    void quantity_sub_assign_u64(Quantity_t *a, uint64_t b);
+
+    # Returns zero and writes the raw value rounded to a multiple of the raw
+    # increment to `out`, otherwise returns the non-zero `FixedPointError` code.
+    #
+    # # Safety
+    #
+    # - `out` must be a valid pointer to write the value to.
+    uint8_t rounding_round_raw(int64_t raw, int64_t increment, RoundingMode mode, int64_t *out);
+
+    # Returns zero and writes the price rounded to a multiple of the tick size
+    # to `out`, otherwise returns the non-zero `FixedPointError` code.
+    #
+    # # Safety
+    #
+    # - `out` must be a valid pointer to write the value to.
+    uint8_t price_round_to_tick(const Price_t *price,
+                                const Price_t *tick,
+                                RoundingMode mode,
+                                Price_t *out);
+
+    double option_black_scholes_price(OptionKind kind,
+                                      double spot,
+                                      double strike,
+                                      double t,
+                                      double rate,
+                                      double vol);
+
+    double option_black_scholes_delta(OptionKind kind,
+                                      double spot,
+                                      double strike,
+                                      double t,
+                                      double rate,
+                                      double vol);
+
+    # Returns the implied volatility of the option price (NaN if none).
+    double option_implied_volatility(OptionKind kind,
+                                     double price,
+                                     double spot,
+                                     double strike,
+                                     double t,
+                                     double rate);
+
+    # Returns the implied volatility added to the surface for the underlying (NaN
+    # if none, in which case the surface is unchanged).
+    #
+    # # Safety
+    #
+    # - `underlying_ptr` must be borrowed from a valid Python UTF-8 `str`.
+    double volatility_surface_update(PyObject *underlying_ptr,
+                                     OptionKind kind,
+                                     int64_t expiry_ns,
+                                     const Price_t *strike,
+                                     double option_price,
+                                     double spot,
+                                     double rate,
+                                     int64_t ts_now);
+
+    # Returns the volatility of the surface for the underlying at the expiry and
+    # strike (NaN if none).
+    #
+    # # Safety
+    #
+    # - `underlying_ptr` must be borrowed from a valid Python UTF-8 `str`.
+    double volatility_surface_get(PyObject *underlying_ptr,
+                                  int64_t expiry_ns,
+                                  double strike,
+                                  int64_t ts_now);
+
+    # Returns the volatility of the surface for the underlying at the expiry and
+    # delta, writing the strike to `strike_out` (NaN if none).
+    #
+    # # Safety
+    #
+    # - `underlying_ptr` must be borrowed from a valid Python UTF-8 `str`.
+    # - `strike_out` must be a valid pointer to write the strike to.
+    double volatility_surface_at_delta(PyObject *underlying_ptr,
+                                       OptionKind kind,
+                                       int64_t expiry_ns,
+                                       double delta,
+                                       double spot,
+                                       double rate,
+                                       int64_t ts_now,
+                                       double *strike_out);
+
+    void volatility_surfaces_reset();
@@ -25,7 +25,7 @@ from nautilus_trader.core.correctness cimport Condition
 cpdef inline uint8_t precision_from_str(str value) except *:
     """
     Return the decimal precision inferred from the given string.
-    Can accept scientific notation strings including an 'e' character.
+    Can accept scientific notation strings including an 'e' or 'E' exponent.
 
     Parameters
     ----------
@@ -40,19 +40,31 @@ cpdef inline uint8_t precision_from_str(str value) except *:
     ------
     ValueError
         If value is not a valid string.
+    ValueError
+        If the exponent is not an optionally signed integer.
 
     Notes
     -----
-    If not scientific notation and no decimal point '.', then precision will be
-    inferred as zero.
+    The precision is the number of digits following the decimal point '.' less
+    the exponent (if any), and is never negative. For example "1.5e-8" has a
+    precision of 9 and "2.5E3" a precision of zero.
 
     """
     Condition.valid_string(value, "value")
 
-    value = value.lower()
-    if value.find("e-") > -1:
-        # Scientific notation string
-        return int(value.partition('e-')[2])
-    else:
-        # If does not contain "." then partition[2] will be ""
-        return len(value.partition('.')[2])
+    cdef str mantissa
+    cdef str separator
+    cdef str exponent
+    cdef str digits
+    mantissa, separator, exponent = value.lower().partition("e")
+
+    # If does not contain "." then partition[2] will be ""
+    precision = len(mantissa.partition(".")[2])  # Not a C int, as exponents may be large
+    if separator:
+        # Stricter than `int`, which also accepts whitespace and underscores
+        digits = exponent[1:] if exponent[:1] in ("+", "-") else exponent
+        if not (digits.isascii() and digits.isdigit()):
+            raise ValueError(f"The exponent of `value` was malformed, was {value}")
+        precision -= int(exponent)
+
+    return min(max(precision, 0), 255)
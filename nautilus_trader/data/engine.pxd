@@ -50,6 +50,8 @@ cdef class DataEngine(Component):
 
     cdef readonly bint debug
     """If debug mode is active (will provide extra debug logging).\n\n:returns: `bool`"""
+    cdef readonly bint filter_duplicate_ticks
+    """If duplicate consecutive quote and trade ticks are dropped.\n\n:returns: `bool`"""
     cdef readonly int command_count
     """The total count of data commands received by the engine.\n\n:returns: `int`"""
     cdef readonly int data_count
@@ -58,6 +60,10 @@ cdef class DataEngine(Component):
     """The total count of data requests received by the engine.\n\n:returns: `int`"""
     cdef readonly int response_count
     """The total count of data responses received by the engine.\n\n:returns: `int`"""
+    cdef readonly int duplicate_quote_count
+    """The total count of duplicate quote ticks dropped by the engine.\n\n:returns: `int`"""
+    cdef readonly int duplicate_trade_count
+    """The total count of duplicate trade ticks dropped by the engine.\n\n:returns: `int`"""
 
     cpdef bint check_connected(self) except *
     cpdef bint check_disconnected(self) except *
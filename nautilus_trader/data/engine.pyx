@@ -45,6 +45,9 @@ from nautilus_trader.common.logging cimport RES
 from nautilus_trader.common.logging cimport Logger
 from nautilus_trader.core.correctness cimport Condition
 from nautilus_trader.core.data cimport Data
+from nautilus_trader.core.rust.model cimport quote_tick_is_duplicate
+from nautilus_trader.core.rust.model cimport session_stats_record_trade
+from nautilus_trader.core.rust.model cimport trade_tick_is_duplicate
 from nautilus_trader.data.aggregation cimport BarAggregator
 from nautilus_trader.data.aggregation cimport TickBarAggregator
 from nautilus_trader.data.aggregation cimport TimeBarAggregator
@@ -125,12 +128,15 @@ cdef class DataEngine(Component):
 
         # Settings
         self.debug = config.debug
+        self.filter_duplicate_ticks = config.filter_duplicate_ticks
 
         # Counters
         self.command_count = 0
         self.data_count = 0
         self.request_count = 0
         self.response_count = 0
+        self.duplicate_quote_count = 0
+        self.duplicate_trade_count = 0
 
         # Register endpoints
         self._msgbus.register(endpoint="DataEngine.execute", handler=self.execute)
@@ -479,6 +485,8 @@ cdef class DataEngine(Component):
         self.data_count = 0
         self.request_count = 0
         self.response_count = 0
+        self.duplicate_quote_count = 0
+        self.duplicate_trade_count = 0
 
     cpdef void _dispose(self) except *:
         cdef DataClient client
@@ -1097,6 +1105,15 @@ cdef class DataEngine(Component):
         )
 
     cdef void _handle_quote_tick(self, QuoteTick tick) except *:
+        cdef QuoteTick last
+        if self.filter_duplicate_ticks:
+            last = self._cache.quote_tick(tick.instrument_id)
+            if last is not None and quote_tick_is_duplicate(&tick._mem, &last._mem):
+                self.duplicate_quote_count += 1
+                if self.debug:
+                    self._log.debug(f"Dropped duplicate {tick}.")
+                return  # Duplicate
+
         self._cache.add_quote_tick(tick)
         self._msgbus.publish_c(
             topic=f"data.quotes"
@@ -1106,7 +1123,17 @@ cdef class DataEngine(Component):
         )
 
     cdef void _handle_trade_tick(self, TradeTick tick) except *:
+        cdef TradeTick last
+        if self.filter_duplicate_ticks:
+            last = self._cache.trade_tick(tick.instrument_id)
+            if last is not None and trade_tick_is_duplicate(&tick._mem, &last._mem):
+                self.duplicate_trade_count += 1
+                if self.debug:
+                    self._log.debug(f"Dropped duplicate {tick}.")
+                return  # Duplicate
+
         self._cache.add_trade_tick(tick)
+        session_stats_record_trade(&tick._mem)
         self._msgbus.publish_c(
             topic=f"data.trades"
                   f".{tick.instrument_id.venue}"
@@ -0,0 +1,29 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from nautilus_trader.cache.cache cimport Cache
+from nautilus_trader.model.data.bar cimport Bar
+from nautilus_trader.model.data.bar cimport BarType
+
+
+cdef class PatternDetection:
+    cdef readonly object pattern
+    """The detected pattern.\n\n:returns: `CandlePattern`"""
+    cdef readonly Bar bar
+    """The bar completing the pattern.\n\n:returns: `Bar`"""
+
+
+cpdef list detect_patterns(list bars, double doji_body_ratio=*, int breakout_period=*)
+cpdef list detect_cached_patterns(Cache cache, BarType bar_type, double doji_body_ratio=*, int breakout_period=*)
@@ -0,0 +1,197 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+"""
+Provides candle pattern detection over bars (engulfing, doji, inside bar and
+N-bar breakout), as building blocks for signal strategies.
+
+The detection runs in a single pass over the bars without per-bar Python
+overhead.
+"""
+
+from enum import Enum
+from enum import unique
+
+from cpython.mem cimport PyMem_Free
+from cpython.mem cimport PyMem_Malloc
+
+from nautilus_trader.cache.cache cimport Cache
+from nautilus_trader.core.correctness cimport Condition
+from nautilus_trader.core.rust.core cimport CVec
+from nautilus_trader.core.rust.model cimport Candle
+from nautilus_trader.core.rust.model cimport PatternDetection as PatternDetection_t
+from nautilus_trader.core.rust.model cimport PatternParams
+from nautilus_trader.core.rust.model cimport candle_patterns_detect
+from nautilus_trader.core.rust.model cimport pattern_detections_drop
+from nautilus_trader.model.data.bar cimport Bar
+from nautilus_trader.model.data.bar cimport BarType
+
+
+@unique
+class CandlePattern(Enum):
+    """
+    Represents a candle pattern.
+    """
+    BULLISH_ENGULFING = 1
+    BEARISH_ENGULFING = 2
+    DOJI = 3
+    INSIDE_BAR = 4
+    BREAKOUT_HIGH = 5
+    BREAKOUT_LOW = 6
+
+
+cdef class PatternDetection:
+    """
+    Represents a candle pattern detected at a bar.
+
+    Parameters
+    ----------
+    pattern : CandlePattern
+        The detected pattern.
+    bar : Bar
+        The bar completing the pattern.
+
+    """
+
+    def __init__(self, pattern not None, Bar bar not None):
+        self.pattern = pattern
+        self.bar = bar
+
+    def __eq__(self, PatternDetection other) -> bool:
+        return self.pattern == other.pattern and self.bar == other.bar
+
+    def __hash__(self) -> int:
+        return hash((self.pattern, self.bar.ts_event))
+
+    def __repr__(self) -> str:
+        return f"{type(self).__name__}({self.pattern.name}, ts_event={self.bar.ts_event})"
+
+    @property
+    def ts_event(self) -> int:
+        """
+        The UNIX timestamp (nanoseconds) of the bar completing the pattern.
+
+        Returns
+        -------
+        int64
+
+        """
+        return self.bar.ts_event
+
+
+cpdef list detect_patterns(list bars, double doji_body_ratio=0.1, int breakout_period=20):
+    """
+    Return the candle patterns detected in the given bars.
+
+    Each detection refers to the bar completing the pattern. A breakout is a
+    close above the highs (or below the lows) of the prior `breakout_period`
+    bars.
+
+    Parameters
+    ----------
+    bars : list[Bar]
+        The bars to scan (in chronological order).
+    doji_body_ratio : double, default 0.1
+        The maximum ratio of body to range for a doji, in the range [0, 1].
+    breakout_period : int, default 20
+        The number of prior bars for a breakout (zero disables breakouts).
+
+    Returns
+    -------
+    list[PatternDetection]
+        Ordered by the completing bar.
+
+    Raises
+    ------
+    ValueError
+        If `doji_body_ratio` is not in range [0, 1].
+    ValueError
+        If `breakout_period` is negative (< 0).
+
+    """
+    Condition.not_none(bars, "bars")
+    Condition.in_range(doji_body_ratio, 0.0, 1.0, "doji_body_ratio")
+    Condition.not_negative_int(breakout_period, "breakout_period")
+
+    cdef int count = len(bars)
+    if count == 0:
+        return []
+
+    cdef Candle *candles = <Candle *>PyMem_Malloc(count * sizeof(Candle))
+    if candles == NULL:
+        raise MemoryError()
+
+    cdef PatternParams params
+    params.doji_body_ratio = doji_body_ratio
+    params.breakout_period = breakout_period
+
+    cdef:
+        int i
+        Bar bar
+        CVec result
+    try:
+        for i in range(count):
+            bar = bars[i]
+            candles[i].open = bar.open._mem
+            candles[i].high = bar.high._mem
+            candles[i].low = bar.low._mem
+            candles[i].close = bar.close._mem
+            candles[i].ts_event = bar.ts_event
+        result = candle_patterns_detect(candles, count, &params)
+    finally:
+        PyMem_Free(candles)
+
+    cdef PatternDetection_t *detections = <PatternDetection_t *>result.ptr
+    cdef list output = [
+        PatternDetection(CandlePattern(<int>detections[i].pattern), bars[detections[i].index])
+        for i in range(result.len)
+    ]
+    pattern_detections_drop(result)
+    return output
+
+
+cpdef list detect_cached_patterns(
+    Cache cache,
+    BarType bar_type,
+    double doji_body_ratio=0.1,
+    int breakout_period=20,
+):
+    """
+    Return the candle patterns detected in the cached bars for the given bar
+    type.
+
+    Parameters
+    ----------
+    cache : Cache
+        The cache holding the bars.
+    bar_type : BarType
+        The bar type to scan.
+    doji_body_ratio : double, default 0.1
+        The maximum ratio of body to range for a doji, in the range [0, 1].
+    breakout_period : int, default 20
+        The number of prior bars for a breakout (zero disables breakouts).
+
+    Returns
+    -------
+    list[PatternDetection]
+        Ordered by the completing bar.
+
+    """
+    Condition.not_none(cache, "cache")
+    Condition.not_none(bar_type, "bar_type")
+
+    # Cached bars are ordered newest first
+    cdef list bars = list(reversed(cache.bars(bar_type)))
+    return detect_patterns(bars, doji_body_ratio, breakout_period)
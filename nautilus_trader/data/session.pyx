@@ -0,0 +1,90 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+"""
+Provides the running per-instrument session statistics recorded by the
+`DataEngine` from trade ticks.
+"""
+
+from libc.stdint cimport int64_t
+
+from nautilus_trader.core.correctness cimport Condition
+from nautilus_trader.core.rust.model cimport SessionStats
+from nautilus_trader.core.rust.model cimport session_stats_get
+from nautilus_trader.core.rust.model cimport session_stats_reset
+from nautilus_trader.core.rust.model cimport session_stats_start
+from nautilus_trader.model.identifiers cimport InstrumentId
+from nautilus_trader.model.objects cimport Price
+from nautilus_trader.model.objects cimport Quantity
+
+
+cpdef dict session_stats(InstrumentId instrument_id):
+    """
+    Return the session statistics for the given instrument ID.
+
+    Includes the session open, high, low, last and VWAP prices (``None`` until
+    the first trade), the traded volume and trade count, and the UNIX
+    timestamps (nanoseconds) of the session start and latest trade.
+
+    Parameters
+    ----------
+    instrument_id : InstrumentId
+        The instrument ID for the statistics.
+
+    Returns
+    -------
+    dict[str, object]
+
+    """
+    Condition.not_none(instrument_id, "instrument_id")
+
+    cdef SessionStats stats = session_stats_get(&instrument_id._mem)
+    cdef bint has_trades = stats.trade_count > 0
+    return {
+        "open": Price.from_raw_c(stats.open.raw, stats.open.precision) if has_trades else None,
+        "high": Price.from_raw_c(stats.high.raw, stats.high.precision) if has_trades else None,
+        "low": Price.from_raw_c(stats.low.raw, stats.low.precision) if has_trades else None,
+        "last": Price.from_raw_c(stats.last.raw, stats.last.precision) if has_trades else None,
+        "vwap": Price.from_raw_c(stats.vwap.raw, stats.vwap.precision) if has_trades else None,
+        "volume": Quantity.from_raw_c(stats.volume.raw, stats.volume.precision),
+        "trade_count": stats.trade_count,
+        "ts_start": stats.ts_start,
+        "ts_last": stats.ts_last,
+    }
+
+
+cpdef void start_session(InstrumentId instrument_id, int64_t ts_start) except *:
+    """
+    Start a new session for the given instrument ID, discarding the
+    statistics of the current session.
+
+    Parameters
+    ----------
+    instrument_id : InstrumentId
+        The instrument ID for the session.
+    ts_start : int64
+        The UNIX timestamp (nanoseconds) when the session started.
+
+    """
+    Condition.not_none(instrument_id, "instrument_id")
+
+    session_stats_start(&instrument_id._mem, ts_start)
+
+
+cpdef void reset_session_stats() except *:
+    """
+    Reset the session statistics for all instruments.
+    """
+    session_stats_reset()
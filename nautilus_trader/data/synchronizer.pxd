@@ -0,0 +1,39 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from libc.stdint cimport int64_t
+
+from nautilus_trader.common.logging cimport LoggerAdapter
+from nautilus_trader.model.data.bar cimport Bar
+
+
+cdef class BarSynchronizer:
+    cdef LoggerAdapter _log
+    cdef object _handler
+    cdef dict _pending
+    cdef dict _ts_last
+
+    cdef readonly list bar_types
+    """The synchronized bar types.\n\n:returns: `list[BarType]`"""
+    cdef readonly int64_t ts_last_synced
+    """The UNIX timestamp (nanoseconds) when the bars were last synchronized.\n\n:returns: `int64`"""
+    cdef readonly int synced_count
+    """The count of synchronized bar groups sent to the handler.\n\n:returns: `int`"""
+    cdef readonly int dropped_count
+    """The count of incomplete bar groups dropped.\n\n:returns: `int`"""
+
+    cpdef void handle_bar(self, Bar bar) except *
+    cpdef void reset(self) except *
+    cdef void _drop_incomplete(self) except *
@@ -0,0 +1,163 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from typing import Callable
+
+from libc.stdint cimport int64_t
+
+from nautilus_trader.common.logging cimport Logger
+from nautilus_trader.common.logging cimport LoggerAdapter
+from nautilus_trader.core.correctness cimport Condition
+from nautilus_trader.model.data.bar cimport Bar
+from nautilus_trader.model.data.bar cimport BarType
+
+
+cdef class BarSynchronizer:
+    """
+    Provides a means of synchronizing bars of multiple bar types for the same
+    instrument, sending them to a registered handler only once a bar of every
+    bar type has closed at the same time.
+
+    For example, synchronizing 1-MINUTE and 15-MINUTE bars sends the pair of
+    bars every 15 minutes, when both bars closed. The bars are buffered until
+    the group is complete, so may arrive in any order across bar types.
+
+    A group which can no longer complete (because a bar type has already
+    closed a later bar, such as when no bar was built for an interval or a
+    partial bar closed early) is dropped.
+
+    Parameters
+    ----------
+    bar_types : list[BarType]
+        The bar types to synchronize.
+    handler : Callable[[list[Bar]], None]
+        The handler for the synchronized bars, in the order of `bar_types`.
+    logger : Logger
+        The logger for the synchronizer.
+
+    Raises
+    ------
+    ValueError
+        If `bar_types` has fewer than two bar types.
+    ValueError
+        If `bar_types` contains duplicates.
+    ValueError
+        If `bar_types` are not all for the same instrument.
+    """
+
+    def __init__(
+        self,
+        list bar_types not None,
+        handler not None: Callable[[list], None],
+        Logger logger not None,
+    ):
+        Condition.true(len(bar_types) >= 2, "fewer than two `bar_types`")
+        Condition.list_type(bar_types, BarType, "bar_types")
+        Condition.true(len(set(bar_types)) == len(bar_types), "duplicate `bar_types`")
+
+        cdef BarType bar_type
+        for bar_type in bar_types[1:]:
+            Condition.equal(
+                bar_type.instrument_id,
+                bar_types[0].instrument_id,
+                "bar_type.instrument_id",
+                "instrument_id",
+            )
+
+        self.bar_types = list(bar_types)
+        self._handler = handler
+        self._log = LoggerAdapter(
+            component_name=type(self).__name__,
+            logger=logger,
+        )
+
+        self._pending = {}  # type: dict[int, dict[BarType, Bar]]
+        self._ts_last = {}  # type: dict[BarType, int]
+
+        self.ts_last_synced = 0
+        self.synced_count = 0
+        self.dropped_count = 0
+
+    cpdef void handle_bar(self, Bar bar) except *:
+        """
+        Update the synchronizer with the given bar.
+
+        If the bar completes a group then the bars are sent to the handler.
+
+        Parameters
+        ----------
+        bar : Bar
+            The bar for the update.
+
+        Raises
+        ------
+        ValueError
+            If `bar.type` is not a synchronized bar type.
+
+        """
+        Condition.not_none(bar, "bar")
+        Condition.is_in(bar.type, self.bar_types, "bar.type", "bar_types")
+
+        cdef int64_t ts_last = self._ts_last.get(bar.type, 0)
+        if bar.ts_event <= ts_last:
+            self._log.warning(
+                f"Bar {bar} was prior to last bar `ts_event` {ts_last}, ignoring.",
+            )
+            return
+
+        self._ts_last[bar.type] = bar.ts_event
+
+        cdef dict group = self._pending.get(bar.ts_event)
+        if group is None:
+            group = {}
+            self._pending[bar.ts_event] = group
+        group[bar.type] = bar
+
+        if len(group) == len(self.bar_types):
+            del self._pending[bar.ts_event]
+            self.ts_last_synced = bar.ts_event
+            self.synced_count += 1
+            self._drop_incomplete()
+            self._handler([group[bar_type] for bar_type in self.bar_types])
+            return
+
+        self._drop_incomplete()
+
+    cpdef void reset(self) except *:
+        """
+        Reset the synchronizer.
+
+        All stateful fields are reset to their initial value.
+        """
+        self._pending.clear()
+        self._ts_last.clear()
+
+        self.ts_last_synced = 0
+        self.synced_count = 0
+        self.dropped_count = 0
+
+    cdef void _drop_incomplete(self) except *:
+        # A group can no longer complete once a missing bar type has closed a
+        # later bar (this includes all groups prior to a synchronized group)
+        cdef int64_t ts
+        cdef dict group
+        cdef BarType bar_type
+        for ts, group in list(self._pending.items()):
+            if any(
+                bar_type not in group and self._ts_last.get(bar_type, 0) > ts
+                for bar_type in self.bar_types
+            ):
+                del self._pending[ts]
+                self.dropped_count += 1
@@ -0,0 +1,27 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from libc.stdint cimport int64_t
+
+from nautilus_trader.model.c_enums.option_kind cimport OptionKind
+from nautilus_trader.model.instruments.option cimport Option
+
+
+cpdef int64_t option_expiry_ns(Option option) except *
+cpdef object update_volatility(Option option, double option_price, double underlying_price, int64_t ts_now, double rate=*)
+cpdef object volatility(str underlying, int64_t expiry_ns, double strike, int64_t ts_now)
+cpdef tuple volatility_at_delta(str underlying, OptionKind kind, int64_t expiry_ns, double delta, double underlying_price, int64_t ts_now, double rate=*)
+cpdef object theoretical_price(Option option, double underlying_price, int64_t ts_now, double rate=*)
+cpdef void reset_volatility_surfaces() except *
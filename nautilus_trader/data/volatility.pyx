@@ -0,0 +1,243 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+"""
+Provides implied volatility surfaces for options, built from option quotes
+and queryable by expiry and strike or delta.
+
+A surface is held per underlying, interpolated linearly in strike within an
+expiry and linearly in total variance across expiries, with flat
+extrapolation outside the quoted range. Volatilities are Black-Scholes
+(European exercise, no dividends) and annualized over a 365 day year.
+"""
+
+import pandas as pd
+
+from cpython.object cimport PyObject
+from libc.math cimport isnan
+from libc.stdint cimport int64_t
+
+from nautilus_trader.core.correctness cimport Condition
+from nautilus_trader.core.datetime cimport dt_to_unix_nanos
+from nautilus_trader.core.rust.model cimport OptionKind as OptionKind_t
+from nautilus_trader.core.rust.model cimport option_black_scholes_price
+from nautilus_trader.core.rust.model cimport volatility_surface_at_delta
+from nautilus_trader.core.rust.model cimport volatility_surface_get
+from nautilus_trader.core.rust.model cimport volatility_surface_update
+from nautilus_trader.core.rust.model cimport volatility_surfaces_reset
+from nautilus_trader.model.c_enums.option_kind cimport OptionKind
+from nautilus_trader.model.instruments.option cimport Option
+
+
+cdef double NANOS_PER_YEAR = 365.0 * 86_400.0 * 1_000_000_000.0
+
+
+cpdef int64_t option_expiry_ns(Option option) except *:
+    """
+    Return the UNIX timestamp (nanoseconds) of the given options expiry date
+    (at 00:00 UTC).
+
+    Parameters
+    ----------
+    option : Option
+        The option for the expiry.
+
+    Returns
+    -------
+    int64
+
+    """
+    Condition.not_none(option, "option")
+
+    return dt_to_unix_nanos(pd.Timestamp(option.expiry_date, tz="UTC"))
+
+
+cpdef object update_volatility(
+    Option option,
+    double option_price,
+    double underlying_price,
+    int64_t ts_now,
+    double rate=0.0,
+):
+    """
+    Update the volatility surface for the options underlying with the implied
+    volatility of the given option price.
+
+    Parameters
+    ----------
+    option : Option
+        The option quoted.
+    option_price : double
+        The option price (typically the quote mid price).
+    underlying_price : double
+        The underlying price.
+    ts_now : int64
+        The current UNIX timestamp (nanoseconds).
+    rate : double, default 0.0
+        The continuously compounded risk-free rate.
+
+    Returns
+    -------
+    float or ``None``
+        The implied volatility, or ``None`` if the price is outside the
+        no-arbitrage bounds or the option has expired (the surface is then
+        unchanged).
+
+    """
+    Condition.not_none(option, "option")
+
+    cdef double vol = volatility_surface_update(
+        <PyObject *>option.underlying,
+        <OptionKind_t>option.kind,
+        option_expiry_ns(option),
+        &option.strike_price._mem,
+        option_price,
+        underlying_price,
+        rate,
+        ts_now,
+    )
+    return None if isnan(vol) else vol
+
+
+cpdef object volatility(str underlying, int64_t expiry_ns, double strike, int64_t ts_now):
+    """
+    Return the volatility of the surface for the given underlying at the
+    expiry and strike.
+
+    Parameters
+    ----------
+    underlying : str
+        The underlying asset of the surface.
+    expiry_ns : int64
+        The UNIX timestamp (nanoseconds) of the expiry.
+    strike : double
+        The strike price.
+    ts_now : int64
+        The current UNIX timestamp (nanoseconds).
+
+    Returns
+    -------
+    float or ``None``
+        ``None`` if the expiry is not after `ts_now` or there are no unexpired
+        quotes for the underlying.
+
+    """
+    Condition.valid_string(underlying, "underlying")
+
+    cdef double vol = volatility_surface_get(<PyObject *>underlying, expiry_ns, strike, ts_now)
+    return None if isnan(vol) else vol
+
+
+cpdef tuple volatility_at_delta(
+    str underlying,
+    OptionKind kind,
+    int64_t expiry_ns,
+    double delta,
+    double underlying_price,
+    int64_t ts_now,
+    double rate=0.0,
+):
+    """
+    Return the strike and volatility of the surface for the given underlying
+    at the expiry where the option delta equals the given delta.
+
+    Parameters
+    ----------
+    underlying : str
+        The underlying asset of the surface.
+    kind : OptionKind
+        The option kind for the delta.
+    expiry_ns : int64
+        The UNIX timestamp (nanoseconds) of the expiry.
+    delta : double
+        The delta, in (0, 1) for calls and (-1, 0) for puts.
+    underlying_price : double
+        The underlying price.
+    ts_now : int64
+        The current UNIX timestamp (nanoseconds).
+    rate : double, default 0.0
+        The continuously compounded risk-free rate.
+
+    Returns
+    -------
+    tuple[float, float] or ``None``
+
+    """
+    Condition.valid_string(underlying, "underlying")
+
+    cdef double strike
+    cdef double vol = volatility_surface_at_delta(
+        <PyObject *>underlying,
+        <OptionKind_t>kind,
+        expiry_ns,
+        delta,
+        underlying_price,
+        rate,
+        ts_now,
+        &strike,
+    )
+    return None if isnan(vol) else (strike, vol)
+
+
+cpdef object theoretical_price(
+    Option option,
+    double underlying_price,
+    int64_t ts_now,
+    double rate=0.0,
+):
+    """
+    Return the theoretical price of the given option using the volatility
+    surface for its underlying.
+
+    Parameters
+    ----------
+    option : Option
+        The option to price.
+    underlying_price : double
+        The underlying price.
+    ts_now : int64
+        The current UNIX timestamp (nanoseconds).
+    rate : double, default 0.0
+        The continuously compounded risk-free rate.
+
+    Returns
+    -------
+    float or ``None``
+        ``None`` if there is no volatility for the option.
+
+    """
+    Condition.not_none(option, "option")
+
+    cdef int64_t expiry_ns = option_expiry_ns(option)
+    cdef double strike = option.strike_price.as_f64_c()
+    vol = volatility(option.underlying, expiry_ns, strike, ts_now)
+    if vol is None:
+        return None
+
+    return option_black_scholes_price(
+        <OptionKind_t>option.kind,
+        underlying_price,
+        strike,
+        (expiry_ns - ts_now) / NANOS_PER_YEAR,
+        rate,
+        vol,
+    )
+
+
+cpdef void reset_volatility_surfaces() except *:
+    """
+    Reset the volatility surfaces for all underlyings.
+    """
+    volatility_surfaces_reset()
@@ -27,6 +27,7 @@ from nautilus_trader.execution.messages cimport TradingCommand
 from nautilus_trader.model.c_enums.oms_type cimport OMSType
 from nautilus_trader.model.events.order cimport OrderEvent
 from nautilus_trader.model.events.order cimport OrderFilled
+from nautilus_trader.model.identifiers cimport InstrumentId
 from nautilus_trader.model.identifiers cimport StrategyId
 from nautilus_trader.model.identifiers cimport Venue
 from nautilus_trader.model.instruments.base cimport Instrument
@@ -55,6 +56,7 @@ cdef class ExecutionEngine(Component):
     """The total count of reports received by the engine.\n\n:returns: `int`"""
 
     cpdef int position_id_count(self, StrategyId strategy_id) except *
+    cpdef dict trading_stats(self, InstrumentId instrument_id)
     cpdef bint check_integrity(self) except *
     cpdef bint check_connected(self) except *
     cpdef bint check_disconnected(self) except *
@@ -100,6 +102,7 @@ cdef class ExecutionEngine(Component):
     cdef void _apply_event_to_order(self, Order order, OrderEvent event) except *
     cdef OMSType _determine_oms_type(self, OrderFilled fill) except *
     cdef void _determine_position_id(self, OrderFilled fill, OMSType oms_type) except *
+    cdef void _record_trading_stats(self, Order order, OrderFilled fill) except *
     cdef void _handle_order_fill(self, OrderFilled fill, OMSType oms_type) except *
     cdef void _open_position(self,  Instrument instrument, OrderFilled fill, OMSType oms_type) except *
     cdef void _update_position(self, Instrument instrument, OrderFilled fill, OMSType oms_type, Position position) except *
@@ -49,6 +49,9 @@ from nautilus_trader.common.logging cimport Logger
 from nautilus_trader.core.correctness cimport Condition
 from nautilus_trader.core.fsm cimport InvalidStateTrigger
 from nautilus_trader.core.rust.core cimport unix_timestamp_ms
+from nautilus_trader.core.rust.model cimport trading_stats_get
+from nautilus_trader.core.rust.model cimport trading_stats_record_fill
+from nautilus_trader.core.rust.model cimport trading_stats_record_round_trip
 from nautilus_trader.execution.client cimport ExecutionClient
 from nautilus_trader.execution.messages cimport CancelAllOrders
 from nautilus_trader.execution.messages cimport CancelOrder
@@ -58,6 +61,7 @@ from nautilus_trader.execution.messages cimport SubmitOrderList
 from nautilus_trader.execution.messages cimport TradingCommand
 from nautilus_trader.model.c_enums.oms_type cimport OMSType
 from nautilus_trader.model.c_enums.oms_type cimport OMSTypeParser
+from nautilus_trader.model.c_enums.order_side cimport OrderSide
 from nautilus_trader.model.c_enums.position_side cimport PositionSide
 from nautilus_trader.model.events.order cimport OrderEvent
 from nautilus_trader.model.events.order cimport OrderFilled
@@ -68,12 +72,14 @@ from nautilus_trader.model.events.position cimport PositionOpened
 from nautilus_trader.model.identifiers cimport ClientId
 from nautilus_trader.model.identifiers cimport ClientOrderId
 from nautilus_trader.model.identifiers cimport ComponentId
+from nautilus_trader.model.identifiers cimport InstrumentId
 from nautilus_trader.model.identifiers cimport PositionId
 from nautilus_trader.model.identifiers cimport StrategyId
 from nautilus_trader.model.identifiers cimport Venue
 from nautilus_trader.model.instruments.base cimport Instrument
 from nautilus_trader.model.instruments.currency_pair cimport CurrencyPair
 from nautilus_trader.model.objects cimport Money
+from nautilus_trader.model.objects cimport Price
 from nautilus_trader.model.objects cimport Quantity
 from nautilus_trader.model.orders.base cimport Order
 from nautilus_trader.msgbus.bus cimport MessageBus
@@ -188,6 +194,28 @@ cdef class ExecutionEngine(Component):
         """
         return self._pos_id_generator.get_count(strategy_id)
 
+    cpdef dict trading_stats(self, InstrumentId instrument_id):
+        """
+        The running trading statistics for the given instrument ID.
+
+        Includes the trade count, volume, fees, win/loss counts, win rate and
+        average slippage (price points, positive when adverse) of all fills
+        handled by the engine for the instrument.
+
+        Parameters
+        ----------
+        instrument_id : InstrumentId
+            The instrument ID for the statistics.
+
+        Returns
+        -------
+        dict[str, object]
+
+        """
+        Condition.not_none(instrument_id, "instrument_id")
+
+        return trading_stats_get(&instrument_id._mem)
+
     cpdef bint check_integrity(self) except *:
         """
         Check integrity of data within the cache and clients.
@@ -604,6 +632,7 @@ cdef class ExecutionEngine(Component):
             oms_type = self._determine_oms_type(event)
             self._determine_position_id(event, oms_type)
             self._apply_event_to_order(order, event)
+            self._record_trading_stats(order, event)
             self._handle_order_fill(event, oms_type)
         else:
             self._apply_event_to_order(order, event)
@@ -671,6 +700,18 @@ cdef class ExecutionEngine(Component):
         else:  # pragma: no cover
             raise ValueError(f"invalid OMSType, was {oms_type}")
 
+    cdef void _record_trading_stats(self, Order order, OrderFilled fill) except *:
+        # Slippage is measured against the order price (if any)
+        cdef Price expected_px = order.price if order.has_price_c() else None
+        trading_stats_record_fill(
+            &fill.instrument_id._mem,
+            <OrderSide>fill.order_side,
+            &fill.last_qty._mem,
+            &fill.last_px._mem,
+            fill.commission.as_f64_c(),
+            &expected_px._mem if expected_px is not None else NULL,
+        )
+
     cdef void _handle_order_fill(self, OrderFilled fill, OMSType oms_type) except *:
         cdef Instrument instrument = self._cache.load_instrument(fill.instrument_id)
         if instrument is None:
@@ -743,6 +784,10 @@ cdef class ExecutionEngine(Component):
 
         cdef PositionEvent position_event
         if position.is_closed_c():
+            trading_stats_record_round_trip(
+                &position.instrument_id._mem,
+                position.realized_pnl.as_f64_c(),
+            )
             event = PositionClosed.create_c(
                 position=position,
                 fill=fill,
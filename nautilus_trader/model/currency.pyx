@@ -20,6 +20,7 @@ from libc.stdint cimport uint16_t
 from nautilus_trader.core.correctness cimport Condition
 from nautilus_trader.core.rust.model cimport currency_free
 from nautilus_trader.core.rust.model cimport currency_from_py
+from nautilus_trader.core.rust.model cimport currency_register
 from nautilus_trader.model.c_enums.currency_type cimport CurrencyType
 from nautilus_trader.model.c_enums.currency_type cimport CurrencyTypeParser
 from nautilus_trader.model.currencies cimport _CURRENCY_MAP
@@ -163,6 +164,14 @@ cdef class Currency:
         if not overwrite and currency.code in _CURRENCY_MAP:
             return
         _CURRENCY_MAP[currency.code] = currency
+        currency_register(  # Mirror for Rust lookups
+            <PyObject *>currency.code,
+            currency._mem.precision,
+            currency._mem.iso4217,
+            <PyObject *>currency.name,
+            currency._mem.currency_type,
+            overwrite,
+        )
 
     @staticmethod
     cdef Currency from_str_c(str code, bint strict=False):
@@ -0,0 +1,34 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from nautilus_trader.core.data cimport Data
+from nautilus_trader.model.c_enums.cash_flow_type cimport CashFlowType
+from nautilus_trader.model.identifiers cimport InstrumentId
+from nautilus_trader.model.objects cimport Money
+
+
+cdef class CashFlow(Data):
+    cdef readonly InstrumentId instrument_id
+    """The instrument ID paying the cash flow.\n\n:returns: `InstrumentId`"""
+    cdef readonly CashFlowType cash_flow_type
+    """The type of cash flow.\n\n:returns: `CashFlowType`"""
+    cdef readonly Money amount
+    """The amount paid per unit of the instrument.\n\n:returns: `Money`"""
+
+    @staticmethod
+    cdef CashFlow from_dict_c(dict values)
+
+    @staticmethod
+    cdef dict to_dict_c(CashFlow obj)
@@ -0,0 +1,124 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from libc.stdint cimport int64_t
+
+from nautilus_trader.core.correctness cimport Condition
+from nautilus_trader.core.data cimport Data
+from nautilus_trader.model.c_enums.cash_flow_type cimport CashFlowType
+from nautilus_trader.model.c_enums.cash_flow_type cimport CashFlowTypeParser
+from nautilus_trader.model.identifiers cimport InstrumentId
+from nautilus_trader.model.objects cimport Money
+
+
+cdef class CashFlow(Data):
+    """
+    Represents a scheduled cash flow paid to the holders of an instrument,
+    such as a cash dividend on an equity or a coupon on a bond.
+
+    Parameters
+    ----------
+    instrument_id : InstrumentId
+        The instrument ID paying the cash flow.
+    cash_flow_type : CashFlowType
+        The type of cash flow.
+    amount : Money
+        The amount paid per unit of the instrument (per contract multiplier).
+    ts_event : int64
+        The UNIX timestamp (nanoseconds) of the ex-date, from which new
+        holders are no longer entitled to the cash flow.
+    ts_init : int64
+        The UNIX timestamp (nanoseconds) when the object was initialized.
+    """
+
+    def __init__(
+        self,
+        InstrumentId instrument_id not None,
+        CashFlowType cash_flow_type,
+        Money amount not None,
+        int64_t ts_event,
+        int64_t ts_init,
+    ):
+        super().__init__(ts_event, ts_init)
+        self.instrument_id = instrument_id
+        self.cash_flow_type = cash_flow_type
+        self.amount = amount
+
+    def __eq__(self, CashFlow other) -> bool:
+        return CashFlow.to_dict_c(self) == CashFlow.to_dict_c(other)
+
+    def __hash__(self) -> int:
+        return hash(frozenset(CashFlow.to_dict_c(self)))
+
+    def __repr__(self) -> str:
+        return (
+            f"{type(self).__name__}("
+            f"instrument_id={self.instrument_id}, "
+            f"cash_flow_type={CashFlowTypeParser.to_str(self.cash_flow_type)}, "
+            f"amount={self.amount.to_str()}, "
+            f"ts_event={self.ts_event})"
+        )
+
+    @staticmethod
+    cdef CashFlow from_dict_c(dict values):
+        Condition.not_none(values, "values")
+        return CashFlow(
+            instrument_id=InstrumentId.from_str_c(values["instrument_id"]),
+            cash_flow_type=CashFlowTypeParser.from_str(values["cash_flow_type"]),
+            amount=Money.from_str_c(values["amount"]),
+            ts_event=values["ts_event"],
+            ts_init=values["ts_init"],
+        )
+
+    @staticmethod
+    cdef dict to_dict_c(CashFlow obj):
+        Condition.not_none(obj, "obj")
+        return {
+            "type": "CashFlow",
+            "instrument_id": obj.instrument_id.value,
+            "cash_flow_type": CashFlowTypeParser.to_str(obj.cash_flow_type),
+            "amount": obj.amount.to_str(),
+            "ts_event": obj.ts_event,
+            "ts_init": obj.ts_init,
+        }
+
+    @staticmethod
+    def from_dict(dict values) -> CashFlow:
+        """
+        Return a cash flow from the given dict values.
+
+        Parameters
+        ----------
+        values : dict[str, object]
+            The values for initialization.
+
+        Returns
+        -------
+        CashFlow
+
+        """
+        return CashFlow.from_dict_c(values)
+
+    @staticmethod
+    def to_dict(CashFlow obj):
+        """
+        Return a dictionary representation of this object.
+
+        Returns
+        -------
+        dict[str, object]
+
+        """
+        return CashFlow.to_dict_c(obj)
@@ -124,6 +124,16 @@ Represents a method of aggregating an OHLCV bar.
 >>> BarAggregation.DAY
 <BarAggregation.DAY: 13>
 
+Cash Flow Type
+--------------
+Represents a type of scheduled cash flow paid to the holders of an instrument.
+
+>>> from nautilus_trader.model.enums import CashFlowType
+>>> CashFlowType.DIVIDEND
+<CashFlowType.DIVIDEND: 1>
+>>> CashFlowType.COUPON
+<CashFlowType.COUPON: 2>
+
 """
 
 from nautilus_trader.model.c_enums.account_type import AccountType
@@ -142,6 +152,8 @@ from nautilus_trader.model.c_enums.book_action import BookAction
 from nautilus_trader.model.c_enums.book_action import BookActionParser
 from nautilus_trader.model.c_enums.book_type import BookType
 from nautilus_trader.model.c_enums.book_type import BookTypeParser
+from nautilus_trader.model.c_enums.cash_flow_type import CashFlowType
+from nautilus_trader.model.c_enums.cash_flow_type import CashFlowTypeParser
 from nautilus_trader.model.c_enums.contingency_type import ContingencyType
 from nautilus_trader.model.c_enums.contingency_type import ContingencyTypeParser
 from nautilus_trader.model.c_enums.currency_type import CurrencyType
@@ -193,6 +205,8 @@ __all__ = [
     "AssetTypeParser",
     "BarAggregation",
     "BarAggregationParser",
+    "CashFlowType",
+    "CashFlowTypeParser",
     "ContingencyType",
     "ContingencyTypeParser",
     "CurrencyType",
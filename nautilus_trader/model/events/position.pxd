@@ -80,6 +80,10 @@ cdef class PositionEvent(Event):
     """The UNIX timestamp (nanoseconds) when the position was closed.\n\n:returns: `int64`"""
     cdef readonly int64_t duration_ns
     """The total open duration (nanoseconds).\n\n:returns: `int64`"""
+    cdef readonly double max_adverse_excursion
+    """The maximum adverse excursion (price points from the average open price).\n\n:returns: `double`"""
+    cdef readonly double max_favorable_excursion
+    """The maximum favorable excursion (price points from the average open price).\n\n:returns: `double`"""
 
 
 cdef class PositionOpened(PositionEvent):
@@ -91,6 +91,10 @@ cdef class PositionEvent(Event):
         The UNIX timestamp (nanoseconds) when the event occurred.
     ts_init : int64
         The UNIX timestamp (nanoseconds) when the object was initialized.
+    max_adverse_excursion : double, default 0
+        The maximum adverse excursion (price points from the average open price).
+    max_favorable_excursion : double, default 0
+        The maximum favorable excursion (price points from the average open price).
 
     Warnings
     --------
@@ -125,6 +129,8 @@ cdef class PositionEvent(Event):
         int64_t duration_ns,
         int64_t ts_event,
         int64_t ts_init,
+        double max_adverse_excursion=0.0,
+        double max_favorable_excursion=0.0,
     ):
         super().__init__(event_id, ts_event, ts_init)
 
@@ -151,6 +157,8 @@ cdef class PositionEvent(Event):
         self.ts_opened = ts_opened
         self.ts_closed = ts_closed
         self.duration_ns = duration_ns
+        self.max_adverse_excursion = max_adverse_excursion
+        self.max_favorable_excursion = max_favorable_excursion
 
     def __str__(self) -> str:
         return (
@@ -505,6 +513,10 @@ cdef class PositionChanged(PositionEvent):
         The UNIX timestamp (nanoseconds) when the position changed event occurred.
     ts_init : int64
         The UNIX timestamp (nanoseconds) when the object was initialized.
+    max_adverse_excursion : double, default 0
+        The maximum adverse excursion (price points from the average open price).
+    max_favorable_excursion : double, default 0
+        The maximum favorable excursion (price points from the average open price).
     """
 
     def __init__(
@@ -532,6 +544,8 @@ cdef class PositionChanged(PositionEvent):
         int64_t ts_opened,
         int64_t ts_event,
         int64_t ts_init,
+        double max_adverse_excursion=0.0,
+        double max_favorable_excursion=0.0,
     ):
         assert side != PositionSide.FLAT  # Design-time check: position side matches event
         super().__init__(
@@ -561,6 +575,8 @@ cdef class PositionChanged(PositionEvent):
             0,
             ts_event,
             ts_init,
+            max_adverse_excursion,
+            max_favorable_excursion,
         )
 
     @staticmethod
@@ -598,6 +614,8 @@ cdef class PositionChanged(PositionEvent):
             ts_opened=position.ts_opened,
             ts_event=position.last_event_c().ts_event,
             ts_init=ts_init,
+            max_adverse_excursion=position.max_adverse_excursion,
+            max_favorable_excursion=position.max_favorable_excursion,
         )
 
     @staticmethod
@@ -627,6 +645,8 @@ cdef class PositionChanged(PositionEvent):
             ts_opened=values["ts_opened"],
             ts_event=values["ts_event"],
             ts_init=values["ts_init"],
+            max_adverse_excursion=values.get("max_adverse_excursion", 0.0),
+            max_favorable_excursion=values.get("max_favorable_excursion", 0.0),
         )
 
     @staticmethod
@@ -657,6 +677,8 @@ cdef class PositionChanged(PositionEvent):
             "ts_opened": obj.ts_opened,
             "ts_event": obj.ts_event,
             "ts_init": obj.ts_init,
+            "max_adverse_excursion": obj.max_adverse_excursion,
+            "max_favorable_excursion": obj.max_favorable_excursion,
         }
 
     @staticmethod
@@ -773,6 +795,10 @@ cdef class PositionClosed(PositionEvent):
         The total open duration (nanoseconds).
     ts_init : int64
         The UNIX timestamp (nanoseconds) when the object was initialized.
+    max_adverse_excursion : double, default 0
+        The maximum adverse excursion (price points from the average open price).
+    max_favorable_excursion : double, default 0
+        The maximum favorable excursion (price points from the average open price).
     """
 
     def __init__(
@@ -801,6 +827,8 @@ cdef class PositionClosed(PositionEvent):
         int64_t ts_closed,
         int64_t duration_ns,
         int64_t ts_init,
+        double max_adverse_excursion=0.0,
+        double max_favorable_excursion=0.0,
     ):
         assert side == PositionSide.FLAT  # Design-time check: position side matches event
         super().__init__(
@@ -830,6 +858,8 @@ cdef class PositionClosed(PositionEvent):
             duration_ns,
             ts_closed,  # ts_event = ts_closed
             ts_init,
+            max_adverse_excursion,
+            max_favorable_excursion,
         )
 
     @staticmethod
@@ -868,6 +898,8 @@ cdef class PositionClosed(PositionEvent):
             ts_closed=position.ts_closed,
             duration_ns=position.duration_ns,
             ts_init=ts_init,
+            max_adverse_excursion=position.max_adverse_excursion,
+            max_favorable_excursion=position.max_favorable_excursion,
         )
 
     @staticmethod
@@ -898,6 +930,8 @@ cdef class PositionClosed(PositionEvent):
             ts_closed=values["ts_closed"],
             duration_ns=values["duration_ns"],
             ts_init=values["ts_init"],
+            max_adverse_excursion=values.get("max_adverse_excursion", 0.0),
+            max_favorable_excursion=values.get("max_favorable_excursion", 0.0),
         )
 
     @staticmethod
@@ -929,6 +963,8 @@ cdef class PositionClosed(PositionEvent):
             "ts_closed": obj.ts_closed,
             "duration_ns": obj.duration_ns,
             "ts_init": obj.ts_init,
+            "max_adverse_excursion": obj.max_adverse_excursion,
+            "max_favorable_excursion": obj.max_favorable_excursion,
         }
 
     @staticmethod
@@ -17,6 +17,7 @@ from nautilus_trader.core.rust.model cimport AccountId_t
 from nautilus_trader.core.rust.model cimport ClientOrderId_t
 from nautilus_trader.core.rust.model cimport ClientOrderLinkId_t
 from nautilus_trader.core.rust.model cimport ComponentId_t
+from nautilus_trader.core.rust.model cimport ExecAlgorithmId_t
 from nautilus_trader.core.rust.model cimport InstrumentId_t
 from nautilus_trader.core.rust.model cimport OrderListId_t
 from nautilus_trader.core.rust.model cimport PositionId_t
@@ -32,6 +33,8 @@ cdef class Symbol:
     cdef readonly str value
     """The identifier (ID) value.\n\n:returns: `str`"""
 
+    cpdef bint eq_ignore_case(self, Symbol other)
+
 
 cdef class Venue:
     cdef Venue_t _mem
@@ -39,6 +42,9 @@ cdef class Venue:
     cdef readonly str value
     """The identifier (ID) value.\n\n:returns: `str`"""
 
+    cpdef bint is_synthetic(self)
+    cpdef bint eq_ignore_case(self, Venue other)
+
 
 cdef class InstrumentId:
     cdef InstrumentId_t _mem
@@ -56,6 +62,8 @@ cdef class InstrumentId:
     @staticmethod
     cdef InstrumentId from_str_c(str value)
 
+    cpdef bint eq_ignore_case(self, InstrumentId other)
+
 
 cdef class ComponentId:
     cdef ComponentId_t _mem
@@ -121,6 +129,13 @@ cdef class OrderListId:
     """The identifier (ID) value.\n\n:returns: `str`"""
 
 
+cdef class ExecAlgorithmId:
+    cdef ExecAlgorithmId_t _mem
+
+    cdef readonly str value
+    """The identifier (ID) value.\n\n:returns: `str`"""
+
+
 cdef class PositionId:
     cdef PositionId_t _mem
 
@@ -138,3 +153,8 @@ cdef class TradeId:
 
     @staticmethod
     cdef TradeId from_raw_c(TradeId_t raw)
+
+
+cpdef list symbols_from_list(list values)
+cpdef list venues_from_list(list values)
+cpdef list instrument_ids_from_list(list values)
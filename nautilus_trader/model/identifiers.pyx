@@ -13,34 +13,72 @@
 #  limitations under the License.
 # -------------------------------------------------------------------------------------------------
 
+from cpython.mem cimport PyMem_Free
+from cpython.mem cimport PyMem_Malloc
 from cpython.object cimport PyObject
+from libc.stdint cimport uint8_t
 
 from nautilus_trader.core.correctness cimport Condition
+from nautilus_trader.core.rust.core cimport CVec
+from nautilus_trader.core.rust.model cimport TRADE_ID_MAX_LEN
+from nautilus_trader.core.rust.model cimport IdentifierError_TooLong
 from nautilus_trader.core.rust.model cimport account_id_free
 from nautilus_trader.core.rust.model cimport account_id_from_pystr
+from nautilus_trader.core.rust.model cimport account_id_hash
 from nautilus_trader.core.rust.model cimport client_order_id_free
 from nautilus_trader.core.rust.model cimport client_order_id_from_pystr
+from nautilus_trader.core.rust.model cimport client_order_id_hash
 from nautilus_trader.core.rust.model cimport client_order_link_id_free
 from nautilus_trader.core.rust.model cimport client_order_link_id_from_pystr
+from nautilus_trader.core.rust.model cimport client_order_link_id_hash
 from nautilus_trader.core.rust.model cimport component_id_free
 from nautilus_trader.core.rust.model cimport component_id_from_pystr
+from nautilus_trader.core.rust.model cimport component_id_hash
+from nautilus_trader.core.rust.model cimport exec_algorithm_id_free
+from nautilus_trader.core.rust.model cimport exec_algorithm_id_from_pystr
+from nautilus_trader.core.rust.model cimport exec_algorithm_id_hash
+from nautilus_trader.core.rust.model cimport instrument_id_clone
+from nautilus_trader.core.rust.model cimport instrument_id_eq_ignore_case
 from nautilus_trader.core.rust.model cimport instrument_id_free
+from nautilus_trader.core.rust.model cimport instrument_id_from_pystr
 from nautilus_trader.core.rust.model cimport instrument_id_from_pystrs
+from nautilus_trader.core.rust.model cimport instrument_id_hash
+from nautilus_trader.core.rust.model cimport instrument_id_to_pystr
+from nautilus_trader.core.rust.model cimport instrument_ids_drop
+from nautilus_trader.core.rust.model cimport instrument_ids_from_pylist
 from nautilus_trader.core.rust.model cimport order_list_id_free
 from nautilus_trader.core.rust.model cimport order_list_id_from_pystr
+from nautilus_trader.core.rust.model cimport order_list_id_hash
 from nautilus_trader.core.rust.model cimport position_id_free
 from nautilus_trader.core.rust.model cimport position_id_from_pystr
+from nautilus_trader.core.rust.model cimport position_id_hash
+from nautilus_trader.core.rust.model cimport symbol_canonical_from_pystr
+from nautilus_trader.core.rust.model cimport symbol_clone
+from nautilus_trader.core.rust.model cimport symbol_eq_ignore_case
 from nautilus_trader.core.rust.model cimport symbol_free
 from nautilus_trader.core.rust.model cimport symbol_from_pystr
+from nautilus_trader.core.rust.model cimport symbol_hash
 from nautilus_trader.core.rust.model cimport symbol_to_pystr
+from nautilus_trader.core.rust.model cimport symbols_drop
+from nautilus_trader.core.rust.model cimport symbols_from_pylist
 from nautilus_trader.core.rust.model cimport trade_id_free
 from nautilus_trader.core.rust.model cimport trade_id_from_pystr
+from nautilus_trader.core.rust.model cimport trade_id_hash
 from nautilus_trader.core.rust.model cimport trade_id_to_pystr
+from nautilus_trader.core.rust.model cimport trade_id_try_from_pystr
+from nautilus_trader.core.rust.model cimport venue_canonical_from_pystr
+from nautilus_trader.core.rust.model cimport venue_clone
+from nautilus_trader.core.rust.model cimport venue_eq_ignore_case
 from nautilus_trader.core.rust.model cimport venue_free
 from nautilus_trader.core.rust.model cimport venue_from_pystr
+from nautilus_trader.core.rust.model cimport venue_hash
+from nautilus_trader.core.rust.model cimport venue_is_synthetic
 from nautilus_trader.core.rust.model cimport venue_order_id_free
 from nautilus_trader.core.rust.model cimport venue_order_id_from_pystr
+from nautilus_trader.core.rust.model cimport venue_order_id_hash
 from nautilus_trader.core.rust.model cimport venue_to_pystr
+from nautilus_trader.core.rust.model cimport venues_drop
+from nautilus_trader.core.rust.model cimport venues_from_pylist
 
 
 cdef class Symbol:
@@ -54,6 +92,8 @@ cdef class Symbol:
     ----------
     value : str
         The ticker symbol ID value.
+    canonical : bool, default False
+        If the value is stored in canonical (uppercased) form.
 
     Raises
     ------
@@ -65,11 +105,15 @@ cdef class Symbol:
     https://en.wikipedia.org/wiki/Ticker_symbol
     """
 
-    def __init__(self, str value):
+    def __init__(self, str value, bint canonical=False):
         Condition.valid_string(value, "value")
 
-        self.value = value
-        self._mem = symbol_from_pystr(<PyObject *>value)
+        if canonical:
+            self._mem = symbol_canonical_from_pystr(<PyObject *>value)
+            self.value = <str>symbol_to_pystr(&self._mem)
+        else:
+            self.value = value
+            self._mem = symbol_from_pystr(<PyObject *>value)
 
     def __del__(self) -> None:
         symbol_free(self._mem)  # `self._mem` moved to Rust (then dropped)
@@ -97,7 +141,7 @@ cdef class Symbol:
         return self.value >= other.value
 
     def __hash__(self) -> int:
-        return hash(self.value)
+        return <Py_ssize_t>symbol_hash(&self._mem)
 
     def __str__(self) -> str:
         return self.value
@@ -105,6 +149,22 @@ cdef class Symbol:
     def __repr__(self) -> str:
         return f"{type(self).__name__}('{self.value}')"
 
+    cpdef bint eq_ignore_case(self, Symbol other):
+        """
+        If the symbol equals the other symbol ignoring case.
+
+        Parameters
+        ----------
+        other : Symbol
+            The symbol to compare.
+
+        Returns
+        -------
+        bool
+
+        """
+        return symbol_eq_ignore_case(&self._mem, &other._mem)
+
 
 cdef class Venue:
     """
@@ -114,6 +174,11 @@ cdef class Venue:
     ----------
     name : str
         The venue ID value.
+    canonical : bool, default False
+        If the value is stored in canonical form, so feeds disagreeing on case
+        (e.g. 'Binance' and 'BINANCE') construct equal venues. The canonical
+        form of a well-known venue (e.g. 'InteractiveBrokers') is its name as
+        written, and of any other venue its uppercased value.
 
     Raises
     ------
@@ -121,11 +186,15 @@ cdef class Venue:
         If `name` is not a valid string.
     """
 
-    def __init__(self, str name):
+    def __init__(self, str name, bint canonical=False):
         Condition.valid_string(name, "name")
 
-        self.value = name
-        self._mem = venue_from_pystr(<PyObject *>name)
+        if canonical:
+            self._mem = venue_canonical_from_pystr(<PyObject *>name)
+            self.value = <str>venue_to_pystr(&self._mem)
+        else:
+            self.value = name
+            self._mem = venue_from_pystr(<PyObject *>name)
 
     def __del__(self) -> None:
         venue_free(self._mem)  # `self._mem` moved to Rust (then dropped)
@@ -153,7 +222,7 @@ cdef class Venue:
         return self.value >= other.value
 
     def __hash__(self) -> int:
-        return hash(self.value)
+        return <Py_ssize_t>venue_hash(&self._mem)
 
     def __str__(self) -> str:
         return self.value
@@ -161,6 +230,34 @@ cdef class Venue:
     def __repr__(self) -> str:
         return f"{type(self).__name__}('{self.value}')"
 
+    cpdef bint is_synthetic(self):
+        """
+        If the venue is synthetic or consolidated rather than a real trading
+        venue, being either 'SYNTH' or a name wrapped in dashes e.g. '-NBBO-'.
+
+        Returns
+        -------
+        bool
+
+        """
+        return venue_is_synthetic(&self._mem)
+
+    cpdef bint eq_ignore_case(self, Venue other):
+        """
+        If the venue equals the other venue ignoring case.
+
+        Parameters
+        ----------
+        other : Venue
+            The venue to compare.
+
+        Returns
+        -------
+        bool
+
+        """
+        return venue_eq_ignore_case(&self._mem, &other._mem)
+
 
 cdef class InstrumentId:
     """
@@ -213,7 +310,7 @@ cdef class InstrumentId:
         return self.value >= other.value
 
     def __hash__(self) -> int:
-        return hash(self.value)
+        return <Py_ssize_t>instrument_id_hash(&self._mem)
 
     def __str__(self) -> str:
         return self.value
@@ -235,7 +332,7 @@ cdef class InstrumentId:
         instrument_id._mem = raw
         instrument_id.symbol = symbol
         instrument_id.venue = venue
-        instrument_id.value = symbol.value + "." + venue.value
+        instrument_id.value = <str>instrument_id_to_pystr(&raw)
 
         return instrument_id
 
@@ -252,10 +349,7 @@ cdef class InstrumentId:
         cdef Venue venue = Venue(pieces[1])
 
         cdef InstrumentId instrument_id = InstrumentId.__new__(InstrumentId)
-        instrument_id._mem = instrument_id_from_pystrs(
-            <PyObject *>pieces[0],
-            <PyObject *>pieces[1],
-        )
+        instrument_id._mem = instrument_id_from_pystr(<PyObject *>value)
         instrument_id.symbol = symbol
         instrument_id.venue = venue
         instrument_id.value = f"{symbol}.{venue}"
@@ -283,6 +377,23 @@ cdef class InstrumentId:
         """
         return InstrumentId.from_str_c(value)
 
+    cpdef bint eq_ignore_case(self, InstrumentId other):
+        """
+        If the instrument ID equals the other instrument ID ignoring the case
+        of the symbol and venue.
+
+        Parameters
+        ----------
+        other : InstrumentId
+            The instrument ID to compare.
+
+        Returns
+        -------
+        bool
+
+        """
+        return instrument_id_eq_ignore_case(&self._mem, &other._mem)
+
 
 cdef class ComponentId:
     """
@@ -333,7 +444,7 @@ cdef class ComponentId:
         return self.value >= other.value
 
     def __hash__(self) -> int:
-        return hash(self.value)
+        return <Py_ssize_t>component_id_hash(&self._mem)
 
     def __str__(self) -> str:
         return self.value
@@ -366,7 +477,7 @@ cdef class ClientId(ComponentId):
         return self.value == other.value
 
     def __hash__(self) -> int:
-        return hash(self.value)
+        return <Py_ssize_t>component_id_hash(&self._mem)
 
 
 cdef class TraderId(ComponentId):
@@ -400,7 +511,7 @@ cdef class TraderId(ComponentId):
         return self.value == other.value
 
     def __hash__(self) -> int:
-        return hash(self.value)
+        return <Py_ssize_t>component_id_hash(&self._mem)
 
     cpdef str get_tag(self):
         """
@@ -456,7 +567,7 @@ cdef class StrategyId(ComponentId):
         return self.value == other.value
 
     def __hash__(self) -> int:
-        return hash(self.value)
+        return <Py_ssize_t>component_id_hash(&self._mem)
 
     cpdef str get_tag(self):
         """
@@ -546,7 +657,7 @@ cdef class AccountId:
         return self.value >= other.value
 
     def __hash__(self) -> int:
-        return hash(self.value)
+        return <Py_ssize_t>account_id_hash(&self._mem)
 
     def __str__(self) -> str:
         return self.value
@@ -635,7 +746,7 @@ cdef class ClientOrderId:
         return self.value >= other.value
 
     def __hash__(self) -> int:
-        return hash(self.value)
+        return <Py_ssize_t>client_order_id_hash(&self._mem)
 
     def __str__(self) -> str:
         return self.value
@@ -704,7 +815,7 @@ cdef class ClientOrderLinkId:
         return self.value >= other.value
 
     def __hash__(self) -> int:
-        return hash(self.value)
+        return <Py_ssize_t>client_order_link_id_hash(&self._mem)
 
     def __str__(self) -> str:
         return self.value
@@ -760,7 +871,7 @@ cdef class VenueOrderId:
         return self.value >= other.value
 
     def __hash__(self) -> int:
-        return hash(self.value)
+        return <Py_ssize_t>venue_order_id_hash(&self._mem)
 
     def __str__(self) -> str:
         return self.value
@@ -816,7 +927,63 @@ cdef class OrderListId:
         return self.value >= other.value
 
     def __hash__(self) -> int:
-        return hash(self.value)
+        return <Py_ssize_t>order_list_id_hash(&self._mem)
+
+    def __str__(self) -> str:
+        return self.value
+
+    def __repr__(self) -> str:
+        return f"{type(self).__name__}('{self.value}')"
+
+
+cdef class ExecAlgorithmId:
+    """
+    Represents a valid execution algorithm ID.
+
+    Parameters
+    ----------
+    value : str
+        The execution algorithm ID value.
+
+    Raises
+    ------
+    ValueError
+        If `value` is not a valid string.
+    """
+
+    def __init__(self, str value):
+        Condition.valid_string(value, "value")
+
+        self.value = value
+        self._mem = exec_algorithm_id_from_pystr(<PyObject *>value)
+
+    def __del__(self) -> None:
+        exec_algorithm_id_free(self._mem)  # `self._mem` moved to Rust (then dropped)
+
+    def __getstate__(self):
+        return self.value
+
+    def __setstate__(self, state):
+        self.value = state
+        self._mem = exec_algorithm_id_from_pystr(<PyObject *>state)
+
+    def __eq__(self, ExecAlgorithmId other) -> bool:
+        return self.value == other.value
+
+    def __lt__(self, ExecAlgorithmId other) -> bool:
+        return self.value < other.value
+
+    def __le__(self, ExecAlgorithmId other) -> bool:
+        return self.value <= other.value
+
+    def __gt__(self, ExecAlgorithmId other) -> bool:
+        return self.value > other.value
+
+    def __ge__(self, ExecAlgorithmId other) -> bool:
+        return self.value >= other.value
+
+    def __hash__(self) -> int:
+        return <Py_ssize_t>exec_algorithm_id_hash(&self._mem)
 
     def __str__(self) -> str:
         return self.value
@@ -872,7 +1039,7 @@ cdef class PositionId:
         return self.value >= other.value
 
     def __hash__(self) -> int:
-        return hash(self.value)
+        return <Py_ssize_t>position_id_hash(&self._mem)
 
     def __str__(self) -> str:
         return self.value
@@ -902,6 +1069,8 @@ cdef class TradeId:
     ------
     ValueError
         If `value` is not a valid string.
+    ValueError
+        If `value` is longer than 64 characters.
 
     References
     ----------
@@ -911,8 +1080,15 @@ cdef class TradeId:
     def __init__(self, str value):
         Condition.valid_string(value, "value")
 
+        cdef uint8_t code = trade_id_try_from_pystr(<PyObject *>value, &self._mem)
+        if code == IdentifierError_TooLong:
+            raise ValueError(
+                f"The TradeId string value exceeded {TRADE_ID_MAX_LEN} characters, was {value}",
+            )
+        elif code != 0:
+            raise ValueError(f"The TradeId string value was invalid, was {value}")
+
         self.value = value
-        self._mem = trade_id_from_pystr(<PyObject *>value)
 
     def __del__(self) -> None:
         trade_id_free(self._mem)  # `self._mem` moved to Rust (then dropped)
@@ -940,7 +1116,7 @@ cdef class TradeId:
         return self.value >= other.value
 
     def __hash__(self) -> int:
-        return hash(self.value)
+        return <Py_ssize_t>trade_id_hash(&self._mem)
 
     def __str__(self) -> str:
         return self.value
@@ -954,3 +1130,175 @@ cdef class TradeId:
         trade_id.value = <str>trade_id_to_pystr(&raw)
         trade_id._mem = raw
         return trade_id
+
+
+cdef PyObject **_pystr_array(list values, str param) except NULL:
+    cdef PyObject **ptrs = <PyObject **>PyMem_Malloc(len(values) * sizeof(PyObject *))
+    if ptrs == NULL:
+        raise MemoryError()
+
+    cdef Py_ssize_t i
+    try:
+        for i in range(len(values)):
+            Condition.valid_string(values[i], param)
+            ptrs[i] = <PyObject *>values[i]
+    except Exception:
+        PyMem_Free(ptrs)
+        raise
+    return ptrs
+
+
+cpdef list symbols_from_list(list values):
+    """
+    Return the symbols for the given values, converted in a single batch.
+
+    Parameters
+    ----------
+    values : list[str]
+        The symbol ID values.
+
+    Returns
+    -------
+    list[Symbol]
+
+    Raises
+    ------
+    ValueError
+        If any value is not a valid string.
+
+    """
+    Condition.not_none(values, "values")
+
+    if not values:
+        return []
+
+    cdef PyObject **ptrs = _pystr_array(values, "value")
+    cdef CVec result
+    try:
+        result = symbols_from_pylist(ptrs, len(values))
+    finally:
+        PyMem_Free(ptrs)
+
+    cdef Symbol_t *mems = <Symbol_t *>result.ptr
+    cdef list output = []
+    cdef Symbol symbol
+    cdef Py_ssize_t i
+    for i in range(result.len):
+        symbol = Symbol.__new__(Symbol)
+        symbol._mem = symbol_clone(&mems[i])
+        symbol.value = values[i]
+        output.append(symbol)
+    symbols_drop(result)
+    return output
+
+
+cpdef list venues_from_list(list values):
+    """
+    Return the venues for the given values, converted in a single batch.
+
+    Parameters
+    ----------
+    values : list[str]
+        The venue ID values.
+
+    Returns
+    -------
+    list[Venue]
+
+    Raises
+    ------
+    ValueError
+        If any value is not a valid string.
+
+    """
+    Condition.not_none(values, "values")
+
+    if not values:
+        return []
+
+    cdef PyObject **ptrs = _pystr_array(values, "value")
+    cdef CVec result
+    try:
+        result = venues_from_pylist(ptrs, len(values))
+    finally:
+        PyMem_Free(ptrs)
+
+    cdef Venue_t *mems = <Venue_t *>result.ptr
+    cdef list output = []
+    cdef Venue venue
+    cdef Py_ssize_t i
+    for i in range(result.len):
+        venue = Venue.__new__(Venue)
+        venue._mem = venue_clone(&mems[i])
+        venue.value = values[i]
+        output.append(venue)
+    venues_drop(result)
+    return output
+
+
+cpdef list instrument_ids_from_list(list values):
+    """
+    Return the instrument IDs for the given values in the "SYMBOL.VENUE"
+    dotted notation, converted in a single batch.
+
+    Parameters
+    ----------
+    values : list[str]
+        The instrument ID values.
+
+    Returns
+    -------
+    list[InstrumentId]
+
+    Raises
+    ------
+    ValueError
+        If any value is not a valid string.
+    ValueError
+        If any value is malformed (no '.' delimiter between symbol and venue).
+
+    """
+    Condition.not_none(values, "values")
+
+    if not values:
+        return []
+
+    cdef list pieces = []
+    cdef str value
+    for value in values:
+        Condition.valid_string(value, "value")
+        split = value.rsplit(".", maxsplit=1)
+        if len(split) != 2:
+            raise ValueError(f"The InstrumentId string value was malformed, was {value}")
+        pieces.append(split)
+
+    cdef PyObject **ptrs = _pystr_array(values, "value")
+    cdef CVec result
+    try:
+        result = instrument_ids_from_pylist(ptrs, len(values))
+    finally:
+        PyMem_Free(ptrs)
+
+    cdef InstrumentId_t *mems = <InstrumentId_t *>result.ptr
+    cdef list output = []
+    cdef Symbol symbol
+    cdef Venue venue
+    cdef InstrumentId instrument_id
+    cdef Py_ssize_t i
+    for i in range(result.len):
+        symbol = Symbol.__new__(Symbol)
+        symbol._mem = symbol_clone(&mems[i].symbol)
+        symbol.value = pieces[i][0]
+
+        venue = Venue.__new__(Venue)
+        venue._mem = venue_clone(&mems[i].venue)
+        venue.value = pieces[i][1]
+
+        instrument_id = InstrumentId.__new__(InstrumentId)
+        instrument_id._mem = instrument_id_clone(&mems[i])
+        instrument_id.symbol = symbol
+        instrument_id.venue = venue
+        instrument_id.value = values[i]
+        output.append(instrument_id)
+    instrument_ids_drop(result)
+    return output
@@ -110,6 +110,7 @@ cdef class Price:
 
     cpdef object as_decimal(self)
     cpdef double as_double(self) except *
+    cpdef str to_formatted_str(self, str locale=*, decimals=*)
 
 
 cdef class Money:
@@ -145,6 +146,7 @@ cdef class Money:
 
     cpdef object as_decimal(self)
     cpdef double as_double(self) except *
+    cpdef str to_formatted_str(self, str locale=*, decimals=*, bint currency_prefix=*)
 
 
 cdef class AccountBalance:
@@ -25,6 +25,7 @@ from cpython.object cimport Py_GE
 from cpython.object cimport Py_GT
 from cpython.object cimport Py_LE
 from cpython.object cimport Py_LT
+from cpython.object cimport PyObject
 from cpython.object cimport PyObject_RichCompareBool
 from libc.stdint cimport int64_t
 from libc.stdint cimport uint8_t
@@ -32,16 +33,28 @@ from libc.stdint cimport uint64_t
 
 from nautilus_trader.core.correctness cimport Condition
 from nautilus_trader.core.rust.model cimport FIXED_SCALAR
+from nautilus_trader.core.rust.model cimport CurrencyPosition_Prefix
 from nautilus_trader.core.rust.model cimport Currency_t
+from nautilus_trader.core.rust.model cimport NumberFormat
+from nautilus_trader.core.rust.model cimport conversion_audit_counts_to_pydict
+from nautilus_trader.core.rust.model cimport conversion_audit_is_enabled
+from nautilus_trader.core.rust.model cimport conversion_audit_reset
+from nautilus_trader.core.rust.model cimport conversion_audit_set_enabled
 from nautilus_trader.core.rust.model cimport money_free
 from nautilus_trader.core.rust.model cimport money_from_raw
 from nautilus_trader.core.rust.model cimport money_new
+from nautilus_trader.core.rust.model cimport money_to_formatted_pystr
+from nautilus_trader.core.rust.model cimport money_to_pystr
+from nautilus_trader.core.rust.model cimport number_format_for_locale
 from nautilus_trader.core.rust.model cimport price_free
 from nautilus_trader.core.rust.model cimport price_from_raw
 from nautilus_trader.core.rust.model cimport price_new
+from nautilus_trader.core.rust.model cimport price_new_audited
+from nautilus_trader.core.rust.model cimport price_to_formatted_pystr
 from nautilus_trader.core.rust.model cimport quantity_free
 from nautilus_trader.core.rust.model cimport quantity_from_raw
 from nautilus_trader.core.rust.model cimport quantity_new
+from nautilus_trader.core.rust.model cimport quantity_new_audited
 from nautilus_trader.core.string cimport precision_from_str
 from nautilus_trader.model.currency cimport Currency
 from nautilus_trader.model.identifiers cimport InstrumentId
@@ -64,6 +77,9 @@ cdef class Quantity:
     precision : uint8
         The precision for the quantity. Use a precision of 0 for whole numbers
         (no fractional units).
+    component : str, optional
+        The component constructing the quantity, which lossy conversions are
+        recorded for when conversion auditing is enabled (default 'Quantity').
 
     Raises
     ------
@@ -79,11 +95,14 @@ cdef class Quantity:
     https://www.onixs.biz/fix-dictionary/5.0.SP2/index.html#Qty
     """
 
-    def __init__(self, double value, uint8_t precision):
+    def __init__(self, double value, uint8_t precision, str component = None):
         Condition.true(precision <= 9, "invalid precision, was > 9")
         Condition.true(value >= 0.0, f"quantity negative, was {value}")
 
-        self._mem = quantity_new(value, precision)
+        if component is None:
+            self._mem = quantity_new(value, precision)
+        else:
+            self._mem = quantity_new_audited(value, precision, <PyObject *>component)
 
     def __del__(self) -> None:
         quantity_free(self._mem)  # `self._mem` moved to Rust (then dropped)
@@ -350,7 +369,8 @@ cdef class Quantity:
         Warnings
         --------
         The decimal precision will be inferred from the number of digits
-        following the '.' point (if no point then precision zero).
+        following the '.' point (if no point then precision zero), less any
+        exponent (e.g. "1.5e-8" has precision 9).
 
         Raises
         ------
@@ -433,6 +453,9 @@ cdef class Price:
     precision : uint8
         The precision for the price. Use a precision of 0 for whole numbers
         (no fractional units).
+    component : str, optional
+        The component constructing the price, which lossy conversions are
+        recorded for when conversion auditing is enabled (default 'Price').
 
     Raises
     ------
@@ -446,10 +469,13 @@ cdef class Price:
     https://www.onixs.biz/fix-dictionary/5.0.SP2/index.html#Price
     """
 
-    def __init__(self, double value, uint8_t precision):
+    def __init__(self, double value, uint8_t precision, str component = None):
         Condition.true(precision <= 9, "invalid precision, was > 9")
 
-        self._mem = price_new(value, precision)
+        if component is None:
+            self._mem = price_new(value, precision)
+        else:
+            self._mem = price_new_audited(value, precision, <PyObject *>component)
 
     def __del__(self) -> None:
         price_free(self._mem)  # `self._mem` moved to Rust (then dropped)
@@ -684,7 +710,8 @@ cdef class Price:
         Warnings
         --------
         The decimal precision will be inferred from the number of digits
-        following the '.' point (if no point then precision zero).
+        following the '.' point (if no point then precision zero), less any
+        exponent (e.g. "1.5e-8" has precision 9).
 
         Raises
         ------
@@ -739,6 +766,33 @@ cdef class Price:
         """
         return self.as_f64_c()
 
+    cpdef str to_formatted_str(self, str locale="en_US", decimals=None):
+        """
+        Return the value formatted with the separators of the given locale.
+
+        Parameters
+        ----------
+        locale : str, default "en_US"
+            The locale for the thousands and decimal separators (e.g. "de_DE").
+        decimals : int, optional
+            The decimals to format (rounding half away from zero), if ``None``
+            then the precision of the price.
+
+        Returns
+        -------
+        str
+
+        Raises
+        ------
+        ValueError
+            If `locale` is not supported.
+        ValueError
+            If `decimals` is not in range [0, 9].
+
+        """
+        cdef NumberFormat number_format = _number_format(locale, decimals)
+        return <str>price_to_formatted_pystr(&self._mem, &number_format)
+
 
 cdef class Money:
     """
@@ -1007,7 +1061,44 @@ cdef class Money:
         str
 
         """
-        return f"{self.as_f64_c():,.{self._mem.currency.precision}f} {self.currency.code}".replace(",", "_")
+        return <str>money_to_pystr(&self._mem)
+
+    cpdef str to_formatted_str(
+        self,
+        str locale="en_US",
+        decimals=None,
+        bint currency_prefix=False,
+    ):
+        """
+        Return the amount and currency code formatted with the separators of
+        the given locale.
+
+        Parameters
+        ----------
+        locale : str, default "en_US"
+            The locale for the thousands and decimal separators (e.g. "de_DE").
+        decimals : int, optional
+            The decimals to format (rounding half away from zero), if ``None``
+            then the precision of the currency.
+        currency_prefix : bool, default False
+            If the currency code is placed before the amount (otherwise after).
+
+        Returns
+        -------
+        str
+
+        Raises
+        ------
+        ValueError
+            If `locale` is not supported.
+        ValueError
+            If `decimals` is not in range [0, 9].
+
+        """
+        cdef NumberFormat number_format = _number_format(locale, decimals)
+        if currency_prefix:
+            number_format.currency_position = CurrencyPosition_Prefix
+        return <str>money_to_formatted_pystr(&self._mem, &number_format)
 
 
 cdef class AccountBalance:
@@ -1194,3 +1285,60 @@ cdef class MarginBalance:
             "currency": self.currency.code,
             "instrument_id": self.instrument_id.value if self.instrument_id is not None else None,
         }
+
+
+cdef NumberFormat _number_format(str locale, object decimals) except *:
+    Condition.not_none(locale, "locale")
+
+    cdef NumberFormat number_format
+    if number_format_for_locale(<PyObject *>locale, &number_format) != 0:
+        raise ValueError(f"locale '{locale}' is not supported")
+    if decimals is not None:
+        Condition.in_range_int(decimals, 0, 9, "decimals")
+        number_format.decimals = decimals
+    return number_format
+
+
+def set_conversion_audit(bint enabled) -> None:
+    """
+    Set whether lossy float to fixed-point conversions by the `Price` and
+    `Quantity` constructors are counted per component.
+
+    Parameters
+    ----------
+    enabled : bool
+        If conversion auditing is enabled.
+
+    """
+    conversion_audit_set_enabled(enabled)
+
+
+def is_conversion_audit_enabled() -> bool:
+    """
+    Return whether conversion auditing is enabled.
+
+    Returns
+    -------
+    bool
+
+    """
+    return <bint>conversion_audit_is_enabled()
+
+
+def reset_conversion_audit() -> None:
+    """
+    Reset the lossy conversion counts.
+    """
+    conversion_audit_reset()
+
+
+def lossy_conversion_counts() -> dict:
+    """
+    Return the lossy conversion counts keyed by component.
+
+    Returns
+    -------
+    dict[str, int]
+
+    """
+    return <dict>conversion_audit_counts_to_pydict()
@@ -95,6 +95,10 @@ cdef class Position:
     """The current realized return for the position.\n\n:returns: `double`"""
     cdef readonly Money realized_pnl
     """The current realized PnL for the position (including commissions).\n\n:returns: `Money`"""
+    cdef readonly double max_adverse_excursion
+    """The maximum adverse excursion (price points from the average open price) while open.\n\n:returns: `double`"""
+    cdef readonly double max_favorable_excursion
+    """The maximum favorable excursion (price points from the average open price) while open.\n\n:returns: `double`"""
 
     cpdef str info(self)
     cpdef dict to_dict(self)
@@ -116,6 +120,8 @@ cdef class Position:
     cpdef bint is_opposite_side(self, OrderSide side) except *
 
     cpdef void apply(self, OrderFilled fill) except *
+    cpdef void update_excursions(self, Price last) except *
+    cpdef Money settle(self, Price settlement_price)
 
     cpdef Money notional_value(self, Price last)
     cpdef Money calculate_pnl(self, double avg_px_open, double avg_px_close, Quantity quantity)
@@ -125,6 +131,7 @@ cdef class Position:
 
     cdef void _handle_buy_order_fill(self, OrderFilled fill) except *
     cdef void _handle_sell_order_fill(self, OrderFilled fill) except *
+    cdef void _update_excursions(self, double last) except *
     cdef double _calculate_avg_px(self, double avg_px, double qty, OrderFilled fill)
     cdef double _calculate_avg_px_open_px(self, OrderFilled fill)
     cdef double _calculate_avg_px_close_px(self, OrderFilled fill)
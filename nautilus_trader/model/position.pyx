@@ -96,6 +96,8 @@ cdef class Position:
 
         self.realized_return = 0.0
         self.realized_pnl = Money(0, self.cost_currency)
+        self.max_adverse_excursion = 0.0
+        self.max_favorable_excursion = 0.0
 
         self.apply(fill)
 
@@ -151,6 +153,8 @@ cdef class Position:
             "cost_currency": self.cost_currency.code,
             "realized_return": str(round(self.realized_return, 5)),
             "realized_pnl": str(self.realized_pnl.to_str()),
+            "max_adverse_excursion": str(self.max_adverse_excursion),
+            "max_favorable_excursion": str(self.max_favorable_excursion),
             "commissions": str([c.to_str() for c in self.commissions()]),
         }
 
@@ -418,6 +422,8 @@ cdef class Position:
         self._events.append(fill)
         self._trade_ids.append(fill.trade_id)
 
+        cdef PositionSide side_before = self.side
+
         if self.side == PositionSide.FLAT:
             self.opening_order_id = fill.client_order_id
 
@@ -457,8 +463,60 @@ cdef class Position:
             self.ts_closed = fill.ts_event
             self.duration_ns = self.ts_closed - self.ts_opened
 
+        # Excursions are measured from the current entry, so restart them
+        # when the position reopens or flips side (retained once closed)
+        if self.side != PositionSide.FLAT and self.side != side_before:
+            self.max_adverse_excursion = 0.0
+            self.max_favorable_excursion = 0.0
+        self._update_excursions(fill.last_px.as_f64_c())
+
         self.ts_last = fill.ts_event
 
+    cpdef void update_excursions(self, Price last) except *:
+        """
+        Update the maximum adverse and favorable excursions with the given
+        market price, if the position is open.
+
+        Parameters
+        ----------
+        last : Price
+            The last price (typically the side the position would close at).
+
+        """
+        Condition.not_none(last, "last")
+
+        self._update_excursions(last.as_f64_c())
+
+    cpdef Money settle(self, Price settlement_price):
+        """
+        Mark the position to the given settlement price, realizing the PnL of
+        the open quantity since the last settlement (or open).
+
+        The average open price becomes the settlement price, so the PnL of
+        later closing fills is from the settlement (daily settlement of
+        futures variation margin).
+
+        Parameters
+        ----------
+        settlement_price : Price
+            The settlement price for the position.
+
+        Returns
+        -------
+        Money
+            The realized variation margin, in the cost currency.
+
+        """
+        Condition.not_none(settlement_price, "settlement_price")
+
+        cdef double px = settlement_price.as_f64_c()
+        cdef double pnl = self._calculate_pnl(self.avg_px_open, px, self.quantity.as_f64_c())
+        if self.side != PositionSide.FLAT:
+            self.avg_px_open = px
+        self.realized_pnl = Money(self.realized_pnl.as_f64_c() + pnl, self.cost_currency)
+
+        return Money(pnl, self.cost_currency)
+
     cpdef Money notional_value(self, Price last):
         """
         Return the current notional value of the position.
@@ -578,6 +636,20 @@ cdef class Position:
         """
         return list(self._commissions.values())
 
+    cdef void _update_excursions(self, double last) except *:
+        cdef double points
+        if self.side == PositionSide.LONG:
+            points = last - self.avg_px_open
+        elif self.side == PositionSide.SHORT:
+            points = self.avg_px_open - last
+        else:
+            return  # Excursions only change while open
+
+        if points > self.max_favorable_excursion:
+            self.max_favorable_excursion = points
+        if -points > self.max_adverse_excursion:
+            self.max_adverse_excursion = -points
+
     cdef void _handle_buy_order_fill(self, OrderFilled fill) except *:
         # Initialize realized PnL for fill
         if fill.commission.currency == self.cost_currency:
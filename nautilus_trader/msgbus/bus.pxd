@@ -18,6 +18,7 @@ from nautilus_trader.common.logging cimport LoggerAdapter
 from nautilus_trader.core.message cimport Request
 from nautilus_trader.core.message cimport Response
 from nautilus_trader.model.identifiers cimport TraderId
+from nautilus_trader.msgbus.coordination cimport ResourceCoordinator
 from nautilus_trader.msgbus.subscription cimport Subscription
 
 
@@ -31,6 +32,8 @@ cdef class MessageBus:
 
     cdef readonly TraderId trader_id
     """The trader ID associated with the bus.\n\n:returns: `TraderId`"""
+    cdef readonly ResourceCoordinator coordinator
+    """The coordinator of locks and budgets shared by components on the bus.\n\n:returns: `ResourceCoordinator`"""
     cdef readonly int sent_count
     """The count of messages sent through the bus.\n\n:returns: `int`"""
     cdef readonly int req_count
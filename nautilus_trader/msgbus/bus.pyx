@@ -23,6 +23,7 @@ from nautilus_trader.common.logging cimport Logger
 from nautilus_trader.core.correctness cimport Condition
 from nautilus_trader.core.uuid cimport UUID4
 from nautilus_trader.model.identifiers cimport TraderId
+from nautilus_trader.msgbus.coordination cimport ResourceCoordinator
 from nautilus_trader.msgbus.wildcard cimport is_matching
 
 
@@ -45,6 +46,9 @@ cdef class MessageBus:
     `camp` and `comp`. The question mark can also be used more than once.
     For example, `c??p` would match both of the above examples and `coop`.
 
+    The bus also holds a `ResourceCoordinator` of named locks and budgets, so
+    components sharing the bus can coordinate access to shared resources.
+
     Parameters
     ----------
     trader_id : TraderId
@@ -79,6 +83,7 @@ cdef class MessageBus:
         Condition.valid_string(name, "name")
 
         self.trader_id = trader_id
+        self.coordinator = ResourceCoordinator()
 
         self._clock = clock
         self._log = LoggerAdapter(component_name=name, logger=logger)
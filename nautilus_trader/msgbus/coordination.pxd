@@ -0,0 +1,37 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from nautilus_trader.model.identifiers cimport InstrumentId
+
+
+cpdef str instrument_lock_name(InstrumentId instrument_id)
+
+
+cdef class ResourceCoordinator:
+    cdef dict _locks
+    cdef dict _budget_limits
+    cdef dict _budget_reserved
+
+    cpdef bint try_acquire(self, str name, owner) except *
+    cpdef bint release(self, str name, owner) except *
+    cpdef object lock_owner(self, str name)
+    cpdef list locks(self, owner=*)
+    cpdef void set_budget(self, str name, double limit) except *
+    cpdef bint try_reserve(self, str name, owner, double amount) except *
+    cpdef void free(self, str name, owner, double amount) except *
+    cpdef double remaining(self, str name) except *
+    cpdef double reserved(self, str name, owner=*) except *
+    cpdef void release_all(self, owner) except *
+    cpdef void reset(self) except *
@@ -0,0 +1,315 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from nautilus_trader.core.correctness cimport Condition
+from nautilus_trader.model.identifiers cimport InstrumentId
+
+
+cpdef str instrument_lock_name(InstrumentId instrument_id):
+    """
+    Return the name of the trading lock for the given instrument.
+
+    Parameters
+    ----------
+    instrument_id : InstrumentId
+        The instrument ID for the lock.
+
+    Returns
+    -------
+    str
+
+    """
+    return f"trading.{instrument_id.value}"
+
+
+cdef class ResourceCoordinator:
+    """
+    Provides named locks and budgets for coordinating shared resources between
+    components, reached through the `MessageBus` they are registered with.
+
+    A lock has at most one owner at a time (e.g. a per-instrument trading lock
+    preventing two strategies from trading the same position). A budget has a
+    limit which the reservations of all owners cannot exceed (e.g. a shared
+    notional or order rate allowance).
+
+    Warnings
+    --------
+    This coordinator is not thread-safe and must be called from the same thread
+    as the event loop.
+    """
+
+    def __init__(self):
+        self._locks = {}            # type: dict[str, object]
+        self._budget_limits = {}    # type: dict[str, float]
+        self._budget_reserved = {}  # type: dict[str, dict[object, float]]
+
+    cpdef bint try_acquire(self, str name, owner) except *:
+        """
+        Try to acquire the named lock for the given owner.
+
+        Acquiring a lock already held by the owner succeeds (locks are not
+        counted, so a single release frees it).
+
+        Parameters
+        ----------
+        name : str
+            The lock name.
+        owner : object
+            The owner acquiring the lock (e.g. a `StrategyId`).
+
+        Returns
+        -------
+        bool
+            True if the lock is held by the owner, else False.
+
+        """
+        Condition.valid_string(name, "name")
+        Condition.not_none(owner, "owner")
+
+        current = self._locks.get(name)
+        if current is None:
+            self._locks[name] = owner
+            return True
+        return current == owner
+
+    cpdef bint release(self, str name, owner) except *:
+        """
+        Release the named lock if held by the given owner.
+
+        Parameters
+        ----------
+        name : str
+            The lock name.
+        owner : object
+            The owner releasing the lock.
+
+        Returns
+        -------
+        bool
+            True if the lock was released, else False.
+
+        """
+        Condition.not_none(owner, "owner")
+
+        if self._locks.get(name) != owner:
+            return False
+        del self._locks[name]
+        return True
+
+    cpdef object lock_owner(self, str name):
+        """
+        Return the owner of the named lock.
+
+        Parameters
+        ----------
+        name : str
+            The lock name.
+
+        Returns
+        -------
+        object or ``None``
+
+        """
+        return self._locks.get(name)
+
+    cpdef list locks(self, owner=None):
+        """
+        Return the names of the held locks, sorted.
+
+        Parameters
+        ----------
+        owner : object, optional
+            The owner to filter on.
+
+        Returns
+        -------
+        list[str]
+
+        """
+        if owner is None:
+            return sorted(self._locks)
+        return sorted([name for name, o in self._locks.items() if o == owner])
+
+    cpdef void set_budget(self, str name, double limit) except *:
+        """
+        Set the limit of the named budget, keeping existing reservations.
+
+        Parameters
+        ----------
+        name : str
+            The budget name.
+        limit : double
+            The limit for the total reserved amount.
+
+        Raises
+        ------
+        ValueError
+            If `limit` is negative (< 0).
+
+        """
+        Condition.valid_string(name, "name")
+        Condition.not_negative(limit, "limit")
+
+        self._budget_limits[name] = limit
+        if name not in self._budget_reserved:
+            self._budget_reserved[name] = {}
+
+    cpdef bint try_reserve(self, str name, owner, double amount) except *:
+        """
+        Try to reserve the amount of the named budget for the given owner.
+
+        Parameters
+        ----------
+        name : str
+            The budget name.
+        owner : object
+            The owner reserving the amount.
+        amount : double
+            The amount to reserve.
+
+        Returns
+        -------
+        bool
+            True if reserved, else False if the budget would be exceeded.
+
+        Raises
+        ------
+        KeyError
+            If the budget has not been set.
+        ValueError
+            If `amount` is not positive (> 0).
+
+        """
+        Condition.is_in(name, self._budget_limits, "name", "_budget_limits")
+        Condition.not_none(owner, "owner")
+        Condition.positive(amount, "amount")
+
+        if amount > self.remaining(name):
+            return False
+        cdef dict reserved = self._budget_reserved[name]
+        reserved[owner] = reserved.get(owner, 0.0) + amount
+        return True
+
+    cpdef void free(self, str name, owner, double amount) except *:
+        """
+        Free the amount of the named budget reserved by the given owner.
+
+        Freeing more than the owner has reserved frees all of its reservation.
+
+        Parameters
+        ----------
+        name : str
+            The budget name.
+        owner : object
+            The owner freeing the amount.
+        amount : double
+            The amount to free.
+
+        Raises
+        ------
+        KeyError
+            If the budget has not been set.
+        ValueError
+            If `amount` is not positive (> 0).
+
+        """
+        Condition.is_in(name, self._budget_limits, "name", "_budget_limits")
+        Condition.positive(amount, "amount")
+
+        cdef dict reserved = self._budget_reserved[name]
+        cdef double remaining = reserved.get(owner, 0.0) - amount
+        if remaining > 0.0:
+            reserved[owner] = remaining
+        else:
+            reserved.pop(owner, None)
+
+    cpdef double remaining(self, str name) except *:
+        """
+        Return the unreserved amount of the named budget.
+
+        Parameters
+        ----------
+        name : str
+            The budget name.
+
+        Returns
+        -------
+        double
+
+        Raises
+        ------
+        KeyError
+            If the budget has not been set.
+
+        """
+        Condition.is_in(name, self._budget_limits, "name", "_budget_limits")
+
+        return max(0.0, self._budget_limits[name] - self.reserved(name))
+
+    cpdef double reserved(self, str name, owner=None) except *:
+        """
+        Return the reserved amount of the named budget.
+
+        Parameters
+        ----------
+        name : str
+            The budget name.
+        owner : object, optional
+            The owner to filter on (if ``None`` then all owners).
+
+        Returns
+        -------
+        double
+
+        Raises
+        ------
+        KeyError
+            If the budget has not been set.
+
+        """
+        Condition.is_in(name, self._budget_limits, "name", "_budget_limits")
+
+        cdef dict reserved = self._budget_reserved[name]
+        if owner is None:
+            return sum(reserved.values())
+        return reserved.get(owner, 0.0)
+
+    cpdef void release_all(self, owner) except *:
+        """
+        Release all locks and free all budget reservations of the given owner.
+
+        Parameters
+        ----------
+        owner : object
+            The owner to release for.
+
+        """
+        Condition.not_none(owner, "owner")
+
+        cdef str name
+        for name in self.locks(owner):
+            del self._locks[name]
+
+        cdef dict reserved
+        for reserved in self._budget_reserved.values():
+            reserved.pop(owner, None)
+
+    cpdef void reset(self) except *:
+        """
+        Reset the coordinator, releasing all locks and removing all budgets.
+        """
+        self._locks.clear()
+        self._budget_limits.clear()
+        self._budget_reserved.clear()
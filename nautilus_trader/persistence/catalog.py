@@ -30,6 +30,7 @@ from nautilus_trader.core.inspect import is_nautilus_class
 from nautilus_trader.model.data.bar import Bar
 from nautilus_trader.model.data.base import DataType
 from nautilus_trader.model.data.base import GenericData
+from nautilus_trader.model.data.cash_flow import CashFlow
 from nautilus_trader.model.data.tick import QuoteTick
 from nautilus_trader.model.data.tick import TradeTick
 from nautilus_trader.model.data.ticker import Ticker
@@ -299,6 +300,22 @@ class DataCatalog(metaclass=Singleton):
             **kwargs,
         )
 
+    def cash_flows(
+        self,
+        instrument_ids=None,
+        filter_expr: Optional[Callable] = None,
+        as_nautilus: bool = False,
+        **kwargs,
+    ):
+        return self.query(
+            cls=CashFlow,
+            instrument_ids=instrument_ids,
+            filter_expr=filter_expr,
+            as_nautilus=as_nautilus,
+            sort_columns=["ts_event", "instrument_id"],
+            **kwargs,
+        )
+
     def trade_ticks(
         self,
         instrument_ids=None,
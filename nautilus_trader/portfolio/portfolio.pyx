@@ -290,9 +290,9 @@ cdef class Portfolio(PortfolioFacade):
         """
         Update the portfolio with the given tick.
 
-        Clears the unrealized PnL for the quote ticks instrument, and
-        performs any initialization calculations which may have been pending
-        a market quote update.
+        Clears the unrealized PnL for the quote ticks instrument, updates the
+        excursions of its open positions, and performs any initialization
+        calculations which may have been pending a market quote update.
 
         Parameters
         ----------
@@ -304,6 +304,14 @@ cdef class Portfolio(PortfolioFacade):
 
         self._unrealized_pnls.pop(tick.instrument_id, None)
 
+        # Update excursions at the price each position would close at
+        cdef Position position
+        for position in self._cache.positions_open(
+            venue=None,  # Faster query filtering
+            instrument_id=tick.instrument_id,
+        ):
+            position.update_excursions(tick.bid if position.is_long_c() else tick.ask)
+
         if self.initialized:
             return
 
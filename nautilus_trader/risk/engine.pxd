@@ -13,8 +13,11 @@
 #  limitations under the License.
 # -------------------------------------------------------------------------------------------------
 
+from datetime import timedelta
 from decimal import Decimal
 
+from libc.stdint cimport int64_t
+
 from nautilus_trader.cache.base cimport CacheFacade
 from nautilus_trader.common.component cimport Component
 from nautilus_trader.common.throttler cimport Throttler
@@ -27,7 +30,9 @@ from nautilus_trader.execution.messages cimport SubmitOrder
 from nautilus_trader.execution.messages cimport SubmitOrderList
 from nautilus_trader.execution.messages cimport TradingCommand
 from nautilus_trader.model.c_enums.trading_state cimport TradingState
+from nautilus_trader.model.currency cimport Currency
 from nautilus_trader.model.identifiers cimport InstrumentId
+from nautilus_trader.model.identifiers cimport StrategyId
 from nautilus_trader.model.instruments.base cimport Instrument
 from nautilus_trader.model.objects cimport Price
 from nautilus_trader.model.objects cimport Quantity
@@ -40,12 +45,16 @@ cdef class RiskEngine(Component):
     cdef PortfolioFacade _portfolio
     cdef CacheFacade _cache
     cdef dict _max_notional_per_order
+    cdef dict _strategy_allocations
+    cdef int64_t _max_data_age_ns
     cdef Throttler _order_throttler
 
     cdef readonly TradingState trading_state
     """The current trading state for the engine.\n\n:returns: `TradingState`"""
     cdef readonly bint is_bypassed
     """If the risk engine is completely bypassed..\n\n:returns: `bool`"""
+    cdef readonly bint deny_stale_data
+    """If orders are denied when market data is stale.\n\n:returns: `bool`"""
     cdef readonly bint debug
     """If debug mode is active (will provide extra debug logging).\n\n:returns: `bool`"""
     cdef readonly int command_count
@@ -59,6 +68,8 @@ cdef class RiskEngine(Component):
     cpdef void process(self, Event event) except *
     cpdef void set_trading_state(self, TradingState state) except *
     cpdef void set_max_notional_per_order(self, InstrumentId instrument_id, new_value: Decimal) except *
+    cpdef void set_strategy_allocation(self, StrategyId strategy_id, new_value: Decimal) except *
+    cpdef void set_max_data_age(self, max_age: timedelta) except *
     cdef void _log_state(self) except *
 
 # -- RISK SETTINGS --------------------------------------------------------------------------------
@@ -66,6 +77,10 @@ cdef class RiskEngine(Component):
     cpdef tuple max_order_rate(self)
     cpdef dict max_notionals_per_order(self)
     cpdef object max_notional_per_order(self, InstrumentId instrument_id)
+    cpdef dict strategy_allocations(self)
+    cpdef object strategy_allocation(self, StrategyId strategy_id)
+    cpdef object max_data_age(self)
+    cpdef object last_data_update_ns(self, InstrumentId instrument_id)
 
 # -- ABSTRACT METHODS -----------------------------------------------------------------------------
 
@@ -87,7 +102,9 @@ cdef class RiskEngine(Component):
     cdef bint _check_order(self, Instrument instrument, Order order) except *
     cdef bint _check_order_price(self, Instrument instrument, Order order) except *
     cdef bint _check_order_quantity(self, Instrument instrument, Order order) except *
+    cdef bint _check_order_data_age(self, Instrument instrument, Order order) except *
     cdef bint _check_orders_risk(self, Instrument instrument, list orders) except *
+    cdef object _strategy_exposure(self, StrategyId strategy_id, Currency currency)
     cdef str _check_price(self, Instrument instrument, Price price)
     cdef str _check_quantity(self, Instrument instrument, Quantity quantity)
 
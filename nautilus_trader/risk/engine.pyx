@@ -13,6 +13,7 @@
 #  limitations under the License.
 # -------------------------------------------------------------------------------------------------
 
+from datetime import timedelta
 from decimal import Decimal
 from typing import Dict, Optional
 
@@ -34,6 +35,7 @@ from nautilus_trader.common.logging cimport LogColor
 from nautilus_trader.common.logging cimport Logger
 from nautilus_trader.common.throttler cimport Throttler
 from nautilus_trader.core.correctness cimport Condition
+from nautilus_trader.core.datetime cimport secs_to_nanos
 from nautilus_trader.core.message cimport Command
 from nautilus_trader.core.message cimport Event
 from nautilus_trader.execution.messages cimport CancelAllOrders
@@ -49,11 +51,13 @@ from nautilus_trader.model.c_enums.order_type cimport OrderType
 from nautilus_trader.model.c_enums.order_type cimport OrderTypeParser
 from nautilus_trader.model.c_enums.trading_state cimport TradingState
 from nautilus_trader.model.c_enums.trading_state cimport TradingStateParser
+from nautilus_trader.model.currency cimport Currency
 from nautilus_trader.model.data.tick cimport QuoteTick
 from nautilus_trader.model.data.tick cimport TradeTick
 from nautilus_trader.model.events.order cimport OrderDenied
 from nautilus_trader.model.identifiers cimport ComponentId
 from nautilus_trader.model.identifiers cimport InstrumentId
+from nautilus_trader.model.identifiers cimport StrategyId
 from nautilus_trader.model.instruments.base cimport Instrument
 from nautilus_trader.model.objects cimport Money
 from nautilus_trader.model.objects cimport Price
@@ -78,6 +82,16 @@ cdef class RiskEngine(Component):
      - ``REDUCING`` (only new orders or updates which reduce an open position are allowed).
      - ``HALTED`` (all trading commands except cancels are denied).
 
+    Strategies may be allocated a fraction of the account total balance, in
+    which case orders are denied once the notional of the strategies open
+    orders and positions would exceed its allocation. Allocations can be
+    reassigned at runtime with `set_strategy_allocation`.
+
+    When a maximum market data age is set, new orders are denied (or warned
+    on) if the latest quote or trade for the instrument is older than the
+    maximum, protecting against trading on a stalled feed. Reduce-only orders
+    are always allowed.
+
     Parameters
     ----------
     portfolio : PortfolioFacade
@@ -126,6 +140,7 @@ cdef class RiskEngine(Component):
         self.trading_state = TradingState.ACTIVE  # Start active by default
         self.is_bypassed = config.bypass
         self.debug = config.debug
+        self.deny_stale_data = config.deny_stale_data
         self._log_state()
 
         # Counters
@@ -154,6 +169,8 @@ cdef class RiskEngine(Component):
 
         # Risk settings
         self._max_notional_per_order: Dict[InstrumentId, Decimal] = {}
+        self._strategy_allocations: Dict[StrategyId, Decimal] = {}
+        self._max_data_age_ns = 0  # No check
 
         # Configure
         self._initialize_risk_checks(config)
@@ -170,6 +187,13 @@ cdef class RiskEngine(Component):
         for instrument_id, value in max_notional_config.items():
             self.set_max_notional_per_order(InstrumentId.from_str_c(instrument_id), Decimal(value))
 
+        cdef dict allocations_config = config.strategy_allocations
+        for strategy_id, value in allocations_config.items():
+            self.set_strategy_allocation(StrategyId(strategy_id), Decimal(value))
+
+        if config.max_data_age is not None:
+            self.set_max_data_age(pd.to_timedelta(config.max_data_age))
+
 # -- COMMANDS -------------------------------------------------------------------------------------
 
     cpdef void execute(self, Command command) except *:
@@ -285,6 +309,73 @@ cdef class RiskEngine(Component):
             color=LogColor.BLUE,
         )
 
+    cpdef void set_strategy_allocation(self, StrategyId strategy_id, new_value) except *:
+        """
+        Set the fraction of account total balance allocated to the given strategy ID.
+
+        Passing a new_value of ``None`` will remove the allocation and disable
+        the pre-trade risk allocation check for the strategy.
+
+        Parameters
+        ----------
+        strategy_id : StrategyId
+            The strategy ID for the allocation.
+        new_value : integer, float, string or Decimal
+            The allocation fraction to set, in the range (0, 1].
+
+        Raises
+        ------
+        decimal.InvalidOperation
+            If `new_value` not a valid input for `decimal.Decimal`.
+        ValueError
+            If `new_value` is not ``None`` and not in the range (0, 1].
+
+        """
+        if new_value is not None:
+            new_value = Decimal(new_value)
+            Condition.type(new_value, Decimal, "new_value")
+            Condition.positive(new_value, "new_value")
+            Condition.true(new_value <= 1, "new_value was > 1")
+            self._strategy_allocations[strategy_id] = new_value
+        else:
+            self._strategy_allocations.pop(strategy_id, None)
+
+        self._log.info(
+            f"Set STRATEGY_ALLOCATION: {strategy_id} {new_value}.",
+            color=LogColor.BLUE,
+        )
+
+    cpdef void set_max_data_age(self, max_age: timedelta) except *:
+        """
+        Set the maximum age of the latest market data for an instrument before
+        new orders for the instrument are considered to be trading on stale data.
+
+        Passing a max_age of ``None`` will disable the pre-trade risk market
+        data staleness check.
+
+        Parameters
+        ----------
+        max_age : timedelta
+            The maximum market data age to set.
+
+        Raises
+        ------
+        ValueError
+            If `max_age` is not ``None`` and not positive.
+
+        """
+        if max_age is None:
+            self._max_data_age_ns = 0
+        else:
+            Condition.type(max_age, timedelta, "max_age")
+            Condition.true(max_age > timedelta(0), "max_age was not positive")
+            self._max_data_age_ns = secs_to_nanos(max_age.total_seconds())
+
+        self._log.info(
+            f"Set MAX_DATA_AGE: {self.max_data_age()}.",
+            color=LogColor.BLUE,
+        )
+
 # -- RISK SETTINGS --------------------------------------------------------------------------------
 
     cpdef tuple max_order_rate(self):
@@ -324,6 +415,66 @@ cdef class RiskEngine(Component):
         """
         return self._max_notional_per_order.get(instrument_id)
 
+    cpdef dict strategy_allocations(self):
+        """
+        Return the current strategy allocation settings.
+
+        Returns
+        -------
+        dict[StrategyId, Decimal]
+
+        """
+        return self._strategy_allocations.copy()
+
+    cpdef object strategy_allocation(self, StrategyId strategy_id):
+        """
+        Return the current allocation fraction for the given strategy ID.
+
+        Returns
+        -------
+        Decimal or ``None``
+
+        """
+        return self._strategy_allocations.get(strategy_id)
+
+    cpdef object max_data_age(self):
+        """
+        Return the current maximum market data age setting.
+
+        Returns
+        -------
+        timedelta or ``None``
+
+        """
+        if self._max_data_age_ns == 0:
+            return None
+        return pd.Timedelta(self._max_data_age_ns, unit="ns")
+
+    cpdef object last_data_update_ns(self, InstrumentId instrument_id):
+        """
+        Return the UNIX timestamp (nanoseconds) of the latest quote or trade
+        tick for the given instrument ID.
+
+        Parameters
+        ----------
+        instrument_id : InstrumentId
+            The instrument ID for the market data.
+
+        Returns
+        -------
+        int or ``None``
+
+        """
+        cdef QuoteTick last_quote = self._cache.quote_tick(instrument_id)
+        cdef TradeTick last_trade = self._cache.trade_tick(instrument_id)
+        if last_quote is None and last_trade is None:
+            return None
+        elif last_trade is None:
+            return last_quote.ts_init
+        elif last_quote is None:
+            return last_trade.ts_init
+        return max(last_quote.ts_init, last_trade.ts_init)
+
 # -- ABSTRACT METHODS -----------------------------------------------------------------------------
 
     cpdef void _on_start(self) except *:
@@ -586,6 +737,8 @@ cdef class RiskEngine(Component):
             return False  # Denied
         if not self._check_order_quantity(instrument, order):
             return False  # Denied
+        if not self._check_order_data_age(instrument, order):
+            return False  # Denied
 
         return True  # Check passed
 
@@ -619,6 +772,35 @@ cdef class RiskEngine(Component):
 
         return True  # Passed
 
+    cdef bint _check_order_data_age(self, Instrument instrument, Order order) except *:
+        ########################################################################
+        # CHECK MARKET DATA STALENESS
+        ########################################################################
+        if self._max_data_age_ns == 0 or order.is_reduce_only:
+            return True  # No check
+
+        cdef str risk_msg = None
+        last_update_ns = self.last_data_update_ns(instrument.id)
+        if last_update_ns is None:
+            risk_msg = f"NO_MARKET_DATA for {instrument.id}"
+        else:
+            age_ns = self._clock.timestamp_ns() - last_update_ns
+            if age_ns > self._max_data_age_ns:
+                risk_msg = (
+                    f"STALE_MARKET_DATA for {instrument.id}: "
+                    f"age {pd.Timedelta(age_ns, unit='ns')} > {self.max_data_age()}"
+                )
+
+        if risk_msg is None:
+            return True  # Check passed
+
+        if not self.deny_stale_data:
+            self._log.warning(f"{risk_msg}.")
+            return True  # Warned only
+
+        self._deny_order(order=order, reason=risk_msg)
+        return False  # Denied
+
     cdef bint _check_orders_risk(self, Instrument instrument, list orders) except *:
         ########################################################################
         # RISK CHECKS
@@ -628,6 +810,9 @@ cdef class RiskEngine(Component):
         cdef Price last_px = None
 
         max_notional: Optional[Decimal] = self._max_notional_per_order.get(instrument.id)
+        allocation: Optional[Decimal] = None
+        allocated: Optional[Decimal] = None
+        exposure: Optional[Decimal] = None
 
         cdef:
             # Get account for risk checks
@@ -637,6 +822,7 @@ cdef class RiskEngine(Component):
             Order order
             Money notional
             Money free = None
+            Money total = None
             Money cum_notional_buy = None
             Money cum_notional_sell = None
         for order in orders:
@@ -692,6 +878,21 @@ cdef class RiskEngine(Component):
                 )
                 return False  # Denied
 
+            if account is not None and not order.is_reduce_only:
+                allocation = self._strategy_allocations.get(order.strategy_id)
+                total = account.balance_total(notional.currency)
+                if allocation is not None and total is not None:
+                    if exposure is None:
+                        exposure = self._strategy_exposure(order.strategy_id, notional.currency)
+                    allocated = total.as_decimal() * allocation
+                    exposure += notional.as_decimal()
+                    if exposure > allocated:
+                        self._deny_order(
+                            order=order,
+                            reason=f"NOTIONAL_EXCEEDS_STRATEGY_ALLOCATION {Money(allocated, notional.currency).to_str()} @ {notional.to_str()}",
+                        )
+                        return False  # Denied
+
             if order.is_buy_c():
                 if cum_notional_buy is None:
                     cum_notional_buy = notional
@@ -718,6 +919,44 @@ cdef class RiskEngine(Component):
         # Finally
         return True  # Passed
 
+    cdef object _strategy_exposure(self, StrategyId strategy_id, Currency currency):
+        # Sum the notional of the strategies open orders and positions in the
+        # given currency (valued at order price and average open price).
+        exposure = Decimal(0)
+
+        cdef:
+            Instrument instrument
+            Order order
+            Price price
+            Money notional
+        for order in self._cache.orders_open(strategy_id=strategy_id):
+            if order.is_reduce_only:
+                continue
+            if order.has_price_c():
+                price = order.price
+            elif order.has_trigger_price_c():
+                price = order.trigger_price
+            else:
+                continue  # Cannot value order
+            instrument = self._cache.instrument(order.instrument_id)
+            if instrument is None:
+                continue
+            notional = instrument.notional_value(order.leaves_qty, price)
+            if notional.currency == currency:
+                exposure += notional.as_decimal()
+
+        cdef Position position
+        for position in self._cache.positions_open(strategy_id=strategy_id):
+            instrument = self._cache.instrument(position.instrument_id)
+            if instrument is None:
+                continue
+            price = instrument.make_price(position.avg_px_open)
+            notional = instrument.notional_value(position.quantity, price)
+            if notional.currency == currency:
+                exposure += notional.as_decimal()
+
+        return exposure
+
     cdef str _check_price(self, Instrument instrument, Price price):
         if price is None:
             # Nothing to check
@@ -21,6 +21,7 @@ from nautilus_trader.adapters.ftx.core.types import FTXTicker
 from nautilus_trader.common.events.risk import TradingStateChanged
 from nautilus_trader.common.events.system import ComponentStateChanged
 from nautilus_trader.model.data.bar import Bar
+from nautilus_trader.model.data.cash_flow import CashFlow
 from nautilus_trader.model.data.tick import QuoteTick
 from nautilus_trader.model.data.tick import TradeTick
 from nautilus_trader.model.data.ticker import Ticker
@@ -144,6 +145,16 @@ NAUTILUS_PARQUET_SCHEMA = {
         },
         metadata={"type": "InstrumentStatusUpdate"},
     ),
+    CashFlow: pa.schema(
+        {
+            "instrument_id": pa.dictionary(pa.int64(), pa.string()),
+            "cash_flow_type": pa.dictionary(pa.int8(), pa.string()),
+            "amount": pa.string(),
+            "ts_event": pa.int64(),
+            "ts_init": pa.int64(),
+        },
+        metadata={"type": "CashFlow"},
+    ),
     ComponentStateChanged: pa.schema(
         {
             "trader_id": pa.dictionary(pa.int8(), pa.string()),
@@ -475,6 +486,8 @@ NAUTILUS_PARQUET_SCHEMA = {
             "ts_opened": pa.int64(),
             "ts_event": pa.int64(),
             "ts_init": pa.int64(),
+            "max_adverse_excursion": pa.float64(),
+            "max_favorable_excursion": pa.float64(),
         }
     ),
     PositionClosed: pa.schema(
@@ -503,6 +516,8 @@ NAUTILUS_PARQUET_SCHEMA = {
             "ts_closed": pa.int64(),
             "duration_ns": pa.int64(),
             "ts_init": pa.int64(),
+            "max_adverse_excursion": pa.float64(),
+            "max_favorable_excursion": pa.float64(),
         }
     ),
     BettingInstrument: pa.schema(
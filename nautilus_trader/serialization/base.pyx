@@ -27,6 +27,7 @@ from nautilus_trader.execution.messages cimport ModifyOrder
 from nautilus_trader.execution.messages cimport SubmitOrder
 from nautilus_trader.execution.messages cimport SubmitOrderList
 from nautilus_trader.model.data.bar cimport Bar
+from nautilus_trader.model.data.cash_flow cimport CashFlow
 from nautilus_trader.model.data.tick cimport QuoteTick
 from nautilus_trader.model.data.tick cimport TradeTick
 from nautilus_trader.model.data.ticker cimport Ticker
@@ -102,6 +103,7 @@ _OBJECT_TO_DICT_MAP: Dict[str, Callable[[None], Dict]] = {
     InstrumentStatusUpdate.__name__: InstrumentStatusUpdate.to_dict_c,
     VenueStatusUpdate.__name__: VenueStatusUpdate.to_dict_c,
     InstrumentClosePrice.__name__: InstrumentClosePrice.to_dict_c,
+    CashFlow.__name__: CashFlow.to_dict_c,
     FTXTicker.__name__: FTXTicker.to_dict,
     BinanceBar.__name__: BinanceBar.to_dict,
     BinanceTicker.__name__: BinanceTicker.to_dict,
@@ -149,6 +151,7 @@ _OBJECT_FROM_DICT_MAP: Dict[str, Callable[[Dict], Any]] = {
     InstrumentStatusUpdate.__name__: InstrumentStatusUpdate.from_dict_c,
     VenueStatusUpdate.__name__: VenueStatusUpdate.from_dict_c,
     InstrumentClosePrice.__name__: InstrumentClosePrice.from_dict_c,
+    CashFlow.__name__: CashFlow.from_dict_c,
     FTXTicker.__name__: FTXTicker.from_dict,
     BinanceBar.__name__: BinanceBar.from_dict,
     BinanceTicker.__name__: BinanceTicker.from_dict,
@@ -50,6 +50,10 @@ cdef class Strategy(Actor):
     """The order factory for the strategy.\n\n:returns: `OrderFactory`"""
     cdef readonly OMSType oms_type
     """The order management system for the strategy.\n\n:returns: `OMSType`"""
+    cdef readonly int state_version
+    """The version of the strategy state.\n\n:returns: `int`"""
+    cdef readonly object save_state_interval
+    """The interval at which the strategy state is saved while running.\n\n:returns: `timedelta` or ``None``"""
 
     cpdef bint indicators_initialized(self) except *
 
@@ -57,6 +61,7 @@ cdef class Strategy(Actor):
 
     cpdef dict on_save(self)
     cpdef void on_load(self, dict state) except *
+    cpdef dict on_migrate_state(self, int version, dict state)
 
 # -- REGISTRATION ---------------------------------------------------------------------------------
 
@@ -78,6 +83,15 @@ cdef class Strategy(Actor):
     cpdef dict save(self)
     cpdef void load(self, dict state) except *
 
+# -- COORDINATION ---------------------------------------------------------------------------------
+
+    cpdef bint acquire_lock(self, str name) except *
+    cpdef bint release_lock(self, str name) except *
+    cpdef bint acquire_instrument_lock(self, InstrumentId instrument_id) except *
+    cpdef bint release_instrument_lock(self, InstrumentId instrument_id) except *
+    cpdef bint reserve_budget(self, str name, double amount) except *
+    cpdef void free_budget(self, str name, double amount) except *
+
 # -- TRADING COMMANDS -----------------------------------------------------------------------------
 
     cpdef void submit_order(self, Order order, PositionId position_id=*, ClientId client_id=*, bint check_position_exists=*) except *
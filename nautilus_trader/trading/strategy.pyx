@@ -25,6 +25,7 @@ attempts to operate without a managing `Trader` instance.
 
 """
 
+from datetime import timedelta
 from typing import Optional
 
 from nautilus_trader.config import StrategyConfig
@@ -70,6 +71,10 @@ from nautilus_trader.model.orders.list cimport OrderList
 from nautilus_trader.model.orders.market cimport MarketOrder
 from nautilus_trader.model.position cimport Position
 from nautilus_trader.msgbus.bus cimport MessageBus
+from nautilus_trader.msgbus.coordination cimport instrument_lock_name
+
+
+STATE_VERSION_KEY = "__state_version__"
 
 
 cdef class Strategy(Actor):
@@ -115,6 +120,12 @@ cdef class Strategy(Actor):
 
         self.oms_type = OMSTypeParser.from_str(str(config.oms_type).upper())
 
+        # State persistence
+        self.state_version = config.state_version
+        self.save_state_interval = None
+        if config.save_state_interval_secs is not None:
+            self.save_state_interval = timedelta(seconds=config.save_state_interval_secs)
+
         # Indicators
         self._indicators = []             # type: list[Indicator]
         self._indicators_for_quotes = {}  # type: dict[InstrumentId, list[Indicator]]
@@ -198,6 +209,32 @@ cdef class Strategy(Actor):
         """
         pass  # Optionally override in subclass
 
+    cpdef dict on_migrate_state(self, int version, dict state):
+        """
+        Actions to be performed when a saved state of an older (or newer)
+        version than `state_version` is loaded.
+
+        Return the state migrated to the current version to pass to `on_load`,
+        or ``None`` to discard it (the default).
+
+        Parameters
+        ----------
+        version : int
+            The version of the saved state.
+        state : dict[str, bytes]
+            The saved state dictionary.
+
+        Returns
+        -------
+        dict[str, bytes] or ``None``
+
+        Warnings
+        --------
+        System method (not intended to be called by user code).
+
+        """
+        return None  # Optionally override in subclass
+
 # -- REGISTRATION ---------------------------------------------------------------------------------
 
     cpdef void register(
@@ -355,6 +392,13 @@ cdef class Strategy(Actor):
 
 # -- ACTION IMPLEMENTATIONS -----------------------------------------------------------------------
 
+    cpdef void _stop(self) except *:
+        Actor._stop(self)
+
+        # Release shared resources for other strategies
+        if self._msgbus is not None:
+            self._msgbus.coordinator.release_all(self.id)
+
     cpdef void _reset(self) except *:
         if self.order_factory:
             self.order_factory.reset()
@@ -372,7 +416,7 @@ cdef class Strategy(Actor):
         """
         Return the strategy state dictionary to be saved.
 
-        Calls `on_save`.
+        Calls `on_save`, and adds the `state_version` under `STATE_VERSION_KEY`.
 
         Raises
         ------
@@ -396,7 +440,7 @@ cdef class Strategy(Actor):
                 self.log.info(f"Saved state: {list(user_state.keys())}.", color=LogColor.BLUE)
             else:
                 self.log.info("No user state to save.", color=LogColor.BLUE)
-            return user_state
+            return {**user_state, STATE_VERSION_KEY: str(self.state_version).encode()}
         except Exception as ex:
             self.log.exception("Error on save", ex)
             raise  # Otherwise invalid state information could be saved
@@ -405,7 +449,9 @@ cdef class Strategy(Actor):
         """
         Load the strategy state from the give state dictionary.
 
-        Calls `on_load` and passes the state.
+        Calls `on_load` and passes the state, first calling `on_migrate_state`
+        if it was saved with a different `state_version` (states saved without
+        a version are taken as version 1).
 
         Parameters
         ----------
@@ -424,6 +470,18 @@ cdef class Strategy(Actor):
         """
         Condition.not_none(state, "state")
 
+        state = dict(state)
+        cdef int version = int(state.pop(STATE_VERSION_KEY, b"1"))
+        if state and version != self.state_version:
+            self.log.info(
+                f"Migrating state from version {version} to {self.state_version}...",
+                color=LogColor.BLUE,
+            )
+            state = self.on_migrate_state(version, state)
+            if state is None:
+                self.log.warning(f"Discarded state of version {version}.")
+                return
+
         if not state:
             self.log.info("No user state to load.", color=LogColor.BLUE)
             return
@@ -436,6 +494,115 @@ cdef class Strategy(Actor):
             self.log.exception(f"Error on load {repr(state)}", ex)
             raise
 
+# -- COORDINATION ---------------------------------------------------------------------------------
+
+    cpdef bint acquire_lock(self, str name) except *:
+        """
+        Try to acquire the named lock shared through the message bus.
+
+        Parameters
+        ----------
+        name : str
+            The lock name.
+
+        Returns
+        -------
+        bool
+            True if the lock is held by the strategy, else False.
+
+        """
+        return self._msgbus.coordinator.try_acquire(name, self.id)
+
+    cpdef bint release_lock(self, str name) except *:
+        """
+        Release the named lock if held by the strategy.
+
+        Parameters
+        ----------
+        name : str
+            The lock name.
+
+        Returns
+        -------
+        bool
+            True if the lock was released, else False.
+
+        """
+        return self._msgbus.coordinator.release(name, self.id)
+
+    cpdef bint acquire_instrument_lock(self, InstrumentId instrument_id) except *:
+        """
+        Try to acquire the trading lock for the given instrument, preventing
+        other strategies acquiring it from trading the same position.
+
+        Parameters
+        ----------
+        instrument_id : InstrumentId
+            The instrument ID for the lock.
+
+        Returns
+        -------
+        bool
+            True if the lock is held by the strategy, else False.
+
+        """
+        Condition.not_none(instrument_id, "instrument_id")
+
+        return self.acquire_lock(instrument_lock_name(instrument_id))
+
+    cpdef bint release_instrument_lock(self, InstrumentId instrument_id) except *:
+        """
+        Release the trading lock for the given instrument if held by the strategy.
+
+        Parameters
+        ----------
+        instrument_id : InstrumentId
+            The instrument ID for the lock.
+
+        Returns
+        -------
+        bool
+            True if the lock was released, else False.
+
+        """
+        Condition.not_none(instrument_id, "instrument_id")
+
+        return self.release_lock(instrument_lock_name(instrument_id))
+
+    cpdef bint reserve_budget(self, str name, double amount) except *:
+        """
+        Try to reserve the amount of the named budget shared through the
+        message bus.
+
+        Parameters
+        ----------
+        name : str
+            The budget name.
+        amount : double
+            The amount to reserve.
+
+        Returns
+        -------
+        bool
+            True if reserved, else False if the budget would be exceeded.
+
+        """
+        return self._msgbus.coordinator.try_reserve(name, self.id, amount)
+
+    cpdef void free_budget(self, str name, double amount) except *:
+        """
+        Free the amount of the named budget reserved by the strategy.
+
+        Parameters
+        ----------
+        name : str
+            The budget name.
+        amount : double
+            The amount to free.
+
+        """
+        self._msgbus.coordinator.free(name, self.id, amount)
+
 # -- TRADING COMMANDS -----------------------------------------------------------------------------
 
     cpdef void submit_order(
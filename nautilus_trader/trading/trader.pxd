@@ -18,6 +18,7 @@ from typing import Any, Callable
 from nautilus_trader.cache.cache cimport Cache
 from nautilus_trader.common.actor cimport Actor
 from nautilus_trader.common.component cimport Component
+from nautilus_trader.common.timer cimport TimeEvent
 from nautilus_trader.data.engine cimport DataEngine
 from nautilus_trader.execution.engine cimport ExecutionEngine
 from nautilus_trader.model.identifiers cimport Venue
@@ -34,6 +35,7 @@ cdef class Trader(Component):
     cdef ExecutionEngine _exec_engine
     cdef list _actors
     cdef list _strategies
+    cdef dict _save_state_timers
 
     cdef list actors_c(self)
     cdef list strategies_c(self)
@@ -53,6 +55,7 @@ cdef class Trader(Component):
     cpdef void start(self) except *
     cpdef void stop(self) except *
     cpdef void save(self) except *
+    cpdef void _save_strategy_state(self, TimeEvent event) except *
     cpdef void load(self) except *
     cpdef void reset(self) except *
     cpdef void dispose(self) except *
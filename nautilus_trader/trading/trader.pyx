@@ -32,6 +32,7 @@ from nautilus_trader.common.actor cimport Actor
 from nautilus_trader.common.clock cimport Clock
 from nautilus_trader.common.component cimport Component
 from nautilus_trader.common.logging cimport Logger
+from nautilus_trader.common.timer cimport TimeEvent
 from nautilus_trader.core.correctness cimport Condition
 from nautilus_trader.data.engine cimport DataEngine
 from nautilus_trader.execution.engine cimport ExecutionEngine
@@ -112,6 +113,7 @@ cdef class Trader(Component):
 
         self._actors = []
         self._strategies = []
+        self._save_state_timers = {}  # type: dict[str, Strategy]
 
     cdef list actors_c(self):
         return self._actors
@@ -180,7 +182,25 @@ cdef class Trader(Component):
         for strategy in self._strategies:
             strategy.start()
 
+        # Periodically save strategy states while running
+        cdef str timer_name
+        for strategy in self._strategies:
+            if strategy.save_state_interval is None:
+                continue
+            timer_name = f"SaveState-{strategy.id}"
+            self._clock.set_timer(
+                name=timer_name,
+                interval=strategy.save_state_interval,
+                callback=self._save_strategy_state,
+            )
+            self._save_state_timers[timer_name] = strategy
+
     cpdef void _stop(self) except *:
+        cdef str timer_name
+        for timer_name in self._save_state_timers:
+            self._clock.cancel_timer(timer_name)
+        self._save_state_timers.clear()
+
         cdef Actor actor
         for actor in self._actors:
             if actor.is_running_c():
@@ -215,6 +235,11 @@ cdef class Trader(Component):
         for strategy in self._strategies:
             strategy.dispose()
 
+    cpdef void _save_strategy_state(self, TimeEvent event) except *:
+        cdef Strategy strategy = self._save_state_timers.get(event.name)
+        if strategy is not None and strategy.is_running_c():
+            self._cache.update_strategy(strategy)
+
 # --------------------------------------------------------------------------------------------------
 
     cpdef void add_strategy(self, Strategy strategy) except *:
@@ -24,6 +24,7 @@ from nautilus_trader.accounting.calculators import ExchangeRateCalculator
 from nautilus_trader.accounting.calculators import RolloverInterestCalculator
 from nautilus_trader.model.currencies import AUD
 from nautilus_trader.model.currencies import BTC
+from nautilus_trader.model.currencies import ETH
 from nautilus_trader.model.currencies import JPY
 from nautilus_trader.model.currencies import USD
 from nautilus_trader.model.enums import PriceType
@@ -190,6 +191,28 @@ class TestExchangeRateCalculator:
         assert result1 == 0.011353315168029064
         assert result2 == 88.11501299999999
 
+    def test_calculate_exchange_rate_by_inference_through_multiple_currencies(self):
+        # Arrange
+        converter = ExchangeRateCalculator()
+        bid_rates = {
+            "ETH/BTC": 0.05,
+            "BTC/USD": 40000.0,
+            "USD/JPY": 110.0,
+        }
+        ask_rates = bid_rates.copy()
+
+        # Act
+        result = converter.get_rate(
+            JPY,
+            ETH,
+            PriceType.MID,
+            bid_rates,
+            ask_rates,
+        )
+
+        # Assert
+        assert result == pytest.approx(1 / (0.05 * 40000.0 * 110.0))
+
     def test_calculate_exchange_rate_for_mid_price_type(self):
         # Arrange
         converter = ExchangeRateCalculator()
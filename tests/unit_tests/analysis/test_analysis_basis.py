@@ -0,0 +1,161 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from datetime import date
+
+import pytest
+
+from nautilus_trader.analysis.basis import BasisAnalytics
+from nautilus_trader.analysis.basis import BasisAnalyticsConfig
+from nautilus_trader.analysis.basis import BasisData
+from nautilus_trader.analysis.basis import annualized_carry
+from nautilus_trader.analysis.basis import basis
+from nautilus_trader.analysis.basis import roll_yield
+from nautilus_trader.backtest.data.providers import TestInstrumentProvider
+from nautilus_trader.common.clock import TestClock
+from nautilus_trader.common.logging import Logger
+from nautilus_trader.model.currencies import USD
+from nautilus_trader.model.data.tick import QuoteTick
+from nautilus_trader.model.enums import AssetClass
+from nautilus_trader.model.identifiers import InstrumentId
+from nautilus_trader.model.identifiers import Symbol
+from nautilus_trader.model.identifiers import Venue
+from nautilus_trader.model.instruments.future import Future
+from nautilus_trader.model.objects import Price
+from nautilus_trader.model.objects import Quantity
+from nautilus_trader.msgbus.bus import MessageBus
+from tests.test_kit.stubs.component import TestComponentStubs
+from tests.test_kit.stubs.identifiers import TestIdStubs
+
+
+ESZ21 = TestInstrumentProvider.es_future()
+ESH22 = Future(
+    instrument_id=InstrumentId(symbol=Symbol("ESH22"), venue=Venue("CME")),
+    native_symbol=Symbol("ESH22"),
+    asset_class=AssetClass.INDEX,
+    currency=USD,
+    price_precision=2,
+    price_increment=Price.from_str("0.01"),
+    multiplier=Quantity.from_int(1),
+    lot_size=Quantity.from_int(1),
+    underlying="ES",
+    expiry_date=date(2022, 3, 18),  # 91 days after ESZ21
+    ts_event=0,
+    ts_init=0,
+)
+SPX_ID = InstrumentId(symbol=Symbol("SPX"), venue=Venue("CME"))
+
+ONE_DAY_NS = 86_400_000_000_000
+ESZ21_EXPIRY_NS = 1639699200000000000  # 2021-12-17 00:00 UTC
+
+
+def _quote_tick(instrument_id: InstrumentId, mid: float, ts: int) -> QuoteTick:
+    return QuoteTick(
+        instrument_id=instrument_id,
+        bid=Price(mid - 0.25, precision=2),
+        ask=Price(mid + 0.25, precision=2),
+        bid_size=Quantity.from_int(10),
+        ask_size=Quantity.from_int(10),
+        ts_event=ts,
+        ts_init=ts,
+    )
+
+
+class TestBasisFunctions:
+    def test_basis(self):
+        # Arrange, Act, Assert
+        assert basis(4610.0, 4600.0) == 10.0
+
+    def test_annualized_carry(self):
+        # Arrange, Act, Assert
+        assert annualized_carry(4610.0, 4600.0, 36.5) == pytest.approx(10.0 / 4600.0 * 10)
+
+    def test_roll_yield_in_backwardation_is_positive(self):
+        # Arrange, Act, Assert
+        assert roll_yield(102.0, 100.0, 365.0) == pytest.approx(0.02)
+        assert roll_yield(100.0, 102.0, 365.0) < 0
+
+
+class TestBasisAnalytics:
+    def setup(self):
+        # Fixture Setup
+        self.clock = TestClock()
+        self.logger = Logger(clock=self.clock)
+        self.msgbus = MessageBus(
+            trader_id=TestIdStubs.trader_id(),
+            clock=self.clock,
+            logger=self.logger,
+        )
+        self.cache = TestComponentStubs.cache()
+        self.cache.add_instrument(ESZ21)
+        self.cache.add_instrument(ESH22)
+
+        self.actor = BasisAnalytics(
+            BasisAnalyticsConfig(
+                spot_instrument_id=str(SPX_ID),
+                future_instrument_ids=[str(ESH22.id), str(ESZ21.id)],
+            ),
+        )
+        self.actor.register_base(
+            trader_id=TestIdStubs.trader_id(),
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+            logger=self.logger,
+        )
+
+        self.received = []
+        self.msgbus.subscribe(topic="data.BasisData*", handler=self.received.append)
+
+    def test_start_orders_futures_by_expiry(self):
+        # Arrange, Act
+        self.actor.start()
+
+        # Assert
+        assert self.actor.future_ids == [ESZ21.id, ESH22.id]
+
+    def test_quote_tick_without_spot_price_publishes_nothing(self):
+        # Arrange
+        self.actor.start()
+
+        # Act
+        self.actor.handle_quote_tick(_quote_tick(ESZ21.id, 4610.0, 0))
+
+        # Assert
+        assert self.received == []
+
+    def test_quote_ticks_publish_basis_data(self):
+        # Arrange
+        ts = ESZ21_EXPIRY_NS - 30 * ONE_DAY_NS
+        self.actor.start()
+        self.actor.handle_quote_tick(_quote_tick(SPX_ID, 4600.0, ts))
+        self.actor.handle_quote_tick(_quote_tick(ESZ21.id, 4610.0, ts))
+
+        # Act
+        self.actor.handle_quote_tick(_quote_tick(ESH22.id, 4630.0, ts))
+
+        # Assert
+        assert len(self.received) == 3
+        near = self.received[1]
+        assert isinstance(near, BasisData)
+        assert near.future_id == ESZ21.id
+        assert near.basis == 10.0
+        assert near.days_to_expiry == 30.0
+        assert near.carry == pytest.approx(annualized_carry(4610.0, 4600.0, 30.0))
+        assert near.roll_yield == pytest.approx(roll_yield(4610.0, 4630.0, 91.0))
+        far = self.received[2]
+        assert far.future_id == ESH22.id
+        assert far.basis == 30.0
+        assert far.roll_yield is None
@@ -253,3 +253,5 @@ class TestReportProvider:
         assert report.iloc[0]["ts_opened"] == UNIX_EPOCH
         assert report.iloc[0]["ts_closed"] == UNIX_EPOCH
         assert report.iloc[0]["realized_return"] == "0.0"
+        assert report.iloc[0]["max_adverse_excursion"] == "0.0"
+        assert report.iloc[0]["max_favorable_excursion"] == "0.0"
@@ -0,0 +1,152 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import pytest
+
+from nautilus_trader.analysis.spread import SpreadData
+from nautilus_trader.analysis.spread import SpreadTracker
+from nautilus_trader.analysis.spread import SpreadTrackerConfig
+from nautilus_trader.analysis.spread import hedge_ratio
+from nautilus_trader.analysis.spread import z_score
+from nautilus_trader.common.clock import TestClock
+from nautilus_trader.common.logging import Logger
+from nautilus_trader.model.data.tick import QuoteTick
+from nautilus_trader.model.identifiers import InstrumentId
+from nautilus_trader.model.identifiers import Symbol
+from nautilus_trader.model.identifiers import Venue
+from nautilus_trader.model.objects import Price
+from nautilus_trader.model.objects import Quantity
+from nautilus_trader.msgbus.bus import MessageBus
+from tests.test_kit.stubs.component import TestComponentStubs
+from tests.test_kit.stubs.identifiers import TestIdStubs
+
+
+KO_ID = InstrumentId(symbol=Symbol("KO"), venue=Venue("NYSE"))
+PEP_ID = InstrumentId(symbol=Symbol("PEP"), venue=Venue("NYSE"))
+
+
+def _quote_tick(instrument_id: InstrumentId, mid: float, ts: int) -> QuoteTick:
+    return QuoteTick(
+        instrument_id=instrument_id,
+        bid=Price(mid - 0.05, precision=2),
+        ask=Price(mid + 0.05, precision=2),
+        bid_size=Quantity.from_int(100),
+        ask_size=Quantity.from_int(100),
+        ts_event=ts,
+        ts_init=ts,
+    )
+
+
+class TestSpreadFunctions:
+    def test_hedge_ratio(self):
+        # Arrange, Act, Assert
+        assert hedge_ratio([3.0, 5.0, 7.0], [1.0, 2.0, 3.0]) == pytest.approx(2.0)
+        assert hedge_ratio([3.0, 5.0], [1.0, 1.0]) is None
+        assert hedge_ratio([3.0], [1.0]) is None
+
+    def test_z_score(self):
+        # Arrange, Act, Assert
+        assert z_score(3.0, [1.0, 3.0]) == pytest.approx(1.0)
+        assert z_score(1.0, [1.0, 1.0]) is None
+        assert z_score(1.0, []) is None
+
+
+class TestSpreadTracker:
+    def setup(self):
+        # Fixture Setup
+        self.clock = TestClock()
+        self.logger = Logger(clock=self.clock)
+        self.msgbus = MessageBus(
+            trader_id=TestIdStubs.trader_id(),
+            clock=self.clock,
+            logger=self.logger,
+        )
+
+        self.tracker = SpreadTracker(
+            SpreadTrackerConfig(
+                instrument_id_a=str(KO_ID),
+                instrument_id_b=str(PEP_ID),
+                lookback=3,
+            ),
+        )
+        self.tracker.register_base(
+            trader_id=TestIdStubs.trader_id(),
+            msgbus=self.msgbus,
+            cache=TestComponentStubs.cache(),
+            clock=self.clock,
+            logger=self.logger,
+        )
+
+        self.received = []
+        self.msgbus.subscribe(topic="data.SpreadData*", handler=self.received.append)
+
+    def test_instantiate_with_short_lookback_raises_value_error(self):
+        # Arrange, Act, Assert
+        with pytest.raises(ValueError):
+            SpreadTracker(
+                SpreadTrackerConfig(
+                    instrument_id_a=str(KO_ID),
+                    instrument_id_b=str(PEP_ID),
+                    lookback=1,
+                ),
+            )
+
+    def test_single_pair_publishes_nothing(self):
+        # Arrange
+        self.tracker.start()
+
+        # Act
+        self.tracker.handle_quote_tick(_quote_tick(KO_ID, 60.0, 0))
+        self.tracker.handle_quote_tick(_quote_tick(PEP_ID, 170.0, 1))
+
+        # Assert
+        assert self.received == []
+
+    def test_quote_ticks_publish_spread_data(self):
+        # Arrange
+        self.tracker.start()
+        self.tracker.handle_quote_tick(_quote_tick(KO_ID, 60.0, 0))
+        self.tracker.handle_quote_tick(_quote_tick(PEP_ID, 170.0, 1))
+
+        # Act
+        self.tracker.handle_quote_tick(_quote_tick(PEP_ID, 180.0, 2))
+        self.tracker.handle_quote_tick(_quote_tick(KO_ID, 65.0, 3))
+
+        # Assert
+        assert len(self.received) == 2
+        data = self.received[-1]
+        assert isinstance(data, SpreadData)
+        assert data.instrument_id_a == KO_ID
+        assert data.instrument_id_b == PEP_ID
+        assert data.hedge_ratio == pytest.approx(0.25)
+        assert data.spread == pytest.approx(65.0 - 0.25 * 180.0)
+        assert data.z_score == pytest.approx(z_score(20.0, [17.5, 15.0, 20.0]))
+        assert data.bid == pytest.approx(64.95 - 0.25 * 180.05)
+        assert data.ask == pytest.approx(65.05 - 0.25 * 179.95)
+        assert data.ts_event == 3
+
+    def test_reset_clears_recorded_prices(self):
+        # Arrange
+        self.tracker.start()
+        self.tracker.handle_quote_tick(_quote_tick(KO_ID, 60.0, 0))
+        self.tracker.handle_quote_tick(_quote_tick(PEP_ID, 170.0, 1))
+        self.tracker.handle_quote_tick(_quote_tick(PEP_ID, 180.0, 2))
+        self.tracker.stop()
+
+        # Act
+        self.tracker.reset()
+
+        # Assert
+        assert self.tracker.calculate(3, 3) is None
@@ -0,0 +1,152 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from decimal import Decimal
+
+import pytest
+
+from nautilus_trader.backtest.data.providers import TestInstrumentProvider
+from nautilus_trader.backtest.determinism import DeterminismCheckpoint
+from nautilus_trader.backtest.determinism import DeterminismValidator
+from nautilus_trader.backtest.determinism import EventStreamHasher
+from nautilus_trader.backtest.determinism import event_bytes
+from nautilus_trader.backtest.determinism import find_divergence
+from nautilus_trader.examples.strategies.ema_cross import EMACross
+from nautilus_trader.examples.strategies.ema_cross import EMACrossConfig
+from tests.test_kit.stubs.component import TestComponentStubs
+from tests.test_kit.stubs.config import TestConfigStubs
+from tests.test_kit.stubs.data import TestDataStubs
+from tests.test_kit.stubs.events import TestEventStubs
+
+
+USDJPY_SIM = TestInstrumentProvider.default_fx_ccy("USD/JPY")
+
+
+def _engine(trade_size: int):
+    engine = TestComponentStubs.backtest_engine(
+        config=TestConfigStubs.backtest_engine_config(),
+        instrument=USDJPY_SIM,
+        ticks=TestDataStubs.quote_ticks_usdjpy(),
+    )
+    config = EMACrossConfig(
+        instrument_id=str(USDJPY_SIM.id),
+        bar_type="USD/JPY.SIM-15-MINUTE-BID-INTERNAL",
+        trade_size=Decimal(trade_size),
+        fast_ema=10,
+        slow_ema=20,
+    )
+    engine.add_strategy(EMACross(config=config))
+    return engine
+
+
+class TestEventStreamHasher:
+    def test_instantiate_with_invalid_interval_raises_value_error(self):
+        # Arrange, Act, Assert
+        with pytest.raises(ValueError):
+            EventStreamHasher(checkpoint_interval=0)
+
+    def test_event_bytes_excludes_event_id(self):
+        # Arrange
+        event1 = TestEventStubs.cash_account_state()
+        event2 = TestEventStubs.cash_account_state()
+
+        # Act, Assert
+        assert event1.id != event2.id
+        assert event_bytes(event1) == event_bytes(event2)
+
+    def test_checkpoints_at_interval_and_finish(self):
+        # Arrange
+        hasher = EventStreamHasher(checkpoint_interval=2)
+
+        # Act
+        for _ in range(3):
+            hasher.on_event(TestEventStubs.cash_account_state())
+        checkpoints = hasher.finish()
+
+        # Assert
+        assert [c.event_count for c in checkpoints] == [2, 3]
+        assert checkpoints[0].digest != checkpoints[1].digest
+        assert hasher.finish() == checkpoints
+
+    def test_finish_with_no_events_adds_empty_checkpoint(self):
+        # Arrange
+        hasher = EventStreamHasher()
+
+        # Act
+        checkpoints = hasher.finish()
+
+        # Assert
+        assert len(checkpoints) == 1
+        assert checkpoints[0].event_count == 0
+
+
+class TestFindDivergence:
+    def test_identical_checkpoints_returns_none(self):
+        # Arrange
+        checkpoints = [DeterminismCheckpoint(2, 10, "a"), DeterminismCheckpoint(3, 20, "b")]
+
+        # Act, Assert
+        assert find_divergence(checkpoints, list(checkpoints)) is None
+
+    def test_returns_first_differing_checkpoint(self):
+        # Arrange
+        expected = [DeterminismCheckpoint(2, 10, "a"), DeterminismCheckpoint(4, 20, "b")]
+        actual = [DeterminismCheckpoint(2, 10, "a"), DeterminismCheckpoint(4, 20, "c")]
+
+        # Act
+        divergence = find_divergence(expected, actual)
+
+        # Assert
+        assert divergence.index == 1
+        assert divergence.expected == expected[1]
+        assert divergence.actual == actual[1]
+
+    def test_shorter_stream_diverges_where_it_ends(self):
+        # Arrange
+        expected = [DeterminismCheckpoint(2, 10, "a"), DeterminismCheckpoint(4, 20, "b")]
+
+        # Act
+        divergence = find_divergence(expected, expected[:1])
+
+        # Assert
+        assert divergence.index == 1
+        assert divergence.actual is None
+
+
+class TestDeterminismValidator:
+    def test_compare_identical_engines_is_deterministic(self):
+        # Arrange
+        engine = _engine(trade_size=1_000_000)
+        other = _engine(trade_size=1_000_000)
+
+        # Act
+        report = DeterminismValidator.compare_engines(engine, other, checkpoint_interval=50)
+
+        # Assert
+        assert report.is_deterministic
+        assert report.expected[-1].event_count > 0
+        assert report.expected == report.actual
+
+    def test_compare_differing_engines_reports_first_divergence(self):
+        # Arrange
+        engine = _engine(trade_size=1_000_000)
+        other = _engine(trade_size=2_000_000)
+
+        # Act
+        report = DeterminismValidator.compare_engines(engine, other, checkpoint_interval=50)
+
+        # Assert
+        assert not report.is_deterministic
+        assert report.divergence.index == 0
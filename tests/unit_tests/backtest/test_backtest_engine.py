@@ -16,6 +16,7 @@ import tempfile
 from decimal import Decimal
 
 import pandas as pd
+import pytest
 
 from nautilus_trader.backtest.data.providers import TestDataProvider
 from nautilus_trader.backtest.data.providers import TestInstrumentProvider
@@ -136,6 +137,45 @@ class TestBacktestEngine:
         # Assert
         assert len(self.engine.trader.strategy_states()) == 1
 
+    def test_run_with_progress_callback_reports_completion(self):
+        # Arrange
+        reports = []
+
+        # Act
+        self.engine.run(progress_callback=reports.append)
+
+        # Assert
+        assert reports[-1]["processed"] == 8000
+        assert reports[-1]["total"] == 8000
+        assert reports[-1]["percent"] == 100.0
+        assert reports[-1]["eta_secs"] == 0.0
+        assert not self.engine.cancelled
+
+    def test_cancel_from_progress_callback_stops_run(self):
+        # Arrange
+        def on_progress(progress):
+            if progress["processed"] >= 100:
+                self.engine.cancel()
+
+        # Act
+        self.engine.run(progress_callback=on_progress, progress_interval_ms=0)
+
+        # Assert
+        assert self.engine.iteration == 100
+        assert self.engine.cancelled
+        assert self.engine.run_finished is not None
+
+    def test_progress_callback_error_cancels_run_and_raises(self):
+        # Arrange
+        def on_progress(progress):
+            raise RuntimeError("boom")
+
+        # Act, Assert
+        with pytest.raises(RuntimeError):
+            self.engine.run(progress_callback=on_progress, progress_interval_ms=0)
+        assert self.engine.iteration == 1
+        assert self.engine.cancelled
+
     def test_change_fill_model(self):
         # Arrange, Act
         self.engine.change_fill_model(Venue("SIM"), FillModel())
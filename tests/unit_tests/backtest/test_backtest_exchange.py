@@ -23,6 +23,7 @@ from nautilus_trader.backtest.exchange import SimulatedExchange
 from nautilus_trader.backtest.execution_client import BacktestExecClient
 from nautilus_trader.backtest.models import FillModel
 from nautilus_trader.backtest.models import LatencyModel
+from nautilus_trader.backtest.models import LinearImpactModel
 from nautilus_trader.common.clock import TestClock
 from nautilus_trader.common.logging import Logger
 from nautilus_trader.common.uuid import UUIDFactory
@@ -355,6 +356,38 @@ class TestSimulatedExchange:
         assert order.status == OrderStatus.FILLED
         assert order.avg_px == 90.005  # No slippage
 
+    def test_submit_large_market_order_with_impact_model_fills_at_impacted_price(self):
+        # Arrange: Prepare market
+        self.exchange.set_impact_model(
+            LinearImpactModel(
+                temporary_coef=0.01,
+                permanent_coef=0.0,
+                reference_volumes={USDJPY_SIM.id: 1_000_000},
+            ),
+        )
+        tick = TestDataStubs.quote_tick_3decimal(
+            instrument_id=USDJPY_SIM.id,
+            bid=Price.from_str("90.002"),
+            ask=Price.from_str("90.005"),
+        )
+        self.data_engine.process(tick)
+        self.exchange.process_quote_tick(tick)
+
+        # Create order
+        order = self.strategy.order_factory.market(
+            USDJPY_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100000),
+        )
+
+        # Act
+        self.strategy.submit_order(order)
+        self.exchange.process(0)
+
+        # Assert: participation 0.1 moves the price up 0.1%
+        assert order.status == OrderStatus.FILLED
+        assert order.avg_px == 90.095
+
     def test_submit_market_order_then_immediately_cancel_submits_and_fills(self):
         # Arrange: Prepare market
         tick = TestDataStubs.quote_tick_3decimal(
@@ -0,0 +1,100 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import math
+
+import pyarrow as pa
+import pytest
+
+from nautilus_trader.backtest.fast import FastBacktestResult
+from nautilus_trader.backtest.fast import run_fast_backtest
+from nautilus_trader.backtest.vectorized import BARS_SCHEMA
+
+
+def _bars(closes) -> pa.Table:
+    n = len(closes)
+    return pa.Table.from_arrays(
+        [
+            pa.array(range(n), type=pa.int64()),
+            pa.array(range(n), type=pa.int64()),
+            pa.array([c + 0.5 for c in closes], type=pa.float64()),
+            pa.array([c + 1.0 for c in closes], type=pa.float64()),
+            pa.array([c - 1.0 for c in closes], type=pa.float64()),
+            pa.array(closes, type=pa.float64()),
+            pa.array([100.0] * n, type=pa.float64()),
+        ],
+        schema=BARS_SCHEMA,
+    )
+
+
+class TestFastBacktest:
+    def test_run_fills_targets_at_next_open(self):
+        # Arrange
+        bars = _bars([10.0, 11.0, 12.0, 13.0])
+
+        # Act
+        result = run_fast_backtest(bars, [1.0, None, 0.0, None])
+
+        # Assert
+        assert isinstance(result, FastBacktestResult)
+        # Bought 1 at 10.5 (open of bar 1), sold 1 at 12.5 (open of bar 3)
+        assert result.positions.tolist() == [1.0, 1.0, 0.0, 0.0]
+        assert result.pnl.tolist() == [-0.5, 0.5, 2.0, 2.0]
+        assert result.total_pnl == 2.0
+        assert result.total_commission == 0.0
+        assert result.fills == 2
+        assert result.turnover == 23.0
+        assert result.max_drawdown == 0.5
+        assert result.sharpe_ratio > 0.0
+
+    def test_run_with_commission_reduces_pnl(self):
+        # Arrange
+        bars = _bars([10.0, 11.0, 12.0, 13.0])
+
+        # Act
+        result = run_fast_backtest(bars, [1.0, None, 0.0, None], commission_rate=0.01)
+
+        # Assert
+        assert result.total_commission == pytest.approx(0.23)
+        assert result.total_pnl == pytest.approx(2.0 - 0.23)
+
+    def test_run_with_no_bars(self):
+        # Arrange
+        bars = BARS_SCHEMA.empty_table()
+
+        # Act
+        result = run_fast_backtest(bars, [])
+
+        # Assert
+        assert len(result.positions) == 0
+        assert result.total_pnl == 0.0
+        assert result.fills == 0
+        assert math.isnan(result.sharpe_ratio)
+
+    def test_run_with_invalid_costs_raises_value_error(self):
+        # Arrange
+        bars = _bars([10.0, 11.0])
+
+        # Act, Assert
+        with pytest.raises(ValueError):
+            run_fast_backtest(bars, [1.0, 0.0], slippage=-0.1)
+
+    def test_run_with_targets_of_wrong_length_raises_value_error(self):
+        # Arrange
+        bars = _bars([10.0, 11.0])
+
+        # Act, Assert
+        with pytest.raises(ValueError):
+            run_fast_backtest(bars, [1.0])
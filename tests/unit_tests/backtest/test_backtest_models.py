@@ -13,8 +13,17 @@
 #  limitations under the License.
 # -------------------------------------------------------------------------------------------------
 
+import pytest
+
 from nautilus_trader.backtest.models import FillModel
 from nautilus_trader.backtest.models import LatencyModel
+from nautilus_trader.backtest.models import LinearImpactModel
+from nautilus_trader.backtest.models import SquareRootImpactModel
+from nautilus_trader.model.enums import OrderSide
+from nautilus_trader.model.identifiers import InstrumentId
+
+
+AUDUSD_SIM = InstrumentId.from_str("AUD/USD.SIM")
 
 
 class TestFillModel:
@@ -76,3 +85,82 @@ class TestExchangeLatency:
         assert latency.insert_latency_nanos == self.NANOSECONDS_IN_MILLISECOND
         assert latency.update_latency_nanos == self.NANOSECONDS_IN_MILLISECOND
         assert latency.cancel_latency_nanos == self.NANOSECONDS_IN_MILLISECOND
+
+
+class TestMarketImpactModel:
+    def test_participation_with_no_reference_volume_returns_zero(self):
+        # Arrange
+        model = LinearImpactModel(temporary_coef=0.1, permanent_coef=0.01)
+
+        # Act, Assert
+        assert model.participation(AUDUSD_SIM, 100_000) == 0.0
+        assert model.price_multiplier(AUDUSD_SIM, OrderSide.BUY, 100_000) == 1.0
+
+    def test_price_multiplier_below_threshold_has_no_impact(self):
+        # Arrange
+        model = LinearImpactModel(
+            temporary_coef=0.1,
+            permanent_coef=0.01,
+            participation_threshold=0.05,
+            reference_volumes={AUDUSD_SIM: 1_000_000},
+        )
+
+        # Act, Assert
+        assert model.price_multiplier(AUDUSD_SIM, OrderSide.BUY, 50_000) == 1.0
+        assert model.permanent_offset(AUDUSD_SIM) == 0.0
+
+    def test_linear_price_multiplier_applies_temporary_and_permanent_impact(self):
+        # Arrange
+        model = LinearImpactModel(
+            temporary_coef=0.1,
+            permanent_coef=0.01,
+            reference_volumes={AUDUSD_SIM: 1_000_000},
+        )
+
+        # Act
+        buy = model.price_multiplier(AUDUSD_SIM, OrderSide.BUY, 100_000)
+        small_sell = model.price_multiplier(AUDUSD_SIM, OrderSide.SELL, 1_000)
+
+        # Assert
+        assert buy == pytest.approx(1.01)
+        assert model.permanent_offset(AUDUSD_SIM) == pytest.approx(0.001)
+        assert small_sell == pytest.approx(1.001)
+
+    def test_square_root_price_multiplier_for_sell(self):
+        # Arrange
+        model = SquareRootImpactModel(
+            temporary_coef=0.1,
+            permanent_coef=0.01,
+            reference_volumes={AUDUSD_SIM: 1_000_000},
+        )
+
+        # Act
+        multiplier = model.price_multiplier(AUDUSD_SIM, OrderSide.SELL, 40_000)
+
+        # Assert
+        assert multiplier == pytest.approx(0.98)
+        assert model.permanent_offset(AUDUSD_SIM) == pytest.approx(-0.0004)
+
+    def test_reset_clears_permanent_impact(self):
+        # Arrange
+        model = LinearImpactModel(
+            temporary_coef=0.1,
+            permanent_coef=0.01,
+            reference_volumes={AUDUSD_SIM: 1_000_000},
+        )
+        model.price_multiplier(AUDUSD_SIM, OrderSide.BUY, 100_000)
+
+        # Act
+        model.reset()
+
+        # Assert
+        assert model.permanent_offset(AUDUSD_SIM) == 0.0
+
+    def test_instantiate_with_invalid_threshold_raises_value_error(self):
+        # Arrange, Act, Assert
+        with pytest.raises(ValueError):
+            LinearImpactModel(
+                temporary_coef=0.1,
+                permanent_coef=0.01,
+                participation_threshold=1.5,
+            )
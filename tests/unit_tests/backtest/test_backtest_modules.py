@@ -0,0 +1,344 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from decimal import Decimal
+
+import pandas as pd
+import pytest
+
+from nautilus_trader.backtest.data.providers import TestInstrumentProvider
+from nautilus_trader.backtest.exchange import SimulatedExchange
+from nautilus_trader.backtest.execution_client import BacktestExecClient
+from nautilus_trader.backtest.models import FillModel
+from nautilus_trader.backtest.models import LatencyModel
+from nautilus_trader.backtest.modules import CashFlowModule
+from nautilus_trader.backtest.modules import CashInterestModule
+from nautilus_trader.backtest.modules import FuturesSettlementModule
+from nautilus_trader.common.clock import TestClock
+from nautilus_trader.common.logging import Logger
+from nautilus_trader.data.engine import DataEngine
+from nautilus_trader.execution.engine import ExecutionEngine
+from nautilus_trader.model.currencies import USD
+from nautilus_trader.model.data.cash_flow import CashFlow
+from nautilus_trader.model.data.tick import QuoteTick
+from nautilus_trader.model.enums import AccountType
+from nautilus_trader.model.enums import CashFlowType
+from nautilus_trader.model.enums import OMSType
+from nautilus_trader.model.enums import OrderSide
+from nautilus_trader.model.objects import Money
+from nautilus_trader.model.objects import Price
+from nautilus_trader.model.objects import Quantity
+from nautilus_trader.msgbus.bus import MessageBus
+from nautilus_trader.portfolio.portfolio import Portfolio
+from nautilus_trader.risk.engine import RiskEngine
+from tests.test_kit.mocks.strategies import MockStrategy
+from tests.test_kit.stubs.component import TestComponentStubs
+from tests.test_kit.stubs.data import TestDataStubs
+from tests.test_kit.stubs.identifiers import TestIdStubs
+
+
+AAPL_NASDAQ = TestInstrumentProvider.aapl_equity()
+ESZ21_CME = TestInstrumentProvider.es_future()
+
+
+def _ns(timestamp: str) -> int:
+    return pd.Timestamp(timestamp, tz="US/Eastern").value
+
+
+class _ModuleFixture:
+    def _setup(self, instrument, module):
+        self.instrument = instrument
+        self.module = module
+        self.clock = TestClock()
+        self.logger = Logger(clock=self.clock)
+        self.trader_id = TestIdStubs.trader_id()
+        self.msgbus = MessageBus(
+            trader_id=self.trader_id,
+            clock=self.clock,
+            logger=self.logger,
+        )
+        self.cache = TestComponentStubs.cache()
+        self.portfolio = Portfolio(
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+            logger=self.logger,
+        )
+        self.data_engine = DataEngine(
+            msgbus=self.msgbus,
+            clock=self.clock,
+            cache=self.cache,
+            logger=self.logger,
+        )
+        self.exec_engine = ExecutionEngine(
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+            logger=self.logger,
+        )
+        self.risk_engine = RiskEngine(
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+            logger=self.logger,
+        )
+
+        self.exchange = SimulatedExchange(
+            venue=instrument.id.venue,
+            oms_type=OMSType.NETTING,
+            account_type=AccountType.MARGIN,
+            base_currency=USD,
+            starting_balances=[Money(1_000_000, USD)],
+            default_leverage=Decimal(10),
+            leverages={},
+            is_frozen_account=False,
+            instruments=[instrument],
+            modules=[module],
+            fill_model=FillModel(),
+            cache=self.cache,
+            clock=self.clock,
+            logger=self.logger,
+            latency_model=LatencyModel(0),
+        )
+        self.exec_client = BacktestExecClient(
+            exchange=self.exchange,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+            logger=self.logger,
+        )
+
+        # Wire up components
+        self.exec_engine.register_client(self.exec_client)
+        self.exchange.register_client(self.exec_client)
+        self.cache.add_instrument(instrument)
+
+        self.strategy = MockStrategy(bar_type=TestDataStubs.bartype_usdjpy_1min_bid())
+        self.strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+            logger=self.logger,
+        )
+
+        # Start components
+        self.exchange.reset()
+        self.data_engine.start()
+        self.exec_engine.start()
+        self.strategy.start()
+
+    def _quote(self, bid: str, ask: str) -> None:
+        tick = QuoteTick(
+            instrument_id=self.instrument.id,
+            bid=Price.from_str(bid),
+            ask=Price.from_str(ask),
+            bid_size=Quantity.from_int(1_000),
+            ask_size=Quantity.from_int(1_000),
+            ts_event=0,
+            ts_init=0,
+        )
+        self.data_engine.process(tick)
+        self.exchange.process_quote_tick(tick)
+
+    def _balance(self) -> Money:
+        return self.cache.account_for_venue(self.instrument.id.venue).balance_total(USD)
+
+
+class TestFuturesSettlementModule(_ModuleFixture):
+    def setup(self):
+        # Fixture Setup
+        self._setup(ESZ21_CME, FuturesSettlementModule())
+
+    def _open_long(self) -> None:
+        self._quote("4000.00", "4000.50")
+        order = self.strategy.order_factory.market(
+            ESZ21_CME.id,
+            OrderSide.BUY,
+            Quantity.from_int(10),
+        )
+        self.strategy.submit_order(order)
+        self.exchange.process(_ns("2021-12-13 14:00"))  # Monday
+
+    def test_invalid_session_day_raises_value_error(self):
+        # Arrange, Act, Assert
+        with pytest.raises(ValueError):
+            FuturesSettlementModule(session_days=(0, 1))
+
+    def test_settlement_credits_variation_margin_at_session_close(self):
+        # Arrange
+        self._open_long()
+        position = self.cache.positions_open()[0]
+        balance = self._balance()
+        self._quote("4010.00", "4010.50")
+
+        # Act
+        self.exchange.process(_ns("2021-12-13 16:59"))
+        balance_before_close = self._balance()
+        self.exchange.process(_ns("2021-12-13 17:00"))
+        self.exchange.process(_ns("2021-12-13 17:30"))
+
+        # Assert
+        assert balance_before_close == balance
+        assert self._balance() == Money(balance.as_double() + 97.50, USD)
+        assert position.avg_px_open == 4010.25
+
+    def test_no_settlement_outside_session_days(self):
+        # Arrange
+        self._open_long()
+        balance = self._balance()
+        self._quote("4010.00", "4010.50")
+
+        # Act
+        self.exchange.process(_ns("2021-12-18 17:00"))  # Saturday
+
+        # Assert
+        assert self._balance() == balance
+
+    def test_settlement_marks_from_previous_settlement(self):
+        # Arrange
+        self._open_long()
+        self._quote("4010.00", "4010.50")
+        self.exchange.process(_ns("2021-12-13 17:00"))
+        balance = self._balance()
+        self._quote("4005.00", "4005.50")
+
+        # Act
+        self.exchange.process(_ns("2021-12-14 17:00"))  # Tuesday
+
+        # Assert
+        assert self._balance() == Money(balance.as_double() - 50.00, USD)
+
+
+class TestCashFlowModule(_ModuleFixture):
+    def setup(self):
+        # Fixture Setup
+        self.ex_date = _ns("2021-11-05 09:30")
+        dividend = CashFlow(
+            instrument_id=AAPL_NASDAQ.id,
+            cash_flow_type=CashFlowType.DIVIDEND,
+            amount=Money(0.22, USD),
+            ts_event=self.ex_date,
+            ts_init=0,
+        )
+        self._setup(AAPL_NASDAQ, CashFlowModule([dividend]))
+
+    def _open(self, side: OrderSide) -> None:
+        self._quote("150.00", "150.05")
+        order = self.strategy.order_factory.market(
+            AAPL_NASDAQ.id,
+            side,
+            Quantity.from_int(100),
+        )
+        self.strategy.submit_order(order)
+        self.exchange.process(_ns("2021-11-04 10:00"))
+
+    def test_invalid_cash_flows_raises_type_error(self):
+        # Arrange, Act, Assert
+        with pytest.raises(TypeError):
+            CashFlowModule([Money(0.22, USD)])
+
+    def test_dividend_paid_to_long_position_at_ex_date(self):
+        # Arrange
+        self._open(OrderSide.BUY)
+        balance = self._balance()
+
+        # Act
+        self.exchange.process(self.ex_date - 1)
+        balance_before_ex_date = self._balance()
+        self.exchange.process(self.ex_date)
+        self.exchange.process(self.ex_date + 1)
+
+        # Assert
+        assert balance_before_ex_date == balance
+        assert self._balance() == Money(balance.as_double() + 22.00, USD)
+
+    def test_dividend_charged_to_short_position(self):
+        # Arrange
+        self._open(OrderSide.SELL)
+        balance = self._balance()
+
+        # Act
+        self.exchange.process(self.ex_date)
+
+        # Assert
+        assert self._balance() == Money(balance.as_double() - 22.00, USD)
+
+    def test_no_cash_flow_without_position(self):
+        # Arrange
+        balance = self._balance()
+
+        # Act
+        self.exchange.process(self.ex_date)
+
+        # Assert
+        assert self._balance() == balance
+
+
+class TestCashInterestModule(_ModuleFixture):
+    def setup(self):
+        # Fixture Setup
+        module = CashInterestModule(rates={USD: 0.036}, timezone="US/Eastern")
+        self._setup(AAPL_NASDAQ, module)
+
+    def test_invalid_day_count_raises_value_error(self):
+        # Arrange, Act, Assert
+        with pytest.raises(ValueError):
+            CashInterestModule(rates={USD: 0.036}, day_counts={USD: "30/360"})
+
+    def test_interest_accrues_daily_on_free_balance(self):
+        # Arrange
+        self.exchange.process(_ns("2021-11-01 12:00"))
+        balance = self._balance()
+
+        # Act
+        self.exchange.process(_ns("2021-11-01 23:59"))
+        balance_before_accrual = self._balance()
+        self.exchange.process(_ns("2021-11-02 00:00"))
+        self.exchange.process(_ns("2021-11-02 12:00"))
+
+        # Assert
+        assert balance_before_accrual == balance
+        assert self._balance() == Money(balance.as_double() + 100.00, USD)
+
+    def test_interest_accrues_for_actual_days_elapsed(self):
+        # Arrange
+        self.exchange.process(_ns("2021-11-01 12:00"))
+        balance = self._balance()
+
+        # Act
+        self.exchange.process(_ns("2021-11-04 12:00"))
+
+        # Assert
+        assert self._balance() == Money(balance.as_double() + 300.00, USD)
+
+    def test_interest_uses_currency_day_count(self):
+        # Arrange
+        module = CashInterestModule(
+            rates={USD: 0.0365},
+            day_counts={USD: "ACT/365"},
+            timezone="US/Eastern",
+        )
+        self._setup(AAPL_NASDAQ, module)
+        self.exchange.process(_ns("2021-11-01 12:00"))
+        balance = self._balance()
+
+        # Act
+        self.exchange.process(_ns("2021-11-02 12:00"))
+
+        # Assert
+        assert self._balance() == Money(balance.as_double() + 100.00, USD)
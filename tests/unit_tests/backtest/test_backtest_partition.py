@@ -0,0 +1,237 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from decimal import Decimal
+
+from nautilus_trader.backtest.engine import BacktestEngineConfig
+from nautilus_trader.backtest.partition import merge_results
+from nautilus_trader.backtest.partition import partition_run_config
+from nautilus_trader.backtest.partition import strategy_instrument_ids
+from nautilus_trader.backtest.results import BacktestResult
+from nautilus_trader.config import BacktestDataConfig
+from nautilus_trader.config import BacktestRunConfig
+from nautilus_trader.config import BacktestVenueConfig
+from nautilus_trader.config import ImportableActorConfig
+from nautilus_trader.config import ImportableStrategyConfig
+from nautilus_trader.model.data.tick import QuoteTick
+
+
+def _venue(name: str) -> BacktestVenueConfig:
+    return BacktestVenueConfig(
+        name=name,
+        oms_type="HEDGING",
+        account_type="MARGIN",
+        base_currency="USD",
+        starting_balances=["1000000 USD"],
+    )
+
+
+def _data(instrument_id: str) -> BacktestDataConfig:
+    return BacktestDataConfig(
+        catalog_path="/.nautilus/catalog",
+        catalog_fs_protocol="memory",
+        data_cls=QuoteTick,
+        instrument_id=instrument_id,
+    )
+
+
+def _strategy(**config) -> ImportableStrategyConfig:
+    return ImportableStrategyConfig(
+        strategy_path="nautilus_trader.examples.strategies.ema_cross:EMACross",
+        config_path="nautilus_trader.examples.strategies.ema_cross:EMACrossConfig",
+        config=dict(trade_size=Decimal(1_000_000), **config),
+    )
+
+
+def _result(**kwargs) -> BacktestResult:
+    values = dict(
+        trader_id="BACKTESTER-001",
+        machine_id="machine",
+        run_config_id=None,
+        instance_id="instance",
+        run_id="run",
+        run_started=0,
+        run_finished=0,
+        backtest_start=0,
+        backtest_end=0,
+        elapsed_time=0.0,
+        iterations=0,
+        total_events=0,
+        total_orders=0,
+        total_positions=0,
+        stats_pnls={},
+        stats_returns={},
+    )
+    values.update(kwargs)
+    return BacktestResult(**values)
+
+
+class TestBacktestPartition:
+    def setup(self):
+        # Fixture Setup
+        self.strategies = [
+            _strategy(instrument_id="USD/JPY.IDEALPRO", order_id_tag="003"),
+            _strategy(bar_type="AUD/USD.SIM-100-TICK-MID-INTERNAL", order_id_tag="001"),
+            _strategy(instrument_id="GBP/USD.SIM", order_id_tag="002"),
+        ]
+        self.config = BacktestRunConfig(
+            engine=BacktestEngineConfig(strategies=self.strategies),
+            venues=[_venue("SIM"), _venue("IDEALPRO")],
+            data=[_data("GBP/USD.SIM"), _data("AUD/USD.SIM"), _data("USD/JPY.IDEALPRO")],
+            partition_instruments=True,
+        )
+
+    def test_strategy_instrument_ids_from_instrument_ids_and_bar_types(self):
+        # Arrange
+        strategy = _strategy(
+            instrument_ids=["AUD/USD.SIM", "GBP/USD.SIM"],
+            bar_type="USD/JPY.SIM-1-MINUTE-BID-EXTERNAL",
+            order_id_tag="001",
+        )
+
+        # Act
+        instrument_ids = strategy_instrument_ids(strategy)
+
+        # Assert
+        assert instrument_ids == {"AUD/USD.SIM", "GBP/USD.SIM", "USD/JPY.SIM"}
+
+    def test_partition_when_not_enabled_returns_config(self):
+        # Arrange
+        config = self.config.replace(partition_instruments=False)
+
+        # Act
+        partitions = partition_run_config(config)
+
+        # Assert
+        assert partitions == [config]
+
+    def test_partition_splits_independent_instruments_in_order(self):
+        # Arrange, Act
+        partitions = partition_run_config(self.config)
+
+        # Assert
+        assert len(partitions) == 3
+        assert [[d.instrument_id for d in p.data] for p in partitions] == [
+            ["AUD/USD.SIM"],
+            ["GBP/USD.SIM"],
+            ["USD/JPY.IDEALPRO"],
+        ]
+        assert [[s.config["order_id_tag"] for s in p.engine.strategies] for p in partitions] == [
+            ["001"],
+            ["002"],
+            ["003"],
+        ]
+        assert [[v.name for v in p.venues] for p in partitions] == [
+            ["SIM"],
+            ["SIM"],
+            ["IDEALPRO"],
+        ]
+        assert not any(p.partition_instruments for p in partitions)
+
+    def test_partition_groups_instruments_of_common_strategy(self):
+        # Arrange
+        strategies = self.strategies + [
+            _strategy(instrument_ids=["AUD/USD.SIM", "GBP/USD.SIM"], order_id_tag="004"),
+        ]
+        config = self.config.replace(
+            engine=BacktestEngineConfig(strategies=strategies),
+        )
+
+        # Act
+        partitions = partition_run_config(config)
+
+        # Assert
+        assert len(partitions) == 2
+        assert [d.instrument_id for d in partitions[0].data] == ["GBP/USD.SIM", "AUD/USD.SIM"]
+        assert [s.config["order_id_tag"] for s in partitions[0].engine.strategies] == [
+            "001",
+            "002",
+            "004",
+        ]
+
+    def test_partition_with_actors_returns_config(self):
+        # Arrange
+        config = self.config.replace(
+            engine=BacktestEngineConfig(
+                strategies=self.strategies,
+                actors=[
+                    ImportableActorConfig(
+                        actor_path="tests.test_kit.mocks.actors:MockActor",
+                        config_path="tests.test_kit.mocks.actors:MockActorConfig",
+                        config={},
+                    ),
+                ],
+            ),
+        )
+
+        # Act
+        partitions = partition_run_config(config)
+
+        # Assert
+        assert partitions == [config]
+
+    def test_partition_with_data_for_no_instrument_returns_config(self):
+        # Arrange
+        config = self.config.replace(data=self.config.data + [_data(None)])
+
+        # Act
+        partitions = partition_run_config(config)
+
+        # Assert
+        assert partitions == [config]
+
+    def test_merge_results(self):
+        # Arrange
+        first = _result(
+            run_started=2,
+            run_finished=5,
+            backtest_start=10,
+            backtest_end=20,
+            elapsed_time=10.0,
+            iterations=100,
+            total_events=4,
+            total_orders=2,
+            total_positions=1,
+            stats_pnls={"USD": {"PnL": 10.0, "Win Rate": 1.0}},
+        )
+        second = _result(
+            run_started=1,
+            run_finished=6,
+            backtest_start=15,
+            backtest_end=30,
+            elapsed_time=15.0,
+            iterations=50,
+            total_events=2,
+            total_orders=1,
+            total_positions=1,
+            stats_pnls={"USD": {"PnL": -4.0}, "JPY": {"PnL": 100.0}},
+        )
+
+        # Act
+        result = merge_results("abc", [first, second])
+
+        # Assert
+        assert result.run_config_id == "abc"
+        assert result.run_started == 1
+        assert result.run_finished == 6
+        assert result.backtest_start == 10
+        assert result.backtest_end == 30
+        assert result.elapsed_time == 15.0
+        assert result.iterations == 150
+        assert result.total_events == 6
+        assert result.total_orders == 3
+        assert result.total_positions == 2
+        assert result.stats_pnls == {"USD": {"PnL": 6.0}, "JPY": {"PnL": 100.0}}
+        assert result.partitions == [first, second]
@@ -0,0 +1,134 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import pyarrow as pa
+import pytest
+
+from nautilus_trader.backtest.vectorized import BARS_SCHEMA
+from nautilus_trader.backtest.vectorized import IndicatorColumn
+from nautilus_trader.backtest.vectorized import bar_columns
+from nautilus_trader.backtest.vectorized import fill_inputs
+from nautilus_trader.backtest.vectorized import indicator_columns
+from nautilus_trader.indicators.average.sma import SimpleMovingAverage
+from nautilus_trader.indicators.donchian_channel import DonchianChannel
+from nautilus_trader.model.data.bar import Bar
+from nautilus_trader.model.objects import Price
+from nautilus_trader.model.objects import Quantity
+from nautilus_trader.persistence.catalog import DataCatalog
+from nautilus_trader.persistence.external.core import write_objects
+from tests.test_kit.mocks.data import data_catalog_setup
+from tests.test_kit.stubs.data import TestDataStubs
+
+
+def _bars(closes) -> pa.Table:
+    n = len(closes)
+    return pa.Table.from_arrays(
+        [
+            pa.array(range(n), type=pa.int64()),
+            pa.array(range(n), type=pa.int64()),
+            pa.array([c - 0.5 for c in closes], type=pa.float64()),
+            pa.array([c + 1.0 for c in closes], type=pa.float64()),
+            pa.array([c - 1.0 for c in closes], type=pa.float64()),
+            pa.array(closes, type=pa.float64()),
+            pa.array([100.0] * n, type=pa.float64()),
+        ],
+        schema=BARS_SCHEMA,
+    )
+
+
+class TestVectorizedBars:
+    def setup(self):
+        # Fixture Setup
+        data_catalog_setup()
+        self.catalog = DataCatalog.from_env()
+
+    def test_bar_columns_with_no_bars_returns_empty_table(self):
+        # Arrange, Act
+        bars = bar_columns(self.catalog, TestDataStubs.bartype_audusd_1min_bid())
+
+        # Assert
+        assert bars.num_rows == 0
+        assert bars.schema == BARS_SCHEMA
+
+    def test_bar_columns_returns_bars_of_bar_type_in_order(self):
+        # Arrange
+        bar_type = TestDataStubs.bartype_audusd_1min_bid()
+        other = TestDataStubs.bartype_audusd_1min_ask()
+        bars = [
+            Bar(
+                bar_type=t,
+                open=Price.from_str(f"1.0000{i}"),
+                high=Price.from_str("1.00010"),
+                low=Price.from_str("1.00000"),
+                close=Price.from_str(f"1.0000{i + 1}"),
+                volume=Quantity.from_int(1_000),
+                ts_event=ts,
+                ts_init=ts,
+            )
+            for i, (t, ts) in enumerate([(bar_type, 2), (other, 1), (bar_type, 1)])
+        ]
+        write_objects(self.catalog, bars)
+
+        # Act
+        table = bar_columns(self.catalog, bar_type)
+
+        # Assert
+        assert table.column("ts_init").to_pylist() == [1, 2]
+        assert table.column("close").to_pylist() == [1.00003, 1.00001]
+        assert table.column("volume").to_pylist() == [1000.0, 1000.0]
+
+
+class TestVectorizedColumns:
+    def test_indicator_columns(self):
+        # Arrange
+        bars = _bars([1.0, 2.0, 3.0, 4.0])
+
+        # Act
+        table = indicator_columns(
+            bars,
+            {
+                "sma": IndicatorColumn(SimpleMovingAverage(2)),
+                "upper": IndicatorColumn(
+                    DonchianChannel(2),
+                    inputs=("high", "low"),
+                    output="upper",
+                ),
+            },
+        )
+
+        # Assert
+        assert table.column("sma").to_pylist() == [None, 1.5, 2.5, 3.5]
+        assert table.column("upper").to_pylist() == [None, 3.0, 4.0, 5.0]
+
+    def test_fill_inputs_lags_fill_prices(self):
+        # Arrange
+        bars = _bars([1.0, 2.0, 3.0])
+
+        # Act
+        batch = fill_inputs(bars, [1.0, None, 0.0])
+
+        # Assert
+        assert batch.schema.names == ["ts_event", "price", "mark", "target"]
+        assert batch.column(1).to_pylist() == [1.5, 2.5, None]
+        assert batch.column(2).to_pylist() == [1.0, 2.0, 3.0]
+        assert batch.column(3).to_pylist() == [1.0, None, 0.0]
+
+    def test_fill_inputs_with_wrong_length_targets_raises(self):
+        # Arrange
+        bars = _bars([1.0, 2.0])
+
+        # Act, Assert
+        with pytest.raises(ValueError):
+            fill_inputs(bars, [1.0])
@@ -179,6 +179,8 @@ class TestDataEngine:
         assert self.data_engine.data_count == 0
         assert self.data_engine.request_count == 0
         assert self.data_engine.response_count == 0
+        assert self.data_engine.duplicate_quote_count == 0
+        assert self.data_engine.duplicate_trade_count == 0
 
     def test_stop_and_resume(self):
         # Arrange
@@ -1451,6 +1453,131 @@ class TestDataEngine:
         assert handler1 == [tick]
         assert handler2 == [tick]
 
+    def test_process_duplicate_quote_ticks_when_filtering_then_drops_duplicates(self):
+        # Arrange
+        msgbus = MessageBus(
+            trader_id=self.trader_id,
+            clock=self.clock,
+            logger=self.logger,
+        )
+        data_engine = DataEngine(
+            msgbus=msgbus,
+            cache=TestComponentStubs.cache(),
+            clock=self.clock,
+            logger=self.logger,
+            config=DataEngineConfig(filter_duplicate_ticks=True),
+        )
+
+        handler = []
+        msgbus.subscribe(topic="data.quotes.BINANCE.ETHUSDT", handler=handler.append)
+
+        tick1 = QuoteTick(
+            instrument_id=ETHUSDT_BINANCE.id,
+            bid=Price.from_str("100.003"),
+            ask=Price.from_str("100.004"),
+            bid_size=Quantity.from_int(1),
+            ask_size=Quantity.from_int(1),
+            ts_event=0,
+            ts_init=0,
+        )
+        tick2 = QuoteTick(  # <-- redelivered with a later ts_init
+            instrument_id=ETHUSDT_BINANCE.id,
+            bid=Price.from_str("100.003"),
+            ask=Price.from_str("100.004"),
+            bid_size=Quantity.from_int(1),
+            ask_size=Quantity.from_int(1),
+            ts_event=0,
+            ts_init=1,
+        )
+        tick3 = QuoteTick(
+            instrument_id=ETHUSDT_BINANCE.id,
+            bid=Price.from_str("100.003"),
+            ask=Price.from_str("100.004"),
+            bid_size=Quantity.from_int(2),
+            ask_size=Quantity.from_int(1),
+            ts_event=0,
+            ts_init=2,
+        )
+
+        # Act
+        data_engine.process(tick1)
+        data_engine.process(tick2)
+        data_engine.process(tick3)
+
+        # Assert
+        assert handler == [tick1, tick3]
+        assert data_engine.data_count == 3
+        assert data_engine.duplicate_quote_count == 1
+
+    def test_process_duplicate_trade_ticks_when_not_filtering_then_sends_all(self):
+        # Arrange
+        handler = []
+        self.msgbus.subscribe(topic="data.trades.BINANCE.ETHUSDT", handler=handler.append)
+
+        tick = TradeTick(
+            instrument_id=ETHUSDT_BINANCE.id,
+            price=Price.from_str("1050.00000"),
+            size=Quantity.from_int(100),
+            aggressor_side=AggressorSide.BUY,
+            trade_id=TradeId("123456789"),
+            ts_event=0,
+            ts_init=0,
+        )
+
+        # Act
+        self.data_engine.process(tick)
+        self.data_engine.process(tick)
+
+        # Assert
+        assert handler == [tick, tick]
+        assert self.data_engine.duplicate_trade_count == 0
+
+    def test_process_duplicate_trade_ticks_when_filtering_then_drops_duplicates(self):
+        # Arrange
+        msgbus = MessageBus(
+            trader_id=self.trader_id,
+            clock=self.clock,
+            logger=self.logger,
+        )
+        data_engine = DataEngine(
+            msgbus=msgbus,
+            cache=TestComponentStubs.cache(),
+            clock=self.clock,
+            logger=self.logger,
+            config=DataEngineConfig(filter_duplicate_ticks=True),
+        )
+
+        handler = []
+        msgbus.subscribe(topic="data.trades.BINANCE.ETHUSDT", handler=handler.append)
+
+        tick1 = TradeTick(
+            instrument_id=ETHUSDT_BINANCE.id,
+            price=Price.from_str("1050.00000"),
+            size=Quantity.from_int(100),
+            aggressor_side=AggressorSide.BUY,
+            trade_id=TradeId("123456789"),
+            ts_event=0,
+            ts_init=0,
+        )
+        tick2 = TradeTick(
+            instrument_id=ETHUSDT_BINANCE.id,
+            price=Price.from_str("1050.00000"),
+            size=Quantity.from_int(100),
+            aggressor_side=AggressorSide.BUY,
+            trade_id=TradeId("123456790"),  # <-- distinct trade
+            ts_event=0,
+            ts_init=0,
+        )
+
+        # Act
+        data_engine.process(tick1)
+        data_engine.process(tick1)
+        data_engine.process(tick2)
+
+        # Assert
+        assert handler == [tick1, tick2]
+        assert data_engine.duplicate_trade_count == 1
+
     def test_subscribe_bar_type_then_subscribes(self):
         # Arrange
         self.data_engine.register_client(self.binance_client)
@@ -0,0 +1,92 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import pytest
+
+from nautilus_trader.data.patterns import CandlePattern
+from nautilus_trader.data.patterns import PatternDetection
+from nautilus_trader.data.patterns import detect_cached_patterns
+from nautilus_trader.data.patterns import detect_patterns
+from nautilus_trader.model.data.bar import Bar
+from nautilus_trader.model.objects import Price
+from nautilus_trader.model.objects import Quantity
+from tests.test_kit.stubs.component import TestComponentStubs
+from tests.test_kit.stubs.data import TestDataStubs
+
+
+BAR_TYPE = TestDataStubs.bartype_audusd_1min_bid()
+
+
+def _bar(open: str, high: str, low: str, close: str, ts: int) -> Bar:
+    return Bar(
+        bar_type=BAR_TYPE,
+        open=Price.from_str(open),
+        high=Price.from_str(high),
+        low=Price.from_str(low),
+        close=Price.from_str(close),
+        volume=Quantity.from_int(100_000),
+        ts_event=ts,
+        ts_init=ts,
+    )
+
+
+class TestCandlePatterns:
+    def test_detect_patterns_with_no_bars_returns_empty_list(self):
+        # Arrange, Act, Assert
+        assert detect_patterns([]) == []
+
+    def test_detect_patterns_with_invalid_doji_body_ratio_raises_value_error(self):
+        # Arrange, Act, Assert
+        with pytest.raises(ValueError):
+            detect_patterns([], doji_body_ratio=1.5)
+
+    def test_detect_engulfing_and_doji(self):
+        # Arrange
+        bars = [
+            _bar("1.00000", "1.00200", "0.99400", "0.99500", 1),
+            _bar("0.99400", "1.00400", "0.99300", "1.00300", 2),
+            _bar("1.00300", "1.00800", "0.99800", "1.00310", 3),
+        ]
+
+        # Act
+        result = detect_patterns(bars, breakout_period=0)
+
+        # Assert
+        assert result == [
+            PatternDetection(CandlePattern.BULLISH_ENGULFING, bars[1]),
+            PatternDetection(CandlePattern.DOJI, bars[2]),
+        ]
+        assert result[1].ts_event == 3
+
+    def test_detect_cached_patterns_returns_breakout_and_inside_bar(self):
+        # Arrange
+        cache = TestComponentStubs.cache()
+        bars = [
+            _bar("1.00000", "1.00500", "0.99500", "1.00200", 1),
+            _bar("1.00200", "1.00600", "0.99800", "1.00400", 2),
+            _bar("1.00400", "1.01000", "1.00300", "1.00900", 3),
+            _bar("1.00900", "1.00950", "1.00350", "1.00500", 4),
+        ]
+        for bar in bars:
+            cache.add_bar(bar)
+
+        # Act
+        result = detect_cached_patterns(cache, BAR_TYPE, doji_body_ratio=0.0, breakout_period=2)
+
+        # Assert
+        assert result == [
+            PatternDetection(CandlePattern.BREAKOUT_HIGH, bars[2]),
+            PatternDetection(CandlePattern.INSIDE_BAR, bars[3]),
+        ]
@@ -0,0 +1,111 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from nautilus_trader.backtest.data.providers import TestInstrumentProvider
+from nautilus_trader.common.clock import TestClock
+from nautilus_trader.common.logging import Logger
+from nautilus_trader.data.engine import DataEngine
+from nautilus_trader.data.session import reset_session_stats
+from nautilus_trader.data.session import session_stats
+from nautilus_trader.data.session import start_session
+from nautilus_trader.model.data.tick import TradeTick
+from nautilus_trader.model.enums import AggressorSide
+from nautilus_trader.model.identifiers import TradeId
+from nautilus_trader.model.objects import Price
+from nautilus_trader.model.objects import Quantity
+from nautilus_trader.msgbus.bus import MessageBus
+from tests.test_kit.stubs.component import TestComponentStubs
+from tests.test_kit.stubs.identifiers import TestIdStubs
+
+
+ETHUSDT_BINANCE = TestInstrumentProvider.ethusdt_binance()
+
+
+class TestSessionStats:
+    def setup(self):
+        # Fixture Setup
+        self.clock = TestClock()
+        self.logger = Logger(clock=self.clock)
+
+        self.msgbus = MessageBus(
+            trader_id=TestIdStubs.trader_id(),
+            clock=self.clock,
+            logger=self.logger,
+        )
+
+        self.data_engine = DataEngine(
+            msgbus=self.msgbus,
+            cache=TestComponentStubs.cache(),
+            clock=self.clock,
+            logger=self.logger,
+        )
+
+        reset_session_stats()
+
+    def _trade_tick(self, price: str, size: int, ts_event: int) -> TradeTick:
+        return TradeTick(
+            instrument_id=ETHUSDT_BINANCE.id,
+            price=Price.from_str(price),
+            size=Quantity.from_int(size),
+            aggressor_side=AggressorSide.BUY,
+            trade_id=TradeId(str(ts_event)),
+            ts_event=ts_event,
+            ts_init=ts_event,
+        )
+
+    def test_session_stats_when_no_trades_returns_empty_stats(self):
+        # Arrange, Act
+        stats = session_stats(ETHUSDT_BINANCE.id)
+
+        # Assert
+        assert stats["open"] is None
+        assert stats["last"] is None
+        assert stats["vwap"] is None
+        assert stats["volume"] == Quantity.from_int(0)
+        assert stats["trade_count"] == 0
+
+    def test_session_stats_after_trades_processed(self):
+        # Arrange
+        self.data_engine.process(self._trade_tick("100.00", 1, 1))
+        self.data_engine.process(self._trade_tick("102.00", 3, 2))
+        self.data_engine.process(self._trade_tick("99.50", 1, 3))
+
+        # Act
+        stats = session_stats(ETHUSDT_BINANCE.id)
+
+        # Assert
+        assert stats["open"] == Price.from_str("100.00")
+        assert stats["high"] == Price.from_str("102.00")
+        assert stats["low"] == Price.from_str("99.50")
+        assert stats["last"] == Price.from_str("99.50")
+        assert stats["vwap"] == Price.from_str("101.10")
+        assert stats["volume"] == Quantity.from_int(5)
+        assert stats["trade_count"] == 3
+        assert stats["ts_start"] == 1
+        assert stats["ts_last"] == 3
+
+    def test_start_session_discards_previous_session(self):
+        # Arrange
+        self.data_engine.process(self._trade_tick("100.00", 1, 1))
+
+        # Act
+        start_session(ETHUSDT_BINANCE.id, 10)
+        self.data_engine.process(self._trade_tick("101.00", 2, 11))
+
+        # Assert
+        stats = session_stats(ETHUSDT_BINANCE.id)
+        assert stats["open"] == Price.from_str("101.00")
+        assert stats["volume"] == Quantity.from_int(2)
+        assert stats["ts_start"] == 10
@@ -0,0 +1,129 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import pytest
+
+from nautilus_trader.common.clock import TestClock
+from nautilus_trader.common.logging import Logger
+from nautilus_trader.data.synchronizer import BarSynchronizer
+from nautilus_trader.model.data.bar import Bar
+from nautilus_trader.model.data.bar import BarType
+from nautilus_trader.model.objects import Price
+from nautilus_trader.model.objects import Quantity
+from tests.test_kit.stubs.data import TestDataStubs
+
+
+ONE_MIN_NS = 60_000_000_000
+
+AUDUSD_1MIN_BID = TestDataStubs.bartype_audusd_1min_bid()
+AUDUSD_5MIN_BID = BarType.from_str("AUD/USD.SIM-5-MINUTE-BID-EXTERNAL")
+
+
+def _bar(bar_type: BarType, minutes: int) -> Bar:
+    return Bar(
+        bar_type=bar_type,
+        open=Price.from_str("1.00001"),
+        high=Price.from_str("1.00004"),
+        low=Price.from_str("1.00000"),
+        close=Price.from_str("1.00003"),
+        volume=Quantity.from_int(100_000),
+        ts_event=minutes * ONE_MIN_NS,
+        ts_init=minutes * ONE_MIN_NS,
+    )
+
+
+class TestBarSynchronizer:
+    def setup(self):
+        # Fixture Setup
+        self.handler = []
+        self.synchronizer = BarSynchronizer(
+            bar_types=[AUDUSD_1MIN_BID, AUDUSD_5MIN_BID],
+            handler=self.handler.append,
+            logger=Logger(TestClock()),
+        )
+
+    def test_instantiate_with_different_instruments_raises_value_error(self):
+        # Arrange, Act, Assert
+        with pytest.raises(ValueError):
+            BarSynchronizer(
+                bar_types=[AUDUSD_1MIN_BID, TestDataStubs.bartype_gbpusd_1min_bid()],
+                handler=self.handler.append,
+                logger=Logger(TestClock()),
+            )
+
+    def test_handle_bar_with_unknown_bar_type_raises_value_error(self):
+        # Arrange, Act, Assert
+        with pytest.raises(ValueError):
+            self.synchronizer.handle_bar(_bar(TestDataStubs.bartype_audusd_1min_ask(), 1))
+
+    def test_handle_bars_sends_group_when_all_bar_types_closed(self):
+        # Arrange
+        for minutes in range(1, 6):
+            self.synchronizer.handle_bar(_bar(AUDUSD_1MIN_BID, minutes))
+
+        # Act
+        self.synchronizer.handle_bar(_bar(AUDUSD_5MIN_BID, 5))
+
+        # Assert
+        assert self.handler == [[_bar(AUDUSD_1MIN_BID, 5), _bar(AUDUSD_5MIN_BID, 5)]]
+        assert self.synchronizer.ts_last_synced == 5 * ONE_MIN_NS
+        assert self.synchronizer.synced_count == 1
+        assert self.synchronizer.dropped_count == 4
+
+    def test_handle_bars_when_higher_timeframe_bar_arrives_first(self):
+        # Arrange
+        self.synchronizer.handle_bar(_bar(AUDUSD_5MIN_BID, 5))
+
+        # Act
+        self.synchronizer.handle_bar(_bar(AUDUSD_1MIN_BID, 5))
+
+        # Assert
+        assert self.handler == [[_bar(AUDUSD_1MIN_BID, 5), _bar(AUDUSD_5MIN_BID, 5)]]
+
+    def test_handle_bars_drops_group_when_bar_missing(self):
+        # Arrange
+        self.synchronizer.handle_bar(_bar(AUDUSD_5MIN_BID, 5))
+        self.synchronizer.handle_bar(_bar(AUDUSD_1MIN_BID, 4))
+
+        # Act: no 1-MINUTE bar closed at minute 5
+        self.synchronizer.handle_bar(_bar(AUDUSD_1MIN_BID, 6))
+
+        # Assert
+        assert self.handler == []
+        assert self.synchronizer.dropped_count == 2
+
+    def test_handle_bar_prior_to_last_bar_is_ignored(self):
+        # Arrange
+        self.synchronizer.handle_bar(_bar(AUDUSD_1MIN_BID, 5))
+
+        # Act
+        self.synchronizer.handle_bar(_bar(AUDUSD_1MIN_BID, 5))
+        self.synchronizer.handle_bar(_bar(AUDUSD_5MIN_BID, 5))
+
+        # Assert
+        assert len(self.handler) == 1
+
+    def test_reset(self):
+        # Arrange
+        self.synchronizer.handle_bar(_bar(AUDUSD_1MIN_BID, 5))
+        self.synchronizer.handle_bar(_bar(AUDUSD_5MIN_BID, 5))
+
+        # Act
+        self.synchronizer.reset()
+
+        # Assert
+        assert self.synchronizer.ts_last_synced == 0
+        assert self.synchronizer.synced_count == 0
+        assert self.synchronizer.dropped_count == 0
@@ -0,0 +1,86 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import pytest
+
+from nautilus_trader.backtest.data.providers import TestInstrumentProvider
+from nautilus_trader.data.volatility import option_expiry_ns
+from nautilus_trader.data.volatility import reset_volatility_surfaces
+from nautilus_trader.data.volatility import theoretical_price
+from nautilus_trader.data.volatility import update_volatility
+from nautilus_trader.data.volatility import volatility
+from nautilus_trader.data.volatility import volatility_at_delta
+from nautilus_trader.model.enums import OptionKind
+
+
+AAPL_OPTION = TestInstrumentProvider.aapl_option()
+ONE_DAY_NS = 86_400_000_000_000
+
+
+class TestVolatilitySurface:
+    def setup(self):
+        # Fixture Setup
+        reset_volatility_surfaces()
+        self.expiry_ns = option_expiry_ns(AAPL_OPTION)
+        self.ts_now = self.expiry_ns - 30 * ONE_DAY_NS
+
+    def test_option_expiry_ns(self):
+        # Arrange, Act, Assert
+        assert self.expiry_ns == 1639699200000000000  # 2021-12-17 00:00 UTC
+
+    def test_volatility_when_no_quotes_returns_none(self):
+        # Arrange, Act, Assert
+        assert volatility("AAPL", self.expiry_ns, 149.0, self.ts_now) is None
+        assert theoretical_price(AAPL_OPTION, 150.0, self.ts_now) is None
+
+    def test_update_volatility_with_price_below_intrinsic_returns_none(self):
+        # Arrange, Act
+        result = update_volatility(AAPL_OPTION, 0.5, 150.0, self.ts_now)
+
+        # Assert
+        assert result is None
+        assert volatility("AAPL", self.expiry_ns, 149.0, self.ts_now) is None
+
+    def test_update_volatility_then_query_surface(self):
+        # Arrange, Act
+        vol = update_volatility(AAPL_OPTION, 5.0, 150.0, self.ts_now)
+
+        # Assert
+        assert 0.0 < vol < 1.0
+        assert volatility("AAPL", self.expiry_ns, 149.0, self.ts_now) == vol
+        assert volatility("AAPL", self.expiry_ns, 149.0, self.expiry_ns) is None
+        assert theoretical_price(AAPL_OPTION, 150.0, self.ts_now) == pytest.approx(5.0)
+
+    def test_volatility_at_delta(self):
+        # Arrange
+        vol = update_volatility(AAPL_OPTION, 5.0, 150.0, self.ts_now)
+
+        # Act
+        strike, result = volatility_at_delta(
+            "AAPL",
+            OptionKind.CALL,
+            self.expiry_ns,
+            0.25,
+            150.0,
+            self.ts_now,
+        )
+
+        # Assert
+        assert strike > 150.0
+        assert result == vol  # Flat extrapolation from the single strike
+        assert (
+            volatility_at_delta("AAPL", OptionKind.CALL, self.expiry_ns, 1.5, 150.0, self.ts_now)
+            is None
+        )
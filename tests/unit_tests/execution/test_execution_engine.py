@@ -1511,6 +1511,61 @@ class TestExecutionEngine:
         assert self.cache.positions_open_count() == 0
         assert self.cache.positions_closed_count() == 1
 
+    def test_trading_stats_record_fills_and_round_trips(self):
+        # Arrange
+        self.exec_engine.start()
+
+        strategy = Strategy()
+        strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+            logger=self.logger,
+        )
+
+        order1 = strategy.order_factory.market(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100000),
+        )
+
+        order2 = strategy.order_factory.market(
+            AUDUSD_SIM.id,
+            OrderSide.SELL,
+            Quantity.from_int(100000),
+        )
+
+        position_id = PositionId("P-1")
+        stats_before = self.exec_engine.trading_stats(AUDUSD_SIM.id)
+
+        # Act
+        for order in (order1, order2):
+            self.cache.add_order(order, position_id)
+            self.exec_engine.process(TestEventStubs.order_submitted(order))
+            self.exec_engine.process(TestEventStubs.order_accepted(order))
+            self.exec_engine.process(
+                TestEventStubs.order_filled(order, AUDUSD_SIM, position_id=position_id)
+            )
+
+        # Assert
+        stats = self.exec_engine.trading_stats(AUDUSD_SIM.id)
+        assert stats["trade_count"] == stats_before["trade_count"] + 2
+        assert stats["volume"] == stats_before["volume"] + 200000
+        assert stats["win_count"] + stats["loss_count"] == (
+            stats_before["win_count"] + stats_before["loss_count"] + 1
+        )
+        assert self.exec_engine.trading_stats(TestIdStubs.betting_instrument_id()) == {
+            "trade_count": 0,
+            "volume": 0.0,
+            "fees": 0.0,
+            "win_count": 0,
+            "loss_count": 0,
+            "win_rate": 0.0,
+            "avg_slippage": 0.0,
+        }
+
     def test_multiple_strategy_positions_opened(self):
         # Arrange
         self.exec_engine.start()
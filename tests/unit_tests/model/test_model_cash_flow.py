@@ -0,0 +1,41 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from nautilus_trader.backtest.data.providers import TestInstrumentProvider
+from nautilus_trader.model.data.cash_flow import CashFlow
+from nautilus_trader.model.enums import CashFlowType
+from nautilus_trader.model.objects import Money
+
+
+AAPL_NASDAQ = TestInstrumentProvider.aapl_equity()
+
+
+class TestCashFlow:
+    def test_cash_flow_dict_round_trip_and_repr(self):
+        # Arrange
+        cash_flow = CashFlow(
+            instrument_id=AAPL_NASDAQ.id,
+            cash_flow_type=CashFlowType.DIVIDEND,
+            amount=Money.from_str("0.22 USD"),
+            ts_event=1,
+            ts_init=2,
+        )
+
+        # Act, Assert
+        assert CashFlow.from_dict(CashFlow.to_dict(cash_flow)) == cash_flow
+        assert (
+            "CashFlow(instrument_id=AAPL.NASDAQ, cash_flow_type=DIVIDEND, amount=0.22 USD, ts_event=1)"
+            == repr(cash_flow)
+        )
@@ -31,6 +31,8 @@ from nautilus_trader.model.enums import BookAction
 from nautilus_trader.model.enums import BookActionParser
 from nautilus_trader.model.enums import BookType
 from nautilus_trader.model.enums import BookTypeParser
+from nautilus_trader.model.enums import CashFlowType
+from nautilus_trader.model.enums import CashFlowTypeParser
 from nautilus_trader.model.enums import ContingencyType
 from nautilus_trader.model.enums import ContingencyTypeParser
 from nautilus_trader.model.enums import CurrencyType
@@ -354,6 +356,44 @@ class TestBarAggregation:
         assert expected == result
 
 
+class TestCashFlowType:
+    def test_cash_flow_type_parser_given_invalid_value_raises_value_error(self):
+        # Arrange, Act, Assert
+        with pytest.raises(ValueError):
+            CashFlowTypeParser.to_str_py(0)
+
+        with pytest.raises(ValueError):
+            CashFlowTypeParser.from_str_py("")
+
+    @pytest.mark.parametrize(
+        "enum, expected",
+        [
+            [CashFlowType.DIVIDEND, "DIVIDEND"],
+            [CashFlowType.COUPON, "COUPON"],
+        ],
+    )
+    def test_cash_flow_type_to_str(self, enum, expected):
+        # Arrange, Act
+        result = CashFlowTypeParser.to_str_py(enum)
+
+        # Assert
+        assert expected == result
+
+    @pytest.mark.parametrize(
+        "string, expected",
+        [
+            ["DIVIDEND", CashFlowType.DIVIDEND],
+            ["COUPON", CashFlowType.COUPON],
+        ],
+    )
+    def test_cash_flow_type_from_str(self, string, expected):
+        # Arrange, Act
+        result = CashFlowTypeParser.from_str_py(string)
+
+        # Assert
+        assert expected == result
+
+
 class TestContingencyType:
     def test_contingency_type_parser_given_invalid_value_raises_value_error(self):
         # Arrange, Act, Assert
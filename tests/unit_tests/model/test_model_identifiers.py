@@ -21,8 +21,12 @@ from nautilus_trader.model.identifiers import AccountId
 from nautilus_trader.model.identifiers import InstrumentId
 from nautilus_trader.model.identifiers import StrategyId
 from nautilus_trader.model.identifiers import Symbol
+from nautilus_trader.model.identifiers import TradeId
 from nautilus_trader.model.identifiers import TraderId
 from nautilus_trader.model.identifiers import Venue
+from nautilus_trader.model.identifiers import instrument_ids_from_list
+from nautilus_trader.model.identifiers import symbols_from_list
+from nautilus_trader.model.identifiers import venues_from_list
 
 
 class TestIdentifiers:
@@ -171,6 +175,32 @@ class TestVenue:
         # Act, Assert
         assert repr(venue) == "Venue('NYMEX')"
 
+    def test_venue_canonical(self):
+        # Arrange
+        venue = Venue("Binance", canonical=True)
+
+        # Act, Assert
+        assert venue == Venue("BINANCE")
+        assert venue.value == "BINANCE"
+        assert hash(venue) == hash(Venue("BINANCE"))
+
+    def test_venue_canonical_well_known_venue(self):
+        # Arrange
+        venue = Venue("interactivebrokers", canonical=True)
+
+        # Act, Assert
+        assert venue == Venue("InteractiveBrokers")
+        assert venue.value == "InteractiveBrokers"
+
+    def test_venue_eq_ignore_case(self):
+        # Arrange
+        venue = Venue("Binance")
+
+        # Act, Assert
+        assert venue != Venue("BINANCE")
+        assert venue.eq_ignore_case(Venue("BINANCE"))
+        assert not venue.eq_ignore_case(Venue("BINANCEUS"))
+
 
 class TestInstrumentId:
     def test_instrument_id_equality(self):
@@ -209,6 +239,18 @@ class TestInstrumentId:
         # Act, Assert
         assert "InstrumentId('AUD/USD.SIM')" == repr(instrument_id)
 
+    def test_instrument_id_eq_ignore_case(self):
+        # Arrange
+        instrument_id = InstrumentId(Symbol("ETH/USDT"), Venue("BINANCE"))
+
+        # Act, Assert
+        assert instrument_id.eq_ignore_case(
+            InstrumentId(Symbol("eth/usdt", canonical=True), Venue("Binance")),
+        )
+        assert not instrument_id.eq_ignore_case(
+            InstrumentId(Symbol("ETH/USDC"), Venue("Binance")),
+        )
+
     def test_parse_instrument_id_from_str(self):
         # Arrange
         instrument_id = InstrumentId(Symbol("AUD/USD"), Venue("SIM"))
@@ -220,6 +262,67 @@ class TestInstrumentId:
         assert instrument_id == result
 
 
+class TestBatchConversion:
+    def test_symbols_from_list(self):
+        # Arrange, Act
+        result = symbols_from_list(["AUD/USD", "ETH-PERP"])
+
+        # Assert
+        assert result == [Symbol("AUD/USD"), Symbol("ETH-PERP")]
+        assert hash(result[1]) == hash(Symbol("ETH-PERP"))
+
+    def test_venues_from_list(self):
+        # Arrange, Act
+        result = venues_from_list(["SIM", "BINANCE"])
+
+        # Assert
+        assert result == [Venue("SIM"), Venue("BINANCE")]
+        assert hash(result[0]) == hash(Venue("SIM"))
+
+    def test_instrument_ids_from_list(self):
+        # Arrange, Act
+        result = instrument_ids_from_list(["AUD/USD.SIM", "ETH-PERP.FTX"])
+
+        # Assert
+        assert result == [
+            InstrumentId.from_str("AUD/USD.SIM"),
+            InstrumentId.from_str("ETH-PERP.FTX"),
+        ]
+        assert result[1].symbol == Symbol("ETH-PERP")
+        assert result[1].venue == Venue("FTX")
+        assert hash(result[0]) == hash(InstrumentId.from_str("AUD/USD.SIM"))
+
+    def test_from_empty_list(self):
+        # Arrange, Act, Assert
+        assert symbols_from_list([]) == []
+        assert venues_from_list([]) == []
+        assert instrument_ids_from_list([]) == []
+
+    def test_invalid_values_raise_value_error(self):
+        # Arrange, Act, Assert
+        with pytest.raises(ValueError):
+            symbols_from_list(["AUD/USD", ""])
+        with pytest.raises(ValueError):
+            instrument_ids_from_list(["AUDUSD"])
+
+
+class TestTradeId:
+    def test_trade_id_at_max_length(self):
+        # Arrange
+        value = "1" * 64
+
+        # Act
+        trade_id = TradeId(value)
+
+        # Assert
+        assert trade_id.value == value
+
+    def test_trade_id_exceeding_max_length_raises_value_error(self):
+        # Arrange, Act, Assert
+        with pytest.raises(ValueError):
+            TradeId("1" * 65)
+
+
 class TestStrategyId:
     def test_is_external(self):
         # Arrange
@@ -19,6 +19,7 @@ from decimal import Decimal
 import pytest
 
 from nautilus_trader.model.currencies import AUD
+from nautilus_trader.model.currencies import JPY
 from nautilus_trader.model.currencies import USD
 from nautilus_trader.model.identifiers import InstrumentId
 from nautilus_trader.model.identifiers import Symbol
@@ -28,6 +29,10 @@ from nautilus_trader.model.objects import MarginBalance
 from nautilus_trader.model.objects import Money
 from nautilus_trader.model.objects import Price
 from nautilus_trader.model.objects import Quantity
+from nautilus_trader.model.objects import is_conversion_audit_enabled
+from nautilus_trader.model.objects import lossy_conversion_counts
+from nautilus_trader.model.objects import reset_conversion_audit
+from nautilus_trader.model.objects import set_conversion_audit
 
 
 class TestQuantity:
@@ -591,6 +596,33 @@ class TestQuantity:
         assert str(qty) == "0.511"
         assert qty.precision == 3
 
+    @pytest.mark.parametrize(
+        "value, string, precision",
+        [
+            ["1e-8", "0.00000001", 8],
+            ["2.5E3", "2_500", 0],
+            ["3.125E-1", "0.3125", 4],
+        ],
+    )
+    def test_from_str_with_exponent_returns_expected_value(self, value, string, precision):
+        # Arrange, Act
+        qty = Quantity.from_str(value)
+
+        # Assert
+        assert qty.to_str() == string
+        assert qty.precision == precision
+
+    def test_from_str_with_exponent_precision_greater_than_nine_raises_value_error(self):
+        # Arrange, Act, Assert
+        with pytest.raises(ValueError):
+            Quantity.from_str("1e-10")
+
+    @pytest.mark.parametrize("value", ["1e", "1e+", "1e1_0", "1e 5"])
+    def test_from_str_with_malformed_exponent_raises_value_error(self, value):
+        # Arrange, Act, Assert
+        with pytest.raises(ValueError):
+            Quantity.from_str(value)
+
     @pytest.mark.parametrize(
         "value, expected",
         [
@@ -1227,6 +1259,10 @@ class TestPrice:
             ["1E7", "10000000", 0],
             ["1E-7", "0.0000001", 7],
             ["1e-2", "0.01", 2],
+            ["1.5e-8", "0.000000015", 9],
+            ["2.5E3", "2500", 0],
+            ["1.25e+1", "12.5", 1],
+            ["-1.25e-1", "-0.125", 3],
         ],
     )
     def test_from_str_returns_expected_value(self, value, string, precision):
@@ -1245,6 +1281,30 @@ class TestPrice:
         assert "1.00000" == str(price)
         assert "Price('1.00000')" == repr(price)
 
+    @pytest.mark.parametrize(
+        "locale, decimals, expected",
+        [
+            ["en_US", None, "12,345.675"],
+            ["de_DE", None, "12.345,675"],
+            ["en_US", 2, "12,345.68"],
+            ["C", 0, "12346"],
+        ],
+    )
+    def test_to_formatted_str(self, locale, decimals, expected):
+        # Arrange
+        price = Price.from_str("12345.675")
+
+        # Act, Assert
+        assert price.to_formatted_str(locale, decimals) == expected
+
+    def test_to_formatted_str_with_unsupported_locale_raises_value_error(self):
+        # Arrange
+        price = Price.from_str("1.0")
+
+        # Act, Assert
+        with pytest.raises(ValueError):
+            price.to_formatted_str("xx_XX")
+
     def test_pickle_dumps_and_loads(self):
         # Arrange
         price = Price(1.2000, 2)
@@ -1340,6 +1400,24 @@ class TestMoney:
         assert "1000000.00" == str(money2)
         assert "1_000_000.00 USD" == money2.to_str()
 
+    def test_to_str_when_negative_and_zero_precision(self):
+        # Arrange
+        money1 = Money(-1_234_567.5, USD)
+        money2 = Money(100, JPY)
+
+        # Act, Assert
+        assert money1.to_str() == "-1_234_567.50 USD"
+        assert money2.to_str() == "100 JPY"
+
+    def test_to_formatted_str(self):
+        # Arrange
+        money = Money(-1_234_567.5, USD)
+
+        # Act, Assert
+        assert money.to_formatted_str() == "-1,234,567.50 USD"
+        assert money.to_formatted_str("de_DE", currency_prefix=True) == "USD -1.234.567,50"
+        assert money.to_formatted_str(decimals=0) == "-1,234,568 USD"
+
     def test_repr(self):
         # Arrange
         money = Money(1.00, USD)
@@ -1432,3 +1510,35 @@ class TestMarginBalance:
             repr(margin)
             == "MarginBalance(initial=5_000.00 USD, maintenance=25_000.00 USD, instrument_id=None)"
         )
+
+
+class TestConversionAudit:
+    def setup(self):
+        # Fixture Setup
+        reset_conversion_audit()
+
+    def teardown(self):
+        set_conversion_audit(False)
+        reset_conversion_audit()
+
+    def test_audit_disabled_by_default_records_nothing(self):
+        # Arrange, Act
+        Price(1.155, precision=2, component="MyStrategy")
+
+        # Assert
+        assert not is_conversion_audit_enabled()
+        assert lossy_conversion_counts() == {}
+
+    def test_audit_counts_lossy_conversions_per_component(self):
+        # Arrange
+        set_conversion_audit(True)
+
+        # Act
+        Price(1.155, precision=2, component="MyStrategy")
+        Price(1.15, precision=2, component="MyStrategy")
+        Quantity(0.1234, precision=3, component="MyStrategy")
+        Quantity(0.1234, precision=3)
+        Price(1.155, precision=2)
+
+        # Assert
+        assert lossy_conversion_counts() == {"MyStrategy": 2, "Quantity": 1, "Price": 1}
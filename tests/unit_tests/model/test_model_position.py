@@ -150,6 +150,8 @@ class TestPosition:
             "cost_currency": "USD",
             "realized_return": "0.0",
             "realized_pnl": "-2.00 USD",
+            "max_adverse_excursion": "0.0",
+            "max_favorable_excursion": "0.0",
             "commissions": "['2.00 USD']",
         }
 
@@ -407,6 +409,149 @@ class TestPosition:
         assert position.commissions() == [Money(3.00, USD)]
         assert repr(position) == "Position(FLAT AUD/USD.SIM, id=P-123456)"
 
+    def test_position_excursions_tracked_while_open_and_retained_when_closed(self):
+        # Arrange
+        order1 = self.order_factory.market(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100000),
+        )
+
+        order2 = self.order_factory.market(
+            AUDUSD_SIM.id,
+            OrderSide.SELL,
+            Quantity.from_int(100000),
+        )
+
+        fill1 = TestEventStubs.order_filled(
+            order1,
+            instrument=AUDUSD_SIM,
+            position_id=PositionId("P-123456"),
+            strategy_id=StrategyId("S-001"),
+            last_px=Price.from_str("1.00000"),
+        )
+
+        fill2 = TestEventStubs.order_filled(
+            order2,
+            instrument=AUDUSD_SIM,
+            position_id=PositionId("P-123456"),
+            strategy_id=StrategyId("S-001"),
+            last_px=Price.from_str("1.00020"),
+        )
+
+        position = Position(instrument=AUDUSD_SIM, fill=fill1)
+
+        # Act
+        position.update_excursions(Price.from_str("0.99970"))
+        position.update_excursions(Price.from_str("1.00050"))
+        position.update_excursions(Price.from_str("0.99990"))
+        position.apply(fill2)
+        position.update_excursions(Price.from_str("0.99000"))
+
+        # Assert
+        assert position.is_closed
+        assert position.max_adverse_excursion == pytest.approx(0.00030)
+        assert position.max_favorable_excursion == pytest.approx(0.00050)
+
+    def test_position_settle_realizes_variation_margin_and_resets_cost_basis(self):
+        # Arrange
+        order1 = self.order_factory.market(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100000),
+        )
+
+        order2 = self.order_factory.market(
+            AUDUSD_SIM.id,
+            OrderSide.SELL,
+            Quantity.from_int(100000),
+        )
+
+        fill1 = TestEventStubs.order_filled(
+            order1,
+            instrument=AUDUSD_SIM,
+            position_id=PositionId("P-123456"),
+            strategy_id=StrategyId("S-001"),
+            last_px=Price.from_str("1.00000"),
+        )
+
+        fill2 = TestEventStubs.order_filled(
+            order2,
+            instrument=AUDUSD_SIM,
+            position_id=PositionId("P-123456"),
+            strategy_id=StrategyId("S-001"),
+            last_px=Price.from_str("1.00020"),
+        )
+
+        position = Position(instrument=AUDUSD_SIM, fill=fill1)
+        unsettled = Position(instrument=AUDUSD_SIM, fill=fill1)
+
+        # Act
+        variation_margin = position.settle(Price.from_str("1.00010"))
+        avg_px_open = position.avg_px_open
+        position.apply(fill2)
+        unsettled.apply(fill2)
+
+        # Assert
+        assert variation_margin == Money(10.00, USD)
+        assert avg_px_open == 1.00010
+        assert position.is_closed
+        assert position.realized_pnl == unsettled.realized_pnl
+
+    def test_position_settle_when_flat_returns_zero(self):
+        # Arrange
+        order1 = self.order_factory.market(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100000),
+        )
+
+        order2 = self.order_factory.market(
+            AUDUSD_SIM.id,
+            OrderSide.SELL,
+            Quantity.from_int(100000),
+        )
+
+        position = Position(
+            instrument=AUDUSD_SIM,
+            fill=TestEventStubs.order_filled(order1, instrument=AUDUSD_SIM),
+        )
+        position.apply(TestEventStubs.order_filled(order2, instrument=AUDUSD_SIM))
+        realized_pnl = position.realized_pnl
+
+        # Act
+        variation_margin = position.settle(Price.from_str("1.00010"))
+
+        # Assert
+        assert variation_margin == Money(0, USD)
+        assert position.realized_pnl == realized_pnl
+
+    def test_position_excursions_for_short_position(self):
+        # Arrange
+        order = self.order_factory.market(
+            AUDUSD_SIM.id,
+            OrderSide.SELL,
+            Quantity.from_int(100000),
+        )
+
+        fill = TestEventStubs.order_filled(
+            order,
+            instrument=AUDUSD_SIM,
+            position_id=PositionId("P-123456"),
+            strategy_id=StrategyId("S-001"),
+            last_px=Price.from_str("1.00000"),
+        )
+
+        position = Position(instrument=AUDUSD_SIM, fill=fill)
+
+        # Act
+        position.update_excursions(Price.from_str("1.00010"))
+        position.update_excursions(Price.from_str("0.99980"))
+
+        # Assert
+        assert position.max_adverse_excursion == pytest.approx(0.00010)
+        assert position.max_favorable_excursion == pytest.approx(0.00020)
+
     def test_position_filled_with_sell_order_then_buy_order_returns_expected_attributes(
         self,
     ):
@@ -0,0 +1,113 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2022 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import pytest
+
+from nautilus_trader.model.identifiers import StrategyId
+from nautilus_trader.msgbus.coordination import ResourceCoordinator
+from nautilus_trader.msgbus.coordination import instrument_lock_name
+from tests.test_kit.stubs.identifiers import TestIdStubs
+
+
+STRATEGY1 = StrategyId("S-001")
+STRATEGY2 = StrategyId("S-002")
+
+
+class TestResourceCoordinator:
+    def setup(self):
+        # Fixture Setup
+        self.coordinator = ResourceCoordinator()
+
+    def test_instrument_lock_name(self):
+        # Arrange, Act, Assert
+        assert instrument_lock_name(TestIdStubs.audusd_id()) == "trading.AUD/USD.SIM"
+
+    def test_lock_held_by_single_owner(self):
+        # Arrange, Act
+        acquired1 = self.coordinator.try_acquire("trading.AUD/USD.SIM", STRATEGY1)
+        acquired2 = self.coordinator.try_acquire("trading.AUD/USD.SIM", STRATEGY2)
+        reacquired1 = self.coordinator.try_acquire("trading.AUD/USD.SIM", STRATEGY1)
+
+        # Assert
+        assert acquired1
+        assert not acquired2
+        assert reacquired1
+        assert self.coordinator.lock_owner("trading.AUD/USD.SIM") == STRATEGY1
+
+    def test_release_only_by_owner(self):
+        # Arrange
+        self.coordinator.try_acquire("lock", STRATEGY1)
+
+        # Act
+        released2 = self.coordinator.release("lock", STRATEGY2)
+        released1 = self.coordinator.release("lock", STRATEGY1)
+
+        # Assert
+        assert not released2
+        assert released1
+        assert self.coordinator.lock_owner("lock") is None
+        assert self.coordinator.try_acquire("lock", STRATEGY2)
+
+    def test_budget_reservations_limited(self):
+        # Arrange
+        self.coordinator.set_budget("notional", 1_000_000)
+
+        # Act
+        reserved1 = self.coordinator.try_reserve("notional", STRATEGY1, 600_000)
+        reserved2 = self.coordinator.try_reserve("notional", STRATEGY2, 500_000)
+        reserved3 = self.coordinator.try_reserve("notional", STRATEGY2, 400_000)
+
+        # Assert
+        assert reserved1
+        assert not reserved2
+        assert reserved3
+        assert self.coordinator.remaining("notional") == 0
+        assert self.coordinator.reserved("notional", STRATEGY1) == 600_000
+
+    def test_free_budget(self):
+        # Arrange
+        self.coordinator.set_budget("notional", 1_000)
+        self.coordinator.try_reserve("notional", STRATEGY1, 600)
+
+        # Act
+        self.coordinator.free("notional", STRATEGY1, 200)
+        remaining = self.coordinator.remaining("notional")
+        self.coordinator.free("notional", STRATEGY1, 1_000)
+
+        # Assert
+        assert remaining == 600
+        assert self.coordinator.reserved("notional") == 0
+
+    def test_reserve_unknown_budget_raises_key_error(self):
+        # Arrange, Act, Assert
+        with pytest.raises(KeyError):
+            self.coordinator.try_reserve("notional", STRATEGY1, 1)
+
+    def test_release_all(self):
+        # Arrange
+        self.coordinator.set_budget("notional", 1_000)
+        self.coordinator.try_reserve("notional", STRATEGY1, 600)
+        self.coordinator.try_reserve("notional", STRATEGY2, 100)
+        self.coordinator.try_acquire("lock1", STRATEGY1)
+        self.coordinator.try_acquire("lock2", STRATEGY1)
+        self.coordinator.try_acquire("lock3", STRATEGY2)
+
+        # Act
+        self.coordinator.release_all(STRATEGY1)
+
+        # Assert
+        assert self.coordinator.locks() == ["lock3"]
+        assert self.coordinator.locks(STRATEGY1) == []
+        assert self.coordinator.remaining("notional") == 900
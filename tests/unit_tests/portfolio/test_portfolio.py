@@ -681,6 +681,46 @@ class TestPortfolio:
         assert not self.portfolio.is_flat(order.instrument_id)
         assert not self.portfolio.is_completely_flat()
 
+    def test_update_quote_tick_updates_open_position_excursions(self):
+        # Arrange
+        order = self.order_factory.market(
+            BTCUSDT_BINANCE.id,
+            OrderSide.BUY,
+            Quantity.from_str("10.000000"),
+        )
+
+        fill = TestEventStubs.order_filled(
+            order=order,
+            instrument=BTCUSDT_BINANCE,
+            strategy_id=StrategyId("S-001"),
+            position_id=PositionId("P-123456"),
+            last_px=Price.from_str("10500.00"),
+        )
+
+        position = Position(instrument=BTCUSDT_BINANCE, fill=fill)
+        self.cache.add_position(position, OMSType.HEDGING)
+
+        ticks = [
+            QuoteTick(
+                instrument_id=BTCUSDT_BINANCE.id,
+                bid=Price.from_str(bid),
+                ask=Price.from_str(ask),
+                bid_size=Quantity.from_str("1.000000"),
+                ask_size=Quantity.from_str("1.000000"),
+                ts_event=0,
+                ts_init=0,
+            )
+            for bid, ask in [("10400.00", "10401.00"), ("10600.00", "10650.00")]
+        ]
+
+        # Act
+        for tick in ticks:
+            self.portfolio.update_quote_tick(tick)
+
+        # Assert (a long position would close at the bid)
+        assert position.max_adverse_excursion == 100.0
+        assert position.max_favorable_excursion == 100.0
+
     def test_opening_one_short_position_updates_portfolio(self):
         # Arrange
         AccountFactory.register_calculated_account("BINANCE")
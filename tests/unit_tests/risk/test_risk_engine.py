@@ -16,6 +16,8 @@
 from datetime import timedelta
 from decimal import Decimal
 
+import pytest
+
 from nautilus_trader.backtest.data.providers import TestInstrumentProvider
 from nautilus_trader.common.clock import TestClock
 from nautilus_trader.common.enums import LogLevel
@@ -151,6 +153,26 @@ class TestRiskEngine:
         assert risk_engine.max_notionals_per_order() == {GBPUSD_SIM.id: Decimal("2000000")}
         assert risk_engine.max_notional_per_order(GBPUSD_SIM.id) == 2_000_000
 
+    def test_config_risk_engine_with_strategy_allocations(self):
+        # Arrange
+        self.msgbus.deregister("RiskEngine.execute", self.risk_engine.execute)
+
+        config = RiskEngineConfig(strategy_allocations={"S-001": "0.25"})
+
+        # Act
+        risk_engine = RiskEngine(
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+            logger=self.logger,
+            config=config,
+        )
+
+        # Assert
+        assert risk_engine.strategy_allocations() == {StrategyId("S-001"): Decimal("0.25")}
+        assert risk_engine.strategy_allocation(StrategyId("S-001")) == Decimal("0.25")
+
     def test_risk_engine_on_stop(self):
         # Arrange, Act
         self.risk_engine.start()
@@ -228,6 +250,65 @@ class TestRiskEngine:
         assert max_notionals == {AUDUSD_SIM.id: Decimal("1000000")}
         assert max_notional == Decimal(1_000_000)
 
+    def test_max_data_age_when_no_risk_config_returns_none(self):
+        # Arrange, Act
+        result = self.risk_engine.max_data_age()
+
+        # Assert
+        assert result is None
+        assert self.risk_engine.deny_stale_data
+
+    def test_config_risk_engine_with_max_data_age(self):
+        # Arrange
+        self.msgbus.deregister("RiskEngine.execute", self.risk_engine.execute)
+
+        config = RiskEngineConfig(max_data_age="00:00:05", deny_stale_data=False)
+
+        # Act
+        risk_engine = RiskEngine(
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+            logger=self.logger,
+            config=config,
+        )
+
+        # Assert
+        assert risk_engine.max_data_age() == timedelta(seconds=5)
+        assert not risk_engine.deny_stale_data
+
+    def test_last_data_update_ns_returns_latest_tick_timestamp(self):
+        # Arrange
+        self.cache.add_quote_tick(TestDataStubs.quote_tick_5decimal(AUDUSD_SIM.id))
+        self.cache.add_trade_tick(TestDataStubs.trade_tick_5decimal(AUDUSD_SIM.id))
+
+        # Act, Assert
+        assert self.risk_engine.last_data_update_ns(AUDUSD_SIM.id) == 0
+        assert self.risk_engine.last_data_update_ns(GBPUSD_SIM.id) is None
+
+    def test_strategy_allocation_when_no_risk_config_returns_none(self):
+        # Arrange, Act
+        result = self.risk_engine.strategy_allocation(StrategyId("S-001"))
+
+        # Assert
+        assert result is None
+        assert self.risk_engine.strategy_allocations() == {}
+
+    def test_set_strategy_allocation_changes_setting(self):
+        # Arrange, Act
+        self.risk_engine.set_strategy_allocation(StrategyId("S-001"), "0.5")
+        self.risk_engine.set_strategy_allocation(StrategyId("S-002"), "0.5")
+        self.risk_engine.set_strategy_allocation(StrategyId("S-002"), None)
+
+        # Assert
+        assert self.risk_engine.strategy_allocations() == {StrategyId("S-001"): Decimal("0.5")}
+
+    def test_set_strategy_allocation_when_greater_than_one_raises_value_error(self):
+        # Arrange, Act, Assert
+        with pytest.raises(ValueError):
+            self.risk_engine.set_strategy_allocation(StrategyId("S-001"), "1.5")
+
     def test_given_random_command_then_logs_and_continues(self):
         # Arrange
         random = TradingCommand(
@@ -895,6 +976,224 @@ class TestRiskEngine:
         # Assert
         assert self.exec_engine.command_count == 0  # <-- command never reaches engine
 
+    def test_submit_order_when_over_strategy_allocation_then_denies(self):
+        # Arrange - Initialize market
+        quote = TestDataStubs.quote_tick_5decimal(AUDUSD_SIM.id)
+        self.cache.add_quote_tick(quote)
+
+        self.exec_engine.start()
+
+        strategy = Strategy()
+        strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+            logger=self.logger,
+        )
+
+        # Allocate 100,000 USD of the 1,000,000 USD account
+        self.risk_engine.set_strategy_allocation(strategy.id, "0.1")
+
+        order = strategy.order_factory.market(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(200000),
+        )
+
+        submit_order = SubmitOrder(
+            self.trader_id,
+            strategy.id,
+            None,
+            True,
+            order,
+            self.uuid_factory.generate(),
+            self.clock.timestamp_ns(),
+        )
+
+        # Act
+        self.risk_engine.execute(submit_order)
+
+        # Assert
+        assert self.exec_engine.command_count == 0  # <-- command never reaches engine
+
+    def test_submit_order_after_reallocation_then_sends_to_client(self):
+        # Arrange - Initialize market
+        quote = TestDataStubs.quote_tick_5decimal(AUDUSD_SIM.id)
+        self.cache.add_quote_tick(quote)
+
+        self.exec_engine.start()
+
+        strategy = Strategy()
+        strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+            logger=self.logger,
+        )
+
+        self.risk_engine.set_strategy_allocation(strategy.id, "0.1")
+        self.risk_engine.set_strategy_allocation(strategy.id, "0.5")
+
+        order = strategy.order_factory.market(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(200000),
+        )
+
+        submit_order = SubmitOrder(
+            self.trader_id,
+            strategy.id,
+            None,
+            True,
+            order,
+            self.uuid_factory.generate(),
+            self.clock.timestamp_ns(),
+        )
+
+        # Act
+        self.risk_engine.execute(submit_order)
+
+        # Assert
+        assert self.exec_engine.command_count == 1
+
+    def test_submit_order_when_market_data_stale_then_denies(self):
+        # Arrange - Initialize market
+        quote = TestDataStubs.quote_tick_5decimal(AUDUSD_SIM.id)
+        self.cache.add_quote_tick(quote)
+
+        self.exec_engine.start()
+
+        strategy = Strategy()
+        strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+            logger=self.logger,
+        )
+
+        self.risk_engine.set_max_data_age(timedelta(seconds=5))
+        self.clock.set_time(10_000_000_000)  # <-- quote is 10 seconds old
+
+        order = strategy.order_factory.limit(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100000),
+            Price.from_str("1.00000"),
+        )
+
+        submit_order = SubmitOrder(
+            self.trader_id,
+            strategy.id,
+            None,
+            True,
+            order,
+            self.uuid_factory.generate(),
+            self.clock.timestamp_ns(),
+        )
+
+        # Act
+        self.risk_engine.execute(submit_order)
+
+        # Assert
+        assert self.exec_engine.command_count == 0  # <-- command never reaches engine
+
+    def test_submit_order_when_market_data_stale_and_reduce_only_then_sends_to_client(self):
+        # Arrange - Initialize market
+        quote = TestDataStubs.quote_tick_5decimal(AUDUSD_SIM.id)
+        self.cache.add_quote_tick(quote)
+
+        self.exec_engine.start()
+
+        strategy = Strategy()
+        strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+            logger=self.logger,
+        )
+
+        self.risk_engine.set_max_data_age(timedelta(seconds=5))
+        self.clock.set_time(10_000_000_000)  # <-- quote is 10 seconds old
+
+        order = strategy.order_factory.market(
+            AUDUSD_SIM.id,
+            OrderSide.SELL,
+            Quantity.from_int(100000),
+            reduce_only=True,
+        )
+
+        submit_order = SubmitOrder(
+            self.trader_id,
+            strategy.id,
+            None,
+            True,
+            order,
+            self.uuid_factory.generate(),
+            self.clock.timestamp_ns(),
+        )
+
+        # Act
+        self.risk_engine.execute(submit_order)
+
+        # Assert
+        assert self.exec_engine.command_count == 1
+
+    def test_submit_order_when_no_market_data_and_only_warning_then_sends_to_client(self):
+        # Arrange
+        self.msgbus.deregister("RiskEngine.execute", self.risk_engine.execute)
+
+        risk_engine = RiskEngine(
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+            logger=self.logger,
+            config=RiskEngineConfig(max_data_age="00:00:05", deny_stale_data=False),
+        )
+
+        self.exec_engine.start()
+
+        strategy = Strategy()
+        strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+            logger=self.logger,
+        )
+
+        order = strategy.order_factory.limit(
+            AUDUSD_SIM.id,
+            OrderSide.BUY,
+            Quantity.from_int(100000),
+            Price.from_str("1.00000"),
+        )
+
+        submit_order = SubmitOrder(
+            self.trader_id,
+            strategy.id,
+            None,
+            True,
+            order,
+            self.uuid_factory.generate(),
+            self.clock.timestamp_ns(),
+        )
+
+        # Act
+        risk_engine.execute(submit_order)
+
+        # Assert
+        assert self.exec_engine.command_count == 1
+
     def test_submit_order_list_buys_when_over_free_balance_then_denies(self):
         # Arrange - Initialize market
         quote = TestDataStubs.quote_tick_5decimal(AUDUSD_SIM.id)
@@ -27,11 +27,14 @@ from nautilus_trader.common.events.system import ComponentStateChanged
 from nautilus_trader.common.factories import OrderFactory
 from nautilus_trader.model.c_enums.book_action import BookAction
 from nautilus_trader.model.c_enums.book_type import BookType
+from nautilus_trader.model.data.cash_flow import CashFlow
+from nautilus_trader.model.enums import CashFlowType
 from nautilus_trader.model.enums import OrderSide
 from nautilus_trader.model.events.account import AccountState
 from nautilus_trader.model.identifiers import PositionId
 from nautilus_trader.model.identifiers import StrategyId
 from nautilus_trader.model.identifiers import TraderId
+from nautilus_trader.model.objects import Money
 from nautilus_trader.model.objects import Price
 from nautilus_trader.model.objects import Quantity
 from nautilus_trader.model.orderbook.data import OrderBookDelta
@@ -126,6 +129,17 @@ class TestParquetSerializer:
         bar = TestDataStubs.bar_5decimal()
         self._test_serialization(obj=bar)
 
+    def test_serialize_and_deserialize_cash_flow(self):
+        cash_flow = CashFlow(
+            instrument_id=AUDUSD_SIM.id,
+            cash_flow_type=CashFlowType.COUPON,
+            amount=Money.from_str("0.25 USD"),
+            ts_event=0,
+            ts_init=0,
+        )
+        self._test_serialization(obj=cash_flow)
+        assert self.catalog.cash_flows(as_nautilus=True) == [cash_flow]
+
     def test_serialize_and_deserialize_order_book_delta(self):
         delta = OrderBookDelta(
             instrument_id=TestIdStubs.audusd_id(),
@@ -287,6 +287,85 @@ class TestStrategy:
         # TODO: Write a users custom save method
         assert True
 
+    def test_save_includes_state_version(self):
+        # Arrange
+        strategy = Strategy(StrategyConfig(state_version=3))
+        strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+            logger=self.logger,
+        )
+
+        # Act
+        state = strategy.save()
+
+        # Assert
+        assert state == {"__state_version__": b"3"}
+
+    def test_load_state_of_other_version_calls_on_migrate_state(self):
+        # Arrange
+        class VersionedStrategy(Strategy):
+            def __init__(self):
+                super().__init__(StrategyConfig(state_version=2))
+                self.migrated = None
+                self.loaded = None
+
+            def on_migrate_state(self, version, state):
+                self.migrated = (version, state)
+                return {"count": state["old_count"]}
+
+            def on_load(self, state):
+                self.loaded = state
+
+        strategy = VersionedStrategy()
+        strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+            logger=self.logger,
+        )
+
+        # Act
+        strategy.load({"old_count": b"5"})
+
+        # Assert
+        assert strategy.migrated == (1, {"old_count": b"5"})
+        assert strategy.loaded == {"count": b"5"}
+
+    def test_load_state_of_other_version_discarded_by_default(self):
+        # Arrange
+        class LoadingStrategy(Strategy):
+            def __init__(self):
+                super().__init__(StrategyConfig(state_version=2))
+                self.loaded = None
+
+            def on_load(self, state):
+                self.loaded = state
+
+        strategy = LoadingStrategy()
+        strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+            logger=self.logger,
+        )
+
+        # Act
+        strategy.load({"count": b"5", "__state_version__": b"1"})
+        loaded_before = strategy.loaded
+        strategy.load({"count": b"6", "__state_version__": b"2"})
+
+        # Assert
+        assert loaded_before is None
+        assert strategy.loaded == {"count": b"6"}
+
     def test_reset(self):
         # Arrange
         bar_type = TestDataStubs.bartype_audusd_1min_bid()
@@ -668,6 +747,54 @@ class TestStrategy:
         # Assert
         assert ema.count == 0
 
+    def test_instrument_lock_held_by_single_strategy(self):
+        # Arrange
+        strategy1 = Strategy(StrategyConfig(order_id_tag="001"))
+        strategy2 = Strategy(StrategyConfig(order_id_tag="002"))
+        for strategy in (strategy1, strategy2):
+            strategy.register(
+                trader_id=self.trader_id,
+                portfolio=self.portfolio,
+                msgbus=self.msgbus,
+                cache=self.cache,
+                clock=self.clock,
+                logger=self.logger,
+            )
+
+        # Act
+        acquired1 = strategy1.acquire_instrument_lock(AUDUSD_SIM.id)
+        acquired2 = strategy2.acquire_instrument_lock(AUDUSD_SIM.id)
+
+        # Assert
+        assert acquired1
+        assert not acquired2
+        assert not strategy2.release_instrument_lock(AUDUSD_SIM.id)
+        assert strategy1.release_instrument_lock(AUDUSD_SIM.id)
+        assert strategy2.acquire_instrument_lock(AUDUSD_SIM.id)
+
+    def test_stop_releases_locks_and_budgets(self):
+        # Arrange
+        strategy = Strategy()
+        strategy.register(
+            trader_id=self.trader_id,
+            portfolio=self.portfolio,
+            msgbus=self.msgbus,
+            cache=self.cache,
+            clock=self.clock,
+            logger=self.logger,
+        )
+        self.msgbus.coordinator.set_budget("notional", 1_000)
+        strategy.start()
+        strategy.acquire_lock("lock")
+        strategy.reserve_budget("notional", 600)
+
+        # Act
+        strategy.stop()
+
+        # Assert
+        assert self.msgbus.coordinator.locks() == []
+        assert self.msgbus.coordinator.remaining("notional") == 1_000
+
     def test_stop_cancels_a_running_time_alert(self):
         # Arrange
         bar_type = TestDataStubs.bartype_audusd_1min_bid()
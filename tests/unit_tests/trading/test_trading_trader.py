@@ -279,6 +279,36 @@ class TestTrader:
         assert strategy_states[StrategyId("Strategy-001")] == "RUNNING"
         assert strategy_states[StrategyId("Strategy-002")] == "RUNNING"
 
+    def test_start_saves_strategy_state_at_interval(self):
+        # Arrange
+        strategies = [
+            Strategy(StrategyConfig(order_id_tag="001", save_state_interval_secs=60)),
+            Strategy(StrategyConfig(order_id_tag="002")),
+        ]
+        self.trader.add_strategies(strategies)
+        self.trader.start()
+
+        # Act
+        for event in self.clock.advance_time(61_000_000_000):
+            event.handle()
+
+        # Assert
+        assert self.clock.timer_names() == ["SaveState-Strategy-001"]
+        assert StrategyId("Strategy-001") in self.cache.strategy_ids()
+        assert StrategyId("Strategy-002") not in self.cache.strategy_ids()
+
+    def test_stop_cancels_save_strategy_state_timers(self):
+        # Arrange
+        strategy = Strategy(StrategyConfig(order_id_tag="001", save_state_interval_secs=60))
+        self.trader.add_strategy(strategy)
+        self.trader.start()
+
+        # Act
+        self.trader.stop()
+
+        # Assert
+        assert self.clock.timer_names() == []
+
     def test_stop_a_running_trader(self):
         # Arrange
         strategies = [